@@ -0,0 +1,198 @@
+use std::collections::{BTreeSet, HashSet};
+use std::hint::black_box;
+use std::str::FromStr;
+
+use criterion::{criterion_group, criterion_main, BatchSize, Criterion, Throughput};
+use trove_classifiers::scanner::Scanner;
+use trove_classifiers::Classifier;
+
+/// Classifier strings spread across the dataset, plus one that is unknown
+const SAMPLES: [(&str, &str); 4] = [
+    ("first", "Development Status :: 1 - Planning"),
+    ("middle", "License :: OSI Approved :: MIT License"),
+    ("last", "Typing :: Typed"),
+    ("unknown", "Programming Language :: Python :: 2.8"),
+];
+
+/// Build `count` core metadata documents resembling the METADATA files of real
+/// distributions, each declaring a dozen classifiers and the occasional invalid one
+fn metadata_corpus(count: usize) -> Vec<String> {
+    let all = Classifier::ALL;
+    (0..count)
+        .map(|package| {
+            let mut document = format!(
+                "Metadata-Version: 2.1\n\
+                 Name: package-{package}\n\
+                 Version: 1.{package}.0\n\
+                 Summary: A package with classifiers\n\
+                 Requires-Python: >=3.8\n"
+            );
+            for offset in 0..12 {
+                let classifier = all[(package * 31 + offset * 67) % all.len()];
+                document.push_str(&format!("Classifier: {classifier}\n"));
+            }
+            if package % 10 == 0 {
+                document.push_str("Classifier: Programming Language :: Python :: 2.8\n");
+            }
+            document.push_str("Requires-Dist: requests>=2\n\n");
+            document.push_str("A long description that is not part of the headers.\n");
+            document
+        })
+        .collect()
+}
+
+/// Validate every `Classifier` header of a METADATA document
+fn validate_headers(document: &str) -> (usize, usize) {
+    let (mut valid, mut invalid) = (0, 0);
+    for line in document.lines().take_while(|line| !line.is_empty()) {
+        if let Some(value) = line.strip_prefix("Classifier: ") {
+            match Classifier::from_str(value) {
+                Ok(_) => valid += 1,
+                Err(_) => invalid += 1,
+            }
+        }
+    }
+    (valid, invalid)
+}
+
+fn parse(c: &mut Criterion) {
+    let mut group = c.benchmark_group("from_str");
+    for (name, classifier) in SAMPLES {
+        group.bench_function(name, |b| {
+            b.iter(|| Classifier::from_str(black_box(classifier)))
+        });
+    }
+    group.finish();
+}
+
+fn complete(c: &mut Criterion) {
+    let mut group = c.benchmark_group("complete");
+    for (name, prefix) in [
+        ("keystroke", "Framework :: Dj"),
+        ("broad", "Programming Language :: "),
+        ("none", "Unknown :: "),
+    ] {
+        group.bench_function(name, |b| {
+            b.iter(|| trove_classifiers::complete(black_box(prefix)).count())
+        });
+    }
+    group.finish();
+}
+
+fn format(c: &mut Criterion) {
+    let mut group = c.benchmark_group("format");
+    group.throughput(Throughput::Elements(Classifier::ALL.len() as u64));
+    group.bench_function("as_ref", |b| {
+        b.iter(|| {
+            Classifier::ALL
+                .iter()
+                .map(|classifier| black_box(classifier).as_ref().len())
+                .sum::<usize>()
+        })
+    });
+    group.bench_function("to_string", |b| {
+        b.iter(|| {
+            Classifier::ALL
+                .iter()
+                .map(|classifier| black_box(classifier).to_string().len())
+                .sum::<usize>()
+        })
+    });
+    group.bench_function("split", |b| {
+        b.iter(|| {
+            Classifier::ALL
+                .iter()
+                .map(|classifier| black_box(classifier).split().count())
+                .sum::<usize>()
+        })
+    });
+    group.finish();
+}
+
+fn bulk(c: &mut Criterion) {
+    let corpus = metadata_corpus(1_000);
+    let mut group = c.benchmark_group("bulk");
+    group.throughput(Throughput::Elements(corpus.len() as u64));
+    group.bench_function("validate_metadata", |b| {
+        b.iter(|| {
+            corpus
+                .iter()
+                .map(|document| validate_headers(black_box(document)))
+                .fold((0, 0), |total, found| {
+                    (total.0 + found.0, total.1 + found.1)
+                })
+        })
+    });
+    let mut scanner = Scanner::new();
+    group.bench_function("scanner", |b| {
+        b.iter(|| {
+            scanner
+                .scan_all(black_box(&corpus))
+                .map(|report| report.classifiers.len())
+                .sum::<usize>()
+        })
+    });
+    group.finish();
+}
+
+fn sets(c: &mut Criterion) {
+    let evens: Vec<Classifier> = Classifier::ALL.iter().copied().step_by(2).collect();
+    let thirds: Vec<Classifier> = Classifier::ALL.iter().copied().step_by(3).collect();
+
+    let mut group = c.benchmark_group("sets");
+    group.bench_function("hash_set_build", |b| {
+        b.iter(|| black_box(&evens).iter().copied().collect::<HashSet<_>>())
+    });
+    group.bench_function("btree_set_build", |b| {
+        b.iter(|| black_box(&evens).iter().copied().collect::<BTreeSet<_>>())
+    });
+
+    let hash = (
+        evens.iter().copied().collect::<HashSet<_>>(),
+        thirds.iter().copied().collect::<HashSet<_>>(),
+    );
+    group.bench_function("hash_set_intersection", |b| {
+        b.iter(|| black_box(&hash.0).intersection(black_box(&hash.1)).count())
+    });
+    group.bench_function("hash_set_union", |b| {
+        b.iter(|| black_box(&hash.0).union(black_box(&hash.1)).count())
+    });
+
+    let btree = (
+        evens.iter().copied().collect::<BTreeSet<_>>(),
+        thirds.iter().copied().collect::<BTreeSet<_>>(),
+    );
+    group.bench_function("btree_set_intersection", |b| {
+        b.iter(|| {
+            black_box(&btree.0)
+                .intersection(black_box(&btree.1))
+                .count()
+        })
+    });
+    group.bench_function("btree_set_union", |b| {
+        b.iter(|| black_box(&btree.0).union(black_box(&btree.1)).count())
+    });
+
+    group.bench_function("sort_dedup", |b| {
+        b.iter_batched(
+            || {
+                evens
+                    .iter()
+                    .chain(&thirds)
+                    .rev()
+                    .copied()
+                    .collect::<Vec<_>>()
+            },
+            |mut classifiers| {
+                classifiers.sort_unstable();
+                classifiers.dedup();
+                classifiers
+            },
+            BatchSize::SmallInput,
+        )
+    });
+    group.finish();
+}
+
+criterion_group!(benches, parse, complete, format, bulk, sets);
+criterion_main!(benches);
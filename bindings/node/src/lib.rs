@@ -0,0 +1,82 @@
+//! Node.js bindings to trove-classifiers, built with napi-rs.
+//!
+//! ```text
+//! cd bindings/node
+//! npm install
+//! npm run build
+//! ```
+//!
+//! ```js
+//! const { parse, validate } = require("trove-classifiers");
+//!
+//! parse("Typing :: Typed");                      // "Typing :: Typed"
+//! validate(["Typing :: Typed", "Typing :: Nope"]); // ["Typing :: Nope"]
+//! ```
+
+use std::str::FromStr;
+
+use napi::{Error, Result};
+use napi_derive::napi;
+use trove_classifiers::Classifier;
+
+/// The version of the python package pypa/trove-classifiers that the dataset was taken from.
+#[napi]
+pub const PYPA_VERSION: &str = trove_classifiers::PYPA_VERSION;
+
+/// Return the canonical form of a classifier, throwing if it is unknown.
+#[napi]
+pub fn parse(classifier: String) -> Result<&'static str> {
+    Classifier::from_str(&classifier)
+        .map(|classifier| classifier.as_str())
+        .map_err(|_| Error::from_reason(format!("unknown classifier: {classifier:?}")))
+}
+
+/// Whether a string is a classifier known to pypi.org.
+#[napi]
+pub fn is_valid(classifier: String) -> bool {
+    Classifier::from_str(&classifier).is_ok()
+}
+
+/// Return the classifiers of a list that are not known to pypi.org, in their original order.
+#[napi]
+pub fn validate(classifiers: Vec<String>) -> Vec<String> {
+    classifiers
+        .into_iter()
+        .filter(|classifier| Classifier::from_str(classifier).is_err())
+        .collect()
+}
+
+/// Return the classifiers matching every whitespace-separated term of a query, ignoring ASCII
+/// case. A term matches as a substring or as the acronym of consecutive words, such as `gpl`.
+#[napi]
+pub fn search(query: String) -> Vec<&'static str> {
+    trove_classifiers::search(&query)
+        .map(|classifier| classifier.as_str())
+        .collect()
+}
+
+/// Return the classifiers starting with a prefix, ignoring ASCII case.
+#[napi]
+pub fn complete(prefix: String, limit: Option<u32>) -> Vec<&'static str> {
+    trove_classifiers::complete(&prefix)
+        .take(limit.map_or(usize::MAX, |limit| limit as usize))
+        .map(|classifier| classifier.as_str())
+        .collect()
+}
+
+/// Return the segments of a classifier, throwing if it is unknown.
+#[napi]
+pub fn segments(classifier: String) -> Result<Vec<&'static str>> {
+    Classifier::from_str(&classifier)
+        .map(|classifier| classifier.segments_static().to_vec())
+        .map_err(|_| Error::from_reason(format!("unknown classifier: {classifier:?}")))
+}
+
+/// Return every known classifier, in canonical order.
+#[napi]
+pub fn all_classifiers() -> Vec<&'static str> {
+    Classifier::ALL
+        .iter()
+        .map(|classifier| classifier.as_str())
+        .collect()
+}
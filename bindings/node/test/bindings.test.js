@@ -0,0 +1,29 @@
+const assert = require("node:assert");
+const test = require("node:test");
+
+const trove = require("..");
+
+test("parse", () => {
+  assert.strictEqual(trove.parse("Typing :: Typed"), "Typing :: Typed");
+  assert.throws(() => trove.parse("Typing :: Untyped"), /unknown classifier/);
+});
+
+test("validate", () => {
+  assert.ok(trove.isValid("Programming Language :: Rust"));
+  assert.deepStrictEqual(trove.validate(["Typing :: Typed", "Typing :: Untyped"]), [
+    "Typing :: Untyped",
+  ]);
+});
+
+test("search", () => {
+  assert.deepStrictEqual(trove.search("typing TYPED"), ["Typing :: Typed"]);
+});
+
+test("complete", () => {
+  assert.deepStrictEqual(trove.complete("typing ::"), ["Typing :: Stubs Only", "Typing :: Typed"]);
+  assert.deepStrictEqual(trove.complete("Typing ::", 1), ["Typing :: Stubs Only"]);
+});
+
+test("segments", () => {
+  assert.deepStrictEqual(trove.segments("Typing :: Typed"), ["Typing", "Typed"]);
+});
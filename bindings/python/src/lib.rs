@@ -0,0 +1,175 @@
+//! Python bindings to trove-classifiers, built with maturin as `trove_classifiers_rs`.
+//!
+//! ```text
+//! cd bindings/python
+//! maturin develop
+//! python -c "import trove_classifiers_rs; print(trove_classifiers_rs.is_valid('Typing :: Typed'))"
+//! ```
+
+use std::str::FromStr;
+
+use pyo3::exceptions::PyValueError;
+use pyo3::prelude::*;
+use trove_classifiers::scanner::Scanner;
+use trove_classifiers::suggest::Project;
+use trove_classifiers::{deprecation, Classifier};
+
+/// Return the canonical form of a classifier, raising ValueError if it is unknown.
+#[pyfunction]
+fn parse(classifier: &str) -> PyResult<&'static str> {
+    Classifier::from_str(classifier)
+        .map(|classifier| classifier.as_str())
+        .map_err(|_| PyValueError::new_err(format!("unknown classifier: {classifier:?}")))
+}
+
+/// Whether a string is a classifier known to pypi.org.
+#[pyfunction]
+fn is_valid(classifier: &str) -> bool {
+    Classifier::from_str(classifier).is_ok()
+}
+
+/// Return the classifiers of a list that are not known to pypi.org, in their original order.
+#[pyfunction]
+fn validate(classifiers: Vec<String>) -> Vec<String> {
+    classifiers
+        .into_iter()
+        .filter(|classifier| Classifier::from_str(classifier).is_err())
+        .collect()
+}
+
+/// Return the classifiers matching every whitespace-separated term of a query, ignoring ASCII
+/// case. A term matches as a substring or as the acronym of consecutive words, such as `gpl`.
+#[pyfunction]
+fn search(query: &str) -> Vec<&'static str> {
+    trove_classifiers::search(query)
+        .map(|classifier| classifier.as_str())
+        .collect()
+}
+
+/// Return the classifiers starting with a prefix, ignoring ASCII case.
+#[pyfunction]
+#[pyo3(signature = (prefix, limit=None))]
+fn complete(prefix: &str, limit: Option<usize>) -> Vec<&'static str> {
+    trove_classifiers::complete(prefix)
+        .take(limit.unwrap_or(usize::MAX))
+        .map(|classifier| classifier.as_str())
+        .collect()
+}
+
+/// Return the segments of a classifier, raising ValueError if it is unknown.
+#[pyfunction]
+fn segments(classifier: &str) -> PyResult<Vec<&'static str>> {
+    Classifier::from_str(classifier)
+        .map(|classifier| classifier.segments_static().to_vec())
+        .map_err(|_| PyValueError::new_err(format!("unknown classifier: {classifier:?}")))
+}
+
+/// Return every known classifier, in canonical order.
+#[pyfunction]
+fn all_classifiers() -> Vec<&'static str> {
+    Classifier::ALL
+        .iter()
+        .map(|classifier| classifier.as_str())
+        .collect()
+}
+
+/// Suggest classifiers from a project's other metadata, as (classifier, reason) pairs.
+///
+/// Classifiers the project already declares are never suggested. They are read leniently and
+/// deprecated ones are skipped; an unknown one raises ValueError.
+#[pyfunction]
+#[pyo3(signature = (
+    *,
+    name=None,
+    version=None,
+    requires_python=None,
+    license=None,
+    dependencies=Vec::new(),
+    classifiers=Vec::new(),
+    typed=false,
+    keywords=Vec::new(),
+    description=None,
+))]
+#[allow(clippy::too_many_arguments)]
+fn suggest(
+    name: Option<String>,
+    version: Option<String>,
+    requires_python: Option<String>,
+    license: Option<String>,
+    dependencies: Vec<String>,
+    classifiers: Vec<String>,
+    typed: bool,
+    keywords: Vec<String>,
+    description: Option<String>,
+) -> PyResult<Vec<(&'static str, String)>> {
+    let project = Project {
+        name,
+        version,
+        requires_python,
+        license,
+        dependencies,
+        classifiers: classifiers
+            .iter()
+            // deprecated classifiers are declared, but no longer known
+            .filter(|classifier| deprecation(classifier.trim()).is_none())
+            .map(|classifier| {
+                Classifier::from_str_lenient(classifier).map_err(|_| {
+                    PyValueError::new_err(format!("unknown classifier: {classifier:?}"))
+                })
+            })
+            .collect::<PyResult<_>>()?,
+        typed,
+        keywords,
+        description,
+        ..Project::default()
+    };
+    Ok(trove_classifiers::suggest::suggest(&project)
+        .into_iter()
+        .map(|suggestion| {
+            (
+                suggestion.classifier.as_str(),
+                suggestion.reason.to_string(),
+            )
+        })
+        .collect())
+}
+
+/// Validate the Classifier headers of a METADATA or PKG-INFO document.
+///
+/// Returns the known classifiers and a list of (line, value) pairs for unknown ones.
+#[pyfunction]
+#[allow(clippy::type_complexity)]
+fn scan_metadata(metadata: &str) -> (Vec<&'static str>, Vec<(usize, String)>) {
+    let report = Scanner::new().scan_str(metadata);
+    (
+        report
+            .classifiers
+            .iter()
+            .map(|classifier| classifier.as_str())
+            .collect(),
+        report
+            .unknown
+            .into_iter()
+            .map(|unknown| (unknown.line, unknown.value))
+            .collect(),
+    )
+}
+
+#[pymodule]
+fn trove_classifiers_rs(m: &Bound<'_, PyModule>) -> PyResult<()> {
+    m.add("PYPA_VERSION", trove_classifiers::PYPA_VERSION)?;
+    m.add(
+        "DATASET_FINGERPRINT",
+        trove_classifiers::DATASET_FINGERPRINT,
+    )?;
+    m.add_function(wrap_pyfunction!(parse, m)?)?;
+    m.add_function(wrap_pyfunction!(is_valid, m)?)?;
+    m.add_function(wrap_pyfunction!(validate, m)?)?;
+    m.add_function(wrap_pyfunction!(search, m)?)?;
+    m.add_function(wrap_pyfunction!(complete, m)?)?;
+    m.add_function(wrap_pyfunction!(segments, m)?)?;
+    m.add_function(wrap_pyfunction!(all_classifiers, m)?)?;
+    m.add_function(wrap_pyfunction!(suggest, m)?)?;
+    m.add_function(wrap_pyfunction!(scan_metadata, m)?)?;
+    Ok(())
+}
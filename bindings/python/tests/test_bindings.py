@@ -0,0 +1,52 @@
+import pytest
+
+import trove_classifiers_rs as trove
+
+
+def test_parse():
+    assert trove.parse("Typing :: Typed") == "Typing :: Typed"
+    with pytest.raises(ValueError):
+        trove.parse("Typing :: Untyped")
+
+
+def test_validate():
+    assert trove.is_valid("Programming Language :: Rust")
+    assert trove.validate(["Typing :: Typed", "Typing :: Untyped"]) == ["Typing :: Untyped"]
+
+
+def test_search():
+    assert trove.search("typing TYPED") == ["Typing :: Typed"]
+
+
+def test_complete():
+    assert trove.complete("typing ::") == ["Typing :: Stubs Only", "Typing :: Typed"]
+    assert trove.complete("Typing ::", limit=1) == ["Typing :: Stubs Only"]
+
+
+def test_segments():
+    assert trove.segments("Typing :: Typed") == ["Typing", "Typed"]
+
+
+def test_all_classifiers():
+    everything = trove.all_classifiers()
+    assert everything[0] == "Development Status :: 1 - Planning"
+    assert len(everything) == len(set(everything))
+
+
+def test_suggest():
+    suggestions = trove.suggest(license="MIT", typed=True)
+    assert ("License :: OSI Approved :: MIT License", "matches the license") in suggestions
+    assert ("Typing :: Typed", "ships a py.typed marker") in suggestions
+    assert ("Typing :: Typed", "ships a py.typed marker") not in trove.suggest(
+        typed=True, classifiers=["Typing :: Typed"]
+    )
+    assert ("Typing :: Typed", "ships a py.typed marker") not in trove.suggest(
+        typed=True, classifiers=["typing :: typed", "Natural Language :: Ukranian"]
+    )
+    with pytest.raises(ValueError):
+        trove.suggest(classifiers=["Typing :: Untyped"])
+
+
+def test_scan_metadata():
+    metadata = "Name: example\nClassifier: Typing :: Typed\nClassifier: Typing :: Untyped\n\nbody\n"
+    assert trove.scan_metadata(metadata) == (["Typing :: Typed"], [(3, "Typing :: Untyped")])
@@ -0,0 +1,107 @@
+//! UniFFI bindings to trove-classifiers, from which Swift and Kotlin bindings are generated.
+//!
+//! The interface is defined in `src/trove_classifiers.udl`. After building the library, generate
+//! the bindings for a language with the bundled `uniffi-bindgen`:
+//!
+//! ```text
+//! cargo build --release -p trove-classifiers-uniffi
+//! cargo run -p trove-classifiers-uniffi --bin uniffi-bindgen -- generate \
+//!     --library target/release/libtrove_classifiers_uniffi.so --language swift --out-dir out
+//! ```
+
+// the scaffolding generated from the UDL file trips this lint
+#![allow(clippy::empty_line_after_doc_comments)]
+
+use std::fmt;
+use std::str::FromStr;
+
+use trove_classifiers::Classifier;
+
+uniffi::include_scaffolding!("trove_classifiers");
+
+/// The error raised when a string is not a known classifier
+#[derive(Debug, PartialEq)]
+pub enum ClassifierError {
+    Unknown,
+}
+
+impl fmt::Display for ClassifierError {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        match self {
+            ClassifierError::Unknown => f.write_str("unknown classifier"),
+        }
+    }
+}
+
+impl std::error::Error for ClassifierError {}
+
+impl From<trove_classifiers::ParseError> for ClassifierError {
+    fn from(_: trove_classifiers::ParseError) -> Self {
+        ClassifierError::Unknown
+    }
+}
+
+fn pypa_version() -> String {
+    trove_classifiers::PYPA_VERSION.to_string()
+}
+
+fn parse(classifier: &str) -> Result<String, ClassifierError> {
+    Ok(Classifier::from_str(classifier)?.as_str().to_string())
+}
+
+fn is_valid(classifier: &str) -> bool {
+    Classifier::from_str(classifier).is_ok()
+}
+
+fn validate(classifiers: Vec<String>) -> Vec<String> {
+    classifiers
+        .into_iter()
+        .filter(|classifier| Classifier::from_str(classifier).is_err())
+        .collect()
+}
+
+fn search(query: &str) -> Vec<String> {
+    strings(trove_classifiers::search(query))
+}
+
+fn complete(prefix: &str, limit: Option<u32>) -> Vec<String> {
+    let limit = limit.map_or(usize::MAX, |limit| limit as usize);
+    strings(trove_classifiers::complete(prefix).take(limit))
+}
+
+fn segments(classifier: &str) -> Result<Vec<String>, ClassifierError> {
+    let classifier = Classifier::from_str(classifier)?;
+    Ok(classifier
+        .segments_static()
+        .iter()
+        .map(|segment| segment.to_string())
+        .collect())
+}
+
+fn all_classifiers() -> Vec<String> {
+    strings(Classifier::ALL.iter().copied())
+}
+
+fn strings(classifiers: impl Iterator<Item = Classifier>) -> Vec<String> {
+    classifiers
+        .map(|classifier| classifier.as_str().to_string())
+        .collect()
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn unknown_classifiers_are_errors() {
+        assert_eq!(parse("Typing :: Typed").unwrap(), "Typing :: Typed");
+        assert_eq!(parse("Typing :: Untyped"), Err(ClassifierError::Unknown));
+        assert_eq!(segments("Typing :: Typed").unwrap(), ["Typing", "Typed"]);
+    }
+
+    #[test]
+    fn complete_respects_limit() {
+        assert_eq!(complete("typing ::", None).len(), 2);
+        assert_eq!(complete("typing ::", Some(1)), ["Typing :: Stubs Only"]);
+    }
+}
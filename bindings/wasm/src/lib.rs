@@ -0,0 +1,72 @@
+//! WebAssembly bindings to trove-classifiers, built with wasm-pack for browsers and Node.js.
+//!
+//! ```text
+//! wasm-pack build bindings/wasm --target web
+//! ```
+//!
+//! ```js
+//! import init, { parse, validate } from "./pkg/trove_classifiers_wasm.js";
+//!
+//! await init();
+//! parse("Typing :: Typed");                      // "Typing :: Typed"
+//! validate(["Typing :: Typed", "Typing :: Nope"]); // ["Typing :: Nope"]
+//! ```
+
+use core::str::FromStr;
+
+use trove_classifiers::Classifier;
+use wasm_bindgen::prelude::*;
+
+/// The version of the python package pypa/trove-classifiers that the dataset was taken from.
+#[wasm_bindgen(js_name = pypaVersion)]
+pub fn pypa_version() -> String {
+    trove_classifiers::PYPA_VERSION.to_string()
+}
+
+/// Return the canonical form of a classifier, throwing if it is unknown.
+#[wasm_bindgen]
+pub fn parse(classifier: &str) -> Result<String, JsError> {
+    Classifier::from_str(classifier)
+        .map(|classifier| classifier.as_str().to_string())
+        .map_err(|_| JsError::new(&format!("unknown classifier: {classifier:?}")))
+}
+
+/// Whether a string is a classifier known to pypi.org.
+#[wasm_bindgen(js_name = isValid)]
+pub fn is_valid(classifier: &str) -> bool {
+    Classifier::from_str(classifier).is_ok()
+}
+
+/// Return the classifiers of a list that are not known to pypi.org, in their original order.
+#[wasm_bindgen]
+pub fn validate(classifiers: Vec<String>) -> Vec<String> {
+    classifiers
+        .into_iter()
+        .filter(|classifier| Classifier::from_str(classifier).is_err())
+        .collect()
+}
+
+/// Return the classifiers matching every whitespace-separated term of a query, ignoring ASCII
+/// case. A term matches as a substring or as the acronym of consecutive words, such as `gpl`.
+#[wasm_bindgen]
+pub fn search(query: &str) -> Vec<String> {
+    strings(trove_classifiers::search(query))
+}
+
+/// Return the classifiers starting with a prefix, ignoring ASCII case.
+#[wasm_bindgen]
+pub fn complete(prefix: &str, limit: Option<usize>) -> Vec<String> {
+    strings(trove_classifiers::complete(prefix).take(limit.unwrap_or(usize::MAX)))
+}
+
+/// Return every known classifier, in canonical order.
+#[wasm_bindgen(js_name = allClassifiers)]
+pub fn all_classifiers() -> Vec<String> {
+    strings(Classifier::ALL.iter().copied())
+}
+
+fn strings(classifiers: impl Iterator<Item = Classifier>) -> Vec<String> {
+    classifiers
+        .map(|classifier| classifier.as_str().to_string())
+        .collect()
+}
@@ -0,0 +1,124 @@
+//! `trove badge`: README badges that match a project's declared classifiers.
+
+use std::io::Write;
+use std::path::PathBuf;
+
+use trove_classifiers::badge::{self, Badge, Kind};
+
+use crate::{project, Result};
+
+#[derive(clap::Args)]
+pub struct Args {
+    /// A pyproject.toml, setup.cfg, METADATA or PKG-INFO file
+    #[arg(default_value = "pyproject.toml")]
+    file: PathBuf,
+    /// Only print this badge
+    #[arg(long, value_enum)]
+    kind: Option<BadgeKind>,
+    /// How to print the badges
+    #[arg(long, value_enum, default_value_t)]
+    format: Format,
+}
+
+#[derive(Clone, Copy, Debug, Eq, PartialEq, clap::ValueEnum)]
+enum BadgeKind {
+    /// From the Development Status classifier
+    Status,
+    /// From the Programming Language :: Python :: X.Y classifiers
+    Python,
+}
+
+impl From<BadgeKind> for Kind {
+    fn from(kind: BadgeKind) -> Self {
+        match kind {
+            BadgeKind::Status => Kind::Status,
+            BadgeKind::Python => Kind::Python,
+        }
+    }
+}
+
+#[derive(Clone, Copy, Debug, Default, Eq, PartialEq, clap::ValueEnum)]
+enum Format {
+    /// Markdown images of shields.io badges
+    #[default]
+    Markdown,
+    /// shields.io badge URLs
+    Url,
+    /// A standalone SVG, which needs --kind
+    Svg,
+}
+
+pub fn run(args: &Args, out: &mut impl Write) -> Result<()> {
+    let report = project::classifiers(&args.file)?;
+    let badges: Vec<Badge> = badge::badges(&report.classifiers)
+        .into_iter()
+        .filter(|badge| args.kind.is_none_or(|kind| badge.kind == kind.into()))
+        .collect();
+    if badges.is_empty() {
+        return Err(format!(
+            "{} declares no Development Status or Python version classifiers to make a badge of",
+            args.file.display()
+        ));
+    }
+    if args.format == Format::Svg && args.kind.is_none() {
+        return Err("an SVG holds one badge, choose it with --kind".to_string());
+    }
+    for badge in badges {
+        let line = match args.format {
+            Format::Markdown => badge.markdown(),
+            Format::Url => badge.url(),
+            Format::Svg => badge.svg(),
+        };
+        writeln!(out, "{line}").map_err(|err| err.to_string())?;
+    }
+    Ok(())
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    fn badge(kind: Option<BadgeKind>, format: Format) -> Result<String> {
+        let path = std::env::temp_dir().join(format!(
+            "trove-badge-{}-{kind:?}-{format:?}-pyproject.toml",
+            std::process::id()
+        ));
+        std::fs::write(
+            &path,
+            "[project]\nclassifiers = [\n\
+             'Development Status :: 2 - Pre-Alpha',\n\
+             'Programming Language :: Python :: 3.10',\n\
+             'Programming Language :: Python :: 3.9',\n]\n",
+        )
+        .unwrap();
+        let args = Args {
+            file: path.clone(),
+            kind,
+            format,
+        };
+        let mut out = Vec::new();
+        let result = run(&args, &mut out);
+        std::fs::remove_file(&path).unwrap();
+        result.map(|()| String::from_utf8(out).unwrap())
+    }
+
+    #[test]
+    fn prints_markdown_badges() {
+        assert_eq!(
+            badge(None, Format::Markdown).unwrap(),
+            "![status](https://img.shields.io/badge/status-pre--alpha-red)\n\
+             ![python](https://img.shields.io/badge/python-3.9%20%7C%203.10-blue)\n"
+        );
+        assert_eq!(
+            badge(Some(BadgeKind::Python), Format::Url).unwrap(),
+            "https://img.shields.io/badge/python-3.9%20%7C%203.10-blue\n"
+        );
+    }
+
+    #[test]
+    fn svgs_need_a_kind() {
+        assert!(badge(None, Format::Svg).is_err());
+        let svg = badge(Some(BadgeKind::Status), Format::Svg).unwrap();
+        assert!(svg.contains("<title>status: pre-alpha</title>"));
+    }
+}
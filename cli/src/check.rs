@@ -0,0 +1,549 @@
+//! `trove check`: check the classifiers declared by project files against pypi.org's rules.
+
+use std::fs;
+use std::io::{self, Read, Write};
+use std::path::{Path, PathBuf};
+
+use serde_json::json;
+use trove_classifiers::baseline::Baseline;
+use trove_classifiers::lint::{self, Declared, Level, Linter, Rule};
+use trove_classifiers::policy::Policy;
+use trove_classifiers::pyproject;
+use trove_classifiers::scanner::Scanner;
+use trove_classifiers::setup_cfg;
+
+use crate::findings::{self, Format};
+use crate::Result;
+
+/// Exit code of a check that found an issue pypi.org would reject
+pub const EXIT_FINDINGS: u8 = 1;
+/// Exit code of a check that could not read or parse a file
+pub const EXIT_PARSE: u8 = 2;
+/// Exit code of a check that failed for any other reason, such as its output being closed or
+/// its arguments being invalid
+pub const EXIT_INTERNAL: u8 = 3;
+
+#[derive(clap::Args)]
+#[command(after_help = "\
+Exit codes, which are stable:
+  0  every file passed, though there may be warnings
+  1  a file declares a classifier pypi.org would reject
+  2  a file could not be read or parsed
+  3  an internal error, or invalid arguments")]
+pub struct Args {
+    /// pyproject.toml, setup.cfg, METADATA or PKG-INFO files to check, or - to read one from
+    /// standard input
+    #[arg(default_value = "pyproject.toml")]
+    files: Vec<PathBuf>,
+    /// How to print the findings
+    #[arg(long, value_enum, default_value_t)]
+    format: Format,
+    /// Print nothing when every file passes, as pre-commit hooks and CI gates expect
+    #[arg(short, long)]
+    quiet: bool,
+    /// Only report findings that this baseline, written by --update-baseline, does not accept
+    #[arg(long, value_name = "FILE")]
+    baseline: Option<PathBuf>,
+    /// Accept every current finding by writing them all to the --baseline file
+    #[arg(long, requires = "baseline")]
+    update_baseline: bool,
+    /// Follow the policy of this trove.toml, or of the [tool.trove] table of this pyproject.toml,
+    /// rather than of the trove.toml or pyproject.toml of the current directory
+    #[arg(long, value_name = "FILE")]
+    config: Option<PathBuf>,
+    /// Ignore the findings of a rule, given by its code or name
+    #[arg(short = 'A', long, value_name = "RULE", value_parser = crate::lint::rule)]
+    allow: Vec<Rule>,
+    /// Report the findings of a rule as warnings
+    #[arg(short = 'W', long, value_name = "RULE", value_parser = crate::lint::rule)]
+    warn: Vec<Rule>,
+    /// Report the findings of a rule as errors; a rule given to several levels gets the strictest
+    #[arg(short = 'D', long, value_name = "RULE", value_parser = crate::lint::rule)]
+    deny: Vec<Rule>,
+}
+
+impl Args {
+    /// The linter of the built-in rules at the levels of the policy, overridden by those given
+    /// on the command line
+    fn linter(&self, policy: &Policy) -> Linter {
+        let levels = [
+            (&self.allow, Level::Allow),
+            (&self.warn, Level::Warn),
+            (&self.deny, Level::Deny),
+        ];
+        levels
+            .into_iter()
+            .flat_map(|(rules, level)| rules.iter().map(move |rule| (*rule, level)))
+            .fold(policy.linter(), |linter, (rule, level)| {
+                linter.level(rule, level)
+            })
+    }
+}
+
+/// Why a check failed
+#[derive(Debug)]
+pub struct Failure {
+    /// One of the `EXIT_*` codes
+    pub code: u8,
+    pub message: String,
+}
+
+impl Failure {
+    fn internal(err: impl ToString) -> Self {
+        Failure {
+            code: EXIT_INTERNAL,
+            message: err.to_string(),
+        }
+    }
+}
+
+pub fn run(args: &Args, out: &mut impl Write) -> std::result::Result<(), Failure> {
+    if !args.quiet {
+        return check(args, out);
+    }
+    let mut buffer = Vec::new();
+    let result = check(args, &mut buffer);
+    if result.is_err() {
+        out.write_all(&buffer).map_err(Failure::internal)?;
+    }
+    result
+}
+
+fn check(args: &Args, out: &mut impl Write) -> std::result::Result<(), Failure> {
+    let baseline = match &args.baseline {
+        Some(path) if !args.update_baseline => Some(read_baseline(path)?),
+        _ => None,
+    };
+    let policy = crate::policy::load(args.config.as_deref()).map_err(|message| Failure {
+        code: EXIT_PARSE,
+        message,
+    })?;
+    let linter = args.linter(&policy);
+    let mut recorded = Vec::new();
+    let mut files = Vec::new();
+    let (mut failed, mut unreadable) = (0, 0);
+    for path in &args.files {
+        let location = path.display().to_string();
+        match declared(path) {
+            Ok(declared) => {
+                let mut findings = linter.check(&policy.context(&declared));
+                if args.update_baseline {
+                    recorded.push((location.clone(), findings));
+                    findings = Vec::new();
+                } else if let Some(baseline) = &baseline {
+                    findings = baseline.new_findings(&location, &findings);
+                }
+                if !lint::passes(&findings) {
+                    failed += 1;
+                }
+                files.push((location, findings));
+            }
+            Err(err) if args.format == Format::Jsonl => {
+                let record = json!({ "file": location, "error": err });
+                writeln!(out, "{record}").map_err(Failure::internal)?;
+                unreadable += 1;
+            }
+            Err(err) => {
+                eprintln!("error: {err}");
+                unreadable += 1;
+            }
+        }
+        if args.format.streams() {
+            findings::report(out, args.format, &files).map_err(Failure::internal)?;
+            files.clear();
+        }
+    }
+    findings::report(out, args.format, &files).map_err(Failure::internal)?;
+    if let (true, Some(path)) = (args.update_baseline, &args.baseline) {
+        let files = recorded
+            .iter()
+            .map(|(location, findings)| (location.as_str(), findings.as_slice()));
+        let baseline = Baseline::from_findings(files);
+        fs::write(path, baseline.to_json())
+            .map_err(|err| Failure::internal(format!("writing {}: {err}", path.display())))?;
+        if !args.quiet {
+            eprintln!(
+                "recorded {} in {}",
+                findings::plural(baseline.entries.len(), "finding"),
+                path.display()
+            );
+        }
+    }
+    out.flush().map_err(Failure::internal)?;
+    let code = match (unreadable, failed) {
+        (0, 0) => return Ok(()),
+        (0, _) => EXIT_FINDINGS,
+        _ => EXIT_PARSE,
+    };
+    Err(Failure {
+        code,
+        message: format!(
+            "{} of {} files failed the checks",
+            failed + unreadable,
+            args.files.len()
+        ),
+    })
+}
+
+fn read_baseline(path: &Path) -> std::result::Result<Baseline, Failure> {
+    let parse_error = |message: String| Failure {
+        code: EXIT_PARSE,
+        message,
+    };
+    let source = fs::read_to_string(path)
+        .map_err(|err| parse_error(format!("reading {}: {err}", path.display())))?;
+    Baseline::from_json(&source).map_err(|err| parse_error(format!("{}: {err}", path.display())))
+}
+
+/// The path standing for standard input
+pub const STDIN: &str = "-";
+
+/// The kinds of file that declare classifiers
+#[derive(Clone, Copy, Debug, Eq, PartialEq)]
+enum Kind {
+    Pyproject,
+    SetupCfg,
+    Metadata,
+}
+
+impl Kind {
+    /// The kind of a file, from its extension
+    fn of(path: &Path) -> Self {
+        match path.extension().and_then(|extension| extension.to_str()) {
+            Some("toml") => Kind::Pyproject,
+            Some("cfg") => Kind::SetupCfg,
+            _ => Kind::Metadata,
+        }
+    }
+
+    /// The kind of a file without a name, from its first line: a METADATA header, or a section
+    /// of a `setup.cfg` or `pyproject.toml` depending on whether any section is `[metadata]`
+    fn sniff(source: &str) -> Self {
+        let mut lines = source
+            .lines()
+            .map(str::trim)
+            .filter(|line| !line.is_empty() && !line.starts_with(['#', ';']));
+        match lines.next() {
+            Some(line) if line.starts_with('[') => {
+                let mut sections =
+                    std::iter::once(line).chain(lines.filter(|l| l.starts_with('[')));
+                if sections.any(|section| section == "[metadata]") {
+                    Kind::SetupCfg
+                } else {
+                    Kind::Pyproject
+                }
+            }
+            _ => Kind::Metadata,
+        }
+    }
+}
+
+/// The classifiers declared by a `pyproject.toml` or `setup.cfg`, or by a METADATA or PKG-INFO
+/// file; a path of `-` reads standard input, telling these apart by their contents
+pub fn declared(path: &Path) -> Result<Vec<Declared>> {
+    let (kind, source) = if path == Path::new(STDIN) {
+        let mut source = String::new();
+        io::stdin()
+            .read_to_string(&mut source)
+            .map_err(|err| format!("reading standard input: {err}"))?;
+        (Kind::sniff(&source), source)
+    } else {
+        let source =
+            fs::read_to_string(path).map_err(|err| format!("reading {}: {err}", path.display()))?;
+        (Kind::of(path), source)
+    };
+    match kind {
+        Kind::Pyproject => {
+            let array = pyproject::classifiers(&source)
+                .map_err(|err| format!("{}: {err}", path.display()))?;
+            Ok(array.map_or_else(Vec::new, |array| {
+                array.entries.iter().map(|entry| entry.declared()).collect()
+            }))
+        }
+        Kind::SetupCfg => Ok(setup_cfg::declared(&source)),
+        Kind::Metadata => Ok(Scanner::new().declared_str(&source)),
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    fn check_quietly(
+        name: &str,
+        contents: &str,
+        format: Format,
+        quiet: bool,
+    ) -> (std::result::Result<(), Failure>, String) {
+        let path = std::env::temp_dir().join(format!("trove-check-{}-{name}", std::process::id()));
+        fs::write(&path, contents).unwrap();
+        let args = Args {
+            files: vec![path.clone()],
+            format,
+            quiet,
+            baseline: None,
+            update_baseline: false,
+            config: None,
+            allow: Vec::new(),
+            warn: Vec::new(),
+            deny: Vec::new(),
+        };
+        let mut out = Vec::new();
+        let result = run(&args, &mut out);
+        fs::remove_file(&path).unwrap();
+        let out = String::from_utf8(out).unwrap();
+        (result, out.replace(&path.display().to_string(), name))
+    }
+
+    fn check(
+        name: &str,
+        contents: &str,
+        format: Format,
+    ) -> (std::result::Result<(), Failure>, String) {
+        check_quietly(name, contents, format, false)
+    }
+
+    #[test]
+    fn valid_files_print_nothing() {
+        let (result, out) = check(
+            "pyproject.toml",
+            "[project]\nclassifiers = ['Typing :: Typed']\n",
+            Format::Text,
+        );
+        result.unwrap();
+        assert_eq!(out, "");
+    }
+
+    #[test]
+    fn prints_findings_with_lines() {
+        let (result, out) = check(
+            "PKG-INFO",
+            "Name: example\nClassifier: Typing :: Typed\nClassifier: Typing :: Typed\n",
+            Format::Text,
+        );
+        result.unwrap();
+        assert_eq!(
+            out,
+            "PKG-INFO:3: warning[TC005] \"Typing :: Typed\" is already declared\n"
+        );
+
+        let (result, out) = check(
+            "pyproject.toml",
+            "[project]\nclassifiers = [\n  'Typing :: Sometimes',\n]\n",
+            Format::Text,
+        );
+        let failure = result.unwrap_err();
+        assert_eq!(failure.code, EXIT_FINDINGS);
+        assert_eq!(failure.message, "1 of 1 files failed the checks");
+        assert_eq!(
+            out,
+            "pyproject.toml:3: error[TC001] \"Typing :: Sometimes\" is not a known classifier\n"
+        );
+    }
+
+    #[test]
+    fn quiet_checks_only_print_failures() {
+        let warning = "Name: example\nClassifier: Typing :: Typed\nClassifier: Typing :: Typed\n";
+        let (result, out) = check_quietly("PKG-INFO", warning, Format::Text, true);
+        result.unwrap();
+        assert_eq!(out, "");
+
+        let error = "Name: example\nClassifier: Typing :: Sometimes\n";
+        let (result, out) = check_quietly("PKG-INFO", error, Format::Text, true);
+        assert_eq!(result.unwrap_err().code, EXIT_FINDINGS);
+        assert!(out.starts_with("PKG-INFO:2: error[TC001]"));
+    }
+
+    #[test]
+    fn rule_levels_are_set_per_rule() {
+        let path = std::env::temp_dir().join(format!("trove-check-{}-levels", std::process::id()));
+        let contents = "Name: example\nClassifier: Typing :: Sometimes\n\
+                        Classifier: Typing :: Typed\nClassifier: typing :: typed\n";
+        fs::write(&path, contents).unwrap();
+        let mut args = Args {
+            files: vec![path.clone()],
+            format: Format::Text,
+            quiet: false,
+            baseline: None,
+            update_baseline: false,
+            config: None,
+            allow: vec![Rule::Unknown],
+            warn: vec![Rule::NonCanonical],
+            deny: Vec::new(),
+        };
+        let mut out = Vec::new();
+        run(&args, &mut out).unwrap();
+        let out = String::from_utf8(out).unwrap();
+        assert!(out
+            .ends_with("warning[TC003] \"typing :: typed\" must be written \"Typing :: Typed\"\n"));
+        assert_eq!(out.lines().count(), 1);
+
+        args.deny = vec![Rule::NonCanonical];
+        let result = run(&args, &mut Vec::new());
+        fs::remove_file(&path).unwrap();
+        assert_eq!(result.unwrap_err().code, EXIT_FINDINGS);
+        assert_eq!(
+            crate::lint::rule("noncanonical-classifier").unwrap(),
+            Rule::NonCanonical
+        );
+    }
+
+    #[test]
+    fn policies_are_followed() {
+        let dir = std::env::temp_dir().join(format!("trove-check-policy-{}", std::process::id()));
+        fs::create_dir_all(&dir).unwrap();
+        let config = dir.join("trove.toml");
+        fs::write(
+            &config,
+            "required = ['Typing :: Typed']\n[levels]\nTC007 = 'warn'\n",
+        )
+        .unwrap();
+        let metadata = dir.join("PKG-INFO");
+        fs::write(
+            &metadata,
+            "Name: example\nClassifier: Typing :: Stubs Only\n",
+        )
+        .unwrap();
+        let mut args = Args {
+            files: vec![metadata.clone()],
+            format: Format::Text,
+            quiet: false,
+            baseline: None,
+            update_baseline: false,
+            config: Some(config.clone()),
+            allow: Vec::new(),
+            warn: Vec::new(),
+            deny: Vec::new(),
+        };
+        let mut out = Vec::new();
+        let result = run(&args, &mut out);
+        args.deny = vec![Rule::Required];
+        let denied = run(&args, &mut Vec::new());
+        fs::remove_dir_all(&dir).unwrap();
+        result.unwrap();
+        assert!(String::from_utf8(out).unwrap().ends_with(
+            "PKG-INFO: warning[TC007] \"Typing :: Typed\" is required, but not declared\n"
+        ));
+        assert_eq!(denied.unwrap_err().code, EXIT_FINDINGS);
+    }
+
+    #[test]
+    fn unparsable_files_exit_with_their_own_code() {
+        let (result, _) = check_quietly(
+            "pyproject.toml",
+            "[project]\nclassifiers = [\n",
+            Format::Text,
+            true,
+        );
+        assert_eq!(result.unwrap_err().code, EXIT_PARSE);
+    }
+
+    #[test]
+    fn baselines_accept_recorded_findings() {
+        let dir = std::env::temp_dir().join(format!("trove-check-baseline-{}", std::process::id()));
+        fs::create_dir_all(&dir).unwrap();
+        let metadata = dir.join("PKG-INFO");
+        let baseline = dir.join("baseline.json");
+        let check = |contents: &str, update_baseline: bool| {
+            fs::write(&metadata, contents).unwrap();
+            let args = Args {
+                files: vec![metadata.clone()],
+                format: Format::Text,
+                quiet: true,
+                baseline: Some(baseline.clone()),
+                update_baseline,
+                config: None,
+                allow: Vec::new(),
+                warn: Vec::new(),
+                deny: Vec::new(),
+            };
+            let mut out = Vec::new();
+            let result = run(&args, &mut out);
+            (result, String::from_utf8(out).unwrap())
+        };
+
+        let missing = check("Classifier: Typing :: Sometimes\n", false);
+        check("Classifier: Typing :: Sometimes\n", true).0.unwrap();
+        let recorded = fs::read_to_string(&baseline).unwrap();
+        let accepted = check("Name: moved\nClassifier: Typing :: Sometimes\n", false);
+        let new = check(
+            "Classifier: Typing :: Sometimes\nClassifier: Typing :: Often\n",
+            false,
+        );
+        fs::remove_dir_all(&dir).unwrap();
+
+        assert_eq!(missing.0.unwrap_err().code, EXIT_PARSE);
+        assert!(recorded.contains(r#""rule": "TC001", "value": "Typing :: Sometimes""#));
+        accepted.0.unwrap();
+        assert_eq!(new.0.unwrap_err().code, EXIT_FINDINGS);
+        assert!(new
+            .1
+            .ends_with(":2: error[TC001] \"Typing :: Often\" is not a known classifier\n"));
+    }
+
+    #[test]
+    fn standard_input_is_sniffed() {
+        assert_eq!(
+            Kind::sniff("# generated\n[build-system]\n[project]\n"),
+            Kind::Pyproject
+        );
+        assert_eq!(
+            Kind::sniff("[options]\npackages = find:\n\n[metadata]\n"),
+            Kind::SetupCfg
+        );
+        assert_eq!(
+            Kind::sniff("Metadata-Version: 2.4\n\n[link](url)\n"),
+            Kind::Metadata
+        );
+        assert_eq!(Kind::sniff(""), Kind::Metadata);
+    }
+
+    #[test]
+    fn reads_setup_cfg() {
+        let (result, out) = check(
+            "setup.cfg",
+            "[metadata]\nclassifiers =\n    Typing :: Typed\n    Typing :: Sometimes\n",
+            Format::Text,
+        );
+        assert!(result.is_err());
+        assert_eq!(
+            out,
+            "setup.cfg:4: error[TC001] \"Typing :: Sometimes\" is not a known classifier\n"
+        );
+    }
+
+    #[test]
+    fn prints_json_lines() {
+        let (result, out) = check(
+            "pyproject.toml",
+            "[project]\nclassifiers = ['Typing :: Typed']\n",
+            Format::Jsonl,
+        );
+        result.unwrap();
+        assert_eq!(
+            out,
+            "{\"file\":\"pyproject.toml\",\"findings\":[],\"passed\":true}\n"
+        );
+    }
+
+    #[test]
+    fn prints_sarif() {
+        let (result, out) = check(
+            "pyproject.toml",
+            "[project]\nclassifiers = [\n  'Typing :: Sometimes',\n]\n",
+            Format::Sarif,
+        );
+        assert!(result.is_err());
+        let log: serde_json::Value = serde_json::from_str(&out).unwrap();
+        let result = &log["runs"][0]["results"][0];
+        assert_eq!(result["ruleId"], "TC001");
+        assert_eq!(
+            result["locations"][0]["physicalLocation"]["artifactLocation"]["uri"],
+            "pyproject.toml"
+        );
+        assert_eq!(
+            result["locations"][0]["physicalLocation"]["region"]["startLine"],
+            3
+        );
+    }
+}
@@ -0,0 +1,276 @@
+//! `trove check-dist`: check the classifiers of built wheels and sdists before they are uploaded.
+
+use std::fs::File;
+use std::io::{BufReader, Read, Write};
+use std::path::{Path, PathBuf};
+
+use flate2::read::GzDecoder;
+use serde_json::json;
+use trove_classifiers::lint::{self, Finding};
+use trove_classifiers::scanner::Scanner;
+
+use crate::{findings, Result};
+
+#[derive(clap::Args)]
+pub struct Args {
+    /// Wheels (.whl) and sdists (.tar.gz or .zip) to check
+    #[arg(required = true)]
+    artifacts: Vec<PathBuf>,
+    /// How to print the results
+    #[arg(long, value_enum, default_value_t)]
+    format: Format,
+}
+
+/// How the results of each artifact are printed
+#[derive(Clone, Copy, Debug, Default, Eq, PartialEq, clap::ValueEnum)]
+enum Format {
+    /// One line per finding, then a line of counts
+    #[default]
+    Text,
+    /// One JSON object per artifact, for jq and data pipelines
+    Jsonl,
+}
+
+/// The metadata of an artifact and its findings
+struct Checked {
+    /// The metadata file within the artifact
+    member: String,
+    classifiers: usize,
+    findings: Vec<Finding>,
+}
+
+pub fn run(args: &Args, out: &mut impl Write) -> Result<()> {
+    let mut failed = 0;
+    for artifact in &args.artifacts {
+        let checked = check(artifact);
+        if checked
+            .as_ref()
+            .map_or(true, |checked| !lint::passes(&checked.findings))
+        {
+            failed += 1;
+        }
+        match args.format {
+            Format::Text => text(out, artifact, &checked),
+            Format::Jsonl => jsonl(out, artifact, &checked),
+        }
+        .map_err(|err| err.to_string())?;
+    }
+    match failed {
+        0 => Ok(()),
+        failed => Err(format!(
+            "{failed} of {} artifacts failed the checks",
+            args.artifacts.len()
+        )),
+    }
+}
+
+fn text(out: &mut impl Write, artifact: &Path, checked: &Result<Checked>) -> std::io::Result<()> {
+    let location = artifact.display();
+    match checked {
+        Ok(checked) => {
+            let location = format!("{location}/{}", checked.member);
+            for finding in &checked.findings {
+                findings::write(out, &location, finding)?;
+            }
+            writeln!(
+                out,
+                "{}: {}, {}",
+                artifact.display(),
+                findings::plural(checked.classifiers, "classifier"),
+                findings::counts(&checked.findings)
+            )
+        }
+        Err(err) => writeln!(out, "{location}: {err}"),
+    }
+}
+
+fn jsonl(out: &mut impl Write, artifact: &Path, checked: &Result<Checked>) -> std::io::Result<()> {
+    let artifact = artifact.display().to_string();
+    let record = match checked {
+        Ok(checked) => json!({
+            "artifact": artifact,
+            "member": checked.member,
+            "classifiers": checked.classifiers,
+            "passed": lint::passes(&checked.findings),
+            "findings": findings::to_json(&checked.findings),
+        }),
+        Err(err) => json!({ "artifact": artifact, "error": err }),
+    };
+    writeln!(out, "{record}")
+}
+
+fn check(artifact: &Path) -> Result<Checked> {
+    let (member, metadata) = metadata(artifact)?;
+    let declared = Scanner::new().declared_str(&metadata);
+    Ok(Checked {
+        member,
+        classifiers: declared.len(),
+        findings: lint::lint(&declared),
+    })
+}
+
+/// The name and contents of the core metadata file of a wheel or sdist
+pub(crate) fn metadata(artifact: &Path) -> Result<(String, String)> {
+    let name = artifact
+        .file_name()
+        .and_then(|name| name.to_str())
+        .unwrap_or_default();
+    let open = || File::open(artifact).map_err(|err| format!("cannot open: {err}"));
+    if name.ends_with(".whl") {
+        from_zip(open()?, |member| {
+            member
+                .split_once('/')
+                .is_some_and(|(dir, file)| dir.ends_with(".dist-info") && file == "METADATA")
+        })
+    } else if name.ends_with(".zip") {
+        from_zip(open()?, is_pkg_info)
+    } else if name.ends_with(".tar.gz") {
+        from_tar(GzDecoder::new(BufReader::new(open()?)))
+    } else {
+        Err("not a wheel (.whl) or an sdist (.tar.gz or .zip)".to_string())
+    }
+}
+
+/// Whether an archive member is the `PKG-INFO` at the root of an sdist
+fn is_pkg_info(member: &str) -> bool {
+    member
+        .split_once('/')
+        .is_some_and(|(_, file)| file == "PKG-INFO")
+}
+
+fn from_zip(file: File, wanted: impl Fn(&str) -> bool) -> Result<(String, String)> {
+    let mut archive =
+        zip::ZipArchive::new(BufReader::new(file)).map_err(|err| format!("not a zip: {err}"))?;
+    let member = archive
+        .file_names()
+        .find(|member| wanted(member))
+        .map(str::to_string)
+        .ok_or("no core metadata file found")?;
+    let mut contents = String::new();
+    archive
+        .by_name(&member)
+        .map_err(|err| err.to_string())?
+        .read_to_string(&mut contents)
+        .map_err(|err| format!("reading {member}: {err}"))?;
+    Ok((member, contents))
+}
+
+fn from_tar(reader: impl Read) -> Result<(String, String)> {
+    let mut archive = tar::Archive::new(reader);
+    for entry in archive.entries().map_err(|err| err.to_string())? {
+        let mut entry = entry.map_err(|err| format!("not a tar.gz: {err}"))?;
+        let member = entry
+            .path()
+            .map_err(|err| err.to_string())?
+            .to_string_lossy()
+            .into_owned();
+        if is_pkg_info(&member) {
+            let mut contents = String::new();
+            entry
+                .read_to_string(&mut contents)
+                .map_err(|err| format!("reading {member}: {err}"))?;
+            return Ok((member, contents));
+        }
+    }
+    Err("no core metadata file found".to_string())
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    const METADATA: &str = "Metadata-Version: 2.1\nName: example\nVersion: 1.0\n\
+        Classifier: Typing :: Typed\nClassifier: Natural Language :: Ukranian\n\n";
+
+    fn wheel(dir: &Path) -> PathBuf {
+        let path = dir.join("example-1.0-py3-none-any.whl");
+        let mut zip = zip::ZipWriter::new(File::create(&path).unwrap());
+        let options = zip::write::SimpleFileOptions::default()
+            .compression_method(zip::CompressionMethod::Stored);
+        zip.start_file("example/__init__.py", options).unwrap();
+        zip.start_file("example-1.0.dist-info/METADATA", options)
+            .unwrap();
+        zip.write_all(METADATA.as_bytes()).unwrap();
+        zip.finish().unwrap();
+        path
+    }
+
+    fn sdist(dir: &Path) -> PathBuf {
+        let path = dir.join("example-1.0.tar.gz");
+        let gz = flate2::write::GzEncoder::new(
+            File::create(&path).unwrap(),
+            flate2::Compression::default(),
+        );
+        let mut tar = tar::Builder::new(gz);
+        let metadata = "Metadata-Version: 2.1\nName: example\nClassifier: Typing :: Typed\n";
+        let mut header = tar::Header::new_gnu();
+        header.set_size(metadata.len() as u64);
+        header.set_cksum();
+        tar.append_data(&mut header, "example-1.0/PKG-INFO", metadata.as_bytes())
+            .unwrap();
+        tar.into_inner().unwrap().finish().unwrap();
+        path
+    }
+
+    #[test]
+    fn checks_wheels_and_sdists() {
+        let dir = std::env::temp_dir().join(format!("trove-check-dist-{}", std::process::id()));
+        std::fs::create_dir_all(&dir).unwrap();
+        let args = Args {
+            artifacts: vec![wheel(&dir), sdist(&dir), dir.join("README.md")],
+            format: Format::Text,
+        };
+        let mut out = Vec::new();
+        let result = run(&args, &mut out);
+        let out = String::from_utf8(out).unwrap();
+        std::fs::remove_dir_all(&dir).unwrap();
+
+        assert_eq!(result.unwrap_err(), "2 of 3 artifacts failed the checks");
+        let wheel = dir.join("example-1.0-py3-none-any.whl");
+        let sdist = dir.join("example-1.0.tar.gz");
+        let readme = dir.join("README.md");
+        assert_eq!(
+            out,
+            format!(
+                "{wheel}/example-1.0.dist-info/METADATA:5: error[TC002] \"Natural Language :: \
+                 Ukranian\" is deprecated, use \"Natural Language :: Ukrainian\" instead\n\
+                 {wheel}: 2 classifiers, 1 error, 0 warnings\n\
+                 {sdist}: 1 classifier, 0 errors, 0 warnings\n\
+                 {readme}: not a wheel (.whl) or an sdist (.tar.gz or .zip)\n",
+                wheel = wheel.display(),
+                sdist = sdist.display(),
+                readme = readme.display(),
+            )
+        );
+    }
+
+    #[test]
+    fn prints_json_lines() {
+        let dir =
+            std::env::temp_dir().join(format!("trove-check-dist-jsonl-{}", std::process::id()));
+        std::fs::create_dir_all(&dir).unwrap();
+        let args = Args {
+            artifacts: vec![wheel(&dir), dir.join("README.md")],
+            format: Format::Jsonl,
+        };
+        let mut out = Vec::new();
+        let result = run(&args, &mut out);
+        std::fs::remove_dir_all(&dir).unwrap();
+
+        assert_eq!(result.unwrap_err(), "2 of 2 artifacts failed the checks");
+        let records: Vec<serde_json::Value> = String::from_utf8(out)
+            .unwrap()
+            .lines()
+            .map(|line| serde_json::from_str(line).unwrap())
+            .collect();
+        assert_eq!(records.len(), 2);
+        assert_eq!(records[0]["member"], "example-1.0.dist-info/METADATA");
+        assert_eq!(records[0]["classifiers"], 2);
+        assert_eq!(records[0]["passed"], false);
+        assert_eq!(records[0]["findings"][0]["rule"], "TC002");
+        assert_eq!(
+            records[1]["error"],
+            "not a wheel (.whl) or an sdist (.tar.gz or .zip)"
+        );
+    }
+}
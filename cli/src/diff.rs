@@ -0,0 +1,197 @@
+//! `trove diff`: compare the classifiers of two dataset versions.
+
+use std::fs;
+use std::io::Write;
+use std::path::Path;
+
+use serde_json::json;
+use trove_classifiers::{history, PYPA_VERSION};
+
+use crate::Result;
+
+#[derive(clap::Args)]
+pub struct Args {
+    /// The older dataset: a version captured by trove-classifiers or a file of classifiers, one
+    /// per line [default: the current version]
+    old: Option<String>,
+    /// The newer dataset, like OLD [default: the current version]
+    #[arg(conflicts_with = "live")]
+    new: Option<String>,
+    /// Compare OLD against the classifiers pypi.org accepts right now
+    #[cfg(feature = "online")]
+    #[arg(long)]
+    live: bool,
+    /// Reuse the live classifiers fetched within this many seconds, from the user's cache
+    /// directory, and revalidate older ones with pypi.org
+    #[cfg(feature = "online")]
+    #[arg(
+        long,
+        value_name = "SECONDS",
+        default_value_t = 86400,
+        requires = "live"
+    )]
+    cache_ttl: u64,
+    /// Neither read nor write the cache of live classifiers
+    #[cfg(feature = "online")]
+    #[arg(long, requires = "live")]
+    no_cache: bool,
+    /// Print the added and removed classifiers as a JSON object
+    #[arg(long)]
+    json: bool,
+}
+
+/// The classifiers of one side of the comparison
+struct Dataset {
+    name: String,
+    classifiers: Vec<String>,
+}
+
+impl Dataset {
+    /// A captured version, or else a file listing classifiers
+    fn load(source: &str) -> Result<Self> {
+        if let Some(snapshot) = history::snapshot(source) {
+            return Ok(Dataset {
+                name: source.to_string(),
+                classifiers: snapshot.classifiers().map(str::to_string).collect(),
+            });
+        }
+        let path = Path::new(source);
+        if !path.is_file() {
+            let versions: Vec<&str> = history::snapshots().map(|s| s.version()).collect();
+            return Err(format!(
+                "{source} is neither a file nor a version captured by trove-classifiers (captured: {})",
+                versions.join(", ")
+            ));
+        }
+        let contents =
+            fs::read_to_string(path).map_err(|err| format!("reading {}: {err}", path.display()))?;
+        Ok(Dataset {
+            name: source.to_string(),
+            classifiers: contents
+                .lines()
+                .map(str::trim)
+                .filter(|line| !line.is_empty() && !line.starts_with('#'))
+                .map(str::to_string)
+                .collect(),
+        })
+    }
+
+    #[cfg(feature = "online")]
+    fn live(args: &Args) -> Result<Self> {
+        use trove_classifiers::online::{Cache, Client};
+
+        let mut client = Client::new();
+        if let Some(cache) = Cache::user().filter(|_| !args.no_cache) {
+            client = client.cache(cache.ttl(std::time::Duration::from_secs(args.cache_ttl)));
+        }
+        let classifiers = client.classifiers().map_err(|err| err.to_string())?;
+        Ok(Dataset {
+            name: client.classifiers_url(),
+            classifiers,
+        })
+    }
+}
+
+pub fn run(args: &Args, out: &mut impl Write) -> Result<()> {
+    let old = Dataset::load(args.old.as_deref().unwrap_or(PYPA_VERSION))?;
+    let new = newer(args)?;
+    let diff = history::diff(
+        old.classifiers.iter().map(String::as_str),
+        new.classifiers.iter().map(String::as_str),
+    );
+    if args.json {
+        let diff = json!({
+            "old": old.name,
+            "new": new.name,
+            "added": diff.added,
+            "removed": diff.removed,
+        });
+        serde_json::to_writer_pretty(&mut *out, &diff).map_err(|err| err.to_string())?;
+        writeln!(out).map_err(|err| err.to_string())?;
+    } else {
+        for removed in &diff.removed {
+            writeln!(out, "- {removed}").map_err(|err| err.to_string())?;
+        }
+        for added in &diff.added {
+            writeln!(out, "+ {added}").map_err(|err| err.to_string())?;
+        }
+    }
+    Ok(())
+}
+
+fn newer(args: &Args) -> Result<Dataset> {
+    #[cfg(feature = "online")]
+    if args.live {
+        return Dataset::live(args);
+    }
+    Dataset::load(args.new.as_deref().unwrap_or(PYPA_VERSION))
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    fn diff(old: &str, new: Option<&str>, json: bool) -> Result<String> {
+        let args = Args {
+            old: Some(old.to_string()),
+            new: new.map(str::to_string),
+            #[cfg(feature = "online")]
+            live: false,
+            #[cfg(feature = "online")]
+            cache_ttl: 0,
+            #[cfg(feature = "online")]
+            no_cache: false,
+            json,
+        };
+        let mut out = Vec::new();
+        run(&args, &mut out)?;
+        Ok(String::from_utf8(out).unwrap())
+    }
+
+    fn listing(name: &str, contents: &str) -> String {
+        let path = std::env::temp_dir().join(format!("trove-diff-{}-{name}", std::process::id()));
+        fs::write(&path, contents).unwrap();
+        path.to_str().unwrap().to_string()
+    }
+
+    #[test]
+    fn same_version_has_no_changes() {
+        assert_eq!(diff(PYPA_VERSION, None, false).unwrap(), "");
+    }
+
+    #[test]
+    fn compares_files_with_versions() {
+        let current = trove_classifiers::Classifier::ALL
+            .iter()
+            .filter(|classifier| classifier.as_str() != "Typing :: Stubs Only")
+            .map(|classifier| format!("{classifier}\n"))
+            .collect::<String>();
+        let old = listing(
+            "old",
+            &format!("# an older dataset\n{current}Typing :: Sometimes\n"),
+        );
+
+        assert_eq!(
+            diff(&old, Some(PYPA_VERSION), false).unwrap(),
+            "- Typing :: Sometimes\n+ Typing :: Stubs Only\n"
+        );
+        let printed: serde_json::Value =
+            serde_json::from_str(&diff(&old, None, true).unwrap()).unwrap();
+        fs::remove_file(&old).unwrap();
+        assert_eq!(
+            printed,
+            json!({
+                "old": old,
+                "new": PYPA_VERSION,
+                "added": ["Typing :: Stubs Only"],
+                "removed": ["Typing :: Sometimes"],
+            })
+        );
+    }
+
+    #[test]
+    fn unknown_versions_list_the_captured_ones() {
+        let err = diff("1999.1.1", None, false).unwrap_err();
+        assert!(err.contains(PYPA_VERSION), "{err}");
+    }
+}
@@ -0,0 +1,218 @@
+//! `trove doctor`: audit everything a project says about its classifiers in one report.
+
+use std::cmp::Reverse;
+use std::fs;
+use std::io::Write;
+use std::path::{Path, PathBuf};
+
+use serde_json::json;
+use trove_classifiers::doctor::{self, Check, Issue, Priority};
+use trove_classifiers::scanner::Scanner;
+
+use crate::{check, check_dist, findings, project, Result};
+
+/// README file names, in the order they are looked for
+#[derive(clap::Args)]
+pub struct Args {
+    /// The project directory, holding its pyproject.toml, README and any dist/ artifacts
+    #[arg(default_value = ".")]
+    project: PathBuf,
+    /// Print the issues as a JSON array
+    #[arg(long)]
+    json: bool,
+}
+
+pub fn run(args: &Args, out: &mut impl Write) -> Result<()> {
+    let issues = audit(&args.project)?;
+    if args.json {
+        let issues: Vec<_> = issues
+            .iter()
+            .map(|(file, issue)| {
+                json!({
+                    "priority": issue.priority.as_str(),
+                    "check": issue.check.as_str(),
+                    "file": file,
+                    "line": issue.line,
+                    "message": issue.message,
+                })
+            })
+            .collect();
+        serde_json::to_writer_pretty(&mut *out, &issues).map_err(|err| err.to_string())?;
+        writeln!(out).map_err(|err| err.to_string())?;
+    } else {
+        write(out, &issues).map_err(|err| err.to_string())?;
+    }
+    let high = count(&issues, Priority::High);
+    match high {
+        0 => Ok(()),
+        high => Err(format!(
+            "the project has {} pypi.org would reject",
+            findings::plural(high, "issue")
+        )),
+    }
+}
+
+/// The issues of a project and the files they were found in, most urgent first
+fn audit(root: &Path) -> Result<Vec<(String, Issue)>> {
+    let pyproject = root.join("pyproject.toml");
+    let project = project::project(&pyproject)?;
+    let declared = check::declared(&pyproject)?;
+    let readme = project::READMES
+        .iter()
+        .map(|name| root.join(name))
+        .find(|path| path.is_file());
+    let readme_text = match &readme {
+        Some(path) => Some(
+            fs::read_to_string(path).map_err(|err| format!("reading {}: {err}", path.display()))?,
+        ),
+        None => None,
+    };
+
+    let location = pyproject.display().to_string();
+    let readme_location = readme.map(|path| path.display().to_string());
+    let mut issues: Vec<(String, Issue)> =
+        doctor::diagnose(&project, &declared, readme_text.as_deref())
+            .into_iter()
+            .map(|issue| {
+                let file = match (issue.check, &readme_location) {
+                    (Check::Readme, Some(readme)) => readme.clone(),
+                    _ => location.clone(),
+                };
+                (file, issue)
+            })
+            .collect();
+
+    for artifact in artifacts(&root.join("dist")) {
+        let issue = match check_dist::metadata(&artifact) {
+            Ok((_, metadata)) => {
+                let built = Scanner::new().scan_str(&metadata).classifiers;
+                doctor::compare_artifact(&project.classifiers, &built)
+            }
+            Err(err) => Some(Issue {
+                priority: Priority::Medium,
+                check: Check::Artifact,
+                line: None,
+                message: err,
+            }),
+        };
+        if let Some(issue) = issue {
+            issues.push((artifact.display().to_string(), issue));
+        }
+    }
+    issues.sort_by_key(|(_, issue)| Reverse(issue.priority));
+    Ok(issues)
+}
+
+/// The wheels and sdists of a `dist/` directory, if there is one
+fn artifacts(dist: &Path) -> Vec<PathBuf> {
+    let Ok(entries) = fs::read_dir(dist) else {
+        return Vec::new();
+    };
+    let mut artifacts: Vec<PathBuf> = entries
+        .filter_map(|entry| entry.ok())
+        .map(|entry| entry.path())
+        .filter(|path| {
+            let name = path.to_string_lossy();
+            name.ends_with(".whl") || name.ends_with(".tar.gz") || name.ends_with(".zip")
+        })
+        .collect();
+    artifacts.sort();
+    artifacts
+}
+
+fn count(issues: &[(String, Issue)], priority: Priority) -> usize {
+    issues
+        .iter()
+        .filter(|(_, issue)| issue.priority == priority)
+        .count()
+}
+
+fn write(out: &mut impl Write, issues: &[(String, Issue)]) -> std::io::Result<()> {
+    for (file, issue) in issues {
+        write!(out, "{:<6}  {file}", issue.priority)?;
+        if let Some(line) = issue.line {
+            write!(out, ":{line}")?;
+        }
+        writeln!(out, ": [{}] {}", issue.check, issue.message)?;
+    }
+    if issues.is_empty() {
+        return writeln!(out, "no issues found");
+    }
+    writeln!(
+        out,
+        "{}: {} high, {} medium, {} low",
+        findings::plural(issues.len(), "issue"),
+        count(issues, Priority::High),
+        count(issues, Priority::Medium),
+        count(issues, Priority::Low)
+    )
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn audits_the_project_readme_and_artifacts() {
+        let root = std::env::temp_dir().join(format!("trove-doctor-{}", std::process::id()));
+        fs::create_dir_all(root.join("dist")).unwrap();
+        fs::write(
+            root.join("pyproject.toml"),
+            "[project]\nname = 'example'\nrequires-python = '>=3.10'\nlicense = 'MIT'\n\
+             classifiers = [\n\
+             \x20 'Development Status :: 5 - Production/Stable',\n\
+             \x20 'Intended Audience :: Developers',\n\
+             \x20 'License :: OSI Approved :: MIT License',\n\
+             \x20 'Operating System :: OS Independent',\n\
+             \x20 'Programming Language :: Python :: 3',\n\
+             \x20 'Programming Language :: Python :: 3 :: Only',\n\
+             \x20 'Programming Language :: Python :: 3.9',\n\
+             \x20 'Topic :: Utilities',\n\
+             \x20 'Natural Language :: Ukranian',\n]\n",
+        )
+        .unwrap();
+        fs::write(
+            root.join("README.md"),
+            "![status](https://img.shields.io/badge/status-beta-yellow)\n",
+        )
+        .unwrap();
+        fs::write(root.join("dist").join("example-1.0.tar.gz"), "").unwrap();
+
+        let args = Args {
+            project: root.clone(),
+            json: false,
+        };
+        let mut out = Vec::new();
+        let result = run(&args, &mut out);
+        fs::remove_dir_all(&root).unwrap();
+        let out = String::from_utf8(out)
+            .unwrap()
+            .replace(&root.display().to_string(), ".");
+
+        assert_eq!(
+            result.unwrap_err(),
+            "the project has 1 issue pypi.org would reject"
+        );
+        let lines: Vec<&str> = out.lines().collect();
+        assert_eq!(
+            lines[0],
+            "high    ./pyproject.toml:14: [deprecated] \"Natural Language :: Ukranian\" is \
+             deprecated, use \"Natural Language :: Ukrainian\" instead"
+        );
+        assert_eq!(
+            lines[1],
+            "medium  ./pyproject.toml:12: [requires-python] \"Programming Language :: Python :: \
+             3.9\" is declared, but requires-python \">=3.10\" excludes it"
+        );
+        assert_eq!(
+            lines[2],
+            "medium  ./README.md: [readme] the README's status badge is out of date, use \
+             https://img.shields.io/badge/status-stable-brightgreen"
+        );
+        assert!(lines[3].starts_with("medium  ./dist/example-1.0.tar.gz: [artifact] "));
+        assert!(lines[4..lines.len() - 1]
+            .iter()
+            .all(|line| line.starts_with("low     ./pyproject.toml: [recommendation] add ")));
+        assert_eq!(lines[lines.len() - 1], "9 issues: 1 high, 3 medium, 5 low");
+    }
+}
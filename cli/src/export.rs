@@ -0,0 +1,291 @@
+//! `trove export`: dump the dataset for toolchains that cannot link trove-classifiers.
+
+use std::io::Write;
+use std::str::FromStr;
+
+use serde_json::json;
+use trove_classifiers::export::{self, Delimiter};
+use trove_classifiers::{deprecations, stable_id, Classifier};
+
+use crate::list::category;
+use crate::Result;
+
+#[derive(Clone, Copy, clap::ValueEnum)]
+enum Format {
+    /// An array of objects
+    Json,
+    /// A sequence of mappings
+    Yaml,
+    /// A header and one row per classifier, replacements separated by ";"
+    Csv,
+    /// The rows of csv, separated by tabs
+    Tsv,
+    /// One accepted classifier per line, without the deprecated ones
+    Txt,
+    /// A JSON Schema that a string is an accepted classifier
+    JsonSchema,
+    /// An OpenAPI 3 document with a TroveClassifier schema component
+    Openapi,
+    /// A proto3 enum numbered with the classifiers' stable codes
+    Protobuf,
+    /// A TypeScript union type of the classifiers' string literals
+    Typescript,
+    /// An HTML datalist with the id "trove-classifiers", for inputs suggesting classifiers
+    HtmlDatalist,
+    /// An HTML select named "classifier", with an optgroup per category
+    HtmlSelect,
+    /// The completion data of editor extensions: a flat list with documentation and the tree of
+    /// segments
+    Editor,
+}
+
+#[derive(clap::Args)]
+pub struct Args {
+    /// The output format
+    #[arg(long, value_enum, default_value_t = Format::Json)]
+    format: Format,
+    /// Only export classifiers of these top-level categories, such as "Framework"
+    #[arg(long)]
+    category: Vec<String>,
+}
+
+/// One classifier of the export, accepted or deprecated
+struct Entry {
+    classifier: &'static str,
+    category: &'static str,
+    /// The classifier this one is nested in, if any
+    parent: Option<&'static str>,
+    /// The number of segments of the classifier
+    depth: usize,
+    deprecated: bool,
+    replacements: Vec<&'static str>,
+    /// The classifier's `stable_id` as 16 hexadecimal digits
+    stable_id: String,
+}
+
+impl Entry {
+    fn new(classifier: &'static str, deprecated: bool, replacements: Vec<&'static str>) -> Self {
+        Entry {
+            classifier,
+            category: classifier.split(" :: ").next().unwrap_or(classifier),
+            parent: classifier
+                .rsplit_once(" :: ")
+                .and_then(|(parent, _)| Classifier::from_str(parent).ok())
+                .map(|parent| parent.as_str()),
+            depth: classifier.split(" :: ").count(),
+            deprecated,
+            replacements,
+            stable_id: format!("{:016x}", stable_id(classifier)),
+        }
+    }
+
+    fn to_json(&self) -> serde_json::Value {
+        json!({
+            "classifier": self.classifier,
+            "category": self.category,
+            "parent": self.parent,
+            "depth": self.depth,
+            "deprecated": self.deprecated,
+            "replacements": self.replacements,
+            "stable_id": self.stable_id,
+        })
+    }
+}
+
+pub fn run(args: &Args, out: &mut impl Write) -> Result<()> {
+    let categories = args
+        .category
+        .iter()
+        .map(|name| category(name))
+        .collect::<Result<Vec<_>>>()?;
+    let mut entries: Vec<Entry> = Classifier::ALL
+        .iter()
+        .map(|classifier| Entry::new(classifier.as_str(), false, Vec::new()))
+        .chain(deprecations().map(|deprecation| {
+            let replacements = deprecation.replacements().map(|c| c.as_str()).collect();
+            Entry::new(deprecation.as_str(), true, replacements)
+        }))
+        .collect();
+    if !categories.is_empty() {
+        entries.retain(|entry| categories.contains(&entry.category));
+    }
+    match args.format {
+        Format::Json => json(&entries, out),
+        Format::Yaml => yaml(&entries, out),
+        Format::Csv => out.write_all(export::delimited(&categories, Delimiter::Comma).as_bytes()),
+        Format::Tsv => out.write_all(export::delimited(&categories, Delimiter::Tab).as_bytes()),
+        Format::Txt => txt(&entries, out),
+        Format::JsonSchema => out.write_all(export::json_schema(&categories).as_bytes()),
+        Format::Openapi => out.write_all(export::openapi(&categories).as_bytes()),
+        Format::Protobuf => out.write_all(export::protobuf(&categories).as_bytes()),
+        Format::Typescript => out.write_all(export::typescript(&categories).as_bytes()),
+        Format::HtmlDatalist => {
+            let datalist = export::html_datalist("trove-classifiers", &categories);
+            out.write_all(datalist.as_bytes())
+        }
+        Format::HtmlSelect => {
+            let select = export::html_select("classifier", &categories);
+            out.write_all(select.as_bytes())
+        }
+        Format::Editor => out.write_all(export::editor_data(&categories).as_bytes()),
+    }
+    .map_err(|err| err.to_string())
+}
+
+fn json(entries: &[Entry], out: &mut impl Write) -> std::io::Result<()> {
+    let entries: Vec<_> = entries.iter().map(Entry::to_json).collect();
+    serde_json::to_writer_pretty(&mut *out, &entries)?;
+    writeln!(out)
+}
+
+fn yaml(entries: &[Entry], out: &mut impl Write) -> std::io::Result<()> {
+    // JSON strings and arrays are also valid YAML flow scalars and sequences
+    let quote = |string: &str| serde_json::Value::from(string).to_string();
+    for entry in entries {
+        writeln!(out, "- classifier: {}", quote(entry.classifier))?;
+        writeln!(out, "  category: {}", quote(entry.category))?;
+        writeln!(
+            out,
+            "  parent: {}",
+            entry.parent.map_or("null".to_string(), quote)
+        )?;
+        writeln!(out, "  depth: {}", entry.depth)?;
+        writeln!(out, "  deprecated: {}", entry.deprecated)?;
+        writeln!(out, "  replacements: {}", json!(entry.replacements))?;
+        writeln!(out, "  stable_id: {}", quote(&entry.stable_id))?;
+    }
+    Ok(())
+}
+
+fn txt(entries: &[Entry], out: &mut impl Write) -> std::io::Result<()> {
+    for entry in entries.iter().filter(|entry| !entry.deprecated) {
+        writeln!(out, "{}", entry.classifier)?;
+    }
+    Ok(())
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    fn export(format: Format, category: &[&str]) -> Result<String> {
+        let args = Args {
+            format,
+            category: category.iter().map(|name| name.to_string()).collect(),
+        };
+        let mut out = Vec::new();
+        run(&args, &mut out)?;
+        Ok(String::from_utf8(out).unwrap())
+    }
+
+    #[test]
+    fn exports_json_with_hierarchy_and_deprecations() {
+        let exported: serde_json::Value =
+            serde_json::from_str(&export(Format::Json, &["natural language"]).unwrap()).unwrap();
+        let entries = exported.as_array().unwrap();
+        assert!(entries.contains(&json!({
+            "classifier": "Natural Language :: Ukranian",
+            "category": "Natural Language",
+            "parent": null,
+            "depth": 2,
+            "deprecated": true,
+            "replacements": ["Natural Language :: Ukrainian"],
+            "stable_id": format!("{:016x}", stable_id("Natural Language :: Ukranian")),
+        })));
+
+        let exported: serde_json::Value =
+            serde_json::from_str(&export(Format::Json, &["Framework"]).unwrap()).unwrap();
+        assert!(exported.as_array().unwrap().contains(&json!({
+            "classifier": "Framework :: Django :: 5.1",
+            "category": "Framework",
+            "parent": "Framework :: Django",
+            "depth": 3,
+            "deprecated": false,
+            "replacements": [],
+            "stable_id": format!("{:016x}", Classifier::Framework__Django__5_1.stable_id()),
+        })));
+    }
+
+    #[test]
+    fn exports_yaml() {
+        assert_eq!(
+            export(Format::Yaml, &["Typing"]).unwrap(),
+            "- classifier: \"Typing :: Stubs Only\"\n  category: \"Typing\"\n  parent: null\n  \
+             depth: 2\n  deprecated: false\n  replacements: []\n  stable_id: \"63c9cb953722ba5f\"\n\
+             - classifier: \"Typing :: Typed\"\n  category: \"Typing\"\n  parent: null\n  \
+             depth: 2\n  deprecated: false\n  replacements: []\n  stable_id: \"e606ef1b72abcf72\"\n"
+        );
+    }
+
+    #[test]
+    fn exports_quoted_csv() {
+        let exported = export(Format::Csv, &["License", "Typing"]).unwrap();
+        let mut lines = exported.lines();
+        assert_eq!(
+            lines.next(),
+            Some("classifier,category,parent,depth,deprecated,replacements,code,stable_id")
+        );
+        assert!(lines
+            .clone()
+            .any(|line| line == "Typing :: Typed,Typing,,2,false,,865,e606ef1b72abcf72"));
+        assert!(lines.any(|line| line.starts_with(
+            "\"License :: OSI Approved :: Educational Community License, Version 2.0 \
+             (ECL-2.0)\",License,License :: OSI Approved,3,false,,"
+        )));
+    }
+
+    #[test]
+    fn exports_tsv() {
+        let exported = export(Format::Tsv, &["Natural Language"]).unwrap();
+        assert!(exported.lines().any(|line| line
+            == "Natural Language :: Ukranian\tNatural Language\t\t2\ttrue\t\
+                Natural Language :: Ukrainian\t\tad8ba398eb8f1537"));
+    }
+
+    #[test]
+    fn exports_generated_sources() {
+        let schema: serde_json::Value =
+            serde_json::from_str(&export(Format::JsonSchema, &["typing"]).unwrap()).unwrap();
+        assert_eq!(schema["type"], "string");
+        assert_eq!(
+            schema["enum"],
+            json!(["Typing :: Stubs Only", "Typing :: Typed"])
+        );
+
+        let document: serde_json::Value =
+            serde_json::from_str(&export(Format::Openapi, &[]).unwrap()).unwrap();
+        let component = &document["components"]["schemas"]["TroveClassifier"];
+        assert_eq!(
+            component["enum"].as_array().unwrap().len(),
+            Classifier::ALL.len()
+        );
+
+        let proto = export(Format::Protobuf, &["Development Status"]).unwrap();
+        assert!(proto.contains("  TROVE_CLASSIFIER_DEVELOPMENT_STATUS_1_PLANNING = 1;\n"));
+        assert!(!proto.contains("TYPING"));
+
+        let datalist = export(Format::HtmlDatalist, &["Typing"]).unwrap();
+        assert!(datalist.starts_with("<datalist id=\"trove-classifiers\">\n"));
+        let select = export(Format::HtmlSelect, &[]).unwrap();
+        assert_eq!(select.matches("<option ").count(), Classifier::ALL.len());
+
+        let data: serde_json::Value =
+            serde_json::from_str(&export(Format::Editor, &["Typing"]).unwrap()).unwrap();
+        assert_eq!(data["classifiers"][1]["classifier"], "Typing :: Typed");
+        assert_eq!(
+            data["segments"][0]["children"][1]["path"],
+            "Typing :: Typed"
+        );
+
+        let declaration = export(Format::Typescript, &[]).unwrap();
+        assert_eq!(declaration.lines().count(), Classifier::ALL.len() + 2);
+        assert!(declaration.contains("\n  | \"Typing :: Typed\";\n"));
+    }
+
+    #[test]
+    fn txt_omits_deprecated_classifiers() {
+        let exported = export(Format::Txt, &[]).unwrap();
+        assert_eq!(exported.lines().count(), Classifier::ALL.len());
+        assert!(!exported.contains("Ukranian"));
+    }
+}
@@ -0,0 +1,204 @@
+//! Printing the findings of `trove_classifiers::lint`.
+
+use std::io::{self, Write};
+
+use serde_json::json;
+use trove_classifiers::lint::{self, Finding, Severity};
+use trove_classifiers::sarif::to_sarif;
+
+/// How findings are printed
+#[derive(Clone, Copy, Debug, Default, Eq, PartialEq, clap::ValueEnum)]
+pub enum Format {
+    /// One line per finding
+    #[default]
+    Text,
+    /// A SARIF 2.1.0 log, for GitHub code scanning and other SARIF consumers
+    Sarif,
+    /// GitHub Actions workflow commands, which annotate the offending lines of a pull request
+    Github,
+    /// One JSON object per file, for jq and data pipelines
+    Jsonl,
+}
+
+impl Format {
+    /// Whether the findings of each file can be printed as soon as it is checked
+    pub fn streams(&self) -> bool {
+        *self != Format::Sarif
+    }
+}
+
+/// Print the findings of each file in a format
+pub fn report(
+    out: &mut impl Write,
+    format: Format,
+    files: &[(String, Vec<Finding>)],
+) -> io::Result<()> {
+    match format {
+        Format::Text => {
+            for (location, findings) in files {
+                for finding in findings {
+                    write(out, location, finding)?;
+                }
+            }
+            Ok(())
+        }
+        Format::Sarif => {
+            let files = files
+                .iter()
+                .map(|(location, findings)| (location.as_str(), findings.as_slice()));
+            writeln!(out, "{}", to_sarif(files))
+        }
+        Format::Github => {
+            for (location, findings) in files {
+                for finding in findings {
+                    annotate(out, location, finding)?;
+                }
+            }
+            Ok(())
+        }
+        Format::Jsonl => {
+            for (location, findings) in files {
+                let record = json!({
+                    "file": location,
+                    "passed": lint::passes(findings),
+                    "findings": to_json(findings),
+                });
+                writeln!(out, "{record}")?;
+            }
+            Ok(())
+        }
+    }
+}
+
+/// Findings as an array of JSON objects
+pub fn to_json(findings: &[Finding]) -> serde_json::Value {
+    findings
+        .iter()
+        .map(|finding| {
+            json!({
+                "rule": finding.rule.code(),
+                "name": finding.rule.name(),
+                "severity": finding.severity.as_str(),
+                "value": finding.value,
+                "line": finding.line,
+                "message": finding.message,
+            })
+        })
+        .collect()
+}
+
+/// Print a finding as a GitHub Actions `::error` or `::warning` workflow command
+fn annotate(out: &mut impl Write, location: &str, finding: &Finding) -> io::Result<()> {
+    write!(
+        out,
+        "::{} file={}",
+        finding.severity,
+        escape_property(location)
+    )?;
+    if let Some(line) = finding.line {
+        write!(out, ",line={line}")?;
+    }
+    let title = format!("{} {}", finding.rule.code(), finding.rule.name());
+    writeln!(
+        out,
+        ",title={}::{}",
+        escape_property(&title),
+        escape_data(&finding.message)
+    )
+}
+
+/// Escape the message of a workflow command
+fn escape_data(value: &str) -> String {
+    value
+        .replace('%', "%25")
+        .replace('\r', "%0D")
+        .replace('\n', "%0A")
+}
+
+/// Escape a property of a workflow command
+fn escape_property(value: &str) -> String {
+    escape_data(value).replace(':', "%3A").replace(',', "%2C")
+}
+
+/// Print a finding as `location:line: severity[code] message`
+pub fn write(out: &mut impl Write, location: &str, finding: &Finding) -> io::Result<()> {
+    match finding.line {
+        Some(line) => write!(out, "{location}:{line}: ")?,
+        None => write!(out, "{location}: ")?,
+    }
+    writeln!(
+        out,
+        "{}[{}] {}",
+        finding.severity, finding.rule, finding.message
+    )
+}
+
+/// How many errors and warnings there are, such as "1 error, 2 warnings"
+pub fn counts(findings: &[Finding]) -> String {
+    let count = |severity: Severity| {
+        findings
+            .iter()
+            .filter(|finding| finding.severity == severity)
+            .count()
+    };
+    format!(
+        "{}, {}",
+        plural(count(Severity::Error), "error"),
+        plural(count(Severity::Warning), "warning")
+    )
+}
+
+/// A count and a noun, made plural unless the count is one
+pub fn plural(count: usize, noun: &str) -> String {
+    match count {
+        1 => format!("1 {noun}"),
+        count => format!("{count} {noun}s"),
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use trove_classifiers::lint::{lint, Declared};
+
+    #[test]
+    fn prints_locations_and_counts() {
+        let findings = lint(&[
+            Declared::new("Typing :: Sometimes").line(4),
+            Declared::new("Private :: Internal"),
+        ]);
+        let mut out = Vec::new();
+        for finding in &findings {
+            write(&mut out, "METADATA", finding).unwrap();
+        }
+        assert_eq!(
+            String::from_utf8(out).unwrap(),
+            "METADATA:4: error[TC001] \"Typing :: Sometimes\" is not a known classifier\n\
+             METADATA: warning[TC004] \"Private :: Internal\" is private, pypi.org will refuse \
+             to upload the project\n"
+        );
+        assert_eq!(counts(&findings), "1 error, 1 warning");
+
+        let mut out = Vec::new();
+        let files = [("a,b.toml".to_string(), findings)];
+        report(&mut out, Format::Github, &files).unwrap();
+        assert_eq!(
+            String::from_utf8(out).unwrap(),
+            "::error file=a%2Cb.toml,line=4,title=TC001 unknown-classifier::\"Typing :: Sometimes\" \
+             is not a known classifier\n\
+             ::warning file=a%2Cb.toml,title=TC004 private-classifier::\"Private :: Internal\" is \
+             private, pypi.org will refuse to upload the project\n"
+        );
+        assert_eq!(counts(&[]), "0 errors, 0 warnings");
+
+        let mut out = Vec::new();
+        report(&mut out, Format::Jsonl, &files).unwrap();
+        let record: serde_json::Value = serde_json::from_slice(&out).unwrap();
+        assert_eq!(record["file"], "a,b.toml");
+        assert_eq!(record["passed"], false);
+        assert_eq!(record["findings"][0]["rule"], "TC001");
+        assert_eq!(record["findings"][0]["line"], 4);
+        assert_eq!(record["findings"][1]["severity"], "warning");
+        assert_eq!(escape_property("50%: a\nb"), "50%25%3A a%0Ab");
+    }
+}
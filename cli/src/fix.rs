@@ -0,0 +1,326 @@
+//! `trove fix`: tidy the classifiers of a pyproject.toml.
+
+use std::fs;
+use std::io::Write;
+use std::path::PathBuf;
+
+use trove_classifiers::alias::Aliases;
+use trove_classifiers::fix::{fix, Options};
+use trove_classifiers::pyproject::ArrayStyle;
+use trove_classifiers::{lint, pyproject};
+
+use crate::{check, findings, Result};
+
+#[derive(Clone, Copy, Debug, Default, Eq, PartialEq, clap::ValueEnum)]
+enum Format {
+    /// A unified diff of the changes
+    #[default]
+    Diff,
+    /// A SARIF 2.1.0 log of the findings the changes fix
+    Sarif,
+}
+
+#[derive(clap::Args)]
+pub struct Args {
+    /// The pyproject.toml to tidy
+    #[arg(default_value = "pyproject.toml")]
+    pyproject: PathBuf,
+    /// Rewrite the file instead of printing a diff of the changes
+    #[arg(long)]
+    write: bool,
+    /// Add the classifiers that declared ones are nested in
+    #[arg(long)]
+    add_parents: bool,
+    /// Expand the shorthands of a file of `shorthand = classifier` lines, rather than the aliases
+    /// of the policy
+    #[arg(long, value_name = "FILE")]
+    aliases: Option<PathBuf>,
+    /// Follow the policy of this trove.toml, or of the [tool.trove] table of this pyproject.toml,
+    /// rather than of the trove.toml or pyproject.toml of the current directory
+    #[arg(long, value_name = "FILE")]
+    config: Option<PathBuf>,
+    /// Fail on deprecated classifiers rather than replace them
+    #[arg(long)]
+    strict: bool,
+    /// How to print what needs fixing
+    #[arg(long, value_enum, default_value_t)]
+    format: Format,
+}
+
+pub fn run(args: &Args, out: &mut impl Write) -> Result<()> {
+    let path = &args.pyproject;
+    let source =
+        fs::read_to_string(path).map_err(|err| format!("reading {}: {err}", path.display()))?;
+    if args.format == Format::Sarif {
+        let findings = lint::lint(&check::declared(path)?);
+        let files = [(path.display().to_string(), findings)];
+        findings::report(out, findings::Format::Sarif, &files).map_err(|err| err.to_string())?;
+    }
+    let mut options = Options::default()
+        .add_parents(args.add_parents)
+        .strict(args.strict);
+    let policy = crate::policy::load(args.config.as_deref())?;
+    if args.aliases.is_none() {
+        options = options.aliases(policy.aliases);
+    }
+    if let Some(file) = &args.aliases {
+        let aliases =
+            fs::read_to_string(file).map_err(|err| format!("reading {}: {err}", file.display()))?;
+        let aliases: Aliases = aliases
+            .parse()
+            .map_err(|err| format!("{}: {err}", file.display()))?;
+        options = options.aliases(aliases);
+    }
+    let Some(fixed) = fixed_source(&source, &options, |note| {
+        eprintln!("{}: {note}", path.display())
+    })
+    .map_err(|err| format!("{}: {err}", path.display()))?
+    else {
+        eprintln!("{}: classifiers are already tidy", path.display());
+        return Ok(());
+    };
+    if args.write {
+        fs::write(path, &fixed).map_err(|err| format!("writing {}: {err}", path.display()))
+    } else if args.format == Format::Diff {
+        let name = path.display().to_string();
+        write!(out, "{}", diff(&name, &source, &fixed)).map_err(|err| err.to_string())
+    } else {
+        Ok(())
+    }
+}
+
+/// The document with its classifiers array tidied, or `None` if it is already tidy
+///
+/// Each change made, and each classifier left alone because it is unknown, is passed to `note`.
+/// With `Options::strict`, a deprecated classifier is an error.
+fn fixed_source(
+    source: &str,
+    options: &Options,
+    mut note: impl FnMut(String),
+) -> Result<Option<String>> {
+    let Some(array) = pyproject::classifiers(source).map_err(|err| err.to_string())? else {
+        return Ok(None);
+    };
+    let fixed = fix(array.entries.iter().map(|entry| &entry.value), options);
+    if let Some(rejected) = fixed.rejected.first() {
+        return Err(rejected.to_string());
+    }
+    for change in &fixed.changes {
+        note(change.to_string());
+    }
+    for unknown in &fixed.unknown {
+        note(format!("kept unknown classifier {unknown:?}"));
+    }
+    if fixed.is_unchanged() {
+        return Ok(None);
+    }
+
+    let indent = array
+        .entries
+        .first()
+        .map(|entry| {
+            let line_start = source[..entry.span.start]
+                .rfind('\n')
+                .map_or(0, |end| end + 1);
+            let indent = &source[line_start..entry.span.start];
+            if indent.trim().is_empty() && !indent.is_empty() {
+                indent
+            } else {
+                "    "
+            }
+        })
+        .unwrap_or("    ");
+    let values = fixed
+        .classifiers
+        .iter()
+        .map(|classifier| classifier.as_str())
+        .chain(fixed.unknown.iter().map(String::as_str));
+    let rendered = pyproject::toml_array(values, &ArrayStyle::default().indent(indent));
+    Ok(Some(format!(
+        "{}{rendered}{}",
+        &source[..array.span.start],
+        &source[array.span.end..]
+    )))
+}
+
+/// A unified diff of two versions of a file, with a single hunk spanning every changed line and
+/// a line of context on either side
+fn diff(name: &str, before: &str, after: &str) -> String {
+    let before: Vec<&str> = before.lines().collect();
+    let after: Vec<&str> = after.lines().collect();
+    let prefix = before
+        .iter()
+        .zip(&after)
+        .take_while(|(a, b)| a == b)
+        .count();
+    let suffix = before[prefix..]
+        .iter()
+        .rev()
+        .zip(after[prefix..].iter().rev())
+        .take_while(|(a, b)| a == b)
+        .count();
+    // patch and git apply place a hunk by its context, so keep a line of it where there is one
+    let (start, end) = (prefix - prefix.min(1), suffix - suffix.min(1));
+    let (removed, added) = (
+        &before[start..before.len() - end],
+        &after[start..after.len() - end],
+    );
+
+    // the longest common subsequence of the changed lines, so that unchanged entries show as
+    // context rather than as removed and added again
+    let mut lengths = vec![vec![0usize; added.len() + 1]; removed.len() + 1];
+    for i in (0..removed.len()).rev() {
+        for j in (0..added.len()).rev() {
+            lengths[i][j] = if removed[i] == added[j] {
+                lengths[i + 1][j + 1] + 1
+            } else {
+                lengths[i + 1][j].max(lengths[i][j + 1])
+            };
+        }
+    }
+    // an empty side is numbered by the line it follows rather than the line it starts at
+    let first = |lines: &[&str]| if lines.is_empty() { start } else { start + 1 };
+    let mut out = format!(
+        "--- {name}\n+++ {name}\n@@ -{},{} +{},{} @@\n",
+        first(removed),
+        removed.len(),
+        first(added),
+        added.len()
+    );
+    let (mut i, mut j) = (0, 0);
+    while i < removed.len() || j < added.len() {
+        if i < removed.len() && j < added.len() && removed[i] == added[j] {
+            out.push_str(&format!(" {}\n", removed[i]));
+            i += 1;
+            j += 1;
+        } else if i < removed.len() && (j == added.len() || lengths[i + 1][j] >= lengths[i][j + 1])
+        {
+            out.push_str(&format!("-{}\n", removed[i]));
+            i += 1;
+        } else {
+            out.push_str(&format!("+{}\n", added[j]));
+            j += 1;
+        }
+    }
+    out
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    const PYPROJECT: &str = "[project]\n\
+        name = \"example\"\n\
+        classifiers = [\n\
+        \x20 \"Typing :: Typed\",\n\
+        \x20 'framework :: django :: 5.1',\n\
+        \x20 \"Natural Language :: Ukranian\",\n\
+        \x20 \"Private :: \\\"Internal\\\"\",\n\
+        \x20 \"Typing :: Typed\",\n\
+        ]\n\
+        dependencies = []\n";
+
+    #[test]
+    fn rewrites_the_array_only() {
+        let mut notes = Vec::new();
+        let options = Options::default().add_parents(true);
+        let fixed = fixed_source(PYPROJECT, &options, |note| notes.push(note))
+            .unwrap()
+            .unwrap();
+        assert_eq!(
+            fixed,
+            "[project]\n\
+             name = \"example\"\n\
+             classifiers = [\n\
+             \x20 \"Framework :: Django\",\n\
+             \x20 \"Framework :: Django :: 5.1\",\n\
+             \x20 \"Natural Language :: Ukrainian\",\n\
+             \x20 \"Typing :: Typed\",\n\
+             \x20 \"Private :: \\\"Internal\\\"\",\n\
+             ]\n\
+             dependencies = []\n"
+        );
+        assert_eq!(notes.len(), 6);
+        assert_eq!(
+            notes[5],
+            "kept unknown classifier \"Private :: \\\"Internal\\\"\""
+        );
+    }
+
+    #[test]
+    fn tidy_files_are_left_alone() {
+        let source = "[project]\nclassifiers = ['Typing :: Typed']\n";
+        let options = Options::default();
+        assert_eq!(fixed_source(source, &options, |_| {}).unwrap(), None);
+        assert_eq!(fixed_source("[project]\n", &options, |_| {}).unwrap(), None);
+    }
+
+    #[test]
+    fn expands_aliases() {
+        let aliases: Aliases = "typed = Typing :: Typed\n".parse().unwrap();
+        let options = Options::default().aliases(aliases);
+        let source = "[project]\nclassifiers = ['typed']\n";
+        assert_eq!(
+            fixed_source(source, &options, |_| {}).unwrap().unwrap(),
+            "[project]\nclassifiers = [\n    \"Typing :: Typed\",\n]\n"
+        );
+    }
+
+    #[test]
+    fn strict_fixes_fail_on_deprecations() {
+        let options = Options::default().strict(true);
+        assert_eq!(
+            fixed_source(PYPROJECT, &options, |_| {}),
+            Err("deprecated classifier \"Natural Language :: Ukranian\", \
+                 use \"Natural Language :: Ukrainian\" instead"
+                .to_string())
+        );
+    }
+
+    #[test]
+    fn prints_sarif_of_the_findings() {
+        let path = std::env::temp_dir().join(format!("trove-fix-{}.toml", std::process::id()));
+        fs::write(&path, PYPROJECT).unwrap();
+        let args = Args {
+            pyproject: path.clone(),
+            write: false,
+            add_parents: false,
+            aliases: None,
+            config: None,
+            strict: false,
+            format: Format::Sarif,
+        };
+        let mut out = Vec::new();
+        let result = run(&args, &mut out);
+        fs::remove_file(&path).unwrap();
+        result.unwrap();
+
+        let log: serde_json::Value = serde_json::from_slice(&out).unwrap();
+        let rules: Vec<&str> = log["runs"][0]["results"]
+            .as_array()
+            .unwrap()
+            .iter()
+            .map(|result| result["ruleId"].as_str().unwrap())
+            .collect();
+        assert_eq!(rules, ["TC003", "TC002", "TC004", "TC005"]);
+    }
+
+    #[test]
+    fn diffs_changed_lines() {
+        let before = "a\nb\nc\nd\ne\n";
+        let after = "a\nc\nb\nd\nf\ne\n";
+        assert_eq!(
+            diff("f", before, after),
+            "--- f\n+++ f\n@@ -1,5 +1,6 @@\n a\n-b\n c\n+b\n d\n+f\n e\n"
+        );
+        assert_eq!(
+            diff("f", "a\nb\nc\n", "a\nb\nx\nc\n"),
+            "--- f\n+++ f\n@@ -2,2 +2,3 @@\n b\n+x\n c\n"
+        );
+        assert_eq!(
+            diff("f", "a\nb\n", "a\n"),
+            "--- f\n+++ f\n@@ -1,2 +1,1 @@\n a\n-b\n"
+        );
+        assert_eq!(diff("f", "", "a\n"), "--- f\n+++ f\n@@ -0,0 +1,1 @@\n+a\n");
+    }
+}
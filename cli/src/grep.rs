@@ -0,0 +1,293 @@
+//! `trove grep`: find the projects of a tree that declare matching classifiers.
+
+use std::fs;
+use std::io::Write;
+use std::path::{Path, PathBuf};
+
+use serde_json::json;
+use trove_classifiers::scanner;
+use trove_classifiers::Classifier;
+
+use crate::{check, findings, Result};
+
+/// Directories never descended into, besides hidden ones
+const SKIPPED: &[&str] = &["node_modules", "__pycache__"];
+
+#[derive(clap::Args)]
+pub struct Args {
+    /// A classifier, matching it and the classifiers nested below it, or a glob where `*` matches
+    /// any text and `?` any one character
+    pattern: String,
+    /// Files and directories to search; directories are walked for pyproject.toml, setup.cfg,
+    /// METADATA and PKG-INFO files, skipping hidden directories
+    #[arg(default_value = ".")]
+    paths: Vec<PathBuf>,
+    /// Only print the files declaring a matching classifier
+    #[arg(short = 'l', long, conflicts_with = "format")]
+    files_with_matches: bool,
+    /// How to print the matches
+    #[arg(long, value_enum, default_value_t)]
+    format: Format,
+}
+
+/// How the matches of each file are printed
+#[derive(Clone, Copy, Debug, Default, Eq, PartialEq, clap::ValueEnum)]
+enum Format {
+    /// One `file:line: classifier` line per match
+    #[default]
+    Text,
+    /// One JSON object per file with matches, for jq and data pipelines
+    Jsonl,
+}
+
+pub fn run(args: &Args, out: &mut impl Write) -> Result<()> {
+    let pattern = Pattern::new(&args.pattern)?;
+    let mut files = Vec::new();
+    for path in &args.paths {
+        if path.is_dir() {
+            walk(path, &mut files);
+        } else {
+            files.push(path.clone());
+        }
+    }
+    let mut matched = 0;
+    for file in &files {
+        let declared = match check::declared(file) {
+            Ok(declared) => declared,
+            Err(err) => {
+                eprintln!("error: {err}");
+                continue;
+            }
+        };
+        let matches: Vec<_> = declared
+            .iter()
+            .filter(|declared| pattern.matches(declared.value.trim()))
+            .collect();
+        if matches.is_empty() {
+            continue;
+        }
+        matched += 1;
+        if args.format == Format::Jsonl {
+            let matches: Vec<_> = matches
+                .iter()
+                .map(|declared| json!({ "line": declared.line, "classifier": declared.value }))
+                .collect();
+            let record = json!({ "file": file.display().to_string(), "matches": matches });
+            writeln!(out, "{record}").map_err(|err| err.to_string())?;
+            continue;
+        }
+        if args.files_with_matches {
+            writeln!(out, "{}", file.display()).map_err(|err| err.to_string())?;
+            continue;
+        }
+        for declared in matches {
+            match declared.line {
+                Some(line) => writeln!(out, "{}:{line}: {}", file.display(), declared.value),
+                None => writeln!(out, "{}: {}", file.display(), declared.value),
+            }
+            .map_err(|err| err.to_string())?;
+        }
+    }
+    match matched {
+        0 => Err(format!(
+            "none of {} declares a classifier matching {:?}",
+            findings::plural(files.len(), "file"),
+            args.pattern
+        )),
+        _ => Ok(()),
+    }
+}
+
+/// What declared classifiers are matched against
+enum Pattern {
+    /// A classifier prefix, matched at segment boundaries
+    Prefix(String),
+    Glob(Vec<char>),
+}
+
+impl Pattern {
+    fn new(pattern: &str) -> Result<Self> {
+        let pattern = pattern.trim();
+        if pattern.contains(['*', '?']) {
+            return Ok(Pattern::Glob(pattern.chars().collect()));
+        }
+        let prefix = Pattern::Prefix(pattern.to_string());
+        if !Classifier::ALL
+            .iter()
+            .any(|classifier| prefix.matches(classifier.as_str()))
+        {
+            return Err(format!(
+                "no classifier is or starts with {pattern:?}, use a glob such as \"*{pattern}*\" \
+                 to match it anywhere"
+            ));
+        }
+        Ok(prefix)
+    }
+
+    fn matches(&self, value: &str) -> bool {
+        match self {
+            Pattern::Prefix(prefix) => value
+                .strip_prefix(prefix.as_str())
+                .is_some_and(|rest| rest.is_empty() || rest.starts_with(" :: ")),
+            Pattern::Glob(glob) => glob_matches(glob, &value.chars().collect::<Vec<_>>()),
+        }
+    }
+}
+
+/// Whether a glob of `*` and `?` wildcards matches the whole of `text`
+fn glob_matches(glob: &[char], text: &[char]) -> bool {
+    let (mut g, mut t) = (0, 0);
+    // The position of the last `*` and of the text it was tried against
+    let mut star = None;
+    while t < text.len() {
+        match glob.get(g) {
+            Some('*') => {
+                star = Some((g, t));
+                g += 1;
+            }
+            Some(&c) if c == '?' || c == text[t] => {
+                g += 1;
+                t += 1;
+            }
+            _ => match star {
+                Some((star_g, star_t)) => {
+                    g = star_g + 1;
+                    t = star_t + 1;
+                    star = Some((star_g, star_t + 1));
+                }
+                None => return false,
+            },
+        }
+    }
+    glob[g..].iter().all(|&c| c == '*')
+}
+
+/// Whether a directory is never descended into
+fn is_skipped(name: &str) -> bool {
+    name.starts_with('.') || SKIPPED.contains(&name)
+}
+
+/// Whether a file declares a project's classifiers
+fn is_project_file(path: &Path) -> bool {
+    path.file_name()
+        .is_some_and(|name| name == "pyproject.toml" || name == "setup.cfg")
+        || scanner::is_metadata_file(path)
+}
+
+/// Collect the project files below a directory, in a stable order
+fn walk(dir: &Path, files: &mut Vec<PathBuf>) {
+    let mut entries = match fs::read_dir(dir) {
+        Ok(entries) => entries.filter_map(|entry| entry.ok()).collect::<Vec<_>>(),
+        Err(err) => {
+            eprintln!("error: reading {}: {err}", dir.display());
+            return;
+        }
+    };
+    entries.sort_by_key(|entry| entry.file_name());
+    for entry in entries {
+        let path = entry.path();
+        let name = entry.file_name();
+        let name = name.to_string_lossy();
+        match entry.file_type() {
+            Ok(kind) if kind.is_dir() && !is_skipped(&name) => walk(&path, files),
+            Ok(kind) if kind.is_file() && is_project_file(&path) => files.push(path),
+            _ => {}
+        }
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn globs_match_whole_strings() {
+        let matches = |glob: &str, text: &str| {
+            glob_matches(
+                &glob.chars().collect::<Vec<_>>(),
+                &text.chars().collect::<Vec<_>>(),
+            )
+        };
+        assert!(matches(
+            "*GPL*",
+            "License :: OSI Approved :: GNU General Public License (GPL)"
+        ));
+        assert!(matches(
+            "Programming Language :: Python :: 3.?",
+            "Programming Language :: Python :: 3.9"
+        ));
+        assert!(!matches(
+            "Programming Language :: Python :: 3.?",
+            "Programming Language :: Python :: 3.10"
+        ));
+        assert!(matches("*", ""));
+        assert!(!matches("Typing", "Typing :: Typed"));
+        assert!(matches("a*b*c", "aXbYbZc"));
+    }
+
+    #[test]
+    fn prefixes_match_at_segment_boundaries() {
+        let pattern = Pattern::new("Typing").unwrap();
+        assert!(pattern.matches("Typing :: Typed"));
+        assert!(!pattern.matches("Typingo :: Typed"));
+        assert!(Pattern::new("Typing :: Sometimes").is_err());
+    }
+
+    #[test]
+    fn walks_projects() {
+        let root = std::env::temp_dir().join(format!("trove-grep-{}", std::process::id()));
+        let write = |path: &str, contents: &str| {
+            let path = root.join(path);
+            fs::create_dir_all(path.parent().unwrap()).unwrap();
+            fs::write(path, contents).unwrap();
+        };
+        write(
+            "a/pyproject.toml",
+            "[project]\nclassifiers = [\n  'Typing :: Typed',\n  'Framework :: Django',\n]\n",
+        );
+        write(
+            "b/setup.cfg",
+            "[metadata]\nclassifiers =\n    Framework :: Django :: 5.1\n",
+        );
+        write("c/pyproject.toml", "[project]\nclassifiers = []\n");
+        write(
+            ".venv/x.dist-info/METADATA",
+            "Classifier: Framework :: Django\n",
+        );
+        write("d/PKG-INFO", "Classifier: Framework :: Flask\n");
+
+        let grep = |pattern: &str, files_with_matches: bool, format: Format| {
+            let args = Args {
+                pattern: pattern.to_string(),
+                paths: vec![root.clone()],
+                files_with_matches,
+                format,
+            };
+            let mut out = Vec::new();
+            let result = run(&args, &mut out);
+            let out = String::from_utf8(out).unwrap();
+            (result, out.replace(&root.display().to_string(), "."))
+        };
+        let (result, django) = grep("Framework :: Django", false, Format::Text);
+        let (_, flask) = grep("Framework :: Fl*", true, Format::Text);
+        let (_, typed) = grep("Typing :: Typed", false, Format::Jsonl);
+        let (none, _) = grep("Typing :: Stubs Only", false, Format::Text);
+        fs::remove_dir_all(&root).unwrap();
+
+        result.unwrap();
+        assert_eq!(
+            django,
+            "./a/pyproject.toml:4: Framework :: Django\n\
+             ./b/setup.cfg:3: Framework :: Django :: 5.1\n"
+        );
+        assert_eq!(flask, "./d/PKG-INFO\n");
+        assert_eq!(
+            typed,
+            "{\"file\":\"./a/pyproject.toml\",\"matches\":[{\"classifier\":\"Typing :: Typed\",\"line\":3}]}\n"
+        );
+        assert_eq!(
+            none.unwrap_err(),
+            "none of 4 files declares a classifier matching \"Typing :: Stubs Only\""
+        );
+    }
+}
@@ -0,0 +1,172 @@
+//! `trove license`: translate between SPDX license identifiers and License classifiers.
+
+use std::io::Write;
+
+use trove_classifiers::{license, Classifier};
+
+use crate::Result;
+
+#[derive(clap::Args)]
+pub struct Args {
+    /// Print the classifier of an SPDX license identifier, such as "MIT"
+    #[arg(
+        long,
+        conflicts_with = "classifier",
+        required_unless_present = "classifier"
+    )]
+    spdx: Option<String>,
+    /// Print the SPDX license identifier of a License classifier, which can be repeated
+    #[arg(long)]
+    classifier: Vec<String>,
+    /// Translate whole sets: read --spdx as an SPDX expression and print the classifier of each
+    /// of its licenses, or join the identifiers of the classifiers into one expression
+    #[arg(long)]
+    expression: bool,
+}
+
+pub fn run(args: &Args, out: &mut impl Write) -> Result<()> {
+    let lines = match &args.spdx {
+        Some(spdx) if args.expression => from_expression(spdx)?,
+        Some(id) => vec![from_id(id)?],
+        None => {
+            let ids = args
+                .classifier
+                .iter()
+                .map(|classifier| to_id(classifier))
+                .collect::<Result<Vec<_>>>()?;
+            if args.expression {
+                let mut unique: Vec<&str> = Vec::new();
+                for id in ids {
+                    if !unique.contains(&id) {
+                        unique.push(id);
+                    }
+                }
+                vec![unique.join(" OR ")]
+            } else {
+                ids.into_iter().map(str::to_string).collect()
+            }
+        }
+    };
+    for line in lines {
+        writeln!(out, "{line}").map_err(|err| err.to_string())?;
+    }
+    Ok(())
+}
+
+fn from_id(id: &str) -> Result<String> {
+    match license::from_spdx(id) {
+        Some(classifier) => Ok(classifier.to_string()),
+        None if id.trim().contains(char::is_whitespace) => Err(format!(
+            "{id:?} is not a single SPDX license identifier, pass --expression to translate it \
+             as an expression"
+        )),
+        None => Err(format!("no classifier matches the SPDX license {id:?}")),
+    }
+}
+
+/// The classifier of each license of an expression, once each
+///
+/// Licenses without a classifier, such as `LicenseRef-` identifiers, are reported on stderr.
+fn from_expression(expression: &str) -> Result<Vec<String>> {
+    let mut classifiers: Vec<String> = Vec::new();
+    for id in license::spdx_ids(expression) {
+        match license::from_spdx(id) {
+            Some(classifier) => {
+                let classifier = classifier.to_string();
+                if !classifiers.contains(&classifier) {
+                    classifiers.push(classifier);
+                }
+            }
+            None => eprintln!("warning: no classifier matches the SPDX license {id:?}"),
+        }
+    }
+    if classifiers.is_empty() {
+        return Err(format!(
+            "no classifier matches any license of {expression:?}"
+        ));
+    }
+    Ok(classifiers)
+}
+
+fn to_id(classifier: &str) -> Result<&'static str> {
+    let parsed = Classifier::from_str_lenient(classifier)
+        .map_err(|_| format!("unknown classifier {classifier:?}"))?;
+    license::to_spdx(parsed).ok_or_else(|| format!("{parsed} does not name a single SPDX license"))
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    fn license(spdx: Option<&str>, classifier: &[&str], expression: bool) -> Result<String> {
+        let args = Args {
+            spdx: spdx.map(str::to_string),
+            classifier: classifier.iter().map(|c| c.to_string()).collect(),
+            expression,
+        };
+        let mut out = Vec::new();
+        run(&args, &mut out)?;
+        Ok(String::from_utf8(out).unwrap())
+    }
+
+    #[test]
+    fn translates_identifiers_both_ways() {
+        assert_eq!(
+            license(Some("mit"), &[], false).unwrap(),
+            "License :: OSI Approved :: MIT License\n"
+        );
+        assert_eq!(
+            license(
+                None,
+                &[
+                    "license :: osi approved :: mit license",
+                    "License :: OSI Approved :: Apache Software License"
+                ],
+                false
+            )
+            .unwrap(),
+            "MIT\nApache-2.0\n"
+        );
+    }
+
+    #[test]
+    fn translates_expressions_both_ways() {
+        assert_eq!(
+            license(
+                Some("(MIT OR Apache-2.0) AND MIT AND LicenseRef-Own"),
+                &[],
+                true
+            )
+            .unwrap(),
+            "License :: OSI Approved :: MIT License\n\
+             License :: OSI Approved :: Apache Software License\n"
+        );
+        assert_eq!(
+            license(
+                None,
+                &[
+                    "License :: OSI Approved :: MIT License",
+                    "License :: OSI Approved :: Apache Software License",
+                    "License :: OSI Approved :: MIT License",
+                ],
+                true
+            )
+            .unwrap(),
+            "MIT OR Apache-2.0\n"
+        );
+    }
+
+    #[test]
+    fn untranslatable_input_is_an_error() {
+        let err = license(Some("MIT OR Apache-2.0"), &[], false).unwrap_err();
+        assert!(err.contains("--expression"), "{err}");
+        assert!(license(Some("Proprietary"), &[], false).is_err());
+        assert!(license(Some("LicenseRef-Own"), &[], true).is_err());
+        let err = license(None, &["License :: OSI Approved"], false).unwrap_err();
+        assert_eq!(
+            err,
+            "License :: OSI Approved does not name a single SPDX license"
+        );
+        assert!(license(None, &["License :: Made Up"], false).is_err());
+    }
+}
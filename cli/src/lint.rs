@@ -0,0 +1,109 @@
+//! `trove lint`: document the rules `trove check` enforces.
+
+use std::io::Write;
+
+use trove_classifiers::lint::Rule;
+
+use crate::Result;
+
+#[derive(clap::Args)]
+pub struct Args {
+    /// Explain a rule, given by its code, such as TC002, or its name
+    #[arg(long, value_name = "RULE")]
+    explain: Option<String>,
+}
+
+pub fn run(args: &Args, out: &mut impl Write) -> Result<()> {
+    match &args.explain {
+        Some(code_or_name) => explain(out, rule(code_or_name)?),
+        None => list(out),
+    }
+    .map_err(|err| err.to_string())
+}
+
+/// The built-in rule with a code or name, as given on the command line
+pub fn rule(code_or_name: &str) -> Result<Rule> {
+    Rule::find(code_or_name).ok_or_else(|| {
+        let codes: Vec<&str> = Rule::ALL.iter().map(|rule| rule.code()).collect();
+        format!(
+            "{code_or_name:?} is not a rule (rules: {})",
+            codes.join(", ")
+        )
+    })
+}
+
+fn list(out: &mut impl Write) -> std::io::Result<()> {
+    let width = Rule::ALL
+        .iter()
+        .map(|rule| rule.name().len())
+        .max()
+        .unwrap_or(0);
+    for rule in Rule::ALL {
+        writeln!(
+            out,
+            "{}  {:width$}  {:7}  {}",
+            rule.code(),
+            rule.name(),
+            rule.severity(),
+            rule.summary()
+        )?;
+    }
+    Ok(())
+}
+
+fn explain(out: &mut impl Write, rule: Rule) -> std::io::Result<()> {
+    let explanation = rule.explanation();
+    writeln!(out, "{} {} ({})", rule.code(), rule.name(), rule.severity())?;
+    writeln!(out, "\n{}", explanation.rationale)?;
+    writeln!(out, "\nFix: {}", explanation.fix)?;
+    writeln!(out, "\nInstead of:")?;
+    for line in explanation.bad.lines() {
+        writeln!(out, "    {line}")?;
+    }
+    writeln!(out, "declare:")?;
+    for line in explanation.good.lines() {
+        writeln!(out, "    {line}")?;
+    }
+    Ok(())
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    fn lint(explain: Option<&str>) -> Result<String> {
+        let args = Args {
+            explain: explain.map(str::to_string),
+        };
+        let mut out = Vec::new();
+        run(&args, &mut out).map(|()| String::from_utf8(out).unwrap())
+    }
+
+    #[test]
+    fn lists_every_rule() {
+        let out = lint(None).unwrap();
+        assert_eq!(out.lines().count(), Rule::ALL.len());
+        assert!(out.starts_with(
+            "TC001  unknown-classifier           error    classifiers must be known to pypi.org\n"
+        ));
+    }
+
+    #[test]
+    fn explains_rules_by_code_or_name() {
+        let out = lint(Some("tc002")).unwrap();
+        assert!(out.starts_with("TC002 deprecated-classifier (error)\n\nClassifiers are"));
+        assert!(out.ends_with(
+            "Instead of:\n    Natural Language :: Ukranian\ndeclare:\n    \
+             Natural Language :: Ukrainian\n"
+        ));
+        assert_eq!(
+            lint(Some("duplicate-classifier")).unwrap().lines().count(),
+            11
+        );
+        assert_eq!(
+            lint(Some("TC999")).unwrap_err(),
+            "\"TC999\" is not a rule (rules: TC001, TC002, TC003, TC004, TC005, TC006, TC007, \
+             TC008, TC009, TC010)"
+        );
+    }
+}
@@ -0,0 +1,144 @@
+//! `trove list`: print classifiers in canonical order.
+
+use std::io::Write;
+
+use trove_classifiers::query::Query;
+use trove_classifiers::Classifier;
+
+use crate::Result;
+
+#[derive(clap::Args)]
+pub struct Args {
+    /// Only list classifiers of this top-level category, such as "Framework"
+    #[arg(long)]
+    category: Option<String>,
+    /// Only list classifiers starting with this prefix, ignoring ASCII case
+    #[arg(long)]
+    prefix: Option<String>,
+    /// Only list classifiers matching a query, such as 'category:License AND osi:true'
+    #[arg(long)]
+    query: Option<String>,
+}
+
+pub fn run(args: &Args, out: &mut impl Write) -> Result<()> {
+    let category = match &args.category {
+        Some(name) => Some(category(name)?),
+        None => None,
+    };
+    let query: Option<Query> = match &args.query {
+        Some(query) => Some(
+            query
+                .parse()
+                .map_err(|err| format!("invalid query: {err}"))?,
+        ),
+        None => None,
+    };
+    for classifier in Classifier::ALL {
+        let segments = classifier.segments_static();
+        if category.is_some_and(|category| segments[0] != category) {
+            continue;
+        }
+        if let Some(prefix) = &args.prefix {
+            let string = classifier.as_str().as_bytes();
+            if !string
+                .get(..prefix.len())
+                .is_some_and(|start| start.eq_ignore_ascii_case(prefix.as_bytes()))
+            {
+                continue;
+            }
+        }
+        if query
+            .as_ref()
+            .is_some_and(|query| !query.matches(classifier))
+        {
+            continue;
+        }
+        writeln!(out, "{classifier}").map_err(|err| err.to_string())?;
+    }
+    Ok(())
+}
+
+/// The canonical name of a top-level category, matched ignoring ASCII case
+pub(crate) fn category(name: &str) -> Result<&'static str> {
+    let mut names: Vec<&'static str> = Classifier::ALL
+        .iter()
+        .map(|classifier| classifier.segments_static()[0])
+        .collect();
+    names.dedup();
+    names
+        .iter()
+        .copied()
+        .find(|category| category.eq_ignore_ascii_case(name.trim()))
+        .ok_or_else(|| {
+            format!(
+                "unknown category {name:?}, expected one of: {}",
+                names.join(", ")
+            )
+        })
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    fn list(category: Option<&str>, prefix: Option<&str>) -> Result<String> {
+        query(category, prefix, None)
+    }
+
+    fn query(category: Option<&str>, prefix: Option<&str>, query: Option<&str>) -> Result<String> {
+        let args = Args {
+            category: category.map(str::to_string),
+            prefix: prefix.map(str::to_string),
+            query: query.map(str::to_string),
+        };
+        let mut out = Vec::new();
+        run(&args, &mut out)?;
+        Ok(String::from_utf8(out).unwrap())
+    }
+
+    #[test]
+    fn lists_everything_by_default() {
+        let listed = list(None, None).unwrap();
+        assert_eq!(listed.lines().count(), Classifier::ALL.len());
+        assert!(listed.starts_with("Development Status :: 1 - Planning\n"));
+    }
+
+    #[test]
+    fn filters_by_category_and_prefix() {
+        assert_eq!(
+            list(Some("typing"), None).unwrap(),
+            "Typing :: Stubs Only\nTyping :: Typed\n"
+        );
+        assert_eq!(
+            list(Some("Typing"), Some("typing :: t")).unwrap(),
+            "Typing :: Typed\n"
+        );
+        assert_eq!(list(Some("License"), Some("Typing")).unwrap(), "");
+    }
+
+    #[test]
+    fn lists_in_canonical_order() {
+        let listed = list(None, Some("Framework :: Django :: ")).unwrap();
+        let parsed: Vec<Classifier> = listed.lines().map(|line| line.parse().unwrap()).collect();
+        assert!(!parsed.is_empty());
+        assert!(parsed.windows(2).all(|pair| pair[0] < pair[1]));
+    }
+
+    #[test]
+    fn filters_by_query() {
+        assert_eq!(
+            query(None, None, Some("category:typing NOT leaf:typed")).unwrap(),
+            "Typing :: Stubs Only\n"
+        );
+        assert_eq!(
+            query(None, None, Some("osi:maybe")).unwrap_err(),
+            "invalid query: osi must be true or false, not \"maybe\""
+        );
+    }
+
+    #[test]
+    fn unknown_category_is_an_error() {
+        let err = list(Some("Frameworks"), None).unwrap_err();
+        assert!(err.starts_with("unknown category \"Frameworks\""));
+    }
+}
@@ -0,0 +1,565 @@
+//! `trove lsp`: a language server for the classifiers of a pyproject.toml, over stdio.
+//!
+//! Editors start `trove lsp` and speak the Language Server Protocol to it. Within the strings of
+//! the `[project] classifiers` array it offers:
+//!
+//! * completion, a segment at a time, replacing the whole string with the completed classifier
+//! * hover, with the documentation `trove_classifiers::export::documentation` gives a classifier
+//! * diagnostics, the findings of `trove check` under the document's `[tool.trove]` policy
+//!
+//! Documents are synchronized in full on every change, which is cheap for a pyproject.toml.
+//! Other documents are kept for completion and hover, but are never checked.
+
+use std::collections::HashMap;
+use std::io::{self, BufRead, Write};
+use std::ops::Range;
+
+use serde_json::{json, Value};
+use trove_classifiers::lint::{Declared, Severity};
+use trove_classifiers::policy::Policy;
+use trove_classifiers::pyproject;
+use trove_classifiers::{complete_segment, export};
+
+use crate::Result;
+
+/// The JSON-RPC error code of a request for a method the server does not implement
+const METHOD_NOT_FOUND: i64 = -32601;
+/// The JSON-RPC error code of a request made after `shutdown`
+const INVALID_REQUEST: i64 = -32600;
+/// The JSON-RPC error code of a message that is not JSON
+const PARSE_ERROR: i64 = -32700;
+
+#[derive(clap::Args)]
+pub struct Args {}
+
+pub fn run(_args: &Args, out: &mut impl Write) -> Result<()> {
+    let mut input = io::stdin().lock();
+    let mut server = Server::default();
+    while let Some(body) = read_message(&mut input).map_err(|err| err.to_string())? {
+        let replies = match serde_json::from_slice::<Value>(&body) {
+            Ok(message) => server.handle(&message),
+            Err(err) => vec![error(Value::Null, PARSE_ERROR, &err.to_string())],
+        };
+        for reply in &replies {
+            write_message(out, reply).map_err(|err| err.to_string())?;
+        }
+        if server.exited {
+            if !server.shut_down {
+                return Err("exit was requested before shutdown".to_string());
+            }
+            return Ok(());
+        }
+    }
+    Ok(())
+}
+
+/// The body of the next message, or `None` at the end of the input
+fn read_message(input: &mut impl BufRead) -> io::Result<Option<Vec<u8>>> {
+    let mut length = None;
+    let mut line = String::new();
+    loop {
+        line.clear();
+        if input.read_line(&mut line)? == 0 {
+            return match length {
+                None => Ok(None),
+                Some(_) => Err(io::ErrorKind::UnexpectedEof.into()),
+            };
+        }
+        let header = line.trim_end();
+        if header.is_empty() {
+            break;
+        }
+        if let Some((name, value)) = header.split_once(':') {
+            if name.eq_ignore_ascii_case("Content-Length") {
+                length = value.trim().parse::<usize>().ok();
+            }
+        }
+    }
+    let length = length.ok_or_else(|| {
+        io::Error::new(
+            io::ErrorKind::InvalidData,
+            "a message has no Content-Length",
+        )
+    })?;
+    let mut body = vec![0; length];
+    input.read_exact(&mut body)?;
+    Ok(Some(body))
+}
+
+fn write_message(out: &mut impl Write, message: &Value) -> io::Result<()> {
+    let body = message.to_string();
+    write!(out, "Content-Length: {}\r\n\r\n{body}", body.len())?;
+    out.flush()
+}
+
+fn response(id: Value, result: Value) -> Value {
+    json!({ "jsonrpc": "2.0", "id": id, "result": result })
+}
+
+fn error(id: Value, code: i64, message: &str) -> Value {
+    json!({ "jsonrpc": "2.0", "id": id, "error": { "code": code, "message": message } })
+}
+
+fn notification(method: &str, params: Value) -> Value {
+    json!({ "jsonrpc": "2.0", "method": method, "params": params })
+}
+
+/// The open documents and the state of the session
+#[derive(Default)]
+struct Server {
+    /// The text of each open document, by URI
+    documents: HashMap<String, String>,
+    shut_down: bool,
+    exited: bool,
+}
+
+impl Server {
+    /// The messages answering a message from the client
+    fn handle(&mut self, message: &Value) -> Vec<Value> {
+        // responses to requests of the server's own have no method, and need no answer
+        let Some(method) = message["method"].as_str() else {
+            return Vec::new();
+        };
+        let params = &message["params"];
+        let uri = params["textDocument"]["uri"].as_str().unwrap_or_default();
+        let id = message.get("id").cloned();
+        if self.shut_down && method != "exit" {
+            return id
+                .map(|id| error(id, INVALID_REQUEST, "the server is shut down"))
+                .into_iter()
+                .collect();
+        }
+        let result = match method {
+            "initialize" => json!({
+                "capabilities": {
+                    "textDocumentSync": 1,
+                    "completionProvider": { "triggerCharacters": ["\"", "'", ":", " "] },
+                    "hoverProvider": true,
+                },
+                "serverInfo": { "name": "trove", "version": env!("CARGO_PKG_VERSION") },
+            }),
+            "shutdown" => {
+                self.shut_down = true;
+                Value::Null
+            }
+            "exit" => {
+                self.exited = true;
+                return Vec::new();
+            }
+            "textDocument/didOpen" => {
+                let text = params["textDocument"]["text"].as_str().unwrap_or_default();
+                self.documents.insert(uri.to_string(), text.to_string());
+                return self.publish(uri);
+            }
+            "textDocument/didChange" => {
+                let changes = params["contentChanges"].as_array();
+                let Some(text) = changes.and_then(|changes| changes.last()?["text"].as_str())
+                else {
+                    return Vec::new();
+                };
+                self.documents.insert(uri.to_string(), text.to_string());
+                return self.publish(uri);
+            }
+            "textDocument/didClose" => {
+                self.documents.remove(uri);
+                if !is_pyproject(uri) {
+                    return Vec::new();
+                }
+                return vec![publish_diagnostics(uri, Vec::new())];
+            }
+            "textDocument/completion" | "textDocument/hover" => {
+                let Some(source) = self.documents.get(uri) else {
+                    return id.map(|id| response(id, Value::Null)).into_iter().collect();
+                };
+                let position = &params["position"];
+                let offset = offset(
+                    source,
+                    position["line"].as_u64().unwrap_or_default() as usize,
+                    position["character"].as_u64().unwrap_or_default() as usize,
+                );
+                match method {
+                    "textDocument/completion" => completion(source, offset),
+                    _ => hover(source, offset),
+                }
+            }
+            _ => {
+                return id
+                    .map(|id| error(id, METHOD_NOT_FOUND, &format!("{method} is not supported")))
+                    .into_iter()
+                    .collect()
+            }
+        };
+        id.map(|id| response(id, result)).into_iter().collect()
+    }
+
+    /// The diagnostics of a document, if it is a pyproject.toml
+    fn publish(&self, uri: &str) -> Vec<Value> {
+        match (is_pyproject(uri), self.documents.get(uri)) {
+            (true, Some(source)) => vec![publish_diagnostics(uri, diagnostics(source))],
+            _ => Vec::new(),
+        }
+    }
+}
+
+fn is_pyproject(uri: &str) -> bool {
+    uri.rsplit('/').next() == Some("pyproject.toml")
+}
+
+fn publish_diagnostics(uri: &str, diagnostics: Vec<Value>) -> Value {
+    notification(
+        "textDocument/publishDiagnostics",
+        json!({ "uri": uri, "diagnostics": diagnostics }),
+    )
+}
+
+/// The findings of the classifiers of a pyproject.toml, and any error reading them or the policy
+fn diagnostics(source: &str) -> Vec<Value> {
+    let array = match pyproject::classifiers(source) {
+        Ok(Some(array)) => array,
+        Ok(None) => return Vec::new(),
+        Err(err) => return vec![line_diagnostic(source, &err)],
+    };
+    let mut diagnostics = Vec::new();
+    let policy = Policy::from_pyproject(source).unwrap_or_else(|err| {
+        diagnostics.push(line_diagnostic(source, &err));
+        None
+    });
+    let policy = policy.unwrap_or_default();
+    let declared: Vec<Declared> = array.entries.iter().map(|entry| entry.declared()).collect();
+    for finding in policy.linter().check(&policy.context(&declared)) {
+        // a finding without a declaration of its own is about the whole array
+        let span = finding
+            .line
+            .and_then(|line| {
+                array
+                    .entries
+                    .iter()
+                    .find(|entry| entry.line == line && entry.value == finding.value)
+            })
+            .map_or(array.span.clone(), |entry| entry.span.clone());
+        diagnostics.push(json!({
+            "range": range(source, span),
+            "severity": match finding.severity {
+                Severity::Error => 1,
+                Severity::Warning => 2,
+            },
+            "code": finding.rule.code(),
+            "source": "trove",
+            "message": finding.message,
+        }));
+    }
+    diagnostics
+}
+
+/// An error covering the line it was found on
+fn line_diagnostic(source: &str, err: &impl std::fmt::Display) -> Value {
+    let message = err.to_string();
+    let (line, message) = message
+        .strip_prefix("line ")
+        .and_then(|rest| rest.split_once(": "))
+        .and_then(|(line, message)| Some((line.parse::<usize>().ok()?, message)))
+        .unwrap_or((1, message.as_str()));
+    let start = offset(source, line.saturating_sub(1), 0);
+    let end = source[start..]
+        .find('\n')
+        .map_or(source.len(), |end| start + end);
+    json!({
+        "range": range(source, start..end),
+        "severity": 1,
+        "source": "trove",
+        "message": message,
+    })
+}
+
+/// The completions of the classifier string the cursor at `offset` is in
+fn completion(source: &str, offset: usize) -> Value {
+    let Some((content, prefix)) = string_at(source, offset) else {
+        return Value::Null;
+    };
+    let replaced = range(source, content..offset);
+    let items: Vec<Value> = complete_segment(&prefix)
+        .iter()
+        .enumerate()
+        .map(|(index, completion)| {
+            json!({
+                "label": completion.segment,
+                // Value for a classifier, Module for a parent of classifiers only
+                "kind": if completion.classifier.is_some() { 12 } else { 9 },
+                "detail": completion.path,
+                "sortText": format!("{index:05}"),
+                "filterText": completion.path,
+                "textEdit": { "range": replaced, "newText": completion.path },
+            })
+        })
+        .collect();
+    json!({ "isIncomplete": false, "items": items })
+}
+
+/// Where the contents of the classifier string the cursor at `offset` is in start, and what is
+/// written of them before the cursor
+///
+/// The document is cut at the cursor and the string and array closed there, so the strings of an
+/// array being written are found even while the rest of the document is not yet valid.
+fn string_at(source: &str, offset: usize) -> Option<(usize, String)> {
+    let head = &source[..offset];
+    ['"', '\''].into_iter().find_map(|quote| {
+        let array = pyproject::classifiers(&format!("{head}{quote}]")).ok()??;
+        let entry = array.entries.last()?;
+        (entry.span.end == offset + 1 && array.span.end == offset + 2)
+            .then(|| (entry.span.start + 1, entry.value.clone()))
+    })
+}
+
+/// What the classifier string under the cursor at `offset` is
+fn hover(source: &str, offset: usize) -> Value {
+    let Some(entry) = pyproject::classifiers(source)
+        .ok()
+        .flatten()
+        .and_then(|array| {
+            array
+                .entries
+                .into_iter()
+                .find(|entry| entry.span.start <= offset && offset < entry.span.end)
+        })
+    else {
+        return Value::Null;
+    };
+    let about =
+        export::documentation(&entry.value).unwrap_or_else(|| "Not a known classifier".to_string());
+    json!({
+        "contents": { "kind": "markdown", "value": format!("**{}**\n\n{about}", entry.value) },
+        "range": range(source, entry.span),
+    })
+}
+
+/// The byte offset of a position, whose character counts UTF-16 code units as LSP's do
+fn offset(source: &str, line: usize, character: usize) -> usize {
+    let mut start = 0;
+    for _ in 0..line {
+        match source[start..].find('\n') {
+            Some(newline) => start += newline + 1,
+            None => return source.len(),
+        }
+    }
+    let mut units = 0;
+    for (index, c) in source[start..].char_indices() {
+        if units >= character || c == '\n' {
+            return start + index;
+        }
+        units += c.len_utf16();
+    }
+    source.len()
+}
+
+/// The LSP position of a byte offset
+fn position(source: &str, offset: usize) -> Value {
+    let head = &source[..offset];
+    let start = head.rfind('\n').map_or(0, |newline| newline + 1);
+    let character: usize = head[start..].chars().map(char::len_utf16).sum();
+    json!({ "line": head.matches('\n').count(), "character": character })
+}
+
+fn range(source: &str, span: Range<usize>) -> Value {
+    json!({ "start": position(source, span.start), "end": position(source, span.end) })
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    const URI: &str = "file:///project/pyproject.toml";
+
+    fn open(server: &mut Server, text: &str) -> Vec<Value> {
+        server.handle(&json!({
+            "jsonrpc": "2.0",
+            "method": "textDocument/didOpen",
+            "params": { "textDocument": { "uri": URI, "languageId": "toml", "version": 1, "text": text } },
+        }))
+    }
+
+    fn request(server: &mut Server, method: &str, line: usize, character: usize) -> Value {
+        let replies = server.handle(&json!({
+            "jsonrpc": "2.0",
+            "id": 7,
+            "method": method,
+            "params": {
+                "textDocument": { "uri": URI },
+                "position": { "line": line, "character": character },
+            },
+        }));
+        assert_eq!(replies.len(), 1);
+        assert_eq!(replies[0]["id"], 7);
+        replies[0]["result"].clone()
+    }
+
+    #[test]
+    fn positions_count_utf16_code_units() {
+        let source = "a\n\u{e9}\u{1d11e}x\n";
+        assert_eq!(offset(source, 0, 1), 1);
+        assert_eq!(offset(source, 1, 0), 2);
+        assert_eq!(offset(source, 1, 3), 8);
+        assert_eq!(offset(source, 1, 99), 9);
+        assert_eq!(offset(source, 9, 0), source.len());
+        assert_eq!(position(source, 8), json!({"line": 1, "character": 3}));
+        assert_eq!(
+            position(source, source.len()),
+            json!({"line": 2, "character": 0})
+        );
+    }
+
+    #[test]
+    fn frames_messages() {
+        let mut framed = Vec::new();
+        write_message(&mut framed, &json!({"id": 1})).unwrap();
+        assert_eq!(framed, b"Content-Length: 8\r\n\r\n{\"id\":1}");
+        framed.extend_from_slice(b"content-length: 2\r\nContent-Type: x\r\n\r\n[]");
+        let mut input = framed.as_slice();
+        assert_eq!(read_message(&mut input).unwrap().unwrap(), b"{\"id\":1}");
+        assert_eq!(read_message(&mut input).unwrap().unwrap(), b"[]");
+        assert_eq!(read_message(&mut input).unwrap(), None);
+        assert!(read_message(&mut b"Content-Length: 9\r\n\r\n{}".as_slice()).is_err());
+    }
+
+    #[test]
+    fn publishes_findings_on_their_strings() {
+        let mut server = Server::default();
+        let source = "[project]\nclassifiers = [\n  'Natural Language :: Ukranian',\n]\n\n\
+                      [tool.trove]\nrequired = ['Typing :: Typed']\n";
+        let published = open(&mut server, source);
+        assert_eq!(published[0]["method"], "textDocument/publishDiagnostics");
+        assert_eq!(published[0]["params"]["uri"], URI);
+        assert_eq!(
+            published[0]["params"]["diagnostics"],
+            json!([
+                {
+                    "range": {
+                        "start": {"line": 2, "character": 2},
+                        "end": {"line": 2, "character": 32},
+                    },
+                    "severity": 1,
+                    "code": "TC002",
+                    "source": "trove",
+                    "message": diagnostics(source)[0]["message"],
+                },
+                {
+                    "range": {
+                        "start": {"line": 1, "character": 14},
+                        "end": {"line": 3, "character": 1},
+                    },
+                    "severity": 1,
+                    "code": "TC007",
+                    "source": "trove",
+                    "message": diagnostics(source)[1]["message"],
+                },
+            ])
+        );
+
+        let unreadable = diagnostics("[project]\nclassifiers = [\n  1,\n]\n");
+        assert_eq!(
+            unreadable,
+            [json!({
+                "range": {
+                    "start": {"line": 2, "character": 0},
+                    "end": {"line": 2, "character": 4},
+                },
+                "severity": 1,
+                "source": "trove",
+                "message": "project.classifiers holds a value that is not a string",
+            })]
+        );
+        assert!(diagnostics("[project]\nname = 'x'\n").is_empty());
+    }
+
+    #[test]
+    fn completes_strings_of_the_classifiers_array() {
+        let mut server = Server::default();
+        open(
+            &mut server,
+            "[project]\nname = 'Typing'\nclassifiers = [\n  \"Typing :: T\",\n  'typing::\n",
+        );
+        let result = request(&mut server, "textDocument/completion", 3, 14);
+        let items = result["items"].as_array().unwrap();
+        assert_eq!(items.len(), 1);
+        assert_eq!(items[0]["label"], "Typed");
+        assert_eq!(items[0]["kind"], 12);
+        assert_eq!(
+            items[0]["textEdit"],
+            json!({
+                "range": {
+                    "start": {"line": 3, "character": 3},
+                    "end": {"line": 3, "character": 14},
+                },
+                "newText": "Typing :: Typed",
+            })
+        );
+        let result = request(&mut server, "textDocument/completion", 4, 11);
+        assert_eq!(result["items"][0]["detail"], "Typing :: Stubs Only");
+
+        assert_eq!(
+            request(&mut server, "textDocument/completion", 1, 12),
+            Value::Null
+        );
+        assert_eq!(
+            request(&mut server, "textDocument/completion", 3, 1),
+            Value::Null
+        );
+    }
+
+    #[test]
+    fn hovers_over_classifiers() {
+        let mut server = Server::default();
+        open(
+            &mut server,
+            "[project]\nclassifiers = ['Typing :: Typed', 'Natural Language :: Ukranian']\n",
+        );
+        let typed = request(&mut server, "textDocument/hover", 1, 20);
+        assert_eq!(
+            typed["contents"]["value"],
+            "**Typing :: Typed**\n\nThe package ships inline type hints or stubs and a py.typed \
+             marker, as PEP 561 describes.\n\nCategory: Typing\n\n\
+             [Projects on pypi.org](https://pypi.org/search/?c=Typing+%3A%3A+Typed)"
+        );
+        assert_eq!(typed["range"]["start"], json!({"line": 1, "character": 15}));
+        let ukranian = request(&mut server, "textDocument/hover", 1, 40);
+        assert_eq!(
+            ukranian["contents"]["value"],
+            "**Natural Language :: Ukranian**\n\n\
+             Deprecated, use `Natural Language :: Ukrainian` instead"
+        );
+        assert_eq!(
+            request(&mut server, "textDocument/hover", 1, 2),
+            Value::Null
+        );
+    }
+
+    #[test]
+    fn follows_the_session_lifecycle() {
+        let mut server = Server::default();
+        let initialized = server
+            .handle(&json!({"jsonrpc": "2.0", "id": 1, "method": "initialize", "params": {}}));
+        assert_eq!(
+            initialized[0]["result"]["capabilities"]["hoverProvider"],
+            true
+        );
+        let unknown =
+            server.handle(&json!({"jsonrpc": "2.0", "id": 2, "method": "workspace/symbol"}));
+        assert_eq!(unknown[0]["error"]["code"], METHOD_NOT_FOUND);
+        assert!(server
+            .handle(&json!({"jsonrpc": "2.0", "method": "$/cancelRequest"}))
+            .is_empty());
+        let closed = server.handle(&json!({
+            "jsonrpc": "2.0",
+            "method": "textDocument/didClose",
+            "params": { "textDocument": { "uri": URI } },
+        }));
+        assert_eq!(closed[0]["params"]["diagnostics"], json!([]));
+        let shutdown = server.handle(&json!({"jsonrpc": "2.0", "id": 3, "method": "shutdown"}));
+        assert_eq!(shutdown[0]["result"], Value::Null);
+        let late = server.handle(&json!({"jsonrpc": "2.0", "id": 4, "method": "initialize"}));
+        assert_eq!(late[0]["error"]["code"], INVALID_REQUEST);
+        assert!(server
+            .handle(&json!({"jsonrpc": "2.0", "method": "exit"}))
+            .is_empty());
+        assert!(server.exited);
+    }
+}
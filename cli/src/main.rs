@@ -0,0 +1,148 @@
+//! `trove`, a command line tool for exploring and checking Python packaging classifiers.
+//!
+//! Every subcommand works offline against the dataset compiled into trove-classifiers, except
+//! `diff --live`, which needs the `online` feature, enabled by default. The `serve` feature adds
+//! the `serve` subcommand, an HTTP service for platforms that cannot embed the crate, and the `lsp`
+//! feature the `lsp` subcommand, a language server for editors. Without `online` and `serve`, the
+//! CLI builds for `wasm32-wasip1`.
+
+use std::io::{self, Write};
+use std::panic::{self, AssertUnwindSafe};
+use std::process::ExitCode;
+
+use clap::{Parser, Subcommand};
+
+mod badge;
+mod check;
+mod check_dist;
+mod diff;
+mod doctor;
+mod export;
+mod findings;
+mod fix;
+mod grep;
+mod license;
+mod lint;
+mod list;
+#[cfg(feature = "lsp")]
+mod lsp;
+mod matrix;
+mod policy;
+mod project;
+mod search;
+#[cfg(feature = "serve")]
+mod serve;
+mod stats;
+mod suggest;
+mod tree;
+
+type Result<T> = std::result::Result<T, String>;
+
+#[derive(Parser)]
+#[command(name = "trove", version, about)]
+struct Cli {
+    #[command(subcommand)]
+    command: Command,
+}
+
+#[derive(Subcommand)]
+enum Command {
+    /// Print README badges made from a project's Development Status and Python classifiers
+    Badge(badge::Args),
+    /// Check the classifiers declared by project files against pypi.org's upload rules
+    Check(check::Args),
+    /// Check the classifiers of built wheels and sdists against pypi.org's upload rules
+    CheckDist(check_dist::Args),
+    /// List the classifiers added and removed between two versions of the dataset
+    Diff(diff::Args),
+    /// Audit a project's classifiers against its metadata, README and built artifacts
+    Doctor(doctor::Args),
+    /// Dump the dataset, with categories, hierarchy and deprecations, for other toolchains
+    Export(export::Args),
+    /// Tidy the classifiers of a pyproject.toml, printing a diff unless --write is given
+    Fix(fix::Args),
+    /// Find the project files below directories that declare matching classifiers
+    Grep(grep::Args),
+    /// Translate between SPDX license identifiers and License classifiers
+    License(license::Args),
+    /// List the rules check enforces, or explain one with --explain
+    Lint(lint::Args),
+    /// Print classifiers in canonical order
+    List(list::Args),
+    /// Complete, explain and check the classifiers of pyproject.toml files in editors, speaking
+    /// the Language Server Protocol over stdio
+    #[cfg(feature = "lsp")]
+    Lsp(lsp::Args),
+    /// Print the Python versions a project declares for CI matrices, tox or nox, or check a
+    /// tox.ini against them
+    Matrix(matrix::Args),
+    /// Find classifiers by partial names and acronyms, best matches first
+    Search(search::Args),
+    /// Answer validation, search, completion and suggestion requests over HTTP
+    #[cfg(feature = "serve")]
+    Serve(serve::Args),
+    /// Summarise a project's classifiers: categories, Python versions, license, kind and gaps
+    Stats(stats::Args),
+    /// Propose classifiers from the rest of a project's metadata
+    Suggest(suggest::Args),
+    /// Print the classifier hierarchy as an indented tree, an HTML page or a Markdown table
+    Tree(tree::Args),
+}
+
+fn main() -> ExitCode {
+    let cli = match Cli::try_parse() {
+        Ok(cli) => cli,
+        // clap exits with 2 on usage errors, which check documents as a parse error
+        Err(err) if err.use_stderr() && std::env::args().nth(1).as_deref() == Some("check") => {
+            let _ = err.print();
+            return ExitCode::from(check::EXIT_INTERNAL);
+        }
+        Err(err) => err.exit(),
+    };
+    let mut out = io::stdout().lock();
+    // Subcommands exit with 1 on failure, except check, which has its own stable codes
+    let mut code = 1;
+    let result = match cli.command {
+        Command::Badge(args) => badge::run(&args, &mut out),
+        Command::Check(args) => {
+            // once check has run, only failing to write its output is left
+            code = check::EXIT_INTERNAL;
+            let result = panic::catch_unwind(AssertUnwindSafe(|| check::run(&args, &mut out)))
+                .unwrap_or_else(|_| {
+                    Err(check::Failure {
+                        code: check::EXIT_INTERNAL,
+                        message: "internal error".to_string(),
+                    })
+                });
+            result.map_err(|failure| {
+                code = failure.code;
+                failure.message
+            })
+        }
+        Command::CheckDist(args) => check_dist::run(&args, &mut out),
+        Command::Diff(args) => diff::run(&args, &mut out),
+        Command::Doctor(args) => doctor::run(&args, &mut out),
+        Command::Export(args) => export::run(&args, &mut out),
+        Command::Fix(args) => fix::run(&args, &mut out),
+        Command::Grep(args) => grep::run(&args, &mut out),
+        Command::License(args) => license::run(&args, &mut out),
+        Command::Lint(args) => lint::run(&args, &mut out),
+        Command::List(args) => list::run(&args, &mut out),
+        #[cfg(feature = "lsp")]
+        Command::Lsp(args) => lsp::run(&args, &mut out),
+        Command::Matrix(args) => matrix::run(&args, &mut out),
+        Command::Search(args) => search::run(&args, &mut out),
+        #[cfg(feature = "serve")]
+        Command::Serve(args) => serve::run(&args, &mut out),
+        Command::Stats(args) => stats::run(&args, &mut out),
+        Command::Suggest(args) => suggest::run(&args, &mut out),
+        Command::Tree(args) => tree::run(&args, &mut out),
+    };
+    match result.and_then(|()| out.flush().map_err(|err| err.to_string())) {
+        Ok(()) => ExitCode::SUCCESS,
+        Err(err) => {
+            eprintln!("error: {err}");
+            ExitCode::from(code)
+        }
+    }
+}
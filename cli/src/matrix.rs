@@ -0,0 +1,165 @@
+//! `trove matrix`: the Python versions a project declares, as a CI matrix, a tox envlist or nox
+//! session parameters.
+
+use std::fs;
+use std::io::Write;
+use std::path::{Path, PathBuf};
+
+use trove_classifiers::{matrix, Classifier};
+
+use crate::{project, Result};
+
+#[derive(clap::Args)]
+pub struct Args {
+    /// A pyproject.toml, setup.cfg, METADATA or PKG-INFO file
+    #[arg(default_value = "pyproject.toml")]
+    file: PathBuf,
+    /// How to print the versions
+    #[arg(long, value_enum, default_value_t)]
+    format: Format,
+    /// Print `NAME=[...]`, ready to append to $GITHUB_OUTPUT
+    #[arg(long, value_name = "NAME")]
+    output: Option<String>,
+    /// Cross the Python versions with the declared Django versions, for tox and nox
+    #[arg(long)]
+    django: bool,
+    /// Instead of printing, report how the envlist of this tox.ini drifted from the classifiers
+    #[arg(long, value_name = "TOX_INI")]
+    check: Option<PathBuf>,
+}
+
+#[derive(Clone, Copy, Debug, Default, Eq, PartialEq, clap::ValueEnum)]
+enum Format {
+    /// A JSON array of versions, for a GitHub Actions matrix
+    #[default]
+    Github,
+    /// A tox envlist, such as `py39, py310`
+    Tox,
+    /// The decorators of a nox session
+    Nox,
+}
+
+pub fn run(args: &Args, out: &mut impl Write) -> Result<()> {
+    let report = project::classifiers(&args.file)?;
+    if matrix::python_versions(&report.classifiers).is_empty() {
+        return Err(format!(
+            "{} declares no Programming Language :: Python :: X.Y classifiers",
+            args.file.display()
+        ));
+    }
+    if let Some(tox_ini) = &args.check {
+        return check(tox_ini, &report.classifiers, out);
+    }
+    let versions = match args.format {
+        Format::Github => matrix::github_actions(&report.classifiers),
+        Format::Tox => matrix::tox_envlist(&report.classifiers, args.django).join(", "),
+        Format::Nox => {
+            let parameters = matrix::nox_parameters(&report.classifiers, args.django);
+            return out
+                .write_all(parameters.as_bytes())
+                .map_err(|err| err.to_string());
+        }
+    };
+    match &args.output {
+        Some(name) => writeln!(out, "{name}={versions}"),
+        None => writeln!(out, "{versions}"),
+    }
+    .map_err(|err| err.to_string())
+}
+
+fn check(tox_ini: &Path, classifiers: &[Classifier], out: &mut impl Write) -> Result<()> {
+    let source = fs::read_to_string(tox_ini)
+        .map_err(|err| format!("reading {}: {err}", tox_ini.display()))?;
+    let drift = matrix::tox_drift(&source, classifiers);
+    let write = |out: &mut dyn Write| -> std::io::Result<()> {
+        for factor in &drift.missing {
+            writeln!(
+                out,
+                "{}: no environment tests {factor}, which is declared",
+                tox_ini.display()
+            )?;
+        }
+        for factor in &drift.extra {
+            writeln!(
+                out,
+                "{}: {factor} is tested, but not declared",
+                tox_ini.display()
+            )?;
+        }
+        Ok(())
+    };
+    write(out).map_err(|err| err.to_string())?;
+    if drift.is_empty() {
+        Ok(())
+    } else {
+        Err(format!(
+            "{} drifted from the classifiers",
+            tox_ini.display()
+        ))
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    fn matrix(classifiers: &str, output: Option<&str>) -> Result<String> {
+        let path = std::env::temp_dir().join(format!(
+            "trove-matrix-{}-{output:?}-pyproject.toml",
+            std::process::id()
+        ));
+        std::fs::write(&path, format!("[project]\nclassifiers = [{classifiers}]\n")).unwrap();
+        let args = Args {
+            file: path.clone(),
+            format: Format::Github,
+            output: output.map(str::to_string),
+            django: false,
+            check: None,
+        };
+        let mut out = Vec::new();
+        let result = run(&args, &mut out);
+        std::fs::remove_file(&path).unwrap();
+        result.map(|()| String::from_utf8(out).unwrap())
+    }
+
+    #[test]
+    fn prints_the_declared_versions() {
+        let classifiers = "'Programming Language :: Python :: 3.12', \
+                           'Programming Language :: Python :: 3.9'";
+        assert_eq!(matrix(classifiers, None).unwrap(), "[\"3.9\",\"3.12\"]\n");
+        assert_eq!(
+            matrix(classifiers, Some("python")).unwrap(),
+            "python=[\"3.9\",\"3.12\"]\n"
+        );
+        assert!(matrix("'Typing :: Typed'", None).is_err());
+    }
+
+    #[test]
+    fn reports_drift_from_tox_ini() {
+        let dir = std::env::temp_dir().join(format!("trove-matrix-{}-tox", std::process::id()));
+        std::fs::create_dir_all(&dir).unwrap();
+        let pyproject = dir.join("pyproject.toml");
+        let tox_ini = dir.join("tox.ini");
+        std::fs::write(
+            &pyproject,
+            "[project]\nclassifiers = ['Programming Language :: Python :: 3.12']\n",
+        )
+        .unwrap();
+        std::fs::write(&tox_ini, "[tox]\nenvlist = py311, py312\n").unwrap();
+        let args = Args {
+            file: pyproject,
+            format: Format::Tox,
+            output: None,
+            django: false,
+            check: Some(tox_ini.clone()),
+        };
+        let mut out = Vec::new();
+        let result = run(&args, &mut out);
+        std::fs::remove_dir_all(&dir).unwrap();
+        assert!(result.is_err());
+        assert_eq!(
+            String::from_utf8(out).unwrap(),
+            format!("{}: py311 is tested, but not declared\n", tox_ini.display())
+        );
+    }
+}
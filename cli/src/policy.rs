@@ -0,0 +1,60 @@
+//! The policy `trove check` and `trove fix` follow.
+
+use std::fs;
+use std::path::Path;
+
+use trove_classifiers::policy::{self, Policy};
+
+use crate::Result;
+
+/// The policy of `config`, a trove.toml or the `[tool.trove]` table of a pyproject.toml, or
+/// without one, of the trove.toml or pyproject.toml of the current directory
+pub fn load(config: Option<&Path>) -> Result<Policy> {
+    let path = match config {
+        Some(path) => path,
+        None if Path::new(policy::FILE_NAME).is_file() => Path::new(policy::FILE_NAME),
+        None if Path::new("pyproject.toml").is_file() => Path::new("pyproject.toml"),
+        None => return Ok(Policy::default()),
+    };
+    let source =
+        fs::read_to_string(path).map_err(|err| format!("reading {}: {err}", path.display()))?;
+    read(path, &source).map_err(|err| format!("{}: {err}", path.display()))
+}
+
+fn read(path: &Path, source: &str) -> std::result::Result<Policy, policy::PolicyError> {
+    if path
+        .file_name()
+        .is_some_and(|name| name == "pyproject.toml")
+    {
+        Ok(Policy::from_pyproject(source)?.unwrap_or_default())
+    } else {
+        Policy::from_toml(source)
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn reads_trove_toml_or_pyproject() {
+        let dir = std::env::temp_dir().join(format!("trove-policy-{}", std::process::id()));
+        fs::create_dir_all(&dir).unwrap();
+        let trove = dir.join("trove.toml");
+        fs::write(&trove, "forbidden = ['Private']\n").unwrap();
+        let pyproject = dir.join("pyproject.toml");
+        fs::write(&pyproject, "[tool.trove]\nforbidden = ['Typing']\n").unwrap();
+        let invalid = dir.join("invalid.toml");
+        fs::write(&invalid, "forbid = []\n").unwrap();
+
+        let from_trove = load(Some(&trove));
+        let from_pyproject = load(Some(&pyproject));
+        let from_invalid = load(Some(&invalid));
+        fs::remove_dir_all(&dir).unwrap();
+        assert_eq!(from_trove.unwrap().forbidden, ["Private"]);
+        assert_eq!(from_pyproject.unwrap().forbidden, ["Typing"]);
+        assert!(from_invalid
+            .unwrap_err()
+            .ends_with("invalid.toml: line 1: \"forbid\" is not a policy setting"));
+    }
+}
@@ -0,0 +1,212 @@
+//! Reading the classifiers declared by a project's files.
+
+use std::fs;
+use std::path::Path;
+
+use trove_classifiers::scanner::{Report, Scanner, UnknownClassifier};
+use trove_classifiers::suggest::Project;
+use trove_classifiers::{pyproject, setup_cfg};
+
+use crate::Result;
+
+/// The classifiers declared by a `pyproject.toml` or `setup.cfg`, or by a METADATA or PKG-INFO
+/// file
+///
+/// Files are told apart by their extension: anything other than `.toml` or `.cfg` is read as
+/// core metadata.
+pub fn classifiers(path: &Path) -> Result<Report> {
+    let source =
+        fs::read_to_string(path).map_err(|err| format!("reading {}: {err}", path.display()))?;
+    match path.extension().and_then(|extension| extension.to_str()) {
+        Some("toml") => {
+            pyproject_report(&source).map_err(|err| format!("{}: {err}", path.display()))
+        }
+        Some("cfg") => Ok(setup_cfg_report(&source)),
+        _ => Ok(Scanner::new().scan_str(&source)),
+    }
+}
+
+/// The names READMEs conventionally have, in the order they are looked for
+pub(crate) const READMES: &[&str] = &["README.md", "README.rst", "README.txt", "README"];
+
+/// The names license files conventionally have, in the order they are looked for
+const LICENSE_FILES: [&str; 5] = [
+    "LICENSE",
+    "LICENSE.txt",
+    "LICENSE.md",
+    "LICENSE.rst",
+    "COPYING",
+];
+
+/// What a `pyproject.toml` says about its project
+///
+/// A `py.typed` marker is looked for in the packages next to the file, and in a `src/` layout,
+/// and the README and license file next to it are read.
+pub fn project(path: &Path) -> Result<Project> {
+    let source =
+        fs::read_to_string(path).map_err(|err| format!("reading {}: {err}", path.display()))?;
+    let mut project = parse_project(&source).map_err(|err| format!("{}: {err}", path.display()))?;
+    let root = path.parent().unwrap_or(Path::new("."));
+    project.typed = [root.to_path_buf(), root.join("src")]
+        .iter()
+        .filter_map(|dir| fs::read_dir(dir).ok())
+        .flatten()
+        .filter_map(|entry| entry.ok())
+        .any(|entry| entry.path().join("py.typed").is_file());
+    project.readme = READMES
+        .iter()
+        .find_map(|name| fs::read_to_string(root.join(name)).ok());
+    project.license_text = LICENSE_FILES
+        .iter()
+        .find_map(|name| fs::read_to_string(root.join(name)).ok());
+    Ok(project)
+}
+
+pub(crate) fn parse_project(source: &str) -> std::result::Result<Project, String> {
+    let document: toml::Table = source
+        .parse()
+        .map_err(|err: toml::de::Error| err.to_string())?;
+    let table = document
+        .get("project")
+        .and_then(|project| project.as_table());
+    let string = |key: &str| {
+        table
+            .and_then(|table| table.get(key))
+            .and_then(|value| value.as_str())
+            .map(str::to_string)
+    };
+    let license = match table.and_then(|table| table.get("license")) {
+        Some(toml::Value::String(expression)) => Some(expression.clone()),
+        Some(toml::Value::Table(license)) => license
+            .get("text")
+            .and_then(|text| text.as_str())
+            .map(str::to_string),
+        _ => None,
+    };
+    let dependencies = table
+        .and_then(|table| table.get("dependencies"))
+        .and_then(|dependencies| dependencies.as_array())
+        .map(|dependencies| {
+            dependencies
+                .iter()
+                .filter_map(|dependency| dependency.as_str())
+                .map(str::to_string)
+                .collect()
+        })
+        .unwrap_or_default();
+    let keywords = table
+        .and_then(|table| table.get("keywords"))
+        .and_then(|keywords| keywords.as_array())
+        .map(|keywords| {
+            keywords
+                .iter()
+                .filter_map(|keyword| keyword.as_str())
+                .map(str::to_string)
+                .collect()
+        })
+        .unwrap_or_default();
+    let classifiers = pyproject_report(source).map_err(|err| err.to_string())?;
+    Ok(Project {
+        name: string("name"),
+        version: string("version"),
+        requires_python: string("requires-python"),
+        license,
+        dependencies,
+        classifiers: classifiers.classifiers,
+        typed: false,
+        license_text: None,
+        readme: None,
+        keywords,
+        description: string("description"),
+    })
+}
+
+fn pyproject_report(source: &str) -> std::result::Result<Report, pyproject::PyprojectError> {
+    let mut report = Report::default();
+    for entry in pyproject::classifiers(source)?.map_or_else(Vec::new, |array| array.entries) {
+        match entry.classifier() {
+            Ok(classifier) => report.classifiers.push(classifier),
+            Err(_) => report.unknown.push(UnknownClassifier {
+                line: entry.line,
+                value: entry.value,
+            }),
+        }
+    }
+    Ok(report)
+}
+
+fn setup_cfg_report(source: &str) -> Report {
+    let mut report = Report::default();
+    for declared in setup_cfg::declared(source) {
+        match declared.value.parse() {
+            Ok(classifier) => report.classifiers.push(classifier),
+            Err(_) => report.unknown.push(UnknownClassifier {
+                line: declared.line.unwrap_or_default(),
+                value: declared.value,
+            }),
+        }
+    }
+    report
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use trove_classifiers::Classifier;
+
+    #[test]
+    fn reads_project_metadata() {
+        let project = parse_project(
+            "[project]\n\
+             name = 'example'\n\
+             version = '0.4.0'\n\
+             description = 'An example'\n\
+             keywords = ['django']\n\
+             requires-python = '>=3.10'\n\
+             license = { text = 'MIT' }\n\
+             dependencies = ['django>=5', 'requests']\n\
+             classifiers = ['Typing :: Typed', 'Typing :: Untyped']\n",
+        )
+        .unwrap();
+        assert_eq!(
+            project,
+            Project {
+                name: Some("example".to_string()),
+                version: Some("0.4.0".to_string()),
+                requires_python: Some(">=3.10".to_string()),
+                license: Some("MIT".to_string()),
+                dependencies: vec!["django>=5".to_string(), "requests".to_string()],
+                classifiers: vec![Classifier::Typing__Typed],
+                typed: false,
+                license_text: None,
+                readme: None,
+                keywords: vec!["django".to_string()],
+                description: Some("An example".to_string()),
+            }
+        );
+    }
+
+    #[test]
+    fn reads_pyproject_classifiers() {
+        let report = pyproject_report(
+            "[project]\nclassifiers = [\n  'Typing :: Typed',\n  'Typing :: Untyped',\n]\n",
+        )
+        .unwrap();
+        assert_eq!(report.classifiers, [Classifier::Typing__Typed]);
+        assert_eq!(
+            report.unknown,
+            [UnknownClassifier {
+                line: 4,
+                value: "Typing :: Untyped".to_string()
+            }]
+        );
+    }
+
+    #[test]
+    fn reads_setup_cfg_classifiers() {
+        let report =
+            setup_cfg_report("[metadata]\nclassifiers =\n  Typing :: Typed\n  Typing :: Untyped\n");
+        assert_eq!(report.classifiers, [Classifier::Typing__Typed]);
+        assert_eq!(report.unknown[0].line, 4);
+    }
+}
@@ -0,0 +1,71 @@
+//! `trove search`: find classifiers by partial names and acronyms.
+
+use std::io::Write;
+
+use serde_json::json;
+use trove_classifiers::search_ranked;
+
+use crate::Result;
+
+#[derive(clap::Args)]
+pub struct Args {
+    /// Words to look for, each matching a substring or acronym of a classifier
+    #[arg(required = true)]
+    query: Vec<String>,
+    /// Print at most this many matches
+    #[arg(long, default_value_t = 20)]
+    limit: usize,
+    /// Print the matches and their scores as a JSON array
+    #[arg(long)]
+    json: bool,
+}
+
+pub fn run(args: &Args, out: &mut impl Write) -> Result<()> {
+    let query = args.query.join(" ");
+    let matches = search_ranked(&query);
+    let matches = &matches[..matches.len().min(args.limit)];
+    if args.json {
+        let matches: Vec<_> = matches
+            .iter()
+            .map(|found| json!({"classifier": found.classifier.as_str(), "score": found.score}))
+            .collect();
+        serde_json::to_writer_pretty(&mut *out, &matches).map_err(|err| err.to_string())?;
+        writeln!(out).map_err(|err| err.to_string())?;
+    } else {
+        for found in matches {
+            writeln!(out, "{}", found.classifier).map_err(|err| err.to_string())?;
+        }
+    }
+    Ok(())
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    fn search(query: &str, limit: usize, json: bool) -> String {
+        let args = Args {
+            query: query.split(' ').map(str::to_string).collect(),
+            limit,
+            json,
+        };
+        let mut out = Vec::new();
+        run(&args, &mut out).unwrap();
+        String::from_utf8(out).unwrap()
+    }
+
+    #[test]
+    fn prints_best_matches_first() {
+        assert_eq!(search("django", 1, false), "Framework :: Django\n");
+        assert_eq!(search("typing", 5, false).lines().count(), 2);
+    }
+
+    #[test]
+    fn prints_json() {
+        let printed: serde_json::Value = serde_json::from_str(&search("stubs", 20, true)).unwrap();
+        assert_eq!(
+            printed,
+            json!([{"classifier": "Typing :: Stubs Only", "score": 3}])
+        );
+    }
+}
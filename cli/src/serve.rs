@@ -0,0 +1,287 @@
+//! `trove serve`: answer validation, search, completion and suggestion requests over HTTP.
+//!
+//! Every endpoint answers with JSON:
+//!
+//! * `GET /validate?classifier=...`, repeatable, or `POST /validate` with a JSON array of
+//!   classifiers
+//! * `GET /search?q=...&limit=20`
+//! * `GET /complete?prefix=...&limit=20`
+//! * `POST /suggest` with the `[project]` table of a pyproject.toml as a JSON object, plus an
+//!   optional `"typed"` boolean for the presence of a `py.typed` marker
+//!
+//! Requests are served one at a time, which is plenty for a validator answering in microseconds.
+
+use std::io::{Read, Write};
+use std::str::FromStr;
+
+use serde_json::{json, Value};
+use tiny_http::{Header, Method, Response, Server};
+use trove_classifiers::suggest::suggest;
+use trove_classifiers::url::{decode, DecodeError};
+use trove_classifiers::{complete, deprecation, search_ranked, Classifier};
+
+use crate::{project, Result};
+
+/// Bodies larger than this are rejected
+const MAX_BODY: u64 = 1 << 20;
+
+/// How many results `/search` and `/complete` return without a `limit`
+const DEFAULT_LIMIT: usize = 20;
+
+#[derive(clap::Args)]
+pub struct Args {
+    /// The address to listen on
+    #[arg(long, default_value = "127.0.0.1:8000")]
+    address: String,
+}
+
+pub fn run(args: &Args, out: &mut impl Write) -> Result<()> {
+    let server = Server::http(&args.address)
+        .map_err(|err| format!("listening on {}: {err}", args.address))?;
+    writeln!(out, "listening on http://{}", args.address).map_err(|err| err.to_string())?;
+    out.flush().map_err(|err| err.to_string())?;
+    for mut request in server.incoming_requests() {
+        let mut body = String::new();
+        let read = request
+            .as_reader()
+            .take(MAX_BODY + 1)
+            .read_to_string(&mut body);
+        let (status, value) = match read {
+            Err(err) => (400, error(&format!("reading the body: {err}"))),
+            Ok(_) if body.len() as u64 > MAX_BODY => (413, error("the body is too large")),
+            Ok(_) => handle(request.method(), request.url(), &body),
+        };
+        let response = Response::from_string(value.to_string())
+            .with_status_code(status)
+            .with_header(
+                Header::from_bytes("Content-Type", "application/json").expect("a valid header"),
+            );
+        if let Err(err) = request.respond(response) {
+            eprintln!("warning: answering a request: {err}");
+        }
+    }
+    Ok(())
+}
+
+fn error(message: &str) -> Value {
+    json!({ "error": message })
+}
+
+/// The status and JSON body answering a request
+fn handle(method: &Method, url: &str, body: &str) -> (u16, Value) {
+    let (path, query) = url.split_once('?').unwrap_or((url, ""));
+    let query = match parse_query(query) {
+        Ok(query) => query,
+        Err(err) => return (400, error(&format!("invalid query string: {err}"))),
+    };
+    let param = |name: &str| {
+        query
+            .iter()
+            .find(|(key, _)| key == name)
+            .map(|(_, value)| value.as_str())
+    };
+    // only the routes answering with a list of matches take a limit
+    let limit = || match param("limit").map(usize::from_str) {
+        None => Ok(DEFAULT_LIMIT),
+        Some(Ok(limit)) => Ok(limit),
+        Some(Err(_)) => Err((400, error("limit must be a non-negative integer"))),
+    };
+    match (method, path) {
+        (Method::Get, "/validate") => validate(
+            query
+                .iter()
+                .filter(|(key, _)| key == "classifier")
+                .map(|(_, value)| value.clone())
+                .collect(),
+        ),
+        (Method::Post, "/validate") => match serde_json::from_str(body) {
+            Ok(classifiers) => validate(classifiers),
+            Err(err) => (
+                400,
+                error(&format!("expected a JSON array of strings: {err}")),
+            ),
+        },
+        (Method::Get, "/search") => {
+            let limit = match limit() {
+                Ok(limit) => limit,
+                Err(response) => return response,
+            };
+            let matches: Vec<Value> = search_ranked(param("q").unwrap_or(""))
+                .iter()
+                .take(limit)
+                .map(|found| json!({"classifier": found.classifier.as_str(), "score": found.score}))
+                .collect();
+            (200, Value::from(matches))
+        }
+        (Method::Get, "/complete") => {
+            let limit = match limit() {
+                Ok(limit) => limit,
+                Err(response) => return response,
+            };
+            let completions: Vec<&str> = complete(param("prefix").unwrap_or(""))
+                .take(limit)
+                .map(|classifier| classifier.as_str())
+                .collect();
+            (200, json!(completions))
+        }
+        (Method::Post, "/suggest") => suggestions(body),
+        (_, "/validate" | "/search" | "/complete" | "/suggest") => {
+            (405, error("method not allowed"))
+        }
+        _ => (404, error("not found")),
+    }
+}
+
+fn validate(classifiers: Vec<String>) -> (u16, Value) {
+    let results: Vec<Value> = classifiers
+        .iter()
+        .map(|value| match deprecation(value) {
+            _ if Classifier::from_str(value).is_ok() => {
+                json!({"classifier": value, "valid": true})
+            }
+            Some(deprecated) => json!({
+                "classifier": value,
+                "valid": false,
+                "replacements": deprecated.replacements().map(|c| c.as_str()).collect::<Vec<_>>(),
+            }),
+            None => json!({"classifier": value, "valid": false}),
+        })
+        .collect();
+    let valid = results.iter().all(|result| result["valid"] == true);
+    (200, json!({"valid": valid, "results": results}))
+}
+
+fn suggestions(body: &str) -> (u16, Value) {
+    let mut table = match serde_json::from_str::<Value>(body) {
+        Ok(Value::Object(table)) => table,
+        Ok(_) => return (400, error("expected a JSON object")),
+        Err(err) => return (400, error(&format!("expected a JSON object: {err}"))),
+    };
+    let typed = table.remove("typed").is_some_and(|typed| typed == true);
+    // the [project] table is read exactly as `trove suggest` reads a pyproject.toml
+    let source = toml::Value::try_from(json!({ "project": table }))
+        .map_err(|err| err.to_string())
+        .and_then(|document| toml::to_string(&document).map_err(|err| err.to_string()))
+        .and_then(|source| project::parse_project(&source));
+    let mut project = match source {
+        Ok(project) => project,
+        Err(err) => return (400, error(&format!("reading the project: {err}"))),
+    };
+    project.typed = typed;
+    let suggestions: Vec<Value> = suggest(&project)
+        .iter()
+        .map(|suggestion| {
+            json!({
+                "classifier": suggestion.classifier.as_str(),
+                "reason": suggestion.reason.to_string(),
+            })
+        })
+        .collect();
+    (200, Value::from(suggestions))
+}
+
+/// The decoded pairs of an `application/x-www-form-urlencoded` query string
+fn parse_query(query: &str) -> std::result::Result<Vec<(String, String)>, DecodeError> {
+    query
+        .split('&')
+        .filter(|pair| !pair.is_empty())
+        .map(|pair| {
+            let (key, value) = pair.split_once('=').unwrap_or((pair, ""));
+            Ok((decode(key)?, decode(value)?))
+        })
+        .collect()
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn validates_query_and_body() {
+        let (status, value) = handle(
+            &Method::Get,
+            "/validate?classifier=Typing+%3A%3A+Typed&classifier=Natural%20Language%20::%20Ukranian",
+            "",
+        );
+        assert_eq!(status, 200);
+        assert_eq!(
+            value,
+            json!({
+                "valid": false,
+                "results": [
+                    {"classifier": "Typing :: Typed", "valid": true},
+                    {
+                        "classifier": "Natural Language :: Ukranian",
+                        "valid": false,
+                        "replacements": ["Natural Language :: Ukrainian"],
+                    },
+                ],
+            })
+        );
+
+        let (status, value) = handle(&Method::Post, "/validate", r#"["Typing :: Typed"]"#);
+        assert_eq!(status, 200);
+        assert_eq!(value["valid"], true);
+        assert_eq!(handle(&Method::Post, "/validate", "{}").0, 400);
+        assert_eq!(handle(&Method::Get, "/validate?classifier=%+1", "").0, 400);
+        assert_eq!(handle(&Method::Get, "/validate?classifier=%FF", "").0, 400);
+    }
+
+    #[test]
+    fn searches_and_completes() {
+        let (status, value) = handle(&Method::Get, "/search?q=django&limit=1", "");
+        assert_eq!(status, 200);
+        assert_eq!(
+            value,
+            json!([{"classifier": "Framework :: Django", "score": 4}])
+        );
+
+        let (status, value) = handle(&Method::Get, "/complete?prefix=typing%20::%20", "");
+        assert_eq!(status, 200);
+        assert_eq!(value, json!(["Typing :: Stubs Only", "Typing :: Typed"]));
+
+        assert_eq!(handle(&Method::Get, "/complete?limit=many", "").0, 400);
+        assert_eq!(handle(&Method::Get, "/search?limit=-1", "").0, 400);
+        assert_eq!(handle(&Method::Get, "/validate?limit=many", "").0, 200);
+    }
+
+    #[test]
+    fn suggests_from_project_tables() {
+        let (status, value) = handle(
+            &Method::Post,
+            "/suggest",
+            r#"{"name": "example", "license": "MIT", "typed": true,
+                "classifiers": ["Operating System :: OS Independent"]}"#,
+        );
+        assert_eq!(status, 200);
+        assert_eq!(
+            value,
+            json!([
+                {"classifier": "License :: OSI Approved :: MIT License", "reason": "matches the license"},
+                {"classifier": "Typing :: Typed", "reason": "ships a py.typed marker"},
+            ])
+        );
+        assert_eq!(handle(&Method::Post, "/suggest", "[]").0, 400);
+    }
+
+    #[test]
+    fn unknown_routes_are_errors() {
+        assert_eq!(handle(&Method::Get, "/", "").0, 404);
+        assert_eq!(handle(&Method::Delete, "/search", "").0, 405);
+    }
+
+    #[test]
+    fn decodes_query_strings() {
+        assert_eq!(
+            parse_query("q=C%2B%2B+tools&flag").unwrap(),
+            [
+                ("q".to_string(), "C++ tools".to_string()),
+                ("flag".to_string(), String::new()),
+            ]
+        );
+        assert_eq!(
+            parse_query("q=ok&x=%zz"),
+            Err(DecodeError::InvalidEscape(0))
+        );
+    }
+}
@@ -0,0 +1,153 @@
+//! `trove stats`: summarise the classifiers a project declares.
+
+use std::fmt::Display;
+use std::io::Write;
+use std::path::PathBuf;
+
+use serde_json::json;
+use trove_classifiers::summary::Summary;
+
+use crate::{project, Result};
+
+#[derive(clap::Args)]
+pub struct Args {
+    /// A pyproject.toml, setup.cfg, METADATA or PKG-INFO file
+    #[arg(default_value = "pyproject.toml")]
+    file: PathBuf,
+    /// Print the summary as a JSON object
+    #[arg(long)]
+    json: bool,
+}
+
+pub fn run(args: &Args, out: &mut impl Write) -> Result<()> {
+    let report = project::classifiers(&args.file)?;
+    let summary = Summary::new(&report.classifiers);
+    let unknown = report.unknown.len();
+    if args.json {
+        json(&summary, unknown, out)
+    } else {
+        table(&summary, unknown, out)
+    }
+    .map_err(|err| err.to_string())
+}
+
+fn json(summary: &Summary, unknown: usize, out: &mut impl Write) -> std::io::Result<()> {
+    let categories: serde_json::Map<_, _> = summary
+        .categories
+        .iter()
+        .map(|(name, count)| (name.to_string(), json!(count)))
+        .collect();
+    let families: Vec<&str> = summary
+        .license_families
+        .iter()
+        .map(|family| family.as_str())
+        .collect();
+    let value = json!({
+        "total": summary.total,
+        "unknown": unknown,
+        "categories": categories,
+        "python_versions": summary.python_versions,
+        "license_families": families,
+        "kind": summary.kind.as_str(),
+        "missing": summary.missing,
+    });
+    serde_json::to_writer_pretty(&mut *out, &value)?;
+    writeln!(out)
+}
+
+fn table(summary: &Summary, unknown: usize, out: &mut impl Write) -> std::io::Result<()> {
+    let mut rows = vec![("classifiers".to_string(), summary.total.to_string())];
+    if unknown > 0 {
+        rows.push(("unknown".to_string(), unknown.to_string()));
+    }
+    rows.extend(
+        summary
+            .categories
+            .iter()
+            .map(|(name, count)| (format!("  {name}"), count.to_string())),
+    );
+    rows.push((
+        "python versions".to_string(),
+        list(&summary.python_versions),
+    ));
+    rows.push(("license".to_string(), list(&summary.license_families)));
+    rows.push(("kind".to_string(), summary.kind.to_string()));
+    rows.push(("missing".to_string(), list(&summary.missing)));
+    let width = rows.iter().map(|(label, _)| label.len()).max().unwrap_or(0);
+    for (label, value) in rows {
+        writeln!(out, "{label:width$}  {value}")?;
+    }
+    Ok(())
+}
+
+/// Items separated by commas, or "none"
+fn list<T: Display>(items: &[T]) -> String {
+    if items.is_empty() {
+        return "none".to_string();
+    }
+    items
+        .iter()
+        .map(|item| item.to_string())
+        .collect::<Vec<_>>()
+        .join(", ")
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    fn stats(json: bool) -> String {
+        let path = std::env::temp_dir().join(format!(
+            "trove-stats-{}-{json}-pyproject.toml",
+            std::process::id()
+        ));
+        std::fs::write(
+            &path,
+            "[project]\nclassifiers = [\n\
+             'License :: OSI Approved :: MIT License',\n\
+             'Programming Language :: Python :: 3.12',\n\
+             'Programming Language :: Python :: 3.9',\n\
+             'Typing :: Typed',\n\
+             'Typing :: Sometimes',\n]\n",
+        )
+        .unwrap();
+        let args = Args {
+            file: path.clone(),
+            json,
+        };
+        let mut out = Vec::new();
+        let result = run(&args, &mut out);
+        std::fs::remove_file(&path).unwrap();
+        result.unwrap();
+        String::from_utf8(out).unwrap()
+    }
+
+    #[test]
+    fn prints_a_table() {
+        assert_eq!(
+            stats(false),
+            "classifiers             4\n\
+             unknown                 1\n\
+             \x20 License               1\n\
+             \x20 Programming Language  2\n\
+             \x20 Typing                1\n\
+             python versions         3.9, 3.12\n\
+             license                 permissive\n\
+             kind                    library\n\
+             missing                 Development Status, Intended Audience, Operating \
+             System, Topic\n"
+        );
+    }
+
+    #[test]
+    fn prints_json() {
+        let summary: serde_json::Value = serde_json::from_str(&stats(true)).unwrap();
+        assert_eq!(summary["total"], 4);
+        assert_eq!(summary["unknown"], 1);
+        assert_eq!(summary["categories"]["Programming Language"], 2);
+        assert_eq!(summary["python_versions"], json!(["3.9", "3.12"]));
+        assert_eq!(summary["license_families"], json!(["permissive"]));
+        assert_eq!(summary["kind"], "library");
+        assert_eq!(summary["missing"][0], "Development Status");
+    }
+}
@@ -0,0 +1,79 @@
+//! `trove suggest`: propose classifiers from the rest of a project's metadata.
+
+use std::io::Write;
+use std::path::PathBuf;
+
+use serde_json::json;
+use trove_classifiers::suggest::suggest;
+
+use crate::{project, Result};
+
+#[derive(clap::Args)]
+pub struct Args {
+    /// The pyproject.toml of the project
+    #[arg(long, default_value = "pyproject.toml")]
+    pyproject: PathBuf,
+    /// Print the suggestions and their reasons as a JSON array
+    #[arg(long)]
+    json: bool,
+}
+
+pub fn run(args: &Args, out: &mut impl Write) -> Result<()> {
+    let project = project::project(&args.pyproject)?;
+    let suggestions = suggest(&project);
+    if args.json {
+        let suggestions: Vec<_> = suggestions
+            .iter()
+            .map(|suggestion| {
+                json!({
+                    "classifier": suggestion.classifier.as_str(),
+                    "reason": suggestion.reason.to_string(),
+                })
+            })
+            .collect();
+        serde_json::to_writer_pretty(&mut *out, &suggestions).map_err(|err| err.to_string())?;
+        writeln!(out).map_err(|err| err.to_string())?;
+    } else {
+        for suggestion in &suggestions {
+            writeln!(out, "{}  # {}", suggestion.classifier, suggestion.reason)
+                .map_err(|err| err.to_string())?;
+        }
+    }
+    Ok(())
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn prints_suggestions_with_reasons() {
+        let root = std::env::temp_dir().join(format!("trove-suggest-{}", std::process::id()));
+        std::fs::create_dir_all(root.join("src").join("example")).unwrap();
+        std::fs::write(root.join("src").join("example").join("py.typed"), "").unwrap();
+        let pyproject = root.join("pyproject.toml");
+        std::fs::write(
+            &pyproject,
+            "[project]\nname = 'example'\nrequires-python = '>=3.14'\nlicense = 'MIT'\n\
+             classifiers = ['Operating System :: OS Independent']\n",
+        )
+        .unwrap();
+
+        let args = Args {
+            pyproject,
+            json: false,
+        };
+        let mut out = Vec::new();
+        let result = run(&args, &mut out);
+        std::fs::remove_dir_all(&root).unwrap();
+        result.unwrap();
+        assert_eq!(
+            String::from_utf8(out).unwrap(),
+            "License :: OSI Approved :: MIT License  # matches the license\n\
+             Programming Language :: Python :: 3  # admitted by requires-python\n\
+             Programming Language :: Python :: 3 :: Only  # admitted by requires-python\n\
+             Programming Language :: Python :: 3.14  # admitted by requires-python\n\
+             Typing :: Typed  # ships a py.typed marker\n"
+        );
+    }
+}
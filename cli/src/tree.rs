@@ -0,0 +1,192 @@
+//! `trove tree`: print the classifier hierarchy as an indented tree.
+
+use std::io::Write;
+use std::path::PathBuf;
+
+use trove_classifiers::{export, Classifier};
+
+use crate::{project, Result};
+
+#[derive(clap::Args)]
+pub struct Args {
+    /// Only print the subtree of classifiers starting with this prefix, ignoring ASCII case
+    prefix: Option<String>,
+    /// Only print the classifiers declared by this pyproject.toml, setup.cfg, METADATA or PKG-INFO file
+    #[arg(long)]
+    file: Option<PathBuf>,
+    /// How to print the tree
+    #[arg(long, value_enum, default_value_t)]
+    format: Format,
+}
+
+#[derive(Clone, Copy, Debug, Default, Eq, PartialEq, clap::ValueEnum)]
+enum Format {
+    /// One segment per line, indented by depth
+    #[default]
+    Text,
+    /// A standalone HTML page with a collapsible tree and a filter box
+    Html,
+    /// A Markdown table grouped by top-level category
+    Markdown,
+}
+
+/// A segment of the hierarchy and the segments nested below it, in canonical order
+#[derive(Default)]
+struct Node {
+    name: &'static str,
+    children: Vec<Node>,
+}
+
+impl Node {
+    fn insert(&mut self, segments: &[&'static str]) {
+        let Some((first, rest)) = segments.split_first() else {
+            return;
+        };
+        let index = match self.children.iter().position(|child| child.name == *first) {
+            Some(index) => index,
+            None => {
+                self.children.push(Node {
+                    name: first,
+                    children: Vec::new(),
+                });
+                self.children.len() - 1
+            }
+        };
+        self.children[index].insert(rest);
+    }
+
+    fn render(&self, depth: usize, out: &mut impl Write) -> std::io::Result<()> {
+        for child in &self.children {
+            writeln!(out, "{:indent$}{}", "", child.name, indent = depth * 2)?;
+            child.render(depth + 1, out)?;
+        }
+        Ok(())
+    }
+}
+
+pub fn run(args: &Args, out: &mut impl Write) -> Result<()> {
+    let classifiers: Vec<Classifier> = match &args.file {
+        Some(path) => {
+            let report = project::classifiers(path)?;
+            for unknown in &report.unknown {
+                eprintln!(
+                    "warning: {}:{}: unknown classifier {:?}",
+                    path.display(),
+                    unknown.line,
+                    unknown.value
+                );
+            }
+            let mut declared = report.classifiers;
+            declared.sort();
+            declared.dedup();
+            declared
+        }
+        None => Classifier::ALL.to_vec(),
+    };
+    let classifiers: Vec<Classifier> = classifiers
+        .into_iter()
+        .filter(|classifier| {
+            args.prefix.as_ref().is_none_or(|prefix| {
+                classifier
+                    .as_str()
+                    .as_bytes()
+                    .get(..prefix.len())
+                    .is_some_and(|start| start.eq_ignore_ascii_case(prefix.as_bytes()))
+            })
+        })
+        .collect();
+    let document = match args.format {
+        Format::Text => None,
+        Format::Html => {
+            let title = match &args.file {
+                Some(path) => format!("Classifiers of {}", path.display()),
+                None => "Trove classifiers".to_string(),
+            };
+            Some(export::html(&title, &classifiers, true))
+        }
+        Format::Markdown => Some(export::to_markdown(&classifiers)),
+    };
+    if let Some(document) = document {
+        return out
+            .write_all(document.as_bytes())
+            .map_err(|err| err.to_string());
+    }
+    let mut root = Node::default();
+    for classifier in classifiers {
+        root.insert(classifier.segments_static());
+    }
+    root.render(0, out).map_err(|err| err.to_string())
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    fn tree(prefix: Option<&str>, file: Option<PathBuf>) -> String {
+        let args = Args {
+            prefix: prefix.map(str::to_string),
+            file,
+            format: Format::Text,
+        };
+        let mut out = Vec::new();
+        run(&args, &mut out).unwrap();
+        String::from_utf8(out).unwrap()
+    }
+
+    #[test]
+    fn prints_subtree() {
+        assert_eq!(
+            tree(Some("typing"), None),
+            "Typing\n  Stubs Only\n  Typed\n"
+        );
+        let django = tree(Some("Framework :: Django :: 4"), None);
+        assert!(django.starts_with("Framework\n  Django\n    4\n"));
+    }
+
+    #[test]
+    fn prints_declared_classifiers_only() {
+        let path = std::env::temp_dir().join(format!("trove-tree-{}.toml", std::process::id()));
+        std::fs::write(
+            &path,
+            "[project]\nclassifiers = [\n\
+             'Typing :: Typed',\n\
+             'License :: OSI Approved :: MIT License',\n\
+             'Programming Language :: Python :: 3 :: Only',\n\
+             'Programming Language :: Python :: 3.12',\n\
+             'Programming Language :: Rust',\n\
+             'Typing :: Typed',\n]\n",
+        )
+        .unwrap();
+        let printed = tree(None, Some(path.clone()));
+        std::fs::remove_file(&path).unwrap();
+        assert_eq!(
+            printed,
+            "License\n  OSI Approved\n    MIT License\n\
+             Programming Language\n  Python\n    3\n      Only\n    3.12\n  Rust\n\
+             Typing\n  Typed\n"
+        );
+    }
+
+    #[test]
+    fn prints_documents() {
+        let document = |format: Format| {
+            let args = Args {
+                prefix: Some("Typing".to_string()),
+                file: None,
+                format,
+            };
+            let mut out = Vec::new();
+            run(&args, &mut out).unwrap();
+            String::from_utf8(out).unwrap()
+        };
+        let page = document(Format::Html);
+        assert!(page.contains("<title>Trove classifiers</title>"));
+        assert!(page.contains("<li data-path=\"Typing :: Stubs Only\">Stubs Only</li>"));
+        assert!(!page.contains("Framework"));
+        assert_eq!(
+            document(Format::Markdown),
+            "| Category | Classifier |\n| --- | --- |\n\
+             | Typing | Stubs Only |\n|  | Typed |\n"
+        );
+    }
+}
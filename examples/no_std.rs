@@ -0,0 +1,63 @@
+//! Using trove-classifiers without the standard library or an allocator.
+//!
+//! This example is a `no_std` library rather than a binary so that it builds on any host:
+//!
+//! ```text
+//! cargo build --example no_std --no-default-features --features all-categories
+//! ```
+
+#![no_std]
+
+use core::fmt::{self, Write};
+use core::str::FromStr;
+
+use trove_classifiers::Classifier;
+
+/// A fixed-size buffer that classifiers can be formatted into
+pub struct Buffer<const N: usize> {
+    bytes: [u8; N],
+    len: usize,
+}
+
+impl<const N: usize> Buffer<N> {
+    pub const fn new() -> Self {
+        Buffer {
+            bytes: [0; N],
+            len: 0,
+        }
+    }
+
+    pub fn as_str(&self) -> &str {
+        core::str::from_utf8(&self.bytes[..self.len]).expect("only str is written")
+    }
+}
+
+impl<const N: usize> Default for Buffer<N> {
+    fn default() -> Self {
+        Self::new()
+    }
+}
+
+impl<const N: usize> Write for Buffer<N> {
+    fn write_str(&mut self, s: &str) -> fmt::Result {
+        let end = self.len + s.len();
+        if end > N {
+            return Err(fmt::Error);
+        }
+        self.bytes[self.len..end].copy_from_slice(s.as_bytes());
+        self.len = end;
+        Ok(())
+    }
+}
+
+/// Whether `classifier` is known to pypi.org
+pub fn is_valid(classifier: &str) -> bool {
+    Classifier::from_str(classifier).is_ok()
+}
+
+/// Write the top-level category of a classifier, such as `License`, into `out`
+pub fn write_category<W: Write>(classifier: &str, out: &mut W) -> fmt::Result {
+    let classifier = Classifier::from_str(classifier).map_err(|_| fmt::Error)?;
+    let category = classifier.segments_static()[0];
+    write!(out, "{category}")
+}
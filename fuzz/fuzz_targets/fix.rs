@@ -0,0 +1,14 @@
+#![no_main]
+
+use libfuzzer_sys::fuzz_target;
+use trove_classifiers::fix::{fix, Options};
+use trove_classifiers::Classifier;
+
+// One declared classifier per line; fixing what was fixed must change nothing
+fuzz_target!(|s: &str| {
+    let options = Options::default().add_parents(true);
+    let fixed = fix(s.lines(), &options);
+    let again = fix(fixed.classifiers.iter().map(Classifier::as_str), &options);
+    assert!(again.is_unchanged(), "{:?}", again.changes);
+    assert_eq!(again.classifiers, fixed.classifiers);
+});
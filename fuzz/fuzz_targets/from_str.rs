@@ -0,0 +1,13 @@
+#![no_main]
+
+use std::str::FromStr;
+
+use libfuzzer_sys::fuzz_target;
+use trove_classifiers::Classifier;
+
+fuzz_target!(|s: &str| {
+    if let Ok(classifier) = Classifier::from_str(s) {
+        assert_eq!(classifier.as_str(), s);
+    }
+    let _ = Classifier::from_str_strict(s);
+});
@@ -0,0 +1,10 @@
+#![no_main]
+
+use libfuzzer_sys::fuzz_target;
+use trove_classifiers::Classifier;
+
+fuzz_target!(|s: &str| {
+    if let Ok(classifier) = Classifier::from_str_lenient(s) {
+        assert_eq!(Classifier::from_str_lenient(classifier.as_str()), Ok(classifier));
+    }
+});
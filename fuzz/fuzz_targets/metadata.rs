@@ -0,0 +1,13 @@
+#![no_main]
+
+use libfuzzer_sys::fuzz_target;
+use trove_classifiers::scanner::Scanner;
+
+fuzz_target!(|data: &[u8]| {
+    let mut scanner = Scanner::new();
+    let _ = scanner.scan_reader(data);
+    if let Ok(s) = std::str::from_utf8(data) {
+        let _ = scanner.scan_str(s);
+        let _ = scanner.declared_str(s);
+    }
+});
@@ -0,0 +1,17 @@
+#![no_main]
+
+use libfuzzer_sys::fuzz_target;
+use trove_classifiers::policy::Policy;
+use trove_classifiers::{pyproject, setup_cfg};
+
+fuzz_target!(|s: &str| {
+    if let Ok(Some(array)) = pyproject::classifiers(s) {
+        assert!(s.get(array.span.clone()).is_some());
+        for entry in &array.entries {
+            assert!(s.get(entry.span.clone()).is_some());
+        }
+    }
+    let _ = Policy::from_pyproject(s);
+    let _ = Policy::from_toml(s);
+    let _ = setup_cfg::declared(s);
+});
@@ -0,0 +1,258 @@
+//! Shorthands for classifiers, registered at runtime.
+//!
+//! Organizations have their own names for the classifiers they use most, such as `mit` or
+//! `py312`. An `Aliases` registry maps such shorthands to classifiers. Each alias is checked when
+//! it is registered, so a typo in a registry is reported once rather than on every lookup.
+//! `Aliases::parse` accepts them as well as everything `Classifier::from_str_lenient` accepts,
+//! `fix` expands the aliases given in its `Options`, and a lint `Context` given them reports each
+//! declared alias as `Rule::NonCanonical`, naming the classifier to write instead.
+//!
+//! Registries can be read from text with one `shorthand = classifier` line per alias:
+//!
+//! ```text
+//! # house shorthands
+//! mit = License :: OSI Approved :: MIT License
+//! py312 = Programming Language :: Python :: 3.12
+//! ```
+//!
+//! # Examples
+//!
+#![cfg_attr(not(feature = "typing"), doc = "```ignore")]
+#![cfg_attr(feature = "typing", doc = "```")]
+//! use trove_classifiers::alias::Aliases;
+//! use trove_classifiers::Classifier;
+//!
+//! let mut aliases = Aliases::new();
+//! aliases.insert("typed", "Typing :: Typed")?;
+//! assert_eq!(aliases.parse("Typed"), Ok(Classifier::Typing__Typed));
+//! assert_eq!(aliases.parse("typing :: typed"), Ok(Classifier::Typing__Typed));
+//! assert!(aliases.insert("untyped", "Typing :: Untyped").is_err());
+//! # Ok::<(), trove_classifiers::alias::AliasError>(())
+//! ```
+
+use alloc::boxed::Box;
+use alloc::string::{String, ToString};
+use alloc::vec::Vec;
+use core::fmt;
+use core::str::FromStr;
+
+use crate::lenient::lenient;
+use crate::{deprecation, Classifier, ParseError};
+
+/// A registry of shorthands for classifiers
+///
+/// Shorthands are matched ignoring ASCII case and the whitespace around and within them.
+#[derive(Clone, Debug, Default, Eq, Hash, PartialEq)]
+pub struct Aliases {
+    /// Normalized shorthands and their classifiers, sorted by shorthand
+    entries: Vec<(String, Classifier)>,
+}
+
+/// The error returned when an alias cannot be registered
+#[derive(Clone, Debug, Eq, PartialEq)]
+#[non_exhaustive]
+pub enum AliasError {
+    /// The classifier an alias stands for is not known
+    UnknownClassifier(String),
+    /// The shorthand is empty, or already names a classifier without the alias
+    InvalidShorthand(String),
+    /// The shorthand is already an alias of another classifier
+    Conflict {
+        shorthand: String,
+        existing: Classifier,
+    },
+    /// A line is not `shorthand = classifier`
+    Syntax,
+    /// A line of a registry read from text cannot be registered
+    Line { line: usize, error: Box<AliasError> },
+}
+
+impl fmt::Display for AliasError {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        match self {
+            AliasError::UnknownClassifier(classifier) => {
+                write!(f, "{classifier:?} is not a known classifier")
+            }
+            AliasError::InvalidShorthand(shorthand) if shorthand.trim().is_empty() => {
+                f.write_str("an alias cannot be empty")
+            }
+            AliasError::InvalidShorthand(shorthand) => {
+                write!(f, "{shorthand:?} is already a classifier")
+            }
+            AliasError::Conflict {
+                shorthand,
+                existing,
+            } => write!(
+                f,
+                "{shorthand:?} is already an alias of {:?}",
+                existing.as_str()
+            ),
+            AliasError::Syntax => f.write_str("expected `shorthand = classifier`"),
+            AliasError::Line { line, error } => write!(f, "line {line}: {error}"),
+        }
+    }
+}
+
+#[cfg(feature = "std")]
+impl std::error::Error for AliasError {}
+
+impl Aliases {
+    /// An empty registry
+    pub fn new() -> Self {
+        Aliases::default()
+    }
+
+    /// Register `shorthand` as an alias of the classifier whose canonical string is `classifier`
+    ///
+    /// Registering the same alias twice is allowed.
+    pub fn insert(&mut self, shorthand: &str, classifier: &str) -> Result<(), AliasError> {
+        let target = Classifier::from_str(classifier.trim())
+            .map_err(|_| AliasError::UnknownClassifier(classifier.to_string()))?;
+        let key = normalize(shorthand);
+        if key.is_empty() || lenient(shorthand).is_ok() || deprecation(shorthand.trim()).is_some() {
+            return Err(AliasError::InvalidShorthand(shorthand.to_string()));
+        }
+        match self
+            .entries
+            .binary_search_by(|(existing, _)| existing.as_str().cmp(&key))
+        {
+            Ok(index) if self.entries[index].1 == target => Ok(()),
+            Ok(index) => Err(AliasError::Conflict {
+                shorthand: shorthand.to_string(),
+                existing: self.entries[index].1,
+            }),
+            Err(index) => {
+                self.entries.insert(index, (key, target));
+                Ok(())
+            }
+        }
+    }
+
+    /// The classifier `shorthand` is an alias of
+    pub fn get(&self, shorthand: &str) -> Option<Classifier> {
+        let key = normalize(shorthand);
+        self.entries
+            .binary_search_by(|(existing, _)| existing.as_str().cmp(&key))
+            .ok()
+            .map(|index| self.entries[index].1)
+    }
+
+    /// Parse a classifier written by hand or as one of these aliases
+    ///
+    /// This is `Classifier::from_str_lenient`, falling back to the aliases.
+    pub fn parse(&self, s: &str) -> Result<Classifier, ParseError> {
+        let parsed = lenient(s).or_else(|err| self.get(s).ok_or(err));
+        #[cfg(feature = "telemetry")]
+        if parsed.is_err() {
+            crate::telemetry::unknown(s);
+        }
+        parsed
+    }
+
+    /// Every alias, with normalized shorthands, ordered by shorthand
+    pub fn iter(&self) -> impl Iterator<Item = (&str, Classifier)> {
+        self.entries
+            .iter()
+            .map(|(shorthand, classifier)| (shorthand.as_str(), *classifier))
+    }
+
+    pub fn len(&self) -> usize {
+        self.entries.len()
+    }
+
+    pub fn is_empty(&self) -> bool {
+        self.entries.is_empty()
+    }
+}
+
+/// Reads one `shorthand = classifier` line per alias, skipping blank lines and `#` comments
+impl FromStr for Aliases {
+    type Err = AliasError;
+
+    fn from_str(s: &str) -> Result<Self, Self::Err> {
+        let mut aliases = Aliases::new();
+        for (index, line) in s.lines().enumerate() {
+            let line = line.trim();
+            if line.is_empty() || line.starts_with('#') {
+                continue;
+            }
+            let result = match line.split_once('=') {
+                Some((shorthand, classifier)) => aliases.insert(shorthand, classifier),
+                None => Err(AliasError::Syntax),
+            };
+            result.map_err(|error| AliasError::Line {
+                line: index + 1,
+                error: Box::new(error),
+            })?;
+        }
+        Ok(aliases)
+    }
+}
+
+/// A shorthand lowercased, with its whitespace runs collapsed to single spaces
+fn normalize(shorthand: &str) -> String {
+    shorthand
+        .split_whitespace()
+        .map(str::to_ascii_lowercase)
+        .collect::<Vec<_>>()
+        .join(" ")
+}
+
+#[cfg(all(test, feature = "typing"))]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn aliases_are_validated_on_registration() {
+        let mut aliases = Aliases::new();
+        aliases.insert("Typed  Code", "Typing :: Typed").unwrap();
+        aliases.insert("typed code", "Typing :: Typed").unwrap();
+        assert_eq!(aliases.len(), 1);
+        assert_eq!(
+            aliases.insert("TYPED CODE", "Typing :: Stubs Only"),
+            Err(AliasError::Conflict {
+                shorthand: "TYPED CODE".to_string(),
+                existing: Classifier::Typing__Typed,
+            })
+        );
+        assert_eq!(
+            aliases.insert("typing::typed", "Typing :: Stubs Only"),
+            Err(AliasError::InvalidShorthand("typing::typed".to_string()))
+        );
+        assert!(matches!(
+            aliases.insert("Natural Language :: Ukranian", "Typing :: Typed"),
+            Err(AliasError::InvalidShorthand(_))
+        ));
+        assert!(matches!(
+            aliases.insert(" ", "Typing :: Typed"),
+            Err(AliasError::InvalidShorthand(_))
+        ));
+        assert_eq!(
+            aliases.insert("stubs", "typing :: stubs only"),
+            Err(AliasError::UnknownClassifier(
+                "typing :: stubs only".to_string()
+            ))
+        );
+        assert_eq!(aliases.get(" typed\tcode"), Some(Classifier::Typing__Typed));
+        assert_eq!(aliases.parse("stubs"), Err(ParseError::Unknown));
+    }
+
+    #[test]
+    fn registries_are_read_from_text() {
+        let aliases: Aliases =
+            "# house style\n\nstubs = Typing :: Stubs Only\ntyped=Typing :: Typed\n"
+                .parse()
+                .unwrap();
+        assert_eq!(
+            aliases.iter().collect::<Vec<_>>(),
+            [
+                ("stubs", Classifier::Typing__StubsOnly),
+                ("typed", Classifier::Typing__Typed)
+            ]
+        );
+        let err = "typed = Typing :: Typed\nuntyped\n"
+            .parse::<Aliases>()
+            .unwrap_err();
+        assert_eq!(err.to_string(), "line 2: expected `shorthand = classifier`");
+    }
+}
@@ -0,0 +1,166 @@
+//! Conversions between classifiers and Arrow dictionary arrays.
+//!
+//! Columns of classifiers repeat a few hundred strings across millions of rows, which Arrow
+//! stores as a `DictionaryArray`: a small array of distinct strings and a key into it per row.
+//! `to_dictionary` builds such a column whose dictionary is indexed by stable code (see
+//! `Classifier::code`), so every column it builds shares one dictionary and its keys are the
+//! codes themselves. `from_dictionary` reads any dictionary of strings back, however it was
+//! encoded.
+//!
+//! # Examples
+//!
+//! ```
+//! use trove_classifiers::arrow::{from_dictionary, to_dictionary};
+//! use trove_classifiers::Classifier;
+//!
+//! let column = to_dictionary([Some(Classifier::Typing__Typed), None]);
+//! assert_eq!(column.keys().value(0), Classifier::Typing__Typed.code());
+//! assert_eq!(
+//!     from_dictionary(&column)?,
+//!     [Some(Classifier::Typing__Typed), None]
+//! );
+//! # Ok::<(), trove_classifiers::arrow::DictionaryError>(())
+//! ```
+
+use std::fmt;
+use std::str::FromStr;
+use std::sync::{Arc, OnceLock};
+
+use arrow_array::types::{ArrowDictionaryKeyType, UInt16Type};
+use arrow_array::{Array, ArrayRef, DictionaryArray, StringArray, UInt16Array};
+
+use crate::generated::RETIRED_CODES;
+use crate::Classifier;
+
+/// The error returned when a dictionary array cannot be read as classifiers
+#[derive(Clone, Debug, Eq, PartialEq)]
+#[non_exhaustive]
+pub enum DictionaryError {
+    /// The values of the dictionary are not a `StringArray`
+    NotStrings,
+    /// A row holds a string that is not a known classifier
+    Unknown(String),
+}
+
+impl fmt::Display for DictionaryError {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        match self {
+            DictionaryError::NotStrings => f.write_str("the dictionary values are not strings"),
+            DictionaryError::Unknown(value) => write!(f, "{value:?} is not a known classifier"),
+        }
+    }
+}
+
+impl std::error::Error for DictionaryError {}
+
+/// The canonical string of every enabled classifier at the index of its stable code, and nulls
+/// at the codes of no enabled classifier, including 0
+pub fn dictionary() -> ArrayRef {
+    static DICTIONARY: OnceLock<ArrayRef> = OnceLock::new();
+    DICTIONARY
+        .get_or_init(|| {
+            let len = Classifier::ALL
+                .iter()
+                .map(Classifier::code)
+                .chain(RETIRED_CODES.iter().copied())
+                .max()
+                .map_or(1, |code| usize::from(code) + 1);
+            let mut strings = vec![None; len];
+            for classifier in Classifier::ALL {
+                strings[usize::from(classifier.code())] = Some(classifier.as_str());
+            }
+            Arc::new(StringArray::from(strings))
+        })
+        .clone()
+}
+
+/// A dictionary encoded column of classifiers, keyed by stable code into `dictionary`
+///
+/// Rows of `None` are null.
+pub fn to_dictionary<I>(classifiers: I) -> DictionaryArray<UInt16Type>
+where
+    I: IntoIterator,
+    I::Item: Into<Option<Classifier>>,
+{
+    let keys: UInt16Array = classifiers
+        .into_iter()
+        .map(|classifier| classifier.into().map(|classifier| classifier.code()))
+        .collect();
+    DictionaryArray::try_new(keys, dictionary()).expect("every code is within the dictionary")
+}
+
+/// The classifiers of a dictionary encoded column of strings, with any key type
+///
+/// Rows that are null, or whose dictionary value is null, are `None`. Only the dictionary
+/// values that rows refer to need to be known classifiers.
+pub fn from_dictionary<K: ArrowDictionaryKeyType>(
+    array: &DictionaryArray<K>,
+) -> Result<Vec<Option<Classifier>>, DictionaryError> {
+    let values = array
+        .values()
+        .as_any()
+        .downcast_ref::<StringArray>()
+        .ok_or(DictionaryError::NotStrings)?;
+    // Dictionaries are much shorter than their columns, so each value is parsed once
+    let parsed: Vec<Option<Result<Classifier, &str>>> = (0..values.len())
+        .map(|index| {
+            let value = values.is_valid(index).then(|| values.value(index))?;
+            Some(Classifier::from_str(value).map_err(|_| value))
+        })
+        .collect();
+    (0..array.len())
+        .map(|row| match array.key(row).and_then(|key| parsed[key]) {
+            None => Ok(None),
+            Some(Ok(classifier)) => Ok(Some(classifier)),
+            Some(Err(value)) => Err(DictionaryError::Unknown(value.to_string())),
+        })
+        .collect()
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use arrow_array::types::Int32Type;
+    use arrow_array::Int32Array;
+
+    #[test]
+    fn columns_round_trip() {
+        let column = to_dictionary(Classifier::ALL.iter().copied());
+        assert_eq!(column.len(), Classifier::ALL.len());
+        assert_eq!(column.null_count(), 0);
+        let decoded = from_dictionary(&column).unwrap();
+        assert!(decoded
+            .iter()
+            .zip(Classifier::ALL)
+            .all(|(decoded, classifier)| *decoded == Some(*classifier)));
+        assert!(dictionary().is_null(0));
+    }
+
+    #[test]
+    #[cfg(feature = "typing")]
+    fn foreign_dictionaries_are_parsed() {
+        let values =
+            StringArray::from(vec![Some("Typing :: Typed"), Some("Typing :: Often"), None]);
+        let read = |keys: Vec<Option<i32>>| {
+            let array = DictionaryArray::<Int32Type>::try_new(
+                Int32Array::from(keys),
+                Arc::new(values.clone()),
+            )
+            .unwrap();
+            from_dictionary(&array)
+        };
+        assert_eq!(
+            read(vec![Some(0), None, Some(2), Some(0)]),
+            Ok(vec![
+                Some(Classifier::Typing__Typed),
+                None,
+                None,
+                Some(Classifier::Typing__Typed)
+            ])
+        );
+        assert_eq!(
+            read(vec![Some(1)]),
+            Err(DictionaryError::Unknown("Typing :: Often".to_string()))
+        );
+    }
+}
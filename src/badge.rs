@@ -0,0 +1,216 @@
+//! README badges describing a project's declared classifiers.
+//!
+//! Badges are built from the classifiers themselves, so they cannot drift from the project's
+//! metadata: the `Development Status` classifier gives a status badge and the `X.Y` Python
+//! versions give a python badge. Each `Badge` renders as a [shields.io](https://shields.io)
+//! static badge URL or as a standalone flat SVG that needs no third-party service.
+//!
+//! # Examples
+//!
+#![cfg_attr(not(feature = "all-categories"), doc = "```ignore")]
+#![cfg_attr(feature = "all-categories", doc = "```")]
+//! use trove_classifiers::badge::{badges, Kind};
+//! use trove_classifiers::Classifier;
+//!
+//! let badges = badges(&[
+//!     Classifier::DevelopmentStatus__4Beta,
+//!     Classifier::ProgrammingLanguage__Python__3_12,
+//!     Classifier::ProgrammingLanguage__Python__3_11,
+//! ]);
+//! assert_eq!(badges[0].kind, Kind::Status);
+//! assert_eq!(badges[0].url(), "https://img.shields.io/badge/status-beta-yellow");
+//! assert_eq!(
+//!     badges[1].url(),
+//!     "https://img.shields.io/badge/python-3.11%20%7C%203.12-blue"
+//! );
+//! ```
+
+use alloc::format;
+use alloc::string::String;
+use alloc::vec::Vec;
+use core::fmt::Write;
+
+use crate::html;
+use crate::summary::Summary;
+use crate::Classifier;
+
+/// What a badge describes
+#[derive(Clone, Copy, Debug, Eq, Hash, Ord, PartialEq, PartialOrd)]
+#[non_exhaustive]
+pub enum Kind {
+    /// The `Development Status` classifier
+    Status,
+    /// The `Programming Language :: Python :: X.Y` classifiers
+    Python,
+}
+
+impl Kind {
+    /// The label shown on the left of the badge
+    pub fn label(&self) -> &'static str {
+        match self {
+            Kind::Status => "status",
+            Kind::Python => "python",
+        }
+    }
+}
+
+/// A badge: a label on the left, a message on a colored background on the right
+#[derive(Clone, Debug, Eq, Hash, PartialEq)]
+pub struct Badge {
+    pub kind: Kind,
+    pub message: String,
+    /// A shields.io color name, such as `brightgreen`
+    pub color: &'static str,
+}
+
+/// Shields.io color names and the hex colors they render as
+const COLORS: &[(&str, &str)] = &[
+    ("brightgreen", "#4c1"),
+    ("green", "#97ca00"),
+    ("yellow", "#dfb317"),
+    ("orange", "#fe7d37"),
+    ("red", "#e05d44"),
+    ("blue", "#007ec6"),
+    ("lightgrey", "#9f9f9f"),
+];
+
+impl Badge {
+    /// The shields.io static badge URL of this badge
+    pub fn url(&self) -> String {
+        format!(
+            "https://img.shields.io/badge/{}-{}-{}",
+            escape(self.kind.label()),
+            escape(&self.message),
+            self.color
+        )
+    }
+
+    /// A Markdown image of this badge, for a README
+    pub fn markdown(&self) -> String {
+        format!("![{}]({})", self.kind.label(), self.url())
+    }
+
+    /// A flat SVG rendering of this badge
+    ///
+    /// Text widths are estimated from the number of characters, so the result is close to, but
+    /// not exactly, what shields.io draws.
+    pub fn svg(&self) -> String {
+        let label = self.kind.label();
+        let color = COLORS
+            .iter()
+            .find(|(name, _)| *name == self.color)
+            .map_or("#9f9f9f", |(_, hex)| hex);
+        let width = |text: &str| text.chars().count() * 7 + 10;
+        let (left, right) = (width(label), width(&self.message));
+        let total = left + right;
+        let mut svg = String::new();
+        write!(
+            svg,
+            r##"<svg xmlns="http://www.w3.org/2000/svg" width="{total}" height="20" role="img" aria-label="{label}: {message}"><title>{label}: {message}</title><rect width="{left}" height="20" fill="#555"/><rect x="{left}" width="{right}" height="20" fill="{color}"/><g fill="#fff" text-anchor="middle" font-family="Verdana,Geneva,DejaVu Sans,sans-serif" font-size="11"><text x="{label_x}" y="14">{label}</text><text x="{message_x}" y="14">{message}</text></g></svg>"##,
+            message = html::escape(&self.message),
+            label_x = left / 2,
+            message_x = left + right / 2,
+        )
+        .unwrap();
+        svg
+    }
+}
+
+/// The badges the classifiers support, in the order of `Kind`
+pub fn badges(classifiers: &[Classifier]) -> Vec<Badge> {
+    let mut badges = Vec::new();
+    if let Some(badge) = status(classifiers) {
+        badges.push(badge);
+    }
+    let versions = Summary::new(classifiers).python_versions;
+    if !versions.is_empty() {
+        badges.push(Badge {
+            kind: Kind::Python,
+            message: versions.join(" | "),
+            color: "blue",
+        });
+    }
+    badges
+}
+
+/// The status badge of the latest stage among the `Development Status` classifiers
+fn status(classifiers: &[Classifier]) -> Option<Badge> {
+    let stage = classifiers
+        .iter()
+        .filter_map(|classifier| match classifier.segments_static() {
+            ["Development Status", stage] => Some(*stage),
+            _ => None,
+        })
+        .max()?;
+    // Stages are written "5 - Production/Stable"
+    let (number, name) = stage.split_once(" - ").unwrap_or(("", stage));
+    let color = match number {
+        "1" | "2" => "red",
+        "3" => "orange",
+        "4" => "yellow",
+        "5" => "brightgreen",
+        "6" => "green",
+        _ => "lightgrey",
+    };
+    let message = name.rsplit('/').next().unwrap_or(name).to_lowercase();
+    Some(Badge {
+        kind: Kind::Status,
+        message,
+        color,
+    })
+}
+
+/// Escape text for a path segment of a shields.io static badge URL
+fn escape(text: &str) -> String {
+    let mut escaped = String::new();
+    for byte in text.bytes() {
+        match byte {
+            b'-' => escaped.push_str("--"),
+            b'_' => escaped.push_str("__"),
+            b'A'..=b'Z' | b'a'..=b'z' | b'0'..=b'9' | b'.' | b'~' => escaped.push(byte as char),
+            _ => write!(escaped, "%{byte:02X}").unwrap(),
+        }
+    }
+    escaped
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn no_classifiers_have_no_badges() {
+        assert!(badges(&[]).is_empty());
+    }
+
+    #[test]
+    #[cfg(feature = "development-status")]
+    fn status_badges_show_the_latest_stage() {
+        let badges = badges(&[
+            Classifier::DevelopmentStatus__3Alpha,
+            Classifier::DevelopmentStatus__5ProductionStable,
+        ]);
+        assert_eq!(badges.len(), 1);
+        assert_eq!(badges[0].message, "stable");
+        assert_eq!(badges[0].color, "brightgreen");
+        assert_eq!(
+            badges[0].markdown(),
+            "![status](https://img.shields.io/badge/status-stable-brightgreen)"
+        );
+    }
+
+    #[test]
+    fn urls_escape_dashes_and_underscores() {
+        assert_eq!(escape("pre-alpha_1 / 2"), "pre--alpha__1%20%2F%202");
+    }
+
+    #[test]
+    #[cfg(feature = "programming-language")]
+    fn svgs_are_self_contained() {
+        let badges = badges(&[Classifier::ProgrammingLanguage__Python__3_13]);
+        let svg = badges[0].svg();
+        assert!(svg.starts_with(r#"<svg xmlns="http://www.w3.org/2000/svg" width="90""#));
+        assert!(svg.contains(r##"fill="#007ec6""##));
+        assert!(svg.contains("<title>python: 3.13</title>"));
+    }
+}
@@ -0,0 +1,278 @@
+//! Baselines of accepted lint findings.
+//!
+//! A project with many findings cannot always fix them at once. A `Baseline` records the findings
+//! it has today so that a check only fails on findings that are new. Findings are matched by file,
+//! rule and declared value, never by line, so moving declarations around does not invalidate the
+//! baseline; a value declared twice with the same finding needs two entries.
+//!
+//! Baselines are stored as JSON, sorted so that updating one gives a readable diff:
+//!
+//! ```json
+//! {
+//!   "version": 1,
+//!   "findings": [
+//!     {"file": "pyproject.toml", "rule": "TC002", "value": "Natural Language :: Ukranian"}
+//!   ]
+//! }
+//! ```
+//!
+//! # Examples
+//!
+//! ```
+//! use trove_classifiers::baseline::Baseline;
+//! use trove_classifiers::lint::{lint, Declared};
+//!
+//! let old = lint(&[Declared::new("Typing :: Sometimes")]);
+//! let baseline = Baseline::from_findings([("pyproject.toml", old.as_slice())]);
+//! let json = baseline.to_json();
+//!
+//! let baseline = Baseline::from_json(&json)?;
+//! let now = lint(&[Declared::new("Typing :: Sometimes"), Declared::new("Typing :: Often")]);
+//! let new = baseline.new_findings("pyproject.toml", &now);
+//! assert_eq!(new.len(), 1);
+//! assert_eq!(new[0].value, "Typing :: Often");
+//! # Ok::<(), trove_classifiers::baseline::BaselineError>(())
+//! ```
+
+use alloc::string::{String, ToString};
+use alloc::vec::Vec;
+use core::fmt;
+
+use crate::json::{push_string, Json, Parser};
+use crate::lint::{Finding, Rule};
+
+/// The version of the baseline format written by `to_json`
+pub const VERSION: u64 = 1;
+
+/// An accepted finding
+#[derive(Clone, Debug, Eq, Hash, Ord, PartialEq, PartialOrd)]
+pub struct Entry {
+    /// The file the finding is in, as it was given to the check
+    pub file: String,
+    pub rule: Rule,
+    /// The offending declaration
+    pub value: String,
+}
+
+/// The findings a project has accepted
+#[derive(Clone, Debug, Default, Eq, PartialEq)]
+pub struct Baseline {
+    /// The accepted findings, sorted
+    pub entries: Vec<Entry>,
+}
+
+/// The error returned when a baseline cannot be read
+#[derive(Clone, Debug, Eq, PartialEq)]
+#[non_exhaustive]
+pub enum BaselineError {
+    /// The document is not JSON, or not shaped like a baseline
+    Invalid(String),
+    /// The baseline was written by a newer version of the format
+    Version(u64),
+    /// A finding names a rule code this crate does not know
+    UnknownRule(String),
+}
+
+impl fmt::Display for BaselineError {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        match self {
+            BaselineError::Invalid(reason) => write!(f, "invalid baseline: {reason}"),
+            BaselineError::Version(version) => {
+                write!(f, "baseline version {version} is newer than {VERSION}")
+            }
+            BaselineError::UnknownRule(code) => write!(f, "unknown rule {code:?} in baseline"),
+        }
+    }
+}
+
+#[cfg(feature = "std")]
+impl std::error::Error for BaselineError {}
+
+impl Baseline {
+    /// A baseline accepting every finding of each file
+    ///
+    /// Findings of custom rules are left out, since their codes cannot be read back.
+    pub fn from_findings<'a, I>(files: I) -> Self
+    where
+        I: IntoIterator<Item = (&'a str, &'a [Finding])>,
+    {
+        let mut entries: Vec<Entry> = files
+            .into_iter()
+            .flat_map(|(file, findings)| {
+                findings
+                    .iter()
+                    .filter(|finding| !matches!(finding.rule, Rule::Custom(_)))
+                    .map(move |finding| Entry {
+                        file: file.to_string(),
+                        rule: finding.rule,
+                        value: finding.value.clone(),
+                    })
+            })
+            .collect();
+        entries.sort();
+        Baseline { entries }
+    }
+
+    /// The findings of a file that the baseline does not accept, in their order
+    pub fn new_findings(&self, file: &str, findings: &[Finding]) -> Vec<Finding> {
+        let mut accepted: Vec<&Entry> = self
+            .entries
+            .iter()
+            .filter(|entry| entry.file == file)
+            .collect();
+        findings
+            .iter()
+            .filter(|finding| {
+                let position = accepted
+                    .iter()
+                    .position(|entry| entry.rule == finding.rule && entry.value == finding.value);
+                match position {
+                    Some(position) => {
+                        accepted.swap_remove(position);
+                        false
+                    }
+                    None => true,
+                }
+            })
+            .cloned()
+            .collect()
+    }
+
+    /// The baseline as pretty printed JSON, ending with a newline
+    pub fn to_json(&self) -> String {
+        let mut out = String::new();
+        out.push_str("{\n  \"version\": 1,\n  \"findings\": [");
+        for (index, entry) in self.entries.iter().enumerate() {
+            out.push_str(if index == 0 { "\n    " } else { ",\n    " });
+            out.push_str("{\"file\": ");
+            push_string(&mut out, &entry.file);
+            out.push_str(", \"rule\": ");
+            push_string(&mut out, entry.rule.code());
+            out.push_str(", \"value\": ");
+            push_string(&mut out, &entry.value);
+            out.push('}');
+        }
+        if !self.entries.is_empty() {
+            out.push_str("\n  ");
+        }
+        out.push_str("]\n}\n");
+        out
+    }
+
+    /// Read a baseline written by `to_json`
+    pub fn from_json(source: &str) -> Result<Self, BaselineError> {
+        let document = Parser::new(source)
+            .document()
+            .map_err(BaselineError::Invalid)?;
+        let invalid = |reason: &str| BaselineError::Invalid(reason.to_string());
+        let Json::Object(fields) = document else {
+            return Err(invalid("expected an object"));
+        };
+        let field = |fields: &[(String, Json)], name: &str| {
+            fields
+                .iter()
+                .find(|(key, _)| key == name)
+                .map(|(_, value)| value.clone())
+        };
+        match field(&fields, "version") {
+            Some(Json::Number(version)) if version <= VERSION => {}
+            Some(Json::Number(version)) => return Err(BaselineError::Version(version)),
+            _ => return Err(invalid("expected a \"version\" number")),
+        }
+        let Some(Json::Array(findings)) = field(&fields, "findings") else {
+            return Err(invalid("expected a \"findings\" array"));
+        };
+        let mut entries = Vec::new();
+        for finding in findings {
+            let Json::Object(finding) = finding else {
+                return Err(invalid("expected each finding to be an object"));
+            };
+            let string = |name: &str| match field(&finding, name) {
+                Some(Json::String(value)) => Ok(value),
+                _ => Err(BaselineError::Invalid(alloc::format!(
+                    "expected each finding to have a {name:?} string"
+                ))),
+            };
+            let code = string("rule")?;
+            let rule = Rule::find(&code).ok_or(BaselineError::UnknownRule(code))?;
+            entries.push(Entry {
+                file: string("file")?,
+                rule,
+                value: string("value")?,
+            });
+        }
+        entries.sort();
+        Ok(Baseline { entries })
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::lint::{lint, Declared};
+
+    #[test]
+    fn round_trips_through_json() {
+        let findings = lint(&[
+            Declared::new("Typing :: \"Sometimes\"\\"),
+            Declared::new("Private :: Ünïcode"),
+        ]);
+        let baseline = Baseline::from_findings([("a/pyproject.toml", findings.as_slice())]);
+        let json = baseline.to_json();
+        assert_eq!(
+            json,
+            "{\n  \"version\": 1,\n  \"findings\": [\n    \
+             {\"file\": \"a/pyproject.toml\", \"rule\": \"TC001\", \"value\": \"Typing :: \\\"Sometimes\\\"\\\\\"},\n    \
+             {\"file\": \"a/pyproject.toml\", \"rule\": \"TC004\", \"value\": \"Private :: Ünïcode\"}\n  \
+             ]\n}\n"
+        );
+        assert_eq!(Baseline::from_json(&json), Ok(baseline));
+        assert_eq!(
+            Baseline::from_json(&Baseline::default().to_json()),
+            Ok(Baseline::default())
+        );
+    }
+
+    #[test]
+    fn only_new_findings_are_reported() {
+        let twice = [
+            Declared::new("Typing :: Often"),
+            Declared::new("Typing :: Often"),
+        ];
+        let baseline = Baseline::from_findings([("setup.cfg", lint(&twice[..1]).as_slice())]);
+        assert_eq!(baseline.new_findings("setup.cfg", &lint(&twice)).len(), 1);
+        assert_eq!(
+            baseline.new_findings("setup.cfg", &lint(&twice[..1])).len(),
+            0
+        );
+        assert_eq!(
+            baseline.new_findings("PKG-INFO", &lint(&twice[..1])).len(),
+            1
+        );
+    }
+
+    #[test]
+    fn rejects_malformed_baselines() {
+        assert!(matches!(
+            Baseline::from_json("[]"),
+            Err(BaselineError::Invalid(_))
+        ));
+        assert_eq!(
+            Baseline::from_json(r#"{"version": 2, "findings": []}"#),
+            Err(BaselineError::Version(2))
+        );
+        assert_eq!(
+            Baseline::from_json(
+                r#"{"version": 1, "findings": [{"file": "f", "rule": "TC999", "value": "v"}]}"#
+            ),
+            Err(BaselineError::UnknownRule("TC999".to_string()))
+        );
+        assert!(Baseline::from_json(r#"{"version": 1, "findings": [}"#).is_err());
+        assert_eq!(
+            Baseline::from_json(
+                "{\"findings\": [], \"version\": 1, \"note\": [null, true, \"\\u00e9\"]}"
+            ),
+            Ok(Baseline::default())
+        );
+    }
+}
@@ -0,0 +1,339 @@
+//! Guided construction of a project's classifiers.
+//!
+//! `ClassifiersBuilder` assembles the classifiers most projects declare from what a scaffolding
+//! tool asks its user: a development status, a license, the supported Python versions and so on.
+//! Each choice is checked against the dataset, and `build` returns the classifiers in canonical
+//! order, or the first choice that could not be satisfied.
+//!
+//! # Examples
+//!
+#![cfg_attr(not(feature = "all-categories"), doc = "```ignore")]
+#![cfg_attr(feature = "all-categories", doc = "```")]
+//! use trove_classifiers::builder::{ClassifiersBuilder, Status};
+//! use trove_classifiers::Classifier;
+//!
+//! let classifiers = ClassifiersBuilder::new()
+//!     .development_status(Status::Beta)
+//!     .license("MIT")
+//!     .python_range("3.12..=3.13")
+//!     .os_independent()
+//!     .typed()
+//!     .build()?;
+//! assert_eq!(
+//!     classifiers,
+//!     [
+//!         Classifier::DevelopmentStatus__4Beta,
+//!         Classifier::License__OSIApproved__MITLicense,
+//!         Classifier::OperatingSystem__OSIndependent,
+//!         Classifier::ProgrammingLanguage__Python__3,
+//!         Classifier::ProgrammingLanguage__Python__3_12,
+//!         Classifier::ProgrammingLanguage__Python__3_13,
+//!         Classifier::Typing__Typed,
+//!     ]
+//! );
+//! # Ok::<(), trove_classifiers::builder::BuildError>(())
+//! ```
+
+use alloc::format;
+use alloc::string::{String, ToString};
+use alloc::vec::Vec;
+use core::fmt;
+use core::str::FromStr;
+
+use crate::pep440::Release;
+use crate::{license, Classifier};
+
+/// A stage of `Development Status`
+#[derive(Clone, Copy, Debug, Eq, Hash, Ord, PartialEq, PartialOrd)]
+pub enum Status {
+    Planning,
+    PreAlpha,
+    Alpha,
+    Beta,
+    Stable,
+    Mature,
+    Inactive,
+}
+
+impl Status {
+    /// The canonical string of this stage's classifier
+    pub fn as_str(&self) -> &'static str {
+        match self {
+            Status::Planning => "Development Status :: 1 - Planning",
+            Status::PreAlpha => "Development Status :: 2 - Pre-Alpha",
+            Status::Alpha => "Development Status :: 3 - Alpha",
+            Status::Beta => "Development Status :: 4 - Beta",
+            Status::Stable => "Development Status :: 5 - Production/Stable",
+            Status::Mature => "Development Status :: 6 - Mature",
+            Status::Inactive => "Development Status :: 7 - Inactive",
+        }
+    }
+}
+
+/// The error returned when a `ClassifiersBuilder` cannot build its classifiers
+#[derive(Clone, Debug, Eq, PartialEq)]
+#[non_exhaustive]
+pub enum BuildError {
+    /// The SPDX license identifier has no License classifier
+    UnknownLicense(String),
+    /// The Python range is not `X.Y..=X.Z`, `X.Y..X.Z` or `X.Y..`
+    InvalidPythonRange(String),
+    /// No Python version classifier is within the range
+    EmptyPythonRange(String),
+    /// The classifier is in a category whose feature is disabled
+    Unavailable(&'static str),
+}
+
+impl fmt::Display for BuildError {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        match self {
+            BuildError::UnknownLicense(id) => {
+                write!(f, "no License classifier names the license {id:?}")
+            }
+            BuildError::InvalidPythonRange(range) => write!(
+                f,
+                "invalid Python range {range:?}, expected X.Y..=X.Z, X.Y..X.Z or X.Y.."
+            ),
+            BuildError::EmptyPythonRange(range) => {
+                write!(f, "no Python version classifier is within {range:?}")
+            }
+            BuildError::Unavailable(classifier) => write!(
+                f,
+                "{classifier:?} is in a category this build of trove-classifiers leaves out"
+            ),
+        }
+    }
+}
+
+#[cfg(feature = "std")]
+impl std::error::Error for BuildError {}
+
+/// A builder of a project's classifiers
+///
+/// Choices are checked as they are made, but errors are only reported by `build`, so calls can
+/// be chained.
+#[derive(Clone, Debug, Default, Eq, PartialEq)]
+#[must_use]
+pub struct ClassifiersBuilder {
+    classifiers: Vec<Classifier>,
+    status: Option<Status>,
+    /// The first error, which `build` returns
+    error: Option<BuildError>,
+}
+
+impl ClassifiersBuilder {
+    pub fn new() -> Self {
+        ClassifiersBuilder::default()
+    }
+
+    /// Declare a development status, replacing any declared before
+    pub fn development_status(mut self, status: Status) -> Self {
+        self.status = Some(status);
+        self
+    }
+
+    /// Declare the License classifier of an SPDX license identifier, such as `MIT`
+    pub fn license(mut self, id: &str) -> Self {
+        match license::from_spdx(id) {
+            Some(classifier) => self.classifiers.push(classifier),
+            None => self.fail(BuildError::UnknownLicense(id.to_string())),
+        }
+        self
+    }
+
+    /// Declare every Python version of a range, such as `3.9..=3.13`, and their major version
+    ///
+    /// The range is inclusive with `..=` and exclusive with `..`; without an end it runs to the
+    /// newest version in the dataset. Versions the dataset does not have are skipped.
+    pub fn python_range(mut self, range: &str) -> Self {
+        match python_versions(range) {
+            Ok(versions) => self.classifiers.extend(versions),
+            Err(err) => self.fail(err),
+        }
+        self
+    }
+
+    /// Declare "Operating System :: OS Independent"
+    pub fn os_independent(self) -> Self {
+        self.string("Operating System :: OS Independent")
+    }
+
+    /// Declare "Typing :: Typed"
+    pub fn typed(self) -> Self {
+        self.string("Typing :: Typed")
+    }
+
+    /// Declare any other classifier
+    pub fn classifier(mut self, classifier: Classifier) -> Self {
+        self.classifiers.push(classifier);
+        self
+    }
+
+    /// Declare every classifier of a preset, such as `preset::CLI_APP`
+    pub fn preset(mut self, preset: &[Classifier]) -> Self {
+        self.classifiers.extend_from_slice(preset);
+        self
+    }
+
+    /// The declared classifiers, in canonical order and without duplicates
+    pub fn build(self) -> Result<Vec<Classifier>, BuildError> {
+        if let Some(err) = self.error {
+            return Err(err);
+        }
+        let mut classifiers = self.classifiers;
+        if let Some(status) = self.status {
+            classifiers.push(
+                Classifier::from_str(status.as_str())
+                    .map_err(|_| BuildError::Unavailable(status.as_str()))?,
+            );
+        }
+        classifiers.sort_unstable();
+        classifiers.dedup();
+        Ok(classifiers)
+    }
+
+    fn string(mut self, classifier: &'static str) -> Self {
+        match Classifier::from_str(classifier) {
+            Ok(classifier) => self.classifiers.push(classifier),
+            Err(_) => self.fail(BuildError::Unavailable(classifier)),
+        }
+        self
+    }
+
+    fn fail(&mut self, err: BuildError) {
+        self.error.get_or_insert(err);
+    }
+}
+
+/// The classifiers of a Python range and of its major version
+fn python_versions(range: &str) -> Result<Vec<Classifier>, BuildError> {
+    let invalid = || BuildError::InvalidPythonRange(range.to_string());
+    let (start, end) = range.trim().split_once("..").ok_or_else(invalid)?;
+    let series = |version: &str| {
+        Release::parse(version)
+            .filter(|release| release.parts().len() == 2)
+            .map(|release| (release.parts()[0], release.parts()[1]))
+            .ok_or_else(invalid)
+    };
+    let (major, first) = series(start)?;
+    let last = match end.strip_prefix('=') {
+        Some(end) => Some(series(end)?),
+        None if end.trim().is_empty() => None,
+        None => {
+            let (major, minor) = series(end)?;
+            Some((major, minor.checked_sub(1).ok_or_else(invalid)?))
+        }
+    };
+    if last.is_some_and(|(last_major, _)| last_major != major) {
+        return Err(invalid());
+    }
+    let prefix = format!("Programming Language :: Python :: {major}.");
+    let versions: Vec<Classifier> = Classifier::ALL
+        .iter()
+        .copied()
+        .filter(|classifier| {
+            let Some(minor) = classifier.as_str().strip_prefix(prefix.as_str()) else {
+                return false;
+            };
+            minor
+                .parse::<u32>()
+                .is_ok_and(|minor| minor >= first && last.is_none_or(|(_, last)| minor <= last))
+        })
+        .collect();
+    if versions.is_empty() {
+        return Err(BuildError::EmptyPythonRange(range.to_string()));
+    }
+    let mut classifiers = versions;
+    if let Ok(major) = Classifier::from_str(&format!("Programming Language :: Python :: {major}")) {
+        classifiers.push(major);
+    }
+    Ok(classifiers)
+}
+
+#[cfg(test)]
+mod tests {
+    #[cfg(any(feature = "programming-language", feature = "typing"))]
+    use super::*;
+
+    #[test]
+    #[cfg(feature = "programming-language")]
+    fn python_ranges_are_inclusive_or_exclusive() {
+        let versions = |range: &str| {
+            python_versions(range).map(|classifiers| {
+                classifiers
+                    .iter()
+                    .map(|classifier| classifier.leaf())
+                    .collect::<Vec<_>>()
+            })
+        };
+        assert_eq!(
+            versions("3.9..=3.11").unwrap(),
+            ["3.9", "3.10", "3.11", "3"]
+        );
+        assert_eq!(versions(" 3.9..3.11 ").unwrap(), ["3.9", "3.10", "3"]);
+        assert!(versions("3.10..").unwrap().len() > 3);
+        assert_eq!(versions("2.6..=2.7").unwrap(), ["2.6", "2.7", "2"]);
+        for invalid in [
+            "3.9",
+            "3..=3.11",
+            "3.9..=4.0",
+            "3.9..3.0",
+            "..3.9",
+            "3.9..=x",
+        ] {
+            assert_eq!(
+                versions(invalid),
+                Err(BuildError::InvalidPythonRange(invalid.to_string())),
+                "{invalid:?}"
+            );
+        }
+        assert_eq!(
+            versions("3.11..3.11"),
+            Err(BuildError::EmptyPythonRange("3.11..3.11".to_string()))
+        );
+    }
+
+    #[test]
+    #[cfg(feature = "all-categories")]
+    fn the_first_error_is_reported() {
+        let built = ClassifiersBuilder::new()
+            .license("Not-A-License")
+            .python_range("3")
+            .build();
+        assert_eq!(
+            built,
+            Err(BuildError::UnknownLicense("Not-A-License".to_string()))
+        );
+    }
+
+    #[test]
+    #[cfg(feature = "all-categories")]
+    fn later_statuses_replace_earlier_ones() {
+        let built = ClassifiersBuilder::new()
+            .development_status(Status::Alpha)
+            .development_status(Status::Stable)
+            .typed()
+            .classifier(Classifier::Typing__Typed)
+            .build();
+        assert_eq!(
+            built,
+            Ok(vec![
+                Classifier::DevelopmentStatus__5ProductionStable,
+                Classifier::Typing__Typed
+            ])
+        );
+    }
+
+    #[test]
+    #[cfg(all(feature = "typing", not(feature = "development-status")))]
+    fn disabled_categories_are_unavailable() {
+        let built = ClassifiersBuilder::new()
+            .typed()
+            .development_status(Status::Beta)
+            .build();
+        assert_eq!(
+            built,
+            Err(BuildError::Unavailable("Development Status :: 4 - Beta"))
+        );
+    }
+}
@@ -0,0 +1,150 @@
+//! Classifiers styled with ANSI escape sequences, for terminal output.
+//!
+//! `paint` and `paint_str` wrap a classifier in a `Painted`, whose `Display` dims the category
+//! and the ` :: ` separators, emphasizes the leaf and strikes a deprecated classifier through, so
+//! command line tools show the structure of classifiers the same way. Colors are left to the
+//! terminal's theme: only the bold, dim and strikethrough attributes are used.
+//!
+//! Whether to style output at all is the caller's decision, usually made once per stream;
+//! `Painted::enabled(false)` writes the plain string. With the `std` feature, `should_color`
+//! makes the usual decision for a stream.
+//!
+//! # Examples
+//!
+//! ```
+//! use trove_classifiers::{color, Classifier};
+//!
+//! let typed = color::paint(Classifier::Typing__Typed);
+//! assert_eq!(
+//!     typed.to_string(),
+//!     "\x1b[2mTyping\x1b[0m\x1b[2m :: \x1b[0m\x1b[1mTyped\x1b[0m"
+//! );
+//! assert_eq!(typed.enabled(false).to_string(), "Typing :: Typed");
+//! assert!(color::paint_str("Natural Language :: Ukranian")
+//!     .to_string()
+//!     .starts_with("\x1b[2;9mNatural Language"));
+//! ```
+
+use core::fmt;
+
+use crate::{deprecation, Classifier};
+
+/// The separator between the segments of a classifier
+const SEPARATOR: &str = " :: ";
+
+/// The SGR parameters of each part of a classifier
+const PLAIN: &str = "";
+const DIM: &str = "2";
+const BOLD: &str = "1";
+const STRUCK: &str = "9";
+
+/// A classifier that displays with ANSI styling
+#[derive(Clone, Copy, Debug, Eq, Hash, PartialEq)]
+#[must_use]
+pub struct Painted<'a> {
+    classifier: &'a str,
+    deprecated: bool,
+    enabled: bool,
+}
+
+/// Style a classifier
+pub fn paint(classifier: Classifier) -> Painted<'static> {
+    Painted {
+        classifier: classifier.as_str(),
+        deprecated: false,
+        enabled: true,
+    }
+}
+
+/// Style any classifier string, striking it through if it is deprecated
+///
+/// Strings that are not classifiers are styled by their ` :: ` segments all the same.
+pub fn paint_str(classifier: &str) -> Painted<'_> {
+    Painted {
+        classifier,
+        deprecated: deprecation(classifier).is_some(),
+        enabled: true,
+    }
+}
+
+impl Painted<'_> {
+    /// Style the classifier only if `enabled` is set, and otherwise display it unchanged
+    pub fn enabled(mut self, enabled: bool) -> Self {
+        self.enabled = enabled;
+        self
+    }
+
+    /// Strike the classifier through, or not, whatever the dataset says
+    pub fn deprecated(mut self, deprecated: bool) -> Self {
+        self.deprecated = deprecated;
+        self
+    }
+
+    /// Write one part of the classifier with the SGR parameters `style`
+    fn part(&self, f: &mut fmt::Formatter<'_>, style: &str, text: &str) -> fmt::Result {
+        if style.is_empty() && !self.deprecated {
+            return f.write_str(text);
+        }
+        let (separator, struck) = match (style.is_empty(), self.deprecated) {
+            (_, false) => ("", ""),
+            (true, true) => ("", STRUCK),
+            (false, true) => (";", STRUCK),
+        };
+        write!(f, "\x1b[{style}{separator}{struck}m{text}\x1b[0m")
+    }
+}
+
+impl fmt::Display for Painted<'_> {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        if !self.enabled {
+            return f.write_str(self.classifier);
+        }
+        let (parents, leaf) = match self.classifier.rsplit_once(SEPARATOR) {
+            Some((parents, leaf)) => (Some(parents), leaf),
+            None => (None, self.classifier),
+        };
+        if let Some(parents) = parents {
+            for (index, segment) in parents.split(SEPARATOR).enumerate() {
+                let style = if index == 0 { DIM } else { PLAIN };
+                self.part(f, style, segment)?;
+                self.part(f, DIM, SEPARATOR)?;
+            }
+        }
+        self.part(f, BOLD, leaf)
+    }
+}
+
+/// Whether output to `stream` should be styled: when it is a terminal, and the `NO_COLOR`
+/// environment variable is unset or empty, as <https://no-color.org> asks
+#[cfg(feature = "std")]
+pub fn should_color(stream: &impl std::io::IsTerminal) -> bool {
+    stream.is_terminal() && std::env::var_os("NO_COLOR").is_none_or(|value| value.is_empty())
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    #[cfg(feature = "framework")]
+    fn middle_segments_are_plain() {
+        assert_eq!(
+            paint(Classifier::Framework__Django__5_1).to_string(),
+            "\x1b[2mFramework\x1b[0m\x1b[2m :: \x1b[0mDjango\x1b[2m :: \x1b[0m\x1b[1m5.1\x1b[0m"
+        );
+    }
+
+    #[test]
+    fn deprecated_classifiers_are_struck_through() {
+        assert_eq!(
+            paint_str("Natural Language :: Ukranian").to_string(),
+            "\x1b[2;9mNatural Language\x1b[0m\x1b[2;9m :: \x1b[0m\x1b[1;9mUkranian\x1b[0m"
+        );
+        assert_eq!(
+            paint_str("A :: B :: C").deprecated(true).to_string(),
+            "\x1b[2;9mA\x1b[0m\x1b[2;9m :: \x1b[0m\x1b[9mB\x1b[0m\x1b[2;9m :: \x1b[0m\
+             \x1b[1;9mC\x1b[0m"
+        );
+        assert_eq!(paint_str("Private").to_string(), "\x1b[1mPrivate\x1b[0m");
+    }
+}
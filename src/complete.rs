@@ -0,0 +1,189 @@
+#[cfg(feature = "alloc")]
+use alloc::vec::Vec;
+
+use crate::generated::CATEGORIES;
+use crate::Classifier;
+
+/// Every classifier whose canonical string starts with `prefix`, ignoring ASCII case
+///
+/// This is intended to be called on every keystroke of an interactive picker. Each category
+/// keeps an index of its classifiers in alphabetical order, so the work done for a query is
+/// logarithmic in the size of the dataset plus the number of classifiers yielded. Matches are
+/// yielded alphabetically within each category, and categories in canonical order.
+///
+/// # Examples
+///
+#[cfg_attr(not(feature = "all-categories"), doc = "```ignore")]
+#[cfg_attr(feature = "all-categories", doc = "```")]
+/// use trove_classifiers::{complete, Classifier};
+///
+/// let typing: Vec<Classifier> = complete("typing :: ").collect();
+/// assert_eq!(typing, [Classifier::Typing__StubsOnly, Classifier::Typing__Typed]);
+///
+/// let mut django = complete("Framework :: Dj");
+/// assert_eq!(django.next(), Some(Classifier::Framework__Django));
+/// ```
+pub fn complete(prefix: &str) -> impl Iterator<Item = Classifier> + '_ {
+    CATEGORIES
+        .iter()
+        .flat_map(move |category| category.complete(prefix))
+}
+
+/// A candidate next segment, as returned by `complete_segment`
+#[derive(Clone, Copy, Debug, Eq, Hash, PartialEq)]
+pub struct Completion {
+    /// The segment completing the last one written
+    pub segment: &'static str,
+    /// The completed classifier string up to and including `segment`
+    pub path: &'static str,
+    /// The classifier whose string is `path`, if it is one rather than only a parent of some
+    pub classifier: Option<Classifier>,
+    /// How many distinct segments come after `segment`
+    pub children: usize,
+}
+
+impl Completion {
+    /// Whether nothing is nested below this completion
+    pub fn is_leaf(&self) -> bool {
+        self.children == 0
+    }
+}
+
+/// The candidates for the segment being written, in canonical order
+///
+/// `partial` is a classifier written up to part of a segment, such as "Framework :: Dj". The
+/// earlier segments and the start of the last one are matched ignoring ASCII case, and a
+/// trailing `::` asks for every segment below the others. Where `complete` offers whole
+/// classifiers, this offers one segment at a time, as menus of nested choices need.
+///
+/// # Examples
+///
+#[cfg_attr(not(feature = "all-categories"), doc = "```ignore")]
+#[cfg_attr(feature = "all-categories", doc = "```")]
+/// use trove_classifiers::{complete_segment, Classifier};
+///
+/// let completions = complete_segment("Framework :: Dj");
+/// assert_eq!(completions[0].segment, "Django");
+/// assert_eq!(completions[0].classifier, Some(Classifier::Framework__Django));
+/// assert!(!completions[0].is_leaf());
+///
+/// let typing = complete_segment("Typing ::");
+/// assert_eq!(typing.len(), 2);
+/// assert!(typing.iter().all(|completion| completion.is_leaf()));
+/// ```
+#[cfg(feature = "alloc")]
+pub fn complete_segment(partial: &str) -> Vec<Completion> {
+    let partial = partial.trim_start();
+    let prefix = match partial.trim_end().strip_suffix("::") {
+        Some(parents) => alloc::format!("{} :: ", parents.trim_end()),
+        None => partial.into(),
+    };
+    let depth = prefix.matches(" :: ").count();
+    let mut matches: Vec<Classifier> = complete(&prefix).collect();
+    matches.sort();
+
+    let mut completions: Vec<Completion> = Vec::new();
+    // The last segment counted among the children of the last completion
+    let mut last_child = None;
+    for classifier in matches {
+        let segments = classifier.segments_static();
+        let Some(&segment) = segments.get(depth) else {
+            continue;
+        };
+        let length = segments[..=depth].iter().map(|s| s.len()).sum::<usize>() + depth * 4;
+        let path = &classifier.as_str()[..length];
+        if completions.last().map(|completion| completion.path) != Some(path) {
+            completions.push(Completion {
+                segment,
+                path,
+                classifier: None,
+                children: 0,
+            });
+            last_child = None;
+        }
+        let completion = completions.last_mut().unwrap();
+        match segments.get(depth + 1) {
+            None => completion.classifier = Some(classifier),
+            Some(&child) if last_child != Some(child) => {
+                completion.children += 1;
+                last_child = Some(child);
+            }
+            Some(_) => {}
+        }
+    }
+    completions
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn empty_prefix_completes_everything() {
+        assert_eq!(complete("").count(), Classifier::ALL.len());
+    }
+
+    #[test]
+    fn completion_matches_linear_scan() {
+        for prefix in [
+            "D",
+            "framework :: django ::",
+            "License :: OSI Approved :: GNU",
+            "Programming Language :: Python :: 3",
+            "TOPIC :: SCIENTIFIC/ENGINEERING :: ",
+            "Typing :: Typed",
+            "Typing :: Typed and more",
+            "Unknown",
+        ] {
+            let mut completed: Vec<Classifier> = complete(prefix).collect();
+            completed.sort();
+            let scanned: Vec<Classifier> = Classifier::ALL
+                .iter()
+                .copied()
+                .filter(|classifier| {
+                    let string = classifier.as_str();
+                    string.len() >= prefix.len()
+                        && string[..prefix.len()].eq_ignore_ascii_case(prefix)
+                })
+                .collect();
+            assert_eq!(completed, scanned, "completing {prefix:?}");
+        }
+    }
+
+    #[test]
+    #[cfg(feature = "all-categories")]
+    fn segments_complete_one_level() {
+        let categories = complete_segment("");
+        assert!(categories
+            .iter()
+            .all(|completion| completion.classifier.is_none()));
+        assert_eq!(categories[0].path, "Development Status");
+        assert_eq!(complete_segment("t").len(), 2);
+
+        let django = complete_segment("framework :: django ::");
+        assert!(django.iter().all(|completion| completion.is_leaf()));
+        assert!(django.iter().any(|completion| completion.segment == "5.1"));
+        assert!(complete_segment("Framework :: Django :: 5.1 ::").is_empty());
+        assert!(complete_segment("Unknown :: ").is_empty());
+    }
+
+    #[test]
+    #[cfg(feature = "topic")]
+    fn parents_count_their_children() {
+        let science = complete_segment("Topic :: Scientific/Engineering");
+        assert_eq!(science.len(), 1);
+        assert_eq!(
+            science[0].classifier,
+            Some(Classifier::Topic__ScientificEngineering)
+        );
+        let scanned = complete_segment("Topic :: Scientific/Engineering :: ");
+        assert_eq!(science[0].children, scanned.len());
+
+        let topics = complete_segment("Topic :: ");
+        let software = topics
+            .iter()
+            .find(|completion| completion.segment == "Software Development")
+            .unwrap();
+        assert!(software.children > 5);
+    }
+}
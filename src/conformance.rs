@@ -0,0 +1,86 @@
+//! Conformance with the python trove-classifiers package this crate's dataset is taken from.
+//!
+//! These tests run the installed python package, so they are ignored by default. Run them with
+//! `cargo test conformance -- --ignored`, choosing the interpreter with the `PYTHON` environment
+//! variable as for `cargo xtask fetch`.
+
+use std::env;
+use std::process::Command;
+
+use crate::{deprecations, Classifier, PYPA_VERSION};
+
+/// Prints the package's version, its sorted classifiers, a blank line, then a line per deprecated
+/// classifier with its replacements, all separated by tabs
+const SCRIPT: &str = r#"
+import importlib.metadata
+import trove_classifiers
+
+print(importlib.metadata.distribution("trove_classifiers").version)
+for classifier in trove_classifiers.sorted_classifiers:
+    print(classifier)
+print()
+for classifier, replacements in sorted(trove_classifiers.deprecated_classifiers.items()):
+    print("\t".join([classifier, *replacements]))
+"#;
+
+/// What the python package reports
+struct Python {
+    version: String,
+    classifiers: Vec<String>,
+    deprecated: Vec<String>,
+}
+
+fn python() -> Python {
+    let python = env::var("PYTHON").unwrap_or_else(|_| "python3".to_string());
+    let output = Command::new(&python)
+        .args(["-c", SCRIPT])
+        .output()
+        .unwrap_or_else(|err| panic!("running {python}: {err}"));
+    assert!(
+        output.status.success(),
+        "{python} could not read trove-classifiers (is it installed?)\n{}",
+        String::from_utf8_lossy(&output.stderr)
+    );
+    let stdout = String::from_utf8(output.stdout).unwrap();
+    let mut lines = stdout.lines().map(str::to_string);
+    let version = lines.next().unwrap_or_default();
+    let classifiers = lines.by_ref().take_while(|line| !line.is_empty()).collect();
+    Python {
+        version,
+        classifiers,
+        deprecated: lines.collect(),
+    }
+}
+
+#[test]
+#[ignore = "needs the python trove-classifiers package"]
+fn same_version() {
+    assert_eq!(
+        python().version,
+        PYPA_VERSION,
+        "the installed package is not the one snapshot in data/; run `cargo xtask fetch`"
+    );
+}
+
+#[test]
+#[ignore = "needs the python trove-classifiers package"]
+fn same_classifiers_in_the_same_order() {
+    let ours: Vec<&str> = Classifier::ALL.iter().map(Classifier::as_str).collect();
+    assert_eq!(python().classifiers, ours);
+}
+
+#[test]
+#[ignore = "needs the python trove-classifiers package"]
+fn same_deprecations() {
+    let ours: Vec<String> = deprecations()
+        .map(|deprecated| {
+            let mut line = deprecated.as_str().to_string();
+            for replacement in deprecated.replacements() {
+                line.push('\t');
+                line.push_str(replacement.as_str());
+            }
+            line
+        })
+        .collect();
+    assert_eq!(python().deprecated, ours);
+}
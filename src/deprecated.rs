@@ -0,0 +1,128 @@
+use core::str::FromStr;
+
+use crate::generated::deprecated::DEPRECATED;
+use crate::{Classifier, ParseError};
+
+/// A classifier that pypi.org no longer accepts
+///
+/// Deprecated classifiers are not variants of `Classifier`, since they cannot be uploaded, but
+/// metadata written before their deprecation still declares them.
+///
+/// # Examples
+///
+#[cfg_attr(not(feature = "natural-language"), doc = "```ignore")]
+#[cfg_attr(feature = "natural-language", doc = "```")]
+/// use trove_classifiers::{deprecation, Classifier};
+///
+/// let ukranian = deprecation("Natural Language :: Ukranian").unwrap();
+/// assert!(ukranian
+///     .replacements()
+///     .eq([Classifier::NaturalLanguage__Ukrainian]));
+/// assert_eq!(deprecation("Natural Language :: Ukrainian"), None);
+/// ```
+#[derive(Clone, Copy, Debug, Eq, Hash, PartialEq)]
+pub struct Deprecation {
+    classifier: &'static str,
+    replacements: &'static [&'static str],
+}
+
+impl Deprecation {
+    /// The deprecated classifier's string
+    pub fn as_str(&self) -> &'static str {
+        self.classifier
+    }
+
+    /// The classifiers replacing the deprecated one, which may be none
+    ///
+    /// Replacements in categories compiled out by features are skipped.
+    pub fn replacements(&self) -> impl Iterator<Item = Classifier> {
+        self.replacements
+            .iter()
+            .filter_map(|replacement| Classifier::from_str(replacement).ok())
+    }
+}
+
+impl Classifier {
+    /// Parse a classifier's canonical string, reporting a deprecated classifier as such
+    ///
+    /// `from_str` rejects deprecated classifiers as unknown, since they are not variants. This
+    /// rejects them as `ParseError::Deprecated`, whose message names their replacements, for
+    /// pipelines that must explain why metadata pypi.org considers obsolete is refused.
+    ///
+    /// # Examples
+    ///
+    #[cfg_attr(not(feature = "natural-language"), doc = "```ignore")]
+    #[cfg_attr(feature = "natural-language", doc = "```")]
+    /// use trove_classifiers::{Classifier, ParseError};
+    ///
+    /// let err = Classifier::from_str_strict("Natural Language :: Ukranian").unwrap_err();
+    /// assert!(matches!(err, ParseError::Deprecated(_)));
+    /// assert_eq!(
+    ///     err.to_string(),
+    ///     "deprecated classifier \"Natural Language :: Ukranian\", \
+    ///      use \"Natural Language :: Ukrainian\" instead"
+    /// );
+    /// assert_eq!(Classifier::from_str_strict("Typing :: Typo"), Err(ParseError::Unknown));
+    /// ```
+    pub fn from_str_strict(s: &str) -> Result<Self, ParseError> {
+        Classifier::from_str(s).map_err(|err| deprecation(s).map_or(err, ParseError::Deprecated))
+    }
+}
+
+/// The deprecation of a classifier string, if it names a deprecated classifier
+pub fn deprecation(s: &str) -> Option<Deprecation> {
+    DEPRECATED
+        .binary_search_by(|(classifier, _)| (*classifier).cmp(s))
+        .ok()
+        .map(|index| {
+            let (classifier, replacements) = DEPRECATED[index];
+            Deprecation {
+                classifier,
+                replacements,
+            }
+        })
+}
+
+/// Every deprecated classifier, ordered by string
+pub fn deprecations() -> impl Iterator<Item = Deprecation> {
+    DEPRECATED
+        .iter()
+        .map(|&(classifier, replacements)| Deprecation {
+            classifier,
+            replacements,
+        })
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    #[cfg(all(feature = "alloc", feature = "topic"))]
+    fn strict_parsing_names_replacements() {
+        use alloc::string::ToString;
+
+        let err =
+            Classifier::from_str_strict("Topic :: Communications :: Chat :: AOL Instant Messenger")
+                .unwrap_err();
+        assert!(err.to_string().ends_with("Instant Messenger\", remove it"));
+        assert_eq!(
+            Classifier::from_str_strict("Topic :: Security"),
+            Ok(Classifier::Topic__Security)
+        );
+    }
+
+    #[test]
+    fn every_deprecation_is_found() {
+        for deprecated in deprecations() {
+            assert_eq!(deprecation(deprecated.as_str()), Some(deprecated));
+            assert!(Classifier::from_str(deprecated.as_str()).is_err());
+        }
+    }
+
+    #[test]
+    fn deprecations_may_have_no_replacement() {
+        let aim = deprecation("Topic :: Communications :: Chat :: AOL Instant Messenger");
+        assert_eq!(aim.unwrap().replacements().count(), 0);
+    }
+}
@@ -0,0 +1,181 @@
+//! Plain-language descriptions of the categories and of the classifiers most often misread.
+//!
+//! A classifier's string says where it sits in the tree, but not always what declaring it
+//! promises: that "Development Status :: 6 - Mature" means few changes are expected, or that
+//! "Typing :: Stubs Only" is for distributions of stub files. `Category::description` describes
+//! each top-level category and `Classifier::description` each classifier that needs more than
+//! its string, so that pickers, tooltips and editor hovers explain them in the same words.
+//! Classifiers whose string says it all, such as the version of a framework, have none.
+//!
+//! # Examples
+//!
+#![cfg_attr(not(feature = "all-categories"), doc = "```ignore")]
+#![cfg_attr(feature = "all-categories", doc = "```")]
+//! use trove_classifiers::{Category, Classifier};
+//!
+//! assert_eq!(
+//!     Classifier::Typing__StubsOnly.description(),
+//!     Some("The distribution only contains type stubs, .pyi files, for another package.")
+//! );
+//! assert_eq!(
+//!     Category::Typing.description(),
+//!     "Whether the project ships type information for its users' type checkers."
+//! );
+//! assert_eq!(Classifier::Framework__Django__5_2.description(), None);
+//! ```
+
+use crate::{Category, Classifier};
+
+/// The description of every category, in the order of `Category::ALL`
+static CATEGORIES: [&str; Category::ALL.len()] = [
+    "How far along the project is, from planning to stable, mature or no longer maintained.",
+    "The environment the project runs in or provides, such as a console, a desktop or a web \
+     server.",
+    "The frameworks the project extends or is built on, and which of their versions it supports.",
+    "The people the project is written for, such as developers or end users.",
+    "The license the project is distributed under. Prefer the License-Expression field with an \
+     SPDX expression to these classifiers.",
+    "The human languages the project's interface and documentation are written in.",
+    "The operating systems the project runs on.",
+    "The programming languages the project is written in, the versions of Python it supports and \
+     the Python implementations it runs on.",
+    "What the project is about or for, to be found when browsing by subject.",
+    "Whether the project ships type information for its users' type checkers.",
+];
+
+/// Each classifier needing more than its string, and its description
+static CLASSIFIERS: &[(&str, &str)] = &[
+    (
+        "Development Status :: 1 - Planning",
+        "Nothing usable has been released yet: the project is being designed.",
+    ),
+    (
+        "Development Status :: 2 - Pre-Alpha",
+        "Early code that is incomplete and expected to change in every release.",
+    ),
+    (
+        "Development Status :: 3 - Alpha",
+        "The main features are being written. Expect bugs and breaking changes.",
+    ),
+    (
+        "Development Status :: 4 - Beta",
+        "The features are mostly complete and being tested. Breaking changes are still possible.",
+    ),
+    (
+        "Development Status :: 5 - Production/Stable",
+        "Ready to be depended on. Breaking changes follow a deprecation policy.",
+    ),
+    (
+        "Development Status :: 6 - Mature",
+        "Stable and complete: few new features or changes are expected, but it is maintained.",
+    ),
+    (
+        "Development Status :: 7 - Inactive",
+        "No longer developed or maintained. Look for an alternative before depending on it.",
+    ),
+    (
+        "Environment :: Console",
+        "The project is used from a terminal, as a command line or text interface.",
+    ),
+    (
+        "Environment :: Plugins",
+        "The project extends another application, loaded as its plugin.",
+    ),
+    (
+        "Environment :: Web Environment",
+        "The project runs on a web server or in a web browser.",
+    ),
+    (
+        "Intended Audience :: Developers",
+        "Written for programmers, for instance as a library or a development tool.",
+    ),
+    (
+        "Intended Audience :: End Users/Desktop",
+        "Written for people using a computer rather than programming one.",
+    ),
+    (
+        "License :: OSI Approved",
+        "An open source license approved by the Open Source Initiative. Name the license with a \
+         more specific classifier or, better, a License-Expression.",
+    ),
+    (
+        "License :: Other/Proprietary License",
+        "A license that is not open source. Use a LicenseRef- identifier in the \
+         License-Expression field instead.",
+    ),
+    (
+        "License :: Public Domain",
+        "The project is dedicated to the public domain. Not a license in every jurisdiction: \
+         consider an expression such as CC0-1.0 or Unlicense.",
+    ),
+    (
+        "Operating System :: OS Independent",
+        "Runs on any operating system Python runs on, typically because it is pure Python.",
+    ),
+    (
+        "Programming Language :: Python :: 3 :: Only",
+        "Supports Python 3 and not Python 2.",
+    ),
+    (
+        "Programming Language :: Python :: Implementation :: CPython",
+        "Runs on CPython, the reference implementation of Python. Declare it to show it is tested \
+         there.",
+    ),
+    (
+        "Programming Language :: Python :: Implementation :: PyPy",
+        "Runs on PyPy, the Python implementation with a just-in-time compiler.",
+    ),
+    (
+        "Typing :: Stubs Only",
+        "The distribution only contains type stubs, .pyi files, for another package.",
+    ),
+    (
+        "Typing :: Typed",
+        "The package ships inline type hints or stubs and a py.typed marker, as PEP 561 describes.",
+    ),
+];
+
+/// The description of a category
+pub(crate) fn category(category: Category) -> &'static str {
+    CATEGORIES[category as usize]
+}
+
+/// The description of a classifier, if it needs one
+pub(crate) fn classifier(classifier: Classifier) -> Option<&'static str> {
+    let classifier = classifier.as_str();
+    CLASSIFIERS
+        .iter()
+        .find(|(described, _)| *described == classifier)
+        .map(|(_, description)| *description)
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn every_described_classifier_is_accepted() {
+        for (classifier, description) in CLASSIFIERS {
+            assert!(description.ends_with('.'), "{classifier}");
+            let category = classifier.split(" :: ").next().unwrap();
+            assert!(
+                Category::ALL.iter().any(|known| known.name() == category),
+                "{classifier}"
+            );
+            #[cfg(feature = "all-categories")]
+            assert!(classifier.parse::<Classifier>().is_ok(), "{classifier}");
+        }
+    }
+
+    #[test]
+    #[cfg(feature = "typing")]
+    fn categories_are_described_in_order() {
+        assert_eq!(
+            Classifier::Typing__Typed.category().description(),
+            CATEGORIES[CATEGORIES.len() - 1]
+        );
+        assert!(CATEGORIES
+            .iter()
+            .all(|description| description.ends_with('.')));
+    }
+}
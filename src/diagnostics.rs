@@ -0,0 +1,207 @@
+//! [miette](https://docs.rs/miette) diagnostics for this crate's errors and lint findings.
+//!
+//! The errors of parsing classifiers, reading a `pyproject.toml` and reading a policy implement
+//! `miette::Diagnostic`, with a stable code and help. A lint `Finding` is not an error, so
+//! `FindingDiagnostic` wraps one, and `pyproject` labels each finding of a `pyproject.toml` with
+//! the span of its declaration, so that any miette report handler renders it in context.
+//!
+//! # Examples
+//!
+//! ```
+//! use miette::Diagnostic;
+//! use trove_classifiers::diagnostics;
+//! use trove_classifiers::lint::{lint, Declared};
+//!
+//! let source = "[project]\nclassifiers = ['Typing :: Sometimes']\n";
+//! let findings = lint(&[Declared::new("Typing :: Sometimes").line(2)]);
+//! let diagnostics = diagnostics::pyproject("pyproject.toml", source, &findings);
+//! let label = diagnostics[0].labels().unwrap().next().unwrap();
+//! assert_eq!(&source[label.offset()..label.offset() + label.len()], "'Typing :: Sometimes'");
+//! assert_eq!(diagnostics[0].code().unwrap().to_string(), "TC001");
+//! ```
+
+use std::fmt;
+
+use miette::{Diagnostic, LabeledSpan, NamedSource, SourceCode, SourceSpan};
+
+use crate::lint::{Finding, Severity};
+use crate::policy::PolicyError;
+use crate::pyproject::{self, PyprojectError};
+use crate::ParseError;
+
+impl Diagnostic for ParseError {
+    fn code<'a>(&'a self) -> Option<Box<dyn fmt::Display + 'a>> {
+        Some(Box::new("trove_classifiers::parse"))
+    }
+
+    fn help<'a>(&'a self) -> Option<Box<dyn fmt::Display + 'a>> {
+        Some(Box::new(
+            "write classifiers exactly as https://pypi.org/classifiers/ lists them",
+        ))
+    }
+}
+
+impl Diagnostic for PyprojectError {
+    fn code<'a>(&'a self) -> Option<Box<dyn fmt::Display + 'a>> {
+        Some(Box::new("trove_classifiers::pyproject"))
+    }
+}
+
+impl Diagnostic for PolicyError {
+    fn code<'a>(&'a self) -> Option<Box<dyn fmt::Display + 'a>> {
+        Some(Box::new("trove_classifiers::policy"))
+    }
+}
+
+/// A lint finding as a diagnostic, labeled in its source when that is known
+#[derive(Clone, Debug)]
+pub struct FindingDiagnostic {
+    pub finding: Finding,
+    /// The file the finding is in, and the span of the offending declaration
+    source: Option<(NamedSource<String>, SourceSpan)>,
+}
+
+impl FindingDiagnostic {
+    /// A diagnostic of a finding without a source
+    pub fn new(finding: Finding) -> Self {
+        FindingDiagnostic {
+            finding,
+            source: None,
+        }
+    }
+
+    /// Label the diagnostic with the byte range `span` of the file `name`, whose text is `source`
+    pub fn with_source(mut self, name: &str, source: &str, span: std::ops::Range<usize>) -> Self {
+        self.source = Some((NamedSource::new(name, source.to_string()), span.into()));
+        self
+    }
+}
+
+impl From<Finding> for FindingDiagnostic {
+    fn from(finding: Finding) -> Self {
+        FindingDiagnostic::new(finding)
+    }
+}
+
+impl fmt::Display for FindingDiagnostic {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        f.write_str(&self.finding.message)
+    }
+}
+
+impl std::error::Error for FindingDiagnostic {}
+
+impl Diagnostic for FindingDiagnostic {
+    fn code<'a>(&'a self) -> Option<Box<dyn fmt::Display + 'a>> {
+        Some(Box::new(self.finding.rule.code()))
+    }
+
+    fn severity(&self) -> Option<miette::Severity> {
+        Some(match self.finding.severity {
+            Severity::Warning => miette::Severity::Warning,
+            Severity::Error => miette::Severity::Error,
+        })
+    }
+
+    fn help<'a>(&'a self) -> Option<Box<dyn fmt::Display + 'a>> {
+        Some(Box::new(self.finding.rule.explanation().fix))
+    }
+
+    fn source_code(&self) -> Option<&dyn SourceCode> {
+        self.source
+            .as_ref()
+            .map(|(source, _)| source as &dyn SourceCode)
+    }
+
+    fn labels(&self) -> Option<Box<dyn Iterator<Item = LabeledSpan> + '_>> {
+        let (_, span) = self.source.as_ref()?;
+        let label = LabeledSpan::new_primary_with_span(
+            Some(self.finding.rule.summary().to_string()),
+            *span,
+        );
+        Some(Box::new(std::iter::once(label)))
+    }
+}
+
+/// The findings of a `pyproject.toml` named `name`, each labeled with its declaration
+///
+/// A finding without a declaration of its own, such as a missing required classifier, is labeled
+/// with the whole `classifiers` array. Findings are left unlabeled if the document cannot be read.
+pub fn pyproject(name: &str, source: &str, findings: &[Finding]) -> Vec<FindingDiagnostic> {
+    let array = pyproject::classifiers(source).ok().flatten();
+    findings
+        .iter()
+        .map(|finding| {
+            let diagnostic = FindingDiagnostic::new(finding.clone());
+            let Some(array) = &array else {
+                return diagnostic;
+            };
+            let span = match finding.line {
+                Some(line) => array
+                    .entries
+                    .iter()
+                    .find(|entry| entry.line == line && entry.value == finding.value)
+                    .map(|entry| entry.span.clone()),
+                None => Some(array.span.clone()),
+            };
+            match span {
+                Some(span) => diagnostic.with_source(name, source, span),
+                None => diagnostic,
+            }
+        })
+        .collect()
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::lint::{Context, Declared, Linter};
+    use crate::Classifier;
+
+    #[test]
+    #[cfg(feature = "typing")]
+    fn findings_are_labeled_with_their_declarations() {
+        let source =
+            "[project]\nclassifiers = [\n  'Typing :: Typed',\n  \"Typing :: Typed\",\n]\n";
+        let declared: Vec<Declared> = pyproject::classifiers(source)
+            .unwrap()
+            .unwrap()
+            .entries
+            .iter()
+            .map(pyproject::Entry::declared)
+            .collect();
+        let required = [Classifier::Typing__StubsOnly];
+        let findings = Linter::new().check(&Context::new(&declared).required(&required));
+        let diagnostics = pyproject("pyproject.toml", source, &findings);
+        let spans: Vec<&str> = diagnostics
+            .iter()
+            .map(|diagnostic| {
+                let label = diagnostic.labels().unwrap().next().unwrap();
+                &source[label.offset()..label.offset() + label.len()]
+            })
+            .collect();
+        assert_eq!(
+            spans,
+            [
+                "\"Typing :: Typed\"",
+                "[\n  'Typing :: Typed',\n  \"Typing :: Typed\",\n]"
+            ]
+        );
+        assert_eq!(diagnostics[0].severity(), Some(miette::Severity::Warning));
+        assert_eq!(diagnostics[1].code().unwrap().to_string(), "TC007");
+        assert!(diagnostics[1].source_code().is_some());
+    }
+
+    #[test]
+    fn unlabeled_findings_have_no_source() {
+        let findings = crate::lint::lint(&[Declared::new("Typing :: Sometimes")]);
+        let diagnostic = FindingDiagnostic::from(findings[0].clone());
+        assert!(diagnostic.labels().is_none());
+        assert!(diagnostic.source_code().is_none());
+        assert_eq!(diagnostic.to_string(), findings[0].message);
+        assert_eq!(
+            ParseError::Unknown.code().unwrap().to_string(),
+            "trove_classifiers::parse"
+        );
+    }
+}
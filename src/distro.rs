@@ -0,0 +1,706 @@
+//! Best-effort mapping of Topic and Environment classifiers to Debian sections, RPM groups and
+//! freedesktop.org menu categories.
+//!
+//! Packagers converting PyPI metadata into distribution packages need a Debian archive section,
+//! or an RPM `Group:` for distributions that still use them. `debian_section` and `rpm_group` map
+//! one classifier, falling back to the mapping of its nearest parent; `debian_section_of` and
+//! `rpm_group_of` pick one for a project's classifiers.
+//!
+//! GUI applications also need the `Categories` of their `.desktop` file. `desktop_categories`
+//! maps one classifier to a main category and the additional categories it implies, as the
+//! [menu specification](https://specifications.freedesktop.org/menu-spec/latest/category-registry.html)
+//! requires, and, with the `alloc` feature, `desktop_entry_categories` writes the value of the
+//! `Categories` key for a project and `from_desktop_entry_categories` reads classifiers back from
+//! it.
+//!
+//! A classifier without a fitting section or group maps to `None`. This is explicit in the
+//! tables for topics too broad to place, such as "Topic :: Other/Nonlisted Topic" or
+//! "Environment :: Console", so that their subtopics do not inherit a guess either, and it is
+//! the answer for every classifier outside the Topic and Environment categories.
+//!
+//! # Examples
+//!
+//! ```
+//! use trove_classifiers::{distro, Classifier};
+//!
+//! let email = Classifier::Topic__Communications__Email__Filters;
+//! assert_eq!(distro::debian_section(email), Some("mail"));
+//! assert_eq!(distro::rpm_group(email), Some("Applications/Communications"));
+//! assert_eq!(distro::debian_section(Classifier::Topic__OtherNonlistedTopic), None);
+//! assert_eq!(
+//!     distro::desktop_categories(Classifier::Topic__GamesEntertainment__BoardGames),
+//!     ["Game", "BoardGame"]
+//! );
+//! ```
+
+#[cfg(feature = "alloc")]
+use alloc::{string::String, vec::Vec};
+
+use crate::Classifier;
+
+/// Topic and Environment classifiers and their Debian section, the longest prefix winning
+const DEBIAN: &[(&str, Option<&str>)] = &[
+    ("Environment :: Console", None),
+    ("Environment :: Console :: Curses", Some("utils")),
+    ("Environment :: GPU", Some("science")),
+    ("Environment :: No Input/Output (Daemon)", Some("admin")),
+    ("Environment :: Other Environment", None),
+    ("Environment :: Plugins", None),
+    ("Environment :: Web Environment", Some("web")),
+    ("Environment :: X11 Applications", Some("x11")),
+    ("Environment :: X11 Applications :: Gnome", Some("gnome")),
+    ("Environment :: X11 Applications :: KDE", Some("kde")),
+    ("Topic :: Adaptive Technologies", Some("misc")),
+    ("Topic :: Artistic Software", Some("graphics")),
+    ("Topic :: Communications", Some("comm")),
+    ("Topic :: Communications :: Email", Some("mail")),
+    ("Topic :: Communications :: Ham Radio", Some("hamradio")),
+    ("Topic :: Communications :: Usenet News", Some("news")),
+    ("Topic :: Database", Some("database")),
+    ("Topic :: Desktop Environment", Some("x11")),
+    ("Topic :: Desktop Environment :: GNUstep", Some("gnustep")),
+    ("Topic :: Desktop Environment :: Gnome", Some("gnome")),
+    (
+        "Topic :: Desktop Environment :: K Desktop Environment (KDE)",
+        Some("kde"),
+    ),
+    ("Topic :: Documentation", Some("doc")),
+    ("Topic :: Education", Some("education")),
+    ("Topic :: File Formats", Some("utils")),
+    ("Topic :: Games/Entertainment", Some("games")),
+    ("Topic :: Home Automation", Some("misc")),
+    ("Topic :: Internet", Some("net")),
+    ("Topic :: Internet :: WWW/HTTP", Some("web")),
+    (
+        "Topic :: Internet :: WWW/HTTP :: HTTP Servers",
+        Some("httpd"),
+    ),
+    ("Topic :: Multimedia", Some("video")),
+    ("Topic :: Multimedia :: Graphics", Some("graphics")),
+    ("Topic :: Multimedia :: Sound/Audio", Some("sound")),
+    ("Topic :: Office/Business", Some("misc")),
+    ("Topic :: Other/Nonlisted Topic", None),
+    ("Topic :: Printing", Some("text")),
+    ("Topic :: Religion", Some("misc")),
+    ("Topic :: Scientific/Engineering", Some("science")),
+    (
+        "Topic :: Scientific/Engineering :: Electronic Design Automation (EDA)",
+        Some("electronics"),
+    ),
+    (
+        "Topic :: Scientific/Engineering :: Mathematics",
+        Some("math"),
+    ),
+    ("Topic :: Security", Some("admin")),
+    ("Topic :: Sociology", Some("misc")),
+    ("Topic :: Software Development", Some("devel")),
+    ("Topic :: Software Development :: Debuggers", Some("debug")),
+    (
+        "Topic :: Software Development :: Documentation",
+        Some("doc"),
+    ),
+    (
+        "Topic :: Software Development :: Embedded Systems",
+        Some("embedded"),
+    ),
+    (
+        "Topic :: Software Development :: Internationalization",
+        Some("localization"),
+    ),
+    (
+        "Topic :: Software Development :: Interpreters",
+        Some("interpreters"),
+    ),
+    ("Topic :: Software Development :: Libraries", Some("python")),
+    (
+        "Topic :: Software Development :: Localization",
+        Some("localization"),
+    ),
+    (
+        "Topic :: Software Development :: Version Control",
+        Some("vcs"),
+    ),
+    ("Topic :: System", Some("admin")),
+    ("Topic :: System :: Console Fonts", Some("fonts")),
+    ("Topic :: System :: Emulators", Some("otherosfs")),
+    ("Topic :: System :: Filesystems", Some("otherosfs")),
+    ("Topic :: System :: Networking", Some("net")),
+    (
+        "Topic :: System :: Operating System Kernels",
+        Some("kernel"),
+    ),
+    ("Topic :: System :: Shells", Some("shells")),
+    ("Topic :: Terminals", Some("utils")),
+    ("Topic :: Text Editors", Some("editors")),
+    ("Topic :: Text Processing", Some("text")),
+    ("Topic :: Text Processing :: Fonts", Some("fonts")),
+    ("Topic :: Text Processing :: Markup :: LaTeX", Some("tex")),
+    ("Topic :: Utilities", Some("utils")),
+];
+
+/// Topic and Environment classifiers and their RPM group, the longest prefix winning
+const RPM: &[(&str, Option<&str>)] = &[
+    ("Environment :: Console", None),
+    (
+        "Environment :: No Input/Output (Daemon)",
+        Some("System Environment/Daemons"),
+    ),
+    ("Environment :: Other Environment", None),
+    ("Environment :: Plugins", None),
+    (
+        "Environment :: Web Environment",
+        Some("Applications/Internet"),
+    ),
+    ("Environment :: X11 Applications", Some("User Interface/X")),
+    (
+        "Topic :: Communications",
+        Some("Applications/Communications"),
+    ),
+    ("Topic :: Database", Some("Applications/Databases")),
+    (
+        "Topic :: Desktop Environment",
+        Some("User Interface/Desktops"),
+    ),
+    ("Topic :: Documentation", Some("Documentation")),
+    ("Topic :: Education", Some("Applications/Engineering")),
+    ("Topic :: File Formats", Some("Applications/File")),
+    ("Topic :: Games/Entertainment", Some("Amusements/Games")),
+    ("Topic :: Internet", Some("Applications/Internet")),
+    ("Topic :: Multimedia", Some("Applications/Multimedia")),
+    (
+        "Topic :: Multimedia :: Graphics",
+        Some("Amusements/Graphics"),
+    ),
+    (
+        "Topic :: Office/Business",
+        Some("Applications/Productivity"),
+    ),
+    ("Topic :: Other/Nonlisted Topic", None),
+    ("Topic :: Printing", Some("Applications/Publishing")),
+    (
+        "Topic :: Scientific/Engineering",
+        Some("Applications/Engineering"),
+    ),
+    ("Topic :: Security", Some("Applications/System")),
+    ("Topic :: Software Development", Some("Development/Tools")),
+    (
+        "Topic :: Software Development :: Compilers",
+        Some("Development/Languages"),
+    ),
+    (
+        "Topic :: Software Development :: Debuggers",
+        Some("Development/Debuggers"),
+    ),
+    (
+        "Topic :: Software Development :: Interpreters",
+        Some("Development/Languages"),
+    ),
+    (
+        "Topic :: Software Development :: Libraries",
+        Some("Development/Libraries"),
+    ),
+    (
+        "Topic :: Software Development :: Widget Sets",
+        Some("Development/Libraries"),
+    ),
+    ("Topic :: System", Some("Applications/System")),
+    (
+        "Topic :: System :: Emulators",
+        Some("Applications/Emulators"),
+    ),
+    (
+        "Topic :: System :: Operating System Kernels",
+        Some("System Environment/Kernel"),
+    ),
+    (
+        "Topic :: System :: Shells",
+        Some("System Environment/Shells"),
+    ),
+    ("Topic :: Terminals", Some("Applications/System")),
+    ("Topic :: Text Editors", Some("Applications/Editors")),
+    ("Topic :: Text Processing", Some("Applications/Text")),
+    ("Topic :: Utilities", Some("Applications/System")),
+];
+
+/// Topic and Environment classifiers and their desktop entry categories, a main category first,
+/// the longest prefix winning
+const DESKTOP: &[(&str, &[&str])] = &[
+    ("Environment :: Console", &[]),
+    ("Environment :: X11 Applications :: GTK", &["GTK"]),
+    (
+        "Environment :: X11 Applications :: Gnome",
+        &["GNOME", "GTK"],
+    ),
+    ("Environment :: X11 Applications :: KDE", &["KDE", "Qt"]),
+    ("Environment :: X11 Applications :: Qt", &["Qt"]),
+    (
+        "Topic :: Adaptive Technologies",
+        &["Utility", "Accessibility"],
+    ),
+    ("Topic :: Artistic Software", &["Graphics"]),
+    ("Topic :: Communications", &["Network"]),
+    ("Topic :: Communications :: Chat", &["Network", "Chat"]),
+    (
+        "Topic :: Communications :: Chat :: Internet Relay Chat",
+        &["Network", "IRCClient"],
+    ),
+    (
+        "Topic :: Communications :: Conferencing",
+        &["Network", "VideoConference"],
+    ),
+    ("Topic :: Communications :: Email", &["Network", "Email"]),
+    (
+        "Topic :: Communications :: File Sharing",
+        &["Network", "FileTransfer"],
+    ),
+    (
+        "Topic :: Communications :: Ham Radio",
+        &["Network", "HamRadio"],
+    ),
+    (
+        "Topic :: Communications :: Internet Phone",
+        &["Network", "Telephony"],
+    ),
+    (
+        "Topic :: Communications :: Telephony",
+        &["Network", "Telephony"],
+    ),
+    (
+        "Topic :: Communications :: Usenet News",
+        &["Network", "News"],
+    ),
+    ("Topic :: Database", &["Office", "Database"]),
+    (
+        "Topic :: Desktop Environment",
+        &["Settings", "DesktopSettings"],
+    ),
+    (
+        "Topic :: Desktop Environment :: File Managers",
+        &["System", "FileManager"],
+    ),
+    ("Topic :: Documentation", &["Utility", "Documentation"]),
+    ("Topic :: Education", &["Education"]),
+    ("Topic :: Games/Entertainment", &["Game"]),
+    (
+        "Topic :: Games/Entertainment :: Arcade",
+        &["Game", "ArcadeGame"],
+    ),
+    (
+        "Topic :: Games/Entertainment :: Board Games",
+        &["Game", "BoardGame"],
+    ),
+    (
+        "Topic :: Games/Entertainment :: First Person Shooters",
+        &["Game", "Shooter"],
+    ),
+    (
+        "Topic :: Games/Entertainment :: Puzzle Games",
+        &["Game", "LogicGame"],
+    ),
+    (
+        "Topic :: Games/Entertainment :: Real Time Strategy",
+        &["Game", "StrategyGame"],
+    ),
+    (
+        "Topic :: Games/Entertainment :: Role-Playing",
+        &["Game", "RolePlaying"],
+    ),
+    (
+        "Topic :: Games/Entertainment :: Side-Scrolling/Arcade Games",
+        &["Game", "ArcadeGame"],
+    ),
+    (
+        "Topic :: Games/Entertainment :: Simulation",
+        &["Game", "Simulation"],
+    ),
+    (
+        "Topic :: Games/Entertainment :: Turn Based Strategy",
+        &["Game", "StrategyGame"],
+    ),
+    ("Topic :: Internet", &["Network"]),
+    (
+        "Topic :: Internet :: File Transfer Protocol (FTP)",
+        &["Network", "FileTransfer"],
+    ),
+    (
+        "Topic :: Internet :: WWW/HTTP :: Browsers",
+        &["Network", "WebBrowser"],
+    ),
+    (
+        "Topic :: Internet :: XMPP",
+        &["Network", "InstantMessaging"],
+    ),
+    ("Topic :: Multimedia", &["AudioVideo"]),
+    ("Topic :: Multimedia :: Graphics", &["Graphics"]),
+    (
+        "Topic :: Multimedia :: Graphics :: 3D Modeling",
+        &["Graphics", "3DGraphics"],
+    ),
+    (
+        "Topic :: Multimedia :: Graphics :: 3D Rendering",
+        &["Graphics", "3DGraphics"],
+    ),
+    (
+        "Topic :: Multimedia :: Graphics :: Capture :: Digital Camera",
+        &["Graphics", "Photography"],
+    ),
+    (
+        "Topic :: Multimedia :: Graphics :: Capture :: Scanners",
+        &["Graphics", "Scanning"],
+    ),
+    (
+        "Topic :: Multimedia :: Graphics :: Editors :: Raster-Based",
+        &["Graphics", "RasterGraphics"],
+    ),
+    (
+        "Topic :: Multimedia :: Graphics :: Editors :: Vector-Based",
+        &["Graphics", "VectorGraphics"],
+    ),
+    (
+        "Topic :: Multimedia :: Graphics :: Presentation",
+        &["Office", "Presentation"],
+    ),
+    (
+        "Topic :: Multimedia :: Graphics :: Viewers",
+        &["Graphics", "Viewer"],
+    ),
+    (
+        "Topic :: Multimedia :: Sound/Audio",
+        &["AudioVideo", "Audio"],
+    ),
+    (
+        "Topic :: Multimedia :: Sound/Audio :: CD Audio :: CD Writing",
+        &["AudioVideo", "DiscBurning"],
+    ),
+    (
+        "Topic :: Multimedia :: Sound/Audio :: Capture/Recording",
+        &["AudioVideo", "Audio", "Recorder"],
+    ),
+    (
+        "Topic :: Multimedia :: Sound/Audio :: Editors",
+        &["AudioVideo", "Audio", "AudioVideoEditing"],
+    ),
+    (
+        "Topic :: Multimedia :: Sound/Audio :: MIDI",
+        &["AudioVideo", "Audio", "Midi"],
+    ),
+    (
+        "Topic :: Multimedia :: Sound/Audio :: Mixers",
+        &["AudioVideo", "Audio", "Mixer"],
+    ),
+    (
+        "Topic :: Multimedia :: Sound/Audio :: Players",
+        &["AudioVideo", "Audio", "Player"],
+    ),
+    ("Topic :: Multimedia :: Video", &["AudioVideo", "Video"]),
+    (
+        "Topic :: Multimedia :: Video :: Capture",
+        &["AudioVideo", "Video", "Recorder"],
+    ),
+    (
+        "Topic :: Multimedia :: Video :: Display",
+        &["AudioVideo", "Video", "Player"],
+    ),
+    (
+        "Topic :: Multimedia :: Video :: Non-Linear Editor",
+        &["AudioVideo", "Video", "AudioVideoEditing"],
+    ),
+    ("Topic :: Office/Business", &["Office"]),
+    (
+        "Topic :: Office/Business :: Financial",
+        &["Office", "Finance"],
+    ),
+    (
+        "Topic :: Office/Business :: Financial :: Spreadsheet",
+        &["Office", "Spreadsheet"],
+    ),
+    (
+        "Topic :: Office/Business :: Scheduling",
+        &["Office", "Calendar"],
+    ),
+    ("Topic :: Other/Nonlisted Topic", &[]),
+    ("Topic :: Printing", &["Settings", "Printing"]),
+    ("Topic :: Religion", &["Education", "Spirituality"]),
+    ("Topic :: Scientific/Engineering", &["Science"]),
+    (
+        "Topic :: Scientific/Engineering :: Artificial Intelligence",
+        &["Science", "ArtificialIntelligence"],
+    ),
+    (
+        "Topic :: Scientific/Engineering :: Astronomy",
+        &["Science", "Astronomy"],
+    ),
+    (
+        "Topic :: Scientific/Engineering :: Bio-Informatics",
+        &["Science", "Biology"],
+    ),
+    (
+        "Topic :: Scientific/Engineering :: Chemistry",
+        &["Science", "Chemistry"],
+    ),
+    (
+        "Topic :: Scientific/Engineering :: Electronic Design Automation (EDA)",
+        &["Development", "Electronics"],
+    ),
+    (
+        "Topic :: Scientific/Engineering :: GIS",
+        &["Science", "Geography"],
+    ),
+    (
+        "Topic :: Scientific/Engineering :: Image Processing",
+        &["Science", "ImageProcessing"],
+    ),
+    (
+        "Topic :: Scientific/Engineering :: Mathematics",
+        &["Science", "Math"],
+    ),
+    (
+        "Topic :: Scientific/Engineering :: Medical Science Apps.",
+        &["Science", "MedicalSoftware"],
+    ),
+    (
+        "Topic :: Scientific/Engineering :: Physics",
+        &["Science", "Physics"],
+    ),
+    (
+        "Topic :: Scientific/Engineering :: Visualization",
+        &["Science", "DataVisualization"],
+    ),
+    ("Topic :: Security", &["System", "Security"]),
+    ("Topic :: Software Development", &["Development"]),
+    (
+        "Topic :: Software Development :: Build Tools",
+        &["Development", "Building"],
+    ),
+    (
+        "Topic :: Software Development :: Debuggers",
+        &["Development", "Debugger"],
+    ),
+    (
+        "Topic :: Software Development :: Localization",
+        &["Development", "Translation"],
+    ),
+    (
+        "Topic :: Software Development :: User Interfaces",
+        &["Development", "GUIDesigner"],
+    ),
+    (
+        "Topic :: Software Development :: Version Control",
+        &["Development", "RevisionControl"],
+    ),
+    ("Topic :: System", &["System"]),
+    ("Topic :: System :: Archiving", &["Utility", "Archiving"]),
+    (
+        "Topic :: System :: Archiving :: Compression",
+        &["Utility", "Compression"],
+    ),
+    (
+        "Topic :: System :: Archiving :: Packaging",
+        &["System", "PackageManager"],
+    ),
+    ("Topic :: System :: Emulators", &["System", "Emulator"]),
+    ("Topic :: System :: Filesystems", &["System", "Filesystem"]),
+    (
+        "Topic :: System :: Hardware",
+        &["Settings", "HardwareSettings"],
+    ),
+    ("Topic :: System :: Monitoring", &["System", "Monitor"]),
+    ("Topic :: Terminals", &["System", "TerminalEmulator"]),
+    ("Topic :: Text Editors", &["Utility", "TextEditor"]),
+    (
+        "Topic :: Text Editors :: Integrated Development Environments (IDE)",
+        &["Development", "IDE"],
+    ),
+    ("Topic :: Text Processing", &["Utility", "TextTools"]),
+    ("Topic :: Utilities", &["Utility"]),
+];
+
+/// The Debian archive section of a classifier, or of its nearest mapped parent
+pub fn debian_section(classifier: Classifier) -> Option<&'static str> {
+    lookup(DEBIAN, classifier.as_str()).flatten()
+}
+
+/// The RPM group of a classifier, or of its nearest mapped parent
+pub fn rpm_group(classifier: Classifier) -> Option<&'static str> {
+    lookup(RPM, classifier.as_str()).flatten()
+}
+
+/// The Debian section of a project, from its most specific mapped Topic classifier, or else its
+/// most specific mapped Environment classifier
+///
+/// Ties go to the classifier declared first.
+pub fn debian_section_of(classifiers: &[Classifier]) -> Option<&'static str> {
+    best(classifiers, debian_section)
+}
+
+/// The RPM group of a project, chosen as by `debian_section_of`
+pub fn rpm_group_of(classifiers: &[Classifier]) -> Option<&'static str> {
+    best(classifiers, rpm_group)
+}
+
+/// The freedesktop.org menu categories of a classifier, or of its nearest mapped parent
+///
+/// A main category comes first, followed by the additional categories that refine it. Classifiers
+/// without a fitting category have none.
+pub fn desktop_categories(classifier: Classifier) -> &'static [&'static str] {
+    lookup(DESKTOP, classifier.as_str()).unwrap_or(&[])
+}
+
+/// The value of the `Categories` key of a project's desktop entry
+///
+/// Every category of every classifier is listed once, in the order they are first found, each
+/// followed by `;`.
+#[cfg(feature = "alloc")]
+pub fn desktop_entry_categories(classifiers: &[Classifier]) -> String {
+    let mut categories: Vec<&str> = Vec::new();
+    for &classifier in classifiers {
+        for category in desktop_categories(classifier) {
+            if !categories.contains(category) {
+                categories.push(category);
+            }
+        }
+    }
+    let mut value = String::new();
+    for category in categories {
+        value.push_str(category);
+        value.push(';');
+    }
+    value
+}
+
+/// The classifiers of the `Categories` value of a desktop entry, in canonical order
+///
+/// Each category is read as the classifier mapping to it with the fewest other categories, such
+/// as `Game` as "Topic :: Games/Entertainment" and `Qt` as "Environment :: X11 Applications ::
+/// Qt". Classifiers that are parents of others found are left out, so
+/// `Game;BoardGame;` is read as "Topic :: Games/Entertainment :: Board Games" only. Categories
+/// without a classifier are ignored.
+#[cfg(feature = "alloc")]
+pub fn from_desktop_entry_categories(value: &str) -> Vec<Classifier> {
+    let mut classifiers: Vec<Classifier> = value
+        .split(';')
+        .map(str::trim)
+        .filter_map(|category| {
+            DESKTOP
+                .iter()
+                .filter(|(_, categories)| categories.last() == Some(&category))
+                .min_by_key(|(_, categories)| categories.len())
+                .and_then(|(classifier, _)| classifier.parse().ok())
+        })
+        .collect();
+    classifiers.sort_unstable();
+    classifiers.dedup();
+    let parents: Vec<Classifier> = classifiers.iter().filter_map(Classifier::parent).collect();
+    classifiers.retain(|classifier| {
+        !parents.iter().any(|parent| {
+            parent
+                .as_str()
+                .strip_prefix(classifier.as_str())
+                .is_some_and(|rest| rest.is_empty() || rest.starts_with(" :: "))
+        })
+    });
+    classifiers
+}
+
+/// The mapping of the longest prefix of `classifier` in `table`
+fn lookup<T: Copy>(table: &[(&str, T)], classifier: &str) -> Option<T> {
+    let mut prefix = classifier;
+    loop {
+        if let Some((_, mapped)) = table.iter().find(|(key, _)| *key == prefix) {
+            return Some(*mapped);
+        }
+        prefix = prefix.rsplit_once(" :: ")?.0;
+    }
+}
+
+fn best(
+    classifiers: &[Classifier],
+    map: fn(Classifier) -> Option<&'static str>,
+) -> Option<&'static str> {
+    classifiers
+        .iter()
+        .filter_map(|&classifier| Some((classifier, map(classifier)?)))
+        .min_by_key(|(classifier, _)| {
+            let topic = classifier.as_str().starts_with("Topic :: ");
+            (
+                !topic,
+                core::cmp::Reverse(classifier.as_str().matches(" :: ").count()),
+            )
+        })
+        .map(|(_, mapped)| mapped)
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    #[cfg(all(feature = "topic", feature = "environment"))]
+    fn tables_name_known_classifiers() {
+        let desktop = DESKTOP.iter().map(|(classifier, _)| classifier);
+        for classifier in DEBIAN
+            .iter()
+            .chain(RPM)
+            .map(|(classifier, _)| classifier)
+            .chain(desktop)
+        {
+            assert!(classifier.parse::<Classifier>().is_ok(), "{classifier:?}");
+        }
+    }
+
+    #[test]
+    #[cfg(all(feature = "topic", feature = "environment"))]
+    fn projects_map_to_their_most_specific_topic() {
+        let classifiers = [
+            Classifier::Environment__WebEnvironment,
+            Classifier::Topic__SoftwareDevelopment,
+            Classifier::Topic__SoftwareDevelopment__Libraries__PythonModules,
+            Classifier::Topic__Internet,
+        ];
+        assert_eq!(debian_section_of(&classifiers), Some("python"));
+        assert_eq!(rpm_group_of(&classifiers), Some("Development/Libraries"));
+        assert_eq!(
+            debian_section_of(&[Classifier::Environment__WebEnvironment]),
+            Some("web")
+        );
+        assert_eq!(
+            debian_section_of(&[
+                Classifier::Topic__OtherNonlistedTopic,
+                Classifier::Environment__Console__Newt,
+            ]),
+            None
+        );
+        assert_eq!(
+            debian_section(Classifier::Environment__Console__Curses),
+            Some("utils")
+        );
+        assert_eq!(rpm_group(Classifier::Environment__Console__Curses), None);
+    }
+
+    #[test]
+    #[cfg(all(feature = "alloc", feature = "topic", feature = "environment"))]
+    fn desktop_categories_round_trip() {
+        let classifiers = [
+            Classifier::Environment__X11Applications__Qt,
+            Classifier::Topic__GamesEntertainment,
+            Classifier::Topic__GamesEntertainment__BoardGames,
+            Classifier::Topic__GamesEntertainment__PuzzleGames,
+            Classifier::Topic__OtherNonlistedTopic,
+        ];
+        let value = desktop_entry_categories(&classifiers);
+        assert_eq!(value, "Qt;Game;BoardGame;LogicGame;");
+        assert_eq!(
+            from_desktop_entry_categories(&value),
+            [
+                Classifier::Environment__X11Applications__Qt,
+                Classifier::Topic__GamesEntertainment__BoardGames,
+                Classifier::Topic__GamesEntertainment__PuzzleGames,
+            ]
+        );
+        assert_eq!(
+            from_desktop_entry_categories("AudioVideo;Audio;Player;X-Custom;"),
+            [Classifier::Topic__Multimedia__SoundAudio__Players]
+        );
+        assert!(desktop_categories(Classifier::Environment__Console__Curses).is_empty());
+    }
+}
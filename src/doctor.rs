@@ -0,0 +1,466 @@
+//! A prioritized audit of everything a project says about its classifiers.
+//!
+//! `diagnose` runs every check this crate has over a project: the `lint` rules pypi.org enforces,
+//! whether the declared Python versions, licenses, development status and frameworks agree with
+//! `requires-python`, the license expression, the version and the dependencies, whether README
+//! badges match the classifiers, and which classifiers `suggest` and `summary::RECOMMENDED` would
+//! add. Each problem is an `Issue` with a `Priority`, most urgent first, so a project can be fixed
+//! from the top of the report down.
+//!
+//! # Examples
+//!
+#![cfg_attr(not(feature = "programming-language"), doc = "```ignore")]
+#![cfg_attr(feature = "programming-language", doc = "```")]
+//! use trove_classifiers::doctor::{diagnose, Check, Priority};
+//! use trove_classifiers::lint::Declared;
+//! use trove_classifiers::suggest::Project;
+//! use trove_classifiers::Classifier;
+//!
+//! let project = Project {
+//!     requires_python: Some(">=3.10".to_string()),
+//!     classifiers: vec![Classifier::ProgrammingLanguage__Python__3_8],
+//!     ..Project::default()
+//! };
+//! let declared = [
+//!     Declared::new("Programming Language :: Python :: 3.8").line(4),
+//!     Declared::new("Typing :: Sometimes").line(5),
+//! ];
+//! let issues = diagnose(&project, &declared, None);
+//! assert_eq!(issues[0].priority, Priority::High);
+//! assert_eq!(issues[0].check, Check::Validity);
+//! assert_eq!(issues[1].check, Check::RequiresPython);
+//! assert_eq!(issues[1].line, Some(4));
+//! ```
+
+use alloc::format;
+use alloc::string::{String, ToString};
+use alloc::vec::Vec;
+use core::cmp::Reverse;
+use core::fmt;
+
+use crate::badge::{self, Badge};
+use crate::lint::{self, Declared, Finding, Rule, Severity, Strictness};
+use crate::pep440::{admits_series, Release};
+use crate::suggest::{self, Project};
+use crate::summary::Summary;
+use crate::{license, Classifier};
+
+/// How urgently an issue should be fixed
+#[derive(Clone, Copy, Debug, Eq, Hash, Ord, PartialEq, PartialOrd)]
+pub enum Priority {
+    /// The classifiers could say more about the project
+    Low,
+    /// The classifiers contradict the project
+    Medium,
+    /// pypi.org rejects an upload of the project
+    High,
+}
+
+impl Priority {
+    pub fn as_str(&self) -> &'static str {
+        match self {
+            Priority::Low => "low",
+            Priority::Medium => "medium",
+            Priority::High => "high",
+        }
+    }
+}
+
+impl fmt::Display for Priority {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        f.pad(self.as_str())
+    }
+}
+
+/// The audit that found an issue
+#[derive(Clone, Copy, Debug, Eq, Hash, Ord, PartialEq, PartialOrd)]
+#[non_exhaustive]
+pub enum Check {
+    /// A `lint` rule other than deprecation
+    Validity,
+    /// A deprecated classifier
+    Deprecated,
+    /// A Python version `requires-python` excludes
+    RequiresPython,
+    /// A License classifier the license expression does not name
+    License,
+    /// A `Development Status` the version contradicts
+    Version,
+    /// A `Framework` classifier the dependencies contradict
+    Dependency,
+    /// A README badge that disagrees with the classifiers
+    Readme,
+    /// A built artifact whose classifiers differ from the project's
+    Artifact,
+    /// A classifier the project could add
+    Recommendation,
+}
+
+impl Check {
+    pub fn as_str(&self) -> &'static str {
+        match self {
+            Check::Validity => "validity",
+            Check::Deprecated => "deprecated",
+            Check::RequiresPython => "requires-python",
+            Check::License => "license",
+            Check::Version => "version",
+            Check::Dependency => "dependency",
+            Check::Readme => "readme",
+            Check::Artifact => "artifact",
+            Check::Recommendation => "recommendation",
+        }
+    }
+}
+
+impl fmt::Display for Check {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        f.pad(self.as_str())
+    }
+}
+
+/// A problem found by an audit
+#[derive(Clone, Debug, Eq, Hash, PartialEq)]
+pub struct Issue {
+    pub priority: Priority,
+    pub check: Check,
+    /// The 1-based line of the declaration at fault, if known
+    pub line: Option<usize>,
+    pub message: String,
+}
+
+impl From<Finding> for Issue {
+    fn from(finding: Finding) -> Self {
+        Issue {
+            priority: match finding.severity {
+                Severity::Error => Priority::High,
+                Severity::Warning => Priority::Medium,
+            },
+            check: match finding.rule {
+                Rule::Deprecated => Check::Deprecated,
+                Rule::VersionStatus => Check::Version,
+                _ => Check::Validity,
+            },
+            line: finding.line,
+            message: finding.message,
+        }
+    }
+}
+
+/// Every issue of a project, most urgent first
+///
+/// `declared` are the project's classifiers as written, for `lint` and for locating issues, and
+/// `readme` is the text of its README, if it has one.
+pub fn diagnose(project: &Project, declared: &[Declared], readme: Option<&str>) -> Vec<Issue> {
+    let line = |classifier: Classifier| {
+        declared
+            .iter()
+            .find(|declared| declared.value.trim() == classifier.as_str())
+            .and_then(|declared| declared.line)
+    };
+    let mut issues: Vec<Issue> = lint::lint(declared).into_iter().map(Issue::from).collect();
+    if let Some(version) = &project.version {
+        let findings = lint::lint_version(version, declared, Strictness::Lenient);
+        issues.extend(findings.into_iter().map(Issue::from));
+    }
+
+    if let Some(specifiers) = &project.requires_python {
+        for &classifier in &project.classifiers {
+            let ["Programming Language", "Python", version] = classifier.segments_static() else {
+                continue;
+            };
+            let excluded = Release::parse(version)
+                .filter(|release| release.parts().len() == 2)
+                .and_then(|release| admits_series(specifiers, release))
+                == Some(false);
+            if excluded {
+                issues.push(Issue {
+                    priority: Priority::Medium,
+                    check: Check::RequiresPython,
+                    line: line(classifier),
+                    message: format!(
+                        "{:?} is declared, but requires-python {specifiers:?} excludes it",
+                        classifier.as_str()
+                    ),
+                });
+            }
+        }
+    }
+
+    if let Some(expression) = &project.license {
+        let named: Vec<Classifier> = license::spdx_ids(expression)
+            .filter_map(license::from_spdx)
+            .collect();
+        for &classifier in &project.classifiers {
+            let Some(id) = license::to_spdx(classifier) else {
+                continue;
+            };
+            if !named.is_empty() && !named.contains(&classifier) {
+                issues.push(Issue {
+                    priority: Priority::Medium,
+                    check: Check::License,
+                    line: line(classifier),
+                    message: format!(
+                        "{:?} is declared, but the license {expression:?} does not include {id}",
+                        classifier.as_str()
+                    ),
+                });
+            }
+        }
+    }
+
+    // Without dependencies, they are most likely declared dynamically and cannot be compared
+    if !project.dependencies.is_empty() {
+        for constraint in suggest::dependency_constraints(&project.classifiers) {
+            let framework = constraint.framework;
+            let dependency = project
+                .dependencies
+                .iter()
+                .map(|dependency| suggest::requirement(dependency))
+                .find(|(name, _)| *name == constraint.name);
+            let Some((name, specifiers)) = dependency else {
+                issues.push(Issue {
+                    priority: Priority::Medium,
+                    check: Check::Dependency,
+                    line: line(framework),
+                    message: format!(
+                        "{:?} is declared, but the project does not depend on {}, add \
+                         {:?} to the dependencies",
+                        framework.as_str(),
+                        constraint.name,
+                        constraint.to_string()
+                    ),
+                });
+                continue;
+            };
+            let depth = framework.segments_static().len();
+            for &classifier in &project.classifiers {
+                if classifier == framework || classifier.family() != framework {
+                    continue;
+                }
+                let excluded = classifier
+                    .segments_static()
+                    .get(depth)
+                    .and_then(|version| Release::parse(version))
+                    .and_then(|release| admits_series(specifiers, release))
+                    == Some(false);
+                if excluded {
+                    issues.push(Issue {
+                        priority: Priority::Medium,
+                        check: Check::Dependency,
+                        line: line(classifier),
+                        message: format!(
+                            "{:?} is declared, but the dependency on {name} {specifiers:?} \
+                             excludes it",
+                            classifier.as_str()
+                        ),
+                    });
+                }
+            }
+        }
+    }
+
+    if let Some(readme) = readme {
+        for badge in badge::badges(&project.classifiers) {
+            if let Some(message) = stale_badge(readme, &badge) {
+                issues.push(Issue {
+                    priority: Priority::Medium,
+                    check: Check::Readme,
+                    line: None,
+                    message,
+                });
+            }
+        }
+    }
+
+    let suggestions = suggest::suggest(project);
+    for suggestion in &suggestions {
+        issues.push(Issue {
+            priority: Priority::Low,
+            check: Check::Recommendation,
+            line: None,
+            message: format!(
+                "add {:?} ({})",
+                suggestion.classifier.as_str(),
+                suggestion.reason
+            ),
+        });
+    }
+    let mut covered = project.classifiers.clone();
+    covered.extend(suggestions.iter().map(|suggestion| suggestion.classifier));
+    for prefix in Summary::new(&covered).missing {
+        issues.push(Issue {
+            priority: Priority::Low,
+            check: Check::Recommendation,
+            line: None,
+            message: format!("no {prefix:?} classifier is declared"),
+        });
+    }
+
+    // A stable sort keeps the issues of each priority in the order they were found
+    issues.sort_by_key(|issue| Reverse(issue.priority));
+    issues
+}
+
+/// An issue if the classifiers of a built artifact differ from those of its project
+pub fn compare_artifact(project: &[Classifier], built: &[Classifier]) -> Option<Issue> {
+    let missing = project.iter().filter(|c| !built.contains(c)).count();
+    let extra = built.iter().filter(|c| !project.contains(c)).count();
+    (missing + extra > 0).then(|| Issue {
+        priority: Priority::Medium,
+        check: Check::Artifact,
+        line: None,
+        message: format!(
+            "built with stale classifiers ({missing} missing, {extra} no longer declared), \
+             rebuild it"
+        ),
+    })
+}
+
+/// A message if the README shows a shields.io badge of the same kind as `badge` that differs
+fn stale_badge(readme: &str, badge: &Badge) -> Option<String> {
+    let expected = badge.url();
+    let prefix = format!("https://img.shields.io/badge/{}-", badge.kind.label());
+    let mut rest = readme;
+    while let Some(start) = rest.find(&prefix) {
+        let url = &rest[start..];
+        let end = url
+            .find(|c: char| c.is_whitespace() || matches!(c, ')' | '"' | '\'' | '>' | '?'))
+            .unwrap_or(url.len());
+        if url[..end] != expected {
+            return Some(format!(
+                "the README's {} badge is out of date, use {expected}",
+                badge.kind.label()
+            ));
+        }
+        rest = &url[end..];
+    }
+    None
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn empty_projects_only_miss_recommendations() {
+        let issues = diagnose(&Project::default(), &[], None);
+        assert!(issues
+            .iter()
+            .all(|issue| issue.priority == Priority::Low && issue.check == Check::Recommendation));
+        assert!(issues
+            .iter()
+            .any(|issue| issue.message == "no \"Development Status\" classifier is declared"));
+    }
+
+    #[test]
+    #[cfg(feature = "all-categories")]
+    fn contradictions_are_located() {
+        let project = Project {
+            license: Some("MIT".into()),
+            requires_python: Some(">=3.9".into()),
+            classifiers: vec![
+                Classifier::License__OSIApproved__ApacheSoftwareLicense,
+                Classifier::ProgrammingLanguage__Python__3_9,
+            ],
+            ..Project::default()
+        };
+        let declared = [
+            Declared::new("License :: OSI Approved :: Apache Software License").line(7),
+            Declared::new("Programming Language :: Python :: 3.9").line(8),
+            Declared::new("Natural Language :: Ukranian").line(9),
+        ];
+        let issues = diagnose(&project, &declared, None);
+        assert_eq!(issues[0].check, Check::Deprecated);
+        assert_eq!(issues[0].priority, Priority::High);
+        assert_eq!(issues[1].check, Check::License);
+        assert_eq!(issues[1].line, Some(7));
+        assert_eq!(
+            issues[1].message,
+            "\"License :: OSI Approved :: Apache Software License\" is declared, but the license \
+             \"MIT\" does not include Apache-2.0"
+        );
+        assert!(issues[2..]
+            .iter()
+            .all(|issue| issue.priority == Priority::Low));
+    }
+
+    #[test]
+    #[cfg(feature = "development-status")]
+    fn statuses_must_match_the_version() {
+        let project = Project {
+            version: Some("3.4.1".into()),
+            classifiers: vec![Classifier::DevelopmentStatus__2PreAlpha],
+            ..Project::default()
+        };
+        let declared = [Declared::new("Development Status :: 2 - Pre-Alpha").line(3)];
+        let issues = diagnose(&project, &declared, None);
+        assert_eq!(issues[0].check, Check::Version);
+        assert_eq!(issues[0].priority, Priority::Medium);
+        assert_eq!(issues[0].line, Some(3));
+    }
+
+    #[test]
+    #[cfg(feature = "framework")]
+    fn frameworks_must_match_the_dependencies() {
+        let project = Project {
+            dependencies: vec!["django>=4.2,<5".into(), "attrs".into()],
+            classifiers: vec![
+                Classifier::Framework__Django__3_2,
+                Classifier::Framework__Django__4_2,
+                Classifier::Framework__Flask,
+            ],
+            ..Project::default()
+        };
+        let declared = [
+            Declared::new("Framework :: Django :: 3.2").line(2),
+            Declared::new("Framework :: Django :: 4.2").line(3),
+            Declared::new("Framework :: Flask").line(4),
+        ];
+        let issues: Vec<Issue> = diagnose(&project, &declared, None)
+            .into_iter()
+            .filter(|issue| issue.check == Check::Dependency)
+            .collect();
+        assert_eq!(issues.len(), 2);
+        assert_eq!(issues[0].line, Some(2));
+        assert_eq!(
+            issues[0].message,
+            "\"Framework :: Django :: 3.2\" is declared, but the dependency on django \
+             \">=4.2,<5\" excludes it"
+        );
+        assert_eq!(issues[1].line, Some(4));
+        assert!(issues[1]
+            .message
+            .ends_with("add \"flask\" to the dependencies"));
+    }
+
+    #[test]
+    #[cfg(feature = "programming-language")]
+    fn readme_badges_must_match() {
+        let project = Project {
+            classifiers: vec![Classifier::ProgrammingLanguage__Python__3_12],
+            ..Project::default()
+        };
+        let readme = "![python](https://img.shields.io/badge/python-3.11-blue)\n";
+        let issues = diagnose(&project, &[], Some(readme));
+        assert_eq!(issues[0].check, Check::Readme);
+        assert!(issues[0]
+            .message
+            .ends_with("use https://img.shields.io/badge/python-3.12-blue"));
+
+        let readme = "![python](https://img.shields.io/badge/python-3.12-blue)\n";
+        let issues = diagnose(&project, &[], Some(readme));
+        assert!(issues.iter().all(|issue| issue.check != Check::Readme));
+    }
+
+    #[test]
+    #[cfg(feature = "typing")]
+    fn artifacts_must_match_their_project() {
+        let typed = [Classifier::Typing__Typed];
+        assert_eq!(compare_artifact(&typed, &typed), None);
+        let issue = compare_artifact(&typed, &[]).unwrap();
+        assert_eq!(issue.check, Check::Artifact);
+        assert!(issue
+            .message
+            .starts_with("built with stale classifiers (1 missing"));
+    }
+}
@@ -0,0 +1,92 @@
+use alloc::sync::Arc;
+use core::convert::Infallible;
+use core::fmt;
+use core::str::FromStr;
+
+use crate::Classifier;
+
+/// A classifier string that may or may not be known to this crate's dataset
+///
+/// Metadata read from the wild carries classifiers that are misspelled, private, or newer than
+/// `PYPA_VERSION`. `DynamicClassifier` keeps those strings instead of rejecting them, so they
+/// can be reported or passed through unchanged.
+///
+/// Unknown strings are reference counted, so clones share their storage. An `Interner` extends
+/// that sharing to every occurrence of the same unknown string.
+///
+/// # Examples
+///
+#[cfg_attr(not(feature = "typing"), doc = "```ignore")]
+#[cfg_attr(feature = "typing", doc = "```")]
+/// use trove_classifiers::{Classifier, DynamicClassifier};
+///
+/// let known = DynamicClassifier::parse("Typing :: Typed");
+/// assert_eq!(known.known(), Some(Classifier::Typing__Typed));
+///
+/// let unknown = DynamicClassifier::parse("Private :: Do Not Upload");
+/// assert_eq!(unknown.known(), None);
+/// assert_eq!(unknown.as_str(), "Private :: Do Not Upload");
+/// ```
+#[derive(Clone, Debug, Eq, Hash, Ord, PartialEq, PartialOrd)]
+pub enum DynamicClassifier {
+    /// A classifier in this crate's dataset
+    Known(Classifier),
+    /// Any other string
+    Unknown(Arc<str>),
+}
+
+impl DynamicClassifier {
+    /// Parse a string, keeping it as `Unknown` if it is not a known classifier
+    pub fn parse(s: &str) -> Self {
+        match Classifier::from_str(s) {
+            Ok(classifier) => DynamicClassifier::Known(classifier),
+            Err(_) => {
+                #[cfg(feature = "telemetry")]
+                crate::telemetry::unknown(s);
+                DynamicClassifier::Unknown(Arc::from(s))
+            }
+        }
+    }
+
+    /// The classifier, if it is in this crate's dataset
+    pub fn known(&self) -> Option<Classifier> {
+        match self {
+            DynamicClassifier::Known(classifier) => Some(*classifier),
+            DynamicClassifier::Unknown(_) => None,
+        }
+    }
+
+    /// The classifier's string
+    pub fn as_str(&self) -> &str {
+        match self {
+            DynamicClassifier::Known(classifier) => classifier.as_str(),
+            DynamicClassifier::Unknown(string) => string,
+        }
+    }
+}
+
+impl From<Classifier> for DynamicClassifier {
+    fn from(classifier: Classifier) -> Self {
+        DynamicClassifier::Known(classifier)
+    }
+}
+
+impl AsRef<str> for DynamicClassifier {
+    fn as_ref(&self) -> &str {
+        self.as_str()
+    }
+}
+
+impl fmt::Display for DynamicClassifier {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        f.pad(self.as_str())
+    }
+}
+
+impl FromStr for DynamicClassifier {
+    type Err = Infallible;
+
+    fn from_str(s: &str) -> Result<Self, Self::Err> {
+        Ok(Self::parse(s))
+    }
+}
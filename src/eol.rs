@@ -0,0 +1,130 @@
+//! End-of-life dates of versioned frameworks.
+//!
+//! The projects behind some frameworks publish when each of their versions stops receiving
+//! security fixes. `end_of_life` gives that date, as an ISO 8601 `YYYY-MM-DD` string that
+//! compares chronologically as a string, for the versioned classifiers of Django, Odoo and Plone.
+//! A classifier naming a major version, such as "Framework :: Django :: 4", ends its life with
+//! the last of its minor versions.
+//!
+//! The dates are those published as of `AS_OF`, which is the date `Classifier::is_framework_eol`
+//! compares against, so that its answer only changes with the version of this crate. Tools that
+//! need to know about a later date compare it with `end_of_life` themselves.
+//!
+//! # Examples
+//!
+#![cfg_attr(not(feature = "framework"), doc = "```ignore")]
+#![cfg_attr(feature = "framework", doc = "```")]
+//! use trove_classifiers::{eol, Classifier};
+//!
+//! let django = Classifier::Framework__Django__3_2;
+//! assert_eq!(eol::end_of_life(django), Some("2024-04-01"));
+//! assert!(django.is_framework_eol());
+//! assert!(!Classifier::Framework__Django__5_2.is_framework_eol());
+//! assert!("2028-05-01" > eol::end_of_life(Classifier::Framework__Django__5_2).unwrap());
+//! ```
+
+use crate::Classifier;
+
+/// The date the end-of-life dates were last checked against their projects' schedules
+pub const AS_OF: &str = "2026-10-01";
+
+/// Each versioned classifier with a published end of life, and that date
+///
+/// Django: <https://www.djangoproject.com/download/#supported-versions>, Odoo: three years after
+/// each release, <https://www.odoo.com/documentation/master/administration/supported_versions.html>,
+/// Plone: <https://plone.org/download/release-schedule>.
+static END_OF_LIFE: &[(&str, &str)] = &[
+    ("Framework :: Django :: 1", "2020-04-01"),
+    ("Framework :: Django :: 1.4", "2015-10-01"),
+    ("Framework :: Django :: 1.5", "2014-09-02"),
+    ("Framework :: Django :: 1.6", "2015-04-01"),
+    ("Framework :: Django :: 1.7", "2015-12-01"),
+    ("Framework :: Django :: 1.8", "2018-04-01"),
+    ("Framework :: Django :: 1.9", "2017-04-04"),
+    ("Framework :: Django :: 1.10", "2017-12-02"),
+    ("Framework :: Django :: 1.11", "2020-04-01"),
+    ("Framework :: Django :: 2", "2022-04-11"),
+    ("Framework :: Django :: 2.0", "2019-04-01"),
+    ("Framework :: Django :: 2.1", "2019-12-02"),
+    ("Framework :: Django :: 2.2", "2022-04-11"),
+    ("Framework :: Django :: 3", "2024-04-01"),
+    ("Framework :: Django :: 3.0", "2021-04-06"),
+    ("Framework :: Django :: 3.1", "2021-12-07"),
+    ("Framework :: Django :: 3.2", "2024-04-01"),
+    ("Framework :: Django :: 4", "2026-04-30"),
+    ("Framework :: Django :: 4.0", "2023-04-01"),
+    ("Framework :: Django :: 4.1", "2023-12-04"),
+    ("Framework :: Django :: 4.2", "2026-04-30"),
+    ("Framework :: Django :: 5", "2028-04-30"),
+    ("Framework :: Django :: 5.0", "2025-04-02"),
+    ("Framework :: Django :: 5.1", "2025-12-03"),
+    ("Framework :: Django :: 5.2", "2028-04-30"),
+    ("Framework :: Odoo :: 8.0", "2017-10-01"),
+    ("Framework :: Odoo :: 9.0", "2018-10-01"),
+    ("Framework :: Odoo :: 10.0", "2019-10-01"),
+    ("Framework :: Odoo :: 11.0", "2020-10-01"),
+    ("Framework :: Odoo :: 12.0", "2021-10-01"),
+    ("Framework :: Odoo :: 13.0", "2022-10-01"),
+    ("Framework :: Odoo :: 14.0", "2023-10-01"),
+    ("Framework :: Odoo :: 15.0", "2024-10-01"),
+    ("Framework :: Odoo :: 16.0", "2025-10-01"),
+    ("Framework :: Odoo :: 17.0", "2026-11-01"),
+    ("Framework :: Odoo :: 18.0", "2027-10-01"),
+    ("Framework :: Plone :: 3.2", "2013-07-01"),
+    ("Framework :: Plone :: 3.3", "2013-07-01"),
+    ("Framework :: Plone :: 4.0", "2015-12-31"),
+    ("Framework :: Plone :: 4.1", "2015-12-31"),
+    ("Framework :: Plone :: 4.2", "2015-12-31"),
+    ("Framework :: Plone :: 4.3", "2021-12-31"),
+    ("Framework :: Plone :: 5.0", "2019-12-31"),
+    ("Framework :: Plone :: 5.1", "2021-12-31"),
+    ("Framework :: Plone :: 5.2", "2025-10-31"),
+    ("Framework :: Plone :: 6.0", "2026-10-31"),
+];
+
+/// The date a framework version stops receiving security fixes, if it is published
+pub fn end_of_life(classifier: Classifier) -> Option<&'static str> {
+    let classifier = classifier.as_str();
+    END_OF_LIFE
+        .iter()
+        .find(|(versioned, _)| *versioned == classifier)
+        .map(|(_, date)| *date)
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn every_dated_classifier_is_a_framework_version() {
+        for (classifier, date) in END_OF_LIFE {
+            assert!(classifier.starts_with("Framework :: "), "{classifier}");
+            assert_eq!(date.len(), 10, "{classifier}");
+            #[cfg(feature = "framework")]
+            assert!(classifier.parse::<Classifier>().is_ok(), "{classifier}");
+        }
+    }
+
+    #[test]
+    #[cfg(feature = "framework")]
+    fn major_versions_end_with_their_last_minor_version() {
+        for major in [
+            Classifier::Framework__Django__1,
+            Classifier::Framework__Django__2,
+            Classifier::Framework__Django__3,
+            Classifier::Framework__Django__4,
+            Classifier::Framework__Django__5,
+        ] {
+            let last = END_OF_LIFE
+                .iter()
+                .filter(|(classifier, _)| {
+                    classifier
+                        .strip_prefix(major.as_str())
+                        .is_some_and(|rest| rest.starts_with('.'))
+                })
+                .map(|(_, date)| *date)
+                .max();
+            assert_eq!(end_of_life(major), last, "{major}");
+        }
+    }
+}
@@ -0,0 +1,916 @@
+//! Descriptions of the dataset for schema languages, other toolchains and documentation.
+//!
+//! Documents validated outside of Rust, such as a `pyproject.toml` checked by a JSON Schema
+//! validator or a request to an API described with OpenAPI, can only reject unknown classifiers
+//! if the validator knows them. The functions of this module describe the accepted classifiers
+//! to those validators and to other languages, optionally only those of some top-level
+//! categories, so those descriptions are generated from the same dataset as `Classifier` rather
+//! than copied by hand.
+//!
+//! # Examples
+//!
+#![cfg_attr(not(feature = "typing"), doc = "```ignore")]
+#![cfg_attr(feature = "typing", doc = "```")]
+//! use trove_classifiers::export::json_schema;
+//!
+//! let schema = json_schema(&["Typing"]);
+//! assert!(schema.contains("\"enum\": [\n    \"Typing :: Stubs Only\",\n    \"Typing :: Typed\"\n  ]"));
+//! ```
+
+use alloc::string::{String, ToString};
+use alloc::vec::Vec;
+use core::fmt;
+use core::str::FromStr;
+
+use crate::generated::RETIRED_CODES;
+use crate::html::escape;
+use crate::json::push_string;
+use crate::{deprecation, deprecations, stable_id, Classifier, PYPA_VERSION};
+
+/// The columns of `delimited`, in order
+pub const COLUMNS: &[&str] = &[
+    "classifier",
+    "category",
+    "parent",
+    "depth",
+    "deprecated",
+    "replacements",
+    "code",
+    "stable_id",
+];
+
+/// The field separator of a `delimited` table
+#[derive(Clone, Copy, Debug, Eq, Hash, PartialEq)]
+pub enum Delimiter {
+    /// Comma separated values, quoted as described by RFC 4180
+    Comma,
+    /// Tab separated values, which never need quoting as classifiers hold no tabs or newlines
+    Tab,
+}
+
+/// The accepted classifiers of the given top-level categories, or of all of them if none are
+/// given, in canonical order
+fn classifiers<'a>(categories: &'a [&str]) -> impl Iterator<Item = Classifier> + 'a {
+    Classifier::ALL.iter().copied().filter(|classifier| {
+        categories.is_empty() || categories.contains(&classifier.segments_static()[0])
+    })
+}
+
+/// A JSON Schema of a string that must be an accepted classifier of the given top-level
+/// categories, such as `"Framework"`, or of any category if none are given
+///
+/// The schema only uses `type` and `enum`, so it is valid in every draft, and has no `$schema`
+/// so that it can be embedded, for example as the `items` of an array of classifiers. The full
+/// schema is also checked in as `schema/classifier.schema.json`.
+pub fn json_schema(categories: &[&str]) -> String {
+    let mut out = String::from("{\n");
+    push_schema(&mut out, "  ", categories);
+    out.push_str("}\n");
+    out
+}
+
+/// An OpenAPI 3 document holding the `TroveClassifier` schema component: a string that must be
+/// an accepted classifier of the given top-level categories, or of any category if none are
+/// given
+///
+/// APIs reference it as `#/components/schemas/TroveClassifier` once it is merged into their
+/// document, or by the path of the file it is written to.
+pub fn openapi(categories: &[&str]) -> String {
+    let mut out = String::from("{\n  \"components\": {\n    \"schemas\": {\n");
+    out.push_str("      \"TroveClassifier\": {\n");
+    push_schema(&mut out, "        ", categories);
+    out.push_str("      }\n    }\n  }\n}\n");
+    out
+}
+
+/// A proto3 file declaring the `trove.TroveClassifier` enum of the accepted classifiers of the
+/// given top-level categories, or of every category if none are given
+///
+/// Each value is numbered with its classifier's stable `code`, so messages encoded against one
+/// version of the file decode correctly against any other. The codes of classifiers that have
+/// left the dataset are reserved, and 0 is `TROVE_CLASSIFIER_UNSPECIFIED`, as proto3 requires.
+///
+/// # Examples
+///
+#[cfg_attr(not(feature = "typing"), doc = "```ignore")]
+#[cfg_attr(feature = "typing", doc = "```")]
+/// use trove_classifiers::export::protobuf;
+/// use trove_classifiers::Classifier;
+///
+/// let proto = protobuf(&["Typing"]);
+/// let code = Classifier::Typing__Typed.code();
+/// assert!(proto.contains(&format!("  TROVE_CLASSIFIER_TYPING_TYPED = {code};\n")));
+/// ```
+pub fn protobuf(categories: &[&str]) -> String {
+    let mut out = alloc::format!(
+        "// Classifiers pypi.org accepts, as of trove-classifiers {PYPA_VERSION}.\n\
+         // Values are numbered with the classifiers' stable codes.\n\n\
+         syntax = \"proto3\";\n\n\
+         package trove;\n\n\
+         enum TroveClassifier {{\n  \
+         TROVE_CLASSIFIER_UNSPECIFIED = 0;\n"
+    );
+    if !RETIRED_CODES.is_empty() {
+        let retired: Vec<String> = RETIRED_CODES.iter().map(|code| code.to_string()).collect();
+        out.push_str("  reserved ");
+        out.push_str(&retired.join(", "));
+        out.push_str(";\n");
+    }
+    for classifier in classifiers(categories) {
+        out.push_str("  // ");
+        out.push_str(classifier.as_str());
+        out.push_str("\n  TROVE_CLASSIFIER_");
+        out.push_str(&protobuf_name(classifier.as_str()));
+        out.push_str(" = ");
+        out.push_str(&classifier.code().to_string());
+        out.push_str(";\n");
+    }
+    out.push_str("}\n");
+    out
+}
+
+/// A TypeScript declaration of the `TroveClassifier` type, the union of the accepted classifiers
+/// of the given top-level categories, or of every category if none are given, as string literals
+///
+/// Frontend code typed with it only compiles with classifier strings that the same version of
+/// the dataset accepts.
+///
+/// # Examples
+///
+#[cfg_attr(not(feature = "typing"), doc = "```ignore")]
+#[cfg_attr(feature = "typing", doc = "```")]
+/// use trove_classifiers::export::typescript;
+///
+/// assert!(typescript(&["Typing"]).ends_with(
+///     "export type TroveClassifier =\n  | \"Typing :: Stubs Only\"\n  | \"Typing :: Typed\";\n"
+/// ));
+/// ```
+pub fn typescript(categories: &[&str]) -> String {
+    let mut out = alloc::format!(
+        "// Classifiers pypi.org accepts, as of trove-classifiers {PYPA_VERSION}.\n\
+         export type TroveClassifier ="
+    );
+    let mut empty = true;
+    for classifier in classifiers(categories) {
+        empty = false;
+        out.push_str("\n  | ");
+        // JSON strings are also TypeScript string literals
+        push_string(&mut out, classifier.as_str());
+    }
+    if empty {
+        out.push_str(" never");
+    }
+    out.push_str(";\n");
+    out
+}
+
+/// A table of the classifiers of the given top-level categories, or of every category if none
+/// are given, for spreadsheets and data warehouses
+///
+/// The table has a header of `COLUMNS` and one row per classifier: the accepted ones in
+/// canonical order, then the deprecated ones. Rows hold the classifier, its top-level category,
+/// the classifier it is nested in if any, its number of segments, whether it is deprecated, the
+/// classifiers replacing it separated by `;`, its stable code, which deprecated classifiers do
+/// not have, and its `stable_id` in hexadecimal.
+///
+/// # Examples
+///
+#[cfg_attr(not(feature = "typing"), doc = "```ignore")]
+#[cfg_attr(feature = "typing", doc = "```")]
+/// use trove_classifiers::export::{delimited, Delimiter};
+///
+/// let table = delimited(&["Typing"], Delimiter::Tab);
+/// let mut rows = table.lines();
+/// assert_eq!(
+///     rows.next(),
+///     Some("classifier\tcategory\tparent\tdepth\tdeprecated\treplacements\tcode\tstable_id")
+/// );
+/// assert_eq!(
+///     rows.next(),
+///     Some("Typing :: Stubs Only\tTyping\t\t2\tfalse\t\t864\t63c9cb953722ba5f")
+/// );
+/// ```
+pub fn delimited(categories: &[&str], delimiter: Delimiter) -> String {
+    let mut out = String::new();
+    write_delimited(&mut out, categories, delimiter).expect("writing to a String cannot fail");
+    out
+}
+
+/// Write the table of `delimited` to `out`, one row at a time
+pub fn write_delimited(
+    out: &mut impl fmt::Write,
+    categories: &[&str],
+    delimiter: Delimiter,
+) -> fmt::Result {
+    let separator = match delimiter {
+        Delimiter::Comma => ",",
+        Delimiter::Tab => "\t",
+    };
+    let field = |out: &mut dyn fmt::Write, field: &str| match delimiter {
+        Delimiter::Comma if field.contains([',', '"', '\n', '\r']) => {
+            write!(out, "\"{}\"", field.replace('"', "\"\""))
+        }
+        _ => out.write_str(field),
+    };
+    writeln!(out, "{}", COLUMNS.join(separator))?;
+    let row = |out: &mut dyn fmt::Write,
+               classifier: &str,
+               replacements: &[&str],
+               code: Option<u16>|
+     -> fmt::Result {
+        let (category, depth) = (
+            classifier.split(" :: ").next().unwrap_or(classifier),
+            classifier.split(" :: ").count(),
+        );
+        let parent = classifier
+            .rsplit_once(" :: ")
+            .and_then(|(parent, _)| Classifier::from_str(parent).ok())
+            .map_or("", |parent| parent.as_str());
+        field(out, classifier)?;
+        out.write_str(separator)?;
+        field(out, category)?;
+        out.write_str(separator)?;
+        field(out, parent)?;
+        write!(
+            out,
+            "{separator}{depth}{separator}{}{separator}",
+            code.is_none()
+        )?;
+        field(out, &replacements.join(";"))?;
+        out.write_str(separator)?;
+        if let Some(code) = code {
+            write!(out, "{code}")?;
+        }
+        writeln!(out, "{separator}{:016x}", stable_id(classifier))
+    };
+    for classifier in classifiers(categories) {
+        row(out, classifier.as_str(), &[], Some(classifier.code()))?;
+    }
+    for deprecation in deprecations() {
+        let category = deprecation.as_str().split(" :: ").next().unwrap_or("");
+        if !categories.is_empty() && !categories.contains(&category) {
+            continue;
+        }
+        let replacements: Vec<&str> = deprecation.replacements().map(|c| c.as_str()).collect();
+        row(out, deprecation.as_str(), &replacements, None)?;
+    }
+    Ok(())
+}
+
+/// The most classifiers an `html` page shows with every level of the tree expanded
+const EXPANDED: usize = 100;
+
+/// A standalone HTML page showing classifiers as a collapsible tree, such as `Classifier::ALL`
+/// for the whole taxonomy or the classifiers of a project, with a box filtering the tree by
+/// substring if `search` is set
+///
+/// The page has no external resources, so it can be served from anywhere. Trees of more than
+/// 100 classifiers start collapsed.
+///
+/// # Examples
+///
+#[cfg_attr(not(feature = "typing"), doc = "```ignore")]
+#[cfg_attr(feature = "typing", doc = "```")]
+/// use trove_classifiers::export::html;
+/// use trove_classifiers::Classifier;
+///
+/// let page = html("example", &[Classifier::Typing__Typed], false);
+/// assert!(page.contains("<title>example</title>"));
+/// assert!(page.contains(r#"<li data-path="Typing :: Typed">Typed</li>"#));
+/// ```
+pub fn html(title: &str, classifiers: &[Classifier], search: bool) -> String {
+    let mut classifiers = classifiers.to_vec();
+    classifiers.sort();
+    classifiers.dedup();
+    let mut root = Node::default();
+    for classifier in &classifiers {
+        root.insert(classifier.segments_static());
+    }
+    let title = escape(title);
+    let mut out = alloc::format!(
+        "<!DOCTYPE html>\n<html lang=\"en\">\n<head>\n<meta charset=\"utf-8\">\n\
+         <title>{title}</title>\n<style>\n\
+         ul {{ list-style: none; padding-left: 1.25em; }}\n\
+         summary {{ cursor: pointer; }}\n\
+         </style>\n</head>\n<body>\n<h1>{title}</h1>\n\
+         <p>{} classifiers, from trove-classifiers {PYPA_VERSION}.</p>\n",
+        classifiers.len()
+    );
+    if search {
+        out.push_str(
+            "<input type=\"search\" id=\"filter\" placeholder=\"Filter classifiers\" \
+             aria-label=\"Filter classifiers\">\n",
+        );
+    }
+    root.render_html(&mut out, "", classifiers.len() <= EXPANDED);
+    if search {
+        out.push_str(FILTER_SCRIPT);
+    }
+    out.push_str("</body>\n</html>\n");
+    out
+}
+
+/// Hides the items of an `html` tree whose path, and the paths below it, do not contain the
+/// filter, and expands the rest
+const FILTER_SCRIPT: &str = r#"<script>
+const filter = document.getElementById("filter");
+filter.addEventListener("input", () => {
+  const query = filter.value.trim().toLowerCase();
+  for (const item of document.querySelectorAll("li")) {
+    const paths = [item, ...item.querySelectorAll("li")].map((li) => li.dataset.path.toLowerCase());
+    item.hidden = query !== "" && !paths.some((path) => path.includes(query));
+  }
+  if (query !== "") {
+    document.querySelectorAll("details").forEach((details) => (details.open = true));
+  }
+});
+</script>
+"#;
+
+/// A segment of the hierarchy and the segments nested below it, in canonical order
+#[derive(Default)]
+struct Node {
+    name: &'static str,
+    children: Vec<Node>,
+}
+
+impl Node {
+    fn insert(&mut self, segments: &[&'static str]) {
+        let Some((first, rest)) = segments.split_first() else {
+            return;
+        };
+        let index = match self.children.iter().position(|child| child.name == *first) {
+            Some(index) => index,
+            None => {
+                self.children.push(Node {
+                    name: first,
+                    children: Vec::new(),
+                });
+                self.children.len() - 1
+            }
+        };
+        self.children[index].insert(rest);
+    }
+
+    /// Append the children of this node, whose classifier is `path`, as a nested list
+    fn render_html(&self, out: &mut String, path: &str, expanded: bool) {
+        out.push_str("<ul>\n");
+        for child in &self.children {
+            let path = match path {
+                "" => String::from(child.name),
+                _ => alloc::format!("{path} :: {}", child.name),
+            };
+            let (path_attribute, name) = (escape(&path), escape(child.name));
+            if child.children.is_empty() {
+                out.push_str(&alloc::format!(
+                    "<li data-path=\"{path_attribute}\">{name}</li>\n"
+                ));
+                continue;
+            }
+            out.push_str(&alloc::format!(
+                "<li data-path=\"{path_attribute}\"><details{}><summary>{name}</summary>\n",
+                if expanded { " open" } else { "" }
+            ));
+            child.render_html(out, &path, expanded);
+            out.push_str("</details></li>\n");
+        }
+        out.push_str("</ul>\n");
+    }
+
+    /// Append the children of this node, whose classifier is `path`, as JSON objects nested
+    /// `indent` deep
+    fn render_json(&self, out: &mut String, path: &str, indent: usize) {
+        for (index, child) in self.children.iter().enumerate() {
+            let path = match path {
+                "" => String::from(child.name),
+                _ => alloc::format!("{path} :: {}", child.name),
+            };
+            out.push_str(if index == 0 { "\n" } else { ",\n" });
+            out.push_str(&" ".repeat(indent));
+            out.push_str("{\"segment\": ");
+            push_string(out, child.name);
+            out.push_str(", \"path\": ");
+            push_string(out, &path);
+            out.push_str(&alloc::format!(
+                ", \"classifier\": {}, \"children\": [",
+                Classifier::from_str(&path).is_ok()
+            ));
+            if !child.children.is_empty() {
+                child.render_json(out, &path, indent + 2);
+                out.push('\n');
+                out.push_str(&" ".repeat(indent));
+            }
+            out.push_str("]}");
+        }
+    }
+}
+
+/// An HTML `<datalist>` with the given `id` of the accepted classifiers of the given top-level
+/// categories, or of every category if none are given, for `<input list="...">` fields that
+/// suggest classifiers as they are typed
+///
+/// # Examples
+///
+#[cfg_attr(not(feature = "typing"), doc = "```ignore")]
+#[cfg_attr(feature = "typing", doc = "```")]
+/// use trove_classifiers::export::html_datalist;
+///
+/// assert_eq!(
+///     html_datalist("typing", &["Typing"]),
+///     "<datalist id=\"typing\">\n\
+///      <option value=\"Typing :: Stubs Only\"></option>\n\
+///      <option value=\"Typing :: Typed\"></option>\n\
+///      </datalist>\n"
+/// );
+/// ```
+pub fn html_datalist(id: &str, categories: &[&str]) -> String {
+    let mut out = alloc::format!("<datalist id=\"{}\">\n", escape(id));
+    for classifier in classifiers(categories) {
+        out.push_str(&alloc::format!(
+            "<option value=\"{}\"></option>\n",
+            escape(classifier.as_str())
+        ));
+    }
+    out.push_str("</datalist>\n");
+    out
+}
+
+/// An HTML `<select>` with the given `name` of the accepted classifiers of the given top-level
+/// categories, or of every category if none are given, in one `<optgroup>` per category
+///
+/// Options show a classifier without its category, which labels their group, and submit the
+/// whole classifier. Options of classifiers with a description have it as their `title`, which
+/// browsers show as a tooltip. Add the `multiple` attribute to select several.
+///
+/// # Examples
+///
+#[cfg_attr(not(feature = "typing"), doc = "```ignore")]
+#[cfg_attr(feature = "typing", doc = "```")]
+/// use trove_classifiers::export::html_select;
+///
+/// assert_eq!(
+///     html_select("classifier", &["Typing"]),
+///     "<select name=\"classifier\">\n\
+///      <optgroup label=\"Typing\">\n\
+///      <option value=\"Typing :: Stubs Only\" title=\"The distribution only contains type \
+///      stubs, .pyi files, for another package.\">Stubs Only</option>\n\
+///      <option value=\"Typing :: Typed\" title=\"The package ships inline type hints or stubs \
+///      and a py.typed marker, as PEP 561 describes.\">Typed</option>\n\
+///      </optgroup>\n\
+///      </select>\n"
+/// );
+/// ```
+pub fn html_select(name: &str, categories: &[&str]) -> String {
+    let mut out = alloc::format!("<select name=\"{}\">\n", escape(name));
+    let mut group = None;
+    for classifier in classifiers(categories) {
+        let (category, rest) = classifier
+            .as_str()
+            .split_once(" :: ")
+            .unwrap_or((classifier.as_str(), classifier.as_str()));
+        if group != Some(category) {
+            if group.is_some() {
+                out.push_str("</optgroup>\n");
+            }
+            out.push_str(&alloc::format!(
+                "<optgroup label=\"{}\">\n",
+                escape(category)
+            ));
+            group = Some(category);
+        }
+        out.push_str(&alloc::format!(
+            "<option value=\"{}\"",
+            escape(classifier.as_str())
+        ));
+        if let Some(description) = classifier.description() {
+            out.push_str(&alloc::format!(" title=\"{}\"", escape(description)));
+        }
+        out.push_str(&alloc::format!(">{}</option>\n", escape(rest)));
+    }
+    if group.is_some() {
+        out.push_str("</optgroup>\n");
+    }
+    out.push_str("</select>\n");
+    out
+}
+
+/// The Markdown documentation of an accepted or deprecated classifier, as editors show beside
+/// its completion or on hover, or `None` for any other string
+///
+/// An accepted classifier is documented with its description, if it has one, its category and a
+/// link to the projects declaring it on pypi.org, a deprecated one with its replacements.
+///
+/// # Examples
+///
+#[cfg_attr(not(feature = "all-categories"), doc = "```ignore")]
+#[cfg_attr(feature = "all-categories", doc = "```")]
+/// use trove_classifiers::export::documentation;
+///
+/// assert_eq!(
+///     documentation("Natural Language :: Icelandic").unwrap(),
+///     "Category: Natural Language\n\n\
+///      [Projects on pypi.org](https://pypi.org/search/?c=Natural+Language+%3A%3A+Icelandic)"
+/// );
+/// assert!(documentation("Typing :: Typed")
+///     .unwrap()
+///     .starts_with("The package ships inline type hints"));
+/// assert_eq!(
+///     documentation("Natural Language :: Ukranian").unwrap(),
+///     "Deprecated, use `Natural Language :: Ukrainian` instead"
+/// );
+/// assert_eq!(documentation("Typing :: Typo"), None);
+/// ```
+pub fn documentation(classifier: &str) -> Option<String> {
+    if let Ok(classifier) = Classifier::from_str(classifier) {
+        let about = alloc::format!(
+            "Category: {}\n\n[Projects on pypi.org]({})",
+            classifier.segments_static()[0],
+            classifier.url()
+        );
+        return Some(match classifier.description() {
+            Some(description) => alloc::format!("{description}\n\n{about}"),
+            None => about,
+        });
+    }
+    let replacements: Vec<String> = deprecation(classifier)?
+        .replacements()
+        .map(|replacement| alloc::format!("`{}`", replacement.as_str()))
+        .collect();
+    Some(match replacements.is_empty() {
+        true => String::from("Deprecated, without a replacement"),
+        false => alloc::format!("Deprecated, use {} instead", replacements.join(" or ")),
+    })
+}
+
+/// The JSON data editor extensions vendor to complete the classifiers of the given top-level
+/// categories, or of every category if none are given
+///
+/// The document holds the `version` of the dataset, the flat list of `classifiers`, accepted
+/// ones in canonical order then deprecated ones, each with a `deprecated` flag, its
+/// `replacements` and its Markdown `documentation`, and the tree of `segments` of the accepted
+/// ones, for completing a segment at a time. Each node of the tree has its `segment`, the `path`
+/// up to it, whether that path is itself a `classifier`, and its `children`. Regenerating it on
+/// every release keeps a VS Code or JetBrains plugin's completions in step with pypi.org.
+///
+/// # Examples
+///
+#[cfg_attr(not(feature = "typing"), doc = "```ignore")]
+#[cfg_attr(feature = "typing", doc = "```")]
+/// use trove_classifiers::export::editor_data;
+///
+/// let data = editor_data(&["Typing"]);
+/// assert!(data.contains(
+///     "  \"segments\": [\n    {\"segment\": \"Typing\", \"path\": \"Typing\", \"classifier\": false, \
+///      \"children\": [\n      {\"segment\": \"Stubs Only\", \"path\": \"Typing :: Stubs Only\", \
+///      \"classifier\": true, \"children\": []},\n"
+/// ));
+/// ```
+pub fn editor_data(categories: &[&str]) -> String {
+    let mut out = String::from("{\n  \"version\": ");
+    push_string(&mut out, PYPA_VERSION);
+    out.push_str(",\n  \"classifiers\": [");
+    let deprecated = deprecations()
+        .filter(|deprecation| {
+            let category = deprecation.as_str().split(" :: ").next().unwrap_or("");
+            categories.is_empty() || categories.contains(&category)
+        })
+        .map(|deprecation| (deprecation.as_str(), true));
+    let entries = classifiers(categories)
+        .map(|classifier| (classifier.as_str(), false))
+        .chain(deprecated);
+    for (index, (classifier, deprecated)) in entries.enumerate() {
+        out.push_str(if index == 0 { "\n    " } else { ",\n    " });
+        out.push_str("{\"classifier\": ");
+        push_string(&mut out, classifier);
+        out.push_str(&alloc::format!(
+            ", \"deprecated\": {deprecated}, \"replacements\": ["
+        ));
+        if let Some(deprecation) = deprecation(classifier) {
+            for (index, replacement) in deprecation.replacements().enumerate() {
+                if index > 0 {
+                    out.push_str(", ");
+                }
+                push_string(&mut out, replacement.as_str());
+            }
+        }
+        out.push_str("], \"documentation\": ");
+        push_string(&mut out, &documentation(classifier).unwrap_or_default());
+        out.push('}');
+    }
+    out.push_str("\n  ],\n  \"segments\": [");
+    let mut root = Node::default();
+    for classifier in classifiers(categories) {
+        root.insert(classifier.segments_static());
+    }
+    root.render_json(&mut out, "", 4);
+    out.push_str("\n  ]\n}\n");
+    out
+}
+
+/// A Markdown table of classifiers grouped by top-level category, for project documentation and
+/// release notes
+///
+/// Classifiers are deduplicated and sorted in canonical order, which keeps each category
+/// together; the category is only written on the first row of its group.
+///
+/// # Examples
+///
+#[cfg_attr(not(feature = "all-categories"), doc = "```ignore")]
+#[cfg_attr(feature = "all-categories", doc = "```")]
+/// use trove_classifiers::export::to_markdown;
+/// use trove_classifiers::Classifier;
+///
+/// let table = to_markdown(&[
+///     Classifier::Typing__Typed,
+///     Classifier::ProgrammingLanguage__Python__3_12,
+///     Classifier::ProgrammingLanguage__Python__3_11,
+/// ]);
+/// assert_eq!(
+///     table,
+///     "| Category | Classifier |\n\
+///      | --- | --- |\n\
+///      | Programming Language | Python :: 3.11 |\n\
+///      |  | Python :: 3.12 |\n\
+///      | Typing | Typed |\n"
+/// );
+/// ```
+pub fn to_markdown(classifiers: &[Classifier]) -> String {
+    let mut classifiers = classifiers.to_vec();
+    classifiers.sort();
+    classifiers.dedup();
+    let cell = |text: &str| text.replace('|', "\\|");
+    let mut out = String::from("| Category | Classifier |\n| --- | --- |\n");
+    let mut previous = None;
+    for classifier in classifiers {
+        let (category, rest) = classifier
+            .as_str()
+            .split_once(" :: ")
+            .unwrap_or((classifier.as_str(), ""));
+        let shown = if previous == Some(category) {
+            ""
+        } else {
+            category
+        };
+        previous = Some(category);
+        out.push_str(&alloc::format!("| {} | {} |\n", cell(shown), cell(rest)));
+    }
+    out
+}
+
+/// A classifier as an upper snake case identifier, spelling out `+` and `#` so that, for
+/// example, C, C# and C++ stay apart
+fn protobuf_name(classifier: &str) -> String {
+    let mut name = String::new();
+    for c in classifier.chars() {
+        match c {
+            '+' => name.push_str("_PLUS"),
+            '#' => name.push_str("_SHARP"),
+            c if c.is_ascii_alphanumeric() => name.push(c.to_ascii_uppercase()),
+            _ => name.push('_'),
+        }
+    }
+    // Collapse the runs of underscores left by separators and punctuation
+    let mut collapsed = String::new();
+    for part in name.split('_').filter(|part| !part.is_empty()) {
+        if !collapsed.is_empty() {
+            collapsed.push('_');
+        }
+        collapsed.push_str(part);
+    }
+    collapsed
+}
+
+/// Append the members of a schema of the classifiers of `categories`, one per line
+fn push_schema(out: &mut String, indent: &str, categories: &[&str]) {
+    out.push_str(indent);
+    out.push_str("\"title\": \"Trove classifier\",\n");
+    out.push_str(indent);
+    out.push_str("\"description\": ");
+    push_string(
+        out,
+        &alloc::format!("A classifier pypi.org accepts, as of trove-classifiers {PYPA_VERSION}"),
+    );
+    out.push_str(",\n");
+    out.push_str(indent);
+    out.push_str("\"type\": \"string\",\n");
+    out.push_str(indent);
+    out.push_str("\"enum\": [");
+    for (index, classifier) in classifiers(categories).enumerate() {
+        out.push_str(if index == 0 { "\n" } else { ",\n" });
+        out.push_str(indent);
+        out.push_str("  ");
+        push_string(out, classifier.as_str());
+    }
+    out.push('\n');
+    out.push_str(indent);
+    out.push_str("]\n");
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    #[cfg(feature = "all-categories")]
+    fn json_schema_matches_the_generated_file() {
+        assert_eq!(
+            json_schema(&[]),
+            include_str!("../schema/classifier.schema.json")
+        );
+    }
+
+    #[test]
+    #[cfg(feature = "typing")]
+    fn json_schemas_can_be_restricted_to_categories() {
+        let schema = json_schema(&["Typing", "Not A Category"]);
+        assert!(schema.starts_with("{\n  \"title\": \"Trove classifier\",\n"));
+        assert!(schema.ends_with(
+            "\"type\": \"string\",\n  \"enum\": [\n    \"Typing :: Stubs Only\",\n    \
+             \"Typing :: Typed\"\n  ]\n}\n"
+        ));
+    }
+
+    #[test]
+    fn protobuf_names_are_unique_identifiers() {
+        let mut names: Vec<String> = Classifier::ALL
+            .iter()
+            .map(|classifier| protobuf_name(classifier.as_str()))
+            .collect();
+        assert!(names.iter().all(|name| name
+            .bytes()
+            .all(|b| b.is_ascii_uppercase() || b.is_ascii_digit() || b == b'_')));
+        names.sort();
+        names.dedup();
+        assert_eq!(names.len(), Classifier::ALL.len());
+        assert_eq!(
+            protobuf_name("Programming Language :: C++"),
+            "PROGRAMMING_LANGUAGE_C_PLUS_PLUS"
+        );
+        assert_eq!(
+            protobuf_name("Development Status :: 5 - Production/Stable"),
+            "DEVELOPMENT_STATUS_5_PRODUCTION_STABLE"
+        );
+    }
+
+    #[test]
+    #[cfg(feature = "typing")]
+    fn protobuf_enums_are_numbered_by_code() {
+        assert_eq!(
+            protobuf(&["Typing"]),
+            alloc::format!(
+                "// Classifiers pypi.org accepts, as of trove-classifiers {PYPA_VERSION}.\n\
+                 // Values are numbered with the classifiers' stable codes.\n\n\
+                 syntax = \"proto3\";\n\npackage trove;\n\n\
+                 enum TroveClassifier {{\n  \
+                 TROVE_CLASSIFIER_UNSPECIFIED = 0;\n  \
+                 // Typing :: Stubs Only\n  TROVE_CLASSIFIER_TYPING_STUBS_ONLY = 864;\n  \
+                 // Typing :: Typed\n  TROVE_CLASSIFIER_TYPING_TYPED = 865;\n}}\n"
+            )
+        );
+    }
+
+    #[test]
+    #[cfg(feature = "all-categories")]
+    fn delimited_tables_quote_commas() {
+        let table = delimited(&["License", "Natural Language"], Delimiter::Comma);
+        let mut rows = table.lines();
+        assert_eq!(
+            rows.next(),
+            Some("classifier,category,parent,depth,deprecated,replacements,code,stable_id")
+        );
+        let ecl = Classifier::License__OSIApproved__EducationalCommunityLicenseVersion2_0ECL2_0;
+        assert!(rows.clone().any(|row| row
+            == alloc::format!(
+                "\"License :: OSI Approved :: Educational Community License, Version 2.0 \
+                 (ECL-2.0)\",License,License :: OSI Approved,3,false,,{},{:016x}",
+                ecl.code(),
+                ecl.stable_id()
+            )));
+        assert!(rows.any(|row| row
+            == alloc::format!(
+                "Natural Language :: Ukranian,Natural Language,,2,true,\
+                 Natural Language :: Ukrainian,,{:016x}",
+                stable_id("Natural Language :: Ukranian")
+            )));
+    }
+
+    #[test]
+    #[cfg(feature = "framework")]
+    fn html_pages_nest_classifiers() {
+        let page = html(
+            "<Django>",
+            &[
+                Classifier::Framework__Django__5_1,
+                Classifier::Framework__Django,
+                Classifier::Framework__Django,
+            ],
+            false,
+        );
+        assert!(page.contains("<title>&lt;Django&gt;</title>"));
+        assert!(page.contains("<p>2 classifiers, from trove-classifiers "));
+        assert!(page.contains(
+            "<ul>\n<li data-path=\"Framework\"><details open><summary>Framework</summary>\n\
+             <ul>\n<li data-path=\"Framework :: Django\"><details open><summary>Django</summary>\n\
+             <ul>\n<li data-path=\"Framework :: Django :: 5.1\">5.1</li>\n</ul>\n\
+             </details></li>\n</ul>\n</details></li>\n</ul>\n"
+        ));
+        assert!(!page.contains("<script>"));
+    }
+
+    #[test]
+    fn large_html_pages_start_collapsed_with_a_filter() {
+        let page = html("Trove classifiers", Classifier::ALL, true);
+        assert!(page.contains("<input type=\"search\" id=\"filter\""));
+        assert!(page.contains("<script>"));
+        assert_eq!(
+            page.contains("<details open>"),
+            Classifier::ALL.len() <= EXPANDED
+        );
+    }
+
+    #[test]
+    #[cfg(all(feature = "development-status", feature = "typing"))]
+    fn html_selects_group_categories() {
+        let select = html_select("a\"b", &["Development Status", "Typing"]);
+        assert!(select.starts_with(
+            "<select name=\"a&quot;b\">\n<optgroup label=\"Development Status\">\n\
+             <option value=\"Development Status :: 1 - Planning\" title=\"Nothing usable has been \
+             released yet: the project is being designed.\">1 - Planning</option>\n"
+        ));
+        assert!(select.contains(
+            "</option>\n</optgroup>\n<optgroup label=\"Typing\">\n\
+             <option value=\"Typing :: Stubs Only\" title=\""
+        ));
+        assert_eq!(
+            html_select("empty", &["Not A Category"]),
+            "<select name=\"empty\">\n</select>\n"
+        );
+    }
+
+    #[test]
+    #[cfg(feature = "all-categories")]
+    fn editor_data_is_json_listing_every_classifier() {
+        use crate::json::{Json, Parser};
+
+        let data = editor_data(&[]);
+        let Ok(Json::Object(members)) = Parser::new(&data).document() else {
+            panic!("editor data is not a JSON object");
+        };
+        let keys: Vec<&str> = members.iter().map(|(key, _)| key.as_str()).collect();
+        assert_eq!(keys, ["version", "classifiers", "segments"]);
+        let Json::Array(entries) = &members[1].1 else {
+            panic!("classifiers is not an array");
+        };
+        assert_eq!(
+            entries.len(),
+            Classifier::ALL.len() + deprecations().count()
+        );
+        assert_eq!(
+            data.matches("\"classifier\": true").count(),
+            Classifier::ALL.len()
+        );
+        assert!(data.contains(
+            "{\"classifier\": \"Natural Language :: Ukranian\", \"deprecated\": true, \
+             \"replacements\": [\"Natural Language :: Ukrainian\"], \
+             \"documentation\": \"Deprecated, use `Natural Language :: Ukrainian` instead\"}"
+        ));
+        assert!(data.contains(
+            "{\"segment\": \"Django\", \"path\": \"Framework :: Django\", \"classifier\": true, \
+             \"children\": [\n"
+        ));
+        assert_eq!(
+            editor_data(&["Not A Category"]),
+            alloc::format!(
+                "{{\n  \"version\": \"{PYPA_VERSION}\",\n  \"classifiers\": [\n  ],\n  \
+                 \"segments\": [\n  ]\n}}\n"
+            )
+        );
+    }
+
+    #[test]
+    fn markdown_tables_of_nothing_have_a_header() {
+        assert_eq!(
+            to_markdown(&[]),
+            "| Category | Classifier |\n| --- | --- |\n"
+        );
+    }
+
+    #[test]
+    fn typescript_unions_of_nothing_are_never() {
+        assert!(typescript(&["Not A Category"]).ends_with("export type TroveClassifier = never;\n"));
+    }
+
+    #[test]
+    #[cfg(feature = "typing")]
+    fn openapi_documents_hold_a_schema_component() {
+        let document = openapi(&["Typing"]);
+        assert!(document.starts_with(
+            "{\n  \"components\": {\n    \"schemas\": {\n      \"TroveClassifier\": {\n        \
+             \"title\": \"Trove classifier\",\n"
+        ));
+        assert!(document.ends_with(
+            "        \"enum\": [\n          \"Typing :: Stubs Only\",\n          \
+             \"Typing :: Typed\"\n        ]\n      }\n    }\n  }\n}\n"
+        ));
+    }
+}
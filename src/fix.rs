@@ -0,0 +1,359 @@
+//! Tidying of a declared list of classifiers.
+//!
+//! `fix` turns classifiers as they are found in the wild into the list a project should
+//! declare: written canonically, free of deprecated entries and duplicates, and in canonical
+//! order. Every change it makes is reported, so tools can explain or preview them.
+//! `sort_classifier_strings` only puts strings into canonical order, for formatters that keep
+//! them as they are written.
+//!
+//! # Examples
+//!
+#![cfg_attr(not(feature = "all-categories"), doc = "```ignore")]
+#![cfg_attr(feature = "all-categories", doc = "```")]
+//! use trove_classifiers::fix::{fix, Change, Options};
+//! use trove_classifiers::Classifier;
+//!
+//! let fixed = fix(
+//!     ["typing :: typed", "Natural Language :: Ukranian", "Typing :: Typed"],
+//!     &Options::default(),
+//! );
+//! assert_eq!(
+//!     fixed.classifiers,
+//!     [Classifier::NaturalLanguage__Ukrainian, Classifier::Typing__Typed]
+//! );
+//! assert_eq!(fixed.changes[0], Change::Canonicalized {
+//!     from: "typing :: typed".to_string(),
+//!     to: Classifier::Typing__Typed,
+//! });
+//! ```
+
+use alloc::string::{String, ToString};
+use alloc::vec::Vec;
+use core::fmt;
+use core::ops::Range;
+
+use crate::alias::Aliases;
+use crate::{deprecation, Classifier, ParseError};
+
+/// What `fix` may change beyond canonical spelling, deprecations, duplicates and order
+#[derive(Clone, Debug, Default, Eq, PartialEq)]
+#[non_exhaustive]
+pub struct Options {
+    /// Add the classifiers that declared ones are nested in, such as "Framework :: Django" for
+    /// "Framework :: Django :: 5.1"
+    pub add_parents: bool,
+    /// Shorthands to expand into the classifiers they stand for
+    pub aliases: Aliases,
+    /// Reject deprecated classifiers, reporting them in `Fixed::rejected`, rather than replace
+    /// them
+    pub strict: bool,
+}
+
+impl Options {
+    pub fn add_parents(mut self, enabled: bool) -> Self {
+        self.add_parents = enabled;
+        self
+    }
+
+    pub fn aliases(mut self, aliases: Aliases) -> Self {
+        self.aliases = aliases;
+        self
+    }
+
+    pub fn strict(mut self, enabled: bool) -> Self {
+        self.strict = enabled;
+        self
+    }
+}
+
+/// A change made by `fix`
+#[derive(Clone, Debug, Eq, PartialEq)]
+#[non_exhaustive]
+pub enum Change {
+    /// A classifier was written in a non-canonical way, or as an alias
+    Canonicalized { from: String, to: Classifier },
+    /// A deprecated classifier was replaced, possibly by nothing
+    Replaced { from: String, to: Vec<Classifier> },
+    /// A classifier was declared more than once
+    Deduplicated(Classifier),
+    /// The parent of a declared classifier was added
+    AddedParent(Classifier),
+    /// The classifiers were not in canonical order
+    Sorted,
+}
+
+impl fmt::Display for Change {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        match self {
+            Change::Canonicalized { from, to } => {
+                write!(f, "rewrote {from:?} as {to:?}", to = to.as_str())
+            }
+            Change::Replaced { from, to } if to.is_empty() => {
+                write!(f, "removed deprecated {from:?}")
+            }
+            Change::Replaced { from, to } => {
+                write!(f, "replaced deprecated {from:?} with ")?;
+                for (index, classifier) in to.iter().enumerate() {
+                    if index > 0 {
+                        f.write_str(", ")?;
+                    }
+                    write!(f, "{:?}", classifier.as_str())?;
+                }
+                Ok(())
+            }
+            Change::Deduplicated(classifier) => {
+                write!(f, "removed duplicate {:?}", classifier.as_str())
+            }
+            Change::AddedParent(classifier) => write!(f, "added parent {:?}", classifier.as_str()),
+            Change::Sorted => f.write_str("sorted into canonical order"),
+        }
+    }
+}
+
+/// The result of `fix`
+#[derive(Clone, Debug, Default, Eq, PartialEq)]
+pub struct Fixed {
+    /// The tidied classifiers, in canonical order
+    pub classifiers: Vec<Classifier>,
+    /// Strings that could not be understood as classifiers, in their original order
+    pub unknown: Vec<String>,
+    /// With `Options::strict`, the deprecated classifiers, in their original order
+    pub rejected: Vec<ParseError>,
+    /// Every change made, in the order it was made
+    pub changes: Vec<Change>,
+}
+
+impl Fixed {
+    /// Whether nothing needed to change
+    pub fn is_unchanged(&self) -> bool {
+        self.changes.is_empty()
+    }
+}
+
+/// Tidy a declared list of classifiers
+pub fn fix<I, S>(declared: I, options: &Options) -> Fixed
+where
+    I: IntoIterator<Item = S>,
+    S: AsRef<str>,
+{
+    let mut fixed = Fixed::default();
+    for value in declared {
+        let value = value.as_ref();
+        if let Ok(classifier) = value.parse::<Classifier>() {
+            fixed.push(classifier);
+        } else if let Ok(classifier) = options.aliases.parse(value) {
+            fixed.changes.push(Change::Canonicalized {
+                from: value.to_string(),
+                to: classifier,
+            });
+            fixed.push(classifier);
+        } else if let Some(deprecated) = deprecation(value.trim()) {
+            if options.strict {
+                fixed.rejected.push(ParseError::Deprecated(deprecated));
+                continue;
+            }
+            let replacements: Vec<Classifier> = deprecated.replacements().collect();
+            fixed.changes.push(Change::Replaced {
+                from: value.to_string(),
+                to: replacements.clone(),
+            });
+            for classifier in replacements {
+                fixed.push(classifier);
+            }
+        } else {
+            fixed.unknown.push(value.to_string());
+        }
+    }
+
+    if options.add_parents {
+        let mut index = 0;
+        while index < fixed.classifiers.len() {
+            if let Some(parent) = fixed.classifiers[index].parent() {
+                if !fixed.classifiers.contains(&parent) {
+                    fixed.changes.push(Change::AddedParent(parent));
+                    fixed.classifiers.push(parent);
+                }
+            }
+            index += 1;
+        }
+    }
+
+    if !fixed.classifiers.is_sorted() {
+        fixed.classifiers.sort_unstable();
+        fixed.changes.push(Change::Sorted);
+    }
+    fixed
+}
+
+/// Sort raw classifier strings into canonical order, in place, without converting them
+///
+/// Known classifiers are sorted into the order pypi.org lists them in. Strings that are not
+/// exactly a classifier's canonical string, including deprecated classifiers, are moved after
+/// them, keeping their original order. They are reported by the returned range of `strings`,
+/// which is empty when every string is known. Duplicates are kept; see `fix` to remove them.
+///
+/// # Examples
+///
+#[cfg_attr(not(feature = "all-categories"), doc = "```ignore")]
+#[cfg_attr(feature = "all-categories", doc = "```")]
+/// use trove_classifiers::sort_classifier_strings;
+///
+/// let mut strings = [
+///     "Typing :: Typed".to_string(),
+///     "Private :: Do Not Upload".to_string(),
+///     "Development Status :: 4 - Beta".to_string(),
+/// ];
+/// let unknown = sort_classifier_strings(&mut strings);
+/// assert_eq!(
+///     strings,
+///     ["Development Status :: 4 - Beta", "Typing :: Typed", "Private :: Do Not Upload"]
+/// );
+/// assert_eq!(strings[unknown], ["Private :: Do Not Upload"]);
+/// ```
+pub fn sort_classifier_strings<S: AsRef<str>>(strings: &mut [S]) -> Range<usize> {
+    strings.sort_by_cached_key(|string| match string.as_ref().parse::<Classifier>() {
+        Ok(classifier) => (false, Some(classifier)),
+        Err(_) => (true, None),
+    });
+    let known = strings.partition_point(|string| string.as_ref().parse::<Classifier>().is_ok());
+    known..strings.len()
+}
+
+impl Fixed {
+    fn push(&mut self, classifier: Classifier) {
+        if self.classifiers.contains(&classifier) {
+            self.changes.push(Change::Deduplicated(classifier));
+        } else {
+            self.classifiers.push(classifier);
+        }
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    #[cfg(any(feature = "natural-language", feature = "typing"))]
+    use super::*;
+
+    #[test]
+    #[cfg(all(feature = "natural-language", feature = "typing"))]
+    fn sorting_strings_moves_unknowns_last() {
+        let mut strings = [
+            "Typing :: Typed",
+            "unknown",
+            "Natural Language :: Ukranian",
+            "Natural Language :: English",
+            "Typing :: Typed",
+        ];
+        let unknown = sort_classifier_strings(&mut strings);
+        assert_eq!(
+            strings,
+            [
+                "Natural Language :: English",
+                "Typing :: Typed",
+                "Typing :: Typed",
+                "unknown",
+                "Natural Language :: Ukranian",
+            ]
+        );
+        assert_eq!(unknown, 3..5);
+        assert!(sort_classifier_strings(&mut ["Typing :: Typed"]).is_empty());
+    }
+
+    #[test]
+    #[cfg(feature = "natural-language")]
+    fn strict_fixes_reject_deprecations() {
+        let fixed = fix(
+            [
+                "Natural Language :: Ukranian",
+                "Natural Language :: Ukrainian",
+            ],
+            &Options::default().strict(true),
+        );
+        assert_eq!(fixed.classifiers, [Classifier::NaturalLanguage__Ukrainian]);
+        assert!(fixed.changes.is_empty());
+        assert_eq!(
+            fixed.rejected,
+            [ParseError::Deprecated(
+                deprecation("Natural Language :: Ukranian").unwrap()
+            )]
+        );
+    }
+
+    #[test]
+    #[cfg(feature = "typing")]
+    fn aliases_are_expanded() {
+        let mut aliases = Aliases::new();
+        aliases.insert("typed", "Typing :: Typed").unwrap();
+        let fixed = fix(["Typed", "stubs"], &Options::default().aliases(aliases));
+        assert_eq!(fixed.classifiers, [Classifier::Typing__Typed]);
+        assert_eq!(fixed.unknown, ["stubs"]);
+        assert_eq!(
+            fixed.changes,
+            [Change::Canonicalized {
+                from: "Typed".to_string(),
+                to: Classifier::Typing__Typed,
+            }]
+        );
+    }
+
+    #[test]
+    #[cfg(feature = "typing")]
+    fn tidy_lists_are_unchanged() {
+        let fixed = fix(
+            ["Typing :: Stubs Only", "Typing :: Typed"],
+            &Options::default(),
+        );
+        assert!(fixed.is_unchanged());
+        assert_eq!(fixed.classifiers.len(), 2);
+    }
+
+    #[test]
+    #[cfg(feature = "all-categories")]
+    fn reports_every_change() {
+        let fixed = fix(
+            [
+                "Typing :: Typed",
+                "Framework :: Django :: 5.1",
+                "framework :: django :: 5.1",
+                "Topic :: Communications :: Chat :: AOL Instant Messenger",
+                "Private :: Do Not Upload",
+            ],
+            &Options::default().add_parents(true),
+        );
+        assert_eq!(
+            fixed.classifiers,
+            [
+                Classifier::Framework__Django,
+                Classifier::Framework__Django__5_1,
+                Classifier::Typing__Typed,
+            ]
+        );
+        assert_eq!(fixed.unknown, ["Private :: Do Not Upload"]);
+        assert_eq!(
+            fixed.changes,
+            [
+                Change::Canonicalized {
+                    from: "framework :: django :: 5.1".to_string(),
+                    to: Classifier::Framework__Django__5_1,
+                },
+                Change::Deduplicated(Classifier::Framework__Django__5_1),
+                Change::Replaced {
+                    from: "Topic :: Communications :: Chat :: AOL Instant Messenger".to_string(),
+                    to: Vec::new(),
+                },
+                Change::AddedParent(Classifier::Framework__Django),
+                Change::Sorted,
+            ]
+        );
+    }
+
+    #[test]
+    #[cfg(feature = "natural-language")]
+    fn describes_changes() {
+        let fixed = fix(["Natural Language :: Ukranian"], &Options::default());
+        assert_eq!(
+            fixed.changes[0].to_string(),
+            "replaced deprecated \"Natural Language :: Ukranian\" with \"Natural Language :: Ukrainian\""
+        );
+    }
+}
@@ -0,0 +1,13 @@
+// @generated by `cargo xtask codegen` from data/, do not edit by hand.
+
+/// Deprecated classifiers, ordered by their string, and the strings of their replacements
+pub(crate) static DEPRECATED: &[(&str, &[&str])] = &[
+    (
+        "Natural Language :: Ukranian",
+        &["Natural Language :: Ukrainian"],
+    ),
+    (
+        "Topic :: Communications :: Chat :: AOL Instant Messenger",
+        &[],
+    ),
+];
@@ -0,0 +1,49 @@
+// @generated by `cargo xtask codegen` from data/, do not edit by hand.
+
+use crate::table::CategoryTable;
+use crate::Classifier;
+
+pub(super) static TABLE: CategoryTable = CategoryTable {
+    name: "Development Status",
+    classifiers: &[
+        Classifier::DevelopmentStatus__1Planning,
+        Classifier::DevelopmentStatus__2PreAlpha,
+        Classifier::DevelopmentStatus__3Alpha,
+        Classifier::DevelopmentStatus__4Beta,
+        Classifier::DevelopmentStatus__5ProductionStable,
+        Classifier::DevelopmentStatus__6Mature,
+        Classifier::DevelopmentStatus__7Inactive,
+    ],
+    text: concat!(
+        "Development Status :: 1 - Planning",
+        "Development Status :: 2 - Pre-Alpha",
+        "Development Status :: 3 - Alpha",
+        "Development Status :: 4 - Beta",
+        "Development Status :: 5 - Production/Stable",
+        "Development Status :: 6 - Mature",
+        "Development Status :: 7 - Inactive",
+    ),
+    ends: &[34, 69, 100, 130, 173, 205, 239],
+    sorted: &[0, 1, 2, 3, 4, 5, 6],
+    codes: &[1, 2, 3, 4, 5, 6, 7],
+};
+
+pub(super) static SEGMENTS: [&[&str]; 7] = [
+    &["Development Status", "1 - Planning"],
+    &["Development Status", "2 - Pre-Alpha"],
+    &["Development Status", "3 - Alpha"],
+    &["Development Status", "4 - Beta"],
+    &["Development Status", "5 - Production/Stable"],
+    &["Development Status", "6 - Mature"],
+    &["Development Status", "7 - Inactive"],
+];
+
+pub(super) static VARIANTS: [&str; 7] = [
+    "DevelopmentStatus__1Planning",
+    "DevelopmentStatus__2PreAlpha",
+    "DevelopmentStatus__3Alpha",
+    "DevelopmentStatus__4Beta",
+    "DevelopmentStatus__5ProductionStable",
+    "DevelopmentStatus__6Mature",
+    "DevelopmentStatus__7Inactive",
+];
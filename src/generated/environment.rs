@@ -0,0 +1,320 @@
+// @generated by `cargo xtask codegen` from data/, do not edit by hand.
+
+use crate::table::CategoryTable;
+use crate::Classifier;
+
+pub(super) static TABLE: CategoryTable = CategoryTable {
+    name: "Environment",
+    classifiers: &[
+        Classifier::Environment__Console,
+        Classifier::Environment__Console__Curses,
+        Classifier::Environment__Console__Framebuffer,
+        Classifier::Environment__Console__Newt,
+        Classifier::Environment__Console__svgalib,
+        Classifier::Environment__GPU,
+        Classifier::Environment__GPU__NVIDIACUDA,
+        Classifier::Environment__GPU__NVIDIACUDA__1_0,
+        Classifier::Environment__GPU__NVIDIACUDA__1_1,
+        Classifier::Environment__GPU__NVIDIACUDA__2_0,
+        Classifier::Environment__GPU__NVIDIACUDA__2_1,
+        Classifier::Environment__GPU__NVIDIACUDA__2_2,
+        Classifier::Environment__GPU__NVIDIACUDA__2_3,
+        Classifier::Environment__GPU__NVIDIACUDA__3_0,
+        Classifier::Environment__GPU__NVIDIACUDA__3_1,
+        Classifier::Environment__GPU__NVIDIACUDA__3_2,
+        Classifier::Environment__GPU__NVIDIACUDA__4_0,
+        Classifier::Environment__GPU__NVIDIACUDA__4_1,
+        Classifier::Environment__GPU__NVIDIACUDA__4_2,
+        Classifier::Environment__GPU__NVIDIACUDA__5_0,
+        Classifier::Environment__GPU__NVIDIACUDA__5_5,
+        Classifier::Environment__GPU__NVIDIACUDA__6_0,
+        Classifier::Environment__GPU__NVIDIACUDA__6_5,
+        Classifier::Environment__GPU__NVIDIACUDA__7_0,
+        Classifier::Environment__GPU__NVIDIACUDA__7_5,
+        Classifier::Environment__GPU__NVIDIACUDA__8_0,
+        Classifier::Environment__GPU__NVIDIACUDA__9_0,
+        Classifier::Environment__GPU__NVIDIACUDA__9_1,
+        Classifier::Environment__GPU__NVIDIACUDA__9_2,
+        Classifier::Environment__GPU__NVIDIACUDA__10_0,
+        Classifier::Environment__GPU__NVIDIACUDA__10_1,
+        Classifier::Environment__GPU__NVIDIACUDA__10_2,
+        Classifier::Environment__GPU__NVIDIACUDA__11,
+        Classifier::Environment__GPU__NVIDIACUDA__11_0,
+        Classifier::Environment__GPU__NVIDIACUDA__11_1,
+        Classifier::Environment__GPU__NVIDIACUDA__11_2,
+        Classifier::Environment__GPU__NVIDIACUDA__11_3,
+        Classifier::Environment__GPU__NVIDIACUDA__11_4,
+        Classifier::Environment__GPU__NVIDIACUDA__11_5,
+        Classifier::Environment__GPU__NVIDIACUDA__11_6,
+        Classifier::Environment__GPU__NVIDIACUDA__11_7,
+        Classifier::Environment__GPU__NVIDIACUDA__11_8,
+        Classifier::Environment__GPU__NVIDIACUDA__12,
+        Classifier::Environment__GPU__NVIDIACUDA__12__12_0,
+        Classifier::Environment__GPU__NVIDIACUDA__12__12_1,
+        Classifier::Environment__GPU__NVIDIACUDA__12__12_2,
+        Classifier::Environment__GPU__NVIDIACUDA__12__12_3,
+        Classifier::Environment__GPU__NVIDIACUDA__12__12_4,
+        Classifier::Environment__GPU__NVIDIACUDA__12__12_5,
+        Classifier::Environment__HandheldsPDAs,
+        Classifier::Environment__MacOSX,
+        Classifier::Environment__MacOSX__Aqua,
+        Classifier::Environment__MacOSX__Carbon,
+        Classifier::Environment__MacOSX__Cocoa,
+        Classifier::Environment__NoInputOutputDaemon,
+        Classifier::Environment__OpenStack,
+        Classifier::Environment__OtherEnvironment,
+        Classifier::Environment__Plugins,
+        Classifier::Environment__WebEnvironment,
+        Classifier::Environment__WebEnvironment__Buffet,
+        Classifier::Environment__WebEnvironment__Mozilla,
+        Classifier::Environment__WebEnvironment__ToscaWidgets,
+        Classifier::Environment__WebAssembly,
+        Classifier::Environment__WebAssembly__Emscripten,
+        Classifier::Environment__WebAssembly__WASI,
+        Classifier::Environment__Win32MSWindows,
+        Classifier::Environment__X11Applications,
+        Classifier::Environment__X11Applications__GTK,
+        Classifier::Environment__X11Applications__Gnome,
+        Classifier::Environment__X11Applications__KDE,
+        Classifier::Environment__X11Applications__Qt,
+    ],
+    text: concat!(
+        "Environment :: Console",
+        "Environment :: Console :: Curses",
+        "Environment :: Console :: Framebuffer",
+        "Environment :: Console :: Newt",
+        "Environment :: Console :: svgalib",
+        "Environment :: GPU",
+        "Environment :: GPU :: NVIDIA CUDA",
+        "Environment :: GPU :: NVIDIA CUDA :: 1.0",
+        "Environment :: GPU :: NVIDIA CUDA :: 1.1",
+        "Environment :: GPU :: NVIDIA CUDA :: 2.0",
+        "Environment :: GPU :: NVIDIA CUDA :: 2.1",
+        "Environment :: GPU :: NVIDIA CUDA :: 2.2",
+        "Environment :: GPU :: NVIDIA CUDA :: 2.3",
+        "Environment :: GPU :: NVIDIA CUDA :: 3.0",
+        "Environment :: GPU :: NVIDIA CUDA :: 3.1",
+        "Environment :: GPU :: NVIDIA CUDA :: 3.2",
+        "Environment :: GPU :: NVIDIA CUDA :: 4.0",
+        "Environment :: GPU :: NVIDIA CUDA :: 4.1",
+        "Environment :: GPU :: NVIDIA CUDA :: 4.2",
+        "Environment :: GPU :: NVIDIA CUDA :: 5.0",
+        "Environment :: GPU :: NVIDIA CUDA :: 5.5",
+        "Environment :: GPU :: NVIDIA CUDA :: 6.0",
+        "Environment :: GPU :: NVIDIA CUDA :: 6.5",
+        "Environment :: GPU :: NVIDIA CUDA :: 7.0",
+        "Environment :: GPU :: NVIDIA CUDA :: 7.5",
+        "Environment :: GPU :: NVIDIA CUDA :: 8.0",
+        "Environment :: GPU :: NVIDIA CUDA :: 9.0",
+        "Environment :: GPU :: NVIDIA CUDA :: 9.1",
+        "Environment :: GPU :: NVIDIA CUDA :: 9.2",
+        "Environment :: GPU :: NVIDIA CUDA :: 10.0",
+        "Environment :: GPU :: NVIDIA CUDA :: 10.1",
+        "Environment :: GPU :: NVIDIA CUDA :: 10.2",
+        "Environment :: GPU :: NVIDIA CUDA :: 11",
+        "Environment :: GPU :: NVIDIA CUDA :: 11.0",
+        "Environment :: GPU :: NVIDIA CUDA :: 11.1",
+        "Environment :: GPU :: NVIDIA CUDA :: 11.2",
+        "Environment :: GPU :: NVIDIA CUDA :: 11.3",
+        "Environment :: GPU :: NVIDIA CUDA :: 11.4",
+        "Environment :: GPU :: NVIDIA CUDA :: 11.5",
+        "Environment :: GPU :: NVIDIA CUDA :: 11.6",
+        "Environment :: GPU :: NVIDIA CUDA :: 11.7",
+        "Environment :: GPU :: NVIDIA CUDA :: 11.8",
+        "Environment :: GPU :: NVIDIA CUDA :: 12",
+        "Environment :: GPU :: NVIDIA CUDA :: 12 :: 12.0",
+        "Environment :: GPU :: NVIDIA CUDA :: 12 :: 12.1",
+        "Environment :: GPU :: NVIDIA CUDA :: 12 :: 12.2",
+        "Environment :: GPU :: NVIDIA CUDA :: 12 :: 12.3",
+        "Environment :: GPU :: NVIDIA CUDA :: 12 :: 12.4",
+        "Environment :: GPU :: NVIDIA CUDA :: 12 :: 12.5",
+        "Environment :: Handhelds/PDA's",
+        "Environment :: MacOS X",
+        "Environment :: MacOS X :: Aqua",
+        "Environment :: MacOS X :: Carbon",
+        "Environment :: MacOS X :: Cocoa",
+        "Environment :: No Input/Output (Daemon)",
+        "Environment :: OpenStack",
+        "Environment :: Other Environment",
+        "Environment :: Plugins",
+        "Environment :: Web Environment",
+        "Environment :: Web Environment :: Buffet",
+        "Environment :: Web Environment :: Mozilla",
+        "Environment :: Web Environment :: ToscaWidgets",
+        "Environment :: WebAssembly",
+        "Environment :: WebAssembly :: Emscripten",
+        "Environment :: WebAssembly :: WASI",
+        "Environment :: Win32 (MS Windows)",
+        "Environment :: X11 Applications",
+        "Environment :: X11 Applications :: GTK",
+        "Environment :: X11 Applications :: Gnome",
+        "Environment :: X11 Applications :: KDE",
+        "Environment :: X11 Applications :: Qt",
+    ),
+    ends: &[
+        22, 54, 91, 121, 154, 172, 205, 245, 285, 325, 365, 405, 445, 485, 525, 565, 605, 645, 685,
+        725, 765, 805, 845, 885, 925, 965, 1005, 1045, 1085, 1126, 1167, 1208, 1247, 1288, 1329,
+        1370, 1411, 1452, 1493, 1534, 1575, 1616, 1655, 1702, 1749, 1796, 1843, 1890, 1937, 1967,
+        1989, 2019, 2051, 2082, 2121, 2145, 2177, 2199, 2229, 2269, 2310, 2356, 2382, 2422, 2456,
+        2489, 2520, 2558, 2598, 2636, 2673,
+    ],
+    sorted: &[
+        0, 1, 2, 3, 4, 5, 6, 7, 8, 29, 30, 31, 32, 33, 34, 35, 36, 37, 38, 39, 40, 41, 42, 43, 44,
+        45, 46, 47, 48, 9, 10, 11, 12, 13, 14, 15, 16, 17, 18, 19, 20, 21, 22, 23, 24, 25, 26, 27,
+        28, 49, 50, 51, 52, 53, 54, 55, 56, 57, 58, 59, 60, 61, 62, 63, 64, 65, 66, 68, 67, 69, 70,
+    ],
+    codes: &[
+        8, 9, 10, 11, 12, 13, 14, 15, 16, 17, 18, 19, 20, 21, 22, 23, 24, 25, 26, 27, 28, 29, 30,
+        31, 32, 33, 34, 35, 36, 37, 38, 39, 40, 41, 42, 43, 44, 45, 46, 47, 48, 49, 50, 51, 52, 53,
+        54, 55, 56, 57, 58, 59, 60, 61, 62, 63, 64, 65, 66, 67, 68, 69, 70, 71, 72, 73, 74, 75, 76,
+        77, 78,
+    ],
+};
+
+pub(super) static SEGMENTS: [&[&str]; 71] = [
+    &["Environment", "Console"],
+    &["Environment", "Console", "Curses"],
+    &["Environment", "Console", "Framebuffer"],
+    &["Environment", "Console", "Newt"],
+    &["Environment", "Console", "svgalib"],
+    &["Environment", "GPU"],
+    &["Environment", "GPU", "NVIDIA CUDA"],
+    &["Environment", "GPU", "NVIDIA CUDA", "1.0"],
+    &["Environment", "GPU", "NVIDIA CUDA", "1.1"],
+    &["Environment", "GPU", "NVIDIA CUDA", "2.0"],
+    &["Environment", "GPU", "NVIDIA CUDA", "2.1"],
+    &["Environment", "GPU", "NVIDIA CUDA", "2.2"],
+    &["Environment", "GPU", "NVIDIA CUDA", "2.3"],
+    &["Environment", "GPU", "NVIDIA CUDA", "3.0"],
+    &["Environment", "GPU", "NVIDIA CUDA", "3.1"],
+    &["Environment", "GPU", "NVIDIA CUDA", "3.2"],
+    &["Environment", "GPU", "NVIDIA CUDA", "4.0"],
+    &["Environment", "GPU", "NVIDIA CUDA", "4.1"],
+    &["Environment", "GPU", "NVIDIA CUDA", "4.2"],
+    &["Environment", "GPU", "NVIDIA CUDA", "5.0"],
+    &["Environment", "GPU", "NVIDIA CUDA", "5.5"],
+    &["Environment", "GPU", "NVIDIA CUDA", "6.0"],
+    &["Environment", "GPU", "NVIDIA CUDA", "6.5"],
+    &["Environment", "GPU", "NVIDIA CUDA", "7.0"],
+    &["Environment", "GPU", "NVIDIA CUDA", "7.5"],
+    &["Environment", "GPU", "NVIDIA CUDA", "8.0"],
+    &["Environment", "GPU", "NVIDIA CUDA", "9.0"],
+    &["Environment", "GPU", "NVIDIA CUDA", "9.1"],
+    &["Environment", "GPU", "NVIDIA CUDA", "9.2"],
+    &["Environment", "GPU", "NVIDIA CUDA", "10.0"],
+    &["Environment", "GPU", "NVIDIA CUDA", "10.1"],
+    &["Environment", "GPU", "NVIDIA CUDA", "10.2"],
+    &["Environment", "GPU", "NVIDIA CUDA", "11"],
+    &["Environment", "GPU", "NVIDIA CUDA", "11.0"],
+    &["Environment", "GPU", "NVIDIA CUDA", "11.1"],
+    &["Environment", "GPU", "NVIDIA CUDA", "11.2"],
+    &["Environment", "GPU", "NVIDIA CUDA", "11.3"],
+    &["Environment", "GPU", "NVIDIA CUDA", "11.4"],
+    &["Environment", "GPU", "NVIDIA CUDA", "11.5"],
+    &["Environment", "GPU", "NVIDIA CUDA", "11.6"],
+    &["Environment", "GPU", "NVIDIA CUDA", "11.7"],
+    &["Environment", "GPU", "NVIDIA CUDA", "11.8"],
+    &["Environment", "GPU", "NVIDIA CUDA", "12"],
+    &["Environment", "GPU", "NVIDIA CUDA", "12", "12.0"],
+    &["Environment", "GPU", "NVIDIA CUDA", "12", "12.1"],
+    &["Environment", "GPU", "NVIDIA CUDA", "12", "12.2"],
+    &["Environment", "GPU", "NVIDIA CUDA", "12", "12.3"],
+    &["Environment", "GPU", "NVIDIA CUDA", "12", "12.4"],
+    &["Environment", "GPU", "NVIDIA CUDA", "12", "12.5"],
+    &["Environment", "Handhelds/PDA's"],
+    &["Environment", "MacOS X"],
+    &["Environment", "MacOS X", "Aqua"],
+    &["Environment", "MacOS X", "Carbon"],
+    &["Environment", "MacOS X", "Cocoa"],
+    &["Environment", "No Input/Output (Daemon)"],
+    &["Environment", "OpenStack"],
+    &["Environment", "Other Environment"],
+    &["Environment", "Plugins"],
+    &["Environment", "Web Environment"],
+    &["Environment", "Web Environment", "Buffet"],
+    &["Environment", "Web Environment", "Mozilla"],
+    &["Environment", "Web Environment", "ToscaWidgets"],
+    &["Environment", "WebAssembly"],
+    &["Environment", "WebAssembly", "Emscripten"],
+    &["Environment", "WebAssembly", "WASI"],
+    &["Environment", "Win32 (MS Windows)"],
+    &["Environment", "X11 Applications"],
+    &["Environment", "X11 Applications", "GTK"],
+    &["Environment", "X11 Applications", "Gnome"],
+    &["Environment", "X11 Applications", "KDE"],
+    &["Environment", "X11 Applications", "Qt"],
+];
+
+pub(super) static VARIANTS: [&str; 71] = [
+    "Environment__Console",
+    "Environment__Console__Curses",
+    "Environment__Console__Framebuffer",
+    "Environment__Console__Newt",
+    "Environment__Console__svgalib",
+    "Environment__GPU",
+    "Environment__GPU__NVIDIACUDA",
+    "Environment__GPU__NVIDIACUDA__1_0",
+    "Environment__GPU__NVIDIACUDA__1_1",
+    "Environment__GPU__NVIDIACUDA__2_0",
+    "Environment__GPU__NVIDIACUDA__2_1",
+    "Environment__GPU__NVIDIACUDA__2_2",
+    "Environment__GPU__NVIDIACUDA__2_3",
+    "Environment__GPU__NVIDIACUDA__3_0",
+    "Environment__GPU__NVIDIACUDA__3_1",
+    "Environment__GPU__NVIDIACUDA__3_2",
+    "Environment__GPU__NVIDIACUDA__4_0",
+    "Environment__GPU__NVIDIACUDA__4_1",
+    "Environment__GPU__NVIDIACUDA__4_2",
+    "Environment__GPU__NVIDIACUDA__5_0",
+    "Environment__GPU__NVIDIACUDA__5_5",
+    "Environment__GPU__NVIDIACUDA__6_0",
+    "Environment__GPU__NVIDIACUDA__6_5",
+    "Environment__GPU__NVIDIACUDA__7_0",
+    "Environment__GPU__NVIDIACUDA__7_5",
+    "Environment__GPU__NVIDIACUDA__8_0",
+    "Environment__GPU__NVIDIACUDA__9_0",
+    "Environment__GPU__NVIDIACUDA__9_1",
+    "Environment__GPU__NVIDIACUDA__9_2",
+    "Environment__GPU__NVIDIACUDA__10_0",
+    "Environment__GPU__NVIDIACUDA__10_1",
+    "Environment__GPU__NVIDIACUDA__10_2",
+    "Environment__GPU__NVIDIACUDA__11",
+    "Environment__GPU__NVIDIACUDA__11_0",
+    "Environment__GPU__NVIDIACUDA__11_1",
+    "Environment__GPU__NVIDIACUDA__11_2",
+    "Environment__GPU__NVIDIACUDA__11_3",
+    "Environment__GPU__NVIDIACUDA__11_4",
+    "Environment__GPU__NVIDIACUDA__11_5",
+    "Environment__GPU__NVIDIACUDA__11_6",
+    "Environment__GPU__NVIDIACUDA__11_7",
+    "Environment__GPU__NVIDIACUDA__11_8",
+    "Environment__GPU__NVIDIACUDA__12",
+    "Environment__GPU__NVIDIACUDA__12__12_0",
+    "Environment__GPU__NVIDIACUDA__12__12_1",
+    "Environment__GPU__NVIDIACUDA__12__12_2",
+    "Environment__GPU__NVIDIACUDA__12__12_3",
+    "Environment__GPU__NVIDIACUDA__12__12_4",
+    "Environment__GPU__NVIDIACUDA__12__12_5",
+    "Environment__HandheldsPDAs",
+    "Environment__MacOSX",
+    "Environment__MacOSX__Aqua",
+    "Environment__MacOSX__Carbon",
+    "Environment__MacOSX__Cocoa",
+    "Environment__NoInputOutputDaemon",
+    "Environment__OpenStack",
+    "Environment__OtherEnvironment",
+    "Environment__Plugins",
+    "Environment__WebEnvironment",
+    "Environment__WebEnvironment__Buffet",
+    "Environment__WebEnvironment__Mozilla",
+    "Environment__WebEnvironment__ToscaWidgets",
+    "Environment__WebAssembly",
+    "Environment__WebAssembly__Emscripten",
+    "Environment__WebAssembly__WASI",
+    "Environment__Win32MSWindows",
+    "Environment__X11Applications",
+    "Environment__X11Applications__GTK",
+    "Environment__X11Applications__Gnome",
+    "Environment__X11Applications__KDE",
+    "Environment__X11Applications__Qt",
+];
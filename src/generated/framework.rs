@@ -0,0 +1,738 @@
+// @generated by `cargo xtask codegen` from data/, do not edit by hand.
+
+use crate::table::CategoryTable;
+use crate::Classifier;
+
+pub(super) static TABLE: CategoryTable = CategoryTable {
+    name: "Framework",
+    classifiers: &[
+        Classifier::Framework__AWSCDK,
+        Classifier::Framework__AWSCDK__1,
+        Classifier::Framework__AWSCDK__2,
+        Classifier::Framework__AiiDA,
+        Classifier::Framework__Ansible,
+        Classifier::Framework__AnyIO,
+        Classifier::Framework__ApacheAirflow,
+        Classifier::Framework__ApacheAirflow__Provider,
+        Classifier::Framework__AsyncIO,
+        Classifier::Framework__BEAT,
+        Classifier::Framework__BFG,
+        Classifier::Framework__Bob,
+        Classifier::Framework__Bottle,
+        Classifier::Framework__Buildout,
+        Classifier::Framework__Buildout__Extension,
+        Classifier::Framework__Buildout__Recipe,
+        Classifier::Framework__CastleCMS,
+        Classifier::Framework__CastleCMS__Theme,
+        Classifier::Framework__Celery,
+        Classifier::Framework__Chandler,
+        Classifier::Framework__CherryPy,
+        Classifier::Framework__CubicWeb,
+        Classifier::Framework__Dash,
+        Classifier::Framework__Datasette,
+        Classifier::Framework__Django,
+        Classifier::Framework__Django__1,
+        Classifier::Framework__Django__1_4,
+        Classifier::Framework__Django__1_5,
+        Classifier::Framework__Django__1_6,
+        Classifier::Framework__Django__1_7,
+        Classifier::Framework__Django__1_8,
+        Classifier::Framework__Django__1_9,
+        Classifier::Framework__Django__1_10,
+        Classifier::Framework__Django__1_11,
+        Classifier::Framework__Django__2,
+        Classifier::Framework__Django__2_0,
+        Classifier::Framework__Django__2_1,
+        Classifier::Framework__Django__2_2,
+        Classifier::Framework__Django__3,
+        Classifier::Framework__Django__3_0,
+        Classifier::Framework__Django__3_1,
+        Classifier::Framework__Django__3_2,
+        Classifier::Framework__Django__4,
+        Classifier::Framework__Django__4_0,
+        Classifier::Framework__Django__4_1,
+        Classifier::Framework__Django__4_2,
+        Classifier::Framework__Django__5,
+        Classifier::Framework__Django__5_0,
+        Classifier::Framework__Django__5_1,
+        Classifier::Framework__Django__5_2,
+        Classifier::Framework__DjangoCMS,
+        Classifier::Framework__DjangoCMS__3_4,
+        Classifier::Framework__DjangoCMS__3_5,
+        Classifier::Framework__DjangoCMS__3_6,
+        Classifier::Framework__DjangoCMS__3_7,
+        Classifier::Framework__DjangoCMS__3_8,
+        Classifier::Framework__DjangoCMS__3_9,
+        Classifier::Framework__DjangoCMS__3_10,
+        Classifier::Framework__DjangoCMS__3_11,
+        Classifier::Framework__DjangoCMS__4_0,
+        Classifier::Framework__DjangoCMS__4_1,
+        Classifier::Framework__FastAPI,
+        Classifier::Framework__Flake8,
+        Classifier::Framework__Flask,
+        Classifier::Framework__Hatch,
+        Classifier::Framework__Hypothesis,
+        Classifier::Framework__IDLE,
+        Classifier::Framework__IPython,
+        Classifier::Framework__Jupyter,
+        Classifier::Framework__Jupyter__JupyterLab,
+        Classifier::Framework__Jupyter__JupyterLab__1,
+        Classifier::Framework__Jupyter__JupyterLab__2,
+        Classifier::Framework__Jupyter__JupyterLab__3,
+        Classifier::Framework__Jupyter__JupyterLab__4,
+        Classifier::Framework__Jupyter__JupyterLab__Extensions,
+        Classifier::Framework__Jupyter__JupyterLab__Extensions__MimeRenderers,
+        Classifier::Framework__Jupyter__JupyterLab__Extensions__Prebuilt,
+        Classifier::Framework__Jupyter__JupyterLab__Extensions__Themes,
+        Classifier::Framework__Kedro,
+        Classifier::Framework__Lektor,
+        Classifier::Framework__Masonite,
+        Classifier::Framework__Matplotlib,
+        Classifier::Framework__MkDocs,
+        Classifier::Framework__Nengo,
+        Classifier::Framework__Odoo,
+        Classifier::Framework__Odoo__8_0,
+        Classifier::Framework__Odoo__9_0,
+        Classifier::Framework__Odoo__10_0,
+        Classifier::Framework__Odoo__11_0,
+        Classifier::Framework__Odoo__12_0,
+        Classifier::Framework__Odoo__13_0,
+        Classifier::Framework__Odoo__14_0,
+        Classifier::Framework__Odoo__15_0,
+        Classifier::Framework__Odoo__16_0,
+        Classifier::Framework__Odoo__17_0,
+        Classifier::Framework__Odoo__18_0,
+        Classifier::Framework__OpenTelemetry,
+        Classifier::Framework__OpenTelemetry__Distros,
+        Classifier::Framework__OpenTelemetry__Exporters,
+        Classifier::Framework__OpenTelemetry__Instrumentations,
+        Classifier::Framework__Opps,
+        Classifier::Framework__Paste,
+        Classifier::Framework__Pelican,
+        Classifier::Framework__Pelican__Plugins,
+        Classifier::Framework__Pelican__Themes,
+        Classifier::Framework__Plone,
+        Classifier::Framework__Plone__3_2,
+        Classifier::Framework__Plone__3_3,
+        Classifier::Framework__Plone__4_0,
+        Classifier::Framework__Plone__4_1,
+        Classifier::Framework__Plone__4_2,
+        Classifier::Framework__Plone__4_3,
+        Classifier::Framework__Plone__5_0,
+        Classifier::Framework__Plone__5_1,
+        Classifier::Framework__Plone__5_2,
+        Classifier::Framework__Plone__5_3,
+        Classifier::Framework__Plone__6_0,
+        Classifier::Framework__Plone__6_1,
+        Classifier::Framework__Plone__Addon,
+        Classifier::Framework__Plone__Core,
+        Classifier::Framework__Plone__Distribution,
+        Classifier::Framework__Plone__Theme,
+        Classifier::Framework__PySimpleGUI,
+        Classifier::Framework__PySimpleGUI__4,
+        Classifier::Framework__PySimpleGUI__5,
+        Classifier::Framework__Pycsou,
+        Classifier::Framework__Pydantic,
+        Classifier::Framework__Pydantic__1,
+        Classifier::Framework__Pydantic__2,
+        Classifier::Framework__Pylons,
+        Classifier::Framework__Pyramid,
+        Classifier::Framework__Pytest,
+        Classifier::Framework__ReviewBoard,
+        Classifier::Framework__RobotFramework,
+        Classifier::Framework__RobotFramework__Library,
+        Classifier::Framework__RobotFramework__Tool,
+        Classifier::Framework__Scrapy,
+        Classifier::Framework__SetuptoolsPlugin,
+        Classifier::Framework__Sphinx,
+        Classifier::Framework__Sphinx__Domain,
+        Classifier::Framework__Sphinx__Extension,
+        Classifier::Framework__Sphinx__Theme,
+        Classifier::Framework__Trac,
+        Classifier::Framework__Trio,
+        Classifier::Framework__Tryton,
+        Classifier::Framework__TurboGears,
+        Classifier::Framework__TurboGears__Applications,
+        Classifier::Framework__TurboGears__Widgets,
+        Classifier::Framework__Twisted,
+        Classifier::Framework__Wagtail,
+        Classifier::Framework__Wagtail__1,
+        Classifier::Framework__Wagtail__2,
+        Classifier::Framework__Wagtail__3,
+        Classifier::Framework__Wagtail__4,
+        Classifier::Framework__Wagtail__5,
+        Classifier::Framework__Wagtail__6,
+        Classifier::Framework__ZODB,
+        Classifier::Framework__Zope,
+        Classifier::Framework__Zope2,
+        Classifier::Framework__Zope3,
+        Classifier::Framework__Zope__2,
+        Classifier::Framework__Zope__3,
+        Classifier::Framework__Zope__4,
+        Classifier::Framework__Zope__5,
+        Classifier::Framework__aiohttp,
+        Classifier::Framework__cocotb,
+        Classifier::Framework__napari,
+        Classifier::Framework__tox,
+    ],
+    text: concat!(
+        "Framework :: AWS CDK",
+        "Framework :: AWS CDK :: 1",
+        "Framework :: AWS CDK :: 2",
+        "Framework :: AiiDA",
+        "Framework :: Ansible",
+        "Framework :: AnyIO",
+        "Framework :: Apache Airflow",
+        "Framework :: Apache Airflow :: Provider",
+        "Framework :: AsyncIO",
+        "Framework :: BEAT",
+        "Framework :: BFG",
+        "Framework :: Bob",
+        "Framework :: Bottle",
+        "Framework :: Buildout",
+        "Framework :: Buildout :: Extension",
+        "Framework :: Buildout :: Recipe",
+        "Framework :: CastleCMS",
+        "Framework :: CastleCMS :: Theme",
+        "Framework :: Celery",
+        "Framework :: Chandler",
+        "Framework :: CherryPy",
+        "Framework :: CubicWeb",
+        "Framework :: Dash",
+        "Framework :: Datasette",
+        "Framework :: Django",
+        "Framework :: Django :: 1",
+        "Framework :: Django :: 1.4",
+        "Framework :: Django :: 1.5",
+        "Framework :: Django :: 1.6",
+        "Framework :: Django :: 1.7",
+        "Framework :: Django :: 1.8",
+        "Framework :: Django :: 1.9",
+        "Framework :: Django :: 1.10",
+        "Framework :: Django :: 1.11",
+        "Framework :: Django :: 2",
+        "Framework :: Django :: 2.0",
+        "Framework :: Django :: 2.1",
+        "Framework :: Django :: 2.2",
+        "Framework :: Django :: 3",
+        "Framework :: Django :: 3.0",
+        "Framework :: Django :: 3.1",
+        "Framework :: Django :: 3.2",
+        "Framework :: Django :: 4",
+        "Framework :: Django :: 4.0",
+        "Framework :: Django :: 4.1",
+        "Framework :: Django :: 4.2",
+        "Framework :: Django :: 5",
+        "Framework :: Django :: 5.0",
+        "Framework :: Django :: 5.1",
+        "Framework :: Django :: 5.2",
+        "Framework :: Django CMS",
+        "Framework :: Django CMS :: 3.4",
+        "Framework :: Django CMS :: 3.5",
+        "Framework :: Django CMS :: 3.6",
+        "Framework :: Django CMS :: 3.7",
+        "Framework :: Django CMS :: 3.8",
+        "Framework :: Django CMS :: 3.9",
+        "Framework :: Django CMS :: 3.10",
+        "Framework :: Django CMS :: 3.11",
+        "Framework :: Django CMS :: 4.0",
+        "Framework :: Django CMS :: 4.1",
+        "Framework :: FastAPI",
+        "Framework :: Flake8",
+        "Framework :: Flask",
+        "Framework :: Hatch",
+        "Framework :: Hypothesis",
+        "Framework :: IDLE",
+        "Framework :: IPython",
+        "Framework :: Jupyter",
+        "Framework :: Jupyter :: JupyterLab",
+        "Framework :: Jupyter :: JupyterLab :: 1",
+        "Framework :: Jupyter :: JupyterLab :: 2",
+        "Framework :: Jupyter :: JupyterLab :: 3",
+        "Framework :: Jupyter :: JupyterLab :: 4",
+        "Framework :: Jupyter :: JupyterLab :: Extensions",
+        "Framework :: Jupyter :: JupyterLab :: Extensions :: Mime Renderers",
+        "Framework :: Jupyter :: JupyterLab :: Extensions :: Prebuilt",
+        "Framework :: Jupyter :: JupyterLab :: Extensions :: Themes",
+        "Framework :: Kedro",
+        "Framework :: Lektor",
+        "Framework :: Masonite",
+        "Framework :: Matplotlib",
+        "Framework :: MkDocs",
+        "Framework :: Nengo",
+        "Framework :: Odoo",
+        "Framework :: Odoo :: 8.0",
+        "Framework :: Odoo :: 9.0",
+        "Framework :: Odoo :: 10.0",
+        "Framework :: Odoo :: 11.0",
+        "Framework :: Odoo :: 12.0",
+        "Framework :: Odoo :: 13.0",
+        "Framework :: Odoo :: 14.0",
+        "Framework :: Odoo :: 15.0",
+        "Framework :: Odoo :: 16.0",
+        "Framework :: Odoo :: 17.0",
+        "Framework :: Odoo :: 18.0",
+        "Framework :: OpenTelemetry",
+        "Framework :: OpenTelemetry :: Distros",
+        "Framework :: OpenTelemetry :: Exporters",
+        "Framework :: OpenTelemetry :: Instrumentations",
+        "Framework :: Opps",
+        "Framework :: Paste",
+        "Framework :: Pelican",
+        "Framework :: Pelican :: Plugins",
+        "Framework :: Pelican :: Themes",
+        "Framework :: Plone",
+        "Framework :: Plone :: 3.2",
+        "Framework :: Plone :: 3.3",
+        "Framework :: Plone :: 4.0",
+        "Framework :: Plone :: 4.1",
+        "Framework :: Plone :: 4.2",
+        "Framework :: Plone :: 4.3",
+        "Framework :: Plone :: 5.0",
+        "Framework :: Plone :: 5.1",
+        "Framework :: Plone :: 5.2",
+        "Framework :: Plone :: 5.3",
+        "Framework :: Plone :: 6.0",
+        "Framework :: Plone :: 6.1",
+        "Framework :: Plone :: Addon",
+        "Framework :: Plone :: Core",
+        "Framework :: Plone :: Distribution",
+        "Framework :: Plone :: Theme",
+        "Framework :: PySimpleGUI",
+        "Framework :: PySimpleGUI :: 4",
+        "Framework :: PySimpleGUI :: 5",
+        "Framework :: Pycsou",
+        "Framework :: Pydantic",
+        "Framework :: Pydantic :: 1",
+        "Framework :: Pydantic :: 2",
+        "Framework :: Pylons",
+        "Framework :: Pyramid",
+        "Framework :: Pytest",
+        "Framework :: Review Board",
+        "Framework :: Robot Framework",
+        "Framework :: Robot Framework :: Library",
+        "Framework :: Robot Framework :: Tool",
+        "Framework :: Scrapy",
+        "Framework :: Setuptools Plugin",
+        "Framework :: Sphinx",
+        "Framework :: Sphinx :: Domain",
+        "Framework :: Sphinx :: Extension",
+        "Framework :: Sphinx :: Theme",
+        "Framework :: Trac",
+        "Framework :: Trio",
+        "Framework :: Tryton",
+        "Framework :: TurboGears",
+        "Framework :: TurboGears :: Applications",
+        "Framework :: TurboGears :: Widgets",
+        "Framework :: Twisted",
+        "Framework :: Wagtail",
+        "Framework :: Wagtail :: 1",
+        "Framework :: Wagtail :: 2",
+        "Framework :: Wagtail :: 3",
+        "Framework :: Wagtail :: 4",
+        "Framework :: Wagtail :: 5",
+        "Framework :: Wagtail :: 6",
+        "Framework :: ZODB",
+        "Framework :: Zope",
+        "Framework :: Zope2",
+        "Framework :: Zope3",
+        "Framework :: Zope :: 2",
+        "Framework :: Zope :: 3",
+        "Framework :: Zope :: 4",
+        "Framework :: Zope :: 5",
+        "Framework :: aiohttp",
+        "Framework :: cocotb",
+        "Framework :: napari",
+        "Framework :: tox",
+    ),
+    ends: &[
+        20, 45, 70, 88, 108, 126, 153, 192, 212, 229, 245, 261, 280, 301, 335, 366, 388, 419, 438,
+        459, 480, 501, 518, 540, 559, 583, 609, 635, 661, 687, 713, 739, 766, 793, 817, 843, 869,
+        895, 919, 945, 971, 997, 1021, 1047, 1073, 1099, 1123, 1149, 1175, 1201, 1224, 1254, 1284,
+        1314, 1344, 1374, 1404, 1435, 1466, 1496, 1526, 1546, 1565, 1583, 1601, 1624, 1641, 1661,
+        1681, 1715, 1754, 1793, 1832, 1871, 1919, 1985, 2045, 2103, 2121, 2140, 2161, 2184, 2203,
+        2221, 2238, 2262, 2286, 2311, 2336, 2361, 2386, 2411, 2436, 2461, 2486, 2511, 2537, 2574,
+        2613, 2659, 2676, 2694, 2714, 2745, 2775, 2793, 2818, 2843, 2868, 2893, 2918, 2943, 2968,
+        2993, 3018, 3043, 3068, 3093, 3120, 3146, 3180, 3207, 3231, 3260, 3289, 3308, 3329, 3355,
+        3381, 3400, 3420, 3439, 3464, 3492, 3531, 3567, 3586, 3616, 3635, 3664, 3696, 3724, 3741,
+        3758, 3777, 3800, 3839, 3873, 3893, 3913, 3938, 3963, 3988, 4013, 4038, 4063, 4080, 4097,
+        4115, 4133, 4155, 4177, 4199, 4221, 4241, 4260, 4279, 4295,
+    ],
+    sorted: &[
+        3, 164, 4, 5, 6, 7, 8, 0, 1, 2, 9, 10, 11, 12, 13, 14, 15, 16, 17, 18, 19, 20, 165, 21, 22,
+        23, 24, 25, 32, 33, 26, 27, 28, 29, 30, 31, 34, 35, 36, 37, 38, 39, 40, 41, 42, 43, 44, 45,
+        46, 47, 48, 49, 50, 57, 58, 51, 52, 53, 54, 55, 56, 59, 60, 61, 62, 63, 64, 65, 66, 67, 68,
+        69, 70, 71, 72, 73, 74, 75, 76, 77, 78, 79, 80, 81, 82, 166, 83, 84, 87, 88, 89, 90, 91,
+        92, 93, 94, 95, 85, 86, 96, 97, 98, 99, 100, 101, 102, 103, 104, 105, 106, 107, 108, 109,
+        110, 111, 112, 113, 114, 115, 116, 117, 118, 119, 120, 121, 125, 126, 127, 128, 129, 130,
+        122, 123, 124, 131, 132, 133, 134, 135, 136, 137, 138, 139, 140, 141, 167, 142, 143, 144,
+        145, 146, 147, 148, 149, 150, 151, 152, 153, 154, 155, 156, 157, 160, 161, 162, 163, 158,
+        159,
+    ],
+    codes: &[
+        79, 80, 81, 82, 83, 84, 85, 86, 87, 88, 89, 90, 91, 92, 93, 94, 95, 96, 97, 98, 99, 100,
+        101, 102, 103, 104, 105, 106, 107, 108, 109, 110, 111, 112, 113, 114, 115, 116, 117, 118,
+        119, 120, 121, 122, 123, 124, 125, 126, 127, 128, 129, 130, 131, 132, 133, 134, 135, 136,
+        137, 138, 139, 140, 141, 142, 143, 144, 145, 146, 147, 148, 149, 150, 151, 152, 153, 154,
+        155, 156, 157, 158, 159, 160, 161, 162, 163, 164, 165, 166, 167, 168, 169, 170, 171, 172,
+        173, 174, 175, 176, 177, 178, 179, 180, 181, 182, 183, 184, 185, 186, 187, 188, 189, 190,
+        191, 192, 193, 194, 195, 196, 197, 198, 199, 200, 201, 202, 203, 204, 205, 206, 207, 208,
+        209, 210, 211, 212, 213, 214, 215, 216, 217, 218, 219, 220, 221, 222, 223, 224, 225, 226,
+        227, 228, 229, 230, 231, 232, 233, 234, 235, 236, 237, 238, 239, 240, 241, 242, 243, 244,
+        245, 246,
+    ],
+};
+
+pub(super) static SEGMENTS: [&[&str]; 168] = [
+    &["Framework", "AWS CDK"],
+    &["Framework", "AWS CDK", "1"],
+    &["Framework", "AWS CDK", "2"],
+    &["Framework", "AiiDA"],
+    &["Framework", "Ansible"],
+    &["Framework", "AnyIO"],
+    &["Framework", "Apache Airflow"],
+    &["Framework", "Apache Airflow", "Provider"],
+    &["Framework", "AsyncIO"],
+    &["Framework", "BEAT"],
+    &["Framework", "BFG"],
+    &["Framework", "Bob"],
+    &["Framework", "Bottle"],
+    &["Framework", "Buildout"],
+    &["Framework", "Buildout", "Extension"],
+    &["Framework", "Buildout", "Recipe"],
+    &["Framework", "CastleCMS"],
+    &["Framework", "CastleCMS", "Theme"],
+    &["Framework", "Celery"],
+    &["Framework", "Chandler"],
+    &["Framework", "CherryPy"],
+    &["Framework", "CubicWeb"],
+    &["Framework", "Dash"],
+    &["Framework", "Datasette"],
+    &["Framework", "Django"],
+    &["Framework", "Django", "1"],
+    &["Framework", "Django", "1.4"],
+    &["Framework", "Django", "1.5"],
+    &["Framework", "Django", "1.6"],
+    &["Framework", "Django", "1.7"],
+    &["Framework", "Django", "1.8"],
+    &["Framework", "Django", "1.9"],
+    &["Framework", "Django", "1.10"],
+    &["Framework", "Django", "1.11"],
+    &["Framework", "Django", "2"],
+    &["Framework", "Django", "2.0"],
+    &["Framework", "Django", "2.1"],
+    &["Framework", "Django", "2.2"],
+    &["Framework", "Django", "3"],
+    &["Framework", "Django", "3.0"],
+    &["Framework", "Django", "3.1"],
+    &["Framework", "Django", "3.2"],
+    &["Framework", "Django", "4"],
+    &["Framework", "Django", "4.0"],
+    &["Framework", "Django", "4.1"],
+    &["Framework", "Django", "4.2"],
+    &["Framework", "Django", "5"],
+    &["Framework", "Django", "5.0"],
+    &["Framework", "Django", "5.1"],
+    &["Framework", "Django", "5.2"],
+    &["Framework", "Django CMS"],
+    &["Framework", "Django CMS", "3.4"],
+    &["Framework", "Django CMS", "3.5"],
+    &["Framework", "Django CMS", "3.6"],
+    &["Framework", "Django CMS", "3.7"],
+    &["Framework", "Django CMS", "3.8"],
+    &["Framework", "Django CMS", "3.9"],
+    &["Framework", "Django CMS", "3.10"],
+    &["Framework", "Django CMS", "3.11"],
+    &["Framework", "Django CMS", "4.0"],
+    &["Framework", "Django CMS", "4.1"],
+    &["Framework", "FastAPI"],
+    &["Framework", "Flake8"],
+    &["Framework", "Flask"],
+    &["Framework", "Hatch"],
+    &["Framework", "Hypothesis"],
+    &["Framework", "IDLE"],
+    &["Framework", "IPython"],
+    &["Framework", "Jupyter"],
+    &["Framework", "Jupyter", "JupyterLab"],
+    &["Framework", "Jupyter", "JupyterLab", "1"],
+    &["Framework", "Jupyter", "JupyterLab", "2"],
+    &["Framework", "Jupyter", "JupyterLab", "3"],
+    &["Framework", "Jupyter", "JupyterLab", "4"],
+    &["Framework", "Jupyter", "JupyterLab", "Extensions"],
+    &[
+        "Framework",
+        "Jupyter",
+        "JupyterLab",
+        "Extensions",
+        "Mime Renderers",
+    ],
+    &[
+        "Framework",
+        "Jupyter",
+        "JupyterLab",
+        "Extensions",
+        "Prebuilt",
+    ],
+    &["Framework", "Jupyter", "JupyterLab", "Extensions", "Themes"],
+    &["Framework", "Kedro"],
+    &["Framework", "Lektor"],
+    &["Framework", "Masonite"],
+    &["Framework", "Matplotlib"],
+    &["Framework", "MkDocs"],
+    &["Framework", "Nengo"],
+    &["Framework", "Odoo"],
+    &["Framework", "Odoo", "8.0"],
+    &["Framework", "Odoo", "9.0"],
+    &["Framework", "Odoo", "10.0"],
+    &["Framework", "Odoo", "11.0"],
+    &["Framework", "Odoo", "12.0"],
+    &["Framework", "Odoo", "13.0"],
+    &["Framework", "Odoo", "14.0"],
+    &["Framework", "Odoo", "15.0"],
+    &["Framework", "Odoo", "16.0"],
+    &["Framework", "Odoo", "17.0"],
+    &["Framework", "Odoo", "18.0"],
+    &["Framework", "OpenTelemetry"],
+    &["Framework", "OpenTelemetry", "Distros"],
+    &["Framework", "OpenTelemetry", "Exporters"],
+    &["Framework", "OpenTelemetry", "Instrumentations"],
+    &["Framework", "Opps"],
+    &["Framework", "Paste"],
+    &["Framework", "Pelican"],
+    &["Framework", "Pelican", "Plugins"],
+    &["Framework", "Pelican", "Themes"],
+    &["Framework", "Plone"],
+    &["Framework", "Plone", "3.2"],
+    &["Framework", "Plone", "3.3"],
+    &["Framework", "Plone", "4.0"],
+    &["Framework", "Plone", "4.1"],
+    &["Framework", "Plone", "4.2"],
+    &["Framework", "Plone", "4.3"],
+    &["Framework", "Plone", "5.0"],
+    &["Framework", "Plone", "5.1"],
+    &["Framework", "Plone", "5.2"],
+    &["Framework", "Plone", "5.3"],
+    &["Framework", "Plone", "6.0"],
+    &["Framework", "Plone", "6.1"],
+    &["Framework", "Plone", "Addon"],
+    &["Framework", "Plone", "Core"],
+    &["Framework", "Plone", "Distribution"],
+    &["Framework", "Plone", "Theme"],
+    &["Framework", "PySimpleGUI"],
+    &["Framework", "PySimpleGUI", "4"],
+    &["Framework", "PySimpleGUI", "5"],
+    &["Framework", "Pycsou"],
+    &["Framework", "Pydantic"],
+    &["Framework", "Pydantic", "1"],
+    &["Framework", "Pydantic", "2"],
+    &["Framework", "Pylons"],
+    &["Framework", "Pyramid"],
+    &["Framework", "Pytest"],
+    &["Framework", "Review Board"],
+    &["Framework", "Robot Framework"],
+    &["Framework", "Robot Framework", "Library"],
+    &["Framework", "Robot Framework", "Tool"],
+    &["Framework", "Scrapy"],
+    &["Framework", "Setuptools Plugin"],
+    &["Framework", "Sphinx"],
+    &["Framework", "Sphinx", "Domain"],
+    &["Framework", "Sphinx", "Extension"],
+    &["Framework", "Sphinx", "Theme"],
+    &["Framework", "Trac"],
+    &["Framework", "Trio"],
+    &["Framework", "Tryton"],
+    &["Framework", "TurboGears"],
+    &["Framework", "TurboGears", "Applications"],
+    &["Framework", "TurboGears", "Widgets"],
+    &["Framework", "Twisted"],
+    &["Framework", "Wagtail"],
+    &["Framework", "Wagtail", "1"],
+    &["Framework", "Wagtail", "2"],
+    &["Framework", "Wagtail", "3"],
+    &["Framework", "Wagtail", "4"],
+    &["Framework", "Wagtail", "5"],
+    &["Framework", "Wagtail", "6"],
+    &["Framework", "ZODB"],
+    &["Framework", "Zope"],
+    &["Framework", "Zope2"],
+    &["Framework", "Zope3"],
+    &["Framework", "Zope", "2"],
+    &["Framework", "Zope", "3"],
+    &["Framework", "Zope", "4"],
+    &["Framework", "Zope", "5"],
+    &["Framework", "aiohttp"],
+    &["Framework", "cocotb"],
+    &["Framework", "napari"],
+    &["Framework", "tox"],
+];
+
+pub(super) static VARIANTS: [&str; 168] = [
+    "Framework__AWSCDK",
+    "Framework__AWSCDK__1",
+    "Framework__AWSCDK__2",
+    "Framework__AiiDA",
+    "Framework__Ansible",
+    "Framework__AnyIO",
+    "Framework__ApacheAirflow",
+    "Framework__ApacheAirflow__Provider",
+    "Framework__AsyncIO",
+    "Framework__BEAT",
+    "Framework__BFG",
+    "Framework__Bob",
+    "Framework__Bottle",
+    "Framework__Buildout",
+    "Framework__Buildout__Extension",
+    "Framework__Buildout__Recipe",
+    "Framework__CastleCMS",
+    "Framework__CastleCMS__Theme",
+    "Framework__Celery",
+    "Framework__Chandler",
+    "Framework__CherryPy",
+    "Framework__CubicWeb",
+    "Framework__Dash",
+    "Framework__Datasette",
+    "Framework__Django",
+    "Framework__Django__1",
+    "Framework__Django__1_4",
+    "Framework__Django__1_5",
+    "Framework__Django__1_6",
+    "Framework__Django__1_7",
+    "Framework__Django__1_8",
+    "Framework__Django__1_9",
+    "Framework__Django__1_10",
+    "Framework__Django__1_11",
+    "Framework__Django__2",
+    "Framework__Django__2_0",
+    "Framework__Django__2_1",
+    "Framework__Django__2_2",
+    "Framework__Django__3",
+    "Framework__Django__3_0",
+    "Framework__Django__3_1",
+    "Framework__Django__3_2",
+    "Framework__Django__4",
+    "Framework__Django__4_0",
+    "Framework__Django__4_1",
+    "Framework__Django__4_2",
+    "Framework__Django__5",
+    "Framework__Django__5_0",
+    "Framework__Django__5_1",
+    "Framework__Django__5_2",
+    "Framework__DjangoCMS",
+    "Framework__DjangoCMS__3_4",
+    "Framework__DjangoCMS__3_5",
+    "Framework__DjangoCMS__3_6",
+    "Framework__DjangoCMS__3_7",
+    "Framework__DjangoCMS__3_8",
+    "Framework__DjangoCMS__3_9",
+    "Framework__DjangoCMS__3_10",
+    "Framework__DjangoCMS__3_11",
+    "Framework__DjangoCMS__4_0",
+    "Framework__DjangoCMS__4_1",
+    "Framework__FastAPI",
+    "Framework__Flake8",
+    "Framework__Flask",
+    "Framework__Hatch",
+    "Framework__Hypothesis",
+    "Framework__IDLE",
+    "Framework__IPython",
+    "Framework__Jupyter",
+    "Framework__Jupyter__JupyterLab",
+    "Framework__Jupyter__JupyterLab__1",
+    "Framework__Jupyter__JupyterLab__2",
+    "Framework__Jupyter__JupyterLab__3",
+    "Framework__Jupyter__JupyterLab__4",
+    "Framework__Jupyter__JupyterLab__Extensions",
+    "Framework__Jupyter__JupyterLab__Extensions__MimeRenderers",
+    "Framework__Jupyter__JupyterLab__Extensions__Prebuilt",
+    "Framework__Jupyter__JupyterLab__Extensions__Themes",
+    "Framework__Kedro",
+    "Framework__Lektor",
+    "Framework__Masonite",
+    "Framework__Matplotlib",
+    "Framework__MkDocs",
+    "Framework__Nengo",
+    "Framework__Odoo",
+    "Framework__Odoo__8_0",
+    "Framework__Odoo__9_0",
+    "Framework__Odoo__10_0",
+    "Framework__Odoo__11_0",
+    "Framework__Odoo__12_0",
+    "Framework__Odoo__13_0",
+    "Framework__Odoo__14_0",
+    "Framework__Odoo__15_0",
+    "Framework__Odoo__16_0",
+    "Framework__Odoo__17_0",
+    "Framework__Odoo__18_0",
+    "Framework__OpenTelemetry",
+    "Framework__OpenTelemetry__Distros",
+    "Framework__OpenTelemetry__Exporters",
+    "Framework__OpenTelemetry__Instrumentations",
+    "Framework__Opps",
+    "Framework__Paste",
+    "Framework__Pelican",
+    "Framework__Pelican__Plugins",
+    "Framework__Pelican__Themes",
+    "Framework__Plone",
+    "Framework__Plone__3_2",
+    "Framework__Plone__3_3",
+    "Framework__Plone__4_0",
+    "Framework__Plone__4_1",
+    "Framework__Plone__4_2",
+    "Framework__Plone__4_3",
+    "Framework__Plone__5_0",
+    "Framework__Plone__5_1",
+    "Framework__Plone__5_2",
+    "Framework__Plone__5_3",
+    "Framework__Plone__6_0",
+    "Framework__Plone__6_1",
+    "Framework__Plone__Addon",
+    "Framework__Plone__Core",
+    "Framework__Plone__Distribution",
+    "Framework__Plone__Theme",
+    "Framework__PySimpleGUI",
+    "Framework__PySimpleGUI__4",
+    "Framework__PySimpleGUI__5",
+    "Framework__Pycsou",
+    "Framework__Pydantic",
+    "Framework__Pydantic__1",
+    "Framework__Pydantic__2",
+    "Framework__Pylons",
+    "Framework__Pyramid",
+    "Framework__Pytest",
+    "Framework__ReviewBoard",
+    "Framework__RobotFramework",
+    "Framework__RobotFramework__Library",
+    "Framework__RobotFramework__Tool",
+    "Framework__Scrapy",
+    "Framework__SetuptoolsPlugin",
+    "Framework__Sphinx",
+    "Framework__Sphinx__Domain",
+    "Framework__Sphinx__Extension",
+    "Framework__Sphinx__Theme",
+    "Framework__Trac",
+    "Framework__Trio",
+    "Framework__Tryton",
+    "Framework__TurboGears",
+    "Framework__TurboGears__Applications",
+    "Framework__TurboGears__Widgets",
+    "Framework__Twisted",
+    "Framework__Wagtail",
+    "Framework__Wagtail__1",
+    "Framework__Wagtail__2",
+    "Framework__Wagtail__3",
+    "Framework__Wagtail__4",
+    "Framework__Wagtail__5",
+    "Framework__Wagtail__6",
+    "Framework__ZODB",
+    "Framework__Zope",
+    "Framework__Zope2",
+    "Framework__Zope3",
+    "Framework__Zope__2",
+    "Framework__Zope__3",
+    "Framework__Zope__4",
+    "Framework__Zope__5",
+    "Framework__aiohttp",
+    "Framework__cocotb",
+    "Framework__napari",
+    "Framework__tox",
+];
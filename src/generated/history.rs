@@ -0,0 +1,5 @@
+// @generated by `cargo xtask codegen` from data/, do not edit by hand.
+
+/// Earlier versions of the dataset, oldest first, each with the classifiers removed and
+/// added since
+pub(crate) static HISTORY: &[(&str, &[&str], &[&str])] = &[];
@@ -0,0 +1,81 @@
+// @generated by `cargo xtask codegen` from data/, do not edit by hand.
+
+use crate::table::CategoryTable;
+use crate::Classifier;
+
+pub(super) static TABLE: CategoryTable = CategoryTable {
+    name: "Intended Audience",
+    classifiers: &[
+        Classifier::IntendedAudience__CustomerService,
+        Classifier::IntendedAudience__Developers,
+        Classifier::IntendedAudience__Education,
+        Classifier::IntendedAudience__EndUsersDesktop,
+        Classifier::IntendedAudience__FinancialandInsuranceIndustry,
+        Classifier::IntendedAudience__HealthcareIndustry,
+        Classifier::IntendedAudience__InformationTechnology,
+        Classifier::IntendedAudience__LegalIndustry,
+        Classifier::IntendedAudience__Manufacturing,
+        Classifier::IntendedAudience__OtherAudience,
+        Classifier::IntendedAudience__Religion,
+        Classifier::IntendedAudience__ScienceResearch,
+        Classifier::IntendedAudience__SystemAdministrators,
+        Classifier::IntendedAudience__TelecommunicationsIndustry,
+    ],
+    text: concat!(
+        "Intended Audience :: Customer Service",
+        "Intended Audience :: Developers",
+        "Intended Audience :: Education",
+        "Intended Audience :: End Users/Desktop",
+        "Intended Audience :: Financial and Insurance Industry",
+        "Intended Audience :: Healthcare Industry",
+        "Intended Audience :: Information Technology",
+        "Intended Audience :: Legal Industry",
+        "Intended Audience :: Manufacturing",
+        "Intended Audience :: Other Audience",
+        "Intended Audience :: Religion",
+        "Intended Audience :: Science/Research",
+        "Intended Audience :: System Administrators",
+        "Intended Audience :: Telecommunications Industry",
+    ),
+    ends: &[
+        37, 68, 98, 136, 189, 229, 272, 307, 341, 376, 405, 442, 484, 532,
+    ],
+    sorted: &[0, 1, 2, 3, 4, 5, 6, 7, 8, 9, 10, 11, 12, 13],
+    codes: &[
+        247, 248, 249, 250, 251, 252, 253, 254, 255, 256, 257, 258, 259, 260,
+    ],
+};
+
+pub(super) static SEGMENTS: [&[&str]; 14] = [
+    &["Intended Audience", "Customer Service"],
+    &["Intended Audience", "Developers"],
+    &["Intended Audience", "Education"],
+    &["Intended Audience", "End Users/Desktop"],
+    &["Intended Audience", "Financial and Insurance Industry"],
+    &["Intended Audience", "Healthcare Industry"],
+    &["Intended Audience", "Information Technology"],
+    &["Intended Audience", "Legal Industry"],
+    &["Intended Audience", "Manufacturing"],
+    &["Intended Audience", "Other Audience"],
+    &["Intended Audience", "Religion"],
+    &["Intended Audience", "Science/Research"],
+    &["Intended Audience", "System Administrators"],
+    &["Intended Audience", "Telecommunications Industry"],
+];
+
+pub(super) static VARIANTS: [&str; 14] = [
+    "IntendedAudience__CustomerService",
+    "IntendedAudience__Developers",
+    "IntendedAudience__Education",
+    "IntendedAudience__EndUsersDesktop",
+    "IntendedAudience__FinancialandInsuranceIndustry",
+    "IntendedAudience__HealthcareIndustry",
+    "IntendedAudience__InformationTechnology",
+    "IntendedAudience__LegalIndustry",
+    "IntendedAudience__Manufacturing",
+    "IntendedAudience__OtherAudience",
+    "IntendedAudience__Religion",
+    "IntendedAudience__ScienceResearch",
+    "IntendedAudience__SystemAdministrators",
+    "IntendedAudience__TelecommunicationsIndustry",
+];
@@ -0,0 +1,812 @@
+// @generated by `cargo xtask codegen` from data/, do not edit by hand.
+
+use crate::table::CategoryTable;
+use crate::Classifier;
+
+pub(super) static TABLE: CategoryTable = CategoryTable {
+    name: "License",
+    classifiers: &[
+        Classifier::License__AladdinFreePublicLicenseAFPL,
+        Classifier::License__CC01_0UniversalCC01_0PublicDomainDedication,
+        Classifier::License__CeCILLBFreeSoftwareLicenseAgreementCECILLB,
+        Classifier::License__CeCILLCFreeSoftwareLicenseAgreementCECILLC,
+        Classifier::License__DFSGapproved,
+        Classifier::License__EiffelForumLicenseEFL,
+        Classifier::License__FreeForEducationalUse,
+        Classifier::License__FreeForHomeUse,
+        Classifier::License__FreeToUseButRestricted,
+        Classifier::License__Freefornoncommercialuse,
+        Classifier::License__FreelyDistributable,
+        Classifier::License__Freeware,
+        Classifier::License__GUSTFontLicense1_0,
+        Classifier::License__GUSTFontLicense20060930,
+        Classifier::License__NetscapePublicLicenseNPL,
+        Classifier::License__NokiaOpenSourceLicenseNOKOS,
+        Classifier::License__OSIApproved,
+        Classifier::License__OSIApproved__AcademicFreeLicenseAFL,
+        Classifier::License__OSIApproved__ApacheSoftwareLicense,
+        Classifier::License__OSIApproved__ApplePublicSourceLicense,
+        Classifier::License__OSIApproved__ArtisticLicense,
+        Classifier::License__OSIApproved__AttributionAssuranceLicense,
+        Classifier::License__OSIApproved__BSDLicense,
+        Classifier::License__OSIApproved__BlueOakModelLicenseBlueOak1_0_0,
+        Classifier::License__OSIApproved__BoostSoftwareLicense1_0BSL1_0,
+        Classifier::License__OSIApproved__CEACNRSInriaLogicielLibreLicenseversion2_1CeCILL2_1,
+        Classifier::License__OSIApproved__CMULicenseMITCMU,
+        Classifier::License__OSIApproved__CommonDevelopmentandDistributionLicense1_0CDDL1_0,
+        Classifier::License__OSIApproved__CommonPublicLicense,
+        Classifier::License__OSIApproved__EclipsePublicLicense1_0EPL1_0,
+        Classifier::License__OSIApproved__EclipsePublicLicense2_0EPL2_0,
+        Classifier::License__OSIApproved__EducationalCommunityLicenseVersion2_0ECL2_0,
+        Classifier::License__OSIApproved__EiffelForumLicense,
+        Classifier::License__OSIApproved__EuropeanUnionPublicLicence1_0EUPL1_0,
+        Classifier::License__OSIApproved__EuropeanUnionPublicLicence1_1EUPL1_1,
+        Classifier::License__OSIApproved__EuropeanUnionPublicLicence1_2EUPL1_2,
+        Classifier::License__OSIApproved__GNUAfferoGeneralPublicLicensev3,
+        Classifier::License__OSIApproved__GNUAfferoGeneralPublicLicensev3orlaterAGPLv3plus,
+        Classifier::License__OSIApproved__GNUFreeDocumentationLicenseFDL,
+        Classifier::License__OSIApproved__GNUGeneralPublicLicenseGPL,
+        Classifier::License__OSIApproved__GNUGeneralPublicLicensev2GPLv2,
+        Classifier::License__OSIApproved__GNUGeneralPublicLicensev2orlaterGPLv2plus,
+        Classifier::License__OSIApproved__GNUGeneralPublicLicensev3GPLv3,
+        Classifier::License__OSIApproved__GNUGeneralPublicLicensev3orlaterGPLv3plus,
+        Classifier::License__OSIApproved__GNULesserGeneralPublicLicensev2LGPLv2,
+        Classifier::License__OSIApproved__GNULesserGeneralPublicLicensev2orlaterLGPLv2plus,
+        Classifier::License__OSIApproved__GNULesserGeneralPublicLicensev3LGPLv3,
+        Classifier::License__OSIApproved__GNULesserGeneralPublicLicensev3orlaterLGPLv3plus,
+        Classifier::License__OSIApproved__GNULibraryorLesserGeneralPublicLicenseLGPL,
+        Classifier::License__OSIApproved__HistoricalPermissionNoticeandDisclaimerHPND,
+        Classifier::License__OSIApproved__IBMPublicLicense,
+        Classifier::License__OSIApproved__ISCLicenseISCL,
+        Classifier::License__OSIApproved__IntelOpenSourceLicense,
+        Classifier::License__OSIApproved__JabberOpenSourceLicense,
+        Classifier::License__OSIApproved__MITLicense,
+        Classifier::License__OSIApproved__MITNoAttributionLicenseMIT0,
+        Classifier::License__OSIApproved__MITRECollaborativeVirtualWorkspaceLicenseCVW,
+        Classifier::License__OSIApproved__MirOSLicenseMirOS,
+        Classifier::License__OSIApproved__MotosotoLicense,
+        Classifier::License__OSIApproved__MozillaPublicLicense1_0MPL,
+        Classifier::License__OSIApproved__MozillaPublicLicense1_1MPL1_1,
+        Classifier::License__OSIApproved__MozillaPublicLicense2_0MPL2_0,
+        Classifier::License__OSIApproved__MulanPermissiveSoftwareLicensev2MulanPSL2_0,
+        Classifier::License__OSIApproved__NASAOpenSourceAgreementv1_3NASA1_3,
+        Classifier::License__OSIApproved__NethackGeneralPublicLicense,
+        Classifier::License__OSIApproved__NokiaOpenSourceLicense,
+        Classifier::License__OSIApproved__OpenGroupTestSuiteLicense,
+        Classifier::License__OSIApproved__OpenSoftwareLicense3_0OSL3_0,
+        Classifier::License__OSIApproved__PostgreSQLLicense,
+        Classifier::License__OSIApproved__PythonLicenseCNRIPythonLicense,
+        Classifier::License__OSIApproved__PythonSoftwareFoundationLicense,
+        Classifier::License__OSIApproved__QtPublicLicenseQPL,
+        Classifier::License__OSIApproved__RicohSourceCodePublicLicense,
+        Classifier::License__OSIApproved__SILOpenFontLicense1_1OFL1_1,
+        Classifier::License__OSIApproved__SleepycatLicense,
+        Classifier::License__OSIApproved__SunIndustryStandardsSourceLicenseSISSL,
+        Classifier::License__OSIApproved__SunPublicLicense,
+        Classifier::License__OSIApproved__TheUnlicenseUnlicense,
+        Classifier::License__OSIApproved__UniversalPermissiveLicenseUPL,
+        Classifier::License__OSIApproved__UniversityofIllinoisNCSAOpenSourceLicense,
+        Classifier::License__OSIApproved__VovidaSoftwareLicense1_0,
+        Classifier::License__OSIApproved__W3CLicense,
+        Classifier::License__OSIApproved__X_NetLicense,
+        Classifier::License__OSIApproved__ZeroClauseBSD0BSD,
+        Classifier::License__OSIApproved__ZopePublicLicense,
+        Classifier::License__OSIApproved__zliblibpngLicense,
+        Classifier::License__OtherProprietaryLicense,
+        Classifier::License__PublicDomain,
+        Classifier::License__RepozePublicLicense,
+    ],
+    text: concat!(
+        "License :: Aladdin Free Public License (AFPL)",
+        "License :: CC0 1.0 Universal (CC0 1.0) Public Domain Dedication",
+        "License :: CeCILL-B Free Software License Agreement (CECILL-B)",
+        "License :: CeCILL-C Free Software License Agreement (CECILL-C)",
+        "License :: DFSG approved",
+        "License :: Eiffel Forum License (EFL)",
+        "License :: Free For Educational Use",
+        "License :: Free For Home Use",
+        "License :: Free To Use But Restricted",
+        "License :: Free for non-commercial use",
+        "License :: Freely Distributable",
+        "License :: Freeware",
+        "License :: GUST Font License 1.0",
+        "License :: GUST Font License 2006-09-30",
+        "License :: Netscape Public License (NPL)",
+        "License :: Nokia Open Source License (NOKOS)",
+        "License :: OSI Approved",
+        "License :: OSI Approved :: Academic Free License (AFL)",
+        "License :: OSI Approved :: Apache Software License",
+        "License :: OSI Approved :: Apple Public Source License",
+        "License :: OSI Approved :: Artistic License",
+        "License :: OSI Approved :: Attribution Assurance License",
+        "License :: OSI Approved :: BSD License",
+        "License :: OSI Approved :: Blue Oak Model License (BlueOak-1.0.0)",
+        "License :: OSI Approved :: Boost Software License 1.0 (BSL-1.0)",
+        "License :: OSI Approved :: CEA CNRS Inria Logiciel Libre License, version 2.1 (CeCILL-2.1)",
+        "License :: OSI Approved :: CMU License (MIT-CMU)",
+        "License :: OSI Approved :: Common Development and Distribution License 1.0 (CDDL-1.0)",
+        "License :: OSI Approved :: Common Public License",
+        "License :: OSI Approved :: Eclipse Public License 1.0 (EPL-1.0)",
+        "License :: OSI Approved :: Eclipse Public License 2.0 (EPL-2.0)",
+        "License :: OSI Approved :: Educational Community License, Version 2.0 (ECL-2.0)",
+        "License :: OSI Approved :: Eiffel Forum License",
+        "License :: OSI Approved :: European Union Public Licence 1.0 (EUPL 1.0)",
+        "License :: OSI Approved :: European Union Public Licence 1.1 (EUPL 1.1)",
+        "License :: OSI Approved :: European Union Public Licence 1.2 (EUPL 1.2)",
+        "License :: OSI Approved :: GNU Affero General Public License v3",
+        "License :: OSI Approved :: GNU Affero General Public License v3 or later (AGPLv3+)",
+        "License :: OSI Approved :: GNU Free Documentation License (FDL)",
+        "License :: OSI Approved :: GNU General Public License (GPL)",
+        "License :: OSI Approved :: GNU General Public License v2 (GPLv2)",
+        "License :: OSI Approved :: GNU General Public License v2 or later (GPLv2+)",
+        "License :: OSI Approved :: GNU General Public License v3 (GPLv3)",
+        "License :: OSI Approved :: GNU General Public License v3 or later (GPLv3+)",
+        "License :: OSI Approved :: GNU Lesser General Public License v2 (LGPLv2)",
+        "License :: OSI Approved :: GNU Lesser General Public License v2 or later (LGPLv2+)",
+        "License :: OSI Approved :: GNU Lesser General Public License v3 (LGPLv3)",
+        "License :: OSI Approved :: GNU Lesser General Public License v3 or later (LGPLv3+)",
+        "License :: OSI Approved :: GNU Library or Lesser General Public License (LGPL)",
+        "License :: OSI Approved :: Historical Permission Notice and Disclaimer (HPND)",
+        "License :: OSI Approved :: IBM Public License",
+        "License :: OSI Approved :: ISC License (ISCL)",
+        "License :: OSI Approved :: Intel Open Source License",
+        "License :: OSI Approved :: Jabber Open Source License",
+        "License :: OSI Approved :: MIT License",
+        "License :: OSI Approved :: MIT No Attribution License (MIT-0)",
+        "License :: OSI Approved :: MITRE Collaborative Virtual Workspace License (CVW)",
+        "License :: OSI Approved :: MirOS License (MirOS)",
+        "License :: OSI Approved :: Motosoto License",
+        "License :: OSI Approved :: Mozilla Public License 1.0 (MPL)",
+        "License :: OSI Approved :: Mozilla Public License 1.1 (MPL 1.1)",
+        "License :: OSI Approved :: Mozilla Public License 2.0 (MPL 2.0)",
+        "License :: OSI Approved :: Mulan Permissive Software License v2 (MulanPSL-2.0)",
+        "License :: OSI Approved :: NASA Open Source Agreement v1.3 (NASA-1.3)",
+        "License :: OSI Approved :: Nethack General Public License",
+        "License :: OSI Approved :: Nokia Open Source License",
+        "License :: OSI Approved :: Open Group Test Suite License",
+        "License :: OSI Approved :: Open Software License 3.0 (OSL-3.0)",
+        "License :: OSI Approved :: PostgreSQL License",
+        "License :: OSI Approved :: Python License (CNRI Python License)",
+        "License :: OSI Approved :: Python Software Foundation License",
+        "License :: OSI Approved :: Qt Public License (QPL)",
+        "License :: OSI Approved :: Ricoh Source Code Public License",
+        "License :: OSI Approved :: SIL Open Font License 1.1 (OFL-1.1)",
+        "License :: OSI Approved :: Sleepycat License",
+        "License :: OSI Approved :: Sun Industry Standards Source License (SISSL)",
+        "License :: OSI Approved :: Sun Public License",
+        "License :: OSI Approved :: The Unlicense (Unlicense)",
+        "License :: OSI Approved :: Universal Permissive License (UPL)",
+        "License :: OSI Approved :: University of Illinois/NCSA Open Source License",
+        "License :: OSI Approved :: Vovida Software License 1.0",
+        "License :: OSI Approved :: W3C License",
+        "License :: OSI Approved :: X.Net License",
+        "License :: OSI Approved :: Zero-Clause BSD (0BSD)",
+        "License :: OSI Approved :: Zope Public License",
+        "License :: OSI Approved :: zlib/libpng License",
+        "License :: Other/Proprietary License",
+        "License :: Public Domain",
+        "License :: Repoze Public License",
+    ),
+    ends: &[
+        45,
+        108,
+        170,
+        232,
+        256,
+        293,
+        328,
+        356,
+        393,
+        431,
+        462,
+        481,
+        513,
+        552,
+        592,
+        636,
+        659,
+        713,
+        763,
+        817,
+        860,
+        916,
+        954,
+        1019,
+        1082,
+        1172,
+        1220,
+        1305,
+        1353,
+        1416,
+        1479,
+        1558,
+        1605,
+        1676,
+        1747,
+        1818,
+        1881,
+        1963,
+        2026,
+        2085,
+        2149,
+        2223,
+        2287,
+        2361,
+        2433,
+        2515,
+        2587,
+        2669,
+        2747,
+        2824,
+        2869,
+        2914,
+        2966,
+        3019,
+        3057,
+        3118,
+        3196,
+        3244,
+        3287,
+        3346,
+        3409,
+        3472,
+        3550,
+        3619,
+        3676,
+        3728,
+        3784,
+        3846,
+        3891,
+        3954,
+        4015,
+        4065,
+        4124,
+        4186,
+        4230,
+        4302,
+        4347,
+        4399,
+        4460,
+        4534,
+        4588,
+        4626,
+        4666,
+        4715,
+        4761,
+        4807,
+        4843,
+        4867,
+        4899,
+    ],
+    sorted: &[
+        0,
+        1,
+        2,
+        3,
+        4,
+        5,
+        6,
+        7,
+        9,
+        8,
+        10,
+        11,
+        12,
+        13,
+        14,
+        15,
+        16,
+        17,
+        18,
+        19,
+        20,
+        21,
+        23,
+        24,
+        22,
+        25,
+        26,
+        27,
+        28,
+        29,
+        30,
+        31,
+        32,
+        33,
+        34,
+        35,
+        36,
+        37,
+        38,
+        39,
+        40,
+        41,
+        42,
+        43,
+        44,
+        45,
+        46,
+        47,
+        48,
+        49,
+        50,
+        52,
+        51,
+        53,
+        57,
+        54,
+        55,
+        56,
+        58,
+        59,
+        60,
+        61,
+        62,
+        63,
+        64,
+        65,
+        66,
+        67,
+        68,
+        69,
+        70,
+        71,
+        72,
+        73,
+        74,
+        75,
+        76,
+        77,
+        78,
+        79,
+        80,
+        81,
+        82,
+        83,
+        85,
+        84,
+        86,
+        87,
+        88,
+    ],
+    codes: &[
+        261,
+        262,
+        263,
+        264,
+        265,
+        266,
+        267,
+        268,
+        269,
+        270,
+        271,
+        272,
+        273,
+        274,
+        275,
+        276,
+        277,
+        278,
+        279,
+        280,
+        281,
+        282,
+        283,
+        284,
+        285,
+        286,
+        287,
+        288,
+        289,
+        290,
+        291,
+        292,
+        293,
+        294,
+        295,
+        296,
+        297,
+        298,
+        299,
+        300,
+        301,
+        302,
+        303,
+        304,
+        305,
+        306,
+        307,
+        308,
+        309,
+        310,
+        311,
+        312,
+        313,
+        314,
+        315,
+        316,
+        317,
+        318,
+        319,
+        320,
+        321,
+        322,
+        323,
+        324,
+        325,
+        326,
+        327,
+        328,
+        329,
+        330,
+        331,
+        332,
+        333,
+        334,
+        335,
+        336,
+        337,
+        338,
+        339,
+        340,
+        341,
+        342,
+        343,
+        344,
+        345,
+        346,
+        347,
+        348,
+        349,
+    ],
+};
+
+pub(super) static SEGMENTS: [&[&str]; 89] = [
+    &["License", "Aladdin Free Public License (AFPL)"],
+    &[
+        "License",
+        "CC0 1.0 Universal (CC0 1.0) Public Domain Dedication",
+    ],
+    &[
+        "License",
+        "CeCILL-B Free Software License Agreement (CECILL-B)",
+    ],
+    &[
+        "License",
+        "CeCILL-C Free Software License Agreement (CECILL-C)",
+    ],
+    &["License", "DFSG approved"],
+    &["License", "Eiffel Forum License (EFL)"],
+    &["License", "Free For Educational Use"],
+    &["License", "Free For Home Use"],
+    &["License", "Free To Use But Restricted"],
+    &["License", "Free for non-commercial use"],
+    &["License", "Freely Distributable"],
+    &["License", "Freeware"],
+    &["License", "GUST Font License 1.0"],
+    &["License", "GUST Font License 2006-09-30"],
+    &["License", "Netscape Public License (NPL)"],
+    &["License", "Nokia Open Source License (NOKOS)"],
+    &["License", "OSI Approved"],
+    &["License", "OSI Approved", "Academic Free License (AFL)"],
+    &["License", "OSI Approved", "Apache Software License"],
+    &["License", "OSI Approved", "Apple Public Source License"],
+    &["License", "OSI Approved", "Artistic License"],
+    &["License", "OSI Approved", "Attribution Assurance License"],
+    &["License", "OSI Approved", "BSD License"],
+    &[
+        "License",
+        "OSI Approved",
+        "Blue Oak Model License (BlueOak-1.0.0)",
+    ],
+    &[
+        "License",
+        "OSI Approved",
+        "Boost Software License 1.0 (BSL-1.0)",
+    ],
+    &[
+        "License",
+        "OSI Approved",
+        "CEA CNRS Inria Logiciel Libre License, version 2.1 (CeCILL-2.1)",
+    ],
+    &["License", "OSI Approved", "CMU License (MIT-CMU)"],
+    &[
+        "License",
+        "OSI Approved",
+        "Common Development and Distribution License 1.0 (CDDL-1.0)",
+    ],
+    &["License", "OSI Approved", "Common Public License"],
+    &[
+        "License",
+        "OSI Approved",
+        "Eclipse Public License 1.0 (EPL-1.0)",
+    ],
+    &[
+        "License",
+        "OSI Approved",
+        "Eclipse Public License 2.0 (EPL-2.0)",
+    ],
+    &[
+        "License",
+        "OSI Approved",
+        "Educational Community License, Version 2.0 (ECL-2.0)",
+    ],
+    &["License", "OSI Approved", "Eiffel Forum License"],
+    &[
+        "License",
+        "OSI Approved",
+        "European Union Public Licence 1.0 (EUPL 1.0)",
+    ],
+    &[
+        "License",
+        "OSI Approved",
+        "European Union Public Licence 1.1 (EUPL 1.1)",
+    ],
+    &[
+        "License",
+        "OSI Approved",
+        "European Union Public Licence 1.2 (EUPL 1.2)",
+    ],
+    &[
+        "License",
+        "OSI Approved",
+        "GNU Affero General Public License v3",
+    ],
+    &[
+        "License",
+        "OSI Approved",
+        "GNU Affero General Public License v3 or later (AGPLv3+)",
+    ],
+    &[
+        "License",
+        "OSI Approved",
+        "GNU Free Documentation License (FDL)",
+    ],
+    &[
+        "License",
+        "OSI Approved",
+        "GNU General Public License (GPL)",
+    ],
+    &[
+        "License",
+        "OSI Approved",
+        "GNU General Public License v2 (GPLv2)",
+    ],
+    &[
+        "License",
+        "OSI Approved",
+        "GNU General Public License v2 or later (GPLv2+)",
+    ],
+    &[
+        "License",
+        "OSI Approved",
+        "GNU General Public License v3 (GPLv3)",
+    ],
+    &[
+        "License",
+        "OSI Approved",
+        "GNU General Public License v3 or later (GPLv3+)",
+    ],
+    &[
+        "License",
+        "OSI Approved",
+        "GNU Lesser General Public License v2 (LGPLv2)",
+    ],
+    &[
+        "License",
+        "OSI Approved",
+        "GNU Lesser General Public License v2 or later (LGPLv2+)",
+    ],
+    &[
+        "License",
+        "OSI Approved",
+        "GNU Lesser General Public License v3 (LGPLv3)",
+    ],
+    &[
+        "License",
+        "OSI Approved",
+        "GNU Lesser General Public License v3 or later (LGPLv3+)",
+    ],
+    &[
+        "License",
+        "OSI Approved",
+        "GNU Library or Lesser General Public License (LGPL)",
+    ],
+    &[
+        "License",
+        "OSI Approved",
+        "Historical Permission Notice and Disclaimer (HPND)",
+    ],
+    &["License", "OSI Approved", "IBM Public License"],
+    &["License", "OSI Approved", "ISC License (ISCL)"],
+    &["License", "OSI Approved", "Intel Open Source License"],
+    &["License", "OSI Approved", "Jabber Open Source License"],
+    &["License", "OSI Approved", "MIT License"],
+    &[
+        "License",
+        "OSI Approved",
+        "MIT No Attribution License (MIT-0)",
+    ],
+    &[
+        "License",
+        "OSI Approved",
+        "MITRE Collaborative Virtual Workspace License (CVW)",
+    ],
+    &["License", "OSI Approved", "MirOS License (MirOS)"],
+    &["License", "OSI Approved", "Motosoto License"],
+    &[
+        "License",
+        "OSI Approved",
+        "Mozilla Public License 1.0 (MPL)",
+    ],
+    &[
+        "License",
+        "OSI Approved",
+        "Mozilla Public License 1.1 (MPL 1.1)",
+    ],
+    &[
+        "License",
+        "OSI Approved",
+        "Mozilla Public License 2.0 (MPL 2.0)",
+    ],
+    &[
+        "License",
+        "OSI Approved",
+        "Mulan Permissive Software License v2 (MulanPSL-2.0)",
+    ],
+    &[
+        "License",
+        "OSI Approved",
+        "NASA Open Source Agreement v1.3 (NASA-1.3)",
+    ],
+    &["License", "OSI Approved", "Nethack General Public License"],
+    &["License", "OSI Approved", "Nokia Open Source License"],
+    &["License", "OSI Approved", "Open Group Test Suite License"],
+    &[
+        "License",
+        "OSI Approved",
+        "Open Software License 3.0 (OSL-3.0)",
+    ],
+    &["License", "OSI Approved", "PostgreSQL License"],
+    &[
+        "License",
+        "OSI Approved",
+        "Python License (CNRI Python License)",
+    ],
+    &[
+        "License",
+        "OSI Approved",
+        "Python Software Foundation License",
+    ],
+    &["License", "OSI Approved", "Qt Public License (QPL)"],
+    &[
+        "License",
+        "OSI Approved",
+        "Ricoh Source Code Public License",
+    ],
+    &[
+        "License",
+        "OSI Approved",
+        "SIL Open Font License 1.1 (OFL-1.1)",
+    ],
+    &["License", "OSI Approved", "Sleepycat License"],
+    &[
+        "License",
+        "OSI Approved",
+        "Sun Industry Standards Source License (SISSL)",
+    ],
+    &["License", "OSI Approved", "Sun Public License"],
+    &["License", "OSI Approved", "The Unlicense (Unlicense)"],
+    &[
+        "License",
+        "OSI Approved",
+        "Universal Permissive License (UPL)",
+    ],
+    &[
+        "License",
+        "OSI Approved",
+        "University of Illinois/NCSA Open Source License",
+    ],
+    &["License", "OSI Approved", "Vovida Software License 1.0"],
+    &["License", "OSI Approved", "W3C License"],
+    &["License", "OSI Approved", "X.Net License"],
+    &["License", "OSI Approved", "Zero-Clause BSD (0BSD)"],
+    &["License", "OSI Approved", "Zope Public License"],
+    &["License", "OSI Approved", "zlib/libpng License"],
+    &["License", "Other/Proprietary License"],
+    &["License", "Public Domain"],
+    &["License", "Repoze Public License"],
+];
+
+pub(super) static VARIANTS: [&str; 89] = [
+    "License__AladdinFreePublicLicenseAFPL",
+    "License__CC01_0UniversalCC01_0PublicDomainDedication",
+    "License__CeCILLBFreeSoftwareLicenseAgreementCECILLB",
+    "License__CeCILLCFreeSoftwareLicenseAgreementCECILLC",
+    "License__DFSGapproved",
+    "License__EiffelForumLicenseEFL",
+    "License__FreeForEducationalUse",
+    "License__FreeForHomeUse",
+    "License__FreeToUseButRestricted",
+    "License__Freefornoncommercialuse",
+    "License__FreelyDistributable",
+    "License__Freeware",
+    "License__GUSTFontLicense1_0",
+    "License__GUSTFontLicense20060930",
+    "License__NetscapePublicLicenseNPL",
+    "License__NokiaOpenSourceLicenseNOKOS",
+    "License__OSIApproved",
+    "License__OSIApproved__AcademicFreeLicenseAFL",
+    "License__OSIApproved__ApacheSoftwareLicense",
+    "License__OSIApproved__ApplePublicSourceLicense",
+    "License__OSIApproved__ArtisticLicense",
+    "License__OSIApproved__AttributionAssuranceLicense",
+    "License__OSIApproved__BSDLicense",
+    "License__OSIApproved__BlueOakModelLicenseBlueOak1_0_0",
+    "License__OSIApproved__BoostSoftwareLicense1_0BSL1_0",
+    "License__OSIApproved__CEACNRSInriaLogicielLibreLicenseversion2_1CeCILL2_1",
+    "License__OSIApproved__CMULicenseMITCMU",
+    "License__OSIApproved__CommonDevelopmentandDistributionLicense1_0CDDL1_0",
+    "License__OSIApproved__CommonPublicLicense",
+    "License__OSIApproved__EclipsePublicLicense1_0EPL1_0",
+    "License__OSIApproved__EclipsePublicLicense2_0EPL2_0",
+    "License__OSIApproved__EducationalCommunityLicenseVersion2_0ECL2_0",
+    "License__OSIApproved__EiffelForumLicense",
+    "License__OSIApproved__EuropeanUnionPublicLicence1_0EUPL1_0",
+    "License__OSIApproved__EuropeanUnionPublicLicence1_1EUPL1_1",
+    "License__OSIApproved__EuropeanUnionPublicLicence1_2EUPL1_2",
+    "License__OSIApproved__GNUAfferoGeneralPublicLicensev3",
+    "License__OSIApproved__GNUAfferoGeneralPublicLicensev3orlaterAGPLv3plus",
+    "License__OSIApproved__GNUFreeDocumentationLicenseFDL",
+    "License__OSIApproved__GNUGeneralPublicLicenseGPL",
+    "License__OSIApproved__GNUGeneralPublicLicensev2GPLv2",
+    "License__OSIApproved__GNUGeneralPublicLicensev2orlaterGPLv2plus",
+    "License__OSIApproved__GNUGeneralPublicLicensev3GPLv3",
+    "License__OSIApproved__GNUGeneralPublicLicensev3orlaterGPLv3plus",
+    "License__OSIApproved__GNULesserGeneralPublicLicensev2LGPLv2",
+    "License__OSIApproved__GNULesserGeneralPublicLicensev2orlaterLGPLv2plus",
+    "License__OSIApproved__GNULesserGeneralPublicLicensev3LGPLv3",
+    "License__OSIApproved__GNULesserGeneralPublicLicensev3orlaterLGPLv3plus",
+    "License__OSIApproved__GNULibraryorLesserGeneralPublicLicenseLGPL",
+    "License__OSIApproved__HistoricalPermissionNoticeandDisclaimerHPND",
+    "License__OSIApproved__IBMPublicLicense",
+    "License__OSIApproved__ISCLicenseISCL",
+    "License__OSIApproved__IntelOpenSourceLicense",
+    "License__OSIApproved__JabberOpenSourceLicense",
+    "License__OSIApproved__MITLicense",
+    "License__OSIApproved__MITNoAttributionLicenseMIT0",
+    "License__OSIApproved__MITRECollaborativeVirtualWorkspaceLicenseCVW",
+    "License__OSIApproved__MirOSLicenseMirOS",
+    "License__OSIApproved__MotosotoLicense",
+    "License__OSIApproved__MozillaPublicLicense1_0MPL",
+    "License__OSIApproved__MozillaPublicLicense1_1MPL1_1",
+    "License__OSIApproved__MozillaPublicLicense2_0MPL2_0",
+    "License__OSIApproved__MulanPermissiveSoftwareLicensev2MulanPSL2_0",
+    "License__OSIApproved__NASAOpenSourceAgreementv1_3NASA1_3",
+    "License__OSIApproved__NethackGeneralPublicLicense",
+    "License__OSIApproved__NokiaOpenSourceLicense",
+    "License__OSIApproved__OpenGroupTestSuiteLicense",
+    "License__OSIApproved__OpenSoftwareLicense3_0OSL3_0",
+    "License__OSIApproved__PostgreSQLLicense",
+    "License__OSIApproved__PythonLicenseCNRIPythonLicense",
+    "License__OSIApproved__PythonSoftwareFoundationLicense",
+    "License__OSIApproved__QtPublicLicenseQPL",
+    "License__OSIApproved__RicohSourceCodePublicLicense",
+    "License__OSIApproved__SILOpenFontLicense1_1OFL1_1",
+    "License__OSIApproved__SleepycatLicense",
+    "License__OSIApproved__SunIndustryStandardsSourceLicenseSISSL",
+    "License__OSIApproved__SunPublicLicense",
+    "License__OSIApproved__TheUnlicenseUnlicense",
+    "License__OSIApproved__UniversalPermissiveLicenseUPL",
+    "License__OSIApproved__UniversityofIllinoisNCSAOpenSourceLicense",
+    "License__OSIApproved__VovidaSoftwareLicense1_0",
+    "License__OSIApproved__W3CLicense",
+    "License__OSIApproved__X_NetLicense",
+    "License__OSIApproved__ZeroClauseBSD0BSD",
+    "License__OSIApproved__ZopePublicLicense",
+    "License__OSIApproved__zliblibpngLicense",
+    "License__OtherProprietaryLicense",
+    "License__PublicDomain",
+    "License__RepozePublicLicense",
+];
@@ -0,0 +1,3759 @@
+// @generated by `cargo xtask codegen` from data/, do not edit by hand.
+
+//! The classifier dataset, split into one module per top-level category.
+//!
+//! Each category is compiled only when its cargo feature is enabled.
+
+#[cfg(not(any(
+    feature = "development-status",
+    feature = "environment",
+    feature = "framework",
+    feature = "intended-audience",
+    feature = "license",
+    feature = "natural-language",
+    feature = "operating-system",
+    feature = "programming-language",
+    feature = "topic",
+    feature = "typing"
+)))]
+compile_error!("at least one classifier category feature must be enabled");
+
+pub(crate) mod deprecated;
+#[cfg(feature = "development-status")]
+mod development_status;
+#[cfg(feature = "environment")]
+mod environment;
+#[cfg(feature = "framework")]
+mod framework;
+pub(crate) mod history;
+#[cfg(feature = "intended-audience")]
+mod intended_audience;
+#[cfg(feature = "license")]
+mod license;
+#[cfg(feature = "natural-language")]
+mod natural_language;
+#[cfg(feature = "operating-system")]
+mod operating_system;
+#[cfg(feature = "programming-language")]
+mod programming_language;
+#[cfg(feature = "topic")]
+mod topic;
+#[cfg(feature = "typing")]
+mod typing;
+
+use crate::table::CategoryTable;
+
+/// # Examples
+///
+#[cfg_attr(not(feature = "license"), doc = "```ignore")]
+#[cfg_attr(feature = "license", doc = "```")]
+/// use trove_classifiers::Classifier;
+///
+/// let license_classifier = Classifier::License__OSIApproved__GNUGeneralPublicLicensev3orlaterGPLv3plus;
+/// assert_eq!(license_classifier.as_ref(), "License :: OSI Approved :: GNU General Public License v3 or later (GPLv3+)");
+/// ```
+///
+#[cfg_attr(not(feature = "programming-language"), doc = "```ignore")]
+#[cfg_attr(feature = "programming-language", doc = "```")]
+/// use std::str::FromStr;
+/// use trove_classifiers::Classifier;
+///
+/// let py3 = Classifier::from_str("Programming Language :: Python :: 3 :: Only")?;
+/// assert_eq!(py3, Classifier::ProgrammingLanguage__Python__3__Only);
+/// # Ok::<(), trove_classifiers::ParseError>(())
+/// ```
+#[derive(Clone, Copy, Debug, Eq, Hash, Ord, PartialEq, PartialOrd)]
+#[allow(non_camel_case_types)]
+#[repr(u16)]
+pub enum Classifier {
+    #[cfg(feature = "development-status")]
+    DevelopmentStatus__1Planning = 0,
+    #[cfg(feature = "development-status")]
+    DevelopmentStatus__2PreAlpha = 1,
+    #[cfg(feature = "development-status")]
+    DevelopmentStatus__3Alpha = 2,
+    #[cfg(feature = "development-status")]
+    DevelopmentStatus__4Beta = 3,
+    #[cfg(feature = "development-status")]
+    DevelopmentStatus__5ProductionStable = 4,
+    #[cfg(feature = "development-status")]
+    DevelopmentStatus__6Mature = 5,
+    #[cfg(feature = "development-status")]
+    DevelopmentStatus__7Inactive = 6,
+    #[cfg(feature = "environment")]
+    Environment__Console = 7,
+    #[cfg(feature = "environment")]
+    Environment__Console__Curses = 8,
+    #[cfg(feature = "environment")]
+    Environment__Console__Framebuffer = 9,
+    #[cfg(feature = "environment")]
+    Environment__Console__Newt = 10,
+    #[cfg(feature = "environment")]
+    Environment__Console__svgalib = 11,
+    #[cfg(feature = "environment")]
+    Environment__GPU = 12,
+    #[cfg(feature = "environment")]
+    Environment__GPU__NVIDIACUDA = 13,
+    #[cfg(feature = "environment")]
+    Environment__GPU__NVIDIACUDA__1_0 = 14,
+    #[cfg(feature = "environment")]
+    Environment__GPU__NVIDIACUDA__1_1 = 15,
+    #[cfg(feature = "environment")]
+    Environment__GPU__NVIDIACUDA__2_0 = 16,
+    #[cfg(feature = "environment")]
+    Environment__GPU__NVIDIACUDA__2_1 = 17,
+    #[cfg(feature = "environment")]
+    Environment__GPU__NVIDIACUDA__2_2 = 18,
+    #[cfg(feature = "environment")]
+    Environment__GPU__NVIDIACUDA__2_3 = 19,
+    #[cfg(feature = "environment")]
+    Environment__GPU__NVIDIACUDA__3_0 = 20,
+    #[cfg(feature = "environment")]
+    Environment__GPU__NVIDIACUDA__3_1 = 21,
+    #[cfg(feature = "environment")]
+    Environment__GPU__NVIDIACUDA__3_2 = 22,
+    #[cfg(feature = "environment")]
+    Environment__GPU__NVIDIACUDA__4_0 = 23,
+    #[cfg(feature = "environment")]
+    Environment__GPU__NVIDIACUDA__4_1 = 24,
+    #[cfg(feature = "environment")]
+    Environment__GPU__NVIDIACUDA__4_2 = 25,
+    #[cfg(feature = "environment")]
+    Environment__GPU__NVIDIACUDA__5_0 = 26,
+    #[cfg(feature = "environment")]
+    Environment__GPU__NVIDIACUDA__5_5 = 27,
+    #[cfg(feature = "environment")]
+    Environment__GPU__NVIDIACUDA__6_0 = 28,
+    #[cfg(feature = "environment")]
+    Environment__GPU__NVIDIACUDA__6_5 = 29,
+    #[cfg(feature = "environment")]
+    Environment__GPU__NVIDIACUDA__7_0 = 30,
+    #[cfg(feature = "environment")]
+    Environment__GPU__NVIDIACUDA__7_5 = 31,
+    #[cfg(feature = "environment")]
+    Environment__GPU__NVIDIACUDA__8_0 = 32,
+    #[cfg(feature = "environment")]
+    Environment__GPU__NVIDIACUDA__9_0 = 33,
+    #[cfg(feature = "environment")]
+    Environment__GPU__NVIDIACUDA__9_1 = 34,
+    #[cfg(feature = "environment")]
+    Environment__GPU__NVIDIACUDA__9_2 = 35,
+    #[cfg(feature = "environment")]
+    Environment__GPU__NVIDIACUDA__10_0 = 36,
+    #[cfg(feature = "environment")]
+    Environment__GPU__NVIDIACUDA__10_1 = 37,
+    #[cfg(feature = "environment")]
+    Environment__GPU__NVIDIACUDA__10_2 = 38,
+    #[cfg(feature = "environment")]
+    Environment__GPU__NVIDIACUDA__11 = 39,
+    #[cfg(feature = "environment")]
+    Environment__GPU__NVIDIACUDA__11_0 = 40,
+    #[cfg(feature = "environment")]
+    Environment__GPU__NVIDIACUDA__11_1 = 41,
+    #[cfg(feature = "environment")]
+    Environment__GPU__NVIDIACUDA__11_2 = 42,
+    #[cfg(feature = "environment")]
+    Environment__GPU__NVIDIACUDA__11_3 = 43,
+    #[cfg(feature = "environment")]
+    Environment__GPU__NVIDIACUDA__11_4 = 44,
+    #[cfg(feature = "environment")]
+    Environment__GPU__NVIDIACUDA__11_5 = 45,
+    #[cfg(feature = "environment")]
+    Environment__GPU__NVIDIACUDA__11_6 = 46,
+    #[cfg(feature = "environment")]
+    Environment__GPU__NVIDIACUDA__11_7 = 47,
+    #[cfg(feature = "environment")]
+    Environment__GPU__NVIDIACUDA__11_8 = 48,
+    #[cfg(feature = "environment")]
+    Environment__GPU__NVIDIACUDA__12 = 49,
+    #[cfg(feature = "environment")]
+    Environment__GPU__NVIDIACUDA__12__12_0 = 50,
+    #[cfg(feature = "environment")]
+    Environment__GPU__NVIDIACUDA__12__12_1 = 51,
+    #[cfg(feature = "environment")]
+    Environment__GPU__NVIDIACUDA__12__12_2 = 52,
+    #[cfg(feature = "environment")]
+    Environment__GPU__NVIDIACUDA__12__12_3 = 53,
+    #[cfg(feature = "environment")]
+    Environment__GPU__NVIDIACUDA__12__12_4 = 54,
+    #[cfg(feature = "environment")]
+    Environment__GPU__NVIDIACUDA__12__12_5 = 55,
+    #[cfg(feature = "environment")]
+    Environment__HandheldsPDAs = 56,
+    #[cfg(feature = "environment")]
+    Environment__MacOSX = 57,
+    #[cfg(feature = "environment")]
+    Environment__MacOSX__Aqua = 58,
+    #[cfg(feature = "environment")]
+    Environment__MacOSX__Carbon = 59,
+    #[cfg(feature = "environment")]
+    Environment__MacOSX__Cocoa = 60,
+    #[cfg(feature = "environment")]
+    Environment__NoInputOutputDaemon = 61,
+    #[cfg(feature = "environment")]
+    Environment__OpenStack = 62,
+    #[cfg(feature = "environment")]
+    Environment__OtherEnvironment = 63,
+    #[cfg(feature = "environment")]
+    Environment__Plugins = 64,
+    #[cfg(feature = "environment")]
+    Environment__WebEnvironment = 65,
+    #[cfg(feature = "environment")]
+    Environment__WebEnvironment__Buffet = 66,
+    #[cfg(feature = "environment")]
+    Environment__WebEnvironment__Mozilla = 67,
+    #[cfg(feature = "environment")]
+    Environment__WebEnvironment__ToscaWidgets = 68,
+    #[cfg(feature = "environment")]
+    Environment__WebAssembly = 69,
+    #[cfg(feature = "environment")]
+    Environment__WebAssembly__Emscripten = 70,
+    #[cfg(feature = "environment")]
+    Environment__WebAssembly__WASI = 71,
+    #[cfg(feature = "environment")]
+    Environment__Win32MSWindows = 72,
+    #[cfg(feature = "environment")]
+    Environment__X11Applications = 73,
+    #[cfg(feature = "environment")]
+    Environment__X11Applications__GTK = 74,
+    #[cfg(feature = "environment")]
+    Environment__X11Applications__Gnome = 75,
+    #[cfg(feature = "environment")]
+    Environment__X11Applications__KDE = 76,
+    #[cfg(feature = "environment")]
+    Environment__X11Applications__Qt = 77,
+    #[cfg(feature = "framework")]
+    Framework__AWSCDK = 78,
+    #[cfg(feature = "framework")]
+    Framework__AWSCDK__1 = 79,
+    #[cfg(feature = "framework")]
+    Framework__AWSCDK__2 = 80,
+    #[cfg(feature = "framework")]
+    Framework__AiiDA = 81,
+    #[cfg(feature = "framework")]
+    Framework__Ansible = 82,
+    #[cfg(feature = "framework")]
+    Framework__AnyIO = 83,
+    #[cfg(feature = "framework")]
+    Framework__ApacheAirflow = 84,
+    #[cfg(feature = "framework")]
+    Framework__ApacheAirflow__Provider = 85,
+    #[cfg(feature = "framework")]
+    Framework__AsyncIO = 86,
+    #[cfg(feature = "framework")]
+    Framework__BEAT = 87,
+    #[cfg(feature = "framework")]
+    Framework__BFG = 88,
+    #[cfg(feature = "framework")]
+    Framework__Bob = 89,
+    #[cfg(feature = "framework")]
+    Framework__Bottle = 90,
+    #[cfg(feature = "framework")]
+    Framework__Buildout = 91,
+    #[cfg(feature = "framework")]
+    Framework__Buildout__Extension = 92,
+    #[cfg(feature = "framework")]
+    Framework__Buildout__Recipe = 93,
+    #[cfg(feature = "framework")]
+    Framework__CastleCMS = 94,
+    #[cfg(feature = "framework")]
+    Framework__CastleCMS__Theme = 95,
+    #[cfg(feature = "framework")]
+    Framework__Celery = 96,
+    #[cfg(feature = "framework")]
+    Framework__Chandler = 97,
+    #[cfg(feature = "framework")]
+    Framework__CherryPy = 98,
+    #[cfg(feature = "framework")]
+    Framework__CubicWeb = 99,
+    #[cfg(feature = "framework")]
+    Framework__Dash = 100,
+    #[cfg(feature = "framework")]
+    Framework__Datasette = 101,
+    #[cfg(feature = "framework")]
+    Framework__Django = 102,
+    #[cfg(feature = "framework")]
+    Framework__Django__1 = 103,
+    #[cfg(feature = "framework")]
+    Framework__Django__1_4 = 104,
+    #[cfg(feature = "framework")]
+    Framework__Django__1_5 = 105,
+    #[cfg(feature = "framework")]
+    Framework__Django__1_6 = 106,
+    #[cfg(feature = "framework")]
+    Framework__Django__1_7 = 107,
+    #[cfg(feature = "framework")]
+    Framework__Django__1_8 = 108,
+    #[cfg(feature = "framework")]
+    Framework__Django__1_9 = 109,
+    #[cfg(feature = "framework")]
+    Framework__Django__1_10 = 110,
+    #[cfg(feature = "framework")]
+    Framework__Django__1_11 = 111,
+    #[cfg(feature = "framework")]
+    Framework__Django__2 = 112,
+    #[cfg(feature = "framework")]
+    Framework__Django__2_0 = 113,
+    #[cfg(feature = "framework")]
+    Framework__Django__2_1 = 114,
+    #[cfg(feature = "framework")]
+    Framework__Django__2_2 = 115,
+    #[cfg(feature = "framework")]
+    Framework__Django__3 = 116,
+    #[cfg(feature = "framework")]
+    Framework__Django__3_0 = 117,
+    #[cfg(feature = "framework")]
+    Framework__Django__3_1 = 118,
+    #[cfg(feature = "framework")]
+    Framework__Django__3_2 = 119,
+    #[cfg(feature = "framework")]
+    Framework__Django__4 = 120,
+    #[cfg(feature = "framework")]
+    Framework__Django__4_0 = 121,
+    #[cfg(feature = "framework")]
+    Framework__Django__4_1 = 122,
+    #[cfg(feature = "framework")]
+    Framework__Django__4_2 = 123,
+    #[cfg(feature = "framework")]
+    Framework__Django__5 = 124,
+    #[cfg(feature = "framework")]
+    Framework__Django__5_0 = 125,
+    #[cfg(feature = "framework")]
+    Framework__Django__5_1 = 126,
+    #[cfg(feature = "framework")]
+    Framework__Django__5_2 = 127,
+    #[cfg(feature = "framework")]
+    Framework__DjangoCMS = 128,
+    #[cfg(feature = "framework")]
+    Framework__DjangoCMS__3_4 = 129,
+    #[cfg(feature = "framework")]
+    Framework__DjangoCMS__3_5 = 130,
+    #[cfg(feature = "framework")]
+    Framework__DjangoCMS__3_6 = 131,
+    #[cfg(feature = "framework")]
+    Framework__DjangoCMS__3_7 = 132,
+    #[cfg(feature = "framework")]
+    Framework__DjangoCMS__3_8 = 133,
+    #[cfg(feature = "framework")]
+    Framework__DjangoCMS__3_9 = 134,
+    #[cfg(feature = "framework")]
+    Framework__DjangoCMS__3_10 = 135,
+    #[cfg(feature = "framework")]
+    Framework__DjangoCMS__3_11 = 136,
+    #[cfg(feature = "framework")]
+    Framework__DjangoCMS__4_0 = 137,
+    #[cfg(feature = "framework")]
+    Framework__DjangoCMS__4_1 = 138,
+    #[cfg(feature = "framework")]
+    Framework__FastAPI = 139,
+    #[cfg(feature = "framework")]
+    Framework__Flake8 = 140,
+    #[cfg(feature = "framework")]
+    Framework__Flask = 141,
+    #[cfg(feature = "framework")]
+    Framework__Hatch = 142,
+    #[cfg(feature = "framework")]
+    Framework__Hypothesis = 143,
+    #[cfg(feature = "framework")]
+    Framework__IDLE = 144,
+    #[cfg(feature = "framework")]
+    Framework__IPython = 145,
+    #[cfg(feature = "framework")]
+    Framework__Jupyter = 146,
+    #[cfg(feature = "framework")]
+    Framework__Jupyter__JupyterLab = 147,
+    #[cfg(feature = "framework")]
+    Framework__Jupyter__JupyterLab__1 = 148,
+    #[cfg(feature = "framework")]
+    Framework__Jupyter__JupyterLab__2 = 149,
+    #[cfg(feature = "framework")]
+    Framework__Jupyter__JupyterLab__3 = 150,
+    #[cfg(feature = "framework")]
+    Framework__Jupyter__JupyterLab__4 = 151,
+    #[cfg(feature = "framework")]
+    Framework__Jupyter__JupyterLab__Extensions = 152,
+    #[cfg(feature = "framework")]
+    Framework__Jupyter__JupyterLab__Extensions__MimeRenderers = 153,
+    #[cfg(feature = "framework")]
+    Framework__Jupyter__JupyterLab__Extensions__Prebuilt = 154,
+    #[cfg(feature = "framework")]
+    Framework__Jupyter__JupyterLab__Extensions__Themes = 155,
+    #[cfg(feature = "framework")]
+    Framework__Kedro = 156,
+    #[cfg(feature = "framework")]
+    Framework__Lektor = 157,
+    #[cfg(feature = "framework")]
+    Framework__Masonite = 158,
+    #[cfg(feature = "framework")]
+    Framework__Matplotlib = 159,
+    #[cfg(feature = "framework")]
+    Framework__MkDocs = 160,
+    #[cfg(feature = "framework")]
+    Framework__Nengo = 161,
+    #[cfg(feature = "framework")]
+    Framework__Odoo = 162,
+    #[cfg(feature = "framework")]
+    Framework__Odoo__8_0 = 163,
+    #[cfg(feature = "framework")]
+    Framework__Odoo__9_0 = 164,
+    #[cfg(feature = "framework")]
+    Framework__Odoo__10_0 = 165,
+    #[cfg(feature = "framework")]
+    Framework__Odoo__11_0 = 166,
+    #[cfg(feature = "framework")]
+    Framework__Odoo__12_0 = 167,
+    #[cfg(feature = "framework")]
+    Framework__Odoo__13_0 = 168,
+    #[cfg(feature = "framework")]
+    Framework__Odoo__14_0 = 169,
+    #[cfg(feature = "framework")]
+    Framework__Odoo__15_0 = 170,
+    #[cfg(feature = "framework")]
+    Framework__Odoo__16_0 = 171,
+    #[cfg(feature = "framework")]
+    Framework__Odoo__17_0 = 172,
+    #[cfg(feature = "framework")]
+    Framework__Odoo__18_0 = 173,
+    #[cfg(feature = "framework")]
+    Framework__OpenTelemetry = 174,
+    #[cfg(feature = "framework")]
+    Framework__OpenTelemetry__Distros = 175,
+    #[cfg(feature = "framework")]
+    Framework__OpenTelemetry__Exporters = 176,
+    #[cfg(feature = "framework")]
+    Framework__OpenTelemetry__Instrumentations = 177,
+    #[cfg(feature = "framework")]
+    Framework__Opps = 178,
+    #[cfg(feature = "framework")]
+    Framework__Paste = 179,
+    #[cfg(feature = "framework")]
+    Framework__Pelican = 180,
+    #[cfg(feature = "framework")]
+    Framework__Pelican__Plugins = 181,
+    #[cfg(feature = "framework")]
+    Framework__Pelican__Themes = 182,
+    #[cfg(feature = "framework")]
+    Framework__Plone = 183,
+    #[cfg(feature = "framework")]
+    Framework__Plone__3_2 = 184,
+    #[cfg(feature = "framework")]
+    Framework__Plone__3_3 = 185,
+    #[cfg(feature = "framework")]
+    Framework__Plone__4_0 = 186,
+    #[cfg(feature = "framework")]
+    Framework__Plone__4_1 = 187,
+    #[cfg(feature = "framework")]
+    Framework__Plone__4_2 = 188,
+    #[cfg(feature = "framework")]
+    Framework__Plone__4_3 = 189,
+    #[cfg(feature = "framework")]
+    Framework__Plone__5_0 = 190,
+    #[cfg(feature = "framework")]
+    Framework__Plone__5_1 = 191,
+    #[cfg(feature = "framework")]
+    Framework__Plone__5_2 = 192,
+    #[cfg(feature = "framework")]
+    Framework__Plone__5_3 = 193,
+    #[cfg(feature = "framework")]
+    Framework__Plone__6_0 = 194,
+    #[cfg(feature = "framework")]
+    Framework__Plone__6_1 = 195,
+    #[cfg(feature = "framework")]
+    Framework__Plone__Addon = 196,
+    #[cfg(feature = "framework")]
+    Framework__Plone__Core = 197,
+    #[cfg(feature = "framework")]
+    Framework__Plone__Distribution = 198,
+    #[cfg(feature = "framework")]
+    Framework__Plone__Theme = 199,
+    #[cfg(feature = "framework")]
+    Framework__PySimpleGUI = 200,
+    #[cfg(feature = "framework")]
+    Framework__PySimpleGUI__4 = 201,
+    #[cfg(feature = "framework")]
+    Framework__PySimpleGUI__5 = 202,
+    #[cfg(feature = "framework")]
+    Framework__Pycsou = 203,
+    #[cfg(feature = "framework")]
+    Framework__Pydantic = 204,
+    #[cfg(feature = "framework")]
+    Framework__Pydantic__1 = 205,
+    #[cfg(feature = "framework")]
+    Framework__Pydantic__2 = 206,
+    #[cfg(feature = "framework")]
+    Framework__Pylons = 207,
+    #[cfg(feature = "framework")]
+    Framework__Pyramid = 208,
+    #[cfg(feature = "framework")]
+    Framework__Pytest = 209,
+    #[cfg(feature = "framework")]
+    Framework__ReviewBoard = 210,
+    #[cfg(feature = "framework")]
+    Framework__RobotFramework = 211,
+    #[cfg(feature = "framework")]
+    Framework__RobotFramework__Library = 212,
+    #[cfg(feature = "framework")]
+    Framework__RobotFramework__Tool = 213,
+    #[cfg(feature = "framework")]
+    Framework__Scrapy = 214,
+    #[cfg(feature = "framework")]
+    Framework__SetuptoolsPlugin = 215,
+    #[cfg(feature = "framework")]
+    Framework__Sphinx = 216,
+    #[cfg(feature = "framework")]
+    Framework__Sphinx__Domain = 217,
+    #[cfg(feature = "framework")]
+    Framework__Sphinx__Extension = 218,
+    #[cfg(feature = "framework")]
+    Framework__Sphinx__Theme = 219,
+    #[cfg(feature = "framework")]
+    Framework__Trac = 220,
+    #[cfg(feature = "framework")]
+    Framework__Trio = 221,
+    #[cfg(feature = "framework")]
+    Framework__Tryton = 222,
+    #[cfg(feature = "framework")]
+    Framework__TurboGears = 223,
+    #[cfg(feature = "framework")]
+    Framework__TurboGears__Applications = 224,
+    #[cfg(feature = "framework")]
+    Framework__TurboGears__Widgets = 225,
+    #[cfg(feature = "framework")]
+    Framework__Twisted = 226,
+    #[cfg(feature = "framework")]
+    Framework__Wagtail = 227,
+    #[cfg(feature = "framework")]
+    Framework__Wagtail__1 = 228,
+    #[cfg(feature = "framework")]
+    Framework__Wagtail__2 = 229,
+    #[cfg(feature = "framework")]
+    Framework__Wagtail__3 = 230,
+    #[cfg(feature = "framework")]
+    Framework__Wagtail__4 = 231,
+    #[cfg(feature = "framework")]
+    Framework__Wagtail__5 = 232,
+    #[cfg(feature = "framework")]
+    Framework__Wagtail__6 = 233,
+    #[cfg(feature = "framework")]
+    Framework__ZODB = 234,
+    #[cfg(feature = "framework")]
+    Framework__Zope = 235,
+    #[cfg(feature = "framework")]
+    Framework__Zope2 = 236,
+    #[cfg(feature = "framework")]
+    Framework__Zope3 = 237,
+    #[cfg(feature = "framework")]
+    Framework__Zope__2 = 238,
+    #[cfg(feature = "framework")]
+    Framework__Zope__3 = 239,
+    #[cfg(feature = "framework")]
+    Framework__Zope__4 = 240,
+    #[cfg(feature = "framework")]
+    Framework__Zope__5 = 241,
+    #[cfg(feature = "framework")]
+    Framework__aiohttp = 242,
+    #[cfg(feature = "framework")]
+    Framework__cocotb = 243,
+    #[cfg(feature = "framework")]
+    Framework__napari = 244,
+    #[cfg(feature = "framework")]
+    Framework__tox = 245,
+    #[cfg(feature = "intended-audience")]
+    IntendedAudience__CustomerService = 246,
+    #[cfg(feature = "intended-audience")]
+    IntendedAudience__Developers = 247,
+    #[cfg(feature = "intended-audience")]
+    IntendedAudience__Education = 248,
+    #[cfg(feature = "intended-audience")]
+    IntendedAudience__EndUsersDesktop = 249,
+    #[cfg(feature = "intended-audience")]
+    IntendedAudience__FinancialandInsuranceIndustry = 250,
+    #[cfg(feature = "intended-audience")]
+    IntendedAudience__HealthcareIndustry = 251,
+    #[cfg(feature = "intended-audience")]
+    IntendedAudience__InformationTechnology = 252,
+    #[cfg(feature = "intended-audience")]
+    IntendedAudience__LegalIndustry = 253,
+    #[cfg(feature = "intended-audience")]
+    IntendedAudience__Manufacturing = 254,
+    #[cfg(feature = "intended-audience")]
+    IntendedAudience__OtherAudience = 255,
+    #[cfg(feature = "intended-audience")]
+    IntendedAudience__Religion = 256,
+    #[cfg(feature = "intended-audience")]
+    IntendedAudience__ScienceResearch = 257,
+    #[cfg(feature = "intended-audience")]
+    IntendedAudience__SystemAdministrators = 258,
+    #[cfg(feature = "intended-audience")]
+    IntendedAudience__TelecommunicationsIndustry = 259,
+    #[cfg(feature = "license")]
+    License__AladdinFreePublicLicenseAFPL = 260,
+    #[cfg(feature = "license")]
+    License__CC01_0UniversalCC01_0PublicDomainDedication = 261,
+    #[cfg(feature = "license")]
+    License__CeCILLBFreeSoftwareLicenseAgreementCECILLB = 262,
+    #[cfg(feature = "license")]
+    License__CeCILLCFreeSoftwareLicenseAgreementCECILLC = 263,
+    #[cfg(feature = "license")]
+    License__DFSGapproved = 264,
+    #[cfg(feature = "license")]
+    License__EiffelForumLicenseEFL = 265,
+    #[cfg(feature = "license")]
+    License__FreeForEducationalUse = 266,
+    #[cfg(feature = "license")]
+    License__FreeForHomeUse = 267,
+    #[cfg(feature = "license")]
+    License__FreeToUseButRestricted = 268,
+    #[cfg(feature = "license")]
+    License__Freefornoncommercialuse = 269,
+    #[cfg(feature = "license")]
+    License__FreelyDistributable = 270,
+    #[cfg(feature = "license")]
+    License__Freeware = 271,
+    #[cfg(feature = "license")]
+    License__GUSTFontLicense1_0 = 272,
+    #[cfg(feature = "license")]
+    License__GUSTFontLicense20060930 = 273,
+    #[cfg(feature = "license")]
+    License__NetscapePublicLicenseNPL = 274,
+    #[cfg(feature = "license")]
+    License__NokiaOpenSourceLicenseNOKOS = 275,
+    #[cfg(feature = "license")]
+    License__OSIApproved = 276,
+    #[cfg(feature = "license")]
+    License__OSIApproved__AcademicFreeLicenseAFL = 277,
+    #[cfg(feature = "license")]
+    License__OSIApproved__ApacheSoftwareLicense = 278,
+    #[cfg(feature = "license")]
+    License__OSIApproved__ApplePublicSourceLicense = 279,
+    #[cfg(feature = "license")]
+    License__OSIApproved__ArtisticLicense = 280,
+    #[cfg(feature = "license")]
+    License__OSIApproved__AttributionAssuranceLicense = 281,
+    #[cfg(feature = "license")]
+    License__OSIApproved__BSDLicense = 282,
+    #[cfg(feature = "license")]
+    License__OSIApproved__BlueOakModelLicenseBlueOak1_0_0 = 283,
+    #[cfg(feature = "license")]
+    License__OSIApproved__BoostSoftwareLicense1_0BSL1_0 = 284,
+    #[cfg(feature = "license")]
+    License__OSIApproved__CEACNRSInriaLogicielLibreLicenseversion2_1CeCILL2_1 = 285,
+    #[cfg(feature = "license")]
+    License__OSIApproved__CMULicenseMITCMU = 286,
+    #[cfg(feature = "license")]
+    License__OSIApproved__CommonDevelopmentandDistributionLicense1_0CDDL1_0 = 287,
+    #[cfg(feature = "license")]
+    License__OSIApproved__CommonPublicLicense = 288,
+    #[cfg(feature = "license")]
+    License__OSIApproved__EclipsePublicLicense1_0EPL1_0 = 289,
+    #[cfg(feature = "license")]
+    License__OSIApproved__EclipsePublicLicense2_0EPL2_0 = 290,
+    #[cfg(feature = "license")]
+    License__OSIApproved__EducationalCommunityLicenseVersion2_0ECL2_0 = 291,
+    #[cfg(feature = "license")]
+    License__OSIApproved__EiffelForumLicense = 292,
+    #[cfg(feature = "license")]
+    License__OSIApproved__EuropeanUnionPublicLicence1_0EUPL1_0 = 293,
+    #[cfg(feature = "license")]
+    License__OSIApproved__EuropeanUnionPublicLicence1_1EUPL1_1 = 294,
+    #[cfg(feature = "license")]
+    License__OSIApproved__EuropeanUnionPublicLicence1_2EUPL1_2 = 295,
+    #[cfg(feature = "license")]
+    License__OSIApproved__GNUAfferoGeneralPublicLicensev3 = 296,
+    #[cfg(feature = "license")]
+    License__OSIApproved__GNUAfferoGeneralPublicLicensev3orlaterAGPLv3plus = 297,
+    #[cfg(feature = "license")]
+    License__OSIApproved__GNUFreeDocumentationLicenseFDL = 298,
+    #[cfg(feature = "license")]
+    License__OSIApproved__GNUGeneralPublicLicenseGPL = 299,
+    #[cfg(feature = "license")]
+    License__OSIApproved__GNUGeneralPublicLicensev2GPLv2 = 300,
+    #[cfg(feature = "license")]
+    License__OSIApproved__GNUGeneralPublicLicensev2orlaterGPLv2plus = 301,
+    #[cfg(feature = "license")]
+    License__OSIApproved__GNUGeneralPublicLicensev3GPLv3 = 302,
+    #[cfg(feature = "license")]
+    License__OSIApproved__GNUGeneralPublicLicensev3orlaterGPLv3plus = 303,
+    #[cfg(feature = "license")]
+    License__OSIApproved__GNULesserGeneralPublicLicensev2LGPLv2 = 304,
+    #[cfg(feature = "license")]
+    License__OSIApproved__GNULesserGeneralPublicLicensev2orlaterLGPLv2plus = 305,
+    #[cfg(feature = "license")]
+    License__OSIApproved__GNULesserGeneralPublicLicensev3LGPLv3 = 306,
+    #[cfg(feature = "license")]
+    License__OSIApproved__GNULesserGeneralPublicLicensev3orlaterLGPLv3plus = 307,
+    #[cfg(feature = "license")]
+    License__OSIApproved__GNULibraryorLesserGeneralPublicLicenseLGPL = 308,
+    #[cfg(feature = "license")]
+    License__OSIApproved__HistoricalPermissionNoticeandDisclaimerHPND = 309,
+    #[cfg(feature = "license")]
+    License__OSIApproved__IBMPublicLicense = 310,
+    #[cfg(feature = "license")]
+    License__OSIApproved__ISCLicenseISCL = 311,
+    #[cfg(feature = "license")]
+    License__OSIApproved__IntelOpenSourceLicense = 312,
+    #[cfg(feature = "license")]
+    License__OSIApproved__JabberOpenSourceLicense = 313,
+    #[cfg(feature = "license")]
+    License__OSIApproved__MITLicense = 314,
+    #[cfg(feature = "license")]
+    License__OSIApproved__MITNoAttributionLicenseMIT0 = 315,
+    #[cfg(feature = "license")]
+    License__OSIApproved__MITRECollaborativeVirtualWorkspaceLicenseCVW = 316,
+    #[cfg(feature = "license")]
+    License__OSIApproved__MirOSLicenseMirOS = 317,
+    #[cfg(feature = "license")]
+    License__OSIApproved__MotosotoLicense = 318,
+    #[cfg(feature = "license")]
+    License__OSIApproved__MozillaPublicLicense1_0MPL = 319,
+    #[cfg(feature = "license")]
+    License__OSIApproved__MozillaPublicLicense1_1MPL1_1 = 320,
+    #[cfg(feature = "license")]
+    License__OSIApproved__MozillaPublicLicense2_0MPL2_0 = 321,
+    #[cfg(feature = "license")]
+    License__OSIApproved__MulanPermissiveSoftwareLicensev2MulanPSL2_0 = 322,
+    #[cfg(feature = "license")]
+    License__OSIApproved__NASAOpenSourceAgreementv1_3NASA1_3 = 323,
+    #[cfg(feature = "license")]
+    License__OSIApproved__NethackGeneralPublicLicense = 324,
+    #[cfg(feature = "license")]
+    License__OSIApproved__NokiaOpenSourceLicense = 325,
+    #[cfg(feature = "license")]
+    License__OSIApproved__OpenGroupTestSuiteLicense = 326,
+    #[cfg(feature = "license")]
+    License__OSIApproved__OpenSoftwareLicense3_0OSL3_0 = 327,
+    #[cfg(feature = "license")]
+    License__OSIApproved__PostgreSQLLicense = 328,
+    #[cfg(feature = "license")]
+    License__OSIApproved__PythonLicenseCNRIPythonLicense = 329,
+    #[cfg(feature = "license")]
+    License__OSIApproved__PythonSoftwareFoundationLicense = 330,
+    #[cfg(feature = "license")]
+    License__OSIApproved__QtPublicLicenseQPL = 331,
+    #[cfg(feature = "license")]
+    License__OSIApproved__RicohSourceCodePublicLicense = 332,
+    #[cfg(feature = "license")]
+    License__OSIApproved__SILOpenFontLicense1_1OFL1_1 = 333,
+    #[cfg(feature = "license")]
+    License__OSIApproved__SleepycatLicense = 334,
+    #[cfg(feature = "license")]
+    License__OSIApproved__SunIndustryStandardsSourceLicenseSISSL = 335,
+    #[cfg(feature = "license")]
+    License__OSIApproved__SunPublicLicense = 336,
+    #[cfg(feature = "license")]
+    License__OSIApproved__TheUnlicenseUnlicense = 337,
+    #[cfg(feature = "license")]
+    License__OSIApproved__UniversalPermissiveLicenseUPL = 338,
+    #[cfg(feature = "license")]
+    License__OSIApproved__UniversityofIllinoisNCSAOpenSourceLicense = 339,
+    #[cfg(feature = "license")]
+    License__OSIApproved__VovidaSoftwareLicense1_0 = 340,
+    #[cfg(feature = "license")]
+    License__OSIApproved__W3CLicense = 341,
+    #[cfg(feature = "license")]
+    License__OSIApproved__X_NetLicense = 342,
+    #[cfg(feature = "license")]
+    License__OSIApproved__ZeroClauseBSD0BSD = 343,
+    #[cfg(feature = "license")]
+    License__OSIApproved__ZopePublicLicense = 344,
+    #[cfg(feature = "license")]
+    License__OSIApproved__zliblibpngLicense = 345,
+    #[cfg(feature = "license")]
+    License__OtherProprietaryLicense = 346,
+    #[cfg(feature = "license")]
+    License__PublicDomain = 347,
+    #[cfg(feature = "license")]
+    License__RepozePublicLicense = 348,
+    #[cfg(feature = "natural-language")]
+    NaturalLanguage__Afrikaans = 349,
+    #[cfg(feature = "natural-language")]
+    NaturalLanguage__Arabic = 350,
+    #[cfg(feature = "natural-language")]
+    NaturalLanguage__Basque = 351,
+    #[cfg(feature = "natural-language")]
+    NaturalLanguage__Bengali = 352,
+    #[cfg(feature = "natural-language")]
+    NaturalLanguage__Bosnian = 353,
+    #[cfg(feature = "natural-language")]
+    NaturalLanguage__Bulgarian = 354,
+    #[cfg(feature = "natural-language")]
+    NaturalLanguage__Cantonese = 355,
+    #[cfg(feature = "natural-language")]
+    NaturalLanguage__Catalan = 356,
+    #[cfg(feature = "natural-language")]
+    NaturalLanguage__CatalanValencian = 357,
+    #[cfg(feature = "natural-language")]
+    NaturalLanguage__ChineseSimplified = 358,
+    #[cfg(feature = "natural-language")]
+    NaturalLanguage__ChineseTraditional = 359,
+    #[cfg(feature = "natural-language")]
+    NaturalLanguage__Croatian = 360,
+    #[cfg(feature = "natural-language")]
+    NaturalLanguage__Czech = 361,
+    #[cfg(feature = "natural-language")]
+    NaturalLanguage__Danish = 362,
+    #[cfg(feature = "natural-language")]
+    NaturalLanguage__Dutch = 363,
+    #[cfg(feature = "natural-language")]
+    NaturalLanguage__English = 364,
+    #[cfg(feature = "natural-language")]
+    NaturalLanguage__Esperanto = 365,
+    #[cfg(feature = "natural-language")]
+    NaturalLanguage__Finnish = 366,
+    #[cfg(feature = "natural-language")]
+    NaturalLanguage__French = 367,
+    #[cfg(feature = "natural-language")]
+    NaturalLanguage__Galician = 368,
+    #[cfg(feature = "natural-language")]
+    NaturalLanguage__Georgian = 369,
+    #[cfg(feature = "natural-language")]
+    NaturalLanguage__German = 370,
+    #[cfg(feature = "natural-language")]
+    NaturalLanguage__Greek = 371,
+    #[cfg(feature = "natural-language")]
+    NaturalLanguage__Hebrew = 372,
+    #[cfg(feature = "natural-language")]
+    NaturalLanguage__Hindi = 373,
+    #[cfg(feature = "natural-language")]
+    NaturalLanguage__Hungarian = 374,
+    #[cfg(feature = "natural-language")]
+    NaturalLanguage__Icelandic = 375,
+    #[cfg(feature = "natural-language")]
+    NaturalLanguage__Indonesian = 376,
+    #[cfg(feature = "natural-language")]
+    NaturalLanguage__Irish = 377,
+    #[cfg(feature = "natural-language")]
+    NaturalLanguage__Italian = 378,
+    #[cfg(feature = "natural-language")]
+    NaturalLanguage__Japanese = 379,
+    #[cfg(feature = "natural-language")]
+    NaturalLanguage__Javanese = 380,
+    #[cfg(feature = "natural-language")]
+    NaturalLanguage__Korean = 381,
+    #[cfg(feature = "natural-language")]
+    NaturalLanguage__Latin = 382,
+    #[cfg(feature = "natural-language")]
+    NaturalLanguage__Latvian = 383,
+    #[cfg(feature = "natural-language")]
+    NaturalLanguage__Lithuanian = 384,
+    #[cfg(feature = "natural-language")]
+    NaturalLanguage__Macedonian = 385,
+    #[cfg(feature = "natural-language")]
+    NaturalLanguage__Malay = 386,
+    #[cfg(feature = "natural-language")]
+    NaturalLanguage__Marathi = 387,
+    #[cfg(feature = "natural-language")]
+    NaturalLanguage__Nepali = 388,
+    #[cfg(feature = "natural-language")]
+    NaturalLanguage__Norwegian = 389,
+    #[cfg(feature = "natural-language")]
+    NaturalLanguage__Panjabi = 390,
+    #[cfg(feature = "natural-language")]
+    NaturalLanguage__Persian = 391,
+    #[cfg(feature = "natural-language")]
+    NaturalLanguage__Polish = 392,
+    #[cfg(feature = "natural-language")]
+    NaturalLanguage__Portuguese = 393,
+    #[cfg(feature = "natural-language")]
+    NaturalLanguage__PortugueseBrazilian = 394,
+    #[cfg(feature = "natural-language")]
+    NaturalLanguage__Romanian = 395,
+    #[cfg(feature = "natural-language")]
+    NaturalLanguage__Russian = 396,
+    #[cfg(feature = "natural-language")]
+    NaturalLanguage__Serbian = 397,
+    #[cfg(feature = "natural-language")]
+    NaturalLanguage__Slovak = 398,
+    #[cfg(feature = "natural-language")]
+    NaturalLanguage__Slovenian = 399,
+    #[cfg(feature = "natural-language")]
+    NaturalLanguage__Spanish = 400,
+    #[cfg(feature = "natural-language")]
+    NaturalLanguage__Swedish = 401,
+    #[cfg(feature = "natural-language")]
+    NaturalLanguage__Tamil = 402,
+    #[cfg(feature = "natural-language")]
+    NaturalLanguage__Telugu = 403,
+    #[cfg(feature = "natural-language")]
+    NaturalLanguage__Thai = 404,
+    #[cfg(feature = "natural-language")]
+    NaturalLanguage__Tibetan = 405,
+    #[cfg(feature = "natural-language")]
+    NaturalLanguage__Turkish = 406,
+    #[cfg(feature = "natural-language")]
+    NaturalLanguage__Ukrainian = 407,
+    #[cfg(feature = "natural-language")]
+    NaturalLanguage__Urdu = 408,
+    #[cfg(feature = "natural-language")]
+    NaturalLanguage__Vietnamese = 409,
+    #[cfg(feature = "operating-system")]
+    OperatingSystem__Android = 410,
+    #[cfg(feature = "operating-system")]
+    OperatingSystem__BeOS = 411,
+    #[cfg(feature = "operating-system")]
+    OperatingSystem__MacOS = 412,
+    #[cfg(feature = "operating-system")]
+    OperatingSystem__MacOS__MacOS9 = 413,
+    #[cfg(feature = "operating-system")]
+    OperatingSystem__MacOS__MacOSX = 414,
+    #[cfg(feature = "operating-system")]
+    OperatingSystem__Microsoft = 415,
+    #[cfg(feature = "operating-system")]
+    OperatingSystem__Microsoft__MSDOS = 416,
+    #[cfg(feature = "operating-system")]
+    OperatingSystem__Microsoft__Windows = 417,
+    #[cfg(feature = "operating-system")]
+    OperatingSystem__Microsoft__Windows__Windows3_1orEarlier = 418,
+    #[cfg(feature = "operating-system")]
+    OperatingSystem__Microsoft__Windows__Windows7 = 419,
+    #[cfg(feature = "operating-system")]
+    OperatingSystem__Microsoft__Windows__Windows8 = 420,
+    #[cfg(feature = "operating-system")]
+    OperatingSystem__Microsoft__Windows__Windows8_1 = 421,
+    #[cfg(feature = "operating-system")]
+    OperatingSystem__Microsoft__Windows__Windows10 = 422,
+    #[cfg(feature = "operating-system")]
+    OperatingSystem__Microsoft__Windows__Windows11 = 423,
+    #[cfg(feature = "operating-system")]
+    OperatingSystem__Microsoft__Windows__Windows95982000 = 424,
+    #[cfg(feature = "operating-system")]
+    OperatingSystem__Microsoft__Windows__WindowsCE = 425,
+    #[cfg(feature = "operating-system")]
+    OperatingSystem__Microsoft__Windows__WindowsNT2000 = 426,
+    #[cfg(feature = "operating-system")]
+    OperatingSystem__Microsoft__Windows__WindowsServer2003 = 427,
+    #[cfg(feature = "operating-system")]
+    OperatingSystem__Microsoft__Windows__WindowsServer2008 = 428,
+    #[cfg(feature = "operating-system")]
+    OperatingSystem__Microsoft__Windows__WindowsVista = 429,
+    #[cfg(feature = "operating-system")]
+    OperatingSystem__Microsoft__Windows__WindowsXP = 430,
+    #[cfg(feature = "operating-system")]
+    OperatingSystem__OSIndependent = 431,
+    #[cfg(feature = "operating-system")]
+    OperatingSystem__OS2 = 432,
+    #[cfg(feature = "operating-system")]
+    OperatingSystem__OtherOS = 433,
+    #[cfg(feature = "operating-system")]
+    OperatingSystem__PDASystems = 434,
+    #[cfg(feature = "operating-system")]
+    OperatingSystem__POSIX = 435,
+    #[cfg(feature = "operating-system")]
+    OperatingSystem__POSIX__AIX = 436,
+    #[cfg(feature = "operating-system")]
+    OperatingSystem__POSIX__BSD = 437,
+    #[cfg(feature = "operating-system")]
+    OperatingSystem__POSIX__BSD__BSDOS = 438,
+    #[cfg(feature = "operating-system")]
+    OperatingSystem__POSIX__BSD__FreeBSD = 439,
+    #[cfg(feature = "operating-system")]
+    OperatingSystem__POSIX__BSD__NetBSD = 440,
+    #[cfg(feature = "operating-system")]
+    OperatingSystem__POSIX__BSD__OpenBSD = 441,
+    #[cfg(feature = "operating-system")]
+    OperatingSystem__POSIX__GNUHurd = 442,
+    #[cfg(feature = "operating-system")]
+    OperatingSystem__POSIX__HPUX = 443,
+    #[cfg(feature = "operating-system")]
+    OperatingSystem__POSIX__IRIX = 444,
+    #[cfg(feature = "operating-system")]
+    OperatingSystem__POSIX__Linux = 445,
+    #[cfg(feature = "operating-system")]
+    OperatingSystem__POSIX__Other = 446,
+    #[cfg(feature = "operating-system")]
+    OperatingSystem__POSIX__SCO = 447,
+    #[cfg(feature = "operating-system")]
+    OperatingSystem__POSIX__SunOSSolaris = 448,
+    #[cfg(feature = "operating-system")]
+    OperatingSystem__PalmOS = 449,
+    #[cfg(feature = "operating-system")]
+    OperatingSystem__RISCOS = 450,
+    #[cfg(feature = "operating-system")]
+    OperatingSystem__Unix = 451,
+    #[cfg(feature = "operating-system")]
+    OperatingSystem__iOS = 452,
+    #[cfg(feature = "programming-language")]
+    ProgrammingLanguage__APL = 453,
+    #[cfg(feature = "programming-language")]
+    ProgrammingLanguage__ASP = 454,
+    #[cfg(feature = "programming-language")]
+    ProgrammingLanguage__Ada = 455,
+    #[cfg(feature = "programming-language")]
+    ProgrammingLanguage__Assembly = 456,
+    #[cfg(feature = "programming-language")]
+    ProgrammingLanguage__Awk = 457,
+    #[cfg(feature = "programming-language")]
+    ProgrammingLanguage__Basic = 458,
+    #[cfg(feature = "programming-language")]
+    ProgrammingLanguage__C = 459,
+    #[cfg(feature = "programming-language")]
+    ProgrammingLanguage__Csharp = 460,
+    #[cfg(feature = "programming-language")]
+    ProgrammingLanguage__Cplusplus = 461,
+    #[cfg(feature = "programming-language")]
+    ProgrammingLanguage__ColdFusion = 462,
+    #[cfg(feature = "programming-language")]
+    ProgrammingLanguage__Cython = 463,
+    #[cfg(feature = "programming-language")]
+    ProgrammingLanguage__D = 464,
+    #[cfg(feature = "programming-language")]
+    ProgrammingLanguage__DelphiKylix = 465,
+    #[cfg(feature = "programming-language")]
+    ProgrammingLanguage__Dylan = 466,
+    #[cfg(feature = "programming-language")]
+    ProgrammingLanguage__Eiffel = 467,
+    #[cfg(feature = "programming-language")]
+    ProgrammingLanguage__EmacsLisp = 468,
+    #[cfg(feature = "programming-language")]
+    ProgrammingLanguage__Erlang = 469,
+    #[cfg(feature = "programming-language")]
+    ProgrammingLanguage__Euler = 470,
+    #[cfg(feature = "programming-language")]
+    ProgrammingLanguage__Euphoria = 471,
+    #[cfg(feature = "programming-language")]
+    ProgrammingLanguage__Fsharp = 472,
+    #[cfg(feature = "programming-language")]
+    ProgrammingLanguage__Forth = 473,
+    #[cfg(feature = "programming-language")]
+    ProgrammingLanguage__Fortran = 474,
+    #[cfg(feature = "programming-language")]
+    ProgrammingLanguage__Go = 475,
+    #[cfg(feature = "programming-language")]
+    ProgrammingLanguage__Haskell = 476,
+    #[cfg(feature = "programming-language")]
+    ProgrammingLanguage__Hy = 477,
+    #[cfg(feature = "programming-language")]
+    ProgrammingLanguage__Java = 478,
+    #[cfg(feature = "programming-language")]
+    ProgrammingLanguage__JavaScript = 479,
+    #[cfg(feature = "programming-language")]
+    ProgrammingLanguage__Kotlin = 480,
+    #[cfg(feature = "programming-language")]
+    ProgrammingLanguage__Lisp = 481,
+    #[cfg(feature = "programming-language")]
+    ProgrammingLanguage__Logo = 482,
+    #[cfg(feature = "programming-language")]
+    ProgrammingLanguage__Lua = 483,
+    #[cfg(feature = "programming-language")]
+    ProgrammingLanguage__ML = 484,
+    #[cfg(feature = "programming-language")]
+    ProgrammingLanguage__Modula = 485,
+    #[cfg(feature = "programming-language")]
+    ProgrammingLanguage__OCaml = 486,
+    #[cfg(feature = "programming-language")]
+    ProgrammingLanguage__ObjectPascal = 487,
+    #[cfg(feature = "programming-language")]
+    ProgrammingLanguage__ObjectiveC = 488,
+    #[cfg(feature = "programming-language")]
+    ProgrammingLanguage__Other = 489,
+    #[cfg(feature = "programming-language")]
+    ProgrammingLanguage__OtherScriptingEngines = 490,
+    #[cfg(feature = "programming-language")]
+    ProgrammingLanguage__PHP = 491,
+    #[cfg(feature = "programming-language")]
+    ProgrammingLanguage__PLSQL = 492,
+    #[cfg(feature = "programming-language")]
+    ProgrammingLanguage__PROGRESS = 493,
+    #[cfg(feature = "programming-language")]
+    ProgrammingLanguage__Pascal = 494,
+    #[cfg(feature = "programming-language")]
+    ProgrammingLanguage__Perl = 495,
+    #[cfg(feature = "programming-language")]
+    ProgrammingLanguage__Pike = 496,
+    #[cfg(feature = "programming-language")]
+    ProgrammingLanguage__Pliant = 497,
+    #[cfg(feature = "programming-language")]
+    ProgrammingLanguage__Prolog = 498,
+    #[cfg(feature = "programming-language")]
+    ProgrammingLanguage__Python = 499,
+    #[cfg(feature = "programming-language")]
+    ProgrammingLanguage__Python__2 = 500,
+    #[cfg(feature = "programming-language")]
+    ProgrammingLanguage__Python__2__Only = 501,
+    #[cfg(feature = "programming-language")]
+    ProgrammingLanguage__Python__2_3 = 502,
+    #[cfg(feature = "programming-language")]
+    ProgrammingLanguage__Python__2_4 = 503,
+    #[cfg(feature = "programming-language")]
+    ProgrammingLanguage__Python__2_5 = 504,
+    #[cfg(feature = "programming-language")]
+    ProgrammingLanguage__Python__2_6 = 505,
+    #[cfg(feature = "programming-language")]
+    ProgrammingLanguage__Python__2_7 = 506,
+    #[cfg(feature = "programming-language")]
+    ProgrammingLanguage__Python__3 = 507,
+    #[cfg(feature = "programming-language")]
+    ProgrammingLanguage__Python__3__Only = 508,
+    #[cfg(feature = "programming-language")]
+    ProgrammingLanguage__Python__3_0 = 509,
+    #[cfg(feature = "programming-language")]
+    ProgrammingLanguage__Python__3_1 = 510,
+    #[cfg(feature = "programming-language")]
+    ProgrammingLanguage__Python__3_2 = 511,
+    #[cfg(feature = "programming-language")]
+    ProgrammingLanguage__Python__3_3 = 512,
+    #[cfg(feature = "programming-language")]
+    ProgrammingLanguage__Python__3_4 = 513,
+    #[cfg(feature = "programming-language")]
+    ProgrammingLanguage__Python__3_5 = 514,
+    #[cfg(feature = "programming-language")]
+    ProgrammingLanguage__Python__3_6 = 515,
+    #[cfg(feature = "programming-language")]
+    ProgrammingLanguage__Python__3_7 = 516,
+    #[cfg(feature = "programming-language")]
+    ProgrammingLanguage__Python__3_8 = 517,
+    #[cfg(feature = "programming-language")]
+    ProgrammingLanguage__Python__3_9 = 518,
+    #[cfg(feature = "programming-language")]
+    ProgrammingLanguage__Python__3_10 = 519,
+    #[cfg(feature = "programming-language")]
+    ProgrammingLanguage__Python__3_11 = 520,
+    #[cfg(feature = "programming-language")]
+    ProgrammingLanguage__Python__3_12 = 521,
+    #[cfg(feature = "programming-language")]
+    ProgrammingLanguage__Python__3_13 = 522,
+    #[cfg(feature = "programming-language")]
+    ProgrammingLanguage__Python__3_14 = 523,
+    #[cfg(feature = "programming-language")]
+    ProgrammingLanguage__Python__Implementation = 524,
+    #[cfg(feature = "programming-language")]
+    ProgrammingLanguage__Python__Implementation__CPython = 525,
+    #[cfg(feature = "programming-language")]
+    ProgrammingLanguage__Python__Implementation__IronPython = 526,
+    #[cfg(feature = "programming-language")]
+    ProgrammingLanguage__Python__Implementation__Jython = 527,
+    #[cfg(feature = "programming-language")]
+    ProgrammingLanguage__Python__Implementation__MicroPython = 528,
+    #[cfg(feature = "programming-language")]
+    ProgrammingLanguage__Python__Implementation__PyPy = 529,
+    #[cfg(feature = "programming-language")]
+    ProgrammingLanguage__Python__Implementation__Stackless = 530,
+    #[cfg(feature = "programming-language")]
+    ProgrammingLanguage__R = 531,
+    #[cfg(feature = "programming-language")]
+    ProgrammingLanguage__REBOL = 532,
+    #[cfg(feature = "programming-language")]
+    ProgrammingLanguage__Rexx = 533,
+    #[cfg(feature = "programming-language")]
+    ProgrammingLanguage__Ruby = 534,
+    #[cfg(feature = "programming-language")]
+    ProgrammingLanguage__Rust = 535,
+    #[cfg(feature = "programming-language")]
+    ProgrammingLanguage__SQL = 536,
+    #[cfg(feature = "programming-language")]
+    ProgrammingLanguage__Scheme = 537,
+    #[cfg(feature = "programming-language")]
+    ProgrammingLanguage__Simula = 538,
+    #[cfg(feature = "programming-language")]
+    ProgrammingLanguage__Smalltalk = 539,
+    #[cfg(feature = "programming-language")]
+    ProgrammingLanguage__Tcl = 540,
+    #[cfg(feature = "programming-language")]
+    ProgrammingLanguage__UnixShell = 541,
+    #[cfg(feature = "programming-language")]
+    ProgrammingLanguage__VisualBasic = 542,
+    #[cfg(feature = "programming-language")]
+    ProgrammingLanguage__XBasic = 543,
+    #[cfg(feature = "programming-language")]
+    ProgrammingLanguage__YACC = 544,
+    #[cfg(feature = "programming-language")]
+    ProgrammingLanguage__Zope = 545,
+    #[cfg(feature = "topic")]
+    Topic__AdaptiveTechnologies = 546,
+    #[cfg(feature = "topic")]
+    Topic__ArtisticSoftware = 547,
+    #[cfg(feature = "topic")]
+    Topic__Communications = 548,
+    #[cfg(feature = "topic")]
+    Topic__Communications__BBS = 549,
+    #[cfg(feature = "topic")]
+    Topic__Communications__Chat = 550,
+    #[cfg(feature = "topic")]
+    Topic__Communications__Chat__ICQ = 551,
+    #[cfg(feature = "topic")]
+    Topic__Communications__Chat__InternetRelayChat = 552,
+    #[cfg(feature = "topic")]
+    Topic__Communications__Chat__UnixTalk = 553,
+    #[cfg(feature = "topic")]
+    Topic__Communications__Conferencing = 554,
+    #[cfg(feature = "topic")]
+    Topic__Communications__Email = 555,
+    #[cfg(feature = "topic")]
+    Topic__Communications__Email__AddressBook = 556,
+    #[cfg(feature = "topic")]
+    Topic__Communications__Email__EmailClientsMUA = 557,
+    #[cfg(feature = "topic")]
+    Topic__Communications__Email__Filters = 558,
+    #[cfg(feature = "topic")]
+    Topic__Communications__Email__MailTransportAgents = 559,
+    #[cfg(feature = "topic")]
+    Topic__Communications__Email__MailingListServers = 560,
+    #[cfg(feature = "topic")]
+    Topic__Communications__Email__PostOffice = 561,
+    #[cfg(feature = "topic")]
+    Topic__Communications__Email__PostOffice__IMAP = 562,
+    #[cfg(feature = "topic")]
+    Topic__Communications__Email__PostOffice__POP3 = 563,
+    #[cfg(feature = "topic")]
+    Topic__Communications__FIDO = 564,
+    #[cfg(feature = "topic")]
+    Topic__Communications__Fax = 565,
+    #[cfg(feature = "topic")]
+    Topic__Communications__FileSharing = 566,
+    #[cfg(feature = "topic")]
+    Topic__Communications__FileSharing__Gnutella = 567,
+    #[cfg(feature = "topic")]
+    Topic__Communications__FileSharing__Napster = 568,
+    #[cfg(feature = "topic")]
+    Topic__Communications__HamRadio = 569,
+    #[cfg(feature = "topic")]
+    Topic__Communications__InternetPhone = 570,
+    #[cfg(feature = "topic")]
+    Topic__Communications__Telephony = 571,
+    #[cfg(feature = "topic")]
+    Topic__Communications__UsenetNews = 572,
+    #[cfg(feature = "topic")]
+    Topic__Database = 573,
+    #[cfg(feature = "topic")]
+    Topic__Database__DatabaseEnginesServers = 574,
+    #[cfg(feature = "topic")]
+    Topic__Database__FrontEnds = 575,
+    #[cfg(feature = "topic")]
+    Topic__DesktopEnvironment = 576,
+    #[cfg(feature = "topic")]
+    Topic__DesktopEnvironment__FileManagers = 577,
+    #[cfg(feature = "topic")]
+    Topic__DesktopEnvironment__GNUstep = 578,
+    #[cfg(feature = "topic")]
+    Topic__DesktopEnvironment__Gnome = 579,
+    #[cfg(feature = "topic")]
+    Topic__DesktopEnvironment__KDesktopEnvironmentKDE = 580,
+    #[cfg(feature = "topic")]
+    Topic__DesktopEnvironment__KDesktopEnvironmentKDE__Themes = 581,
+    #[cfg(feature = "topic")]
+    Topic__DesktopEnvironment__PicoGUI = 582,
+    #[cfg(feature = "topic")]
+    Topic__DesktopEnvironment__PicoGUI__Applications = 583,
+    #[cfg(feature = "topic")]
+    Topic__DesktopEnvironment__PicoGUI__Themes = 584,
+    #[cfg(feature = "topic")]
+    Topic__DesktopEnvironment__ScreenSavers = 585,
+    #[cfg(feature = "topic")]
+    Topic__DesktopEnvironment__WindowManagers = 586,
+    #[cfg(feature = "topic")]
+    Topic__DesktopEnvironment__WindowManagers__Afterstep = 587,
+    #[cfg(feature = "topic")]
+    Topic__DesktopEnvironment__WindowManagers__Afterstep__Themes = 588,
+    #[cfg(feature = "topic")]
+    Topic__DesktopEnvironment__WindowManagers__Applets = 589,
+    #[cfg(feature = "topic")]
+    Topic__DesktopEnvironment__WindowManagers__Blackbox = 590,
+    #[cfg(feature = "topic")]
+    Topic__DesktopEnvironment__WindowManagers__Blackbox__Themes = 591,
+    #[cfg(feature = "topic")]
+    Topic__DesktopEnvironment__WindowManagers__CTWM = 592,
+    #[cfg(feature = "topic")]
+    Topic__DesktopEnvironment__WindowManagers__CTWM__Themes = 593,
+    #[cfg(feature = "topic")]
+    Topic__DesktopEnvironment__WindowManagers__Enlightenment = 594,
+    #[cfg(feature = "topic")]
+    Topic__DesktopEnvironment__WindowManagers__Enlightenment__Epplets = 595,
+    #[cfg(feature = "topic")]
+    Topic__DesktopEnvironment__WindowManagers__Enlightenment__ThemesDR15 = 596,
+    #[cfg(feature = "topic")]
+    Topic__DesktopEnvironment__WindowManagers__Enlightenment__ThemesDR16 = 597,
+    #[cfg(feature = "topic")]
+    Topic__DesktopEnvironment__WindowManagers__Enlightenment__ThemesDR17 = 598,
+    #[cfg(feature = "topic")]
+    Topic__DesktopEnvironment__WindowManagers__FVWM = 599,
+    #[cfg(feature = "topic")]
+    Topic__DesktopEnvironment__WindowManagers__FVWM__Themes = 600,
+    #[cfg(feature = "topic")]
+    Topic__DesktopEnvironment__WindowManagers__Fluxbox = 601,
+    #[cfg(feature = "topic")]
+    Topic__DesktopEnvironment__WindowManagers__Fluxbox__Themes = 602,
+    #[cfg(feature = "topic")]
+    Topic__DesktopEnvironment__WindowManagers__IceWM = 603,
+    #[cfg(feature = "topic")]
+    Topic__DesktopEnvironment__WindowManagers__IceWM__Themes = 604,
+    #[cfg(feature = "topic")]
+    Topic__DesktopEnvironment__WindowManagers__MetaCity = 605,
+    #[cfg(feature = "topic")]
+    Topic__DesktopEnvironment__WindowManagers__MetaCity__Themes = 606,
+    #[cfg(feature = "topic")]
+    Topic__DesktopEnvironment__WindowManagers__Oroborus = 607,
+    #[cfg(feature = "topic")]
+    Topic__DesktopEnvironment__WindowManagers__Oroborus__Themes = 608,
+    #[cfg(feature = "topic")]
+    Topic__DesktopEnvironment__WindowManagers__Sawfish = 609,
+    #[cfg(feature = "topic")]
+    Topic__DesktopEnvironment__WindowManagers__Sawfish__Themes0_30 = 610,
+    #[cfg(feature = "topic")]
+    Topic__DesktopEnvironment__WindowManagers__Sawfish__Themespre0_30 = 611,
+    #[cfg(feature = "topic")]
+    Topic__DesktopEnvironment__WindowManagers__Waimea = 612,
+    #[cfg(feature = "topic")]
+    Topic__DesktopEnvironment__WindowManagers__Waimea__Themes = 613,
+    #[cfg(feature = "topic")]
+    Topic__DesktopEnvironment__WindowManagers__WindowMaker = 614,
+    #[cfg(feature = "topic")]
+    Topic__DesktopEnvironment__WindowManagers__WindowMaker__Applets = 615,
+    #[cfg(feature = "topic")]
+    Topic__DesktopEnvironment__WindowManagers__WindowMaker__Themes = 616,
+    #[cfg(feature = "topic")]
+    Topic__DesktopEnvironment__WindowManagers__XFCE = 617,
+    #[cfg(feature = "topic")]
+    Topic__DesktopEnvironment__WindowManagers__XFCE__Themes = 618,
+    #[cfg(feature = "topic")]
+    Topic__Documentation = 619,
+    #[cfg(feature = "topic")]
+    Topic__Documentation__Sphinx = 620,
+    #[cfg(feature = "topic")]
+    Topic__Education = 621,
+    #[cfg(feature = "topic")]
+    Topic__Education__ComputerAidedInstructionCAI = 622,
+    #[cfg(feature = "topic")]
+    Topic__Education__Testing = 623,
+    #[cfg(feature = "topic")]
+    Topic__FileFormats = 624,
+    #[cfg(feature = "topic")]
+    Topic__FileFormats__JSON = 625,
+    #[cfg(feature = "topic")]
+    Topic__FileFormats__JSON__JSONSchema = 626,
+    #[cfg(feature = "topic")]
+    Topic__GamesEntertainment = 627,
+    #[cfg(feature = "topic")]
+    Topic__GamesEntertainment__Arcade = 628,
+    #[cfg(feature = "topic")]
+    Topic__GamesEntertainment__BoardGames = 629,
+    #[cfg(feature = "topic")]
+    Topic__GamesEntertainment__FirstPersonShooters = 630,
+    #[cfg(feature = "topic")]
+    Topic__GamesEntertainment__FortuneCookies = 631,
+    #[cfg(feature = "topic")]
+    Topic__GamesEntertainment__MultiUserDungeonsMUD = 632,
+    #[cfg(feature = "topic")]
+    Topic__GamesEntertainment__PuzzleGames = 633,
+    #[cfg(feature = "topic")]
+    Topic__GamesEntertainment__RealTimeStrategy = 634,
+    #[cfg(feature = "topic")]
+    Topic__GamesEntertainment__RolePlaying = 635,
+    #[cfg(feature = "topic")]
+    Topic__GamesEntertainment__SideScrollingArcadeGames = 636,
+    #[cfg(feature = "topic")]
+    Topic__GamesEntertainment__Simulation = 637,
+    #[cfg(feature = "topic")]
+    Topic__GamesEntertainment__TurnBasedStrategy = 638,
+    #[cfg(feature = "topic")]
+    Topic__HomeAutomation = 639,
+    #[cfg(feature = "topic")]
+    Topic__Internet = 640,
+    #[cfg(feature = "topic")]
+    Topic__Internet__FileTransferProtocolFTP = 641,
+    #[cfg(feature = "topic")]
+    Topic__Internet__Finger = 642,
+    #[cfg(feature = "topic")]
+    Topic__Internet__LogAnalysis = 643,
+    #[cfg(feature = "topic")]
+    Topic__Internet__NameServiceDNS = 644,
+    #[cfg(feature = "topic")]
+    Topic__Internet__ProxyServers = 645,
+    #[cfg(feature = "topic")]
+    Topic__Internet__WAP = 646,
+    #[cfg(feature = "topic")]
+    Topic__Internet__WWWHTTP = 647,
+    #[cfg(feature = "topic")]
+    Topic__Internet__WWWHTTP__Browsers = 648,
+    #[cfg(feature = "topic")]
+    Topic__Internet__WWWHTTP__DynamicContent = 649,
+    #[cfg(feature = "topic")]
+    Topic__Internet__WWWHTTP__DynamicContent__CGIToolsLibraries = 650,
+    #[cfg(feature = "topic")]
+    Topic__Internet__WWWHTTP__DynamicContent__ContentManagementSystem = 651,
+    #[cfg(feature = "topic")]
+    Topic__Internet__WWWHTTP__DynamicContent__MessageBoards = 652,
+    #[cfg(feature = "topic")]
+    Topic__Internet__WWWHTTP__DynamicContent__NewsDiary = 653,
+    #[cfg(feature = "topic")]
+    Topic__Internet__WWWHTTP__DynamicContent__PageCounters = 654,
+    #[cfg(feature = "topic")]
+    Topic__Internet__WWWHTTP__DynamicContent__Wiki = 655,
+    #[cfg(feature = "topic")]
+    Topic__Internet__WWWHTTP__HTTPServers = 656,
+    #[cfg(feature = "topic")]
+    Topic__Internet__WWWHTTP__IndexingSearch = 657,
+    #[cfg(feature = "topic")]
+    Topic__Internet__WWWHTTP__Session = 658,
+    #[cfg(feature = "topic")]
+    Topic__Internet__WWWHTTP__SiteManagement = 659,
+    #[cfg(feature = "topic")]
+    Topic__Internet__WWWHTTP__SiteManagement__LinkChecking = 660,
+    #[cfg(feature = "topic")]
+    Topic__Internet__WWWHTTP__WSGI = 661,
+    #[cfg(feature = "topic")]
+    Topic__Internet__WWWHTTP__WSGI__Application = 662,
+    #[cfg(feature = "topic")]
+    Topic__Internet__WWWHTTP__WSGI__Middleware = 663,
+    #[cfg(feature = "topic")]
+    Topic__Internet__WWWHTTP__WSGI__Server = 664,
+    #[cfg(feature = "topic")]
+    Topic__Internet__XMPP = 665,
+    #[cfg(feature = "topic")]
+    Topic__Internet__Z39_50 = 666,
+    #[cfg(feature = "topic")]
+    Topic__Multimedia = 667,
+    #[cfg(feature = "topic")]
+    Topic__Multimedia__Graphics = 668,
+    #[cfg(feature = "topic")]
+    Topic__Multimedia__Graphics__3DModeling = 669,
+    #[cfg(feature = "topic")]
+    Topic__Multimedia__Graphics__3DRendering = 670,
+    #[cfg(feature = "topic")]
+    Topic__Multimedia__Graphics__Capture = 671,
+    #[cfg(feature = "topic")]
+    Topic__Multimedia__Graphics__Capture__DigitalCamera = 672,
+    #[cfg(feature = "topic")]
+    Topic__Multimedia__Graphics__Capture__Scanners = 673,
+    #[cfg(feature = "topic")]
+    Topic__Multimedia__Graphics__Capture__ScreenCapture = 674,
+    #[cfg(feature = "topic")]
+    Topic__Multimedia__Graphics__Editors = 675,
+    #[cfg(feature = "topic")]
+    Topic__Multimedia__Graphics__Editors__RasterBased = 676,
+    #[cfg(feature = "topic")]
+    Topic__Multimedia__Graphics__Editors__VectorBased = 677,
+    #[cfg(feature = "topic")]
+    Topic__Multimedia__Graphics__GraphicsConversion = 678,
+    #[cfg(feature = "topic")]
+    Topic__Multimedia__Graphics__Presentation = 679,
+    #[cfg(feature = "topic")]
+    Topic__Multimedia__Graphics__Viewers = 680,
+    #[cfg(feature = "topic")]
+    Topic__Multimedia__SoundAudio = 681,
+    #[cfg(feature = "topic")]
+    Topic__Multimedia__SoundAudio__Analysis = 682,
+    #[cfg(feature = "topic")]
+    Topic__Multimedia__SoundAudio__CDAudio = 683,
+    #[cfg(feature = "topic")]
+    Topic__Multimedia__SoundAudio__CDAudio__CDPlaying = 684,
+    #[cfg(feature = "topic")]
+    Topic__Multimedia__SoundAudio__CDAudio__CDRipping = 685,
+    #[cfg(feature = "topic")]
+    Topic__Multimedia__SoundAudio__CDAudio__CDWriting = 686,
+    #[cfg(feature = "topic")]
+    Topic__Multimedia__SoundAudio__CaptureRecording = 687,
+    #[cfg(feature = "topic")]
+    Topic__Multimedia__SoundAudio__Conversion = 688,
+    #[cfg(feature = "topic")]
+    Topic__Multimedia__SoundAudio__Editors = 689,
+    #[cfg(feature = "topic")]
+    Topic__Multimedia__SoundAudio__MIDI = 690,
+    #[cfg(feature = "topic")]
+    Topic__Multimedia__SoundAudio__Mixers = 691,
+    #[cfg(feature = "topic")]
+    Topic__Multimedia__SoundAudio__Players = 692,
+    #[cfg(feature = "topic")]
+    Topic__Multimedia__SoundAudio__Players__MP3 = 693,
+    #[cfg(feature = "topic")]
+    Topic__Multimedia__SoundAudio__SoundSynthesis = 694,
+    #[cfg(feature = "topic")]
+    Topic__Multimedia__SoundAudio__Speech = 695,
+    #[cfg(feature = "topic")]
+    Topic__Multimedia__Video = 696,
+    #[cfg(feature = "topic")]
+    Topic__Multimedia__Video__Capture = 697,
+    #[cfg(feature = "topic")]
+    Topic__Multimedia__Video__Conversion = 698,
+    #[cfg(feature = "topic")]
+    Topic__Multimedia__Video__Display = 699,
+    #[cfg(feature = "topic")]
+    Topic__Multimedia__Video__NonLinearEditor = 700,
+    #[cfg(feature = "topic")]
+    Topic__OfficeBusiness = 701,
+    #[cfg(feature = "topic")]
+    Topic__OfficeBusiness__Financial = 702,
+    #[cfg(feature = "topic")]
+    Topic__OfficeBusiness__Financial__Accounting = 703,
+    #[cfg(feature = "topic")]
+    Topic__OfficeBusiness__Financial__Investment = 704,
+    #[cfg(feature = "topic")]
+    Topic__OfficeBusiness__Financial__PointOfSale = 705,
+    #[cfg(feature = "topic")]
+    Topic__OfficeBusiness__Financial__Spreadsheet = 706,
+    #[cfg(feature = "topic")]
+    Topic__OfficeBusiness__Groupware = 707,
+    #[cfg(feature = "topic")]
+    Topic__OfficeBusiness__NewsDiary = 708,
+    #[cfg(feature = "topic")]
+    Topic__OfficeBusiness__OfficeSuites = 709,
+    #[cfg(feature = "topic")]
+    Topic__OfficeBusiness__Scheduling = 710,
+    #[cfg(feature = "topic")]
+    Topic__OtherNonlistedTopic = 711,
+    #[cfg(feature = "topic")]
+    Topic__Printing = 712,
+    #[cfg(feature = "topic")]
+    Topic__Religion = 713,
+    #[cfg(feature = "topic")]
+    Topic__ScientificEngineering = 714,
+    #[cfg(feature = "topic")]
+    Topic__ScientificEngineering__ArtificialIntelligence = 715,
+    #[cfg(feature = "topic")]
+    Topic__ScientificEngineering__ArtificialLife = 716,
+    #[cfg(feature = "topic")]
+    Topic__ScientificEngineering__Astronomy = 717,
+    #[cfg(feature = "topic")]
+    Topic__ScientificEngineering__AtmosphericScience = 718,
+    #[cfg(feature = "topic")]
+    Topic__ScientificEngineering__BioInformatics = 719,
+    #[cfg(feature = "topic")]
+    Topic__ScientificEngineering__Chemistry = 720,
+    #[cfg(feature = "topic")]
+    Topic__ScientificEngineering__ElectronicDesignAutomationEDA = 721,
+    #[cfg(feature = "topic")]
+    Topic__ScientificEngineering__GIS = 722,
+    #[cfg(feature = "topic")]
+    Topic__ScientificEngineering__HumanMachineInterfaces = 723,
+    #[cfg(feature = "topic")]
+    Topic__ScientificEngineering__Hydrology = 724,
+    #[cfg(feature = "topic")]
+    Topic__ScientificEngineering__ImageProcessing = 725,
+    #[cfg(feature = "topic")]
+    Topic__ScientificEngineering__ImageRecognition = 726,
+    #[cfg(feature = "topic")]
+    Topic__ScientificEngineering__InformationAnalysis = 727,
+    #[cfg(feature = "topic")]
+    Topic__ScientificEngineering__InterfaceEngineProtocolTranslator = 728,
+    #[cfg(feature = "topic")]
+    Topic__ScientificEngineering__Mathematics = 729,
+    #[cfg(feature = "topic")]
+    Topic__ScientificEngineering__MedicalScienceApps_ = 730,
+    #[cfg(feature = "topic")]
+    Topic__ScientificEngineering__Oceanography = 731,
+    #[cfg(feature = "topic")]
+    Topic__ScientificEngineering__Physics = 732,
+    #[cfg(feature = "topic")]
+    Topic__ScientificEngineering__Visualization = 733,
+    #[cfg(feature = "topic")]
+    Topic__Security = 734,
+    #[cfg(feature = "topic")]
+    Topic__Security__Cryptography = 735,
+    #[cfg(feature = "topic")]
+    Topic__Sociology = 736,
+    #[cfg(feature = "topic")]
+    Topic__Sociology__Genealogy = 737,
+    #[cfg(feature = "topic")]
+    Topic__Sociology__History = 738,
+    #[cfg(feature = "topic")]
+    Topic__SoftwareDevelopment = 739,
+    #[cfg(feature = "topic")]
+    Topic__SoftwareDevelopment__Assemblers = 740,
+    #[cfg(feature = "topic")]
+    Topic__SoftwareDevelopment__BugTracking = 741,
+    #[cfg(feature = "topic")]
+    Topic__SoftwareDevelopment__BuildTools = 742,
+    #[cfg(feature = "topic")]
+    Topic__SoftwareDevelopment__CodeGenerators = 743,
+    #[cfg(feature = "topic")]
+    Topic__SoftwareDevelopment__Compilers = 744,
+    #[cfg(feature = "topic")]
+    Topic__SoftwareDevelopment__Debuggers = 745,
+    #[cfg(feature = "topic")]
+    Topic__SoftwareDevelopment__Disassemblers = 746,
+    #[cfg(feature = "topic")]
+    Topic__SoftwareDevelopment__Documentation = 747,
+    #[cfg(feature = "topic")]
+    Topic__SoftwareDevelopment__EmbeddedSystems = 748,
+    #[cfg(feature = "topic")]
+    Topic__SoftwareDevelopment__EmbeddedSystems__ControllerAreaNetworkCAN = 749,
+    #[cfg(feature = "topic")]
+    Topic__SoftwareDevelopment__EmbeddedSystems__ControllerAreaNetworkCAN__CANopen = 750,
+    #[cfg(feature = "topic")]
+    Topic__SoftwareDevelopment__EmbeddedSystems__ControllerAreaNetworkCAN__J1939 = 751,
+    #[cfg(feature = "topic")]
+    Topic__SoftwareDevelopment__Internationalization = 752,
+    #[cfg(feature = "topic")]
+    Topic__SoftwareDevelopment__Interpreters = 753,
+    #[cfg(feature = "topic")]
+    Topic__SoftwareDevelopment__Libraries = 754,
+    #[cfg(feature = "topic")]
+    Topic__SoftwareDevelopment__Libraries__ApplicationFrameworks = 755,
+    #[cfg(feature = "topic")]
+    Topic__SoftwareDevelopment__Libraries__JavaLibraries = 756,
+    #[cfg(feature = "topic")]
+    Topic__SoftwareDevelopment__Libraries__PHPClasses = 757,
+    #[cfg(feature = "topic")]
+    Topic__SoftwareDevelopment__Libraries__PerlModules = 758,
+    #[cfg(feature = "topic")]
+    Topic__SoftwareDevelopment__Libraries__PikeModules = 759,
+    #[cfg(feature = "topic")]
+    Topic__SoftwareDevelopment__Libraries__PythonModules = 760,
+    #[cfg(feature = "topic")]
+    Topic__SoftwareDevelopment__Libraries__RubyModules = 761,
+    #[cfg(feature = "topic")]
+    Topic__SoftwareDevelopment__Libraries__TclExtensions = 762,
+    #[cfg(feature = "topic")]
+    Topic__SoftwareDevelopment__Libraries__pygame = 763,
+    #[cfg(feature = "topic")]
+    Topic__SoftwareDevelopment__Localization = 764,
+    #[cfg(feature = "topic")]
+    Topic__SoftwareDevelopment__ObjectBrokering = 765,
+    #[cfg(feature = "topic")]
+    Topic__SoftwareDevelopment__ObjectBrokering__CORBA = 766,
+    #[cfg(feature = "topic")]
+    Topic__SoftwareDevelopment__Preprocessors = 767,
+    #[cfg(feature = "topic")]
+    Topic__SoftwareDevelopment__QualityAssurance = 768,
+    #[cfg(feature = "topic")]
+    Topic__SoftwareDevelopment__Testing = 769,
+    #[cfg(feature = "topic")]
+    Topic__SoftwareDevelopment__Testing__Acceptance = 770,
+    #[cfg(feature = "topic")]
+    Topic__SoftwareDevelopment__Testing__BDD = 771,
+    #[cfg(feature = "topic")]
+    Topic__SoftwareDevelopment__Testing__Mocking = 772,
+    #[cfg(feature = "topic")]
+    Topic__SoftwareDevelopment__Testing__TrafficGeneration = 773,
+    #[cfg(feature = "topic")]
+    Topic__SoftwareDevelopment__Testing__Unit = 774,
+    #[cfg(feature = "topic")]
+    Topic__SoftwareDevelopment__UserInterfaces = 775,
+    #[cfg(feature = "topic")]
+    Topic__SoftwareDevelopment__VersionControl = 776,
+    #[cfg(feature = "topic")]
+    Topic__SoftwareDevelopment__VersionControl__Bazaar = 777,
+    #[cfg(feature = "topic")]
+    Topic__SoftwareDevelopment__VersionControl__CVS = 778,
+    #[cfg(feature = "topic")]
+    Topic__SoftwareDevelopment__VersionControl__Git = 779,
+    #[cfg(feature = "topic")]
+    Topic__SoftwareDevelopment__VersionControl__Mercurial = 780,
+    #[cfg(feature = "topic")]
+    Topic__SoftwareDevelopment__VersionControl__RCS = 781,
+    #[cfg(feature = "topic")]
+    Topic__SoftwareDevelopment__VersionControl__SCCS = 782,
+    #[cfg(feature = "topic")]
+    Topic__SoftwareDevelopment__WidgetSets = 783,
+    #[cfg(feature = "topic")]
+    Topic__System = 784,
+    #[cfg(feature = "topic")]
+    Topic__System__Archiving = 785,
+    #[cfg(feature = "topic")]
+    Topic__System__Archiving__Backup = 786,
+    #[cfg(feature = "topic")]
+    Topic__System__Archiving__Compression = 787,
+    #[cfg(feature = "topic")]
+    Topic__System__Archiving__Mirroring = 788,
+    #[cfg(feature = "topic")]
+    Topic__System__Archiving__Packaging = 789,
+    #[cfg(feature = "topic")]
+    Topic__System__Benchmark = 790,
+    #[cfg(feature = "topic")]
+    Topic__System__Boot = 791,
+    #[cfg(feature = "topic")]
+    Topic__System__Boot__Init = 792,
+    #[cfg(feature = "topic")]
+    Topic__System__Clustering = 793,
+    #[cfg(feature = "topic")]
+    Topic__System__ConsoleFonts = 794,
+    #[cfg(feature = "topic")]
+    Topic__System__DistributedComputing = 795,
+    #[cfg(feature = "topic")]
+    Topic__System__Emulators = 796,
+    #[cfg(feature = "topic")]
+    Topic__System__Filesystems = 797,
+    #[cfg(feature = "topic")]
+    Topic__System__Hardware = 798,
+    #[cfg(feature = "topic")]
+    Topic__System__Hardware__HardwareDrivers = 799,
+    #[cfg(feature = "topic")]
+    Topic__System__Hardware__Mainframes = 800,
+    #[cfg(feature = "topic")]
+    Topic__System__Hardware__SymmetricMultiprocessing = 801,
+    #[cfg(feature = "topic")]
+    Topic__System__Hardware__UniversalSerialBusUSB = 802,
+    #[cfg(feature = "topic")]
+    Topic__System__Hardware__UniversalSerialBusUSB__Audio = 803,
+    #[cfg(feature = "topic")]
+    Topic__System__Hardware__UniversalSerialBusUSB__AudioVideoAV = 804,
+    #[cfg(feature = "topic")]
+    Topic__System__Hardware__UniversalSerialBusUSB__CommunicationsDeviceClassCDC = 805,
+    #[cfg(feature = "topic")]
+    Topic__System__Hardware__UniversalSerialBusUSB__DiagnosticDevice = 806,
+    #[cfg(feature = "topic")]
+    Topic__System__Hardware__UniversalSerialBusUSB__Hub = 807,
+    #[cfg(feature = "topic")]
+    Topic__System__Hardware__UniversalSerialBusUSB__HumanInterfaceDeviceHID = 808,
+    #[cfg(feature = "topic")]
+    Topic__System__Hardware__UniversalSerialBusUSB__MassStorage = 809,
+    #[cfg(feature = "topic")]
+    Topic__System__Hardware__UniversalSerialBusUSB__Miscellaneous = 810,
+    #[cfg(feature = "topic")]
+    Topic__System__Hardware__UniversalSerialBusUSB__Printer = 811,
+    #[cfg(feature = "topic")]
+    Topic__System__Hardware__UniversalSerialBusUSB__SmartCard = 812,
+    #[cfg(feature = "topic")]
+    Topic__System__Hardware__UniversalSerialBusUSB__Vendor = 813,
+    #[cfg(feature = "topic")]
+    Topic__System__Hardware__UniversalSerialBusUSB__VideoUVC = 814,
+    #[cfg(feature = "topic")]
+    Topic__System__Hardware__UniversalSerialBusUSB__WirelessController = 815,
+    #[cfg(feature = "topic")]
+    Topic__System__InstallationSetup = 816,
+    #[cfg(feature = "topic")]
+    Topic__System__Logging = 817,
+    #[cfg(feature = "topic")]
+    Topic__System__Monitoring = 818,
+    #[cfg(feature = "topic")]
+    Topic__System__Networking = 819,
+    #[cfg(feature = "topic")]
+    Topic__System__Networking__Firewalls = 820,
+    #[cfg(feature = "topic")]
+    Topic__System__Networking__Monitoring = 821,
+    #[cfg(feature = "topic")]
+    Topic__System__Networking__Monitoring__HardwareWatchdog = 822,
+    #[cfg(feature = "topic")]
+    Topic__System__Networking__TimeSynchronization = 823,
+    #[cfg(feature = "topic")]
+    Topic__System__OperatingSystem = 824,
+    #[cfg(feature = "topic")]
+    Topic__System__OperatingSystemKernels = 825,
+    #[cfg(feature = "topic")]
+    Topic__System__OperatingSystemKernels__BSD = 826,
+    #[cfg(feature = "topic")]
+    Topic__System__OperatingSystemKernels__GNUHurd = 827,
+    #[cfg(feature = "topic")]
+    Topic__System__OperatingSystemKernels__Linux = 828,
+    #[cfg(feature = "topic")]
+    Topic__System__PowerUPS = 829,
+    #[cfg(feature = "topic")]
+    Topic__System__RecoveryTools = 830,
+    #[cfg(feature = "topic")]
+    Topic__System__Shells = 831,
+    #[cfg(feature = "topic")]
+    Topic__System__SoftwareDistribution = 832,
+    #[cfg(feature = "topic")]
+    Topic__System__SystemShells = 833,
+    #[cfg(feature = "topic")]
+    Topic__System__SystemsAdministration = 834,
+    #[cfg(feature = "topic")]
+    Topic__System__SystemsAdministration__AuthenticationDirectory = 835,
+    #[cfg(feature = "topic")]
+    Topic__System__SystemsAdministration__AuthenticationDirectory__LDAP = 836,
+    #[cfg(feature = "topic")]
+    Topic__System__SystemsAdministration__AuthenticationDirectory__NIS = 837,
+    #[cfg(feature = "topic")]
+    Topic__Terminals = 838,
+    #[cfg(feature = "topic")]
+    Topic__Terminals__Serial = 839,
+    #[cfg(feature = "topic")]
+    Topic__Terminals__Telnet = 840,
+    #[cfg(feature = "topic")]
+    Topic__Terminals__TerminalEmulatorsXTerminals = 841,
+    #[cfg(feature = "topic")]
+    Topic__TextEditors = 842,
+    #[cfg(feature = "topic")]
+    Topic__TextEditors__Documentation = 843,
+    #[cfg(feature = "topic")]
+    Topic__TextEditors__Emacs = 844,
+    #[cfg(feature = "topic")]
+    Topic__TextEditors__IntegratedDevelopmentEnvironmentsIDE = 845,
+    #[cfg(feature = "topic")]
+    Topic__TextEditors__TextProcessing = 846,
+    #[cfg(feature = "topic")]
+    Topic__TextEditors__WordProcessors = 847,
+    #[cfg(feature = "topic")]
+    Topic__TextProcessing = 848,
+    #[cfg(feature = "topic")]
+    Topic__TextProcessing__Filters = 849,
+    #[cfg(feature = "topic")]
+    Topic__TextProcessing__Fonts = 850,
+    #[cfg(feature = "topic")]
+    Topic__TextProcessing__General = 851,
+    #[cfg(feature = "topic")]
+    Topic__TextProcessing__Indexing = 852,
+    #[cfg(feature = "topic")]
+    Topic__TextProcessing__Linguistic = 853,
+    #[cfg(feature = "topic")]
+    Topic__TextProcessing__Markup = 854,
+    #[cfg(feature = "topic")]
+    Topic__TextProcessing__Markup__HTML = 855,
+    #[cfg(feature = "topic")]
+    Topic__TextProcessing__Markup__LaTeX = 856,
+    #[cfg(feature = "topic")]
+    Topic__TextProcessing__Markup__Markdown = 857,
+    #[cfg(feature = "topic")]
+    Topic__TextProcessing__Markup__SGML = 858,
+    #[cfg(feature = "topic")]
+    Topic__TextProcessing__Markup__VRML = 859,
+    #[cfg(feature = "topic")]
+    Topic__TextProcessing__Markup__XML = 860,
+    #[cfg(feature = "topic")]
+    Topic__TextProcessing__Markup__reStructuredText = 861,
+    #[cfg(feature = "topic")]
+    Topic__Utilities = 862,
+    #[cfg(feature = "typing")]
+    Typing__StubsOnly = 863,
+    #[cfg(feature = "typing")]
+    Typing__Typed = 864,
+}
+
+impl Classifier {
+    /// Every classifier, in the canonical order used by pypi.org
+    pub const ALL: &'static [Classifier] = &[
+        #[cfg(feature = "development-status")]
+        Classifier::DevelopmentStatus__1Planning,
+        #[cfg(feature = "development-status")]
+        Classifier::DevelopmentStatus__2PreAlpha,
+        #[cfg(feature = "development-status")]
+        Classifier::DevelopmentStatus__3Alpha,
+        #[cfg(feature = "development-status")]
+        Classifier::DevelopmentStatus__4Beta,
+        #[cfg(feature = "development-status")]
+        Classifier::DevelopmentStatus__5ProductionStable,
+        #[cfg(feature = "development-status")]
+        Classifier::DevelopmentStatus__6Mature,
+        #[cfg(feature = "development-status")]
+        Classifier::DevelopmentStatus__7Inactive,
+        #[cfg(feature = "environment")]
+        Classifier::Environment__Console,
+        #[cfg(feature = "environment")]
+        Classifier::Environment__Console__Curses,
+        #[cfg(feature = "environment")]
+        Classifier::Environment__Console__Framebuffer,
+        #[cfg(feature = "environment")]
+        Classifier::Environment__Console__Newt,
+        #[cfg(feature = "environment")]
+        Classifier::Environment__Console__svgalib,
+        #[cfg(feature = "environment")]
+        Classifier::Environment__GPU,
+        #[cfg(feature = "environment")]
+        Classifier::Environment__GPU__NVIDIACUDA,
+        #[cfg(feature = "environment")]
+        Classifier::Environment__GPU__NVIDIACUDA__1_0,
+        #[cfg(feature = "environment")]
+        Classifier::Environment__GPU__NVIDIACUDA__1_1,
+        #[cfg(feature = "environment")]
+        Classifier::Environment__GPU__NVIDIACUDA__2_0,
+        #[cfg(feature = "environment")]
+        Classifier::Environment__GPU__NVIDIACUDA__2_1,
+        #[cfg(feature = "environment")]
+        Classifier::Environment__GPU__NVIDIACUDA__2_2,
+        #[cfg(feature = "environment")]
+        Classifier::Environment__GPU__NVIDIACUDA__2_3,
+        #[cfg(feature = "environment")]
+        Classifier::Environment__GPU__NVIDIACUDA__3_0,
+        #[cfg(feature = "environment")]
+        Classifier::Environment__GPU__NVIDIACUDA__3_1,
+        #[cfg(feature = "environment")]
+        Classifier::Environment__GPU__NVIDIACUDA__3_2,
+        #[cfg(feature = "environment")]
+        Classifier::Environment__GPU__NVIDIACUDA__4_0,
+        #[cfg(feature = "environment")]
+        Classifier::Environment__GPU__NVIDIACUDA__4_1,
+        #[cfg(feature = "environment")]
+        Classifier::Environment__GPU__NVIDIACUDA__4_2,
+        #[cfg(feature = "environment")]
+        Classifier::Environment__GPU__NVIDIACUDA__5_0,
+        #[cfg(feature = "environment")]
+        Classifier::Environment__GPU__NVIDIACUDA__5_5,
+        #[cfg(feature = "environment")]
+        Classifier::Environment__GPU__NVIDIACUDA__6_0,
+        #[cfg(feature = "environment")]
+        Classifier::Environment__GPU__NVIDIACUDA__6_5,
+        #[cfg(feature = "environment")]
+        Classifier::Environment__GPU__NVIDIACUDA__7_0,
+        #[cfg(feature = "environment")]
+        Classifier::Environment__GPU__NVIDIACUDA__7_5,
+        #[cfg(feature = "environment")]
+        Classifier::Environment__GPU__NVIDIACUDA__8_0,
+        #[cfg(feature = "environment")]
+        Classifier::Environment__GPU__NVIDIACUDA__9_0,
+        #[cfg(feature = "environment")]
+        Classifier::Environment__GPU__NVIDIACUDA__9_1,
+        #[cfg(feature = "environment")]
+        Classifier::Environment__GPU__NVIDIACUDA__9_2,
+        #[cfg(feature = "environment")]
+        Classifier::Environment__GPU__NVIDIACUDA__10_0,
+        #[cfg(feature = "environment")]
+        Classifier::Environment__GPU__NVIDIACUDA__10_1,
+        #[cfg(feature = "environment")]
+        Classifier::Environment__GPU__NVIDIACUDA__10_2,
+        #[cfg(feature = "environment")]
+        Classifier::Environment__GPU__NVIDIACUDA__11,
+        #[cfg(feature = "environment")]
+        Classifier::Environment__GPU__NVIDIACUDA__11_0,
+        #[cfg(feature = "environment")]
+        Classifier::Environment__GPU__NVIDIACUDA__11_1,
+        #[cfg(feature = "environment")]
+        Classifier::Environment__GPU__NVIDIACUDA__11_2,
+        #[cfg(feature = "environment")]
+        Classifier::Environment__GPU__NVIDIACUDA__11_3,
+        #[cfg(feature = "environment")]
+        Classifier::Environment__GPU__NVIDIACUDA__11_4,
+        #[cfg(feature = "environment")]
+        Classifier::Environment__GPU__NVIDIACUDA__11_5,
+        #[cfg(feature = "environment")]
+        Classifier::Environment__GPU__NVIDIACUDA__11_6,
+        #[cfg(feature = "environment")]
+        Classifier::Environment__GPU__NVIDIACUDA__11_7,
+        #[cfg(feature = "environment")]
+        Classifier::Environment__GPU__NVIDIACUDA__11_8,
+        #[cfg(feature = "environment")]
+        Classifier::Environment__GPU__NVIDIACUDA__12,
+        #[cfg(feature = "environment")]
+        Classifier::Environment__GPU__NVIDIACUDA__12__12_0,
+        #[cfg(feature = "environment")]
+        Classifier::Environment__GPU__NVIDIACUDA__12__12_1,
+        #[cfg(feature = "environment")]
+        Classifier::Environment__GPU__NVIDIACUDA__12__12_2,
+        #[cfg(feature = "environment")]
+        Classifier::Environment__GPU__NVIDIACUDA__12__12_3,
+        #[cfg(feature = "environment")]
+        Classifier::Environment__GPU__NVIDIACUDA__12__12_4,
+        #[cfg(feature = "environment")]
+        Classifier::Environment__GPU__NVIDIACUDA__12__12_5,
+        #[cfg(feature = "environment")]
+        Classifier::Environment__HandheldsPDAs,
+        #[cfg(feature = "environment")]
+        Classifier::Environment__MacOSX,
+        #[cfg(feature = "environment")]
+        Classifier::Environment__MacOSX__Aqua,
+        #[cfg(feature = "environment")]
+        Classifier::Environment__MacOSX__Carbon,
+        #[cfg(feature = "environment")]
+        Classifier::Environment__MacOSX__Cocoa,
+        #[cfg(feature = "environment")]
+        Classifier::Environment__NoInputOutputDaemon,
+        #[cfg(feature = "environment")]
+        Classifier::Environment__OpenStack,
+        #[cfg(feature = "environment")]
+        Classifier::Environment__OtherEnvironment,
+        #[cfg(feature = "environment")]
+        Classifier::Environment__Plugins,
+        #[cfg(feature = "environment")]
+        Classifier::Environment__WebEnvironment,
+        #[cfg(feature = "environment")]
+        Classifier::Environment__WebEnvironment__Buffet,
+        #[cfg(feature = "environment")]
+        Classifier::Environment__WebEnvironment__Mozilla,
+        #[cfg(feature = "environment")]
+        Classifier::Environment__WebEnvironment__ToscaWidgets,
+        #[cfg(feature = "environment")]
+        Classifier::Environment__WebAssembly,
+        #[cfg(feature = "environment")]
+        Classifier::Environment__WebAssembly__Emscripten,
+        #[cfg(feature = "environment")]
+        Classifier::Environment__WebAssembly__WASI,
+        #[cfg(feature = "environment")]
+        Classifier::Environment__Win32MSWindows,
+        #[cfg(feature = "environment")]
+        Classifier::Environment__X11Applications,
+        #[cfg(feature = "environment")]
+        Classifier::Environment__X11Applications__GTK,
+        #[cfg(feature = "environment")]
+        Classifier::Environment__X11Applications__Gnome,
+        #[cfg(feature = "environment")]
+        Classifier::Environment__X11Applications__KDE,
+        #[cfg(feature = "environment")]
+        Classifier::Environment__X11Applications__Qt,
+        #[cfg(feature = "framework")]
+        Classifier::Framework__AWSCDK,
+        #[cfg(feature = "framework")]
+        Classifier::Framework__AWSCDK__1,
+        #[cfg(feature = "framework")]
+        Classifier::Framework__AWSCDK__2,
+        #[cfg(feature = "framework")]
+        Classifier::Framework__AiiDA,
+        #[cfg(feature = "framework")]
+        Classifier::Framework__Ansible,
+        #[cfg(feature = "framework")]
+        Classifier::Framework__AnyIO,
+        #[cfg(feature = "framework")]
+        Classifier::Framework__ApacheAirflow,
+        #[cfg(feature = "framework")]
+        Classifier::Framework__ApacheAirflow__Provider,
+        #[cfg(feature = "framework")]
+        Classifier::Framework__AsyncIO,
+        #[cfg(feature = "framework")]
+        Classifier::Framework__BEAT,
+        #[cfg(feature = "framework")]
+        Classifier::Framework__BFG,
+        #[cfg(feature = "framework")]
+        Classifier::Framework__Bob,
+        #[cfg(feature = "framework")]
+        Classifier::Framework__Bottle,
+        #[cfg(feature = "framework")]
+        Classifier::Framework__Buildout,
+        #[cfg(feature = "framework")]
+        Classifier::Framework__Buildout__Extension,
+        #[cfg(feature = "framework")]
+        Classifier::Framework__Buildout__Recipe,
+        #[cfg(feature = "framework")]
+        Classifier::Framework__CastleCMS,
+        #[cfg(feature = "framework")]
+        Classifier::Framework__CastleCMS__Theme,
+        #[cfg(feature = "framework")]
+        Classifier::Framework__Celery,
+        #[cfg(feature = "framework")]
+        Classifier::Framework__Chandler,
+        #[cfg(feature = "framework")]
+        Classifier::Framework__CherryPy,
+        #[cfg(feature = "framework")]
+        Classifier::Framework__CubicWeb,
+        #[cfg(feature = "framework")]
+        Classifier::Framework__Dash,
+        #[cfg(feature = "framework")]
+        Classifier::Framework__Datasette,
+        #[cfg(feature = "framework")]
+        Classifier::Framework__Django,
+        #[cfg(feature = "framework")]
+        Classifier::Framework__Django__1,
+        #[cfg(feature = "framework")]
+        Classifier::Framework__Django__1_4,
+        #[cfg(feature = "framework")]
+        Classifier::Framework__Django__1_5,
+        #[cfg(feature = "framework")]
+        Classifier::Framework__Django__1_6,
+        #[cfg(feature = "framework")]
+        Classifier::Framework__Django__1_7,
+        #[cfg(feature = "framework")]
+        Classifier::Framework__Django__1_8,
+        #[cfg(feature = "framework")]
+        Classifier::Framework__Django__1_9,
+        #[cfg(feature = "framework")]
+        Classifier::Framework__Django__1_10,
+        #[cfg(feature = "framework")]
+        Classifier::Framework__Django__1_11,
+        #[cfg(feature = "framework")]
+        Classifier::Framework__Django__2,
+        #[cfg(feature = "framework")]
+        Classifier::Framework__Django__2_0,
+        #[cfg(feature = "framework")]
+        Classifier::Framework__Django__2_1,
+        #[cfg(feature = "framework")]
+        Classifier::Framework__Django__2_2,
+        #[cfg(feature = "framework")]
+        Classifier::Framework__Django__3,
+        #[cfg(feature = "framework")]
+        Classifier::Framework__Django__3_0,
+        #[cfg(feature = "framework")]
+        Classifier::Framework__Django__3_1,
+        #[cfg(feature = "framework")]
+        Classifier::Framework__Django__3_2,
+        #[cfg(feature = "framework")]
+        Classifier::Framework__Django__4,
+        #[cfg(feature = "framework")]
+        Classifier::Framework__Django__4_0,
+        #[cfg(feature = "framework")]
+        Classifier::Framework__Django__4_1,
+        #[cfg(feature = "framework")]
+        Classifier::Framework__Django__4_2,
+        #[cfg(feature = "framework")]
+        Classifier::Framework__Django__5,
+        #[cfg(feature = "framework")]
+        Classifier::Framework__Django__5_0,
+        #[cfg(feature = "framework")]
+        Classifier::Framework__Django__5_1,
+        #[cfg(feature = "framework")]
+        Classifier::Framework__Django__5_2,
+        #[cfg(feature = "framework")]
+        Classifier::Framework__DjangoCMS,
+        #[cfg(feature = "framework")]
+        Classifier::Framework__DjangoCMS__3_4,
+        #[cfg(feature = "framework")]
+        Classifier::Framework__DjangoCMS__3_5,
+        #[cfg(feature = "framework")]
+        Classifier::Framework__DjangoCMS__3_6,
+        #[cfg(feature = "framework")]
+        Classifier::Framework__DjangoCMS__3_7,
+        #[cfg(feature = "framework")]
+        Classifier::Framework__DjangoCMS__3_8,
+        #[cfg(feature = "framework")]
+        Classifier::Framework__DjangoCMS__3_9,
+        #[cfg(feature = "framework")]
+        Classifier::Framework__DjangoCMS__3_10,
+        #[cfg(feature = "framework")]
+        Classifier::Framework__DjangoCMS__3_11,
+        #[cfg(feature = "framework")]
+        Classifier::Framework__DjangoCMS__4_0,
+        #[cfg(feature = "framework")]
+        Classifier::Framework__DjangoCMS__4_1,
+        #[cfg(feature = "framework")]
+        Classifier::Framework__FastAPI,
+        #[cfg(feature = "framework")]
+        Classifier::Framework__Flake8,
+        #[cfg(feature = "framework")]
+        Classifier::Framework__Flask,
+        #[cfg(feature = "framework")]
+        Classifier::Framework__Hatch,
+        #[cfg(feature = "framework")]
+        Classifier::Framework__Hypothesis,
+        #[cfg(feature = "framework")]
+        Classifier::Framework__IDLE,
+        #[cfg(feature = "framework")]
+        Classifier::Framework__IPython,
+        #[cfg(feature = "framework")]
+        Classifier::Framework__Jupyter,
+        #[cfg(feature = "framework")]
+        Classifier::Framework__Jupyter__JupyterLab,
+        #[cfg(feature = "framework")]
+        Classifier::Framework__Jupyter__JupyterLab__1,
+        #[cfg(feature = "framework")]
+        Classifier::Framework__Jupyter__JupyterLab__2,
+        #[cfg(feature = "framework")]
+        Classifier::Framework__Jupyter__JupyterLab__3,
+        #[cfg(feature = "framework")]
+        Classifier::Framework__Jupyter__JupyterLab__4,
+        #[cfg(feature = "framework")]
+        Classifier::Framework__Jupyter__JupyterLab__Extensions,
+        #[cfg(feature = "framework")]
+        Classifier::Framework__Jupyter__JupyterLab__Extensions__MimeRenderers,
+        #[cfg(feature = "framework")]
+        Classifier::Framework__Jupyter__JupyterLab__Extensions__Prebuilt,
+        #[cfg(feature = "framework")]
+        Classifier::Framework__Jupyter__JupyterLab__Extensions__Themes,
+        #[cfg(feature = "framework")]
+        Classifier::Framework__Kedro,
+        #[cfg(feature = "framework")]
+        Classifier::Framework__Lektor,
+        #[cfg(feature = "framework")]
+        Classifier::Framework__Masonite,
+        #[cfg(feature = "framework")]
+        Classifier::Framework__Matplotlib,
+        #[cfg(feature = "framework")]
+        Classifier::Framework__MkDocs,
+        #[cfg(feature = "framework")]
+        Classifier::Framework__Nengo,
+        #[cfg(feature = "framework")]
+        Classifier::Framework__Odoo,
+        #[cfg(feature = "framework")]
+        Classifier::Framework__Odoo__8_0,
+        #[cfg(feature = "framework")]
+        Classifier::Framework__Odoo__9_0,
+        #[cfg(feature = "framework")]
+        Classifier::Framework__Odoo__10_0,
+        #[cfg(feature = "framework")]
+        Classifier::Framework__Odoo__11_0,
+        #[cfg(feature = "framework")]
+        Classifier::Framework__Odoo__12_0,
+        #[cfg(feature = "framework")]
+        Classifier::Framework__Odoo__13_0,
+        #[cfg(feature = "framework")]
+        Classifier::Framework__Odoo__14_0,
+        #[cfg(feature = "framework")]
+        Classifier::Framework__Odoo__15_0,
+        #[cfg(feature = "framework")]
+        Classifier::Framework__Odoo__16_0,
+        #[cfg(feature = "framework")]
+        Classifier::Framework__Odoo__17_0,
+        #[cfg(feature = "framework")]
+        Classifier::Framework__Odoo__18_0,
+        #[cfg(feature = "framework")]
+        Classifier::Framework__OpenTelemetry,
+        #[cfg(feature = "framework")]
+        Classifier::Framework__OpenTelemetry__Distros,
+        #[cfg(feature = "framework")]
+        Classifier::Framework__OpenTelemetry__Exporters,
+        #[cfg(feature = "framework")]
+        Classifier::Framework__OpenTelemetry__Instrumentations,
+        #[cfg(feature = "framework")]
+        Classifier::Framework__Opps,
+        #[cfg(feature = "framework")]
+        Classifier::Framework__Paste,
+        #[cfg(feature = "framework")]
+        Classifier::Framework__Pelican,
+        #[cfg(feature = "framework")]
+        Classifier::Framework__Pelican__Plugins,
+        #[cfg(feature = "framework")]
+        Classifier::Framework__Pelican__Themes,
+        #[cfg(feature = "framework")]
+        Classifier::Framework__Plone,
+        #[cfg(feature = "framework")]
+        Classifier::Framework__Plone__3_2,
+        #[cfg(feature = "framework")]
+        Classifier::Framework__Plone__3_3,
+        #[cfg(feature = "framework")]
+        Classifier::Framework__Plone__4_0,
+        #[cfg(feature = "framework")]
+        Classifier::Framework__Plone__4_1,
+        #[cfg(feature = "framework")]
+        Classifier::Framework__Plone__4_2,
+        #[cfg(feature = "framework")]
+        Classifier::Framework__Plone__4_3,
+        #[cfg(feature = "framework")]
+        Classifier::Framework__Plone__5_0,
+        #[cfg(feature = "framework")]
+        Classifier::Framework__Plone__5_1,
+        #[cfg(feature = "framework")]
+        Classifier::Framework__Plone__5_2,
+        #[cfg(feature = "framework")]
+        Classifier::Framework__Plone__5_3,
+        #[cfg(feature = "framework")]
+        Classifier::Framework__Plone__6_0,
+        #[cfg(feature = "framework")]
+        Classifier::Framework__Plone__6_1,
+        #[cfg(feature = "framework")]
+        Classifier::Framework__Plone__Addon,
+        #[cfg(feature = "framework")]
+        Classifier::Framework__Plone__Core,
+        #[cfg(feature = "framework")]
+        Classifier::Framework__Plone__Distribution,
+        #[cfg(feature = "framework")]
+        Classifier::Framework__Plone__Theme,
+        #[cfg(feature = "framework")]
+        Classifier::Framework__PySimpleGUI,
+        #[cfg(feature = "framework")]
+        Classifier::Framework__PySimpleGUI__4,
+        #[cfg(feature = "framework")]
+        Classifier::Framework__PySimpleGUI__5,
+        #[cfg(feature = "framework")]
+        Classifier::Framework__Pycsou,
+        #[cfg(feature = "framework")]
+        Classifier::Framework__Pydantic,
+        #[cfg(feature = "framework")]
+        Classifier::Framework__Pydantic__1,
+        #[cfg(feature = "framework")]
+        Classifier::Framework__Pydantic__2,
+        #[cfg(feature = "framework")]
+        Classifier::Framework__Pylons,
+        #[cfg(feature = "framework")]
+        Classifier::Framework__Pyramid,
+        #[cfg(feature = "framework")]
+        Classifier::Framework__Pytest,
+        #[cfg(feature = "framework")]
+        Classifier::Framework__ReviewBoard,
+        #[cfg(feature = "framework")]
+        Classifier::Framework__RobotFramework,
+        #[cfg(feature = "framework")]
+        Classifier::Framework__RobotFramework__Library,
+        #[cfg(feature = "framework")]
+        Classifier::Framework__RobotFramework__Tool,
+        #[cfg(feature = "framework")]
+        Classifier::Framework__Scrapy,
+        #[cfg(feature = "framework")]
+        Classifier::Framework__SetuptoolsPlugin,
+        #[cfg(feature = "framework")]
+        Classifier::Framework__Sphinx,
+        #[cfg(feature = "framework")]
+        Classifier::Framework__Sphinx__Domain,
+        #[cfg(feature = "framework")]
+        Classifier::Framework__Sphinx__Extension,
+        #[cfg(feature = "framework")]
+        Classifier::Framework__Sphinx__Theme,
+        #[cfg(feature = "framework")]
+        Classifier::Framework__Trac,
+        #[cfg(feature = "framework")]
+        Classifier::Framework__Trio,
+        #[cfg(feature = "framework")]
+        Classifier::Framework__Tryton,
+        #[cfg(feature = "framework")]
+        Classifier::Framework__TurboGears,
+        #[cfg(feature = "framework")]
+        Classifier::Framework__TurboGears__Applications,
+        #[cfg(feature = "framework")]
+        Classifier::Framework__TurboGears__Widgets,
+        #[cfg(feature = "framework")]
+        Classifier::Framework__Twisted,
+        #[cfg(feature = "framework")]
+        Classifier::Framework__Wagtail,
+        #[cfg(feature = "framework")]
+        Classifier::Framework__Wagtail__1,
+        #[cfg(feature = "framework")]
+        Classifier::Framework__Wagtail__2,
+        #[cfg(feature = "framework")]
+        Classifier::Framework__Wagtail__3,
+        #[cfg(feature = "framework")]
+        Classifier::Framework__Wagtail__4,
+        #[cfg(feature = "framework")]
+        Classifier::Framework__Wagtail__5,
+        #[cfg(feature = "framework")]
+        Classifier::Framework__Wagtail__6,
+        #[cfg(feature = "framework")]
+        Classifier::Framework__ZODB,
+        #[cfg(feature = "framework")]
+        Classifier::Framework__Zope,
+        #[cfg(feature = "framework")]
+        Classifier::Framework__Zope2,
+        #[cfg(feature = "framework")]
+        Classifier::Framework__Zope3,
+        #[cfg(feature = "framework")]
+        Classifier::Framework__Zope__2,
+        #[cfg(feature = "framework")]
+        Classifier::Framework__Zope__3,
+        #[cfg(feature = "framework")]
+        Classifier::Framework__Zope__4,
+        #[cfg(feature = "framework")]
+        Classifier::Framework__Zope__5,
+        #[cfg(feature = "framework")]
+        Classifier::Framework__aiohttp,
+        #[cfg(feature = "framework")]
+        Classifier::Framework__cocotb,
+        #[cfg(feature = "framework")]
+        Classifier::Framework__napari,
+        #[cfg(feature = "framework")]
+        Classifier::Framework__tox,
+        #[cfg(feature = "intended-audience")]
+        Classifier::IntendedAudience__CustomerService,
+        #[cfg(feature = "intended-audience")]
+        Classifier::IntendedAudience__Developers,
+        #[cfg(feature = "intended-audience")]
+        Classifier::IntendedAudience__Education,
+        #[cfg(feature = "intended-audience")]
+        Classifier::IntendedAudience__EndUsersDesktop,
+        #[cfg(feature = "intended-audience")]
+        Classifier::IntendedAudience__FinancialandInsuranceIndustry,
+        #[cfg(feature = "intended-audience")]
+        Classifier::IntendedAudience__HealthcareIndustry,
+        #[cfg(feature = "intended-audience")]
+        Classifier::IntendedAudience__InformationTechnology,
+        #[cfg(feature = "intended-audience")]
+        Classifier::IntendedAudience__LegalIndustry,
+        #[cfg(feature = "intended-audience")]
+        Classifier::IntendedAudience__Manufacturing,
+        #[cfg(feature = "intended-audience")]
+        Classifier::IntendedAudience__OtherAudience,
+        #[cfg(feature = "intended-audience")]
+        Classifier::IntendedAudience__Religion,
+        #[cfg(feature = "intended-audience")]
+        Classifier::IntendedAudience__ScienceResearch,
+        #[cfg(feature = "intended-audience")]
+        Classifier::IntendedAudience__SystemAdministrators,
+        #[cfg(feature = "intended-audience")]
+        Classifier::IntendedAudience__TelecommunicationsIndustry,
+        #[cfg(feature = "license")]
+        Classifier::License__AladdinFreePublicLicenseAFPL,
+        #[cfg(feature = "license")]
+        Classifier::License__CC01_0UniversalCC01_0PublicDomainDedication,
+        #[cfg(feature = "license")]
+        Classifier::License__CeCILLBFreeSoftwareLicenseAgreementCECILLB,
+        #[cfg(feature = "license")]
+        Classifier::License__CeCILLCFreeSoftwareLicenseAgreementCECILLC,
+        #[cfg(feature = "license")]
+        Classifier::License__DFSGapproved,
+        #[cfg(feature = "license")]
+        Classifier::License__EiffelForumLicenseEFL,
+        #[cfg(feature = "license")]
+        Classifier::License__FreeForEducationalUse,
+        #[cfg(feature = "license")]
+        Classifier::License__FreeForHomeUse,
+        #[cfg(feature = "license")]
+        Classifier::License__FreeToUseButRestricted,
+        #[cfg(feature = "license")]
+        Classifier::License__Freefornoncommercialuse,
+        #[cfg(feature = "license")]
+        Classifier::License__FreelyDistributable,
+        #[cfg(feature = "license")]
+        Classifier::License__Freeware,
+        #[cfg(feature = "license")]
+        Classifier::License__GUSTFontLicense1_0,
+        #[cfg(feature = "license")]
+        Classifier::License__GUSTFontLicense20060930,
+        #[cfg(feature = "license")]
+        Classifier::License__NetscapePublicLicenseNPL,
+        #[cfg(feature = "license")]
+        Classifier::License__NokiaOpenSourceLicenseNOKOS,
+        #[cfg(feature = "license")]
+        Classifier::License__OSIApproved,
+        #[cfg(feature = "license")]
+        Classifier::License__OSIApproved__AcademicFreeLicenseAFL,
+        #[cfg(feature = "license")]
+        Classifier::License__OSIApproved__ApacheSoftwareLicense,
+        #[cfg(feature = "license")]
+        Classifier::License__OSIApproved__ApplePublicSourceLicense,
+        #[cfg(feature = "license")]
+        Classifier::License__OSIApproved__ArtisticLicense,
+        #[cfg(feature = "license")]
+        Classifier::License__OSIApproved__AttributionAssuranceLicense,
+        #[cfg(feature = "license")]
+        Classifier::License__OSIApproved__BSDLicense,
+        #[cfg(feature = "license")]
+        Classifier::License__OSIApproved__BlueOakModelLicenseBlueOak1_0_0,
+        #[cfg(feature = "license")]
+        Classifier::License__OSIApproved__BoostSoftwareLicense1_0BSL1_0,
+        #[cfg(feature = "license")]
+        Classifier::License__OSIApproved__CEACNRSInriaLogicielLibreLicenseversion2_1CeCILL2_1,
+        #[cfg(feature = "license")]
+        Classifier::License__OSIApproved__CMULicenseMITCMU,
+        #[cfg(feature = "license")]
+        Classifier::License__OSIApproved__CommonDevelopmentandDistributionLicense1_0CDDL1_0,
+        #[cfg(feature = "license")]
+        Classifier::License__OSIApproved__CommonPublicLicense,
+        #[cfg(feature = "license")]
+        Classifier::License__OSIApproved__EclipsePublicLicense1_0EPL1_0,
+        #[cfg(feature = "license")]
+        Classifier::License__OSIApproved__EclipsePublicLicense2_0EPL2_0,
+        #[cfg(feature = "license")]
+        Classifier::License__OSIApproved__EducationalCommunityLicenseVersion2_0ECL2_0,
+        #[cfg(feature = "license")]
+        Classifier::License__OSIApproved__EiffelForumLicense,
+        #[cfg(feature = "license")]
+        Classifier::License__OSIApproved__EuropeanUnionPublicLicence1_0EUPL1_0,
+        #[cfg(feature = "license")]
+        Classifier::License__OSIApproved__EuropeanUnionPublicLicence1_1EUPL1_1,
+        #[cfg(feature = "license")]
+        Classifier::License__OSIApproved__EuropeanUnionPublicLicence1_2EUPL1_2,
+        #[cfg(feature = "license")]
+        Classifier::License__OSIApproved__GNUAfferoGeneralPublicLicensev3,
+        #[cfg(feature = "license")]
+        Classifier::License__OSIApproved__GNUAfferoGeneralPublicLicensev3orlaterAGPLv3plus,
+        #[cfg(feature = "license")]
+        Classifier::License__OSIApproved__GNUFreeDocumentationLicenseFDL,
+        #[cfg(feature = "license")]
+        Classifier::License__OSIApproved__GNUGeneralPublicLicenseGPL,
+        #[cfg(feature = "license")]
+        Classifier::License__OSIApproved__GNUGeneralPublicLicensev2GPLv2,
+        #[cfg(feature = "license")]
+        Classifier::License__OSIApproved__GNUGeneralPublicLicensev2orlaterGPLv2plus,
+        #[cfg(feature = "license")]
+        Classifier::License__OSIApproved__GNUGeneralPublicLicensev3GPLv3,
+        #[cfg(feature = "license")]
+        Classifier::License__OSIApproved__GNUGeneralPublicLicensev3orlaterGPLv3plus,
+        #[cfg(feature = "license")]
+        Classifier::License__OSIApproved__GNULesserGeneralPublicLicensev2LGPLv2,
+        #[cfg(feature = "license")]
+        Classifier::License__OSIApproved__GNULesserGeneralPublicLicensev2orlaterLGPLv2plus,
+        #[cfg(feature = "license")]
+        Classifier::License__OSIApproved__GNULesserGeneralPublicLicensev3LGPLv3,
+        #[cfg(feature = "license")]
+        Classifier::License__OSIApproved__GNULesserGeneralPublicLicensev3orlaterLGPLv3plus,
+        #[cfg(feature = "license")]
+        Classifier::License__OSIApproved__GNULibraryorLesserGeneralPublicLicenseLGPL,
+        #[cfg(feature = "license")]
+        Classifier::License__OSIApproved__HistoricalPermissionNoticeandDisclaimerHPND,
+        #[cfg(feature = "license")]
+        Classifier::License__OSIApproved__IBMPublicLicense,
+        #[cfg(feature = "license")]
+        Classifier::License__OSIApproved__ISCLicenseISCL,
+        #[cfg(feature = "license")]
+        Classifier::License__OSIApproved__IntelOpenSourceLicense,
+        #[cfg(feature = "license")]
+        Classifier::License__OSIApproved__JabberOpenSourceLicense,
+        #[cfg(feature = "license")]
+        Classifier::License__OSIApproved__MITLicense,
+        #[cfg(feature = "license")]
+        Classifier::License__OSIApproved__MITNoAttributionLicenseMIT0,
+        #[cfg(feature = "license")]
+        Classifier::License__OSIApproved__MITRECollaborativeVirtualWorkspaceLicenseCVW,
+        #[cfg(feature = "license")]
+        Classifier::License__OSIApproved__MirOSLicenseMirOS,
+        #[cfg(feature = "license")]
+        Classifier::License__OSIApproved__MotosotoLicense,
+        #[cfg(feature = "license")]
+        Classifier::License__OSIApproved__MozillaPublicLicense1_0MPL,
+        #[cfg(feature = "license")]
+        Classifier::License__OSIApproved__MozillaPublicLicense1_1MPL1_1,
+        #[cfg(feature = "license")]
+        Classifier::License__OSIApproved__MozillaPublicLicense2_0MPL2_0,
+        #[cfg(feature = "license")]
+        Classifier::License__OSIApproved__MulanPermissiveSoftwareLicensev2MulanPSL2_0,
+        #[cfg(feature = "license")]
+        Classifier::License__OSIApproved__NASAOpenSourceAgreementv1_3NASA1_3,
+        #[cfg(feature = "license")]
+        Classifier::License__OSIApproved__NethackGeneralPublicLicense,
+        #[cfg(feature = "license")]
+        Classifier::License__OSIApproved__NokiaOpenSourceLicense,
+        #[cfg(feature = "license")]
+        Classifier::License__OSIApproved__OpenGroupTestSuiteLicense,
+        #[cfg(feature = "license")]
+        Classifier::License__OSIApproved__OpenSoftwareLicense3_0OSL3_0,
+        #[cfg(feature = "license")]
+        Classifier::License__OSIApproved__PostgreSQLLicense,
+        #[cfg(feature = "license")]
+        Classifier::License__OSIApproved__PythonLicenseCNRIPythonLicense,
+        #[cfg(feature = "license")]
+        Classifier::License__OSIApproved__PythonSoftwareFoundationLicense,
+        #[cfg(feature = "license")]
+        Classifier::License__OSIApproved__QtPublicLicenseQPL,
+        #[cfg(feature = "license")]
+        Classifier::License__OSIApproved__RicohSourceCodePublicLicense,
+        #[cfg(feature = "license")]
+        Classifier::License__OSIApproved__SILOpenFontLicense1_1OFL1_1,
+        #[cfg(feature = "license")]
+        Classifier::License__OSIApproved__SleepycatLicense,
+        #[cfg(feature = "license")]
+        Classifier::License__OSIApproved__SunIndustryStandardsSourceLicenseSISSL,
+        #[cfg(feature = "license")]
+        Classifier::License__OSIApproved__SunPublicLicense,
+        #[cfg(feature = "license")]
+        Classifier::License__OSIApproved__TheUnlicenseUnlicense,
+        #[cfg(feature = "license")]
+        Classifier::License__OSIApproved__UniversalPermissiveLicenseUPL,
+        #[cfg(feature = "license")]
+        Classifier::License__OSIApproved__UniversityofIllinoisNCSAOpenSourceLicense,
+        #[cfg(feature = "license")]
+        Classifier::License__OSIApproved__VovidaSoftwareLicense1_0,
+        #[cfg(feature = "license")]
+        Classifier::License__OSIApproved__W3CLicense,
+        #[cfg(feature = "license")]
+        Classifier::License__OSIApproved__X_NetLicense,
+        #[cfg(feature = "license")]
+        Classifier::License__OSIApproved__ZeroClauseBSD0BSD,
+        #[cfg(feature = "license")]
+        Classifier::License__OSIApproved__ZopePublicLicense,
+        #[cfg(feature = "license")]
+        Classifier::License__OSIApproved__zliblibpngLicense,
+        #[cfg(feature = "license")]
+        Classifier::License__OtherProprietaryLicense,
+        #[cfg(feature = "license")]
+        Classifier::License__PublicDomain,
+        #[cfg(feature = "license")]
+        Classifier::License__RepozePublicLicense,
+        #[cfg(feature = "natural-language")]
+        Classifier::NaturalLanguage__Afrikaans,
+        #[cfg(feature = "natural-language")]
+        Classifier::NaturalLanguage__Arabic,
+        #[cfg(feature = "natural-language")]
+        Classifier::NaturalLanguage__Basque,
+        #[cfg(feature = "natural-language")]
+        Classifier::NaturalLanguage__Bengali,
+        #[cfg(feature = "natural-language")]
+        Classifier::NaturalLanguage__Bosnian,
+        #[cfg(feature = "natural-language")]
+        Classifier::NaturalLanguage__Bulgarian,
+        #[cfg(feature = "natural-language")]
+        Classifier::NaturalLanguage__Cantonese,
+        #[cfg(feature = "natural-language")]
+        Classifier::NaturalLanguage__Catalan,
+        #[cfg(feature = "natural-language")]
+        Classifier::NaturalLanguage__CatalanValencian,
+        #[cfg(feature = "natural-language")]
+        Classifier::NaturalLanguage__ChineseSimplified,
+        #[cfg(feature = "natural-language")]
+        Classifier::NaturalLanguage__ChineseTraditional,
+        #[cfg(feature = "natural-language")]
+        Classifier::NaturalLanguage__Croatian,
+        #[cfg(feature = "natural-language")]
+        Classifier::NaturalLanguage__Czech,
+        #[cfg(feature = "natural-language")]
+        Classifier::NaturalLanguage__Danish,
+        #[cfg(feature = "natural-language")]
+        Classifier::NaturalLanguage__Dutch,
+        #[cfg(feature = "natural-language")]
+        Classifier::NaturalLanguage__English,
+        #[cfg(feature = "natural-language")]
+        Classifier::NaturalLanguage__Esperanto,
+        #[cfg(feature = "natural-language")]
+        Classifier::NaturalLanguage__Finnish,
+        #[cfg(feature = "natural-language")]
+        Classifier::NaturalLanguage__French,
+        #[cfg(feature = "natural-language")]
+        Classifier::NaturalLanguage__Galician,
+        #[cfg(feature = "natural-language")]
+        Classifier::NaturalLanguage__Georgian,
+        #[cfg(feature = "natural-language")]
+        Classifier::NaturalLanguage__German,
+        #[cfg(feature = "natural-language")]
+        Classifier::NaturalLanguage__Greek,
+        #[cfg(feature = "natural-language")]
+        Classifier::NaturalLanguage__Hebrew,
+        #[cfg(feature = "natural-language")]
+        Classifier::NaturalLanguage__Hindi,
+        #[cfg(feature = "natural-language")]
+        Classifier::NaturalLanguage__Hungarian,
+        #[cfg(feature = "natural-language")]
+        Classifier::NaturalLanguage__Icelandic,
+        #[cfg(feature = "natural-language")]
+        Classifier::NaturalLanguage__Indonesian,
+        #[cfg(feature = "natural-language")]
+        Classifier::NaturalLanguage__Irish,
+        #[cfg(feature = "natural-language")]
+        Classifier::NaturalLanguage__Italian,
+        #[cfg(feature = "natural-language")]
+        Classifier::NaturalLanguage__Japanese,
+        #[cfg(feature = "natural-language")]
+        Classifier::NaturalLanguage__Javanese,
+        #[cfg(feature = "natural-language")]
+        Classifier::NaturalLanguage__Korean,
+        #[cfg(feature = "natural-language")]
+        Classifier::NaturalLanguage__Latin,
+        #[cfg(feature = "natural-language")]
+        Classifier::NaturalLanguage__Latvian,
+        #[cfg(feature = "natural-language")]
+        Classifier::NaturalLanguage__Lithuanian,
+        #[cfg(feature = "natural-language")]
+        Classifier::NaturalLanguage__Macedonian,
+        #[cfg(feature = "natural-language")]
+        Classifier::NaturalLanguage__Malay,
+        #[cfg(feature = "natural-language")]
+        Classifier::NaturalLanguage__Marathi,
+        #[cfg(feature = "natural-language")]
+        Classifier::NaturalLanguage__Nepali,
+        #[cfg(feature = "natural-language")]
+        Classifier::NaturalLanguage__Norwegian,
+        #[cfg(feature = "natural-language")]
+        Classifier::NaturalLanguage__Panjabi,
+        #[cfg(feature = "natural-language")]
+        Classifier::NaturalLanguage__Persian,
+        #[cfg(feature = "natural-language")]
+        Classifier::NaturalLanguage__Polish,
+        #[cfg(feature = "natural-language")]
+        Classifier::NaturalLanguage__Portuguese,
+        #[cfg(feature = "natural-language")]
+        Classifier::NaturalLanguage__PortugueseBrazilian,
+        #[cfg(feature = "natural-language")]
+        Classifier::NaturalLanguage__Romanian,
+        #[cfg(feature = "natural-language")]
+        Classifier::NaturalLanguage__Russian,
+        #[cfg(feature = "natural-language")]
+        Classifier::NaturalLanguage__Serbian,
+        #[cfg(feature = "natural-language")]
+        Classifier::NaturalLanguage__Slovak,
+        #[cfg(feature = "natural-language")]
+        Classifier::NaturalLanguage__Slovenian,
+        #[cfg(feature = "natural-language")]
+        Classifier::NaturalLanguage__Spanish,
+        #[cfg(feature = "natural-language")]
+        Classifier::NaturalLanguage__Swedish,
+        #[cfg(feature = "natural-language")]
+        Classifier::NaturalLanguage__Tamil,
+        #[cfg(feature = "natural-language")]
+        Classifier::NaturalLanguage__Telugu,
+        #[cfg(feature = "natural-language")]
+        Classifier::NaturalLanguage__Thai,
+        #[cfg(feature = "natural-language")]
+        Classifier::NaturalLanguage__Tibetan,
+        #[cfg(feature = "natural-language")]
+        Classifier::NaturalLanguage__Turkish,
+        #[cfg(feature = "natural-language")]
+        Classifier::NaturalLanguage__Ukrainian,
+        #[cfg(feature = "natural-language")]
+        Classifier::NaturalLanguage__Urdu,
+        #[cfg(feature = "natural-language")]
+        Classifier::NaturalLanguage__Vietnamese,
+        #[cfg(feature = "operating-system")]
+        Classifier::OperatingSystem__Android,
+        #[cfg(feature = "operating-system")]
+        Classifier::OperatingSystem__BeOS,
+        #[cfg(feature = "operating-system")]
+        Classifier::OperatingSystem__MacOS,
+        #[cfg(feature = "operating-system")]
+        Classifier::OperatingSystem__MacOS__MacOS9,
+        #[cfg(feature = "operating-system")]
+        Classifier::OperatingSystem__MacOS__MacOSX,
+        #[cfg(feature = "operating-system")]
+        Classifier::OperatingSystem__Microsoft,
+        #[cfg(feature = "operating-system")]
+        Classifier::OperatingSystem__Microsoft__MSDOS,
+        #[cfg(feature = "operating-system")]
+        Classifier::OperatingSystem__Microsoft__Windows,
+        #[cfg(feature = "operating-system")]
+        Classifier::OperatingSystem__Microsoft__Windows__Windows3_1orEarlier,
+        #[cfg(feature = "operating-system")]
+        Classifier::OperatingSystem__Microsoft__Windows__Windows7,
+        #[cfg(feature = "operating-system")]
+        Classifier::OperatingSystem__Microsoft__Windows__Windows8,
+        #[cfg(feature = "operating-system")]
+        Classifier::OperatingSystem__Microsoft__Windows__Windows8_1,
+        #[cfg(feature = "operating-system")]
+        Classifier::OperatingSystem__Microsoft__Windows__Windows10,
+        #[cfg(feature = "operating-system")]
+        Classifier::OperatingSystem__Microsoft__Windows__Windows11,
+        #[cfg(feature = "operating-system")]
+        Classifier::OperatingSystem__Microsoft__Windows__Windows95982000,
+        #[cfg(feature = "operating-system")]
+        Classifier::OperatingSystem__Microsoft__Windows__WindowsCE,
+        #[cfg(feature = "operating-system")]
+        Classifier::OperatingSystem__Microsoft__Windows__WindowsNT2000,
+        #[cfg(feature = "operating-system")]
+        Classifier::OperatingSystem__Microsoft__Windows__WindowsServer2003,
+        #[cfg(feature = "operating-system")]
+        Classifier::OperatingSystem__Microsoft__Windows__WindowsServer2008,
+        #[cfg(feature = "operating-system")]
+        Classifier::OperatingSystem__Microsoft__Windows__WindowsVista,
+        #[cfg(feature = "operating-system")]
+        Classifier::OperatingSystem__Microsoft__Windows__WindowsXP,
+        #[cfg(feature = "operating-system")]
+        Classifier::OperatingSystem__OSIndependent,
+        #[cfg(feature = "operating-system")]
+        Classifier::OperatingSystem__OS2,
+        #[cfg(feature = "operating-system")]
+        Classifier::OperatingSystem__OtherOS,
+        #[cfg(feature = "operating-system")]
+        Classifier::OperatingSystem__PDASystems,
+        #[cfg(feature = "operating-system")]
+        Classifier::OperatingSystem__POSIX,
+        #[cfg(feature = "operating-system")]
+        Classifier::OperatingSystem__POSIX__AIX,
+        #[cfg(feature = "operating-system")]
+        Classifier::OperatingSystem__POSIX__BSD,
+        #[cfg(feature = "operating-system")]
+        Classifier::OperatingSystem__POSIX__BSD__BSDOS,
+        #[cfg(feature = "operating-system")]
+        Classifier::OperatingSystem__POSIX__BSD__FreeBSD,
+        #[cfg(feature = "operating-system")]
+        Classifier::OperatingSystem__POSIX__BSD__NetBSD,
+        #[cfg(feature = "operating-system")]
+        Classifier::OperatingSystem__POSIX__BSD__OpenBSD,
+        #[cfg(feature = "operating-system")]
+        Classifier::OperatingSystem__POSIX__GNUHurd,
+        #[cfg(feature = "operating-system")]
+        Classifier::OperatingSystem__POSIX__HPUX,
+        #[cfg(feature = "operating-system")]
+        Classifier::OperatingSystem__POSIX__IRIX,
+        #[cfg(feature = "operating-system")]
+        Classifier::OperatingSystem__POSIX__Linux,
+        #[cfg(feature = "operating-system")]
+        Classifier::OperatingSystem__POSIX__Other,
+        #[cfg(feature = "operating-system")]
+        Classifier::OperatingSystem__POSIX__SCO,
+        #[cfg(feature = "operating-system")]
+        Classifier::OperatingSystem__POSIX__SunOSSolaris,
+        #[cfg(feature = "operating-system")]
+        Classifier::OperatingSystem__PalmOS,
+        #[cfg(feature = "operating-system")]
+        Classifier::OperatingSystem__RISCOS,
+        #[cfg(feature = "operating-system")]
+        Classifier::OperatingSystem__Unix,
+        #[cfg(feature = "operating-system")]
+        Classifier::OperatingSystem__iOS,
+        #[cfg(feature = "programming-language")]
+        Classifier::ProgrammingLanguage__APL,
+        #[cfg(feature = "programming-language")]
+        Classifier::ProgrammingLanguage__ASP,
+        #[cfg(feature = "programming-language")]
+        Classifier::ProgrammingLanguage__Ada,
+        #[cfg(feature = "programming-language")]
+        Classifier::ProgrammingLanguage__Assembly,
+        #[cfg(feature = "programming-language")]
+        Classifier::ProgrammingLanguage__Awk,
+        #[cfg(feature = "programming-language")]
+        Classifier::ProgrammingLanguage__Basic,
+        #[cfg(feature = "programming-language")]
+        Classifier::ProgrammingLanguage__C,
+        #[cfg(feature = "programming-language")]
+        Classifier::ProgrammingLanguage__Csharp,
+        #[cfg(feature = "programming-language")]
+        Classifier::ProgrammingLanguage__Cplusplus,
+        #[cfg(feature = "programming-language")]
+        Classifier::ProgrammingLanguage__ColdFusion,
+        #[cfg(feature = "programming-language")]
+        Classifier::ProgrammingLanguage__Cython,
+        #[cfg(feature = "programming-language")]
+        Classifier::ProgrammingLanguage__D,
+        #[cfg(feature = "programming-language")]
+        Classifier::ProgrammingLanguage__DelphiKylix,
+        #[cfg(feature = "programming-language")]
+        Classifier::ProgrammingLanguage__Dylan,
+        #[cfg(feature = "programming-language")]
+        Classifier::ProgrammingLanguage__Eiffel,
+        #[cfg(feature = "programming-language")]
+        Classifier::ProgrammingLanguage__EmacsLisp,
+        #[cfg(feature = "programming-language")]
+        Classifier::ProgrammingLanguage__Erlang,
+        #[cfg(feature = "programming-language")]
+        Classifier::ProgrammingLanguage__Euler,
+        #[cfg(feature = "programming-language")]
+        Classifier::ProgrammingLanguage__Euphoria,
+        #[cfg(feature = "programming-language")]
+        Classifier::ProgrammingLanguage__Fsharp,
+        #[cfg(feature = "programming-language")]
+        Classifier::ProgrammingLanguage__Forth,
+        #[cfg(feature = "programming-language")]
+        Classifier::ProgrammingLanguage__Fortran,
+        #[cfg(feature = "programming-language")]
+        Classifier::ProgrammingLanguage__Go,
+        #[cfg(feature = "programming-language")]
+        Classifier::ProgrammingLanguage__Haskell,
+        #[cfg(feature = "programming-language")]
+        Classifier::ProgrammingLanguage__Hy,
+        #[cfg(feature = "programming-language")]
+        Classifier::ProgrammingLanguage__Java,
+        #[cfg(feature = "programming-language")]
+        Classifier::ProgrammingLanguage__JavaScript,
+        #[cfg(feature = "programming-language")]
+        Classifier::ProgrammingLanguage__Kotlin,
+        #[cfg(feature = "programming-language")]
+        Classifier::ProgrammingLanguage__Lisp,
+        #[cfg(feature = "programming-language")]
+        Classifier::ProgrammingLanguage__Logo,
+        #[cfg(feature = "programming-language")]
+        Classifier::ProgrammingLanguage__Lua,
+        #[cfg(feature = "programming-language")]
+        Classifier::ProgrammingLanguage__ML,
+        #[cfg(feature = "programming-language")]
+        Classifier::ProgrammingLanguage__Modula,
+        #[cfg(feature = "programming-language")]
+        Classifier::ProgrammingLanguage__OCaml,
+        #[cfg(feature = "programming-language")]
+        Classifier::ProgrammingLanguage__ObjectPascal,
+        #[cfg(feature = "programming-language")]
+        Classifier::ProgrammingLanguage__ObjectiveC,
+        #[cfg(feature = "programming-language")]
+        Classifier::ProgrammingLanguage__Other,
+        #[cfg(feature = "programming-language")]
+        Classifier::ProgrammingLanguage__OtherScriptingEngines,
+        #[cfg(feature = "programming-language")]
+        Classifier::ProgrammingLanguage__PHP,
+        #[cfg(feature = "programming-language")]
+        Classifier::ProgrammingLanguage__PLSQL,
+        #[cfg(feature = "programming-language")]
+        Classifier::ProgrammingLanguage__PROGRESS,
+        #[cfg(feature = "programming-language")]
+        Classifier::ProgrammingLanguage__Pascal,
+        #[cfg(feature = "programming-language")]
+        Classifier::ProgrammingLanguage__Perl,
+        #[cfg(feature = "programming-language")]
+        Classifier::ProgrammingLanguage__Pike,
+        #[cfg(feature = "programming-language")]
+        Classifier::ProgrammingLanguage__Pliant,
+        #[cfg(feature = "programming-language")]
+        Classifier::ProgrammingLanguage__Prolog,
+        #[cfg(feature = "programming-language")]
+        Classifier::ProgrammingLanguage__Python,
+        #[cfg(feature = "programming-language")]
+        Classifier::ProgrammingLanguage__Python__2,
+        #[cfg(feature = "programming-language")]
+        Classifier::ProgrammingLanguage__Python__2__Only,
+        #[cfg(feature = "programming-language")]
+        Classifier::ProgrammingLanguage__Python__2_3,
+        #[cfg(feature = "programming-language")]
+        Classifier::ProgrammingLanguage__Python__2_4,
+        #[cfg(feature = "programming-language")]
+        Classifier::ProgrammingLanguage__Python__2_5,
+        #[cfg(feature = "programming-language")]
+        Classifier::ProgrammingLanguage__Python__2_6,
+        #[cfg(feature = "programming-language")]
+        Classifier::ProgrammingLanguage__Python__2_7,
+        #[cfg(feature = "programming-language")]
+        Classifier::ProgrammingLanguage__Python__3,
+        #[cfg(feature = "programming-language")]
+        Classifier::ProgrammingLanguage__Python__3__Only,
+        #[cfg(feature = "programming-language")]
+        Classifier::ProgrammingLanguage__Python__3_0,
+        #[cfg(feature = "programming-language")]
+        Classifier::ProgrammingLanguage__Python__3_1,
+        #[cfg(feature = "programming-language")]
+        Classifier::ProgrammingLanguage__Python__3_2,
+        #[cfg(feature = "programming-language")]
+        Classifier::ProgrammingLanguage__Python__3_3,
+        #[cfg(feature = "programming-language")]
+        Classifier::ProgrammingLanguage__Python__3_4,
+        #[cfg(feature = "programming-language")]
+        Classifier::ProgrammingLanguage__Python__3_5,
+        #[cfg(feature = "programming-language")]
+        Classifier::ProgrammingLanguage__Python__3_6,
+        #[cfg(feature = "programming-language")]
+        Classifier::ProgrammingLanguage__Python__3_7,
+        #[cfg(feature = "programming-language")]
+        Classifier::ProgrammingLanguage__Python__3_8,
+        #[cfg(feature = "programming-language")]
+        Classifier::ProgrammingLanguage__Python__3_9,
+        #[cfg(feature = "programming-language")]
+        Classifier::ProgrammingLanguage__Python__3_10,
+        #[cfg(feature = "programming-language")]
+        Classifier::ProgrammingLanguage__Python__3_11,
+        #[cfg(feature = "programming-language")]
+        Classifier::ProgrammingLanguage__Python__3_12,
+        #[cfg(feature = "programming-language")]
+        Classifier::ProgrammingLanguage__Python__3_13,
+        #[cfg(feature = "programming-language")]
+        Classifier::ProgrammingLanguage__Python__3_14,
+        #[cfg(feature = "programming-language")]
+        Classifier::ProgrammingLanguage__Python__Implementation,
+        #[cfg(feature = "programming-language")]
+        Classifier::ProgrammingLanguage__Python__Implementation__CPython,
+        #[cfg(feature = "programming-language")]
+        Classifier::ProgrammingLanguage__Python__Implementation__IronPython,
+        #[cfg(feature = "programming-language")]
+        Classifier::ProgrammingLanguage__Python__Implementation__Jython,
+        #[cfg(feature = "programming-language")]
+        Classifier::ProgrammingLanguage__Python__Implementation__MicroPython,
+        #[cfg(feature = "programming-language")]
+        Classifier::ProgrammingLanguage__Python__Implementation__PyPy,
+        #[cfg(feature = "programming-language")]
+        Classifier::ProgrammingLanguage__Python__Implementation__Stackless,
+        #[cfg(feature = "programming-language")]
+        Classifier::ProgrammingLanguage__R,
+        #[cfg(feature = "programming-language")]
+        Classifier::ProgrammingLanguage__REBOL,
+        #[cfg(feature = "programming-language")]
+        Classifier::ProgrammingLanguage__Rexx,
+        #[cfg(feature = "programming-language")]
+        Classifier::ProgrammingLanguage__Ruby,
+        #[cfg(feature = "programming-language")]
+        Classifier::ProgrammingLanguage__Rust,
+        #[cfg(feature = "programming-language")]
+        Classifier::ProgrammingLanguage__SQL,
+        #[cfg(feature = "programming-language")]
+        Classifier::ProgrammingLanguage__Scheme,
+        #[cfg(feature = "programming-language")]
+        Classifier::ProgrammingLanguage__Simula,
+        #[cfg(feature = "programming-language")]
+        Classifier::ProgrammingLanguage__Smalltalk,
+        #[cfg(feature = "programming-language")]
+        Classifier::ProgrammingLanguage__Tcl,
+        #[cfg(feature = "programming-language")]
+        Classifier::ProgrammingLanguage__UnixShell,
+        #[cfg(feature = "programming-language")]
+        Classifier::ProgrammingLanguage__VisualBasic,
+        #[cfg(feature = "programming-language")]
+        Classifier::ProgrammingLanguage__XBasic,
+        #[cfg(feature = "programming-language")]
+        Classifier::ProgrammingLanguage__YACC,
+        #[cfg(feature = "programming-language")]
+        Classifier::ProgrammingLanguage__Zope,
+        #[cfg(feature = "topic")]
+        Classifier::Topic__AdaptiveTechnologies,
+        #[cfg(feature = "topic")]
+        Classifier::Topic__ArtisticSoftware,
+        #[cfg(feature = "topic")]
+        Classifier::Topic__Communications,
+        #[cfg(feature = "topic")]
+        Classifier::Topic__Communications__BBS,
+        #[cfg(feature = "topic")]
+        Classifier::Topic__Communications__Chat,
+        #[cfg(feature = "topic")]
+        Classifier::Topic__Communications__Chat__ICQ,
+        #[cfg(feature = "topic")]
+        Classifier::Topic__Communications__Chat__InternetRelayChat,
+        #[cfg(feature = "topic")]
+        Classifier::Topic__Communications__Chat__UnixTalk,
+        #[cfg(feature = "topic")]
+        Classifier::Topic__Communications__Conferencing,
+        #[cfg(feature = "topic")]
+        Classifier::Topic__Communications__Email,
+        #[cfg(feature = "topic")]
+        Classifier::Topic__Communications__Email__AddressBook,
+        #[cfg(feature = "topic")]
+        Classifier::Topic__Communications__Email__EmailClientsMUA,
+        #[cfg(feature = "topic")]
+        Classifier::Topic__Communications__Email__Filters,
+        #[cfg(feature = "topic")]
+        Classifier::Topic__Communications__Email__MailTransportAgents,
+        #[cfg(feature = "topic")]
+        Classifier::Topic__Communications__Email__MailingListServers,
+        #[cfg(feature = "topic")]
+        Classifier::Topic__Communications__Email__PostOffice,
+        #[cfg(feature = "topic")]
+        Classifier::Topic__Communications__Email__PostOffice__IMAP,
+        #[cfg(feature = "topic")]
+        Classifier::Topic__Communications__Email__PostOffice__POP3,
+        #[cfg(feature = "topic")]
+        Classifier::Topic__Communications__FIDO,
+        #[cfg(feature = "topic")]
+        Classifier::Topic__Communications__Fax,
+        #[cfg(feature = "topic")]
+        Classifier::Topic__Communications__FileSharing,
+        #[cfg(feature = "topic")]
+        Classifier::Topic__Communications__FileSharing__Gnutella,
+        #[cfg(feature = "topic")]
+        Classifier::Topic__Communications__FileSharing__Napster,
+        #[cfg(feature = "topic")]
+        Classifier::Topic__Communications__HamRadio,
+        #[cfg(feature = "topic")]
+        Classifier::Topic__Communications__InternetPhone,
+        #[cfg(feature = "topic")]
+        Classifier::Topic__Communications__Telephony,
+        #[cfg(feature = "topic")]
+        Classifier::Topic__Communications__UsenetNews,
+        #[cfg(feature = "topic")]
+        Classifier::Topic__Database,
+        #[cfg(feature = "topic")]
+        Classifier::Topic__Database__DatabaseEnginesServers,
+        #[cfg(feature = "topic")]
+        Classifier::Topic__Database__FrontEnds,
+        #[cfg(feature = "topic")]
+        Classifier::Topic__DesktopEnvironment,
+        #[cfg(feature = "topic")]
+        Classifier::Topic__DesktopEnvironment__FileManagers,
+        #[cfg(feature = "topic")]
+        Classifier::Topic__DesktopEnvironment__GNUstep,
+        #[cfg(feature = "topic")]
+        Classifier::Topic__DesktopEnvironment__Gnome,
+        #[cfg(feature = "topic")]
+        Classifier::Topic__DesktopEnvironment__KDesktopEnvironmentKDE,
+        #[cfg(feature = "topic")]
+        Classifier::Topic__DesktopEnvironment__KDesktopEnvironmentKDE__Themes,
+        #[cfg(feature = "topic")]
+        Classifier::Topic__DesktopEnvironment__PicoGUI,
+        #[cfg(feature = "topic")]
+        Classifier::Topic__DesktopEnvironment__PicoGUI__Applications,
+        #[cfg(feature = "topic")]
+        Classifier::Topic__DesktopEnvironment__PicoGUI__Themes,
+        #[cfg(feature = "topic")]
+        Classifier::Topic__DesktopEnvironment__ScreenSavers,
+        #[cfg(feature = "topic")]
+        Classifier::Topic__DesktopEnvironment__WindowManagers,
+        #[cfg(feature = "topic")]
+        Classifier::Topic__DesktopEnvironment__WindowManagers__Afterstep,
+        #[cfg(feature = "topic")]
+        Classifier::Topic__DesktopEnvironment__WindowManagers__Afterstep__Themes,
+        #[cfg(feature = "topic")]
+        Classifier::Topic__DesktopEnvironment__WindowManagers__Applets,
+        #[cfg(feature = "topic")]
+        Classifier::Topic__DesktopEnvironment__WindowManagers__Blackbox,
+        #[cfg(feature = "topic")]
+        Classifier::Topic__DesktopEnvironment__WindowManagers__Blackbox__Themes,
+        #[cfg(feature = "topic")]
+        Classifier::Topic__DesktopEnvironment__WindowManagers__CTWM,
+        #[cfg(feature = "topic")]
+        Classifier::Topic__DesktopEnvironment__WindowManagers__CTWM__Themes,
+        #[cfg(feature = "topic")]
+        Classifier::Topic__DesktopEnvironment__WindowManagers__Enlightenment,
+        #[cfg(feature = "topic")]
+        Classifier::Topic__DesktopEnvironment__WindowManagers__Enlightenment__Epplets,
+        #[cfg(feature = "topic")]
+        Classifier::Topic__DesktopEnvironment__WindowManagers__Enlightenment__ThemesDR15,
+        #[cfg(feature = "topic")]
+        Classifier::Topic__DesktopEnvironment__WindowManagers__Enlightenment__ThemesDR16,
+        #[cfg(feature = "topic")]
+        Classifier::Topic__DesktopEnvironment__WindowManagers__Enlightenment__ThemesDR17,
+        #[cfg(feature = "topic")]
+        Classifier::Topic__DesktopEnvironment__WindowManagers__FVWM,
+        #[cfg(feature = "topic")]
+        Classifier::Topic__DesktopEnvironment__WindowManagers__FVWM__Themes,
+        #[cfg(feature = "topic")]
+        Classifier::Topic__DesktopEnvironment__WindowManagers__Fluxbox,
+        #[cfg(feature = "topic")]
+        Classifier::Topic__DesktopEnvironment__WindowManagers__Fluxbox__Themes,
+        #[cfg(feature = "topic")]
+        Classifier::Topic__DesktopEnvironment__WindowManagers__IceWM,
+        #[cfg(feature = "topic")]
+        Classifier::Topic__DesktopEnvironment__WindowManagers__IceWM__Themes,
+        #[cfg(feature = "topic")]
+        Classifier::Topic__DesktopEnvironment__WindowManagers__MetaCity,
+        #[cfg(feature = "topic")]
+        Classifier::Topic__DesktopEnvironment__WindowManagers__MetaCity__Themes,
+        #[cfg(feature = "topic")]
+        Classifier::Topic__DesktopEnvironment__WindowManagers__Oroborus,
+        #[cfg(feature = "topic")]
+        Classifier::Topic__DesktopEnvironment__WindowManagers__Oroborus__Themes,
+        #[cfg(feature = "topic")]
+        Classifier::Topic__DesktopEnvironment__WindowManagers__Sawfish,
+        #[cfg(feature = "topic")]
+        Classifier::Topic__DesktopEnvironment__WindowManagers__Sawfish__Themes0_30,
+        #[cfg(feature = "topic")]
+        Classifier::Topic__DesktopEnvironment__WindowManagers__Sawfish__Themespre0_30,
+        #[cfg(feature = "topic")]
+        Classifier::Topic__DesktopEnvironment__WindowManagers__Waimea,
+        #[cfg(feature = "topic")]
+        Classifier::Topic__DesktopEnvironment__WindowManagers__Waimea__Themes,
+        #[cfg(feature = "topic")]
+        Classifier::Topic__DesktopEnvironment__WindowManagers__WindowMaker,
+        #[cfg(feature = "topic")]
+        Classifier::Topic__DesktopEnvironment__WindowManagers__WindowMaker__Applets,
+        #[cfg(feature = "topic")]
+        Classifier::Topic__DesktopEnvironment__WindowManagers__WindowMaker__Themes,
+        #[cfg(feature = "topic")]
+        Classifier::Topic__DesktopEnvironment__WindowManagers__XFCE,
+        #[cfg(feature = "topic")]
+        Classifier::Topic__DesktopEnvironment__WindowManagers__XFCE__Themes,
+        #[cfg(feature = "topic")]
+        Classifier::Topic__Documentation,
+        #[cfg(feature = "topic")]
+        Classifier::Topic__Documentation__Sphinx,
+        #[cfg(feature = "topic")]
+        Classifier::Topic__Education,
+        #[cfg(feature = "topic")]
+        Classifier::Topic__Education__ComputerAidedInstructionCAI,
+        #[cfg(feature = "topic")]
+        Classifier::Topic__Education__Testing,
+        #[cfg(feature = "topic")]
+        Classifier::Topic__FileFormats,
+        #[cfg(feature = "topic")]
+        Classifier::Topic__FileFormats__JSON,
+        #[cfg(feature = "topic")]
+        Classifier::Topic__FileFormats__JSON__JSONSchema,
+        #[cfg(feature = "topic")]
+        Classifier::Topic__GamesEntertainment,
+        #[cfg(feature = "topic")]
+        Classifier::Topic__GamesEntertainment__Arcade,
+        #[cfg(feature = "topic")]
+        Classifier::Topic__GamesEntertainment__BoardGames,
+        #[cfg(feature = "topic")]
+        Classifier::Topic__GamesEntertainment__FirstPersonShooters,
+        #[cfg(feature = "topic")]
+        Classifier::Topic__GamesEntertainment__FortuneCookies,
+        #[cfg(feature = "topic")]
+        Classifier::Topic__GamesEntertainment__MultiUserDungeonsMUD,
+        #[cfg(feature = "topic")]
+        Classifier::Topic__GamesEntertainment__PuzzleGames,
+        #[cfg(feature = "topic")]
+        Classifier::Topic__GamesEntertainment__RealTimeStrategy,
+        #[cfg(feature = "topic")]
+        Classifier::Topic__GamesEntertainment__RolePlaying,
+        #[cfg(feature = "topic")]
+        Classifier::Topic__GamesEntertainment__SideScrollingArcadeGames,
+        #[cfg(feature = "topic")]
+        Classifier::Topic__GamesEntertainment__Simulation,
+        #[cfg(feature = "topic")]
+        Classifier::Topic__GamesEntertainment__TurnBasedStrategy,
+        #[cfg(feature = "topic")]
+        Classifier::Topic__HomeAutomation,
+        #[cfg(feature = "topic")]
+        Classifier::Topic__Internet,
+        #[cfg(feature = "topic")]
+        Classifier::Topic__Internet__FileTransferProtocolFTP,
+        #[cfg(feature = "topic")]
+        Classifier::Topic__Internet__Finger,
+        #[cfg(feature = "topic")]
+        Classifier::Topic__Internet__LogAnalysis,
+        #[cfg(feature = "topic")]
+        Classifier::Topic__Internet__NameServiceDNS,
+        #[cfg(feature = "topic")]
+        Classifier::Topic__Internet__ProxyServers,
+        #[cfg(feature = "topic")]
+        Classifier::Topic__Internet__WAP,
+        #[cfg(feature = "topic")]
+        Classifier::Topic__Internet__WWWHTTP,
+        #[cfg(feature = "topic")]
+        Classifier::Topic__Internet__WWWHTTP__Browsers,
+        #[cfg(feature = "topic")]
+        Classifier::Topic__Internet__WWWHTTP__DynamicContent,
+        #[cfg(feature = "topic")]
+        Classifier::Topic__Internet__WWWHTTP__DynamicContent__CGIToolsLibraries,
+        #[cfg(feature = "topic")]
+        Classifier::Topic__Internet__WWWHTTP__DynamicContent__ContentManagementSystem,
+        #[cfg(feature = "topic")]
+        Classifier::Topic__Internet__WWWHTTP__DynamicContent__MessageBoards,
+        #[cfg(feature = "topic")]
+        Classifier::Topic__Internet__WWWHTTP__DynamicContent__NewsDiary,
+        #[cfg(feature = "topic")]
+        Classifier::Topic__Internet__WWWHTTP__DynamicContent__PageCounters,
+        #[cfg(feature = "topic")]
+        Classifier::Topic__Internet__WWWHTTP__DynamicContent__Wiki,
+        #[cfg(feature = "topic")]
+        Classifier::Topic__Internet__WWWHTTP__HTTPServers,
+        #[cfg(feature = "topic")]
+        Classifier::Topic__Internet__WWWHTTP__IndexingSearch,
+        #[cfg(feature = "topic")]
+        Classifier::Topic__Internet__WWWHTTP__Session,
+        #[cfg(feature = "topic")]
+        Classifier::Topic__Internet__WWWHTTP__SiteManagement,
+        #[cfg(feature = "topic")]
+        Classifier::Topic__Internet__WWWHTTP__SiteManagement__LinkChecking,
+        #[cfg(feature = "topic")]
+        Classifier::Topic__Internet__WWWHTTP__WSGI,
+        #[cfg(feature = "topic")]
+        Classifier::Topic__Internet__WWWHTTP__WSGI__Application,
+        #[cfg(feature = "topic")]
+        Classifier::Topic__Internet__WWWHTTP__WSGI__Middleware,
+        #[cfg(feature = "topic")]
+        Classifier::Topic__Internet__WWWHTTP__WSGI__Server,
+        #[cfg(feature = "topic")]
+        Classifier::Topic__Internet__XMPP,
+        #[cfg(feature = "topic")]
+        Classifier::Topic__Internet__Z39_50,
+        #[cfg(feature = "topic")]
+        Classifier::Topic__Multimedia,
+        #[cfg(feature = "topic")]
+        Classifier::Topic__Multimedia__Graphics,
+        #[cfg(feature = "topic")]
+        Classifier::Topic__Multimedia__Graphics__3DModeling,
+        #[cfg(feature = "topic")]
+        Classifier::Topic__Multimedia__Graphics__3DRendering,
+        #[cfg(feature = "topic")]
+        Classifier::Topic__Multimedia__Graphics__Capture,
+        #[cfg(feature = "topic")]
+        Classifier::Topic__Multimedia__Graphics__Capture__DigitalCamera,
+        #[cfg(feature = "topic")]
+        Classifier::Topic__Multimedia__Graphics__Capture__Scanners,
+        #[cfg(feature = "topic")]
+        Classifier::Topic__Multimedia__Graphics__Capture__ScreenCapture,
+        #[cfg(feature = "topic")]
+        Classifier::Topic__Multimedia__Graphics__Editors,
+        #[cfg(feature = "topic")]
+        Classifier::Topic__Multimedia__Graphics__Editors__RasterBased,
+        #[cfg(feature = "topic")]
+        Classifier::Topic__Multimedia__Graphics__Editors__VectorBased,
+        #[cfg(feature = "topic")]
+        Classifier::Topic__Multimedia__Graphics__GraphicsConversion,
+        #[cfg(feature = "topic")]
+        Classifier::Topic__Multimedia__Graphics__Presentation,
+        #[cfg(feature = "topic")]
+        Classifier::Topic__Multimedia__Graphics__Viewers,
+        #[cfg(feature = "topic")]
+        Classifier::Topic__Multimedia__SoundAudio,
+        #[cfg(feature = "topic")]
+        Classifier::Topic__Multimedia__SoundAudio__Analysis,
+        #[cfg(feature = "topic")]
+        Classifier::Topic__Multimedia__SoundAudio__CDAudio,
+        #[cfg(feature = "topic")]
+        Classifier::Topic__Multimedia__SoundAudio__CDAudio__CDPlaying,
+        #[cfg(feature = "topic")]
+        Classifier::Topic__Multimedia__SoundAudio__CDAudio__CDRipping,
+        #[cfg(feature = "topic")]
+        Classifier::Topic__Multimedia__SoundAudio__CDAudio__CDWriting,
+        #[cfg(feature = "topic")]
+        Classifier::Topic__Multimedia__SoundAudio__CaptureRecording,
+        #[cfg(feature = "topic")]
+        Classifier::Topic__Multimedia__SoundAudio__Conversion,
+        #[cfg(feature = "topic")]
+        Classifier::Topic__Multimedia__SoundAudio__Editors,
+        #[cfg(feature = "topic")]
+        Classifier::Topic__Multimedia__SoundAudio__MIDI,
+        #[cfg(feature = "topic")]
+        Classifier::Topic__Multimedia__SoundAudio__Mixers,
+        #[cfg(feature = "topic")]
+        Classifier::Topic__Multimedia__SoundAudio__Players,
+        #[cfg(feature = "topic")]
+        Classifier::Topic__Multimedia__SoundAudio__Players__MP3,
+        #[cfg(feature = "topic")]
+        Classifier::Topic__Multimedia__SoundAudio__SoundSynthesis,
+        #[cfg(feature = "topic")]
+        Classifier::Topic__Multimedia__SoundAudio__Speech,
+        #[cfg(feature = "topic")]
+        Classifier::Topic__Multimedia__Video,
+        #[cfg(feature = "topic")]
+        Classifier::Topic__Multimedia__Video__Capture,
+        #[cfg(feature = "topic")]
+        Classifier::Topic__Multimedia__Video__Conversion,
+        #[cfg(feature = "topic")]
+        Classifier::Topic__Multimedia__Video__Display,
+        #[cfg(feature = "topic")]
+        Classifier::Topic__Multimedia__Video__NonLinearEditor,
+        #[cfg(feature = "topic")]
+        Classifier::Topic__OfficeBusiness,
+        #[cfg(feature = "topic")]
+        Classifier::Topic__OfficeBusiness__Financial,
+        #[cfg(feature = "topic")]
+        Classifier::Topic__OfficeBusiness__Financial__Accounting,
+        #[cfg(feature = "topic")]
+        Classifier::Topic__OfficeBusiness__Financial__Investment,
+        #[cfg(feature = "topic")]
+        Classifier::Topic__OfficeBusiness__Financial__PointOfSale,
+        #[cfg(feature = "topic")]
+        Classifier::Topic__OfficeBusiness__Financial__Spreadsheet,
+        #[cfg(feature = "topic")]
+        Classifier::Topic__OfficeBusiness__Groupware,
+        #[cfg(feature = "topic")]
+        Classifier::Topic__OfficeBusiness__NewsDiary,
+        #[cfg(feature = "topic")]
+        Classifier::Topic__OfficeBusiness__OfficeSuites,
+        #[cfg(feature = "topic")]
+        Classifier::Topic__OfficeBusiness__Scheduling,
+        #[cfg(feature = "topic")]
+        Classifier::Topic__OtherNonlistedTopic,
+        #[cfg(feature = "topic")]
+        Classifier::Topic__Printing,
+        #[cfg(feature = "topic")]
+        Classifier::Topic__Religion,
+        #[cfg(feature = "topic")]
+        Classifier::Topic__ScientificEngineering,
+        #[cfg(feature = "topic")]
+        Classifier::Topic__ScientificEngineering__ArtificialIntelligence,
+        #[cfg(feature = "topic")]
+        Classifier::Topic__ScientificEngineering__ArtificialLife,
+        #[cfg(feature = "topic")]
+        Classifier::Topic__ScientificEngineering__Astronomy,
+        #[cfg(feature = "topic")]
+        Classifier::Topic__ScientificEngineering__AtmosphericScience,
+        #[cfg(feature = "topic")]
+        Classifier::Topic__ScientificEngineering__BioInformatics,
+        #[cfg(feature = "topic")]
+        Classifier::Topic__ScientificEngineering__Chemistry,
+        #[cfg(feature = "topic")]
+        Classifier::Topic__ScientificEngineering__ElectronicDesignAutomationEDA,
+        #[cfg(feature = "topic")]
+        Classifier::Topic__ScientificEngineering__GIS,
+        #[cfg(feature = "topic")]
+        Classifier::Topic__ScientificEngineering__HumanMachineInterfaces,
+        #[cfg(feature = "topic")]
+        Classifier::Topic__ScientificEngineering__Hydrology,
+        #[cfg(feature = "topic")]
+        Classifier::Topic__ScientificEngineering__ImageProcessing,
+        #[cfg(feature = "topic")]
+        Classifier::Topic__ScientificEngineering__ImageRecognition,
+        #[cfg(feature = "topic")]
+        Classifier::Topic__ScientificEngineering__InformationAnalysis,
+        #[cfg(feature = "topic")]
+        Classifier::Topic__ScientificEngineering__InterfaceEngineProtocolTranslator,
+        #[cfg(feature = "topic")]
+        Classifier::Topic__ScientificEngineering__Mathematics,
+        #[cfg(feature = "topic")]
+        Classifier::Topic__ScientificEngineering__MedicalScienceApps_,
+        #[cfg(feature = "topic")]
+        Classifier::Topic__ScientificEngineering__Oceanography,
+        #[cfg(feature = "topic")]
+        Classifier::Topic__ScientificEngineering__Physics,
+        #[cfg(feature = "topic")]
+        Classifier::Topic__ScientificEngineering__Visualization,
+        #[cfg(feature = "topic")]
+        Classifier::Topic__Security,
+        #[cfg(feature = "topic")]
+        Classifier::Topic__Security__Cryptography,
+        #[cfg(feature = "topic")]
+        Classifier::Topic__Sociology,
+        #[cfg(feature = "topic")]
+        Classifier::Topic__Sociology__Genealogy,
+        #[cfg(feature = "topic")]
+        Classifier::Topic__Sociology__History,
+        #[cfg(feature = "topic")]
+        Classifier::Topic__SoftwareDevelopment,
+        #[cfg(feature = "topic")]
+        Classifier::Topic__SoftwareDevelopment__Assemblers,
+        #[cfg(feature = "topic")]
+        Classifier::Topic__SoftwareDevelopment__BugTracking,
+        #[cfg(feature = "topic")]
+        Classifier::Topic__SoftwareDevelopment__BuildTools,
+        #[cfg(feature = "topic")]
+        Classifier::Topic__SoftwareDevelopment__CodeGenerators,
+        #[cfg(feature = "topic")]
+        Classifier::Topic__SoftwareDevelopment__Compilers,
+        #[cfg(feature = "topic")]
+        Classifier::Topic__SoftwareDevelopment__Debuggers,
+        #[cfg(feature = "topic")]
+        Classifier::Topic__SoftwareDevelopment__Disassemblers,
+        #[cfg(feature = "topic")]
+        Classifier::Topic__SoftwareDevelopment__Documentation,
+        #[cfg(feature = "topic")]
+        Classifier::Topic__SoftwareDevelopment__EmbeddedSystems,
+        #[cfg(feature = "topic")]
+        Classifier::Topic__SoftwareDevelopment__EmbeddedSystems__ControllerAreaNetworkCAN,
+        #[cfg(feature = "topic")]
+        Classifier::Topic__SoftwareDevelopment__EmbeddedSystems__ControllerAreaNetworkCAN__CANopen,
+        #[cfg(feature = "topic")]
+        Classifier::Topic__SoftwareDevelopment__EmbeddedSystems__ControllerAreaNetworkCAN__J1939,
+        #[cfg(feature = "topic")]
+        Classifier::Topic__SoftwareDevelopment__Internationalization,
+        #[cfg(feature = "topic")]
+        Classifier::Topic__SoftwareDevelopment__Interpreters,
+        #[cfg(feature = "topic")]
+        Classifier::Topic__SoftwareDevelopment__Libraries,
+        #[cfg(feature = "topic")]
+        Classifier::Topic__SoftwareDevelopment__Libraries__ApplicationFrameworks,
+        #[cfg(feature = "topic")]
+        Classifier::Topic__SoftwareDevelopment__Libraries__JavaLibraries,
+        #[cfg(feature = "topic")]
+        Classifier::Topic__SoftwareDevelopment__Libraries__PHPClasses,
+        #[cfg(feature = "topic")]
+        Classifier::Topic__SoftwareDevelopment__Libraries__PerlModules,
+        #[cfg(feature = "topic")]
+        Classifier::Topic__SoftwareDevelopment__Libraries__PikeModules,
+        #[cfg(feature = "topic")]
+        Classifier::Topic__SoftwareDevelopment__Libraries__PythonModules,
+        #[cfg(feature = "topic")]
+        Classifier::Topic__SoftwareDevelopment__Libraries__RubyModules,
+        #[cfg(feature = "topic")]
+        Classifier::Topic__SoftwareDevelopment__Libraries__TclExtensions,
+        #[cfg(feature = "topic")]
+        Classifier::Topic__SoftwareDevelopment__Libraries__pygame,
+        #[cfg(feature = "topic")]
+        Classifier::Topic__SoftwareDevelopment__Localization,
+        #[cfg(feature = "topic")]
+        Classifier::Topic__SoftwareDevelopment__ObjectBrokering,
+        #[cfg(feature = "topic")]
+        Classifier::Topic__SoftwareDevelopment__ObjectBrokering__CORBA,
+        #[cfg(feature = "topic")]
+        Classifier::Topic__SoftwareDevelopment__Preprocessors,
+        #[cfg(feature = "topic")]
+        Classifier::Topic__SoftwareDevelopment__QualityAssurance,
+        #[cfg(feature = "topic")]
+        Classifier::Topic__SoftwareDevelopment__Testing,
+        #[cfg(feature = "topic")]
+        Classifier::Topic__SoftwareDevelopment__Testing__Acceptance,
+        #[cfg(feature = "topic")]
+        Classifier::Topic__SoftwareDevelopment__Testing__BDD,
+        #[cfg(feature = "topic")]
+        Classifier::Topic__SoftwareDevelopment__Testing__Mocking,
+        #[cfg(feature = "topic")]
+        Classifier::Topic__SoftwareDevelopment__Testing__TrafficGeneration,
+        #[cfg(feature = "topic")]
+        Classifier::Topic__SoftwareDevelopment__Testing__Unit,
+        #[cfg(feature = "topic")]
+        Classifier::Topic__SoftwareDevelopment__UserInterfaces,
+        #[cfg(feature = "topic")]
+        Classifier::Topic__SoftwareDevelopment__VersionControl,
+        #[cfg(feature = "topic")]
+        Classifier::Topic__SoftwareDevelopment__VersionControl__Bazaar,
+        #[cfg(feature = "topic")]
+        Classifier::Topic__SoftwareDevelopment__VersionControl__CVS,
+        #[cfg(feature = "topic")]
+        Classifier::Topic__SoftwareDevelopment__VersionControl__Git,
+        #[cfg(feature = "topic")]
+        Classifier::Topic__SoftwareDevelopment__VersionControl__Mercurial,
+        #[cfg(feature = "topic")]
+        Classifier::Topic__SoftwareDevelopment__VersionControl__RCS,
+        #[cfg(feature = "topic")]
+        Classifier::Topic__SoftwareDevelopment__VersionControl__SCCS,
+        #[cfg(feature = "topic")]
+        Classifier::Topic__SoftwareDevelopment__WidgetSets,
+        #[cfg(feature = "topic")]
+        Classifier::Topic__System,
+        #[cfg(feature = "topic")]
+        Classifier::Topic__System__Archiving,
+        #[cfg(feature = "topic")]
+        Classifier::Topic__System__Archiving__Backup,
+        #[cfg(feature = "topic")]
+        Classifier::Topic__System__Archiving__Compression,
+        #[cfg(feature = "topic")]
+        Classifier::Topic__System__Archiving__Mirroring,
+        #[cfg(feature = "topic")]
+        Classifier::Topic__System__Archiving__Packaging,
+        #[cfg(feature = "topic")]
+        Classifier::Topic__System__Benchmark,
+        #[cfg(feature = "topic")]
+        Classifier::Topic__System__Boot,
+        #[cfg(feature = "topic")]
+        Classifier::Topic__System__Boot__Init,
+        #[cfg(feature = "topic")]
+        Classifier::Topic__System__Clustering,
+        #[cfg(feature = "topic")]
+        Classifier::Topic__System__ConsoleFonts,
+        #[cfg(feature = "topic")]
+        Classifier::Topic__System__DistributedComputing,
+        #[cfg(feature = "topic")]
+        Classifier::Topic__System__Emulators,
+        #[cfg(feature = "topic")]
+        Classifier::Topic__System__Filesystems,
+        #[cfg(feature = "topic")]
+        Classifier::Topic__System__Hardware,
+        #[cfg(feature = "topic")]
+        Classifier::Topic__System__Hardware__HardwareDrivers,
+        #[cfg(feature = "topic")]
+        Classifier::Topic__System__Hardware__Mainframes,
+        #[cfg(feature = "topic")]
+        Classifier::Topic__System__Hardware__SymmetricMultiprocessing,
+        #[cfg(feature = "topic")]
+        Classifier::Topic__System__Hardware__UniversalSerialBusUSB,
+        #[cfg(feature = "topic")]
+        Classifier::Topic__System__Hardware__UniversalSerialBusUSB__Audio,
+        #[cfg(feature = "topic")]
+        Classifier::Topic__System__Hardware__UniversalSerialBusUSB__AudioVideoAV,
+        #[cfg(feature = "topic")]
+        Classifier::Topic__System__Hardware__UniversalSerialBusUSB__CommunicationsDeviceClassCDC,
+        #[cfg(feature = "topic")]
+        Classifier::Topic__System__Hardware__UniversalSerialBusUSB__DiagnosticDevice,
+        #[cfg(feature = "topic")]
+        Classifier::Topic__System__Hardware__UniversalSerialBusUSB__Hub,
+        #[cfg(feature = "topic")]
+        Classifier::Topic__System__Hardware__UniversalSerialBusUSB__HumanInterfaceDeviceHID,
+        #[cfg(feature = "topic")]
+        Classifier::Topic__System__Hardware__UniversalSerialBusUSB__MassStorage,
+        #[cfg(feature = "topic")]
+        Classifier::Topic__System__Hardware__UniversalSerialBusUSB__Miscellaneous,
+        #[cfg(feature = "topic")]
+        Classifier::Topic__System__Hardware__UniversalSerialBusUSB__Printer,
+        #[cfg(feature = "topic")]
+        Classifier::Topic__System__Hardware__UniversalSerialBusUSB__SmartCard,
+        #[cfg(feature = "topic")]
+        Classifier::Topic__System__Hardware__UniversalSerialBusUSB__Vendor,
+        #[cfg(feature = "topic")]
+        Classifier::Topic__System__Hardware__UniversalSerialBusUSB__VideoUVC,
+        #[cfg(feature = "topic")]
+        Classifier::Topic__System__Hardware__UniversalSerialBusUSB__WirelessController,
+        #[cfg(feature = "topic")]
+        Classifier::Topic__System__InstallationSetup,
+        #[cfg(feature = "topic")]
+        Classifier::Topic__System__Logging,
+        #[cfg(feature = "topic")]
+        Classifier::Topic__System__Monitoring,
+        #[cfg(feature = "topic")]
+        Classifier::Topic__System__Networking,
+        #[cfg(feature = "topic")]
+        Classifier::Topic__System__Networking__Firewalls,
+        #[cfg(feature = "topic")]
+        Classifier::Topic__System__Networking__Monitoring,
+        #[cfg(feature = "topic")]
+        Classifier::Topic__System__Networking__Monitoring__HardwareWatchdog,
+        #[cfg(feature = "topic")]
+        Classifier::Topic__System__Networking__TimeSynchronization,
+        #[cfg(feature = "topic")]
+        Classifier::Topic__System__OperatingSystem,
+        #[cfg(feature = "topic")]
+        Classifier::Topic__System__OperatingSystemKernels,
+        #[cfg(feature = "topic")]
+        Classifier::Topic__System__OperatingSystemKernels__BSD,
+        #[cfg(feature = "topic")]
+        Classifier::Topic__System__OperatingSystemKernels__GNUHurd,
+        #[cfg(feature = "topic")]
+        Classifier::Topic__System__OperatingSystemKernels__Linux,
+        #[cfg(feature = "topic")]
+        Classifier::Topic__System__PowerUPS,
+        #[cfg(feature = "topic")]
+        Classifier::Topic__System__RecoveryTools,
+        #[cfg(feature = "topic")]
+        Classifier::Topic__System__Shells,
+        #[cfg(feature = "topic")]
+        Classifier::Topic__System__SoftwareDistribution,
+        #[cfg(feature = "topic")]
+        Classifier::Topic__System__SystemShells,
+        #[cfg(feature = "topic")]
+        Classifier::Topic__System__SystemsAdministration,
+        #[cfg(feature = "topic")]
+        Classifier::Topic__System__SystemsAdministration__AuthenticationDirectory,
+        #[cfg(feature = "topic")]
+        Classifier::Topic__System__SystemsAdministration__AuthenticationDirectory__LDAP,
+        #[cfg(feature = "topic")]
+        Classifier::Topic__System__SystemsAdministration__AuthenticationDirectory__NIS,
+        #[cfg(feature = "topic")]
+        Classifier::Topic__Terminals,
+        #[cfg(feature = "topic")]
+        Classifier::Topic__Terminals__Serial,
+        #[cfg(feature = "topic")]
+        Classifier::Topic__Terminals__Telnet,
+        #[cfg(feature = "topic")]
+        Classifier::Topic__Terminals__TerminalEmulatorsXTerminals,
+        #[cfg(feature = "topic")]
+        Classifier::Topic__TextEditors,
+        #[cfg(feature = "topic")]
+        Classifier::Topic__TextEditors__Documentation,
+        #[cfg(feature = "topic")]
+        Classifier::Topic__TextEditors__Emacs,
+        #[cfg(feature = "topic")]
+        Classifier::Topic__TextEditors__IntegratedDevelopmentEnvironmentsIDE,
+        #[cfg(feature = "topic")]
+        Classifier::Topic__TextEditors__TextProcessing,
+        #[cfg(feature = "topic")]
+        Classifier::Topic__TextEditors__WordProcessors,
+        #[cfg(feature = "topic")]
+        Classifier::Topic__TextProcessing,
+        #[cfg(feature = "topic")]
+        Classifier::Topic__TextProcessing__Filters,
+        #[cfg(feature = "topic")]
+        Classifier::Topic__TextProcessing__Fonts,
+        #[cfg(feature = "topic")]
+        Classifier::Topic__TextProcessing__General,
+        #[cfg(feature = "topic")]
+        Classifier::Topic__TextProcessing__Indexing,
+        #[cfg(feature = "topic")]
+        Classifier::Topic__TextProcessing__Linguistic,
+        #[cfg(feature = "topic")]
+        Classifier::Topic__TextProcessing__Markup,
+        #[cfg(feature = "topic")]
+        Classifier::Topic__TextProcessing__Markup__HTML,
+        #[cfg(feature = "topic")]
+        Classifier::Topic__TextProcessing__Markup__LaTeX,
+        #[cfg(feature = "topic")]
+        Classifier::Topic__TextProcessing__Markup__Markdown,
+        #[cfg(feature = "topic")]
+        Classifier::Topic__TextProcessing__Markup__SGML,
+        #[cfg(feature = "topic")]
+        Classifier::Topic__TextProcessing__Markup__VRML,
+        #[cfg(feature = "topic")]
+        Classifier::Topic__TextProcessing__Markup__XML,
+        #[cfg(feature = "topic")]
+        Classifier::Topic__TextProcessing__Markup__reStructuredText,
+        #[cfg(feature = "topic")]
+        Classifier::Topic__Utilities,
+        #[cfg(feature = "typing")]
+        Classifier::Typing__StubsOnly,
+        #[cfg(feature = "typing")]
+        Classifier::Typing__Typed,
+    ];
+}
+
+/// A top-level category of classifiers
+///
+/// Every category of the dataset has a variant, including those compiled out by features. The
+/// number of classifiers in each is generated with the dataset, so tables and bitsets can be
+/// sized at compile time.
+///
+/// # Examples
+///
+/// ```
+/// use trove_classifiers::{Category, DATASET_LEN};
+///
+/// // one bit per framework classifier
+/// type Frameworks = [u64; Category::Framework.len().div_ceil(64)];
+/// assert_eq!(core::mem::size_of::<Frameworks>(), 8 * 3);
+///
+/// let total: usize = Category::ALL.iter().map(Category::len).sum();
+/// assert_eq!(total, DATASET_LEN);
+/// ```
+#[derive(Clone, Copy, Debug, Eq, Hash, Ord, PartialEq, PartialOrd)]
+pub enum Category {
+    DevelopmentStatus,
+    Environment,
+    Framework,
+    IntendedAudience,
+    License,
+    NaturalLanguage,
+    OperatingSystem,
+    ProgrammingLanguage,
+    Topic,
+    Typing,
+}
+
+impl Category {
+    /// Every category, in canonical order
+    pub const ALL: &'static [Category] = &[
+        Category::DevelopmentStatus,
+        Category::Environment,
+        Category::Framework,
+        Category::IntendedAudience,
+        Category::License,
+        Category::NaturalLanguage,
+        Category::OperatingSystem,
+        Category::ProgrammingLanguage,
+        Category::Topic,
+        Category::Typing,
+    ];
+
+    /// The name of this category, which is the first segment of its classifiers
+    pub const fn name(&self) -> &'static str {
+        match self {
+            Category::DevelopmentStatus => "Development Status",
+            Category::Environment => "Environment",
+            Category::Framework => "Framework",
+            Category::IntendedAudience => "Intended Audience",
+            Category::License => "License",
+            Category::NaturalLanguage => "Natural Language",
+            Category::OperatingSystem => "Operating System",
+            Category::ProgrammingLanguage => "Programming Language",
+            Category::Topic => "Topic",
+            Category::Typing => "Typing",
+        }
+    }
+
+    /// The number of classifiers in this category, including any compiled out by features
+    #[allow(clippy::len_without_is_empty)]
+    pub const fn len(&self) -> usize {
+        match self {
+            Category::DevelopmentStatus => 7,
+            Category::Environment => 71,
+            Category::Framework => 168,
+            Category::IntendedAudience => 14,
+            Category::License => 89,
+            Category::NaturalLanguage => 61,
+            Category::OperatingSystem => 43,
+            Category::ProgrammingLanguage => 93,
+            Category::Topic => 317,
+            Category::Typing => 2,
+        }
+    }
+}
+
+/// The number of classifiers in the dataset, including any compiled out by features
+pub const DATASET_LEN: usize = 865;
+
+/// The stable codes of classifiers no longer in the dataset, which are never reassigned
+#[cfg(feature = "alloc")]
+pub(crate) static RETIRED_CODES: &[u16] = &[];
+
+/// Every enabled category, in canonical order
+pub(crate) static CATEGORIES: &[&CategoryTable] = &[
+    #[cfg(feature = "development-status")]
+    &development_status::TABLE,
+    #[cfg(feature = "environment")]
+    &environment::TABLE,
+    #[cfg(feature = "framework")]
+    &framework::TABLE,
+    #[cfg(feature = "intended-audience")]
+    &intended_audience::TABLE,
+    #[cfg(feature = "license")]
+    &license::TABLE,
+    #[cfg(feature = "natural-language")]
+    &natural_language::TABLE,
+    #[cfg(feature = "operating-system")]
+    &operating_system::TABLE,
+    #[cfg(feature = "programming-language")]
+    &programming_language::TABLE,
+    #[cfg(feature = "topic")]
+    &topic::TABLE,
+    #[cfg(feature = "typing")]
+    &typing::TABLE,
+];
+
+/// The category table holding a classifier and its index within that table
+pub(crate) fn locate(classifier: Classifier) -> (&'static CategoryTable, usize) {
+    let discriminant = classifier as usize;
+    match discriminant {
+        #[cfg(feature = "development-status")]
+        0..=6 => (&development_status::TABLE, discriminant),
+        #[cfg(feature = "environment")]
+        7..=77 => (&environment::TABLE, discriminant - 7),
+        #[cfg(feature = "framework")]
+        78..=245 => (&framework::TABLE, discriminant - 78),
+        #[cfg(feature = "intended-audience")]
+        246..=259 => (&intended_audience::TABLE, discriminant - 246),
+        #[cfg(feature = "license")]
+        260..=348 => (&license::TABLE, discriminant - 260),
+        #[cfg(feature = "natural-language")]
+        349..=409 => (&natural_language::TABLE, discriminant - 349),
+        #[cfg(feature = "operating-system")]
+        410..=452 => (&operating_system::TABLE, discriminant - 410),
+        #[cfg(feature = "programming-language")]
+        453..=545 => (&programming_language::TABLE, discriminant - 453),
+        #[cfg(feature = "topic")]
+        546..=862 => (&topic::TABLE, discriminant - 546),
+        #[cfg(feature = "typing")]
+        863..=864 => (&typing::TABLE, discriminant - 863),
+        _ => unreachable!("every discriminant is within a category"),
+    }
+}
+
+/// The segments of a classifier's canonical string
+pub(crate) fn segments(classifier: Classifier) -> &'static [&'static str] {
+    let discriminant = classifier as usize;
+    match discriminant {
+        #[cfg(feature = "development-status")]
+        0..=6 => development_status::SEGMENTS[discriminant],
+        #[cfg(feature = "environment")]
+        7..=77 => environment::SEGMENTS[discriminant - 7],
+        #[cfg(feature = "framework")]
+        78..=245 => framework::SEGMENTS[discriminant - 78],
+        #[cfg(feature = "intended-audience")]
+        246..=259 => intended_audience::SEGMENTS[discriminant - 246],
+        #[cfg(feature = "license")]
+        260..=348 => license::SEGMENTS[discriminant - 260],
+        #[cfg(feature = "natural-language")]
+        349..=409 => natural_language::SEGMENTS[discriminant - 349],
+        #[cfg(feature = "operating-system")]
+        410..=452 => operating_system::SEGMENTS[discriminant - 410],
+        #[cfg(feature = "programming-language")]
+        453..=545 => programming_language::SEGMENTS[discriminant - 453],
+        #[cfg(feature = "topic")]
+        546..=862 => topic::SEGMENTS[discriminant - 546],
+        #[cfg(feature = "typing")]
+        863..=864 => typing::SEGMENTS[discriminant - 863],
+        _ => unreachable!("every discriminant is within a category"),
+    }
+}
+
+/// The name of a classifier's variant
+pub(crate) fn variant_name(classifier: Classifier) -> &'static str {
+    let discriminant = classifier as usize;
+    match discriminant {
+        #[cfg(feature = "development-status")]
+        0..=6 => development_status::VARIANTS[discriminant],
+        #[cfg(feature = "environment")]
+        7..=77 => environment::VARIANTS[discriminant - 7],
+        #[cfg(feature = "framework")]
+        78..=245 => framework::VARIANTS[discriminant - 78],
+        #[cfg(feature = "intended-audience")]
+        246..=259 => intended_audience::VARIANTS[discriminant - 246],
+        #[cfg(feature = "license")]
+        260..=348 => license::VARIANTS[discriminant - 260],
+        #[cfg(feature = "natural-language")]
+        349..=409 => natural_language::VARIANTS[discriminant - 349],
+        #[cfg(feature = "operating-system")]
+        410..=452 => operating_system::VARIANTS[discriminant - 410],
+        #[cfg(feature = "programming-language")]
+        453..=545 => programming_language::VARIANTS[discriminant - 453],
+        #[cfg(feature = "topic")]
+        546..=862 => topic::VARIANTS[discriminant - 546],
+        #[cfg(feature = "typing")]
+        863..=864 => typing::VARIANTS[discriminant - 863],
+        _ => unreachable!("every discriminant is within a category"),
+    }
+}
+
+/// The category of a classifier
+pub(crate) fn category(classifier: Classifier) -> Category {
+    let discriminant = classifier as usize;
+    match discriminant {
+        #[cfg(feature = "development-status")]
+        0..=6 => Category::DevelopmentStatus,
+        #[cfg(feature = "environment")]
+        7..=77 => Category::Environment,
+        #[cfg(feature = "framework")]
+        78..=245 => Category::Framework,
+        #[cfg(feature = "intended-audience")]
+        246..=259 => Category::IntendedAudience,
+        #[cfg(feature = "license")]
+        260..=348 => Category::License,
+        #[cfg(feature = "natural-language")]
+        349..=409 => Category::NaturalLanguage,
+        #[cfg(feature = "operating-system")]
+        410..=452 => Category::OperatingSystem,
+        #[cfg(feature = "programming-language")]
+        453..=545 => Category::ProgrammingLanguage,
+        #[cfg(feature = "topic")]
+        546..=862 => Category::Topic,
+        #[cfg(feature = "typing")]
+        863..=864 => Category::Typing,
+        _ => unreachable!("every discriminant is within a category"),
+    }
+}
@@ -0,0 +1,279 @@
+// @generated by `cargo xtask codegen` from data/, do not edit by hand.
+
+use crate::table::CategoryTable;
+use crate::Classifier;
+
+pub(super) static TABLE: CategoryTable = CategoryTable {
+    name: "Natural Language",
+    classifiers: &[
+        Classifier::NaturalLanguage__Afrikaans,
+        Classifier::NaturalLanguage__Arabic,
+        Classifier::NaturalLanguage__Basque,
+        Classifier::NaturalLanguage__Bengali,
+        Classifier::NaturalLanguage__Bosnian,
+        Classifier::NaturalLanguage__Bulgarian,
+        Classifier::NaturalLanguage__Cantonese,
+        Classifier::NaturalLanguage__Catalan,
+        Classifier::NaturalLanguage__CatalanValencian,
+        Classifier::NaturalLanguage__ChineseSimplified,
+        Classifier::NaturalLanguage__ChineseTraditional,
+        Classifier::NaturalLanguage__Croatian,
+        Classifier::NaturalLanguage__Czech,
+        Classifier::NaturalLanguage__Danish,
+        Classifier::NaturalLanguage__Dutch,
+        Classifier::NaturalLanguage__English,
+        Classifier::NaturalLanguage__Esperanto,
+        Classifier::NaturalLanguage__Finnish,
+        Classifier::NaturalLanguage__French,
+        Classifier::NaturalLanguage__Galician,
+        Classifier::NaturalLanguage__Georgian,
+        Classifier::NaturalLanguage__German,
+        Classifier::NaturalLanguage__Greek,
+        Classifier::NaturalLanguage__Hebrew,
+        Classifier::NaturalLanguage__Hindi,
+        Classifier::NaturalLanguage__Hungarian,
+        Classifier::NaturalLanguage__Icelandic,
+        Classifier::NaturalLanguage__Indonesian,
+        Classifier::NaturalLanguage__Irish,
+        Classifier::NaturalLanguage__Italian,
+        Classifier::NaturalLanguage__Japanese,
+        Classifier::NaturalLanguage__Javanese,
+        Classifier::NaturalLanguage__Korean,
+        Classifier::NaturalLanguage__Latin,
+        Classifier::NaturalLanguage__Latvian,
+        Classifier::NaturalLanguage__Lithuanian,
+        Classifier::NaturalLanguage__Macedonian,
+        Classifier::NaturalLanguage__Malay,
+        Classifier::NaturalLanguage__Marathi,
+        Classifier::NaturalLanguage__Nepali,
+        Classifier::NaturalLanguage__Norwegian,
+        Classifier::NaturalLanguage__Panjabi,
+        Classifier::NaturalLanguage__Persian,
+        Classifier::NaturalLanguage__Polish,
+        Classifier::NaturalLanguage__Portuguese,
+        Classifier::NaturalLanguage__PortugueseBrazilian,
+        Classifier::NaturalLanguage__Romanian,
+        Classifier::NaturalLanguage__Russian,
+        Classifier::NaturalLanguage__Serbian,
+        Classifier::NaturalLanguage__Slovak,
+        Classifier::NaturalLanguage__Slovenian,
+        Classifier::NaturalLanguage__Spanish,
+        Classifier::NaturalLanguage__Swedish,
+        Classifier::NaturalLanguage__Tamil,
+        Classifier::NaturalLanguage__Telugu,
+        Classifier::NaturalLanguage__Thai,
+        Classifier::NaturalLanguage__Tibetan,
+        Classifier::NaturalLanguage__Turkish,
+        Classifier::NaturalLanguage__Ukrainian,
+        Classifier::NaturalLanguage__Urdu,
+        Classifier::NaturalLanguage__Vietnamese,
+    ],
+    text: concat!(
+        "Natural Language :: Afrikaans",
+        "Natural Language :: Arabic",
+        "Natural Language :: Basque",
+        "Natural Language :: Bengali",
+        "Natural Language :: Bosnian",
+        "Natural Language :: Bulgarian",
+        "Natural Language :: Cantonese",
+        "Natural Language :: Catalan",
+        "Natural Language :: Catalan (Valencian)",
+        "Natural Language :: Chinese (Simplified)",
+        "Natural Language :: Chinese (Traditional)",
+        "Natural Language :: Croatian",
+        "Natural Language :: Czech",
+        "Natural Language :: Danish",
+        "Natural Language :: Dutch",
+        "Natural Language :: English",
+        "Natural Language :: Esperanto",
+        "Natural Language :: Finnish",
+        "Natural Language :: French",
+        "Natural Language :: Galician",
+        "Natural Language :: Georgian",
+        "Natural Language :: German",
+        "Natural Language :: Greek",
+        "Natural Language :: Hebrew",
+        "Natural Language :: Hindi",
+        "Natural Language :: Hungarian",
+        "Natural Language :: Icelandic",
+        "Natural Language :: Indonesian",
+        "Natural Language :: Irish",
+        "Natural Language :: Italian",
+        "Natural Language :: Japanese",
+        "Natural Language :: Javanese",
+        "Natural Language :: Korean",
+        "Natural Language :: Latin",
+        "Natural Language :: Latvian",
+        "Natural Language :: Lithuanian",
+        "Natural Language :: Macedonian",
+        "Natural Language :: Malay",
+        "Natural Language :: Marathi",
+        "Natural Language :: Nepali",
+        "Natural Language :: Norwegian",
+        "Natural Language :: Panjabi",
+        "Natural Language :: Persian",
+        "Natural Language :: Polish",
+        "Natural Language :: Portuguese",
+        "Natural Language :: Portuguese (Brazilian)",
+        "Natural Language :: Romanian",
+        "Natural Language :: Russian",
+        "Natural Language :: Serbian",
+        "Natural Language :: Slovak",
+        "Natural Language :: Slovenian",
+        "Natural Language :: Spanish",
+        "Natural Language :: Swedish",
+        "Natural Language :: Tamil",
+        "Natural Language :: Telugu",
+        "Natural Language :: Thai",
+        "Natural Language :: Tibetan",
+        "Natural Language :: Turkish",
+        "Natural Language :: Ukrainian",
+        "Natural Language :: Urdu",
+        "Natural Language :: Vietnamese",
+    ),
+    ends: &[
+        29, 55, 81, 108, 135, 164, 193, 220, 259, 299, 340, 368, 393, 419, 444, 471, 500, 527, 553,
+        581, 609, 635, 660, 686, 711, 740, 769, 799, 824, 851, 879, 907, 933, 958, 985, 1015, 1045,
+        1070, 1097, 1123, 1152, 1179, 1206, 1232, 1262, 1304, 1332, 1359, 1386, 1412, 1441, 1468,
+        1495, 1520, 1546, 1570, 1597, 1624, 1653, 1677, 1707,
+    ],
+    sorted: &[
+        0, 1, 2, 3, 4, 5, 6, 7, 8, 9, 10, 11, 12, 13, 14, 15, 16, 17, 18, 19, 20, 21, 22, 23, 24,
+        25, 26, 27, 28, 29, 30, 31, 32, 33, 34, 35, 36, 37, 38, 39, 40, 41, 42, 43, 44, 45, 46, 47,
+        48, 49, 50, 51, 52, 53, 54, 55, 56, 57, 58, 59, 60,
+    ],
+    codes: &[
+        350, 351, 352, 353, 354, 355, 356, 357, 358, 359, 360, 361, 362, 363, 364, 365, 366, 367,
+        368, 369, 370, 371, 372, 373, 374, 375, 376, 377, 378, 379, 380, 381, 382, 383, 384, 385,
+        386, 387, 388, 389, 390, 391, 392, 393, 394, 395, 396, 397, 398, 399, 400, 401, 402, 403,
+        404, 405, 406, 407, 408, 409, 410,
+    ],
+};
+
+pub(super) static SEGMENTS: [&[&str]; 61] = [
+    &["Natural Language", "Afrikaans"],
+    &["Natural Language", "Arabic"],
+    &["Natural Language", "Basque"],
+    &["Natural Language", "Bengali"],
+    &["Natural Language", "Bosnian"],
+    &["Natural Language", "Bulgarian"],
+    &["Natural Language", "Cantonese"],
+    &["Natural Language", "Catalan"],
+    &["Natural Language", "Catalan (Valencian)"],
+    &["Natural Language", "Chinese (Simplified)"],
+    &["Natural Language", "Chinese (Traditional)"],
+    &["Natural Language", "Croatian"],
+    &["Natural Language", "Czech"],
+    &["Natural Language", "Danish"],
+    &["Natural Language", "Dutch"],
+    &["Natural Language", "English"],
+    &["Natural Language", "Esperanto"],
+    &["Natural Language", "Finnish"],
+    &["Natural Language", "French"],
+    &["Natural Language", "Galician"],
+    &["Natural Language", "Georgian"],
+    &["Natural Language", "German"],
+    &["Natural Language", "Greek"],
+    &["Natural Language", "Hebrew"],
+    &["Natural Language", "Hindi"],
+    &["Natural Language", "Hungarian"],
+    &["Natural Language", "Icelandic"],
+    &["Natural Language", "Indonesian"],
+    &["Natural Language", "Irish"],
+    &["Natural Language", "Italian"],
+    &["Natural Language", "Japanese"],
+    &["Natural Language", "Javanese"],
+    &["Natural Language", "Korean"],
+    &["Natural Language", "Latin"],
+    &["Natural Language", "Latvian"],
+    &["Natural Language", "Lithuanian"],
+    &["Natural Language", "Macedonian"],
+    &["Natural Language", "Malay"],
+    &["Natural Language", "Marathi"],
+    &["Natural Language", "Nepali"],
+    &["Natural Language", "Norwegian"],
+    &["Natural Language", "Panjabi"],
+    &["Natural Language", "Persian"],
+    &["Natural Language", "Polish"],
+    &["Natural Language", "Portuguese"],
+    &["Natural Language", "Portuguese (Brazilian)"],
+    &["Natural Language", "Romanian"],
+    &["Natural Language", "Russian"],
+    &["Natural Language", "Serbian"],
+    &["Natural Language", "Slovak"],
+    &["Natural Language", "Slovenian"],
+    &["Natural Language", "Spanish"],
+    &["Natural Language", "Swedish"],
+    &["Natural Language", "Tamil"],
+    &["Natural Language", "Telugu"],
+    &["Natural Language", "Thai"],
+    &["Natural Language", "Tibetan"],
+    &["Natural Language", "Turkish"],
+    &["Natural Language", "Ukrainian"],
+    &["Natural Language", "Urdu"],
+    &["Natural Language", "Vietnamese"],
+];
+
+pub(super) static VARIANTS: [&str; 61] = [
+    "NaturalLanguage__Afrikaans",
+    "NaturalLanguage__Arabic",
+    "NaturalLanguage__Basque",
+    "NaturalLanguage__Bengali",
+    "NaturalLanguage__Bosnian",
+    "NaturalLanguage__Bulgarian",
+    "NaturalLanguage__Cantonese",
+    "NaturalLanguage__Catalan",
+    "NaturalLanguage__CatalanValencian",
+    "NaturalLanguage__ChineseSimplified",
+    "NaturalLanguage__ChineseTraditional",
+    "NaturalLanguage__Croatian",
+    "NaturalLanguage__Czech",
+    "NaturalLanguage__Danish",
+    "NaturalLanguage__Dutch",
+    "NaturalLanguage__English",
+    "NaturalLanguage__Esperanto",
+    "NaturalLanguage__Finnish",
+    "NaturalLanguage__French",
+    "NaturalLanguage__Galician",
+    "NaturalLanguage__Georgian",
+    "NaturalLanguage__German",
+    "NaturalLanguage__Greek",
+    "NaturalLanguage__Hebrew",
+    "NaturalLanguage__Hindi",
+    "NaturalLanguage__Hungarian",
+    "NaturalLanguage__Icelandic",
+    "NaturalLanguage__Indonesian",
+    "NaturalLanguage__Irish",
+    "NaturalLanguage__Italian",
+    "NaturalLanguage__Japanese",
+    "NaturalLanguage__Javanese",
+    "NaturalLanguage__Korean",
+    "NaturalLanguage__Latin",
+    "NaturalLanguage__Latvian",
+    "NaturalLanguage__Lithuanian",
+    "NaturalLanguage__Macedonian",
+    "NaturalLanguage__Malay",
+    "NaturalLanguage__Marathi",
+    "NaturalLanguage__Nepali",
+    "NaturalLanguage__Norwegian",
+    "NaturalLanguage__Panjabi",
+    "NaturalLanguage__Persian",
+    "NaturalLanguage__Polish",
+    "NaturalLanguage__Portuguese",
+    "NaturalLanguage__PortugueseBrazilian",
+    "NaturalLanguage__Romanian",
+    "NaturalLanguage__Russian",
+    "NaturalLanguage__Serbian",
+    "NaturalLanguage__Slovak",
+    "NaturalLanguage__Slovenian",
+    "NaturalLanguage__Spanish",
+    "NaturalLanguage__Swedish",
+    "NaturalLanguage__Tamil",
+    "NaturalLanguage__Telugu",
+    "NaturalLanguage__Thai",
+    "NaturalLanguage__Tibetan",
+    "NaturalLanguage__Turkish",
+    "NaturalLanguage__Ukrainian",
+    "NaturalLanguage__Urdu",
+    "NaturalLanguage__Vietnamese",
+];
@@ -0,0 +1,229 @@
+// @generated by `cargo xtask codegen` from data/, do not edit by hand.
+
+use crate::table::CategoryTable;
+use crate::Classifier;
+
+pub(super) static TABLE: CategoryTable = CategoryTable {
+    name: "Operating System",
+    classifiers: &[
+        Classifier::OperatingSystem__Android,
+        Classifier::OperatingSystem__BeOS,
+        Classifier::OperatingSystem__MacOS,
+        Classifier::OperatingSystem__MacOS__MacOS9,
+        Classifier::OperatingSystem__MacOS__MacOSX,
+        Classifier::OperatingSystem__Microsoft,
+        Classifier::OperatingSystem__Microsoft__MSDOS,
+        Classifier::OperatingSystem__Microsoft__Windows,
+        Classifier::OperatingSystem__Microsoft__Windows__Windows3_1orEarlier,
+        Classifier::OperatingSystem__Microsoft__Windows__Windows7,
+        Classifier::OperatingSystem__Microsoft__Windows__Windows8,
+        Classifier::OperatingSystem__Microsoft__Windows__Windows8_1,
+        Classifier::OperatingSystem__Microsoft__Windows__Windows10,
+        Classifier::OperatingSystem__Microsoft__Windows__Windows11,
+        Classifier::OperatingSystem__Microsoft__Windows__Windows95982000,
+        Classifier::OperatingSystem__Microsoft__Windows__WindowsCE,
+        Classifier::OperatingSystem__Microsoft__Windows__WindowsNT2000,
+        Classifier::OperatingSystem__Microsoft__Windows__WindowsServer2003,
+        Classifier::OperatingSystem__Microsoft__Windows__WindowsServer2008,
+        Classifier::OperatingSystem__Microsoft__Windows__WindowsVista,
+        Classifier::OperatingSystem__Microsoft__Windows__WindowsXP,
+        Classifier::OperatingSystem__OSIndependent,
+        Classifier::OperatingSystem__OS2,
+        Classifier::OperatingSystem__OtherOS,
+        Classifier::OperatingSystem__PDASystems,
+        Classifier::OperatingSystem__POSIX,
+        Classifier::OperatingSystem__POSIX__AIX,
+        Classifier::OperatingSystem__POSIX__BSD,
+        Classifier::OperatingSystem__POSIX__BSD__BSDOS,
+        Classifier::OperatingSystem__POSIX__BSD__FreeBSD,
+        Classifier::OperatingSystem__POSIX__BSD__NetBSD,
+        Classifier::OperatingSystem__POSIX__BSD__OpenBSD,
+        Classifier::OperatingSystem__POSIX__GNUHurd,
+        Classifier::OperatingSystem__POSIX__HPUX,
+        Classifier::OperatingSystem__POSIX__IRIX,
+        Classifier::OperatingSystem__POSIX__Linux,
+        Classifier::OperatingSystem__POSIX__Other,
+        Classifier::OperatingSystem__POSIX__SCO,
+        Classifier::OperatingSystem__POSIX__SunOSSolaris,
+        Classifier::OperatingSystem__PalmOS,
+        Classifier::OperatingSystem__RISCOS,
+        Classifier::OperatingSystem__Unix,
+        Classifier::OperatingSystem__iOS,
+    ],
+    text: concat!(
+        "Operating System :: Android",
+        "Operating System :: BeOS",
+        "Operating System :: MacOS",
+        "Operating System :: MacOS :: MacOS 9",
+        "Operating System :: MacOS :: MacOS X",
+        "Operating System :: Microsoft",
+        "Operating System :: Microsoft :: MS-DOS",
+        "Operating System :: Microsoft :: Windows",
+        "Operating System :: Microsoft :: Windows :: Windows 3.1 or Earlier",
+        "Operating System :: Microsoft :: Windows :: Windows 7",
+        "Operating System :: Microsoft :: Windows :: Windows 8",
+        "Operating System :: Microsoft :: Windows :: Windows 8.1",
+        "Operating System :: Microsoft :: Windows :: Windows 10",
+        "Operating System :: Microsoft :: Windows :: Windows 11",
+        "Operating System :: Microsoft :: Windows :: Windows 95/98/2000",
+        "Operating System :: Microsoft :: Windows :: Windows CE",
+        "Operating System :: Microsoft :: Windows :: Windows NT/2000",
+        "Operating System :: Microsoft :: Windows :: Windows Server 2003",
+        "Operating System :: Microsoft :: Windows :: Windows Server 2008",
+        "Operating System :: Microsoft :: Windows :: Windows Vista",
+        "Operating System :: Microsoft :: Windows :: Windows XP",
+        "Operating System :: OS Independent",
+        "Operating System :: OS/2",
+        "Operating System :: Other OS",
+        "Operating System :: PDA Systems",
+        "Operating System :: POSIX",
+        "Operating System :: POSIX :: AIX",
+        "Operating System :: POSIX :: BSD",
+        "Operating System :: POSIX :: BSD :: BSD/OS",
+        "Operating System :: POSIX :: BSD :: FreeBSD",
+        "Operating System :: POSIX :: BSD :: NetBSD",
+        "Operating System :: POSIX :: BSD :: OpenBSD",
+        "Operating System :: POSIX :: GNU Hurd",
+        "Operating System :: POSIX :: HP-UX",
+        "Operating System :: POSIX :: IRIX",
+        "Operating System :: POSIX :: Linux",
+        "Operating System :: POSIX :: Other",
+        "Operating System :: POSIX :: SCO",
+        "Operating System :: POSIX :: SunOS/Solaris",
+        "Operating System :: PalmOS",
+        "Operating System :: RISC OS",
+        "Operating System :: Unix",
+        "Operating System :: iOS",
+    ),
+    ends: &[
+        27, 51, 76, 112, 148, 177, 216, 256, 322, 375, 428, 483, 537, 591, 653, 707, 766, 829, 892,
+        949, 1003, 1037, 1061, 1089, 1120, 1145, 1177, 1209, 1251, 1294, 1336, 1379, 1416, 1450,
+        1483, 1517, 1551, 1583, 1625, 1651, 1678, 1702, 1725,
+    ],
+    sorted: &[
+        0, 1, 42, 2, 3, 4, 5, 6, 7, 12, 13, 8, 9, 10, 11, 14, 15, 16, 17, 18, 19, 20, 21, 22, 23,
+        39, 24, 25, 26, 27, 28, 29, 30, 31, 32, 33, 34, 35, 36, 37, 38, 40, 41,
+    ],
+    codes: &[
+        411, 412, 413, 414, 415, 416, 417, 418, 419, 420, 421, 422, 423, 424, 425, 426, 427, 428,
+        429, 430, 431, 432, 433, 434, 435, 436, 437, 438, 439, 440, 441, 442, 443, 444, 445, 446,
+        447, 448, 449, 450, 451, 452, 453,
+    ],
+};
+
+pub(super) static SEGMENTS: [&[&str]; 43] = [
+    &["Operating System", "Android"],
+    &["Operating System", "BeOS"],
+    &["Operating System", "MacOS"],
+    &["Operating System", "MacOS", "MacOS 9"],
+    &["Operating System", "MacOS", "MacOS X"],
+    &["Operating System", "Microsoft"],
+    &["Operating System", "Microsoft", "MS-DOS"],
+    &["Operating System", "Microsoft", "Windows"],
+    &[
+        "Operating System",
+        "Microsoft",
+        "Windows",
+        "Windows 3.1 or Earlier",
+    ],
+    &["Operating System", "Microsoft", "Windows", "Windows 7"],
+    &["Operating System", "Microsoft", "Windows", "Windows 8"],
+    &["Operating System", "Microsoft", "Windows", "Windows 8.1"],
+    &["Operating System", "Microsoft", "Windows", "Windows 10"],
+    &["Operating System", "Microsoft", "Windows", "Windows 11"],
+    &[
+        "Operating System",
+        "Microsoft",
+        "Windows",
+        "Windows 95/98/2000",
+    ],
+    &["Operating System", "Microsoft", "Windows", "Windows CE"],
+    &[
+        "Operating System",
+        "Microsoft",
+        "Windows",
+        "Windows NT/2000",
+    ],
+    &[
+        "Operating System",
+        "Microsoft",
+        "Windows",
+        "Windows Server 2003",
+    ],
+    &[
+        "Operating System",
+        "Microsoft",
+        "Windows",
+        "Windows Server 2008",
+    ],
+    &["Operating System", "Microsoft", "Windows", "Windows Vista"],
+    &["Operating System", "Microsoft", "Windows", "Windows XP"],
+    &["Operating System", "OS Independent"],
+    &["Operating System", "OS/2"],
+    &["Operating System", "Other OS"],
+    &["Operating System", "PDA Systems"],
+    &["Operating System", "POSIX"],
+    &["Operating System", "POSIX", "AIX"],
+    &["Operating System", "POSIX", "BSD"],
+    &["Operating System", "POSIX", "BSD", "BSD/OS"],
+    &["Operating System", "POSIX", "BSD", "FreeBSD"],
+    &["Operating System", "POSIX", "BSD", "NetBSD"],
+    &["Operating System", "POSIX", "BSD", "OpenBSD"],
+    &["Operating System", "POSIX", "GNU Hurd"],
+    &["Operating System", "POSIX", "HP-UX"],
+    &["Operating System", "POSIX", "IRIX"],
+    &["Operating System", "POSIX", "Linux"],
+    &["Operating System", "POSIX", "Other"],
+    &["Operating System", "POSIX", "SCO"],
+    &["Operating System", "POSIX", "SunOS/Solaris"],
+    &["Operating System", "PalmOS"],
+    &["Operating System", "RISC OS"],
+    &["Operating System", "Unix"],
+    &["Operating System", "iOS"],
+];
+
+pub(super) static VARIANTS: [&str; 43] = [
+    "OperatingSystem__Android",
+    "OperatingSystem__BeOS",
+    "OperatingSystem__MacOS",
+    "OperatingSystem__MacOS__MacOS9",
+    "OperatingSystem__MacOS__MacOSX",
+    "OperatingSystem__Microsoft",
+    "OperatingSystem__Microsoft__MSDOS",
+    "OperatingSystem__Microsoft__Windows",
+    "OperatingSystem__Microsoft__Windows__Windows3_1orEarlier",
+    "OperatingSystem__Microsoft__Windows__Windows7",
+    "OperatingSystem__Microsoft__Windows__Windows8",
+    "OperatingSystem__Microsoft__Windows__Windows8_1",
+    "OperatingSystem__Microsoft__Windows__Windows10",
+    "OperatingSystem__Microsoft__Windows__Windows11",
+    "OperatingSystem__Microsoft__Windows__Windows95982000",
+    "OperatingSystem__Microsoft__Windows__WindowsCE",
+    "OperatingSystem__Microsoft__Windows__WindowsNT2000",
+    "OperatingSystem__Microsoft__Windows__WindowsServer2003",
+    "OperatingSystem__Microsoft__Windows__WindowsServer2008",
+    "OperatingSystem__Microsoft__Windows__WindowsVista",
+    "OperatingSystem__Microsoft__Windows__WindowsXP",
+    "OperatingSystem__OSIndependent",
+    "OperatingSystem__OS2",
+    "OperatingSystem__OtherOS",
+    "OperatingSystem__PDASystems",
+    "OperatingSystem__POSIX",
+    "OperatingSystem__POSIX__AIX",
+    "OperatingSystem__POSIX__BSD",
+    "OperatingSystem__POSIX__BSD__BSDOS",
+    "OperatingSystem__POSIX__BSD__FreeBSD",
+    "OperatingSystem__POSIX__BSD__NetBSD",
+    "OperatingSystem__POSIX__BSD__OpenBSD",
+    "OperatingSystem__POSIX__GNUHurd",
+    "OperatingSystem__POSIX__HPUX",
+    "OperatingSystem__POSIX__IRIX",
+    "OperatingSystem__POSIX__Linux",
+    "OperatingSystem__POSIX__Other",
+    "OperatingSystem__POSIX__SCO",
+    "OperatingSystem__POSIX__SunOSSolaris",
+    "OperatingSystem__PalmOS",
+    "OperatingSystem__RISCOS",
+    "OperatingSystem__Unix",
+    "OperatingSystem__iOS",
+];
@@ -0,0 +1,432 @@
+// @generated by `cargo xtask codegen` from data/, do not edit by hand.
+
+use crate::table::CategoryTable;
+use crate::Classifier;
+
+pub(super) static TABLE: CategoryTable = CategoryTable {
+    name: "Programming Language",
+    classifiers: &[
+        Classifier::ProgrammingLanguage__APL,
+        Classifier::ProgrammingLanguage__ASP,
+        Classifier::ProgrammingLanguage__Ada,
+        Classifier::ProgrammingLanguage__Assembly,
+        Classifier::ProgrammingLanguage__Awk,
+        Classifier::ProgrammingLanguage__Basic,
+        Classifier::ProgrammingLanguage__C,
+        Classifier::ProgrammingLanguage__Csharp,
+        Classifier::ProgrammingLanguage__Cplusplus,
+        Classifier::ProgrammingLanguage__ColdFusion,
+        Classifier::ProgrammingLanguage__Cython,
+        Classifier::ProgrammingLanguage__D,
+        Classifier::ProgrammingLanguage__DelphiKylix,
+        Classifier::ProgrammingLanguage__Dylan,
+        Classifier::ProgrammingLanguage__Eiffel,
+        Classifier::ProgrammingLanguage__EmacsLisp,
+        Classifier::ProgrammingLanguage__Erlang,
+        Classifier::ProgrammingLanguage__Euler,
+        Classifier::ProgrammingLanguage__Euphoria,
+        Classifier::ProgrammingLanguage__Fsharp,
+        Classifier::ProgrammingLanguage__Forth,
+        Classifier::ProgrammingLanguage__Fortran,
+        Classifier::ProgrammingLanguage__Go,
+        Classifier::ProgrammingLanguage__Haskell,
+        Classifier::ProgrammingLanguage__Hy,
+        Classifier::ProgrammingLanguage__Java,
+        Classifier::ProgrammingLanguage__JavaScript,
+        Classifier::ProgrammingLanguage__Kotlin,
+        Classifier::ProgrammingLanguage__Lisp,
+        Classifier::ProgrammingLanguage__Logo,
+        Classifier::ProgrammingLanguage__Lua,
+        Classifier::ProgrammingLanguage__ML,
+        Classifier::ProgrammingLanguage__Modula,
+        Classifier::ProgrammingLanguage__OCaml,
+        Classifier::ProgrammingLanguage__ObjectPascal,
+        Classifier::ProgrammingLanguage__ObjectiveC,
+        Classifier::ProgrammingLanguage__Other,
+        Classifier::ProgrammingLanguage__OtherScriptingEngines,
+        Classifier::ProgrammingLanguage__PHP,
+        Classifier::ProgrammingLanguage__PLSQL,
+        Classifier::ProgrammingLanguage__PROGRESS,
+        Classifier::ProgrammingLanguage__Pascal,
+        Classifier::ProgrammingLanguage__Perl,
+        Classifier::ProgrammingLanguage__Pike,
+        Classifier::ProgrammingLanguage__Pliant,
+        Classifier::ProgrammingLanguage__Prolog,
+        Classifier::ProgrammingLanguage__Python,
+        Classifier::ProgrammingLanguage__Python__2,
+        Classifier::ProgrammingLanguage__Python__2__Only,
+        Classifier::ProgrammingLanguage__Python__2_3,
+        Classifier::ProgrammingLanguage__Python__2_4,
+        Classifier::ProgrammingLanguage__Python__2_5,
+        Classifier::ProgrammingLanguage__Python__2_6,
+        Classifier::ProgrammingLanguage__Python__2_7,
+        Classifier::ProgrammingLanguage__Python__3,
+        Classifier::ProgrammingLanguage__Python__3__Only,
+        Classifier::ProgrammingLanguage__Python__3_0,
+        Classifier::ProgrammingLanguage__Python__3_1,
+        Classifier::ProgrammingLanguage__Python__3_2,
+        Classifier::ProgrammingLanguage__Python__3_3,
+        Classifier::ProgrammingLanguage__Python__3_4,
+        Classifier::ProgrammingLanguage__Python__3_5,
+        Classifier::ProgrammingLanguage__Python__3_6,
+        Classifier::ProgrammingLanguage__Python__3_7,
+        Classifier::ProgrammingLanguage__Python__3_8,
+        Classifier::ProgrammingLanguage__Python__3_9,
+        Classifier::ProgrammingLanguage__Python__3_10,
+        Classifier::ProgrammingLanguage__Python__3_11,
+        Classifier::ProgrammingLanguage__Python__3_12,
+        Classifier::ProgrammingLanguage__Python__3_13,
+        Classifier::ProgrammingLanguage__Python__3_14,
+        Classifier::ProgrammingLanguage__Python__Implementation,
+        Classifier::ProgrammingLanguage__Python__Implementation__CPython,
+        Classifier::ProgrammingLanguage__Python__Implementation__IronPython,
+        Classifier::ProgrammingLanguage__Python__Implementation__Jython,
+        Classifier::ProgrammingLanguage__Python__Implementation__MicroPython,
+        Classifier::ProgrammingLanguage__Python__Implementation__PyPy,
+        Classifier::ProgrammingLanguage__Python__Implementation__Stackless,
+        Classifier::ProgrammingLanguage__R,
+        Classifier::ProgrammingLanguage__REBOL,
+        Classifier::ProgrammingLanguage__Rexx,
+        Classifier::ProgrammingLanguage__Ruby,
+        Classifier::ProgrammingLanguage__Rust,
+        Classifier::ProgrammingLanguage__SQL,
+        Classifier::ProgrammingLanguage__Scheme,
+        Classifier::ProgrammingLanguage__Simula,
+        Classifier::ProgrammingLanguage__Smalltalk,
+        Classifier::ProgrammingLanguage__Tcl,
+        Classifier::ProgrammingLanguage__UnixShell,
+        Classifier::ProgrammingLanguage__VisualBasic,
+        Classifier::ProgrammingLanguage__XBasic,
+        Classifier::ProgrammingLanguage__YACC,
+        Classifier::ProgrammingLanguage__Zope,
+    ],
+    text: concat!(
+        "Programming Language :: APL",
+        "Programming Language :: ASP",
+        "Programming Language :: Ada",
+        "Programming Language :: Assembly",
+        "Programming Language :: Awk",
+        "Programming Language :: Basic",
+        "Programming Language :: C",
+        "Programming Language :: C#",
+        "Programming Language :: C++",
+        "Programming Language :: Cold Fusion",
+        "Programming Language :: Cython",
+        "Programming Language :: D",
+        "Programming Language :: Delphi/Kylix",
+        "Programming Language :: Dylan",
+        "Programming Language :: Eiffel",
+        "Programming Language :: Emacs-Lisp",
+        "Programming Language :: Erlang",
+        "Programming Language :: Euler",
+        "Programming Language :: Euphoria",
+        "Programming Language :: F#",
+        "Programming Language :: Forth",
+        "Programming Language :: Fortran",
+        "Programming Language :: Go",
+        "Programming Language :: Haskell",
+        "Programming Language :: Hy",
+        "Programming Language :: Java",
+        "Programming Language :: JavaScript",
+        "Programming Language :: Kotlin",
+        "Programming Language :: Lisp",
+        "Programming Language :: Logo",
+        "Programming Language :: Lua",
+        "Programming Language :: ML",
+        "Programming Language :: Modula",
+        "Programming Language :: OCaml",
+        "Programming Language :: Object Pascal",
+        "Programming Language :: Objective C",
+        "Programming Language :: Other",
+        "Programming Language :: Other Scripting Engines",
+        "Programming Language :: PHP",
+        "Programming Language :: PL/SQL",
+        "Programming Language :: PROGRESS",
+        "Programming Language :: Pascal",
+        "Programming Language :: Perl",
+        "Programming Language :: Pike",
+        "Programming Language :: Pliant",
+        "Programming Language :: Prolog",
+        "Programming Language :: Python",
+        "Programming Language :: Python :: 2",
+        "Programming Language :: Python :: 2 :: Only",
+        "Programming Language :: Python :: 2.3",
+        "Programming Language :: Python :: 2.4",
+        "Programming Language :: Python :: 2.5",
+        "Programming Language :: Python :: 2.6",
+        "Programming Language :: Python :: 2.7",
+        "Programming Language :: Python :: 3",
+        "Programming Language :: Python :: 3 :: Only",
+        "Programming Language :: Python :: 3.0",
+        "Programming Language :: Python :: 3.1",
+        "Programming Language :: Python :: 3.2",
+        "Programming Language :: Python :: 3.3",
+        "Programming Language :: Python :: 3.4",
+        "Programming Language :: Python :: 3.5",
+        "Programming Language :: Python :: 3.6",
+        "Programming Language :: Python :: 3.7",
+        "Programming Language :: Python :: 3.8",
+        "Programming Language :: Python :: 3.9",
+        "Programming Language :: Python :: 3.10",
+        "Programming Language :: Python :: 3.11",
+        "Programming Language :: Python :: 3.12",
+        "Programming Language :: Python :: 3.13",
+        "Programming Language :: Python :: 3.14",
+        "Programming Language :: Python :: Implementation",
+        "Programming Language :: Python :: Implementation :: CPython",
+        "Programming Language :: Python :: Implementation :: IronPython",
+        "Programming Language :: Python :: Implementation :: Jython",
+        "Programming Language :: Python :: Implementation :: MicroPython",
+        "Programming Language :: Python :: Implementation :: PyPy",
+        "Programming Language :: Python :: Implementation :: Stackless",
+        "Programming Language :: R",
+        "Programming Language :: REBOL",
+        "Programming Language :: Rexx",
+        "Programming Language :: Ruby",
+        "Programming Language :: Rust",
+        "Programming Language :: SQL",
+        "Programming Language :: Scheme",
+        "Programming Language :: Simula",
+        "Programming Language :: Smalltalk",
+        "Programming Language :: Tcl",
+        "Programming Language :: Unix Shell",
+        "Programming Language :: Visual Basic",
+        "Programming Language :: XBasic",
+        "Programming Language :: YACC",
+        "Programming Language :: Zope",
+    ),
+    ends: &[
+        27, 54, 81, 113, 140, 169, 194, 220, 247, 282, 312, 337, 373, 402, 432, 466, 496, 525, 557,
+        583, 612, 643, 669, 700, 726, 754, 788, 818, 846, 874, 901, 927, 957, 986, 1023, 1058,
+        1087, 1134, 1161, 1191, 1223, 1253, 1281, 1309, 1339, 1369, 1399, 1434, 1477, 1514, 1551,
+        1588, 1625, 1662, 1697, 1740, 1777, 1814, 1851, 1888, 1925, 1962, 1999, 2036, 2073, 2110,
+        2148, 2186, 2224, 2262, 2300, 2348, 2407, 2469, 2527, 2590, 2646, 2707, 2732, 2761, 2789,
+        2817, 2845, 2872, 2902, 2932, 2965, 2992, 3026, 3062, 3092, 3120, 3148,
+    ],
+    sorted: &[
+        2, 0, 1, 3, 4, 5, 6, 7, 8, 9, 10, 11, 12, 13, 14, 15, 16, 17, 18, 19, 20, 21, 22, 23, 24,
+        25, 26, 27, 28, 29, 30, 31, 32, 34, 35, 33, 36, 37, 41, 42, 38, 43, 39, 44, 40, 45, 46, 47,
+        48, 49, 50, 51, 52, 53, 54, 55, 56, 57, 66, 67, 68, 69, 70, 58, 59, 60, 61, 62, 63, 64, 65,
+        71, 72, 73, 74, 75, 76, 77, 78, 79, 80, 81, 82, 84, 85, 86, 83, 87, 88, 89, 90, 91, 92,
+    ],
+    codes: &[
+        454, 455, 456, 457, 458, 459, 460, 461, 462, 463, 464, 465, 466, 467, 468, 469, 470, 471,
+        472, 473, 474, 475, 476, 477, 478, 479, 480, 481, 482, 483, 484, 485, 486, 487, 488, 489,
+        490, 491, 492, 493, 494, 495, 496, 497, 498, 499, 500, 501, 502, 503, 504, 505, 506, 507,
+        508, 509, 510, 511, 512, 513, 514, 515, 516, 517, 518, 519, 520, 521, 522, 523, 524, 525,
+        526, 527, 528, 529, 530, 531, 532, 533, 534, 535, 536, 537, 538, 539, 540, 541, 542, 543,
+        544, 545, 546,
+    ],
+};
+
+pub(super) static SEGMENTS: [&[&str]; 93] = [
+    &["Programming Language", "APL"],
+    &["Programming Language", "ASP"],
+    &["Programming Language", "Ada"],
+    &["Programming Language", "Assembly"],
+    &["Programming Language", "Awk"],
+    &["Programming Language", "Basic"],
+    &["Programming Language", "C"],
+    &["Programming Language", "C#"],
+    &["Programming Language", "C++"],
+    &["Programming Language", "Cold Fusion"],
+    &["Programming Language", "Cython"],
+    &["Programming Language", "D"],
+    &["Programming Language", "Delphi/Kylix"],
+    &["Programming Language", "Dylan"],
+    &["Programming Language", "Eiffel"],
+    &["Programming Language", "Emacs-Lisp"],
+    &["Programming Language", "Erlang"],
+    &["Programming Language", "Euler"],
+    &["Programming Language", "Euphoria"],
+    &["Programming Language", "F#"],
+    &["Programming Language", "Forth"],
+    &["Programming Language", "Fortran"],
+    &["Programming Language", "Go"],
+    &["Programming Language", "Haskell"],
+    &["Programming Language", "Hy"],
+    &["Programming Language", "Java"],
+    &["Programming Language", "JavaScript"],
+    &["Programming Language", "Kotlin"],
+    &["Programming Language", "Lisp"],
+    &["Programming Language", "Logo"],
+    &["Programming Language", "Lua"],
+    &["Programming Language", "ML"],
+    &["Programming Language", "Modula"],
+    &["Programming Language", "OCaml"],
+    &["Programming Language", "Object Pascal"],
+    &["Programming Language", "Objective C"],
+    &["Programming Language", "Other"],
+    &["Programming Language", "Other Scripting Engines"],
+    &["Programming Language", "PHP"],
+    &["Programming Language", "PL/SQL"],
+    &["Programming Language", "PROGRESS"],
+    &["Programming Language", "Pascal"],
+    &["Programming Language", "Perl"],
+    &["Programming Language", "Pike"],
+    &["Programming Language", "Pliant"],
+    &["Programming Language", "Prolog"],
+    &["Programming Language", "Python"],
+    &["Programming Language", "Python", "2"],
+    &["Programming Language", "Python", "2", "Only"],
+    &["Programming Language", "Python", "2.3"],
+    &["Programming Language", "Python", "2.4"],
+    &["Programming Language", "Python", "2.5"],
+    &["Programming Language", "Python", "2.6"],
+    &["Programming Language", "Python", "2.7"],
+    &["Programming Language", "Python", "3"],
+    &["Programming Language", "Python", "3", "Only"],
+    &["Programming Language", "Python", "3.0"],
+    &["Programming Language", "Python", "3.1"],
+    &["Programming Language", "Python", "3.2"],
+    &["Programming Language", "Python", "3.3"],
+    &["Programming Language", "Python", "3.4"],
+    &["Programming Language", "Python", "3.5"],
+    &["Programming Language", "Python", "3.6"],
+    &["Programming Language", "Python", "3.7"],
+    &["Programming Language", "Python", "3.8"],
+    &["Programming Language", "Python", "3.9"],
+    &["Programming Language", "Python", "3.10"],
+    &["Programming Language", "Python", "3.11"],
+    &["Programming Language", "Python", "3.12"],
+    &["Programming Language", "Python", "3.13"],
+    &["Programming Language", "Python", "3.14"],
+    &["Programming Language", "Python", "Implementation"],
+    &[
+        "Programming Language",
+        "Python",
+        "Implementation",
+        "CPython",
+    ],
+    &[
+        "Programming Language",
+        "Python",
+        "Implementation",
+        "IronPython",
+    ],
+    &["Programming Language", "Python", "Implementation", "Jython"],
+    &[
+        "Programming Language",
+        "Python",
+        "Implementation",
+        "MicroPython",
+    ],
+    &["Programming Language", "Python", "Implementation", "PyPy"],
+    &[
+        "Programming Language",
+        "Python",
+        "Implementation",
+        "Stackless",
+    ],
+    &["Programming Language", "R"],
+    &["Programming Language", "REBOL"],
+    &["Programming Language", "Rexx"],
+    &["Programming Language", "Ruby"],
+    &["Programming Language", "Rust"],
+    &["Programming Language", "SQL"],
+    &["Programming Language", "Scheme"],
+    &["Programming Language", "Simula"],
+    &["Programming Language", "Smalltalk"],
+    &["Programming Language", "Tcl"],
+    &["Programming Language", "Unix Shell"],
+    &["Programming Language", "Visual Basic"],
+    &["Programming Language", "XBasic"],
+    &["Programming Language", "YACC"],
+    &["Programming Language", "Zope"],
+];
+
+pub(super) static VARIANTS: [&str; 93] = [
+    "ProgrammingLanguage__APL",
+    "ProgrammingLanguage__ASP",
+    "ProgrammingLanguage__Ada",
+    "ProgrammingLanguage__Assembly",
+    "ProgrammingLanguage__Awk",
+    "ProgrammingLanguage__Basic",
+    "ProgrammingLanguage__C",
+    "ProgrammingLanguage__Csharp",
+    "ProgrammingLanguage__Cplusplus",
+    "ProgrammingLanguage__ColdFusion",
+    "ProgrammingLanguage__Cython",
+    "ProgrammingLanguage__D",
+    "ProgrammingLanguage__DelphiKylix",
+    "ProgrammingLanguage__Dylan",
+    "ProgrammingLanguage__Eiffel",
+    "ProgrammingLanguage__EmacsLisp",
+    "ProgrammingLanguage__Erlang",
+    "ProgrammingLanguage__Euler",
+    "ProgrammingLanguage__Euphoria",
+    "ProgrammingLanguage__Fsharp",
+    "ProgrammingLanguage__Forth",
+    "ProgrammingLanguage__Fortran",
+    "ProgrammingLanguage__Go",
+    "ProgrammingLanguage__Haskell",
+    "ProgrammingLanguage__Hy",
+    "ProgrammingLanguage__Java",
+    "ProgrammingLanguage__JavaScript",
+    "ProgrammingLanguage__Kotlin",
+    "ProgrammingLanguage__Lisp",
+    "ProgrammingLanguage__Logo",
+    "ProgrammingLanguage__Lua",
+    "ProgrammingLanguage__ML",
+    "ProgrammingLanguage__Modula",
+    "ProgrammingLanguage__OCaml",
+    "ProgrammingLanguage__ObjectPascal",
+    "ProgrammingLanguage__ObjectiveC",
+    "ProgrammingLanguage__Other",
+    "ProgrammingLanguage__OtherScriptingEngines",
+    "ProgrammingLanguage__PHP",
+    "ProgrammingLanguage__PLSQL",
+    "ProgrammingLanguage__PROGRESS",
+    "ProgrammingLanguage__Pascal",
+    "ProgrammingLanguage__Perl",
+    "ProgrammingLanguage__Pike",
+    "ProgrammingLanguage__Pliant",
+    "ProgrammingLanguage__Prolog",
+    "ProgrammingLanguage__Python",
+    "ProgrammingLanguage__Python__2",
+    "ProgrammingLanguage__Python__2__Only",
+    "ProgrammingLanguage__Python__2_3",
+    "ProgrammingLanguage__Python__2_4",
+    "ProgrammingLanguage__Python__2_5",
+    "ProgrammingLanguage__Python__2_6",
+    "ProgrammingLanguage__Python__2_7",
+    "ProgrammingLanguage__Python__3",
+    "ProgrammingLanguage__Python__3__Only",
+    "ProgrammingLanguage__Python__3_0",
+    "ProgrammingLanguage__Python__3_1",
+    "ProgrammingLanguage__Python__3_2",
+    "ProgrammingLanguage__Python__3_3",
+    "ProgrammingLanguage__Python__3_4",
+    "ProgrammingLanguage__Python__3_5",
+    "ProgrammingLanguage__Python__3_6",
+    "ProgrammingLanguage__Python__3_7",
+    "ProgrammingLanguage__Python__3_8",
+    "ProgrammingLanguage__Python__3_9",
+    "ProgrammingLanguage__Python__3_10",
+    "ProgrammingLanguage__Python__3_11",
+    "ProgrammingLanguage__Python__3_12",
+    "ProgrammingLanguage__Python__3_13",
+    "ProgrammingLanguage__Python__3_14",
+    "ProgrammingLanguage__Python__Implementation",
+    "ProgrammingLanguage__Python__Implementation__CPython",
+    "ProgrammingLanguage__Python__Implementation__IronPython",
+    "ProgrammingLanguage__Python__Implementation__Jython",
+    "ProgrammingLanguage__Python__Implementation__MicroPython",
+    "ProgrammingLanguage__Python__Implementation__PyPy",
+    "ProgrammingLanguage__Python__Implementation__Stackless",
+    "ProgrammingLanguage__R",
+    "ProgrammingLanguage__REBOL",
+    "ProgrammingLanguage__Rexx",
+    "ProgrammingLanguage__Ruby",
+    "ProgrammingLanguage__Rust",
+    "ProgrammingLanguage__SQL",
+    "ProgrammingLanguage__Scheme",
+    "ProgrammingLanguage__Simula",
+    "ProgrammingLanguage__Smalltalk",
+    "ProgrammingLanguage__Tcl",
+    "ProgrammingLanguage__UnixShell",
+    "ProgrammingLanguage__VisualBasic",
+    "ProgrammingLanguage__XBasic",
+    "ProgrammingLanguage__YACC",
+    "ProgrammingLanguage__Zope",
+];
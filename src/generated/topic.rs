@@ -0,0 +1,2624 @@
+// @generated by `cargo xtask codegen` from data/, do not edit by hand.
+
+use crate::table::CategoryTable;
+use crate::Classifier;
+
+pub(super) static TABLE: CategoryTable = CategoryTable {
+    name: "Topic",
+    classifiers: &[
+        Classifier::Topic__AdaptiveTechnologies,
+        Classifier::Topic__ArtisticSoftware,
+        Classifier::Topic__Communications,
+        Classifier::Topic__Communications__BBS,
+        Classifier::Topic__Communications__Chat,
+        Classifier::Topic__Communications__Chat__ICQ,
+        Classifier::Topic__Communications__Chat__InternetRelayChat,
+        Classifier::Topic__Communications__Chat__UnixTalk,
+        Classifier::Topic__Communications__Conferencing,
+        Classifier::Topic__Communications__Email,
+        Classifier::Topic__Communications__Email__AddressBook,
+        Classifier::Topic__Communications__Email__EmailClientsMUA,
+        Classifier::Topic__Communications__Email__Filters,
+        Classifier::Topic__Communications__Email__MailTransportAgents,
+        Classifier::Topic__Communications__Email__MailingListServers,
+        Classifier::Topic__Communications__Email__PostOffice,
+        Classifier::Topic__Communications__Email__PostOffice__IMAP,
+        Classifier::Topic__Communications__Email__PostOffice__POP3,
+        Classifier::Topic__Communications__FIDO,
+        Classifier::Topic__Communications__Fax,
+        Classifier::Topic__Communications__FileSharing,
+        Classifier::Topic__Communications__FileSharing__Gnutella,
+        Classifier::Topic__Communications__FileSharing__Napster,
+        Classifier::Topic__Communications__HamRadio,
+        Classifier::Topic__Communications__InternetPhone,
+        Classifier::Topic__Communications__Telephony,
+        Classifier::Topic__Communications__UsenetNews,
+        Classifier::Topic__Database,
+        Classifier::Topic__Database__DatabaseEnginesServers,
+        Classifier::Topic__Database__FrontEnds,
+        Classifier::Topic__DesktopEnvironment,
+        Classifier::Topic__DesktopEnvironment__FileManagers,
+        Classifier::Topic__DesktopEnvironment__GNUstep,
+        Classifier::Topic__DesktopEnvironment__Gnome,
+        Classifier::Topic__DesktopEnvironment__KDesktopEnvironmentKDE,
+        Classifier::Topic__DesktopEnvironment__KDesktopEnvironmentKDE__Themes,
+        Classifier::Topic__DesktopEnvironment__PicoGUI,
+        Classifier::Topic__DesktopEnvironment__PicoGUI__Applications,
+        Classifier::Topic__DesktopEnvironment__PicoGUI__Themes,
+        Classifier::Topic__DesktopEnvironment__ScreenSavers,
+        Classifier::Topic__DesktopEnvironment__WindowManagers,
+        Classifier::Topic__DesktopEnvironment__WindowManagers__Afterstep,
+        Classifier::Topic__DesktopEnvironment__WindowManagers__Afterstep__Themes,
+        Classifier::Topic__DesktopEnvironment__WindowManagers__Applets,
+        Classifier::Topic__DesktopEnvironment__WindowManagers__Blackbox,
+        Classifier::Topic__DesktopEnvironment__WindowManagers__Blackbox__Themes,
+        Classifier::Topic__DesktopEnvironment__WindowManagers__CTWM,
+        Classifier::Topic__DesktopEnvironment__WindowManagers__CTWM__Themes,
+        Classifier::Topic__DesktopEnvironment__WindowManagers__Enlightenment,
+        Classifier::Topic__DesktopEnvironment__WindowManagers__Enlightenment__Epplets,
+        Classifier::Topic__DesktopEnvironment__WindowManagers__Enlightenment__ThemesDR15,
+        Classifier::Topic__DesktopEnvironment__WindowManagers__Enlightenment__ThemesDR16,
+        Classifier::Topic__DesktopEnvironment__WindowManagers__Enlightenment__ThemesDR17,
+        Classifier::Topic__DesktopEnvironment__WindowManagers__FVWM,
+        Classifier::Topic__DesktopEnvironment__WindowManagers__FVWM__Themes,
+        Classifier::Topic__DesktopEnvironment__WindowManagers__Fluxbox,
+        Classifier::Topic__DesktopEnvironment__WindowManagers__Fluxbox__Themes,
+        Classifier::Topic__DesktopEnvironment__WindowManagers__IceWM,
+        Classifier::Topic__DesktopEnvironment__WindowManagers__IceWM__Themes,
+        Classifier::Topic__DesktopEnvironment__WindowManagers__MetaCity,
+        Classifier::Topic__DesktopEnvironment__WindowManagers__MetaCity__Themes,
+        Classifier::Topic__DesktopEnvironment__WindowManagers__Oroborus,
+        Classifier::Topic__DesktopEnvironment__WindowManagers__Oroborus__Themes,
+        Classifier::Topic__DesktopEnvironment__WindowManagers__Sawfish,
+        Classifier::Topic__DesktopEnvironment__WindowManagers__Sawfish__Themes0_30,
+        Classifier::Topic__DesktopEnvironment__WindowManagers__Sawfish__Themespre0_30,
+        Classifier::Topic__DesktopEnvironment__WindowManagers__Waimea,
+        Classifier::Topic__DesktopEnvironment__WindowManagers__Waimea__Themes,
+        Classifier::Topic__DesktopEnvironment__WindowManagers__WindowMaker,
+        Classifier::Topic__DesktopEnvironment__WindowManagers__WindowMaker__Applets,
+        Classifier::Topic__DesktopEnvironment__WindowManagers__WindowMaker__Themes,
+        Classifier::Topic__DesktopEnvironment__WindowManagers__XFCE,
+        Classifier::Topic__DesktopEnvironment__WindowManagers__XFCE__Themes,
+        Classifier::Topic__Documentation,
+        Classifier::Topic__Documentation__Sphinx,
+        Classifier::Topic__Education,
+        Classifier::Topic__Education__ComputerAidedInstructionCAI,
+        Classifier::Topic__Education__Testing,
+        Classifier::Topic__FileFormats,
+        Classifier::Topic__FileFormats__JSON,
+        Classifier::Topic__FileFormats__JSON__JSONSchema,
+        Classifier::Topic__GamesEntertainment,
+        Classifier::Topic__GamesEntertainment__Arcade,
+        Classifier::Topic__GamesEntertainment__BoardGames,
+        Classifier::Topic__GamesEntertainment__FirstPersonShooters,
+        Classifier::Topic__GamesEntertainment__FortuneCookies,
+        Classifier::Topic__GamesEntertainment__MultiUserDungeonsMUD,
+        Classifier::Topic__GamesEntertainment__PuzzleGames,
+        Classifier::Topic__GamesEntertainment__RealTimeStrategy,
+        Classifier::Topic__GamesEntertainment__RolePlaying,
+        Classifier::Topic__GamesEntertainment__SideScrollingArcadeGames,
+        Classifier::Topic__GamesEntertainment__Simulation,
+        Classifier::Topic__GamesEntertainment__TurnBasedStrategy,
+        Classifier::Topic__HomeAutomation,
+        Classifier::Topic__Internet,
+        Classifier::Topic__Internet__FileTransferProtocolFTP,
+        Classifier::Topic__Internet__Finger,
+        Classifier::Topic__Internet__LogAnalysis,
+        Classifier::Topic__Internet__NameServiceDNS,
+        Classifier::Topic__Internet__ProxyServers,
+        Classifier::Topic__Internet__WAP,
+        Classifier::Topic__Internet__WWWHTTP,
+        Classifier::Topic__Internet__WWWHTTP__Browsers,
+        Classifier::Topic__Internet__WWWHTTP__DynamicContent,
+        Classifier::Topic__Internet__WWWHTTP__DynamicContent__CGIToolsLibraries,
+        Classifier::Topic__Internet__WWWHTTP__DynamicContent__ContentManagementSystem,
+        Classifier::Topic__Internet__WWWHTTP__DynamicContent__MessageBoards,
+        Classifier::Topic__Internet__WWWHTTP__DynamicContent__NewsDiary,
+        Classifier::Topic__Internet__WWWHTTP__DynamicContent__PageCounters,
+        Classifier::Topic__Internet__WWWHTTP__DynamicContent__Wiki,
+        Classifier::Topic__Internet__WWWHTTP__HTTPServers,
+        Classifier::Topic__Internet__WWWHTTP__IndexingSearch,
+        Classifier::Topic__Internet__WWWHTTP__Session,
+        Classifier::Topic__Internet__WWWHTTP__SiteManagement,
+        Classifier::Topic__Internet__WWWHTTP__SiteManagement__LinkChecking,
+        Classifier::Topic__Internet__WWWHTTP__WSGI,
+        Classifier::Topic__Internet__WWWHTTP__WSGI__Application,
+        Classifier::Topic__Internet__WWWHTTP__WSGI__Middleware,
+        Classifier::Topic__Internet__WWWHTTP__WSGI__Server,
+        Classifier::Topic__Internet__XMPP,
+        Classifier::Topic__Internet__Z39_50,
+        Classifier::Topic__Multimedia,
+        Classifier::Topic__Multimedia__Graphics,
+        Classifier::Topic__Multimedia__Graphics__3DModeling,
+        Classifier::Topic__Multimedia__Graphics__3DRendering,
+        Classifier::Topic__Multimedia__Graphics__Capture,
+        Classifier::Topic__Multimedia__Graphics__Capture__DigitalCamera,
+        Classifier::Topic__Multimedia__Graphics__Capture__Scanners,
+        Classifier::Topic__Multimedia__Graphics__Capture__ScreenCapture,
+        Classifier::Topic__Multimedia__Graphics__Editors,
+        Classifier::Topic__Multimedia__Graphics__Editors__RasterBased,
+        Classifier::Topic__Multimedia__Graphics__Editors__VectorBased,
+        Classifier::Topic__Multimedia__Graphics__GraphicsConversion,
+        Classifier::Topic__Multimedia__Graphics__Presentation,
+        Classifier::Topic__Multimedia__Graphics__Viewers,
+        Classifier::Topic__Multimedia__SoundAudio,
+        Classifier::Topic__Multimedia__SoundAudio__Analysis,
+        Classifier::Topic__Multimedia__SoundAudio__CDAudio,
+        Classifier::Topic__Multimedia__SoundAudio__CDAudio__CDPlaying,
+        Classifier::Topic__Multimedia__SoundAudio__CDAudio__CDRipping,
+        Classifier::Topic__Multimedia__SoundAudio__CDAudio__CDWriting,
+        Classifier::Topic__Multimedia__SoundAudio__CaptureRecording,
+        Classifier::Topic__Multimedia__SoundAudio__Conversion,
+        Classifier::Topic__Multimedia__SoundAudio__Editors,
+        Classifier::Topic__Multimedia__SoundAudio__MIDI,
+        Classifier::Topic__Multimedia__SoundAudio__Mixers,
+        Classifier::Topic__Multimedia__SoundAudio__Players,
+        Classifier::Topic__Multimedia__SoundAudio__Players__MP3,
+        Classifier::Topic__Multimedia__SoundAudio__SoundSynthesis,
+        Classifier::Topic__Multimedia__SoundAudio__Speech,
+        Classifier::Topic__Multimedia__Video,
+        Classifier::Topic__Multimedia__Video__Capture,
+        Classifier::Topic__Multimedia__Video__Conversion,
+        Classifier::Topic__Multimedia__Video__Display,
+        Classifier::Topic__Multimedia__Video__NonLinearEditor,
+        Classifier::Topic__OfficeBusiness,
+        Classifier::Topic__OfficeBusiness__Financial,
+        Classifier::Topic__OfficeBusiness__Financial__Accounting,
+        Classifier::Topic__OfficeBusiness__Financial__Investment,
+        Classifier::Topic__OfficeBusiness__Financial__PointOfSale,
+        Classifier::Topic__OfficeBusiness__Financial__Spreadsheet,
+        Classifier::Topic__OfficeBusiness__Groupware,
+        Classifier::Topic__OfficeBusiness__NewsDiary,
+        Classifier::Topic__OfficeBusiness__OfficeSuites,
+        Classifier::Topic__OfficeBusiness__Scheduling,
+        Classifier::Topic__OtherNonlistedTopic,
+        Classifier::Topic__Printing,
+        Classifier::Topic__Religion,
+        Classifier::Topic__ScientificEngineering,
+        Classifier::Topic__ScientificEngineering__ArtificialIntelligence,
+        Classifier::Topic__ScientificEngineering__ArtificialLife,
+        Classifier::Topic__ScientificEngineering__Astronomy,
+        Classifier::Topic__ScientificEngineering__AtmosphericScience,
+        Classifier::Topic__ScientificEngineering__BioInformatics,
+        Classifier::Topic__ScientificEngineering__Chemistry,
+        Classifier::Topic__ScientificEngineering__ElectronicDesignAutomationEDA,
+        Classifier::Topic__ScientificEngineering__GIS,
+        Classifier::Topic__ScientificEngineering__HumanMachineInterfaces,
+        Classifier::Topic__ScientificEngineering__Hydrology,
+        Classifier::Topic__ScientificEngineering__ImageProcessing,
+        Classifier::Topic__ScientificEngineering__ImageRecognition,
+        Classifier::Topic__ScientificEngineering__InformationAnalysis,
+        Classifier::Topic__ScientificEngineering__InterfaceEngineProtocolTranslator,
+        Classifier::Topic__ScientificEngineering__Mathematics,
+        Classifier::Topic__ScientificEngineering__MedicalScienceApps_,
+        Classifier::Topic__ScientificEngineering__Oceanography,
+        Classifier::Topic__ScientificEngineering__Physics,
+        Classifier::Topic__ScientificEngineering__Visualization,
+        Classifier::Topic__Security,
+        Classifier::Topic__Security__Cryptography,
+        Classifier::Topic__Sociology,
+        Classifier::Topic__Sociology__Genealogy,
+        Classifier::Topic__Sociology__History,
+        Classifier::Topic__SoftwareDevelopment,
+        Classifier::Topic__SoftwareDevelopment__Assemblers,
+        Classifier::Topic__SoftwareDevelopment__BugTracking,
+        Classifier::Topic__SoftwareDevelopment__BuildTools,
+        Classifier::Topic__SoftwareDevelopment__CodeGenerators,
+        Classifier::Topic__SoftwareDevelopment__Compilers,
+        Classifier::Topic__SoftwareDevelopment__Debuggers,
+        Classifier::Topic__SoftwareDevelopment__Disassemblers,
+        Classifier::Topic__SoftwareDevelopment__Documentation,
+        Classifier::Topic__SoftwareDevelopment__EmbeddedSystems,
+        Classifier::Topic__SoftwareDevelopment__EmbeddedSystems__ControllerAreaNetworkCAN,
+        Classifier::Topic__SoftwareDevelopment__EmbeddedSystems__ControllerAreaNetworkCAN__CANopen,
+        Classifier::Topic__SoftwareDevelopment__EmbeddedSystems__ControllerAreaNetworkCAN__J1939,
+        Classifier::Topic__SoftwareDevelopment__Internationalization,
+        Classifier::Topic__SoftwareDevelopment__Interpreters,
+        Classifier::Topic__SoftwareDevelopment__Libraries,
+        Classifier::Topic__SoftwareDevelopment__Libraries__ApplicationFrameworks,
+        Classifier::Topic__SoftwareDevelopment__Libraries__JavaLibraries,
+        Classifier::Topic__SoftwareDevelopment__Libraries__PHPClasses,
+        Classifier::Topic__SoftwareDevelopment__Libraries__PerlModules,
+        Classifier::Topic__SoftwareDevelopment__Libraries__PikeModules,
+        Classifier::Topic__SoftwareDevelopment__Libraries__PythonModules,
+        Classifier::Topic__SoftwareDevelopment__Libraries__RubyModules,
+        Classifier::Topic__SoftwareDevelopment__Libraries__TclExtensions,
+        Classifier::Topic__SoftwareDevelopment__Libraries__pygame,
+        Classifier::Topic__SoftwareDevelopment__Localization,
+        Classifier::Topic__SoftwareDevelopment__ObjectBrokering,
+        Classifier::Topic__SoftwareDevelopment__ObjectBrokering__CORBA,
+        Classifier::Topic__SoftwareDevelopment__Preprocessors,
+        Classifier::Topic__SoftwareDevelopment__QualityAssurance,
+        Classifier::Topic__SoftwareDevelopment__Testing,
+        Classifier::Topic__SoftwareDevelopment__Testing__Acceptance,
+        Classifier::Topic__SoftwareDevelopment__Testing__BDD,
+        Classifier::Topic__SoftwareDevelopment__Testing__Mocking,
+        Classifier::Topic__SoftwareDevelopment__Testing__TrafficGeneration,
+        Classifier::Topic__SoftwareDevelopment__Testing__Unit,
+        Classifier::Topic__SoftwareDevelopment__UserInterfaces,
+        Classifier::Topic__SoftwareDevelopment__VersionControl,
+        Classifier::Topic__SoftwareDevelopment__VersionControl__Bazaar,
+        Classifier::Topic__SoftwareDevelopment__VersionControl__CVS,
+        Classifier::Topic__SoftwareDevelopment__VersionControl__Git,
+        Classifier::Topic__SoftwareDevelopment__VersionControl__Mercurial,
+        Classifier::Topic__SoftwareDevelopment__VersionControl__RCS,
+        Classifier::Topic__SoftwareDevelopment__VersionControl__SCCS,
+        Classifier::Topic__SoftwareDevelopment__WidgetSets,
+        Classifier::Topic__System,
+        Classifier::Topic__System__Archiving,
+        Classifier::Topic__System__Archiving__Backup,
+        Classifier::Topic__System__Archiving__Compression,
+        Classifier::Topic__System__Archiving__Mirroring,
+        Classifier::Topic__System__Archiving__Packaging,
+        Classifier::Topic__System__Benchmark,
+        Classifier::Topic__System__Boot,
+        Classifier::Topic__System__Boot__Init,
+        Classifier::Topic__System__Clustering,
+        Classifier::Topic__System__ConsoleFonts,
+        Classifier::Topic__System__DistributedComputing,
+        Classifier::Topic__System__Emulators,
+        Classifier::Topic__System__Filesystems,
+        Classifier::Topic__System__Hardware,
+        Classifier::Topic__System__Hardware__HardwareDrivers,
+        Classifier::Topic__System__Hardware__Mainframes,
+        Classifier::Topic__System__Hardware__SymmetricMultiprocessing,
+        Classifier::Topic__System__Hardware__UniversalSerialBusUSB,
+        Classifier::Topic__System__Hardware__UniversalSerialBusUSB__Audio,
+        Classifier::Topic__System__Hardware__UniversalSerialBusUSB__AudioVideoAV,
+        Classifier::Topic__System__Hardware__UniversalSerialBusUSB__CommunicationsDeviceClassCDC,
+        Classifier::Topic__System__Hardware__UniversalSerialBusUSB__DiagnosticDevice,
+        Classifier::Topic__System__Hardware__UniversalSerialBusUSB__Hub,
+        Classifier::Topic__System__Hardware__UniversalSerialBusUSB__HumanInterfaceDeviceHID,
+        Classifier::Topic__System__Hardware__UniversalSerialBusUSB__MassStorage,
+        Classifier::Topic__System__Hardware__UniversalSerialBusUSB__Miscellaneous,
+        Classifier::Topic__System__Hardware__UniversalSerialBusUSB__Printer,
+        Classifier::Topic__System__Hardware__UniversalSerialBusUSB__SmartCard,
+        Classifier::Topic__System__Hardware__UniversalSerialBusUSB__Vendor,
+        Classifier::Topic__System__Hardware__UniversalSerialBusUSB__VideoUVC,
+        Classifier::Topic__System__Hardware__UniversalSerialBusUSB__WirelessController,
+        Classifier::Topic__System__InstallationSetup,
+        Classifier::Topic__System__Logging,
+        Classifier::Topic__System__Monitoring,
+        Classifier::Topic__System__Networking,
+        Classifier::Topic__System__Networking__Firewalls,
+        Classifier::Topic__System__Networking__Monitoring,
+        Classifier::Topic__System__Networking__Monitoring__HardwareWatchdog,
+        Classifier::Topic__System__Networking__TimeSynchronization,
+        Classifier::Topic__System__OperatingSystem,
+        Classifier::Topic__System__OperatingSystemKernels,
+        Classifier::Topic__System__OperatingSystemKernels__BSD,
+        Classifier::Topic__System__OperatingSystemKernels__GNUHurd,
+        Classifier::Topic__System__OperatingSystemKernels__Linux,
+        Classifier::Topic__System__PowerUPS,
+        Classifier::Topic__System__RecoveryTools,
+        Classifier::Topic__System__Shells,
+        Classifier::Topic__System__SoftwareDistribution,
+        Classifier::Topic__System__SystemShells,
+        Classifier::Topic__System__SystemsAdministration,
+        Classifier::Topic__System__SystemsAdministration__AuthenticationDirectory,
+        Classifier::Topic__System__SystemsAdministration__AuthenticationDirectory__LDAP,
+        Classifier::Topic__System__SystemsAdministration__AuthenticationDirectory__NIS,
+        Classifier::Topic__Terminals,
+        Classifier::Topic__Terminals__Serial,
+        Classifier::Topic__Terminals__Telnet,
+        Classifier::Topic__Terminals__TerminalEmulatorsXTerminals,
+        Classifier::Topic__TextEditors,
+        Classifier::Topic__TextEditors__Documentation,
+        Classifier::Topic__TextEditors__Emacs,
+        Classifier::Topic__TextEditors__IntegratedDevelopmentEnvironmentsIDE,
+        Classifier::Topic__TextEditors__TextProcessing,
+        Classifier::Topic__TextEditors__WordProcessors,
+        Classifier::Topic__TextProcessing,
+        Classifier::Topic__TextProcessing__Filters,
+        Classifier::Topic__TextProcessing__Fonts,
+        Classifier::Topic__TextProcessing__General,
+        Classifier::Topic__TextProcessing__Indexing,
+        Classifier::Topic__TextProcessing__Linguistic,
+        Classifier::Topic__TextProcessing__Markup,
+        Classifier::Topic__TextProcessing__Markup__HTML,
+        Classifier::Topic__TextProcessing__Markup__LaTeX,
+        Classifier::Topic__TextProcessing__Markup__Markdown,
+        Classifier::Topic__TextProcessing__Markup__SGML,
+        Classifier::Topic__TextProcessing__Markup__VRML,
+        Classifier::Topic__TextProcessing__Markup__XML,
+        Classifier::Topic__TextProcessing__Markup__reStructuredText,
+        Classifier::Topic__Utilities,
+    ],
+    text: concat!(
+        "Topic :: Adaptive Technologies",
+        "Topic :: Artistic Software",
+        "Topic :: Communications",
+        "Topic :: Communications :: BBS",
+        "Topic :: Communications :: Chat",
+        "Topic :: Communications :: Chat :: ICQ",
+        "Topic :: Communications :: Chat :: Internet Relay Chat",
+        "Topic :: Communications :: Chat :: Unix Talk",
+        "Topic :: Communications :: Conferencing",
+        "Topic :: Communications :: Email",
+        "Topic :: Communications :: Email :: Address Book",
+        "Topic :: Communications :: Email :: Email Clients (MUA)",
+        "Topic :: Communications :: Email :: Filters",
+        "Topic :: Communications :: Email :: Mail Transport Agents",
+        "Topic :: Communications :: Email :: Mailing List Servers",
+        "Topic :: Communications :: Email :: Post-Office",
+        "Topic :: Communications :: Email :: Post-Office :: IMAP",
+        "Topic :: Communications :: Email :: Post-Office :: POP3",
+        "Topic :: Communications :: FIDO",
+        "Topic :: Communications :: Fax",
+        "Topic :: Communications :: File Sharing",
+        "Topic :: Communications :: File Sharing :: Gnutella",
+        "Topic :: Communications :: File Sharing :: Napster",
+        "Topic :: Communications :: Ham Radio",
+        "Topic :: Communications :: Internet Phone",
+        "Topic :: Communications :: Telephony",
+        "Topic :: Communications :: Usenet News",
+        "Topic :: Database",
+        "Topic :: Database :: Database Engines/Servers",
+        "Topic :: Database :: Front-Ends",
+        "Topic :: Desktop Environment",
+        "Topic :: Desktop Environment :: File Managers",
+        "Topic :: Desktop Environment :: GNUstep",
+        "Topic :: Desktop Environment :: Gnome",
+        "Topic :: Desktop Environment :: K Desktop Environment (KDE)",
+        "Topic :: Desktop Environment :: K Desktop Environment (KDE) :: Themes",
+        "Topic :: Desktop Environment :: PicoGUI",
+        "Topic :: Desktop Environment :: PicoGUI :: Applications",
+        "Topic :: Desktop Environment :: PicoGUI :: Themes",
+        "Topic :: Desktop Environment :: Screen Savers",
+        "Topic :: Desktop Environment :: Window Managers",
+        "Topic :: Desktop Environment :: Window Managers :: Afterstep",
+        "Topic :: Desktop Environment :: Window Managers :: Afterstep :: Themes",
+        "Topic :: Desktop Environment :: Window Managers :: Applets",
+        "Topic :: Desktop Environment :: Window Managers :: Blackbox",
+        "Topic :: Desktop Environment :: Window Managers :: Blackbox :: Themes",
+        "Topic :: Desktop Environment :: Window Managers :: CTWM",
+        "Topic :: Desktop Environment :: Window Managers :: CTWM :: Themes",
+        "Topic :: Desktop Environment :: Window Managers :: Enlightenment",
+        "Topic :: Desktop Environment :: Window Managers :: Enlightenment :: Epplets",
+        "Topic :: Desktop Environment :: Window Managers :: Enlightenment :: Themes DR15",
+        "Topic :: Desktop Environment :: Window Managers :: Enlightenment :: Themes DR16",
+        "Topic :: Desktop Environment :: Window Managers :: Enlightenment :: Themes DR17",
+        "Topic :: Desktop Environment :: Window Managers :: FVWM",
+        "Topic :: Desktop Environment :: Window Managers :: FVWM :: Themes",
+        "Topic :: Desktop Environment :: Window Managers :: Fluxbox",
+        "Topic :: Desktop Environment :: Window Managers :: Fluxbox :: Themes",
+        "Topic :: Desktop Environment :: Window Managers :: IceWM",
+        "Topic :: Desktop Environment :: Window Managers :: IceWM :: Themes",
+        "Topic :: Desktop Environment :: Window Managers :: MetaCity",
+        "Topic :: Desktop Environment :: Window Managers :: MetaCity :: Themes",
+        "Topic :: Desktop Environment :: Window Managers :: Oroborus",
+        "Topic :: Desktop Environment :: Window Managers :: Oroborus :: Themes",
+        "Topic :: Desktop Environment :: Window Managers :: Sawfish",
+        "Topic :: Desktop Environment :: Window Managers :: Sawfish :: Themes 0.30",
+        "Topic :: Desktop Environment :: Window Managers :: Sawfish :: Themes pre-0.30",
+        "Topic :: Desktop Environment :: Window Managers :: Waimea",
+        "Topic :: Desktop Environment :: Window Managers :: Waimea :: Themes",
+        "Topic :: Desktop Environment :: Window Managers :: Window Maker",
+        "Topic :: Desktop Environment :: Window Managers :: Window Maker :: Applets",
+        "Topic :: Desktop Environment :: Window Managers :: Window Maker :: Themes",
+        "Topic :: Desktop Environment :: Window Managers :: XFCE",
+        "Topic :: Desktop Environment :: Window Managers :: XFCE :: Themes",
+        "Topic :: Documentation",
+        "Topic :: Documentation :: Sphinx",
+        "Topic :: Education",
+        "Topic :: Education :: Computer Aided Instruction (CAI)",
+        "Topic :: Education :: Testing",
+        "Topic :: File Formats",
+        "Topic :: File Formats :: JSON",
+        "Topic :: File Formats :: JSON :: JSON Schema",
+        "Topic :: Games/Entertainment",
+        "Topic :: Games/Entertainment :: Arcade",
+        "Topic :: Games/Entertainment :: Board Games",
+        "Topic :: Games/Entertainment :: First Person Shooters",
+        "Topic :: Games/Entertainment :: Fortune Cookies",
+        "Topic :: Games/Entertainment :: Multi-User Dungeons (MUD)",
+        "Topic :: Games/Entertainment :: Puzzle Games",
+        "Topic :: Games/Entertainment :: Real Time Strategy",
+        "Topic :: Games/Entertainment :: Role-Playing",
+        "Topic :: Games/Entertainment :: Side-Scrolling/Arcade Games",
+        "Topic :: Games/Entertainment :: Simulation",
+        "Topic :: Games/Entertainment :: Turn Based Strategy",
+        "Topic :: Home Automation",
+        "Topic :: Internet",
+        "Topic :: Internet :: File Transfer Protocol (FTP)",
+        "Topic :: Internet :: Finger",
+        "Topic :: Internet :: Log Analysis",
+        "Topic :: Internet :: Name Service (DNS)",
+        "Topic :: Internet :: Proxy Servers",
+        "Topic :: Internet :: WAP",
+        "Topic :: Internet :: WWW/HTTP",
+        "Topic :: Internet :: WWW/HTTP :: Browsers",
+        "Topic :: Internet :: WWW/HTTP :: Dynamic Content",
+        "Topic :: Internet :: WWW/HTTP :: Dynamic Content :: CGI Tools/Libraries",
+        "Topic :: Internet :: WWW/HTTP :: Dynamic Content :: Content Management System",
+        "Topic :: Internet :: WWW/HTTP :: Dynamic Content :: Message Boards",
+        "Topic :: Internet :: WWW/HTTP :: Dynamic Content :: News/Diary",
+        "Topic :: Internet :: WWW/HTTP :: Dynamic Content :: Page Counters",
+        "Topic :: Internet :: WWW/HTTP :: Dynamic Content :: Wiki",
+        "Topic :: Internet :: WWW/HTTP :: HTTP Servers",
+        "Topic :: Internet :: WWW/HTTP :: Indexing/Search",
+        "Topic :: Internet :: WWW/HTTP :: Session",
+        "Topic :: Internet :: WWW/HTTP :: Site Management",
+        "Topic :: Internet :: WWW/HTTP :: Site Management :: Link Checking",
+        "Topic :: Internet :: WWW/HTTP :: WSGI",
+        "Topic :: Internet :: WWW/HTTP :: WSGI :: Application",
+        "Topic :: Internet :: WWW/HTTP :: WSGI :: Middleware",
+        "Topic :: Internet :: WWW/HTTP :: WSGI :: Server",
+        "Topic :: Internet :: XMPP",
+        "Topic :: Internet :: Z39.50",
+        "Topic :: Multimedia",
+        "Topic :: Multimedia :: Graphics",
+        "Topic :: Multimedia :: Graphics :: 3D Modeling",
+        "Topic :: Multimedia :: Graphics :: 3D Rendering",
+        "Topic :: Multimedia :: Graphics :: Capture",
+        "Topic :: Multimedia :: Graphics :: Capture :: Digital Camera",
+        "Topic :: Multimedia :: Graphics :: Capture :: Scanners",
+        "Topic :: Multimedia :: Graphics :: Capture :: Screen Capture",
+        "Topic :: Multimedia :: Graphics :: Editors",
+        "Topic :: Multimedia :: Graphics :: Editors :: Raster-Based",
+        "Topic :: Multimedia :: Graphics :: Editors :: Vector-Based",
+        "Topic :: Multimedia :: Graphics :: Graphics Conversion",
+        "Topic :: Multimedia :: Graphics :: Presentation",
+        "Topic :: Multimedia :: Graphics :: Viewers",
+        "Topic :: Multimedia :: Sound/Audio",
+        "Topic :: Multimedia :: Sound/Audio :: Analysis",
+        "Topic :: Multimedia :: Sound/Audio :: CD Audio",
+        "Topic :: Multimedia :: Sound/Audio :: CD Audio :: CD Playing",
+        "Topic :: Multimedia :: Sound/Audio :: CD Audio :: CD Ripping",
+        "Topic :: Multimedia :: Sound/Audio :: CD Audio :: CD Writing",
+        "Topic :: Multimedia :: Sound/Audio :: Capture/Recording",
+        "Topic :: Multimedia :: Sound/Audio :: Conversion",
+        "Topic :: Multimedia :: Sound/Audio :: Editors",
+        "Topic :: Multimedia :: Sound/Audio :: MIDI",
+        "Topic :: Multimedia :: Sound/Audio :: Mixers",
+        "Topic :: Multimedia :: Sound/Audio :: Players",
+        "Topic :: Multimedia :: Sound/Audio :: Players :: MP3",
+        "Topic :: Multimedia :: Sound/Audio :: Sound Synthesis",
+        "Topic :: Multimedia :: Sound/Audio :: Speech",
+        "Topic :: Multimedia :: Video",
+        "Topic :: Multimedia :: Video :: Capture",
+        "Topic :: Multimedia :: Video :: Conversion",
+        "Topic :: Multimedia :: Video :: Display",
+        "Topic :: Multimedia :: Video :: Non-Linear Editor",
+        "Topic :: Office/Business",
+        "Topic :: Office/Business :: Financial",
+        "Topic :: Office/Business :: Financial :: Accounting",
+        "Topic :: Office/Business :: Financial :: Investment",
+        "Topic :: Office/Business :: Financial :: Point-Of-Sale",
+        "Topic :: Office/Business :: Financial :: Spreadsheet",
+        "Topic :: Office/Business :: Groupware",
+        "Topic :: Office/Business :: News/Diary",
+        "Topic :: Office/Business :: Office Suites",
+        "Topic :: Office/Business :: Scheduling",
+        "Topic :: Other/Nonlisted Topic",
+        "Topic :: Printing",
+        "Topic :: Religion",
+        "Topic :: Scientific/Engineering",
+        "Topic :: Scientific/Engineering :: Artificial Intelligence",
+        "Topic :: Scientific/Engineering :: Artificial Life",
+        "Topic :: Scientific/Engineering :: Astronomy",
+        "Topic :: Scientific/Engineering :: Atmospheric Science",
+        "Topic :: Scientific/Engineering :: Bio-Informatics",
+        "Topic :: Scientific/Engineering :: Chemistry",
+        "Topic :: Scientific/Engineering :: Electronic Design Automation (EDA)",
+        "Topic :: Scientific/Engineering :: GIS",
+        "Topic :: Scientific/Engineering :: Human Machine Interfaces",
+        "Topic :: Scientific/Engineering :: Hydrology",
+        "Topic :: Scientific/Engineering :: Image Processing",
+        "Topic :: Scientific/Engineering :: Image Recognition",
+        "Topic :: Scientific/Engineering :: Information Analysis",
+        "Topic :: Scientific/Engineering :: Interface Engine/Protocol Translator",
+        "Topic :: Scientific/Engineering :: Mathematics",
+        "Topic :: Scientific/Engineering :: Medical Science Apps.",
+        "Topic :: Scientific/Engineering :: Oceanography",
+        "Topic :: Scientific/Engineering :: Physics",
+        "Topic :: Scientific/Engineering :: Visualization",
+        "Topic :: Security",
+        "Topic :: Security :: Cryptography",
+        "Topic :: Sociology",
+        "Topic :: Sociology :: Genealogy",
+        "Topic :: Sociology :: History",
+        "Topic :: Software Development",
+        "Topic :: Software Development :: Assemblers",
+        "Topic :: Software Development :: Bug Tracking",
+        "Topic :: Software Development :: Build Tools",
+        "Topic :: Software Development :: Code Generators",
+        "Topic :: Software Development :: Compilers",
+        "Topic :: Software Development :: Debuggers",
+        "Topic :: Software Development :: Disassemblers",
+        "Topic :: Software Development :: Documentation",
+        "Topic :: Software Development :: Embedded Systems",
+        "Topic :: Software Development :: Embedded Systems :: Controller Area Network (CAN)",
+        "Topic :: Software Development :: Embedded Systems :: Controller Area Network (CAN) :: CANopen",
+        "Topic :: Software Development :: Embedded Systems :: Controller Area Network (CAN) :: J1939",
+        "Topic :: Software Development :: Internationalization",
+        "Topic :: Software Development :: Interpreters",
+        "Topic :: Software Development :: Libraries",
+        "Topic :: Software Development :: Libraries :: Application Frameworks",
+        "Topic :: Software Development :: Libraries :: Java Libraries",
+        "Topic :: Software Development :: Libraries :: PHP Classes",
+        "Topic :: Software Development :: Libraries :: Perl Modules",
+        "Topic :: Software Development :: Libraries :: Pike Modules",
+        "Topic :: Software Development :: Libraries :: Python Modules",
+        "Topic :: Software Development :: Libraries :: Ruby Modules",
+        "Topic :: Software Development :: Libraries :: Tcl Extensions",
+        "Topic :: Software Development :: Libraries :: pygame",
+        "Topic :: Software Development :: Localization",
+        "Topic :: Software Development :: Object Brokering",
+        "Topic :: Software Development :: Object Brokering :: CORBA",
+        "Topic :: Software Development :: Pre-processors",
+        "Topic :: Software Development :: Quality Assurance",
+        "Topic :: Software Development :: Testing",
+        "Topic :: Software Development :: Testing :: Acceptance",
+        "Topic :: Software Development :: Testing :: BDD",
+        "Topic :: Software Development :: Testing :: Mocking",
+        "Topic :: Software Development :: Testing :: Traffic Generation",
+        "Topic :: Software Development :: Testing :: Unit",
+        "Topic :: Software Development :: User Interfaces",
+        "Topic :: Software Development :: Version Control",
+        "Topic :: Software Development :: Version Control :: Bazaar",
+        "Topic :: Software Development :: Version Control :: CVS",
+        "Topic :: Software Development :: Version Control :: Git",
+        "Topic :: Software Development :: Version Control :: Mercurial",
+        "Topic :: Software Development :: Version Control :: RCS",
+        "Topic :: Software Development :: Version Control :: SCCS",
+        "Topic :: Software Development :: Widget Sets",
+        "Topic :: System",
+        "Topic :: System :: Archiving",
+        "Topic :: System :: Archiving :: Backup",
+        "Topic :: System :: Archiving :: Compression",
+        "Topic :: System :: Archiving :: Mirroring",
+        "Topic :: System :: Archiving :: Packaging",
+        "Topic :: System :: Benchmark",
+        "Topic :: System :: Boot",
+        "Topic :: System :: Boot :: Init",
+        "Topic :: System :: Clustering",
+        "Topic :: System :: Console Fonts",
+        "Topic :: System :: Distributed Computing",
+        "Topic :: System :: Emulators",
+        "Topic :: System :: Filesystems",
+        "Topic :: System :: Hardware",
+        "Topic :: System :: Hardware :: Hardware Drivers",
+        "Topic :: System :: Hardware :: Mainframes",
+        "Topic :: System :: Hardware :: Symmetric Multi-processing",
+        "Topic :: System :: Hardware :: Universal Serial Bus (USB)",
+        "Topic :: System :: Hardware :: Universal Serial Bus (USB) :: Audio",
+        "Topic :: System :: Hardware :: Universal Serial Bus (USB) :: Audio/Video (AV)",
+        "Topic :: System :: Hardware :: Universal Serial Bus (USB) :: Communications Device Class (CDC)",
+        "Topic :: System :: Hardware :: Universal Serial Bus (USB) :: Diagnostic Device",
+        "Topic :: System :: Hardware :: Universal Serial Bus (USB) :: Hub",
+        "Topic :: System :: Hardware :: Universal Serial Bus (USB) :: Human Interface Device (HID)",
+        "Topic :: System :: Hardware :: Universal Serial Bus (USB) :: Mass Storage",
+        "Topic :: System :: Hardware :: Universal Serial Bus (USB) :: Miscellaneous",
+        "Topic :: System :: Hardware :: Universal Serial Bus (USB) :: Printer",
+        "Topic :: System :: Hardware :: Universal Serial Bus (USB) :: Smart Card",
+        "Topic :: System :: Hardware :: Universal Serial Bus (USB) :: Vendor",
+        "Topic :: System :: Hardware :: Universal Serial Bus (USB) :: Video (UVC)",
+        "Topic :: System :: Hardware :: Universal Serial Bus (USB) :: Wireless Controller",
+        "Topic :: System :: Installation/Setup",
+        "Topic :: System :: Logging",
+        "Topic :: System :: Monitoring",
+        "Topic :: System :: Networking",
+        "Topic :: System :: Networking :: Firewalls",
+        "Topic :: System :: Networking :: Monitoring",
+        "Topic :: System :: Networking :: Monitoring :: Hardware Watchdog",
+        "Topic :: System :: Networking :: Time Synchronization",
+        "Topic :: System :: Operating System",
+        "Topic :: System :: Operating System Kernels",
+        "Topic :: System :: Operating System Kernels :: BSD",
+        "Topic :: System :: Operating System Kernels :: GNU Hurd",
+        "Topic :: System :: Operating System Kernels :: Linux",
+        "Topic :: System :: Power (UPS)",
+        "Topic :: System :: Recovery Tools",
+        "Topic :: System :: Shells",
+        "Topic :: System :: Software Distribution",
+        "Topic :: System :: System Shells",
+        "Topic :: System :: Systems Administration",
+        "Topic :: System :: Systems Administration :: Authentication/Directory",
+        "Topic :: System :: Systems Administration :: Authentication/Directory :: LDAP",
+        "Topic :: System :: Systems Administration :: Authentication/Directory :: NIS",
+        "Topic :: Terminals",
+        "Topic :: Terminals :: Serial",
+        "Topic :: Terminals :: Telnet",
+        "Topic :: Terminals :: Terminal Emulators/X Terminals",
+        "Topic :: Text Editors",
+        "Topic :: Text Editors :: Documentation",
+        "Topic :: Text Editors :: Emacs",
+        "Topic :: Text Editors :: Integrated Development Environments (IDE)",
+        "Topic :: Text Editors :: Text Processing",
+        "Topic :: Text Editors :: Word Processors",
+        "Topic :: Text Processing",
+        "Topic :: Text Processing :: Filters",
+        "Topic :: Text Processing :: Fonts",
+        "Topic :: Text Processing :: General",
+        "Topic :: Text Processing :: Indexing",
+        "Topic :: Text Processing :: Linguistic",
+        "Topic :: Text Processing :: Markup",
+        "Topic :: Text Processing :: Markup :: HTML",
+        "Topic :: Text Processing :: Markup :: LaTeX",
+        "Topic :: Text Processing :: Markup :: Markdown",
+        "Topic :: Text Processing :: Markup :: SGML",
+        "Topic :: Text Processing :: Markup :: VRML",
+        "Topic :: Text Processing :: Markup :: XML",
+        "Topic :: Text Processing :: Markup :: reStructuredText",
+        "Topic :: Utilities",
+    ),
+    ends: &[
+        30,
+        56,
+        79,
+        109,
+        140,
+        178,
+        232,
+        276,
+        315,
+        347,
+        395,
+        450,
+        493,
+        550,
+        606,
+        653,
+        708,
+        763,
+        794,
+        824,
+        863,
+        914,
+        964,
+        1000,
+        1041,
+        1077,
+        1115,
+        1132,
+        1177,
+        1208,
+        1236,
+        1281,
+        1320,
+        1357,
+        1416,
+        1485,
+        1524,
+        1579,
+        1628,
+        1673,
+        1720,
+        1780,
+        1850,
+        1908,
+        1967,
+        2036,
+        2091,
+        2156,
+        2220,
+        2295,
+        2374,
+        2453,
+        2532,
+        2587,
+        2652,
+        2710,
+        2778,
+        2834,
+        2900,
+        2959,
+        3028,
+        3087,
+        3156,
+        3214,
+        3287,
+        3364,
+        3421,
+        3488,
+        3551,
+        3625,
+        3698,
+        3753,
+        3818,
+        3840,
+        3872,
+        3890,
+        3944,
+        3973,
+        3994,
+        4023,
+        4067,
+        4095,
+        4133,
+        4176,
+        4229,
+        4276,
+        4333,
+        4377,
+        4427,
+        4471,
+        4530,
+        4572,
+        4623,
+        4647,
+        4664,
+        4713,
+        4740,
+        4773,
+        4812,
+        4846,
+        4870,
+        4899,
+        4940,
+        4988,
+        5059,
+        5136,
+        5202,
+        5264,
+        5329,
+        5385,
+        5430,
+        5478,
+        5518,
+        5566,
+        5631,
+        5668,
+        5720,
+        5771,
+        5818,
+        5843,
+        5870,
+        5889,
+        5920,
+        5966,
+        6013,
+        6055,
+        6115,
+        6169,
+        6229,
+        6271,
+        6329,
+        6387,
+        6441,
+        6488,
+        6530,
+        6564,
+        6610,
+        6656,
+        6716,
+        6776,
+        6836,
+        6891,
+        6939,
+        6984,
+        7026,
+        7070,
+        7115,
+        7167,
+        7220,
+        7264,
+        7292,
+        7331,
+        7373,
+        7412,
+        7461,
+        7485,
+        7522,
+        7573,
+        7624,
+        7678,
+        7730,
+        7767,
+        7805,
+        7846,
+        7884,
+        7914,
+        7931,
+        7948,
+        7979,
+        8037,
+        8087,
+        8131,
+        8185,
+        8235,
+        8279,
+        8348,
+        8386,
+        8445,
+        8489,
+        8540,
+        8592,
+        8647,
+        8718,
+        8764,
+        8820,
+        8867,
+        8909,
+        8957,
+        8974,
+        9007,
+        9025,
+        9056,
+        9085,
+        9114,
+        9157,
+        9202,
+        9246,
+        9294,
+        9336,
+        9378,
+        9424,
+        9470,
+        9519,
+        9601,
+        9694,
+        9785,
+        9838,
+        9883,
+        9925,
+        9993,
+        10053,
+        10110,
+        10168,
+        10226,
+        10286,
+        10344,
+        10404,
+        10456,
+        10501,
+        10550,
+        10608,
+        10655,
+        10705,
+        10745,
+        10799,
+        10846,
+        10897,
+        10959,
+        11007,
+        11055,
+        11103,
+        11161,
+        11216,
+        11271,
+        11332,
+        11387,
+        11443,
+        11487,
+        11502,
+        11530,
+        11568,
+        11611,
+        11652,
+        11693,
+        11721,
+        11744,
+        11775,
+        11804,
+        11836,
+        11876,
+        11904,
+        11934,
+        11961,
+        12008,
+        12049,
+        12106,
+        12163,
+        12229,
+        12306,
+        12400,
+        12478,
+        12542,
+        12631,
+        12704,
+        12778,
+        12846,
+        12917,
+        12984,
+        13056,
+        13136,
+        13173,
+        13199,
+        13228,
+        13257,
+        13299,
+        13342,
+        13406,
+        13459,
+        13494,
+        13537,
+        13587,
+        13642,
+        13694,
+        13724,
+        13757,
+        13782,
+        13822,
+        13854,
+        13895,
+        13964,
+        14041,
+        14117,
+        14135,
+        14163,
+        14191,
+        14243,
+        14264,
+        14302,
+        14332,
+        14398,
+        14438,
+        14478,
+        14502,
+        14537,
+        14570,
+        14605,
+        14641,
+        14679,
+        14713,
+        14755,
+        14798,
+        14844,
+        14886,
+        14928,
+        14969,
+        15023,
+        15041,
+    ],
+    sorted: &[
+        0,
+        1,
+        2,
+        3,
+        4,
+        5,
+        6,
+        7,
+        8,
+        9,
+        10,
+        11,
+        12,
+        13,
+        14,
+        15,
+        16,
+        17,
+        19,
+        18,
+        20,
+        21,
+        22,
+        23,
+        24,
+        25,
+        26,
+        27,
+        28,
+        29,
+        30,
+        31,
+        33,
+        32,
+        34,
+        35,
+        36,
+        37,
+        38,
+        39,
+        40,
+        41,
+        42,
+        43,
+        44,
+        45,
+        46,
+        47,
+        48,
+        49,
+        50,
+        51,
+        52,
+        55,
+        56,
+        53,
+        54,
+        57,
+        58,
+        59,
+        60,
+        61,
+        62,
+        63,
+        64,
+        65,
+        66,
+        67,
+        68,
+        69,
+        70,
+        71,
+        72,
+        73,
+        74,
+        75,
+        76,
+        77,
+        78,
+        79,
+        80,
+        81,
+        82,
+        83,
+        84,
+        85,
+        86,
+        87,
+        88,
+        89,
+        90,
+        91,
+        92,
+        93,
+        94,
+        95,
+        96,
+        97,
+        98,
+        99,
+        100,
+        101,
+        102,
+        103,
+        104,
+        105,
+        106,
+        107,
+        108,
+        109,
+        110,
+        111,
+        112,
+        113,
+        114,
+        115,
+        116,
+        117,
+        118,
+        119,
+        120,
+        121,
+        122,
+        123,
+        124,
+        125,
+        126,
+        127,
+        128,
+        129,
+        130,
+        131,
+        132,
+        133,
+        134,
+        135,
+        136,
+        141,
+        137,
+        138,
+        139,
+        140,
+        142,
+        143,
+        144,
+        145,
+        146,
+        147,
+        148,
+        149,
+        150,
+        151,
+        152,
+        153,
+        154,
+        155,
+        156,
+        157,
+        158,
+        159,
+        160,
+        161,
+        162,
+        163,
+        164,
+        165,
+        166,
+        167,
+        168,
+        169,
+        170,
+        171,
+        172,
+        173,
+        174,
+        175,
+        176,
+        177,
+        178,
+        179,
+        180,
+        181,
+        182,
+        183,
+        184,
+        185,
+        186,
+        187,
+        188,
+        189,
+        190,
+        191,
+        192,
+        193,
+        194,
+        195,
+        196,
+        197,
+        198,
+        199,
+        200,
+        201,
+        202,
+        203,
+        204,
+        205,
+        206,
+        207,
+        208,
+        209,
+        210,
+        212,
+        211,
+        213,
+        217,
+        214,
+        215,
+        216,
+        218,
+        219,
+        220,
+        221,
+        222,
+        223,
+        224,
+        225,
+        226,
+        227,
+        228,
+        229,
+        230,
+        231,
+        232,
+        233,
+        234,
+        235,
+        236,
+        237,
+        238,
+        239,
+        240,
+        241,
+        242,
+        243,
+        244,
+        245,
+        246,
+        247,
+        248,
+        249,
+        250,
+        251,
+        252,
+        253,
+        254,
+        255,
+        256,
+        257,
+        258,
+        259,
+        260,
+        261,
+        262,
+        263,
+        264,
+        265,
+        266,
+        267,
+        268,
+        269,
+        270,
+        271,
+        272,
+        273,
+        274,
+        275,
+        276,
+        277,
+        278,
+        279,
+        280,
+        281,
+        282,
+        283,
+        284,
+        285,
+        286,
+        287,
+        288,
+        289,
+        290,
+        291,
+        292,
+        293,
+        294,
+        295,
+        296,
+        297,
+        298,
+        299,
+        300,
+        301,
+        302,
+        303,
+        304,
+        305,
+        306,
+        307,
+        308,
+        309,
+        310,
+        311,
+        315,
+        312,
+        313,
+        314,
+        316,
+    ],
+    codes: &[
+        547,
+        548,
+        549,
+        550,
+        551,
+        552,
+        553,
+        554,
+        555,
+        556,
+        557,
+        558,
+        559,
+        560,
+        561,
+        562,
+        563,
+        564,
+        565,
+        566,
+        567,
+        568,
+        569,
+        570,
+        571,
+        572,
+        573,
+        574,
+        575,
+        576,
+        577,
+        578,
+        579,
+        580,
+        581,
+        582,
+        583,
+        584,
+        585,
+        586,
+        587,
+        588,
+        589,
+        590,
+        591,
+        592,
+        593,
+        594,
+        595,
+        596,
+        597,
+        598,
+        599,
+        600,
+        601,
+        602,
+        603,
+        604,
+        605,
+        606,
+        607,
+        608,
+        609,
+        610,
+        611,
+        612,
+        613,
+        614,
+        615,
+        616,
+        617,
+        618,
+        619,
+        620,
+        621,
+        622,
+        623,
+        624,
+        625,
+        626,
+        627,
+        628,
+        629,
+        630,
+        631,
+        632,
+        633,
+        634,
+        635,
+        636,
+        637,
+        638,
+        639,
+        640,
+        641,
+        642,
+        643,
+        644,
+        645,
+        646,
+        647,
+        648,
+        649,
+        650,
+        651,
+        652,
+        653,
+        654,
+        655,
+        656,
+        657,
+        658,
+        659,
+        660,
+        661,
+        662,
+        663,
+        664,
+        665,
+        666,
+        667,
+        668,
+        669,
+        670,
+        671,
+        672,
+        673,
+        674,
+        675,
+        676,
+        677,
+        678,
+        679,
+        680,
+        681,
+        682,
+        683,
+        684,
+        685,
+        686,
+        687,
+        688,
+        689,
+        690,
+        691,
+        692,
+        693,
+        694,
+        695,
+        696,
+        697,
+        698,
+        699,
+        700,
+        701,
+        702,
+        703,
+        704,
+        705,
+        706,
+        707,
+        708,
+        709,
+        710,
+        711,
+        712,
+        713,
+        714,
+        715,
+        716,
+        717,
+        718,
+        719,
+        720,
+        721,
+        722,
+        723,
+        724,
+        725,
+        726,
+        727,
+        728,
+        729,
+        730,
+        731,
+        732,
+        733,
+        734,
+        735,
+        736,
+        737,
+        738,
+        739,
+        740,
+        741,
+        742,
+        743,
+        744,
+        745,
+        746,
+        747,
+        748,
+        749,
+        750,
+        751,
+        752,
+        753,
+        754,
+        755,
+        756,
+        757,
+        758,
+        759,
+        760,
+        761,
+        762,
+        763,
+        764,
+        765,
+        766,
+        767,
+        768,
+        769,
+        770,
+        771,
+        772,
+        773,
+        774,
+        775,
+        776,
+        777,
+        778,
+        779,
+        780,
+        781,
+        782,
+        783,
+        784,
+        785,
+        786,
+        787,
+        788,
+        789,
+        790,
+        791,
+        792,
+        793,
+        794,
+        795,
+        796,
+        797,
+        798,
+        799,
+        800,
+        801,
+        802,
+        803,
+        804,
+        805,
+        806,
+        807,
+        808,
+        809,
+        810,
+        811,
+        812,
+        813,
+        814,
+        815,
+        816,
+        817,
+        818,
+        819,
+        820,
+        821,
+        822,
+        823,
+        824,
+        825,
+        826,
+        827,
+        828,
+        829,
+        830,
+        831,
+        832,
+        833,
+        834,
+        835,
+        836,
+        837,
+        838,
+        839,
+        840,
+        841,
+        842,
+        843,
+        844,
+        845,
+        846,
+        847,
+        848,
+        849,
+        850,
+        851,
+        852,
+        853,
+        854,
+        855,
+        856,
+        857,
+        858,
+        859,
+        860,
+        861,
+        862,
+        863,
+    ],
+};
+
+pub(super) static SEGMENTS: [&[&str]; 317] = [
+    &["Topic", "Adaptive Technologies"],
+    &["Topic", "Artistic Software"],
+    &["Topic", "Communications"],
+    &["Topic", "Communications", "BBS"],
+    &["Topic", "Communications", "Chat"],
+    &["Topic", "Communications", "Chat", "ICQ"],
+    &["Topic", "Communications", "Chat", "Internet Relay Chat"],
+    &["Topic", "Communications", "Chat", "Unix Talk"],
+    &["Topic", "Communications", "Conferencing"],
+    &["Topic", "Communications", "Email"],
+    &["Topic", "Communications", "Email", "Address Book"],
+    &["Topic", "Communications", "Email", "Email Clients (MUA)"],
+    &["Topic", "Communications", "Email", "Filters"],
+    &["Topic", "Communications", "Email", "Mail Transport Agents"],
+    &["Topic", "Communications", "Email", "Mailing List Servers"],
+    &["Topic", "Communications", "Email", "Post-Office"],
+    &["Topic", "Communications", "Email", "Post-Office", "IMAP"],
+    &["Topic", "Communications", "Email", "Post-Office", "POP3"],
+    &["Topic", "Communications", "FIDO"],
+    &["Topic", "Communications", "Fax"],
+    &["Topic", "Communications", "File Sharing"],
+    &["Topic", "Communications", "File Sharing", "Gnutella"],
+    &["Topic", "Communications", "File Sharing", "Napster"],
+    &["Topic", "Communications", "Ham Radio"],
+    &["Topic", "Communications", "Internet Phone"],
+    &["Topic", "Communications", "Telephony"],
+    &["Topic", "Communications", "Usenet News"],
+    &["Topic", "Database"],
+    &["Topic", "Database", "Database Engines/Servers"],
+    &["Topic", "Database", "Front-Ends"],
+    &["Topic", "Desktop Environment"],
+    &["Topic", "Desktop Environment", "File Managers"],
+    &["Topic", "Desktop Environment", "GNUstep"],
+    &["Topic", "Desktop Environment", "Gnome"],
+    &[
+        "Topic",
+        "Desktop Environment",
+        "K Desktop Environment (KDE)",
+    ],
+    &[
+        "Topic",
+        "Desktop Environment",
+        "K Desktop Environment (KDE)",
+        "Themes",
+    ],
+    &["Topic", "Desktop Environment", "PicoGUI"],
+    &["Topic", "Desktop Environment", "PicoGUI", "Applications"],
+    &["Topic", "Desktop Environment", "PicoGUI", "Themes"],
+    &["Topic", "Desktop Environment", "Screen Savers"],
+    &["Topic", "Desktop Environment", "Window Managers"],
+    &[
+        "Topic",
+        "Desktop Environment",
+        "Window Managers",
+        "Afterstep",
+    ],
+    &[
+        "Topic",
+        "Desktop Environment",
+        "Window Managers",
+        "Afterstep",
+        "Themes",
+    ],
+    &["Topic", "Desktop Environment", "Window Managers", "Applets"],
+    &[
+        "Topic",
+        "Desktop Environment",
+        "Window Managers",
+        "Blackbox",
+    ],
+    &[
+        "Topic",
+        "Desktop Environment",
+        "Window Managers",
+        "Blackbox",
+        "Themes",
+    ],
+    &["Topic", "Desktop Environment", "Window Managers", "CTWM"],
+    &[
+        "Topic",
+        "Desktop Environment",
+        "Window Managers",
+        "CTWM",
+        "Themes",
+    ],
+    &[
+        "Topic",
+        "Desktop Environment",
+        "Window Managers",
+        "Enlightenment",
+    ],
+    &[
+        "Topic",
+        "Desktop Environment",
+        "Window Managers",
+        "Enlightenment",
+        "Epplets",
+    ],
+    &[
+        "Topic",
+        "Desktop Environment",
+        "Window Managers",
+        "Enlightenment",
+        "Themes DR15",
+    ],
+    &[
+        "Topic",
+        "Desktop Environment",
+        "Window Managers",
+        "Enlightenment",
+        "Themes DR16",
+    ],
+    &[
+        "Topic",
+        "Desktop Environment",
+        "Window Managers",
+        "Enlightenment",
+        "Themes DR17",
+    ],
+    &["Topic", "Desktop Environment", "Window Managers", "FVWM"],
+    &[
+        "Topic",
+        "Desktop Environment",
+        "Window Managers",
+        "FVWM",
+        "Themes",
+    ],
+    &["Topic", "Desktop Environment", "Window Managers", "Fluxbox"],
+    &[
+        "Topic",
+        "Desktop Environment",
+        "Window Managers",
+        "Fluxbox",
+        "Themes",
+    ],
+    &["Topic", "Desktop Environment", "Window Managers", "IceWM"],
+    &[
+        "Topic",
+        "Desktop Environment",
+        "Window Managers",
+        "IceWM",
+        "Themes",
+    ],
+    &[
+        "Topic",
+        "Desktop Environment",
+        "Window Managers",
+        "MetaCity",
+    ],
+    &[
+        "Topic",
+        "Desktop Environment",
+        "Window Managers",
+        "MetaCity",
+        "Themes",
+    ],
+    &[
+        "Topic",
+        "Desktop Environment",
+        "Window Managers",
+        "Oroborus",
+    ],
+    &[
+        "Topic",
+        "Desktop Environment",
+        "Window Managers",
+        "Oroborus",
+        "Themes",
+    ],
+    &["Topic", "Desktop Environment", "Window Managers", "Sawfish"],
+    &[
+        "Topic",
+        "Desktop Environment",
+        "Window Managers",
+        "Sawfish",
+        "Themes 0.30",
+    ],
+    &[
+        "Topic",
+        "Desktop Environment",
+        "Window Managers",
+        "Sawfish",
+        "Themes pre-0.30",
+    ],
+    &["Topic", "Desktop Environment", "Window Managers", "Waimea"],
+    &[
+        "Topic",
+        "Desktop Environment",
+        "Window Managers",
+        "Waimea",
+        "Themes",
+    ],
+    &[
+        "Topic",
+        "Desktop Environment",
+        "Window Managers",
+        "Window Maker",
+    ],
+    &[
+        "Topic",
+        "Desktop Environment",
+        "Window Managers",
+        "Window Maker",
+        "Applets",
+    ],
+    &[
+        "Topic",
+        "Desktop Environment",
+        "Window Managers",
+        "Window Maker",
+        "Themes",
+    ],
+    &["Topic", "Desktop Environment", "Window Managers", "XFCE"],
+    &[
+        "Topic",
+        "Desktop Environment",
+        "Window Managers",
+        "XFCE",
+        "Themes",
+    ],
+    &["Topic", "Documentation"],
+    &["Topic", "Documentation", "Sphinx"],
+    &["Topic", "Education"],
+    &["Topic", "Education", "Computer Aided Instruction (CAI)"],
+    &["Topic", "Education", "Testing"],
+    &["Topic", "File Formats"],
+    &["Topic", "File Formats", "JSON"],
+    &["Topic", "File Formats", "JSON", "JSON Schema"],
+    &["Topic", "Games/Entertainment"],
+    &["Topic", "Games/Entertainment", "Arcade"],
+    &["Topic", "Games/Entertainment", "Board Games"],
+    &["Topic", "Games/Entertainment", "First Person Shooters"],
+    &["Topic", "Games/Entertainment", "Fortune Cookies"],
+    &["Topic", "Games/Entertainment", "Multi-User Dungeons (MUD)"],
+    &["Topic", "Games/Entertainment", "Puzzle Games"],
+    &["Topic", "Games/Entertainment", "Real Time Strategy"],
+    &["Topic", "Games/Entertainment", "Role-Playing"],
+    &[
+        "Topic",
+        "Games/Entertainment",
+        "Side-Scrolling/Arcade Games",
+    ],
+    &["Topic", "Games/Entertainment", "Simulation"],
+    &["Topic", "Games/Entertainment", "Turn Based Strategy"],
+    &["Topic", "Home Automation"],
+    &["Topic", "Internet"],
+    &["Topic", "Internet", "File Transfer Protocol (FTP)"],
+    &["Topic", "Internet", "Finger"],
+    &["Topic", "Internet", "Log Analysis"],
+    &["Topic", "Internet", "Name Service (DNS)"],
+    &["Topic", "Internet", "Proxy Servers"],
+    &["Topic", "Internet", "WAP"],
+    &["Topic", "Internet", "WWW/HTTP"],
+    &["Topic", "Internet", "WWW/HTTP", "Browsers"],
+    &["Topic", "Internet", "WWW/HTTP", "Dynamic Content"],
+    &[
+        "Topic",
+        "Internet",
+        "WWW/HTTP",
+        "Dynamic Content",
+        "CGI Tools/Libraries",
+    ],
+    &[
+        "Topic",
+        "Internet",
+        "WWW/HTTP",
+        "Dynamic Content",
+        "Content Management System",
+    ],
+    &[
+        "Topic",
+        "Internet",
+        "WWW/HTTP",
+        "Dynamic Content",
+        "Message Boards",
+    ],
+    &[
+        "Topic",
+        "Internet",
+        "WWW/HTTP",
+        "Dynamic Content",
+        "News/Diary",
+    ],
+    &[
+        "Topic",
+        "Internet",
+        "WWW/HTTP",
+        "Dynamic Content",
+        "Page Counters",
+    ],
+    &["Topic", "Internet", "WWW/HTTP", "Dynamic Content", "Wiki"],
+    &["Topic", "Internet", "WWW/HTTP", "HTTP Servers"],
+    &["Topic", "Internet", "WWW/HTTP", "Indexing/Search"],
+    &["Topic", "Internet", "WWW/HTTP", "Session"],
+    &["Topic", "Internet", "WWW/HTTP", "Site Management"],
+    &[
+        "Topic",
+        "Internet",
+        "WWW/HTTP",
+        "Site Management",
+        "Link Checking",
+    ],
+    &["Topic", "Internet", "WWW/HTTP", "WSGI"],
+    &["Topic", "Internet", "WWW/HTTP", "WSGI", "Application"],
+    &["Topic", "Internet", "WWW/HTTP", "WSGI", "Middleware"],
+    &["Topic", "Internet", "WWW/HTTP", "WSGI", "Server"],
+    &["Topic", "Internet", "XMPP"],
+    &["Topic", "Internet", "Z39.50"],
+    &["Topic", "Multimedia"],
+    &["Topic", "Multimedia", "Graphics"],
+    &["Topic", "Multimedia", "Graphics", "3D Modeling"],
+    &["Topic", "Multimedia", "Graphics", "3D Rendering"],
+    &["Topic", "Multimedia", "Graphics", "Capture"],
+    &[
+        "Topic",
+        "Multimedia",
+        "Graphics",
+        "Capture",
+        "Digital Camera",
+    ],
+    &["Topic", "Multimedia", "Graphics", "Capture", "Scanners"],
+    &[
+        "Topic",
+        "Multimedia",
+        "Graphics",
+        "Capture",
+        "Screen Capture",
+    ],
+    &["Topic", "Multimedia", "Graphics", "Editors"],
+    &["Topic", "Multimedia", "Graphics", "Editors", "Raster-Based"],
+    &["Topic", "Multimedia", "Graphics", "Editors", "Vector-Based"],
+    &["Topic", "Multimedia", "Graphics", "Graphics Conversion"],
+    &["Topic", "Multimedia", "Graphics", "Presentation"],
+    &["Topic", "Multimedia", "Graphics", "Viewers"],
+    &["Topic", "Multimedia", "Sound/Audio"],
+    &["Topic", "Multimedia", "Sound/Audio", "Analysis"],
+    &["Topic", "Multimedia", "Sound/Audio", "CD Audio"],
+    &[
+        "Topic",
+        "Multimedia",
+        "Sound/Audio",
+        "CD Audio",
+        "CD Playing",
+    ],
+    &[
+        "Topic",
+        "Multimedia",
+        "Sound/Audio",
+        "CD Audio",
+        "CD Ripping",
+    ],
+    &[
+        "Topic",
+        "Multimedia",
+        "Sound/Audio",
+        "CD Audio",
+        "CD Writing",
+    ],
+    &["Topic", "Multimedia", "Sound/Audio", "Capture/Recording"],
+    &["Topic", "Multimedia", "Sound/Audio", "Conversion"],
+    &["Topic", "Multimedia", "Sound/Audio", "Editors"],
+    &["Topic", "Multimedia", "Sound/Audio", "MIDI"],
+    &["Topic", "Multimedia", "Sound/Audio", "Mixers"],
+    &["Topic", "Multimedia", "Sound/Audio", "Players"],
+    &["Topic", "Multimedia", "Sound/Audio", "Players", "MP3"],
+    &["Topic", "Multimedia", "Sound/Audio", "Sound Synthesis"],
+    &["Topic", "Multimedia", "Sound/Audio", "Speech"],
+    &["Topic", "Multimedia", "Video"],
+    &["Topic", "Multimedia", "Video", "Capture"],
+    &["Topic", "Multimedia", "Video", "Conversion"],
+    &["Topic", "Multimedia", "Video", "Display"],
+    &["Topic", "Multimedia", "Video", "Non-Linear Editor"],
+    &["Topic", "Office/Business"],
+    &["Topic", "Office/Business", "Financial"],
+    &["Topic", "Office/Business", "Financial", "Accounting"],
+    &["Topic", "Office/Business", "Financial", "Investment"],
+    &["Topic", "Office/Business", "Financial", "Point-Of-Sale"],
+    &["Topic", "Office/Business", "Financial", "Spreadsheet"],
+    &["Topic", "Office/Business", "Groupware"],
+    &["Topic", "Office/Business", "News/Diary"],
+    &["Topic", "Office/Business", "Office Suites"],
+    &["Topic", "Office/Business", "Scheduling"],
+    &["Topic", "Other/Nonlisted Topic"],
+    &["Topic", "Printing"],
+    &["Topic", "Religion"],
+    &["Topic", "Scientific/Engineering"],
+    &["Topic", "Scientific/Engineering", "Artificial Intelligence"],
+    &["Topic", "Scientific/Engineering", "Artificial Life"],
+    &["Topic", "Scientific/Engineering", "Astronomy"],
+    &["Topic", "Scientific/Engineering", "Atmospheric Science"],
+    &["Topic", "Scientific/Engineering", "Bio-Informatics"],
+    &["Topic", "Scientific/Engineering", "Chemistry"],
+    &[
+        "Topic",
+        "Scientific/Engineering",
+        "Electronic Design Automation (EDA)",
+    ],
+    &["Topic", "Scientific/Engineering", "GIS"],
+    &[
+        "Topic",
+        "Scientific/Engineering",
+        "Human Machine Interfaces",
+    ],
+    &["Topic", "Scientific/Engineering", "Hydrology"],
+    &["Topic", "Scientific/Engineering", "Image Processing"],
+    &["Topic", "Scientific/Engineering", "Image Recognition"],
+    &["Topic", "Scientific/Engineering", "Information Analysis"],
+    &[
+        "Topic",
+        "Scientific/Engineering",
+        "Interface Engine/Protocol Translator",
+    ],
+    &["Topic", "Scientific/Engineering", "Mathematics"],
+    &["Topic", "Scientific/Engineering", "Medical Science Apps."],
+    &["Topic", "Scientific/Engineering", "Oceanography"],
+    &["Topic", "Scientific/Engineering", "Physics"],
+    &["Topic", "Scientific/Engineering", "Visualization"],
+    &["Topic", "Security"],
+    &["Topic", "Security", "Cryptography"],
+    &["Topic", "Sociology"],
+    &["Topic", "Sociology", "Genealogy"],
+    &["Topic", "Sociology", "History"],
+    &["Topic", "Software Development"],
+    &["Topic", "Software Development", "Assemblers"],
+    &["Topic", "Software Development", "Bug Tracking"],
+    &["Topic", "Software Development", "Build Tools"],
+    &["Topic", "Software Development", "Code Generators"],
+    &["Topic", "Software Development", "Compilers"],
+    &["Topic", "Software Development", "Debuggers"],
+    &["Topic", "Software Development", "Disassemblers"],
+    &["Topic", "Software Development", "Documentation"],
+    &["Topic", "Software Development", "Embedded Systems"],
+    &[
+        "Topic",
+        "Software Development",
+        "Embedded Systems",
+        "Controller Area Network (CAN)",
+    ],
+    &[
+        "Topic",
+        "Software Development",
+        "Embedded Systems",
+        "Controller Area Network (CAN)",
+        "CANopen",
+    ],
+    &[
+        "Topic",
+        "Software Development",
+        "Embedded Systems",
+        "Controller Area Network (CAN)",
+        "J1939",
+    ],
+    &["Topic", "Software Development", "Internationalization"],
+    &["Topic", "Software Development", "Interpreters"],
+    &["Topic", "Software Development", "Libraries"],
+    &[
+        "Topic",
+        "Software Development",
+        "Libraries",
+        "Application Frameworks",
+    ],
+    &[
+        "Topic",
+        "Software Development",
+        "Libraries",
+        "Java Libraries",
+    ],
+    &["Topic", "Software Development", "Libraries", "PHP Classes"],
+    &["Topic", "Software Development", "Libraries", "Perl Modules"],
+    &["Topic", "Software Development", "Libraries", "Pike Modules"],
+    &[
+        "Topic",
+        "Software Development",
+        "Libraries",
+        "Python Modules",
+    ],
+    &["Topic", "Software Development", "Libraries", "Ruby Modules"],
+    &[
+        "Topic",
+        "Software Development",
+        "Libraries",
+        "Tcl Extensions",
+    ],
+    &["Topic", "Software Development", "Libraries", "pygame"],
+    &["Topic", "Software Development", "Localization"],
+    &["Topic", "Software Development", "Object Brokering"],
+    &["Topic", "Software Development", "Object Brokering", "CORBA"],
+    &["Topic", "Software Development", "Pre-processors"],
+    &["Topic", "Software Development", "Quality Assurance"],
+    &["Topic", "Software Development", "Testing"],
+    &["Topic", "Software Development", "Testing", "Acceptance"],
+    &["Topic", "Software Development", "Testing", "BDD"],
+    &["Topic", "Software Development", "Testing", "Mocking"],
+    &[
+        "Topic",
+        "Software Development",
+        "Testing",
+        "Traffic Generation",
+    ],
+    &["Topic", "Software Development", "Testing", "Unit"],
+    &["Topic", "Software Development", "User Interfaces"],
+    &["Topic", "Software Development", "Version Control"],
+    &["Topic", "Software Development", "Version Control", "Bazaar"],
+    &["Topic", "Software Development", "Version Control", "CVS"],
+    &["Topic", "Software Development", "Version Control", "Git"],
+    &[
+        "Topic",
+        "Software Development",
+        "Version Control",
+        "Mercurial",
+    ],
+    &["Topic", "Software Development", "Version Control", "RCS"],
+    &["Topic", "Software Development", "Version Control", "SCCS"],
+    &["Topic", "Software Development", "Widget Sets"],
+    &["Topic", "System"],
+    &["Topic", "System", "Archiving"],
+    &["Topic", "System", "Archiving", "Backup"],
+    &["Topic", "System", "Archiving", "Compression"],
+    &["Topic", "System", "Archiving", "Mirroring"],
+    &["Topic", "System", "Archiving", "Packaging"],
+    &["Topic", "System", "Benchmark"],
+    &["Topic", "System", "Boot"],
+    &["Topic", "System", "Boot", "Init"],
+    &["Topic", "System", "Clustering"],
+    &["Topic", "System", "Console Fonts"],
+    &["Topic", "System", "Distributed Computing"],
+    &["Topic", "System", "Emulators"],
+    &["Topic", "System", "Filesystems"],
+    &["Topic", "System", "Hardware"],
+    &["Topic", "System", "Hardware", "Hardware Drivers"],
+    &["Topic", "System", "Hardware", "Mainframes"],
+    &["Topic", "System", "Hardware", "Symmetric Multi-processing"],
+    &["Topic", "System", "Hardware", "Universal Serial Bus (USB)"],
+    &[
+        "Topic",
+        "System",
+        "Hardware",
+        "Universal Serial Bus (USB)",
+        "Audio",
+    ],
+    &[
+        "Topic",
+        "System",
+        "Hardware",
+        "Universal Serial Bus (USB)",
+        "Audio/Video (AV)",
+    ],
+    &[
+        "Topic",
+        "System",
+        "Hardware",
+        "Universal Serial Bus (USB)",
+        "Communications Device Class (CDC)",
+    ],
+    &[
+        "Topic",
+        "System",
+        "Hardware",
+        "Universal Serial Bus (USB)",
+        "Diagnostic Device",
+    ],
+    &[
+        "Topic",
+        "System",
+        "Hardware",
+        "Universal Serial Bus (USB)",
+        "Hub",
+    ],
+    &[
+        "Topic",
+        "System",
+        "Hardware",
+        "Universal Serial Bus (USB)",
+        "Human Interface Device (HID)",
+    ],
+    &[
+        "Topic",
+        "System",
+        "Hardware",
+        "Universal Serial Bus (USB)",
+        "Mass Storage",
+    ],
+    &[
+        "Topic",
+        "System",
+        "Hardware",
+        "Universal Serial Bus (USB)",
+        "Miscellaneous",
+    ],
+    &[
+        "Topic",
+        "System",
+        "Hardware",
+        "Universal Serial Bus (USB)",
+        "Printer",
+    ],
+    &[
+        "Topic",
+        "System",
+        "Hardware",
+        "Universal Serial Bus (USB)",
+        "Smart Card",
+    ],
+    &[
+        "Topic",
+        "System",
+        "Hardware",
+        "Universal Serial Bus (USB)",
+        "Vendor",
+    ],
+    &[
+        "Topic",
+        "System",
+        "Hardware",
+        "Universal Serial Bus (USB)",
+        "Video (UVC)",
+    ],
+    &[
+        "Topic",
+        "System",
+        "Hardware",
+        "Universal Serial Bus (USB)",
+        "Wireless Controller",
+    ],
+    &["Topic", "System", "Installation/Setup"],
+    &["Topic", "System", "Logging"],
+    &["Topic", "System", "Monitoring"],
+    &["Topic", "System", "Networking"],
+    &["Topic", "System", "Networking", "Firewalls"],
+    &["Topic", "System", "Networking", "Monitoring"],
+    &[
+        "Topic",
+        "System",
+        "Networking",
+        "Monitoring",
+        "Hardware Watchdog",
+    ],
+    &["Topic", "System", "Networking", "Time Synchronization"],
+    &["Topic", "System", "Operating System"],
+    &["Topic", "System", "Operating System Kernels"],
+    &["Topic", "System", "Operating System Kernels", "BSD"],
+    &["Topic", "System", "Operating System Kernels", "GNU Hurd"],
+    &["Topic", "System", "Operating System Kernels", "Linux"],
+    &["Topic", "System", "Power (UPS)"],
+    &["Topic", "System", "Recovery Tools"],
+    &["Topic", "System", "Shells"],
+    &["Topic", "System", "Software Distribution"],
+    &["Topic", "System", "System Shells"],
+    &["Topic", "System", "Systems Administration"],
+    &[
+        "Topic",
+        "System",
+        "Systems Administration",
+        "Authentication/Directory",
+    ],
+    &[
+        "Topic",
+        "System",
+        "Systems Administration",
+        "Authentication/Directory",
+        "LDAP",
+    ],
+    &[
+        "Topic",
+        "System",
+        "Systems Administration",
+        "Authentication/Directory",
+        "NIS",
+    ],
+    &["Topic", "Terminals"],
+    &["Topic", "Terminals", "Serial"],
+    &["Topic", "Terminals", "Telnet"],
+    &["Topic", "Terminals", "Terminal Emulators/X Terminals"],
+    &["Topic", "Text Editors"],
+    &["Topic", "Text Editors", "Documentation"],
+    &["Topic", "Text Editors", "Emacs"],
+    &[
+        "Topic",
+        "Text Editors",
+        "Integrated Development Environments (IDE)",
+    ],
+    &["Topic", "Text Editors", "Text Processing"],
+    &["Topic", "Text Editors", "Word Processors"],
+    &["Topic", "Text Processing"],
+    &["Topic", "Text Processing", "Filters"],
+    &["Topic", "Text Processing", "Fonts"],
+    &["Topic", "Text Processing", "General"],
+    &["Topic", "Text Processing", "Indexing"],
+    &["Topic", "Text Processing", "Linguistic"],
+    &["Topic", "Text Processing", "Markup"],
+    &["Topic", "Text Processing", "Markup", "HTML"],
+    &["Topic", "Text Processing", "Markup", "LaTeX"],
+    &["Topic", "Text Processing", "Markup", "Markdown"],
+    &["Topic", "Text Processing", "Markup", "SGML"],
+    &["Topic", "Text Processing", "Markup", "VRML"],
+    &["Topic", "Text Processing", "Markup", "XML"],
+    &["Topic", "Text Processing", "Markup", "reStructuredText"],
+    &["Topic", "Utilities"],
+];
+
+pub(super) static VARIANTS: [&str; 317] = [
+    "Topic__AdaptiveTechnologies",
+    "Topic__ArtisticSoftware",
+    "Topic__Communications",
+    "Topic__Communications__BBS",
+    "Topic__Communications__Chat",
+    "Topic__Communications__Chat__ICQ",
+    "Topic__Communications__Chat__InternetRelayChat",
+    "Topic__Communications__Chat__UnixTalk",
+    "Topic__Communications__Conferencing",
+    "Topic__Communications__Email",
+    "Topic__Communications__Email__AddressBook",
+    "Topic__Communications__Email__EmailClientsMUA",
+    "Topic__Communications__Email__Filters",
+    "Topic__Communications__Email__MailTransportAgents",
+    "Topic__Communications__Email__MailingListServers",
+    "Topic__Communications__Email__PostOffice",
+    "Topic__Communications__Email__PostOffice__IMAP",
+    "Topic__Communications__Email__PostOffice__POP3",
+    "Topic__Communications__FIDO",
+    "Topic__Communications__Fax",
+    "Topic__Communications__FileSharing",
+    "Topic__Communications__FileSharing__Gnutella",
+    "Topic__Communications__FileSharing__Napster",
+    "Topic__Communications__HamRadio",
+    "Topic__Communications__InternetPhone",
+    "Topic__Communications__Telephony",
+    "Topic__Communications__UsenetNews",
+    "Topic__Database",
+    "Topic__Database__DatabaseEnginesServers",
+    "Topic__Database__FrontEnds",
+    "Topic__DesktopEnvironment",
+    "Topic__DesktopEnvironment__FileManagers",
+    "Topic__DesktopEnvironment__GNUstep",
+    "Topic__DesktopEnvironment__Gnome",
+    "Topic__DesktopEnvironment__KDesktopEnvironmentKDE",
+    "Topic__DesktopEnvironment__KDesktopEnvironmentKDE__Themes",
+    "Topic__DesktopEnvironment__PicoGUI",
+    "Topic__DesktopEnvironment__PicoGUI__Applications",
+    "Topic__DesktopEnvironment__PicoGUI__Themes",
+    "Topic__DesktopEnvironment__ScreenSavers",
+    "Topic__DesktopEnvironment__WindowManagers",
+    "Topic__DesktopEnvironment__WindowManagers__Afterstep",
+    "Topic__DesktopEnvironment__WindowManagers__Afterstep__Themes",
+    "Topic__DesktopEnvironment__WindowManagers__Applets",
+    "Topic__DesktopEnvironment__WindowManagers__Blackbox",
+    "Topic__DesktopEnvironment__WindowManagers__Blackbox__Themes",
+    "Topic__DesktopEnvironment__WindowManagers__CTWM",
+    "Topic__DesktopEnvironment__WindowManagers__CTWM__Themes",
+    "Topic__DesktopEnvironment__WindowManagers__Enlightenment",
+    "Topic__DesktopEnvironment__WindowManagers__Enlightenment__Epplets",
+    "Topic__DesktopEnvironment__WindowManagers__Enlightenment__ThemesDR15",
+    "Topic__DesktopEnvironment__WindowManagers__Enlightenment__ThemesDR16",
+    "Topic__DesktopEnvironment__WindowManagers__Enlightenment__ThemesDR17",
+    "Topic__DesktopEnvironment__WindowManagers__FVWM",
+    "Topic__DesktopEnvironment__WindowManagers__FVWM__Themes",
+    "Topic__DesktopEnvironment__WindowManagers__Fluxbox",
+    "Topic__DesktopEnvironment__WindowManagers__Fluxbox__Themes",
+    "Topic__DesktopEnvironment__WindowManagers__IceWM",
+    "Topic__DesktopEnvironment__WindowManagers__IceWM__Themes",
+    "Topic__DesktopEnvironment__WindowManagers__MetaCity",
+    "Topic__DesktopEnvironment__WindowManagers__MetaCity__Themes",
+    "Topic__DesktopEnvironment__WindowManagers__Oroborus",
+    "Topic__DesktopEnvironment__WindowManagers__Oroborus__Themes",
+    "Topic__DesktopEnvironment__WindowManagers__Sawfish",
+    "Topic__DesktopEnvironment__WindowManagers__Sawfish__Themes0_30",
+    "Topic__DesktopEnvironment__WindowManagers__Sawfish__Themespre0_30",
+    "Topic__DesktopEnvironment__WindowManagers__Waimea",
+    "Topic__DesktopEnvironment__WindowManagers__Waimea__Themes",
+    "Topic__DesktopEnvironment__WindowManagers__WindowMaker",
+    "Topic__DesktopEnvironment__WindowManagers__WindowMaker__Applets",
+    "Topic__DesktopEnvironment__WindowManagers__WindowMaker__Themes",
+    "Topic__DesktopEnvironment__WindowManagers__XFCE",
+    "Topic__DesktopEnvironment__WindowManagers__XFCE__Themes",
+    "Topic__Documentation",
+    "Topic__Documentation__Sphinx",
+    "Topic__Education",
+    "Topic__Education__ComputerAidedInstructionCAI",
+    "Topic__Education__Testing",
+    "Topic__FileFormats",
+    "Topic__FileFormats__JSON",
+    "Topic__FileFormats__JSON__JSONSchema",
+    "Topic__GamesEntertainment",
+    "Topic__GamesEntertainment__Arcade",
+    "Topic__GamesEntertainment__BoardGames",
+    "Topic__GamesEntertainment__FirstPersonShooters",
+    "Topic__GamesEntertainment__FortuneCookies",
+    "Topic__GamesEntertainment__MultiUserDungeonsMUD",
+    "Topic__GamesEntertainment__PuzzleGames",
+    "Topic__GamesEntertainment__RealTimeStrategy",
+    "Topic__GamesEntertainment__RolePlaying",
+    "Topic__GamesEntertainment__SideScrollingArcadeGames",
+    "Topic__GamesEntertainment__Simulation",
+    "Topic__GamesEntertainment__TurnBasedStrategy",
+    "Topic__HomeAutomation",
+    "Topic__Internet",
+    "Topic__Internet__FileTransferProtocolFTP",
+    "Topic__Internet__Finger",
+    "Topic__Internet__LogAnalysis",
+    "Topic__Internet__NameServiceDNS",
+    "Topic__Internet__ProxyServers",
+    "Topic__Internet__WAP",
+    "Topic__Internet__WWWHTTP",
+    "Topic__Internet__WWWHTTP__Browsers",
+    "Topic__Internet__WWWHTTP__DynamicContent",
+    "Topic__Internet__WWWHTTP__DynamicContent__CGIToolsLibraries",
+    "Topic__Internet__WWWHTTP__DynamicContent__ContentManagementSystem",
+    "Topic__Internet__WWWHTTP__DynamicContent__MessageBoards",
+    "Topic__Internet__WWWHTTP__DynamicContent__NewsDiary",
+    "Topic__Internet__WWWHTTP__DynamicContent__PageCounters",
+    "Topic__Internet__WWWHTTP__DynamicContent__Wiki",
+    "Topic__Internet__WWWHTTP__HTTPServers",
+    "Topic__Internet__WWWHTTP__IndexingSearch",
+    "Topic__Internet__WWWHTTP__Session",
+    "Topic__Internet__WWWHTTP__SiteManagement",
+    "Topic__Internet__WWWHTTP__SiteManagement__LinkChecking",
+    "Topic__Internet__WWWHTTP__WSGI",
+    "Topic__Internet__WWWHTTP__WSGI__Application",
+    "Topic__Internet__WWWHTTP__WSGI__Middleware",
+    "Topic__Internet__WWWHTTP__WSGI__Server",
+    "Topic__Internet__XMPP",
+    "Topic__Internet__Z39_50",
+    "Topic__Multimedia",
+    "Topic__Multimedia__Graphics",
+    "Topic__Multimedia__Graphics__3DModeling",
+    "Topic__Multimedia__Graphics__3DRendering",
+    "Topic__Multimedia__Graphics__Capture",
+    "Topic__Multimedia__Graphics__Capture__DigitalCamera",
+    "Topic__Multimedia__Graphics__Capture__Scanners",
+    "Topic__Multimedia__Graphics__Capture__ScreenCapture",
+    "Topic__Multimedia__Graphics__Editors",
+    "Topic__Multimedia__Graphics__Editors__RasterBased",
+    "Topic__Multimedia__Graphics__Editors__VectorBased",
+    "Topic__Multimedia__Graphics__GraphicsConversion",
+    "Topic__Multimedia__Graphics__Presentation",
+    "Topic__Multimedia__Graphics__Viewers",
+    "Topic__Multimedia__SoundAudio",
+    "Topic__Multimedia__SoundAudio__Analysis",
+    "Topic__Multimedia__SoundAudio__CDAudio",
+    "Topic__Multimedia__SoundAudio__CDAudio__CDPlaying",
+    "Topic__Multimedia__SoundAudio__CDAudio__CDRipping",
+    "Topic__Multimedia__SoundAudio__CDAudio__CDWriting",
+    "Topic__Multimedia__SoundAudio__CaptureRecording",
+    "Topic__Multimedia__SoundAudio__Conversion",
+    "Topic__Multimedia__SoundAudio__Editors",
+    "Topic__Multimedia__SoundAudio__MIDI",
+    "Topic__Multimedia__SoundAudio__Mixers",
+    "Topic__Multimedia__SoundAudio__Players",
+    "Topic__Multimedia__SoundAudio__Players__MP3",
+    "Topic__Multimedia__SoundAudio__SoundSynthesis",
+    "Topic__Multimedia__SoundAudio__Speech",
+    "Topic__Multimedia__Video",
+    "Topic__Multimedia__Video__Capture",
+    "Topic__Multimedia__Video__Conversion",
+    "Topic__Multimedia__Video__Display",
+    "Topic__Multimedia__Video__NonLinearEditor",
+    "Topic__OfficeBusiness",
+    "Topic__OfficeBusiness__Financial",
+    "Topic__OfficeBusiness__Financial__Accounting",
+    "Topic__OfficeBusiness__Financial__Investment",
+    "Topic__OfficeBusiness__Financial__PointOfSale",
+    "Topic__OfficeBusiness__Financial__Spreadsheet",
+    "Topic__OfficeBusiness__Groupware",
+    "Topic__OfficeBusiness__NewsDiary",
+    "Topic__OfficeBusiness__OfficeSuites",
+    "Topic__OfficeBusiness__Scheduling",
+    "Topic__OtherNonlistedTopic",
+    "Topic__Printing",
+    "Topic__Religion",
+    "Topic__ScientificEngineering",
+    "Topic__ScientificEngineering__ArtificialIntelligence",
+    "Topic__ScientificEngineering__ArtificialLife",
+    "Topic__ScientificEngineering__Astronomy",
+    "Topic__ScientificEngineering__AtmosphericScience",
+    "Topic__ScientificEngineering__BioInformatics",
+    "Topic__ScientificEngineering__Chemistry",
+    "Topic__ScientificEngineering__ElectronicDesignAutomationEDA",
+    "Topic__ScientificEngineering__GIS",
+    "Topic__ScientificEngineering__HumanMachineInterfaces",
+    "Topic__ScientificEngineering__Hydrology",
+    "Topic__ScientificEngineering__ImageProcessing",
+    "Topic__ScientificEngineering__ImageRecognition",
+    "Topic__ScientificEngineering__InformationAnalysis",
+    "Topic__ScientificEngineering__InterfaceEngineProtocolTranslator",
+    "Topic__ScientificEngineering__Mathematics",
+    "Topic__ScientificEngineering__MedicalScienceApps_",
+    "Topic__ScientificEngineering__Oceanography",
+    "Topic__ScientificEngineering__Physics",
+    "Topic__ScientificEngineering__Visualization",
+    "Topic__Security",
+    "Topic__Security__Cryptography",
+    "Topic__Sociology",
+    "Topic__Sociology__Genealogy",
+    "Topic__Sociology__History",
+    "Topic__SoftwareDevelopment",
+    "Topic__SoftwareDevelopment__Assemblers",
+    "Topic__SoftwareDevelopment__BugTracking",
+    "Topic__SoftwareDevelopment__BuildTools",
+    "Topic__SoftwareDevelopment__CodeGenerators",
+    "Topic__SoftwareDevelopment__Compilers",
+    "Topic__SoftwareDevelopment__Debuggers",
+    "Topic__SoftwareDevelopment__Disassemblers",
+    "Topic__SoftwareDevelopment__Documentation",
+    "Topic__SoftwareDevelopment__EmbeddedSystems",
+    "Topic__SoftwareDevelopment__EmbeddedSystems__ControllerAreaNetworkCAN",
+    "Topic__SoftwareDevelopment__EmbeddedSystems__ControllerAreaNetworkCAN__CANopen",
+    "Topic__SoftwareDevelopment__EmbeddedSystems__ControllerAreaNetworkCAN__J1939",
+    "Topic__SoftwareDevelopment__Internationalization",
+    "Topic__SoftwareDevelopment__Interpreters",
+    "Topic__SoftwareDevelopment__Libraries",
+    "Topic__SoftwareDevelopment__Libraries__ApplicationFrameworks",
+    "Topic__SoftwareDevelopment__Libraries__JavaLibraries",
+    "Topic__SoftwareDevelopment__Libraries__PHPClasses",
+    "Topic__SoftwareDevelopment__Libraries__PerlModules",
+    "Topic__SoftwareDevelopment__Libraries__PikeModules",
+    "Topic__SoftwareDevelopment__Libraries__PythonModules",
+    "Topic__SoftwareDevelopment__Libraries__RubyModules",
+    "Topic__SoftwareDevelopment__Libraries__TclExtensions",
+    "Topic__SoftwareDevelopment__Libraries__pygame",
+    "Topic__SoftwareDevelopment__Localization",
+    "Topic__SoftwareDevelopment__ObjectBrokering",
+    "Topic__SoftwareDevelopment__ObjectBrokering__CORBA",
+    "Topic__SoftwareDevelopment__Preprocessors",
+    "Topic__SoftwareDevelopment__QualityAssurance",
+    "Topic__SoftwareDevelopment__Testing",
+    "Topic__SoftwareDevelopment__Testing__Acceptance",
+    "Topic__SoftwareDevelopment__Testing__BDD",
+    "Topic__SoftwareDevelopment__Testing__Mocking",
+    "Topic__SoftwareDevelopment__Testing__TrafficGeneration",
+    "Topic__SoftwareDevelopment__Testing__Unit",
+    "Topic__SoftwareDevelopment__UserInterfaces",
+    "Topic__SoftwareDevelopment__VersionControl",
+    "Topic__SoftwareDevelopment__VersionControl__Bazaar",
+    "Topic__SoftwareDevelopment__VersionControl__CVS",
+    "Topic__SoftwareDevelopment__VersionControl__Git",
+    "Topic__SoftwareDevelopment__VersionControl__Mercurial",
+    "Topic__SoftwareDevelopment__VersionControl__RCS",
+    "Topic__SoftwareDevelopment__VersionControl__SCCS",
+    "Topic__SoftwareDevelopment__WidgetSets",
+    "Topic__System",
+    "Topic__System__Archiving",
+    "Topic__System__Archiving__Backup",
+    "Topic__System__Archiving__Compression",
+    "Topic__System__Archiving__Mirroring",
+    "Topic__System__Archiving__Packaging",
+    "Topic__System__Benchmark",
+    "Topic__System__Boot",
+    "Topic__System__Boot__Init",
+    "Topic__System__Clustering",
+    "Topic__System__ConsoleFonts",
+    "Topic__System__DistributedComputing",
+    "Topic__System__Emulators",
+    "Topic__System__Filesystems",
+    "Topic__System__Hardware",
+    "Topic__System__Hardware__HardwareDrivers",
+    "Topic__System__Hardware__Mainframes",
+    "Topic__System__Hardware__SymmetricMultiprocessing",
+    "Topic__System__Hardware__UniversalSerialBusUSB",
+    "Topic__System__Hardware__UniversalSerialBusUSB__Audio",
+    "Topic__System__Hardware__UniversalSerialBusUSB__AudioVideoAV",
+    "Topic__System__Hardware__UniversalSerialBusUSB__CommunicationsDeviceClassCDC",
+    "Topic__System__Hardware__UniversalSerialBusUSB__DiagnosticDevice",
+    "Topic__System__Hardware__UniversalSerialBusUSB__Hub",
+    "Topic__System__Hardware__UniversalSerialBusUSB__HumanInterfaceDeviceHID",
+    "Topic__System__Hardware__UniversalSerialBusUSB__MassStorage",
+    "Topic__System__Hardware__UniversalSerialBusUSB__Miscellaneous",
+    "Topic__System__Hardware__UniversalSerialBusUSB__Printer",
+    "Topic__System__Hardware__UniversalSerialBusUSB__SmartCard",
+    "Topic__System__Hardware__UniversalSerialBusUSB__Vendor",
+    "Topic__System__Hardware__UniversalSerialBusUSB__VideoUVC",
+    "Topic__System__Hardware__UniversalSerialBusUSB__WirelessController",
+    "Topic__System__InstallationSetup",
+    "Topic__System__Logging",
+    "Topic__System__Monitoring",
+    "Topic__System__Networking",
+    "Topic__System__Networking__Firewalls",
+    "Topic__System__Networking__Monitoring",
+    "Topic__System__Networking__Monitoring__HardwareWatchdog",
+    "Topic__System__Networking__TimeSynchronization",
+    "Topic__System__OperatingSystem",
+    "Topic__System__OperatingSystemKernels",
+    "Topic__System__OperatingSystemKernels__BSD",
+    "Topic__System__OperatingSystemKernels__GNUHurd",
+    "Topic__System__OperatingSystemKernels__Linux",
+    "Topic__System__PowerUPS",
+    "Topic__System__RecoveryTools",
+    "Topic__System__Shells",
+    "Topic__System__SoftwareDistribution",
+    "Topic__System__SystemShells",
+    "Topic__System__SystemsAdministration",
+    "Topic__System__SystemsAdministration__AuthenticationDirectory",
+    "Topic__System__SystemsAdministration__AuthenticationDirectory__LDAP",
+    "Topic__System__SystemsAdministration__AuthenticationDirectory__NIS",
+    "Topic__Terminals",
+    "Topic__Terminals__Serial",
+    "Topic__Terminals__Telnet",
+    "Topic__Terminals__TerminalEmulatorsXTerminals",
+    "Topic__TextEditors",
+    "Topic__TextEditors__Documentation",
+    "Topic__TextEditors__Emacs",
+    "Topic__TextEditors__IntegratedDevelopmentEnvironmentsIDE",
+    "Topic__TextEditors__TextProcessing",
+    "Topic__TextEditors__WordProcessors",
+    "Topic__TextProcessing",
+    "Topic__TextProcessing__Filters",
+    "Topic__TextProcessing__Fonts",
+    "Topic__TextProcessing__General",
+    "Topic__TextProcessing__Indexing",
+    "Topic__TextProcessing__Linguistic",
+    "Topic__TextProcessing__Markup",
+    "Topic__TextProcessing__Markup__HTML",
+    "Topic__TextProcessing__Markup__LaTeX",
+    "Topic__TextProcessing__Markup__Markdown",
+    "Topic__TextProcessing__Markup__SGML",
+    "Topic__TextProcessing__Markup__VRML",
+    "Topic__TextProcessing__Markup__XML",
+    "Topic__TextProcessing__Markup__reStructuredText",
+    "Topic__Utilities",
+];
@@ -0,0 +1,17 @@
+// @generated by `cargo xtask codegen` from data/, do not edit by hand.
+
+use crate::table::CategoryTable;
+use crate::Classifier;
+
+pub(super) static TABLE: CategoryTable = CategoryTable {
+    name: "Typing",
+    classifiers: &[Classifier::Typing__StubsOnly, Classifier::Typing__Typed],
+    text: concat!("Typing :: Stubs Only", "Typing :: Typed",),
+    ends: &[20, 35],
+    sorted: &[0, 1],
+    codes: &[864, 865],
+};
+
+pub(super) static SEGMENTS: [&[&str]; 2] = [&["Typing", "Stubs Only"], &["Typing", "Typed"]];
+
+pub(super) static VARIANTS: [&str; 2] = ["Typing__StubsOnly", "Typing__Typed"];
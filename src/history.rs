@@ -0,0 +1,189 @@
+//! Earlier versions of the classifier dataset.
+//!
+//! Each time `cargo xtask fetch` updates the dataset, the version it replaces is kept as a
+//! snapshot, recorded as the classifiers removed and added since. Together with the current
+//! dataset they let tools compare any two versions this crate has captured.
+//!
+//! # Examples
+//!
+#![cfg_attr(not(feature = "typing"), doc = "```ignore")]
+#![cfg_attr(feature = "typing", doc = "```")]
+//! use trove_classifiers::history;
+//!
+//! let current = history::snapshot(trove_classifiers::PYPA_VERSION).unwrap();
+//! assert!(current.contains("Typing :: Typed"));
+//! assert_eq!(history::snapshots().last(), Some(current));
+//! ```
+
+#[cfg(feature = "alloc")]
+use alloc::vec::Vec;
+
+use crate::generated::history::HISTORY;
+use crate::{Classifier, PYPA_VERSION};
+
+/// The classifiers of one version of pypa/trove-classifiers
+///
+/// The current snapshot holds the classifiers of `Classifier`, so the classifiers of categories
+/// compiled out by features are missing from it and from every earlier snapshot.
+#[derive(Clone, Copy, Debug, Eq, Hash, PartialEq)]
+pub struct Snapshot {
+    version: &'static str,
+    removed_since: &'static [&'static str],
+    added_since: &'static [&'static str],
+}
+
+impl Snapshot {
+    /// The version of pypa/trove-classifiers this snapshot was taken from
+    pub fn version(&self) -> &'static str {
+        self.version
+    }
+
+    /// Whether this is the dataset of `Classifier`
+    pub fn is_current(&self) -> bool {
+        self.version == PYPA_VERSION
+    }
+
+    /// Whether the snapshot holds a classifier string
+    pub fn contains(&self, s: &str) -> bool {
+        if self.removed_since.contains(&s) {
+            return true;
+        }
+        !self.added_since.contains(&s) && s.parse::<Classifier>().is_ok()
+    }
+
+    /// Every classifier string of the snapshot
+    ///
+    /// Classifiers still in the dataset come first, in canonical order, followed by those
+    /// removed since.
+    pub fn classifiers(&self) -> impl Iterator<Item = &'static str> {
+        let added_since = self.added_since;
+        Classifier::ALL
+            .iter()
+            .map(Classifier::as_str)
+            .filter(move |string| !added_since.contains(string))
+            .chain(self.removed_since.iter().copied())
+    }
+}
+
+const CURRENT: Snapshot = Snapshot {
+    version: PYPA_VERSION,
+    removed_since: &[],
+    added_since: &[],
+};
+
+/// Every snapshot captured by this crate, oldest first and ending with the current dataset
+pub fn snapshots() -> impl Iterator<Item = Snapshot> {
+    HISTORY
+        .iter()
+        .map(|&(version, removed_since, added_since)| Snapshot {
+            version,
+            removed_since,
+            added_since,
+        })
+        .chain([CURRENT])
+}
+
+/// The snapshot of a version of pypa/trove-classifiers, if this crate captured it
+pub fn snapshot(version: &str) -> Option<Snapshot> {
+    snapshots().find(|snapshot| snapshot.version == version)
+}
+
+/// The classifiers added and removed between two lists of classifier strings
+#[cfg(feature = "alloc")]
+#[derive(Clone, Debug, Default, Eq, PartialEq)]
+pub struct Diff<'a> {
+    /// Classifiers of the new list missing from the old one, sorted
+    pub added: Vec<&'a str>,
+    /// Classifiers of the old list missing from the new one, sorted
+    pub removed: Vec<&'a str>,
+}
+
+#[cfg(feature = "alloc")]
+impl Diff<'_> {
+    /// Whether both lists hold the same classifiers
+    pub fn is_empty(&self) -> bool {
+        self.added.is_empty() && self.removed.is_empty()
+    }
+}
+
+/// Compare two lists of classifier strings, such as the classifiers of two snapshots
+///
+/// # Examples
+///
+/// ```
+/// use trove_classifiers::history::diff;
+///
+/// let diff = diff(["Typing :: Typed"], ["Typing :: Stubs Only", "Typing :: Typed"]);
+/// assert_eq!(diff.added, ["Typing :: Stubs Only"]);
+/// assert!(diff.removed.is_empty());
+/// ```
+#[cfg(feature = "alloc")]
+pub fn diff<'a, 'o: 'a, 'n: 'a>(
+    old: impl IntoIterator<Item = &'o str>,
+    new: impl IntoIterator<Item = &'n str>,
+) -> Diff<'a> {
+    let mut old: Vec<&'a str> = old.into_iter().map(|string| string as &'a str).collect();
+    let mut new: Vec<&'a str> = new.into_iter().map(|string| string as &'a str).collect();
+    old.sort_unstable();
+    old.dedup();
+    new.sort_unstable();
+    new.dedup();
+    Diff {
+        added: new
+            .iter()
+            .copied()
+            .filter(|string| old.binary_search(string).is_err())
+            .collect(),
+        removed: old
+            .iter()
+            .copied()
+            .filter(|string| new.binary_search(string).is_err())
+            .collect(),
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[cfg(all(feature = "alloc", feature = "typing"))]
+    const OLDER: Snapshot = Snapshot {
+        version: "2000.1.1",
+        removed_since: &["Typing :: Sometimes"],
+        added_since: &["Typing :: Stubs Only"],
+    };
+
+    #[test]
+    fn current_snapshot_is_the_dataset() {
+        let current = snapshot(PYPA_VERSION).unwrap();
+        assert!(current.is_current());
+        assert!(current
+            .classifiers()
+            .eq(Classifier::ALL.iter().map(Classifier::as_str)));
+        assert_eq!(snapshot("0.0.0"), None);
+    }
+
+    #[test]
+    #[cfg(all(feature = "alloc", feature = "typing"))]
+    fn earlier_snapshots_apply_their_changes() {
+        assert!(OLDER.contains("Typing :: Sometimes"));
+        assert!(OLDER.contains("Typing :: Typed"));
+        assert!(!OLDER.contains("Typing :: Stubs Only"));
+
+        let classifiers: Vec<&str> = OLDER.classifiers().collect();
+        assert!(classifiers.contains(&"Typing :: Sometimes"));
+        assert!(!classifiers.contains(&"Typing :: Stubs Only"));
+        assert_eq!(classifiers.len(), Classifier::ALL.len());
+
+        let diff = diff(OLDER.classifiers(), CURRENT.classifiers());
+        assert_eq!(diff.added, ["Typing :: Stubs Only"]);
+        assert_eq!(diff.removed, ["Typing :: Sometimes"]);
+    }
+
+    #[test]
+    #[cfg(feature = "alloc")]
+    fn diff_ignores_order_and_duplicates() {
+        let diff = diff(["b", "a", "a"], ["a", "b"]);
+        assert!(diff.is_empty());
+    }
+}
@@ -0,0 +1,161 @@
+//! Translated names of the top-level categories, for localized interfaces.
+//!
+//! Classifiers themselves are only ever written in English, which is what pypi.org accepts, but
+//! an interface grouping them by category can show the category in its user's language.
+//! `Category::localized_name` takes a BCP 47 language tag such as `de` or `pt-BR`, and falls
+//! back from a regional tag to its language, and from an unknown language to English.
+//!
+//! # Examples
+//!
+//! ```
+//! use trove_classifiers::Category;
+//!
+//! let status = Category::DevelopmentStatus;
+//! assert_eq!(status.localized_name("de"), "Entwicklungsstatus");
+//! assert_eq!(status.localized_name("fr_CA"), "État du développement");
+//! assert_eq!(status.localized_name("tlh"), "Development Status");
+//! ```
+
+use crate::Category;
+
+/// The names of every category, in the order of `Category::ALL`, in one language
+type Names = [&'static str; Category::ALL.len()];
+
+/// The languages categories are translated to, as BCP 47 language subtags
+pub const LOCALES: &[&str] = &["de", "es", "fr", "it", "ja", "pt", "ru", "zh"];
+
+static TRANSLATIONS: [Names; LOCALES.len()] = [
+    [
+        "Entwicklungsstatus",
+        "Umgebung",
+        "Framework",
+        "Zielgruppe",
+        "Lizenz",
+        "Natürliche Sprache",
+        "Betriebssystem",
+        "Programmiersprache",
+        "Thema",
+        "Typisierung",
+    ],
+    [
+        "Estado de desarrollo",
+        "Entorno",
+        "Framework",
+        "Público objetivo",
+        "Licencia",
+        "Idioma",
+        "Sistema operativo",
+        "Lenguaje de programación",
+        "Tema",
+        "Tipado",
+    ],
+    [
+        "État du développement",
+        "Environnement",
+        "Framework",
+        "Public visé",
+        "Licence",
+        "Langue",
+        "Système d'exploitation",
+        "Langage de programmation",
+        "Sujet",
+        "Typage",
+    ],
+    [
+        "Stato di sviluppo",
+        "Ambiente",
+        "Framework",
+        "Pubblico di riferimento",
+        "Licenza",
+        "Lingua",
+        "Sistema operativo",
+        "Linguaggio di programmazione",
+        "Argomento",
+        "Tipizzazione",
+    ],
+    [
+        "開発状況",
+        "環境",
+        "フレームワーク",
+        "対象ユーザー",
+        "ライセンス",
+        "自然言語",
+        "オペレーティングシステム",
+        "プログラミング言語",
+        "トピック",
+        "型付け",
+    ],
+    [
+        "Estado de desenvolvimento",
+        "Ambiente",
+        "Framework",
+        "Público-alvo",
+        "Licença",
+        "Idioma",
+        "Sistema operacional",
+        "Linguagem de programação",
+        "Tópico",
+        "Tipagem",
+    ],
+    [
+        "Статус разработки",
+        "Окружение",
+        "Фреймворк",
+        "Целевая аудитория",
+        "Лицензия",
+        "Естественный язык",
+        "Операционная система",
+        "Язык программирования",
+        "Тема",
+        "Типизация",
+    ],
+    [
+        "开发状态",
+        "环境",
+        "框架",
+        "目标受众",
+        "许可证",
+        "自然语言",
+        "操作系统",
+        "编程语言",
+        "主题",
+        "类型标注",
+    ],
+];
+
+impl Category {
+    /// The name of this category in the language of a BCP 47 tag, or its English name if the
+    /// language is not one of `LOCALES`
+    ///
+    /// Only the language subtag is looked at, ignoring ASCII case, and `_` is accepted in place
+    /// of `-` as in POSIX locales.
+    pub fn localized_name(&self, locale: &str) -> &'static str {
+        let language = locale.trim().split(['-', '_']).next().unwrap_or_default();
+        LOCALES
+            .iter()
+            .position(|known| known.eq_ignore_ascii_case(language))
+            .map_or(self.name(), |index| TRANSLATIONS[index][*self as usize])
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn every_locale_names_every_category_differently() {
+        for (locale, names) in LOCALES.iter().zip(&TRANSLATIONS) {
+            for (index, name) in names.iter().enumerate() {
+                assert!(!name.is_empty(), "{locale}");
+                assert!(!names[..index].contains(name), "{locale}: {name}");
+            }
+        }
+    }
+
+    #[test]
+    fn english_is_the_fallback() {
+        assert_eq!(Category::Typing.localized_name(""), "Typing");
+        assert_eq!(Category::Typing.localized_name("en-GB"), "Typing");
+        assert_eq!(Category::Topic.localized_name("ZH-Hans"), "主题");
+    }
+}
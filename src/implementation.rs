@@ -0,0 +1,172 @@
+//! Mapping between `Programming Language :: Python :: Implementation` classifiers, the names of
+//! Python implementations and the interpreter tags of wheels.
+//!
+//! `sys.implementation.name` names the implementation running Python, such as `cpython`, and the
+//! python tag of a wheel's filename starts with the abbreviation of the implementation it was
+//! built for, such as `cp` in `cp312`, as described by the platform compatibility tags
+//! specification. The generic `py` tag names no implementation. Stackless Python calls itself
+//! `cpython` and builds CPython wheels, so neither maps to its classifier. Classifiers of
+//! categories compiled out by features never match.
+//!
+//! # Examples
+//!
+#![cfg_attr(not(feature = "programming-language"), doc = "```ignore")]
+#![cfg_attr(feature = "programming-language", doc = "```")]
+//! use trove_classifiers::{implementation, Classifier};
+//!
+//! let pypy = Classifier::ProgrammingLanguage__Python__Implementation__PyPy;
+//! assert_eq!(implementation::from_sys_name("pypy"), Some(pypy));
+//! assert_eq!(implementation::to_tag_prefix(pypy), Some("pp"));
+//! assert_eq!(implementation::from_tag("pp310"), Some(pypy));
+//! assert_eq!(implementation::from_tag("py3"), None);
+//!
+//! let built: Vec<Classifier> =
+//!     implementation::from_wheel_filename("numpy-2.1.0-cp312-cp312-win_amd64.whl").collect();
+//! assert_eq!(built, [Classifier::ProgrammingLanguage__Python__Implementation__CPython]);
+//! ```
+
+use core::str::FromStr;
+
+use crate::Classifier;
+
+/// Implementation classifiers, the `sys.implementation.name` and the interpreter tag
+/// abbreviation of each
+const IMPLEMENTATIONS: &[(&str, Option<&str>, Option<&str>)] = &[
+    (
+        "Programming Language :: Python :: Implementation :: CPython",
+        Some("cpython"),
+        Some("cp"),
+    ),
+    (
+        "Programming Language :: Python :: Implementation :: IronPython",
+        Some("ironpython"),
+        Some("ip"),
+    ),
+    (
+        "Programming Language :: Python :: Implementation :: Jython",
+        Some("jython"),
+        Some("jy"),
+    ),
+    (
+        "Programming Language :: Python :: Implementation :: MicroPython",
+        Some("micropython"),
+        None,
+    ),
+    (
+        "Programming Language :: Python :: Implementation :: PyPy",
+        Some("pypy"),
+        Some("pp"),
+    ),
+    (
+        "Programming Language :: Python :: Implementation :: Stackless",
+        None,
+        None,
+    ),
+];
+
+/// The classifier of a `sys.implementation.name`, which is matched ignoring ASCII case
+pub fn from_sys_name(name: &str) -> Option<Classifier> {
+    let name = name.trim();
+    IMPLEMENTATIONS
+        .iter()
+        .find(|(_, sys_name, _)| {
+            sys_name.is_some_and(|sys_name| sys_name.eq_ignore_ascii_case(name))
+        })
+        .and_then(|(classifier, _, _)| Classifier::from_str(classifier).ok())
+}
+
+/// The `sys.implementation.name` of an implementation classifier
+pub fn to_sys_name(classifier: Classifier) -> Option<&'static str> {
+    let string = classifier.as_str();
+    IMPLEMENTATIONS
+        .iter()
+        .find(|(mapped, _, _)| *mapped == string)
+        .and_then(|(_, sys_name, _)| *sys_name)
+}
+
+/// The classifier of an interpreter tag, such as `cp312`, or of its abbreviation, such as `cp`
+pub fn from_tag(tag: &str) -> Option<Classifier> {
+    let prefix = tag_prefix(tag);
+    IMPLEMENTATIONS
+        .iter()
+        .find(|(_, _, abbreviation)| *abbreviation == Some(prefix))
+        .and_then(|(classifier, _, _)| Classifier::from_str(classifier).ok())
+}
+
+/// The abbreviation starting the interpreter tags of an implementation classifier, such as `cp`
+pub fn to_tag_prefix(classifier: Classifier) -> Option<&'static str> {
+    let string = classifier.as_str();
+    IMPLEMENTATIONS
+        .iter()
+        .find(|(mapped, _, _)| *mapped == string)
+        .and_then(|(_, _, abbreviation)| *abbreviation)
+}
+
+/// The implementations a wheel was built for, from the python tag of its filename
+///
+/// Each implementation is given once, in canonical order, even when the python tag is a
+/// compressed tag set such as `cp311.cp312`. A wheel for any implementation, such as
+/// `py3-none-any`, and a filename that is not a wheel's give none.
+pub fn from_wheel_filename(filename: &str) -> impl Iterator<Item = Classifier> + '_ {
+    let python_tag = filename
+        .strip_suffix(".whl")
+        .and_then(|stem| {
+            let mut tags = stem.rsplitn(4, '-');
+            let (_platform, _abi) = (tags.next()?, tags.next()?);
+            let python_tag = tags.next()?;
+            // the name and version come before the tags
+            tags.next()
+                .is_some_and(|rest| rest.contains('-'))
+                .then_some(python_tag)
+        })
+        .unwrap_or_default();
+    IMPLEMENTATIONS
+        .iter()
+        .filter(move |(_, _, abbreviation)| {
+            python_tag
+                .split('.')
+                .any(|tag| !tag.is_empty() && *abbreviation == Some(tag_prefix(tag)))
+        })
+        .filter_map(|(classifier, _, _)| Classifier::from_str(classifier).ok())
+}
+
+/// The letters starting an interpreter tag, such as `cp` of `cp312`
+fn tag_prefix(tag: &str) -> &str {
+    let tag = tag.trim();
+    let end = tag
+        .find(|c: char| !c.is_ascii_alphabetic())
+        .unwrap_or(tag.len());
+    &tag[..end]
+}
+
+#[cfg(all(test, feature = "programming-language"))]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn names_and_tags_round_trip() {
+        for (classifier, sys_name, abbreviation) in IMPLEMENTATIONS {
+            let classifier = Classifier::from_str(classifier).unwrap();
+            assert_eq!(to_sys_name(classifier), *sys_name);
+            assert_eq!(to_tag_prefix(classifier), *abbreviation);
+            if let Some(sys_name) = sys_name {
+                assert_eq!(from_sys_name(sys_name), Some(classifier));
+            }
+            if let Some(abbreviation) = abbreviation {
+                assert_eq!(from_tag(abbreviation), Some(classifier));
+            }
+        }
+    }
+
+    #[test]
+    fn wheel_filenames_name_their_implementations() {
+        let implementations = |filename| from_wheel_filename(filename).count();
+        assert_eq!(implementations("example-1.0-py3-none-any.whl"), 0);
+        assert_eq!(
+            implementations("example-1.0-1-pp310.cp310.cp311-none-any.whl"),
+            2
+        );
+        assert_eq!(implementations("cp312-none-any.whl"), 0);
+        assert_eq!(implementations("example-1.0.tar.gz"), 0);
+    }
+}
@@ -0,0 +1,214 @@
+use core::cmp::Ordering;
+use core::fmt;
+use core::str::FromStr;
+
+use crate::generated::{locate, CATEGORIES, DATASET_LEN};
+use crate::{Classifier, DATASET_FINGERPRINT};
+
+/// An inconsistency found between the internal classifier tables
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub enum IntegrityError {
+    /// `Classifier::ALL` and the category tables hold a different number of classifiers
+    LengthMismatch { all: usize, strings: usize },
+    /// A classifier's discriminant does not follow the one before it in `Classifier::ALL`, or,
+    /// with every category enabled, is not its index there
+    OutOfOrder {
+        classifier: Classifier,
+        index: usize,
+    },
+    /// A classifier is not stored in the table of the category it names
+    CategoryMismatch { classifier: Classifier },
+    /// A classifier does not render as its entry in the string table
+    StringMismatch { classifier: Classifier },
+    /// A classifier's canonical string does not parse back into that classifier
+    ParseMismatch { classifier: Classifier },
+    /// A classifier's segments or parent do not match its canonical string
+    HierarchyMismatch { classifier: Classifier },
+    /// A classifier is out of place in the case-insensitive index `complete` searches
+    SortMismatch { classifier: Classifier },
+    /// The string tables of a build with every category do not hash to `DATASET_FINGERPRINT`
+    FingerprintMismatch { expected: u64, actual: u64 },
+}
+
+impl fmt::Display for IntegrityError {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        match self {
+            IntegrityError::LengthMismatch { all, strings } => write!(
+                f,
+                "{all} classifiers but {strings} classifier strings are known"
+            ),
+            IntegrityError::OutOfOrder { classifier, index } => {
+                write!(f, "{classifier:?} is out of order at index {index}")
+            }
+            IntegrityError::CategoryMismatch { classifier } => {
+                write!(f, "{classifier:?} is stored in the wrong category")
+            }
+            IntegrityError::StringMismatch { classifier } => {
+                write!(f, "{classifier:?} does not match its canonical string")
+            }
+            IntegrityError::ParseMismatch { classifier } => {
+                write!(f, "{classifier:?} does not parse from its canonical string")
+            }
+            IntegrityError::HierarchyMismatch { classifier } => {
+                write!(f, "{classifier:?} does not match its segments or parent")
+            }
+            IntegrityError::SortMismatch { classifier } => {
+                write!(f, "{classifier:?} is out of place in the sorted index")
+            }
+            IntegrityError::FingerprintMismatch { expected, actual } => write!(
+                f,
+                "dataset fingerprint is {actual:#018x}, expected {expected:#018x}"
+            ),
+        }
+    }
+}
+
+#[cfg(feature = "std")]
+impl std::error::Error for IntegrityError {}
+
+/// The offset basis of 64-bit FNV-1a
+const FNV_OFFSET: u64 = 0xcbf2_9ce4_8422_2325;
+
+/// Continue the 64-bit FNV-1a hash `hash` over `bytes`
+fn fnv1a(mut hash: u64, bytes: impl IntoIterator<Item = u8>) -> u64 {
+    for byte in bytes {
+        hash ^= u64::from(byte);
+        hash = hash.wrapping_mul(0x0000_0100_0000_01b3);
+    }
+    hash
+}
+
+/// Compute the fingerprint of a list of classifier strings
+///
+/// See `DATASET_FINGERPRINT` for the definition.
+pub(crate) fn fingerprint<'a>(classifiers: impl IntoIterator<Item = &'a str>) -> u64 {
+    classifiers
+        .into_iter()
+        .fold(FNV_OFFSET, |hash, classifier| {
+            fnv1a(hash, classifier.bytes().chain([b'\n']))
+        })
+}
+
+/// The 64-bit FNV-1a hash of a classifier string
+///
+/// See `Classifier::stable_id` for why.
+pub fn stable_id(classifier: &str) -> u64 {
+    fnv1a(FNV_OFFSET, classifier.bytes())
+}
+
+/// Check that the classifier tables compiled into this crate are mutually consistent
+///
+/// The fingerprint describes the whole dataset, so it is only checked when every category
+/// feature is enabled.
+///
+/// # Examples
+///
+/// ```
+/// trove_classifiers::verify().expect("classifier dataset is corrupt");
+/// ```
+pub fn verify() -> Result<(), IntegrityError> {
+    let strings = CATEGORIES.iter().map(|category| category.ends.len()).sum();
+    if Classifier::ALL.len() != strings {
+        return Err(IntegrityError::LengthMismatch {
+            all: Classifier::ALL.len(),
+            strings,
+        });
+    }
+    let full = strings == DATASET_LEN;
+    for (index, &classifier) in Classifier::ALL.iter().enumerate() {
+        // codes of disabled categories leave gaps, so only a full build is indexed by them
+        if (full && classifier as usize != index)
+            || (index > 0 && classifier as usize <= Classifier::ALL[index - 1] as usize)
+        {
+            return Err(IntegrityError::OutOfOrder { classifier, index });
+        }
+        let (category, position) = locate(classifier);
+        if category.classifiers[position] != classifier
+            || classifier.split().next() != Some(category.name)
+            || classifier.category().name() != category.name
+        {
+            return Err(IntegrityError::CategoryMismatch { classifier });
+        }
+        if classifier.as_ref() != category.string(position) {
+            return Err(IntegrityError::StringMismatch { classifier });
+        }
+        if Classifier::from_str(classifier.as_ref()) != Ok(classifier) {
+            return Err(IntegrityError::ParseMismatch { classifier });
+        }
+        let parent_is_prefix = classifier.parent().is_none_or(|parent| {
+            classifier
+                .as_str()
+                .strip_prefix(parent.as_str())
+                .is_some_and(|rest| rest.starts_with(" :: "))
+        });
+        if !classifier
+            .split()
+            .eq(classifier.segments_static().iter().copied())
+            || !parent_is_prefix
+        {
+            return Err(IntegrityError::HierarchyMismatch { classifier });
+        }
+    }
+    for category in CATEGORIES {
+        let lowercase = |index: u16| {
+            category
+                .string(index as usize)
+                .bytes()
+                .map(|byte| byte.to_ascii_lowercase())
+        };
+        // strictly ascending strings hold no index twice, so in-bounds ones are a permutation
+        let misplaced = category.sorted.len() != category.classifiers.len()
+            || category
+                .sorted
+                .iter()
+                .any(|&index| index as usize >= category.classifiers.len())
+            || category
+                .sorted
+                .windows(2)
+                .any(|pair| lowercase(pair[0]).cmp(lowercase(pair[1])) != Ordering::Less);
+        if let (true, Some(&classifier)) = (misplaced, category.classifiers.first()) {
+            return Err(IntegrityError::SortMismatch { classifier });
+        }
+    }
+    if !full {
+        return Ok(());
+    }
+    let actual = fingerprint(CATEGORIES.iter().flat_map(|category| category.strings()));
+    if actual != DATASET_FINGERPRINT {
+        return Err(IntegrityError::FingerprintMismatch {
+            expected: DATASET_FINGERPRINT,
+            actual,
+        });
+    }
+    Ok(())
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::RAW_CLASSIFIERS;
+
+    #[test]
+    fn dataset_is_consistent() {
+        assert_eq!(verify(), Ok(()));
+    }
+
+    #[test]
+    fn fingerprint_is_order_sensitive() {
+        let forward = fingerprint(["Typing :: Typed", "Typing :: Stubs Only"]);
+        let backward = fingerprint(["Typing :: Stubs Only", "Typing :: Typed"]);
+        assert_ne!(forward, backward);
+    }
+
+    #[test]
+    fn raw_classifiers_are_the_dataset() {
+        assert_eq!(fingerprint(RAW_CLASSIFIERS.lines()), DATASET_FINGERPRINT);
+        assert_eq!(RAW_CLASSIFIERS.lines().count(), DATASET_LEN);
+        assert!(RAW_CLASSIFIERS.ends_with('\n'));
+        for &classifier in Classifier::ALL {
+            assert!(RAW_CLASSIFIERS
+                .lines()
+                .any(|line| line == classifier.as_str()));
+        }
+    }
+}
@@ -0,0 +1,155 @@
+use std::collections::HashSet;
+use std::sync::{Arc, Mutex, PoisonError};
+
+use crate::{Classifier, DynamicClassifier};
+
+/// A thread-safe store of unknown classifier strings
+///
+/// Every `DynamicClassifier` produced by the same interner for the same unknown string shares
+/// one allocation, so a service reading millions of copies of a custom classifier holds it in
+/// memory once. Known classifiers never touch the store.
+///
+/// # Examples
+///
+/// ```
+/// use trove_classifiers::{DynamicClassifier, Interner};
+///
+/// let interner = Interner::new();
+/// let first = interner.intern("Private :: Do Not Upload");
+/// let second = interner.intern("Private :: Do Not Upload");
+///
+/// match (&first, &second) {
+///     (DynamicClassifier::Unknown(a), DynamicClassifier::Unknown(b)) => {
+///         assert!(std::sync::Arc::ptr_eq(a, b))
+///     }
+///     _ => unreachable!(),
+/// }
+/// assert_eq!(interner.stats().strings, 1);
+/// ```
+#[derive(Debug, Default)]
+pub struct Interner {
+    state: Mutex<State>,
+}
+
+#[derive(Debug, Default)]
+struct State {
+    strings: HashSet<Arc<str>>,
+    stats: InternerStats,
+}
+
+/// Counters describing the use of an `Interner`
+#[derive(Clone, Copy, Debug, Default, Eq, PartialEq)]
+pub struct InternerStats {
+    /// Distinct unknown strings currently stored
+    pub strings: usize,
+    /// Total length in bytes of the distinct unknown strings currently stored
+    pub bytes: usize,
+    /// Strings that parsed as known classifiers and so were not stored
+    pub known: u64,
+    /// Unknown strings that reused an existing allocation
+    pub hits: u64,
+    /// Unknown strings that were stored for the first time
+    pub misses: u64,
+}
+
+impl Interner {
+    pub fn new() -> Self {
+        Self::default()
+    }
+
+    /// Parse a string, sharing storage with earlier occurrences if it is unknown
+    pub fn intern(&self, s: &str) -> DynamicClassifier {
+        let known = s.parse::<Classifier>().ok();
+        #[cfg(feature = "telemetry")]
+        if known.is_none() {
+            crate::telemetry::unknown(s);
+        }
+        let mut state = self.state.lock().unwrap_or_else(PoisonError::into_inner);
+        if let Some(classifier) = known {
+            state.stats.known += 1;
+            return DynamicClassifier::Known(classifier);
+        }
+        if let Some(existing) = state.strings.get(s) {
+            let existing = Arc::clone(existing);
+            state.stats.hits += 1;
+            return DynamicClassifier::Unknown(existing);
+        }
+        let string: Arc<str> = Arc::from(s);
+        state.strings.insert(Arc::clone(&string));
+        state.stats.misses += 1;
+        state.stats.strings += 1;
+        state.stats.bytes += s.len();
+        DynamicClassifier::Unknown(string)
+    }
+
+    /// The interner's counters at this moment
+    pub fn stats(&self) -> InternerStats {
+        self.state
+            .lock()
+            .unwrap_or_else(PoisonError::into_inner)
+            .stats
+    }
+
+    /// Drop stored strings that are no longer referenced by any `DynamicClassifier`
+    ///
+    /// Returns the number of strings removed.
+    pub fn purge(&self) -> usize {
+        let mut state = self.state.lock().unwrap_or_else(PoisonError::into_inner);
+        let before = state.strings.len();
+        state.strings.retain(|string| Arc::strong_count(string) > 1);
+        let removed = before - state.strings.len();
+        state.stats.strings = state.strings.len();
+        state.stats.bytes = state.strings.iter().map(|string| string.len()).sum();
+        removed
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    #[cfg(feature = "typing")]
+    fn known_classifiers_are_not_stored() {
+        let interner = Interner::new();
+        let typed = interner.intern("Typing :: Typed");
+        assert_eq!(typed, DynamicClassifier::Known(Classifier::Typing__Typed));
+        assert_eq!(
+            interner.stats(),
+            InternerStats {
+                known: 1,
+                ..InternerStats::default()
+            }
+        );
+    }
+
+    #[test]
+    fn unknown_strings_are_shared() {
+        let interner = Interner::new();
+        let copies: Vec<DynamicClassifier> = (0..3)
+            .map(|_| interner.intern("Framework :: In-House"))
+            .collect();
+        interner.intern("Framework :: Other In-House");
+        assert!(copies.windows(2).all(|pair| pair[0] == pair[1]));
+        assert_eq!(
+            interner.stats(),
+            InternerStats {
+                strings: 2,
+                bytes: 48,
+                known: 0,
+                hits: 2,
+                misses: 2,
+            }
+        );
+    }
+
+    #[test]
+    fn purge_drops_unreferenced_strings() {
+        let interner = Interner::new();
+        let kept = interner.intern("Framework :: In-House");
+        drop(interner.intern("Framework :: Other In-House"));
+        assert_eq!(interner.purge(), 1);
+        assert_eq!(interner.stats().strings, 1);
+        assert_eq!(interner.intern("Framework :: In-House"), kept);
+    }
+}
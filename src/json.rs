@@ -0,0 +1,232 @@
+//! Writing and reading the JSON documents of the `sarif`, `baseline`, `export` and `matrix`
+//! modules, which do not warrant a dependency.
+
+use alloc::format;
+use alloc::string::String;
+use alloc::vec::Vec;
+use core::fmt::Write;
+
+/// Append a JSON string
+pub(crate) fn push_string(out: &mut String, value: &str) {
+    out.push('"');
+    for c in value.chars() {
+        match c {
+            '"' => out.push_str("\\\""),
+            '\\' => out.push_str("\\\\"),
+            '\n' => out.push_str("\\n"),
+            '\r' => out.push_str("\\r"),
+            '\t' => out.push_str("\\t"),
+            c if c.is_control() => write!(out, "\\u{:04x}", c as u32).unwrap(),
+            c => out.push(c),
+        }
+    }
+    out.push('"');
+}
+
+/// A JSON value
+#[derive(Clone, Debug)]
+pub(crate) enum Json {
+    Null,
+    Bool,
+    /// A non-negative integer; no other numbers are read
+    Number(u64),
+    String(String),
+    Array(Vec<Json>),
+    Object(Vec<(String, Json)>),
+}
+
+/// Just enough of a JSON parser to read the documents this crate writes
+pub(crate) struct Parser<'a> {
+    source: &'a [u8],
+    position: usize,
+}
+
+impl<'a> Parser<'a> {
+    pub(crate) fn new(source: &'a str) -> Self {
+        Parser {
+            source: source.as_bytes(),
+            position: 0,
+        }
+    }
+
+    /// The single value of a document, or why it is not JSON
+    pub(crate) fn document(&mut self) -> Result<Json, String> {
+        let value = self.value()?;
+        self.whitespace();
+        match self.position == self.source.len() {
+            true => Ok(value),
+            false => Err(self.error("trailing characters")),
+        }
+    }
+
+    fn error(&self, reason: &str) -> String {
+        format!("{reason} at byte {}", self.position)
+    }
+
+    fn whitespace(&mut self) {
+        while self
+            .source
+            .get(self.position)
+            .is_some_and(|byte| matches!(byte, b' ' | b'\t' | b'\n' | b'\r'))
+        {
+            self.position += 1;
+        }
+    }
+
+    fn eat(&mut self, expected: u8) -> bool {
+        self.whitespace();
+        let found = self.source.get(self.position) == Some(&expected);
+        if found {
+            self.position += 1;
+        }
+        found
+    }
+
+    fn keyword(&mut self, keyword: &str) -> bool {
+        let found = self.source[self.position..].starts_with(keyword.as_bytes());
+        if found {
+            self.position += keyword.len();
+        }
+        found
+    }
+
+    fn value(&mut self) -> Result<Json, String> {
+        self.whitespace();
+        match self.source.get(self.position) {
+            Some(b'{') => {
+                self.position += 1;
+                let mut fields = Vec::new();
+                if self.eat(b'}') {
+                    return Ok(Json::Object(fields));
+                }
+                loop {
+                    self.whitespace();
+                    let key = self.string()?;
+                    if !self.eat(b':') {
+                        return Err(self.error("expected ':'"));
+                    }
+                    fields.push((key, self.value()?));
+                    if self.eat(b'}') {
+                        return Ok(Json::Object(fields));
+                    }
+                    if !self.eat(b',') {
+                        return Err(self.error("expected ',' or '}'"));
+                    }
+                }
+            }
+            Some(b'[') => {
+                self.position += 1;
+                let mut items = Vec::new();
+                if self.eat(b']') {
+                    return Ok(Json::Array(items));
+                }
+                loop {
+                    items.push(self.value()?);
+                    if self.eat(b']') {
+                        return Ok(Json::Array(items));
+                    }
+                    if !self.eat(b',') {
+                        return Err(self.error("expected ',' or ']'"));
+                    }
+                }
+            }
+            Some(b'"') => self.string().map(Json::String),
+            Some(b'0'..=b'9') => {
+                let start = self.position;
+                while self
+                    .source
+                    .get(self.position)
+                    .is_some_and(u8::is_ascii_digit)
+                {
+                    self.position += 1;
+                }
+                core::str::from_utf8(&self.source[start..self.position])
+                    .ok()
+                    .and_then(|digits| digits.parse().ok())
+                    .map(Json::Number)
+                    .ok_or_else(|| self.error("number out of range"))
+            }
+            _ if self.keyword("null") => Ok(Json::Null),
+            _ if self.keyword("true") || self.keyword("false") => Ok(Json::Bool),
+            _ => Err(self.error("expected a value")),
+        }
+    }
+
+    fn string(&mut self) -> Result<String, String> {
+        if self.source.get(self.position) != Some(&b'"') {
+            return Err(self.error("expected a string"));
+        }
+        self.position += 1;
+        let mut string = String::new();
+        loop {
+            let rest = core::str::from_utf8(&self.source[self.position..])
+                .map_err(|_| self.error("invalid UTF-8"))?;
+            let mut chars = rest.chars();
+            let c = chars
+                .next()
+                .ok_or_else(|| self.error("unterminated string"))?;
+            self.position += c.len_utf8();
+            match c {
+                '"' => return Ok(string),
+                '\\' => {
+                    let escape = chars
+                        .next()
+                        .ok_or_else(|| self.error("unterminated string"))?;
+                    self.position += 1;
+                    string.push(match escape {
+                        '"' => '"',
+                        '\\' => '\\',
+                        '/' => '/',
+                        'b' => '\u{8}',
+                        'f' => '\u{c}',
+                        'n' => '\n',
+                        'r' => '\r',
+                        't' => '\t',
+                        'u' => {
+                            let hex = rest.get(2..6).ok_or_else(|| self.error("bad escape"))?;
+                            self.position += 4;
+                            u32::from_str_radix(hex, 16)
+                                .ok()
+                                .and_then(char::from_u32)
+                                .ok_or_else(|| self.error("bad escape"))?
+                        }
+                        _ => return Err(self.error("bad escape")),
+                    });
+                }
+                c => string.push(c),
+            }
+        }
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn strings_round_trip() {
+        let value = "quote \" backslash \\ newline \n tab \t bell \u{7} é";
+        let mut json = String::new();
+        push_string(&mut json, value);
+        assert_eq!(
+            json,
+            r#""quote \" backslash \\ newline \n tab \t bell \u0007 é""#
+        );
+        match Parser::new(&json).document() {
+            Ok(Json::String(parsed)) => assert_eq!(parsed, value),
+            other => panic!("{other:?}"),
+        }
+    }
+
+    #[test]
+    fn reports_where_documents_are_malformed() {
+        assert_eq!(
+            Parser::new("[1, }").document().unwrap_err(),
+            "expected a value at byte 4"
+        );
+        assert_eq!(
+            Parser::new("{} x").document().unwrap_err(),
+            "trailing characters at byte 3"
+        );
+    }
+}
@@ -0,0 +1,97 @@
+use core::str::FromStr;
+
+use crate::{Classifier, ParseError};
+
+impl Classifier {
+    /// Parse a classifier written by hand, ignoring ASCII case and the whitespace around and
+    /// within its segments
+    ///
+    /// # Examples
+    ///
+    #[cfg_attr(not(feature = "typing"), doc = "```ignore")]
+    #[cfg_attr(feature = "typing", doc = "```")]
+    /// use trove_classifiers::Classifier;
+    ///
+    /// let typed = Classifier::from_str_lenient("typing::  TYPED ")?;
+    /// assert_eq!(typed, Classifier::Typing__Typed);
+    /// # Ok::<(), trove_classifiers::ParseError>(())
+    /// ```
+    pub fn from_str_lenient(s: &str) -> Result<Self, ParseError> {
+        let parsed = lenient(s);
+        #[cfg(feature = "telemetry")]
+        if parsed.is_err() {
+            crate::telemetry::unknown(s);
+        }
+        parsed
+    }
+}
+
+/// `Classifier::from_str_lenient`, without reporting unknown strings to the telemetry hook
+pub(crate) fn lenient(s: &str) -> Result<Classifier, ParseError> {
+    if let Ok(classifier) = Classifier::from_str(s) {
+        return Ok(classifier);
+    }
+    Classifier::ALL
+        .iter()
+        .copied()
+        .find(|classifier| segments_match(s, classifier.segments_static()))
+        .ok_or(ParseError::Unknown)
+}
+
+/// Whether `s` has the segments of `canonical`, ignoring ASCII case and whitespace runs
+fn segments_match(s: &str, canonical: &[&str]) -> bool {
+    let mut segments = s.split("::");
+    canonical.iter().all(|expected| {
+        segments
+            .next()
+            .is_some_and(|segment| words_match(segment, expected))
+    }) && segments.next().is_none()
+}
+
+fn words_match(segment: &str, expected: &str) -> bool {
+    let mut words = segment.split_whitespace();
+    expected.split(' ').all(|expected| {
+        words
+            .next()
+            .is_some_and(|word| word.eq_ignore_ascii_case(expected))
+    }) && words.next().is_none()
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    #[cfg(feature = "license")]
+    fn normalizes_case_and_whitespace() {
+        let mit = Classifier::License__OSIApproved__MITLicense;
+        for written in [
+            "License :: OSI Approved :: MIT License",
+            "license::osi approved::mit license",
+            "  License ::OSI   Approved::  MIT\tLicense ",
+        ] {
+            assert_eq!(
+                Classifier::from_str_lenient(written),
+                Ok(mit),
+                "{written:?}"
+            );
+        }
+    }
+
+    #[test]
+    fn rejects_other_strings() {
+        for written in [
+            "Typing :: Typed :: Typed",
+            "Typing",
+            "Typing :: Type d",
+            "Typing : Typed",
+            "",
+        ] {
+            assert_eq!(
+                Classifier::from_str_lenient(written),
+                Err(ParseError::Unknown),
+                "{written:?}"
+            );
+        }
+    }
+}
@@ -0,0 +1,457 @@
+//! Mapping between SPDX license identifiers and `License` classifiers.
+//!
+//! Several identifiers can map to the same classifier, such as `BSD-2-Clause` and `BSD-3-Clause`
+//! to "License :: OSI Approved :: BSD License". Classifiers of categories compiled out by
+//! features never match.
+//!
+//! # Examples
+//!
+#![cfg_attr(not(feature = "license"), doc = "```ignore")]
+#![cfg_attr(feature = "license", doc = "```")]
+//! use trove_classifiers::{license, Classifier};
+//!
+//! assert_eq!(license::from_spdx("mit"), Some(Classifier::License__OSIApproved__MITLicense));
+//! assert_eq!(license::to_spdx(Classifier::License__OSIApproved__MITLicense), Some("MIT"));
+//! ```
+
+use core::fmt;
+use core::str::FromStr;
+
+use crate::Classifier;
+
+/// The broad kind of a license
+#[derive(Clone, Copy, Debug, Eq, Hash, Ord, PartialEq, PartialOrd)]
+#[non_exhaustive]
+pub enum LicenseFamily {
+    /// Few conditions beyond attribution, such as MIT or Apache-2.0
+    Permissive,
+    /// Changes to the licensed files must be shared, such as LGPL or MPL
+    WeakCopyleft,
+    /// Derived works must be shared under the same license, such as GPL
+    StrongCopyleft,
+    /// No rights are reserved
+    PublicDomain,
+    /// Use or distribution is restricted
+    Proprietary,
+    /// The license does not fit any other family, or the classifier does not name one license
+    Other,
+}
+
+impl LicenseFamily {
+    pub fn as_str(&self) -> &'static str {
+        match self {
+            LicenseFamily::Permissive => "permissive",
+            LicenseFamily::WeakCopyleft => "weak copyleft",
+            LicenseFamily::StrongCopyleft => "strong copyleft",
+            LicenseFamily::PublicDomain => "public domain",
+            LicenseFamily::Proprietary => "proprietary",
+            LicenseFamily::Other => "other",
+        }
+    }
+}
+
+impl fmt::Display for LicenseFamily {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        f.pad(self.as_str())
+    }
+}
+
+/// Words of License classifiers and the family of the licenses they name, the first match
+/// winning
+const FAMILIES: &[(&str, LicenseFamily)] = {
+    use LicenseFamily::*;
+    &[
+        ("Lesser General Public", WeakCopyleft),
+        ("Affero", StrongCopyleft),
+        ("General Public License", StrongCopyleft),
+        ("Free Documentation", StrongCopyleft),
+        ("European Union Public", StrongCopyleft),
+        ("Open Software License", StrongCopyleft),
+        ("Sleepycat", StrongCopyleft),
+        ("CeCILL-2.1", StrongCopyleft),
+        ("Mozilla", WeakCopyleft),
+        ("Eclipse", WeakCopyleft),
+        ("Common Development", WeakCopyleft),
+        ("Common Public", WeakCopyleft),
+        ("IBM Public", WeakCopyleft),
+        ("Apple Public", WeakCopyleft),
+        ("CECILL-C", WeakCopyleft),
+        ("Netscape", WeakCopyleft),
+        ("Nokia", WeakCopyleft),
+        ("Sun Public", WeakCopyleft),
+        ("Sun Industry", WeakCopyleft),
+        ("Ricoh", WeakCopyleft),
+        ("Motosoto", WeakCopyleft),
+        ("Jabber", WeakCopyleft),
+        ("Qt Public", WeakCopyleft),
+        ("Open Font", WeakCopyleft),
+        ("NASA", WeakCopyleft),
+        ("Public Domain", PublicDomain),
+        ("Unlicense", PublicDomain),
+        ("Proprietary", Proprietary),
+        ("Freeware", Proprietary),
+        ("Free For", Proprietary),
+        ("Free for non-commercial", Proprietary),
+        ("Free To Use But Restricted", Proprietary),
+        ("Aladdin", Proprietary),
+        ("Academic Free", Permissive),
+        ("Apache", Permissive),
+        ("Artistic", Permissive),
+        ("Attribution Assurance", Permissive),
+        ("BSD", Permissive),
+        ("Blue Oak", Permissive),
+        ("Boost", Permissive),
+        ("CMU", Permissive),
+        ("CECILL-B", Permissive),
+        ("Educational Community", Permissive),
+        ("Eiffel", Permissive),
+        ("Historical Permission", Permissive),
+        ("ISC", Permissive),
+        ("Intel", Permissive),
+        ("MIT", Permissive),
+        ("MirOS", Permissive),
+        ("Open Group Test Suite", Permissive),
+        ("Mulan", Permissive),
+        ("PostgreSQL", Permissive),
+        ("Python", Permissive),
+        ("Repoze", Permissive),
+        ("Universal Permissive", Permissive),
+        ("Illinois", Permissive),
+        ("Vovida", Permissive),
+        ("W3C", Permissive),
+        ("X.Net", Permissive),
+        ("Zope", Permissive),
+        ("zlib", Permissive),
+    ]
+};
+
+/// SPDX identifiers and the classifier each maps to, the first for a classifier being the one
+/// `to_spdx` returns
+pub(crate) const SPDX: &[(&str, &str)] = &[
+    ("0BSD", "License :: OSI Approved :: Zero-Clause BSD (0BSD)"),
+    ("AAL", "License :: OSI Approved :: Attribution Assurance License"),
+    ("AFL-3.0", "License :: OSI Approved :: Academic Free License (AFL)"),
+    ("AGPL-3.0-only", "License :: OSI Approved :: GNU Affero General Public License v3"),
+    ("AGPL-3.0-or-later", "License :: OSI Approved :: GNU Affero General Public License v3 or later (AGPLv3+)"),
+    ("APSL-2.0", "License :: OSI Approved :: Apple Public Source License"),
+    ("Aladdin", "License :: Aladdin Free Public License (AFPL)"),
+    ("Apache-2.0", "License :: OSI Approved :: Apache Software License"),
+    ("Apache-1.1", "License :: OSI Approved :: Apache Software License"),
+    ("Artistic-2.0", "License :: OSI Approved :: Artistic License"),
+    ("BSD-3-Clause", "License :: OSI Approved :: BSD License"),
+    ("BSD-2-Clause", "License :: OSI Approved :: BSD License"),
+    ("BSL-1.0", "License :: OSI Approved :: Boost Software License 1.0 (BSL-1.0)"),
+    ("BlueOak-1.0.0", "License :: OSI Approved :: Blue Oak Model License (BlueOak-1.0.0)"),
+    ("CC0-1.0", "License :: CC0 1.0 Universal (CC0 1.0) Public Domain Dedication"),
+    ("CDDL-1.0", "License :: OSI Approved :: Common Development and Distribution License 1.0 (CDDL-1.0)"),
+    ("CECILL-2.1", "License :: OSI Approved :: CEA CNRS Inria Logiciel Libre License, version 2.1 (CeCILL-2.1)"),
+    ("CECILL-B", "License :: CeCILL-B Free Software License Agreement (CECILL-B)"),
+    ("CECILL-C", "License :: CeCILL-C Free Software License Agreement (CECILL-C)"),
+    ("CNRI-Python", "License :: OSI Approved :: Python License (CNRI Python License)"),
+    ("CPL-1.0", "License :: OSI Approved :: Common Public License"),
+    ("ECL-2.0", "License :: OSI Approved :: Educational Community License, Version 2.0 (ECL-2.0)"),
+    ("EFL-2.0", "License :: OSI Approved :: Eiffel Forum License"),
+    ("EFL-1.0", "License :: Eiffel Forum License (EFL)"),
+    ("EPL-1.0", "License :: OSI Approved :: Eclipse Public License 1.0 (EPL-1.0)"),
+    ("EPL-2.0", "License :: OSI Approved :: Eclipse Public License 2.0 (EPL-2.0)"),
+    ("EUPL-1.0", "License :: OSI Approved :: European Union Public Licence 1.0 (EUPL 1.0)"),
+    ("EUPL-1.1", "License :: OSI Approved :: European Union Public Licence 1.1 (EUPL 1.1)"),
+    ("EUPL-1.2", "License :: OSI Approved :: European Union Public Licence 1.2 (EUPL 1.2)"),
+    ("GFDL-1.3-or-later", "License :: OSI Approved :: GNU Free Documentation License (FDL)"),
+    ("GFDL-1.3-only", "License :: OSI Approved :: GNU Free Documentation License (FDL)"),
+    ("GPL-2.0-only", "License :: OSI Approved :: GNU General Public License v2 (GPLv2)"),
+    ("GPL-2.0-or-later", "License :: OSI Approved :: GNU General Public License v2 or later (GPLv2+)"),
+    ("GPL-3.0-only", "License :: OSI Approved :: GNU General Public License v3 (GPLv3)"),
+    ("GPL-3.0-or-later", "License :: OSI Approved :: GNU General Public License v3 or later (GPLv3+)"),
+    ("HPND", "License :: OSI Approved :: Historical Permission Notice and Disclaimer (HPND)"),
+    ("IPL-1.0", "License :: OSI Approved :: IBM Public License"),
+    ("ISC", "License :: OSI Approved :: ISC License (ISCL)"),
+    ("Intel", "License :: OSI Approved :: Intel Open Source License"),
+    ("LGPL-2.0-only", "License :: OSI Approved :: GNU Lesser General Public License v2 (LGPLv2)"),
+    ("LGPL-2.0-or-later", "License :: OSI Approved :: GNU Lesser General Public License v2 or later (LGPLv2+)"),
+    ("LGPL-3.0-only", "License :: OSI Approved :: GNU Lesser General Public License v3 (LGPLv3)"),
+    ("LGPL-3.0-or-later", "License :: OSI Approved :: GNU Lesser General Public License v3 or later (LGPLv3+)"),
+    ("MIT", "License :: OSI Approved :: MIT License"),
+    ("MIT-0", "License :: OSI Approved :: MIT No Attribution License (MIT-0)"),
+    ("MIT-CMU", "License :: OSI Approved :: CMU License (MIT-CMU)"),
+    ("MPL-1.0", "License :: OSI Approved :: Mozilla Public License 1.0 (MPL)"),
+    ("MPL-1.1", "License :: OSI Approved :: Mozilla Public License 1.1 (MPL 1.1)"),
+    ("MPL-2.0", "License :: OSI Approved :: Mozilla Public License 2.0 (MPL 2.0)"),
+    ("MirOS", "License :: OSI Approved :: MirOS License (MirOS)"),
+    ("Motosoto", "License :: OSI Approved :: Motosoto License"),
+    ("MulanPSL-2.0", "License :: OSI Approved :: Mulan Permissive Software License v2 (MulanPSL-2.0)"),
+    ("NASA-1.3", "License :: OSI Approved :: NASA Open Source Agreement v1.3 (NASA-1.3)"),
+    ("NCSA", "License :: OSI Approved :: University of Illinois/NCSA Open Source License"),
+    ("NGPL", "License :: OSI Approved :: Nethack General Public License"),
+    ("NPL-1.1", "License :: Netscape Public License (NPL)"),
+    ("Nokia", "License :: OSI Approved :: Nokia Open Source License"),
+    ("OFL-1.1", "License :: OSI Approved :: SIL Open Font License 1.1 (OFL-1.1)"),
+    ("OGTSL", "License :: OSI Approved :: Open Group Test Suite License"),
+    ("OSL-3.0", "License :: OSI Approved :: Open Software License 3.0 (OSL-3.0)"),
+    ("PSF-2.0", "License :: OSI Approved :: Python Software Foundation License"),
+    ("PostgreSQL", "License :: OSI Approved :: PostgreSQL License"),
+    ("QPL-1.0", "License :: OSI Approved :: Qt Public License (QPL)"),
+    ("RSCPL", "License :: OSI Approved :: Ricoh Source Code Public License"),
+    ("SISSL", "License :: OSI Approved :: Sun Industry Standards Source License (SISSL)"),
+    ("SPL-1.0", "License :: OSI Approved :: Sun Public License"),
+    ("Sleepycat", "License :: OSI Approved :: Sleepycat License"),
+    ("UPL-1.0", "License :: OSI Approved :: Universal Permissive License (UPL)"),
+    ("Unlicense", "License :: OSI Approved :: The Unlicense (Unlicense)"),
+    ("VSL-1.0", "License :: OSI Approved :: Vovida Software License 1.0"),
+    ("W3C", "License :: OSI Approved :: W3C License"),
+    ("Xnet", "License :: OSI Approved :: X.Net License"),
+    ("ZPL-2.1", "License :: OSI Approved :: Zope Public License"),
+    ("Zlib", "License :: OSI Approved :: zlib/libpng License"),
+];
+
+/// The classifier of an SPDX license identifier, which is matched ignoring ASCII case
+pub fn from_spdx(id: &str) -> Option<Classifier> {
+    let id = id.trim();
+    SPDX.iter()
+        .find(|(spdx, _)| spdx.eq_ignore_ascii_case(id))
+        .and_then(|(_, classifier)| Classifier::from_str(classifier).ok())
+}
+
+/// The SPDX license identifier of a classifier, if it names a single license
+pub fn to_spdx(classifier: Classifier) -> Option<&'static str> {
+    let string = classifier.as_str();
+    SPDX.iter()
+        .find(|(_, mapped)| *mapped == string)
+        .map(|(spdx, _)| *spdx)
+}
+
+/// The classifier of a license of the `spdx` crate
+#[cfg(feature = "spdx")]
+pub fn from_license_id(id: spdx::LicenseId) -> Option<Classifier> {
+    from_spdx(id.name)
+}
+
+/// The license of the `spdx` crate a classifier names, if it names a single license
+///
+/// # Examples
+///
+/// ```
+/// use trove_classifiers::{license, Classifier};
+///
+/// let mit = spdx::license_id("MIT").unwrap();
+/// assert_eq!(license::to_license_id(Classifier::License__OSIApproved__MITLicense), Some(mit));
+/// assert_eq!(license::from_license_id(mit), Some(Classifier::License__OSIApproved__MITLicense));
+/// ```
+#[cfg(feature = "spdx")]
+pub fn to_license_id(classifier: Classifier) -> Option<spdx::LicenseId> {
+    to_spdx(classifier).and_then(spdx::license_id)
+}
+
+/// The classifier of a license requirement of an SPDX expression
+///
+/// The `spdx` crate reads `GPL-3.0-or-later` as `GPL-3.0` with `or_later` set, which this maps
+/// back to the classifier of GPLv3+. Exceptions, and `+` on other licenses, are ignored.
+#[cfg(feature = "spdx")]
+pub fn from_license_req(req: &spdx::LicenseReq) -> Option<Classifier> {
+    let spdx::LicenseItem::Spdx { id, or_later } = req.license else {
+        return None;
+    };
+    if id.is_gnu() {
+        let suffix = if or_later { "or-later" } else { "only" };
+        if let Some(classifier) = from_spdx(&format!("{}-{suffix}", id.name)) {
+            return Some(classifier);
+        }
+    }
+    from_license_id(id)
+}
+
+/// The license requirement of the `spdx` crate a classifier names, if it names a single license
+#[cfg(feature = "spdx")]
+pub fn to_license_req(classifier: Classifier) -> Option<spdx::LicenseReq> {
+    to_license_id(classifier).map(spdx::LicenseReq::from)
+}
+
+/// The classifiers of the licenses of a parsed SPDX expression, each once, in the order they
+/// first appear
+///
+/// Licenses without a classifier, including `LicenseRef-` references, are skipped. Classifiers
+/// do not say how the licenses they name combine, so there is no conversion back to an
+/// expression: build one from the `to_license_req` of each classifier.
+///
+/// # Examples
+///
+/// ```
+/// use trove_classifiers::{license, Classifier};
+///
+/// let expression = spdx::Expression::parse("(MIT OR GPL-3.0-or-later) AND LicenseRef-Mine")?;
+/// assert_eq!(
+///     license::from_expression(&expression),
+///     [
+///         Classifier::License__OSIApproved__MITLicense,
+///         Classifier::License__OSIApproved__GNUGeneralPublicLicensev3orlaterGPLv3plus,
+///     ]
+/// );
+/// # Ok::<(), spdx::ParseError>(())
+/// ```
+#[cfg(feature = "spdx")]
+pub fn from_expression(expression: &spdx::Expression) -> Vec<Classifier> {
+    let mut classifiers = Vec::new();
+    for requirement in expression.requirements() {
+        if let Some(classifier) = from_license_req(&requirement.req) {
+            if !classifiers.contains(&classifier) {
+                classifiers.push(classifier);
+            }
+        }
+    }
+    classifiers
+}
+
+/// The family of the license a License classifier names, or `None` for other classifiers
+///
+/// # Examples
+///
+#[cfg_attr(not(feature = "all-categories"), doc = "```ignore")]
+#[cfg_attr(feature = "all-categories", doc = "```")]
+/// use trove_classifiers::license::{family, LicenseFamily};
+/// use trove_classifiers::Classifier;
+///
+/// let lgpl = Classifier::License__OSIApproved__GNULesserGeneralPublicLicensev3LGPLv3;
+/// assert_eq!(family(lgpl), Some(LicenseFamily::WeakCopyleft));
+/// assert_eq!(family(Classifier::Typing__Typed), None);
+/// ```
+pub fn family(classifier: Classifier) -> Option<LicenseFamily> {
+    let string = classifier.as_str();
+    let license = string.strip_prefix("License :: ")?;
+    Some(
+        FAMILIES
+            .iter()
+            .find(|(words, _)| license.contains(words))
+            .map_or(LicenseFamily::Other, |(_, family)| *family),
+    )
+}
+
+/// The license identifiers of an SPDX license expression, in the order they appear
+///
+/// Operators, parentheses and the exceptions following `WITH` are skipped. The expression is not
+/// otherwise validated.
+///
+/// # Examples
+///
+/// ```
+/// use trove_classifiers::license::spdx_ids;
+///
+/// let ids: Vec<&str> = spdx_ids("(MIT OR Apache-2.0) AND GPL-2.0-only WITH Classpath-exception-2.0")
+///     .collect();
+/// assert_eq!(ids, ["MIT", "Apache-2.0", "GPL-2.0-only"]);
+/// ```
+pub fn spdx_ids(expression: &str) -> impl Iterator<Item = &str> {
+    let mut exception = false;
+    expression
+        .split(|c: char| c.is_whitespace() || c == '(' || c == ')')
+        .filter(move |token| match *token {
+            "" | "AND" | "OR" => false,
+            "WITH" => {
+                exception = true;
+                false
+            }
+            _ => !core::mem::take(&mut exception),
+        })
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    #[cfg(feature = "license")]
+    fn every_mapping_names_a_classifier() {
+        for (spdx, classifier) in SPDX {
+            assert!(Classifier::from_str(classifier).is_ok(), "{spdx}");
+            assert!(from_spdx(spdx).is_some(), "{spdx}");
+        }
+    }
+
+    #[test]
+    #[cfg(feature = "license")]
+    fn round_trips_to_first_identifier() {
+        let bsd = from_spdx("BSD-2-Clause").unwrap();
+        assert_eq!(bsd, Classifier::License__OSIApproved__BSDLicense);
+        assert_eq!(to_spdx(bsd), Some("BSD-3-Clause"));
+        assert_eq!(to_spdx(Classifier::License__OSIApproved), None);
+    }
+
+    #[test]
+    #[cfg(feature = "license")]
+    fn license_families() {
+        let family_of = |string: &str| family(Classifier::from_str(string).unwrap());
+        for (string, expected) in [
+            (
+                "License :: OSI Approved :: MIT License",
+                LicenseFamily::Permissive,
+            ),
+            (
+                "License :: OSI Approved :: MIT No Attribution License (MIT-0)",
+                LicenseFamily::Permissive,
+            ),
+            (
+                "License :: OSI Approved :: GNU General Public License v2 (GPLv2)",
+                LicenseFamily::StrongCopyleft,
+            ),
+            (
+                "License :: OSI Approved :: GNU Library or Lesser General Public License (LGPL)",
+                LicenseFamily::WeakCopyleft,
+            ),
+            (
+                "License :: OSI Approved :: Mozilla Public License 2.0 (MPL 2.0)",
+                LicenseFamily::WeakCopyleft,
+            ),
+            (
+                "License :: CC0 1.0 Universal (CC0 1.0) Public Domain Dedication",
+                LicenseFamily::PublicDomain,
+            ),
+            ("License :: Free For Home Use", LicenseFamily::Proprietary),
+            (
+                "License :: Other/Proprietary License",
+                LicenseFamily::Proprietary,
+            ),
+            ("License :: OSI Approved", LicenseFamily::Other),
+            ("License :: DFSG approved", LicenseFamily::Other),
+        ] {
+            assert_eq!(family_of(string), Some(expected), "{string}");
+        }
+    }
+
+    #[test]
+    #[cfg(feature = "license")]
+    fn every_mapping_has_a_family() {
+        for (spdx, _) in SPDX {
+            let family = family(from_spdx(spdx).unwrap());
+            assert!(
+                family.is_some_and(|family| family != LicenseFamily::Other),
+                "{spdx}"
+            );
+        }
+    }
+
+    #[test]
+    fn expressions_yield_license_identifiers() {
+        assert!(spdx_ids("MIT").eq(["MIT"]));
+        assert!(spdx_ids(" ( MIT OR Apache-2.0+ ) ").eq(["MIT", "Apache-2.0+"]));
+        assert!(spdx_ids("GPL-3.0-or-later WITH GCC-exception-3.1 OR MIT")
+            .eq(["GPL-3.0-or-later", "MIT"]));
+        assert_eq!(spdx_ids("").count(), 0);
+    }
+
+    #[test]
+    #[cfg(all(feature = "spdx", feature = "license"))]
+    fn every_mapping_is_an_spdx_license() {
+        for (id, _) in SPDX {
+            let license = spdx::license_id(id).unwrap();
+            let classifier = from_license_id(license).unwrap();
+            assert_eq!(from_license_req(&license.into()), Some(classifier), "{id}");
+        }
+        let gpl = to_license_req(Classifier::License__OSIApproved__GNUGeneralPublicLicensev2GPLv2);
+        assert_eq!(gpl.unwrap().to_string(), "GPL-2.0");
+    }
+
+    #[test]
+    fn unknown_identifiers_do_not_match() {
+        assert_eq!(from_spdx("Proprietary"), None);
+        assert_eq!(from_spdx(""), None);
+    }
+}
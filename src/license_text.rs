@@ -0,0 +1,124 @@
+//! Detection of the license of a license file from its text.
+//!
+//! Texts are compared as askalono compares them: both are normalized to lowercase words, with
+//! copyright lines and punctuation left out, and the score of a license is the Sørensen–Dice
+//! coefficient of the word bigrams of its SPDX text and of the file. Only the
+//! licenses that `license::from_spdx` maps to a classifier are candidates.
+
+use std::collections::HashSet;
+use std::sync::OnceLock;
+
+use crate::license;
+
+/// The word bigrams of a text, hashed
+type Fingerprint = HashSet<u64>;
+
+/// The SPDX identifiers with a classifier and the fingerprints of their texts
+fn candidates() -> &'static [(&'static str, Fingerprint)] {
+    static CANDIDATES: OnceLock<Vec<(&'static str, Fingerprint)>> = OnceLock::new();
+    CANDIDATES.get_or_init(|| {
+        license::SPDX
+            .iter()
+            .filter_map(|(id, _)| {
+                let text = spdx::license_id(id)?.text();
+                Some((*id, fingerprint(text)))
+            })
+            .collect()
+    })
+}
+
+/// The SPDX identifier of the license most like `text`, and how alike they are from 0 to 1
+///
+/// Of licenses with the same text, such as `GPL-3.0-only` and `GPL-3.0-or-later`, the first
+/// listed by `license::SPDX` is returned.
+pub(crate) fn best_match(text: &str) -> Option<(&'static str, f32)> {
+    let file = fingerprint(text);
+    if file.is_empty() {
+        return None;
+    }
+    candidates()
+        .iter()
+        .map(|(id, template)| {
+            let shared = file.intersection(template).count();
+            let score = 2.0 * shared as f32 / (file.len() + template.len()) as f32;
+            (*id, score)
+        })
+        .reduce(|best, candidate| {
+            if candidate.1 > best.1 {
+                candidate
+            } else {
+                best
+            }
+        })
+}
+
+fn fingerprint(text: &str) -> Fingerprint {
+    let words: Vec<String> = text
+        .lines()
+        .filter(|line| !is_copyright(line))
+        .flat_map(|line| line.split(|c: char| !c.is_alphanumeric()))
+        .filter(|word| !word.is_empty())
+        .map(str::to_lowercase)
+        .collect();
+    words
+        .windows(2)
+        .map(|pair| {
+            // FNV-1a, so that fingerprints do not depend on a random seed
+            let mut hash: u64 = 0xcbf29ce484222325;
+            for byte in pair[0].bytes().chain([b' ']).chain(pair[1].bytes()) {
+                hash ^= u64::from(byte);
+                hash = hash.wrapping_mul(0x100000001b3);
+            }
+            hash
+        })
+        .collect()
+}
+
+/// Whether a line is a copyright notice, which differs from one project to the next
+fn is_copyright(line: &str) -> bool {
+    let line = line.trim_start().to_lowercase();
+    line.starts_with("copyright") || line.starts_with("(c)") || line.starts_with('©')
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    const MIT: &str = "Copyright (c) 2024 Jane Doe\n\n\
+        Permission is hereby granted, free of charge, to any person obtaining a copy of this \
+        software and associated documentation files (the \"Software\"), to deal in the Software \
+        without restriction, including without limitation the rights to use, copy, modify, \
+        merge, publish, distribute, sublicense, and/or sell copies of the Software, and to \
+        permit persons to whom the Software is furnished to do so, subject to the following \
+        conditions:\n\n\
+        The above copyright notice and this permission notice shall be included in all copies \
+        or substantial portions of the Software.\n\n\
+        THE SOFTWARE IS PROVIDED \"AS IS\", WITHOUT WARRANTY OF ANY KIND, EXPRESS OR IMPLIED, \
+        INCLUDING BUT NOT LIMITED TO THE WARRANTIES OF MERCHANTABILITY, FITNESS FOR A PARTICULAR \
+        PURPOSE AND NONINFRINGEMENT. IN NO EVENT SHALL THE AUTHORS OR COPYRIGHT HOLDERS BE \
+        LIABLE FOR ANY CLAIM, DAMAGES OR OTHER LIABILITY, WHETHER IN AN ACTION OF CONTRACT, TORT \
+        OR OTHERWISE, ARISING FROM, OUT OF OR IN CONNECTION WITH THE SOFTWARE OR THE USE OR \
+        OTHER DEALINGS IN THE SOFTWARE.\n";
+
+    #[test]
+    fn license_files_match_their_license() {
+        let (id, confidence) = best_match(MIT).unwrap();
+        assert_eq!(id, "MIT");
+        assert!(confidence > 0.9, "{confidence}");
+        for (id, _) in license::SPDX {
+            if let Some(license) = spdx::license_id(id) {
+                let (found, _) = best_match(license.text()).unwrap();
+                assert_eq!(spdx::license_id(found).unwrap().text(), license.text());
+            }
+        }
+    }
+
+    #[test]
+    fn other_text_matches_poorly() {
+        assert_eq!(best_match(""), None);
+        assert_eq!(best_match("Copyright 2024 Jane Doe\n"), None);
+        let (_, confidence) =
+            best_match("A package that does things, and does them well.").unwrap();
+        assert!(confidence < 0.1, "{confidence}");
+    }
+}
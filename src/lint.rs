@@ -0,0 +1,1226 @@
+//! Checks of declared classifiers against the rules pypi.org enforces on upload.
+//!
+//! pypi.org rejects an upload declaring a classifier it does not know, including deprecated
+//! classifiers, classifiers that are not written canonically and, on purpose, `Private ::`
+//! classifiers. `lint` reports each of these, and declarations that are merely untidy, as a
+//! `Finding` of a `Rule` with a stable code that tools can match on.
+//!
+//! `lint_version` also checks the declared `Development Status` against the project's version,
+//! which `lint` cannot see, and `lint_legacy` advises against classifiers of abandoned ecosystems
+//! in a project still being developed. `lint_framework_eol` warns of frameworks only declared in
+//! versions past their end of life.
+//!
+//! Each rule is a `Lint`. Organizations with policies of their own implement `Lint` for them and
+//! register them with a `Linter`, which checks a `Context` against the built-in rules and theirs.
+//! A `Linter` can also set the `Level` of each rule, clippy-style, so that a large project can
+//! adopt a rule as a warning before denying it.
+//!
+//! # Examples
+//!
+#![cfg_attr(not(feature = "typing"), doc = "```ignore")]
+#![cfg_attr(feature = "typing", doc = "```")]
+//! use trove_classifiers::lint::{lint, Declared, Rule, Severity};
+//!
+//! let findings = lint(&[
+//!     Declared::new("Typing :: Typed").line(7),
+//!     Declared::new("typing :: typed").line(8),
+//! ]);
+//! assert_eq!(findings.len(), 1);
+//! assert_eq!(findings[0].rule, Rule::NonCanonical);
+//! assert_eq!(findings[0].rule.code(), "TC003");
+//! assert_eq!(findings[0].severity, Severity::Error);
+//! assert_eq!(findings[0].line, Some(8));
+//! ```
+
+use alloc::boxed::Box;
+use alloc::format;
+use alloc::string::{String, ToString};
+use alloc::vec::Vec;
+use core::fmt;
+
+use crate::alias::Aliases;
+use crate::lenient::lenient;
+use crate::pep440::Release;
+use crate::suggest::development_status;
+use crate::{deprecation, eol, Classifier};
+
+/// How serious a finding is
+#[derive(Clone, Copy, Debug, Eq, Hash, Ord, PartialEq, PartialOrd)]
+pub enum Severity {
+    /// The declaration is untidy, but pypi.org accepts it
+    Warning,
+    /// pypi.org rejects an upload with this declaration
+    Error,
+}
+
+impl Severity {
+    pub fn as_str(&self) -> &'static str {
+        match self {
+            Severity::Warning => "warning",
+            Severity::Error => "error",
+        }
+    }
+}
+
+impl fmt::Display for Severity {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        f.pad(self.as_str())
+    }
+}
+
+/// What a `Linter` does with the findings of a rule
+#[derive(Clone, Copy, Debug, Eq, Hash, Ord, PartialEq, PartialOrd)]
+pub enum Level {
+    /// Ignore the findings
+    Allow,
+    /// Report the findings as warnings
+    Warn,
+    /// Report the findings as errors
+    Deny,
+}
+
+impl Level {
+    pub fn as_str(&self) -> &'static str {
+        match self {
+            Level::Allow => "allow",
+            Level::Warn => "warn",
+            Level::Deny => "deny",
+        }
+    }
+
+    /// The level with a name, ignoring ASCII case
+    pub fn find(name: &str) -> Option<Level> {
+        [Level::Allow, Level::Warn, Level::Deny]
+            .into_iter()
+            .find(|level| level.as_str().eq_ignore_ascii_case(name.trim()))
+    }
+}
+
+impl fmt::Display for Level {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        f.pad(self.as_str())
+    }
+}
+
+/// A check made by `lint`
+///
+/// Codes and names are stable: a rule keeps them for as long as it exists, and they are never
+/// reused by another rule.
+#[derive(Clone, Copy, Debug, Eq, Hash, Ord, PartialEq, PartialOrd)]
+#[non_exhaustive]
+pub enum Rule {
+    /// The classifier is not known to pypi.org
+    Unknown,
+    /// The classifier is deprecated
+    Deprecated,
+    /// The classifier is known, but not written canonically
+    NonCanonical,
+    /// The classifier is a `Private ::` classifier, which pypi.org refuses by design
+    Private,
+    /// The classifier is declared more than once
+    Duplicate,
+    /// The `Development Status` classifier disagrees with the version
+    VersionStatus,
+    /// A classifier the policy requires is not declared
+    Required,
+    /// The classifier is in a category or under a prefix the policy forbids
+    Forbidden,
+    /// The classifier signals an abandoned ecosystem, in a project still being developed
+    Legacy,
+    /// The framework version is past its end of life, like every other declared version of it
+    FrameworkEol,
+    /// A rule registered with a `Linter` by its caller, with its code
+    Custom(&'static str),
+}
+
+impl Rule {
+    /// Every built-in rule, in order of their codes
+    pub const ALL: &'static [Rule] = &[
+        Rule::Unknown,
+        Rule::Deprecated,
+        Rule::NonCanonical,
+        Rule::Private,
+        Rule::Duplicate,
+        Rule::VersionStatus,
+        Rule::Required,
+        Rule::Forbidden,
+        Rule::Legacy,
+        Rule::FrameworkEol,
+    ];
+
+    /// The stable code of the rule, such as `TC001`
+    pub fn code(&self) -> &'static str {
+        match self {
+            Rule::Unknown => "TC001",
+            Rule::Deprecated => "TC002",
+            Rule::NonCanonical => "TC003",
+            Rule::Private => "TC004",
+            Rule::Duplicate => "TC005",
+            Rule::VersionStatus => "TC006",
+            Rule::Required => "TC007",
+            Rule::Forbidden => "TC008",
+            Rule::Legacy => "TC009",
+            Rule::FrameworkEol => "TC010",
+            Rule::Custom(code) => code,
+        }
+    }
+
+    /// The stable name of the rule, such as `unknown-classifier`
+    ///
+    /// A custom rule is named by its code.
+    pub fn name(&self) -> &'static str {
+        match self {
+            Rule::Unknown => "unknown-classifier",
+            Rule::Deprecated => "deprecated-classifier",
+            Rule::NonCanonical => "noncanonical-classifier",
+            Rule::Private => "private-classifier",
+            Rule::Duplicate => "duplicate-classifier",
+            Rule::VersionStatus => "version-status-mismatch",
+            Rule::Required => "missing-required-classifier",
+            Rule::Forbidden => "forbidden-classifier",
+            Rule::Legacy => "legacy-classifier",
+            Rule::FrameworkEol => "eol-framework",
+            Rule::Custom(code) => code,
+        }
+    }
+
+    /// The severity of the rule's findings
+    pub fn severity(&self) -> Severity {
+        match self {
+            Rule::Unknown
+            | Rule::Deprecated
+            | Rule::NonCanonical
+            | Rule::Required
+            | Rule::Forbidden => Severity::Error,
+            Rule::Private
+            | Rule::Duplicate
+            | Rule::VersionStatus
+            | Rule::Legacy
+            | Rule::FrameworkEol
+            | Rule::Custom(_) => Severity::Warning,
+        }
+    }
+
+    /// A one line description of what the rule checks
+    pub fn summary(&self) -> &'static str {
+        match self {
+            Rule::Unknown => "classifiers must be known to pypi.org",
+            Rule::Deprecated => "deprecated classifiers must be replaced",
+            Rule::NonCanonical => "classifiers must be written exactly as pypi.org lists them",
+            Rule::Private => "Private :: classifiers prevent uploads to pypi.org",
+            Rule::Duplicate => "classifiers should be declared once",
+            Rule::VersionStatus => "the development status should agree with the version",
+            Rule::Required => "classifiers the policy requires must be declared",
+            Rule::Forbidden => "classifiers the policy forbids must not be declared",
+            Rule::Legacy => "active projects should not declare abandoned ecosystems",
+            Rule::FrameworkEol => "frameworks should be supported in a version still maintained",
+            Rule::Custom(_) => "a rule of the tool reporting it",
+        }
+    }
+
+    /// Why the rule exists, how to fix its findings and an example of each
+    pub fn explanation(&self) -> &'static Explanation {
+        match self {
+            Rule::Unknown => &Explanation {
+                rationale: "pypi.org only accepts the classifiers it lists at \
+                    https://pypi.org/classifiers/ and rejects an upload declaring any other. \
+                    Classifiers are a closed vocabulary: they cannot be invented, and a typo is \
+                    as unknown as a made up classifier.",
+                fix: "Find the intended classifier with `trove search`, or remove the \
+                    declaration. A project that needs to mark itself as never to be uploaded \
+                    should use a `Private ::` classifier instead.",
+                bad: "Programming Language :: Python :: 3.1O",
+                good: "Programming Language :: Python :: 3.10",
+            },
+            Rule::Deprecated => &Explanation {
+                rationale: "Classifiers are occasionally deprecated, usually because they were \
+                    misspelled or have been split into more precise classifiers. pypi.org \
+                    rejects uploads declaring a deprecated classifier.",
+                fix: "Replace the classifier with its replacements, which `trove fix` does \
+                    automatically, or remove it if it has none.",
+                bad: "Natural Language :: Ukranian",
+                good: "Natural Language :: Ukrainian",
+            },
+            Rule::NonCanonical => &Explanation {
+                rationale: "pypi.org compares classifiers exactly, so a classifier written with \
+                    different case or spacing than it is listed with is unknown to it, even \
+                    though a reader would recognize it. The same goes for the shorthands a \
+                    policy defines as aliases.",
+                fix: "Write the classifier exactly as pypi.org lists it, which `trove fix` \
+                    does automatically.",
+                bad: "typing::typed",
+                good: "Typing :: Typed",
+            },
+            Rule::Private => &Explanation {
+                rationale: "pypi.org rejects every classifier starting with `Private ::`. \
+                    Projects declare one on purpose to make sure they are never uploaded by \
+                    accident, so this is only a warning.",
+                fix: "Keep the classifier if the project must stay private, otherwise remove \
+                    it before uploading.",
+                bad: "Private :: Do Not Upload",
+                good: "(no classifier)",
+            },
+            Rule::Duplicate => &Explanation {
+                rationale: "Declaring a classifier twice says nothing more than declaring it \
+                    once. pypi.org accepts it, but the duplicate usually hides a merge mistake \
+                    or a copy and paste error.",
+                fix: "Remove every declaration but the first, which `trove fix` does \
+                    automatically.",
+                bad: "Typing :: Typed\nTyping :: Typed",
+                good: "Typing :: Typed",
+            },
+            Rule::VersionStatus => &Explanation {
+                rationale: "Readers pick between projects by their development status, so a \
+                    status that contradicts the version misleads them: a project at version \
+                    3.4.1 is rarely pre-alpha, and one at 0.0.1 is rarely stable. The examples \
+                    are declared at version 3.4.1.",
+                fix: "Declare the status `trove suggest` proposes for the version, or release a \
+                    version that matches the status.",
+                bad: "Development Status :: 2 - Pre-Alpha",
+                good: "Development Status :: 5 - Production/Stable",
+            },
+            Rule::Required => &Explanation {
+                rationale: "A policy, such as the `required` list of a trove.toml, can require \
+                    every project it applies to to declare some classifiers, so that an \
+                    organization's projects are described alike. The examples are of a policy \
+                    requiring \"Typing :: Typed\".",
+                fix: "Declare the required classifiers, or change the policy.",
+                bad: "License :: OSI Approved :: MIT License",
+                good: "License :: OSI Approved :: MIT License\nTyping :: Typed",
+            },
+            Rule::Forbidden => &Explanation {
+                rationale: "A policy, such as the `forbidden` list of a trove.toml, can forbid \
+                    the classifiers of a category, or under a longer prefix, that its projects \
+                    must never declare. The examples are of a policy forbidding \
+                    \"License :: Other/Proprietary License\" and requiring \"Typing :: Typed\".",
+                fix: "Remove the forbidden classifiers, or change the policy.",
+                bad: "Typing :: Typed\nLicense :: Other/Proprietary License",
+                good: "Typing :: Typed\nLicense :: OSI Approved :: MIT License",
+            },
+            Rule::Legacy => &Explanation {
+                rationale: "Some classifiers describe ecosystems that have long been abandoned, \
+                    such as Python 2, Django 1 or window managers nobody maintains. A project \
+                    still being developed rarely supports them, and declaring them usually means \
+                    the classifiers were copied from an older project. This is only advice: a \
+                    project that really does support them should allow the rule.",
+                fix: "Remove the classifiers, or declare the project \"6 - Mature\" or \
+                    \"7 - Inactive\" if it is no longer developed.",
+                bad: "Development Status :: 5 - Production/Stable\n\
+                    Programming Language :: Python :: 2.7",
+                good: "Development Status :: 5 - Production/Stable\n\
+                    Programming Language :: Python :: 3",
+            },
+            Rule::FrameworkEol => &Explanation {
+                rationale: "Django, Odoo and Plone publish when each of their versions stops \
+                    receiving security fixes. A project only declaring versions of a framework \
+                    that are past their end of life tells its users it cannot be run securely, \
+                    or that its classifiers were not updated when it moved to a newer version.",
+                fix: "Support a version of the framework that is still maintained and declare \
+                    it. The older versions can stay declared alongside it.",
+                bad: "Framework :: Django :: 3.2",
+                good: "Framework :: Django :: 3.2\nFramework :: Django :: 5.2",
+            },
+            Rule::Custom(_) => &Explanation {
+                rationale: "This rule is not one of trove-classifiers' own, but was added by the \
+                    tool reporting it.",
+                fix: "See the documentation of the tool reporting it.",
+                bad: "",
+                good: "",
+            },
+        }
+    }
+
+    /// The built-in rule with a code or name, ignoring ASCII case
+    pub fn find(code_or_name: &str) -> Option<Rule> {
+        Rule::ALL.iter().copied().find(|rule| {
+            rule.code().eq_ignore_ascii_case(code_or_name)
+                || rule.name().eq_ignore_ascii_case(code_or_name)
+        })
+    }
+}
+
+/// The documentation of a rule
+#[derive(Clone, Copy, Debug, Eq, Hash, PartialEq)]
+#[non_exhaustive]
+pub struct Explanation {
+    /// Why the rule exists
+    pub rationale: &'static str,
+    /// How to fix a finding
+    pub fix: &'static str,
+    /// Declarations breaking the rule, one per line
+    pub bad: &'static str,
+    /// The same declarations, fixed
+    pub good: &'static str,
+}
+
+impl fmt::Display for Rule {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        f.pad(self.code())
+    }
+}
+
+/// A classifier as a project declares it, before it is checked
+#[derive(Clone, Debug, Eq, Hash, PartialEq)]
+pub struct Declared {
+    /// The declared string, as written
+    pub value: String,
+    /// The 1-based line of the declaration in its file, if it was read from one
+    pub line: Option<usize>,
+}
+
+impl Declared {
+    pub fn new(value: &str) -> Self {
+        Declared {
+            value: value.to_string(),
+            line: None,
+        }
+    }
+
+    pub fn line(mut self, line: usize) -> Self {
+        self.line = Some(line);
+        self
+    }
+}
+
+/// A declaration breaking a rule
+#[derive(Clone, Debug, Eq, Hash, PartialEq)]
+pub struct Finding {
+    pub rule: Rule,
+    pub severity: Severity,
+    /// The offending declaration
+    pub value: String,
+    /// The 1-based line of the declaration, if known
+    pub line: Option<usize>,
+    /// What is wrong, and how to fix it when that is known
+    pub message: String,
+}
+
+/// Check declared classifiers, reporting findings in the order of the declarations
+pub fn lint(declared: &[Declared]) -> Vec<Finding> {
+    lint_aliased(declared, None)
+}
+
+/// `lint`, reporting the shorthands of `aliases` as `Rule::NonCanonical` rather than unknown
+fn lint_aliased(declared: &[Declared], aliases: Option<&Aliases>) -> Vec<Finding> {
+    let mut findings = Vec::new();
+    let mut seen: Vec<Classifier> = Vec::new();
+    for declaration in declared {
+        let value = declaration.value.as_str();
+        let found = match value.parse::<Classifier>() {
+            Ok(classifier) if seen.contains(&classifier) => {
+                Some((Rule::Duplicate, format!("{value:?} is already declared")))
+            }
+            Ok(classifier) => {
+                seen.push(classifier);
+                None
+            }
+            Err(_) => Some(check_unknown(value, aliases)),
+        };
+        if let Some((rule, message)) = found {
+            findings.push(Finding {
+                rule,
+                severity: rule.severity(),
+                value: value.to_string(),
+                line: declaration.line,
+                message,
+            });
+        }
+    }
+    findings
+}
+
+/// What a `Lint` checks: a project's declared classifiers, what else is known about it and the
+/// policy it follows
+#[derive(Clone, Copy, Debug, Eq, Hash, PartialEq)]
+pub struct Context<'a> {
+    pub declared: &'a [Declared],
+    /// The project's PEP 440 version, if known
+    pub version: Option<&'a str>,
+    /// How closely `Rule::VersionStatus` holds the development status to the version
+    pub strictness: Strictness,
+    /// The classifiers `Rule::Required` requires
+    pub required: &'a [Classifier],
+    /// The categories and prefixes, such as `Private` or `License :: Other/Proprietary License`,
+    /// whose classifiers `Rule::Forbidden` forbids
+    pub forbidden: &'a [String],
+    /// The shorthands `Rule::NonCanonical` resolves, rather than reporting them unknown
+    pub aliases: Option<&'a Aliases>,
+}
+
+impl<'a> Context<'a> {
+    pub fn new(declared: &'a [Declared]) -> Self {
+        Context {
+            declared,
+            version: None,
+            strictness: Strictness::default(),
+            required: &[],
+            forbidden: &[],
+            aliases: None,
+        }
+    }
+
+    pub fn version(mut self, version: &'a str) -> Self {
+        self.version = Some(version);
+        self
+    }
+
+    pub fn strictness(mut self, strictness: Strictness) -> Self {
+        self.strictness = strictness;
+        self
+    }
+
+    pub fn required(mut self, required: &'a [Classifier]) -> Self {
+        self.required = required;
+        self
+    }
+
+    pub fn forbidden(mut self, forbidden: &'a [String]) -> Self {
+        self.forbidden = forbidden;
+        self
+    }
+
+    pub fn aliases(mut self, aliases: &'a Aliases) -> Self {
+        self.aliases = Some(aliases);
+        self
+    }
+
+    /// The declarations that are known classifiers written canonically, with the classifiers
+    pub fn classifiers(&self) -> impl Iterator<Item = (&'a Declared, Classifier)> + 'a {
+        self.declared
+            .iter()
+            .filter_map(|declared| Some((declared, declared.value.parse().ok()?)))
+    }
+}
+
+/// A check of a `Context`
+///
+/// # Examples
+///
+/// A policy requiring every project to declare a license:
+///
+#[cfg_attr(not(feature = "typing"), doc = "```ignore")]
+#[cfg_attr(feature = "typing", doc = "```")]
+/// use trove_classifiers::lint::{Context, Declared, Finding, Lint, Linter, Rule, Severity};
+///
+/// struct RequireLicense;
+///
+/// impl Lint for RequireLicense {
+///     fn code(&self) -> &'static str {
+///         "ORG001"
+///     }
+///
+///     fn severity(&self) -> Severity {
+///         Severity::Error
+///     }
+///
+///     fn check(&self, context: &Context<'_>) -> Vec<Finding> {
+///         let licensed = context
+///             .classifiers()
+///             .any(|(_, classifier)| classifier.segments_static()[0] == "License");
+///         if licensed {
+///             return Vec::new();
+///         }
+///         vec![Finding {
+///             rule: Rule::Custom(self.code()),
+///             severity: self.severity(),
+///             value: String::new(),
+///             line: None,
+///             message: "no License classifier is declared".to_string(),
+///         }]
+///     }
+/// }
+///
+/// let linter = Linter::new().register(RequireLicense);
+/// let declared = [Declared::new("Typing :: Typed")];
+/// let findings = linter.check(&Context::new(&declared));
+/// assert_eq!(findings[0].rule, Rule::Custom("ORG001"));
+/// ```
+pub trait Lint {
+    /// The stable code of the rule, such as `TC001`
+    ///
+    /// The `TC` prefix is reserved for the built-in rules.
+    fn code(&self) -> &'static str;
+
+    /// The severity of the rule's findings
+    fn severity(&self) -> Severity;
+
+    /// The findings of the rule in a context
+    fn check(&self, context: &Context<'_>) -> Vec<Finding>;
+}
+
+/// Each built-in rule checks the declarations as `lint` and the other `lint_` functions do, or
+/// against the policy of the context
+impl Lint for Rule {
+    fn code(&self) -> &'static str {
+        Rule::code(self)
+    }
+
+    fn severity(&self) -> Severity {
+        Rule::severity(self)
+    }
+
+    fn check(&self, context: &Context<'_>) -> Vec<Finding> {
+        match self {
+            Rule::VersionStatus => context.version.map_or_else(Vec::new, |version| {
+                lint_version(version, context.declared, context.strictness)
+            }),
+            Rule::Required => check_required(context),
+            Rule::Forbidden => check_forbidden(context),
+            Rule::Legacy => lint_legacy(context.declared),
+            Rule::FrameworkEol => lint_framework_eol(context.declared),
+            Rule::Custom(_) => Vec::new(),
+            _ => {
+                let mut findings = lint_aliased(context.declared, context.aliases);
+                findings.retain(|finding| finding.rule == *self);
+                findings
+            }
+        }
+    }
+}
+
+/// The built-in rules and any registered by the caller, each at a `Level`
+///
+/// A rule whose level is not set reports its findings with the severity it gives them.
+pub struct Linter {
+    lints: Vec<Box<dyn Lint>>,
+    /// The codes of the rules whose level is set, and their levels
+    levels: Vec<(&'static str, Level)>,
+}
+
+impl Linter {
+    /// A linter of the built-in rules
+    pub fn new() -> Self {
+        Linter {
+            lints: Rule::ALL
+                .iter()
+                .map(|rule| Box::new(*rule) as Box<dyn Lint>)
+                .collect(),
+            levels: Vec::new(),
+        }
+    }
+
+    /// Add a rule, which is checked after those added before it
+    pub fn register(mut self, lint: impl Lint + 'static) -> Self {
+        self.lints.push(Box::new(lint));
+        self
+    }
+
+    /// Set the level of a rule, replacing any set before
+    ///
+    /// The rule of a registered lint is `Rule::Custom` with its code.
+    pub fn level(mut self, rule: Rule, level: Level) -> Self {
+        let code = rule.code();
+        self.levels.retain(|(set, _)| *set != code);
+        self.levels.push((code, level));
+        self
+    }
+
+    /// The level of a rule, if it is set
+    pub fn level_of(&self, rule: Rule) -> Option<Level> {
+        let code = rule.code();
+        self.levels
+            .iter()
+            .find(|(set, _)| *set == code)
+            .map(|(_, level)| *level)
+    }
+
+    /// The codes of the rules, in the order they are checked
+    pub fn codes(&self) -> impl Iterator<Item = &'static str> + '_ {
+        self.lints.iter().map(|lint| lint.code())
+    }
+
+    /// The findings of every rule that is not allowed, ordered by line, then by the order of the
+    /// rules
+    ///
+    /// Findings without a line come last.
+    pub fn check(&self, context: &Context<'_>) -> Vec<Finding> {
+        let mut findings = Vec::new();
+        for lint in &self.lints {
+            let code = lint.code();
+            let severity = match self.levels.iter().find(|(set, _)| *set == code) {
+                Some((_, Level::Allow)) => continue,
+                Some((_, Level::Warn)) => Some(Severity::Warning),
+                Some((_, Level::Deny)) => Some(Severity::Error),
+                None => None,
+            };
+            findings.extend(lint.check(context).into_iter().map(|mut finding| {
+                finding.severity = severity.unwrap_or(finding.severity);
+                finding
+            }));
+        }
+        findings.sort_by_key(|finding| finding.line.unwrap_or(usize::MAX));
+        findings
+    }
+}
+
+impl Default for Linter {
+    fn default() -> Self {
+        Linter::new()
+    }
+}
+
+impl fmt::Debug for Linter {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        f.debug_list().entries(self.codes()).finish()
+    }
+}
+
+fn check_required(context: &Context<'_>) -> Vec<Finding> {
+    let declared: Vec<Classifier> = context
+        .classifiers()
+        .map(|(_, classifier)| classifier)
+        .collect();
+    context
+        .required
+        .iter()
+        .filter(|required| !declared.contains(required))
+        .map(|required| Finding {
+            rule: Rule::Required,
+            severity: Rule::Required.severity(),
+            value: required.as_str().to_string(),
+            line: None,
+            message: format!("{:?} is required, but not declared", required.as_str()),
+        })
+        .collect()
+}
+
+fn check_forbidden(context: &Context<'_>) -> Vec<Finding> {
+    let mut findings = Vec::new();
+    for declaration in context.declared {
+        let value = declaration.value.as_str();
+        let forbidden = context.forbidden.iter().find(|forbidden| {
+            let mut segments = value.split("::").map(str::trim);
+            forbidden
+                .split("::")
+                .all(|segment| segments.next() == Some(segment.trim()))
+        });
+        if let Some(forbidden) = forbidden {
+            findings.push(Finding {
+                rule: Rule::Forbidden,
+                severity: Rule::Forbidden.severity(),
+                value: value.to_string(),
+                line: declaration.line,
+                message: format!("{value:?} is forbidden by {forbidden:?}"),
+            });
+        }
+    }
+    findings
+}
+
+/// How closely `lint_version` holds the development status to the version
+#[derive(Clone, Copy, Debug, Default, Eq, Hash, Ord, PartialEq, PartialOrd)]
+pub enum Strictness {
+    /// Only flag contradictions: a final release from 1.0 on that is planned, pre-alpha or alpha,
+    /// and a 0.0.x release that is stable or mature
+    #[default]
+    Lenient,
+    /// Flag every status but the one `suggest::development_status` proposes for the version
+    ///
+    /// "1 - Planning" is accepted for a pre-alpha version, "6 - Mature" for a stable one, and
+    /// "7 - Inactive" for any.
+    Strict,
+}
+
+/// Check the declared `Development Status` classifiers against a PEP 440 version
+///
+/// Returns no findings for a version that does not start with a release segment.
+///
+/// # Examples
+///
+#[cfg_attr(not(feature = "development-status"), doc = "```ignore")]
+#[cfg_attr(feature = "development-status", doc = "```")]
+/// use trove_classifiers::lint::{lint_version, Declared, Rule, Strictness};
+///
+/// let declared = [Declared::new("Development Status :: 2 - Pre-Alpha")];
+/// let findings = lint_version("3.4.1", &declared, Strictness::Lenient);
+/// assert_eq!(findings[0].rule, Rule::VersionStatus);
+/// assert!(lint_version("0.4.0", &declared, Strictness::Lenient).is_empty());
+/// assert_eq!(lint_version("0.4.0", &declared, Strictness::Strict).len(), 1);
+/// ```
+pub fn lint_version(version: &str, declared: &[Declared], strictness: Strictness) -> Vec<Finding> {
+    let Some(expected) = development_status(version) else {
+        return Vec::new();
+    };
+    let release = Release::parse(version.split_once('!').map_or(version, |(_, rest)| rest));
+    let zero_zero = release.is_some_and(|release| matches!(release.parts(), [0] | [0, 0, ..]));
+    let expected_stage = stage(expected).unwrap_or_default();
+    let mut findings = Vec::new();
+    for declaration in declared {
+        let value = declaration.value.as_str();
+        let Ok(classifier) = value.parse::<Classifier>() else {
+            continue;
+        };
+        let Some(declared_stage) = stage(classifier) else {
+            continue;
+        };
+        let mismatched = match strictness {
+            Strictness::Lenient => {
+                (expected_stage == 5 && declared_stage <= 3)
+                    || (zero_zero && matches!(declared_stage, 5 | 6))
+            }
+            Strictness::Strict => {
+                !matches!((expected_stage, declared_stage), (_, 7) | (2, 1) | (5, 6))
+                    && declared_stage != expected_stage
+            }
+        };
+        if mismatched {
+            findings.push(Finding {
+                rule: Rule::VersionStatus,
+                severity: Rule::VersionStatus.severity(),
+                value: value.to_string(),
+                line: declaration.line,
+                message: format!(
+                    "{value:?} is declared, but version {} suggests {:?}",
+                    version.trim(),
+                    expected.as_str()
+                ),
+            });
+        }
+    }
+    findings
+}
+
+/// The classifiers of abandoned ecosystems, and every classifier nested in them
+const LEGACY: &[&str] = &[
+    "Environment :: MacOS X :: Carbon",
+    "Environment :: Web Environment :: Buffet",
+    "Framework :: Django :: 1",
+    "Framework :: Django :: 1.4",
+    "Framework :: Django :: 1.5",
+    "Framework :: Django :: 1.6",
+    "Framework :: Django :: 1.7",
+    "Framework :: Django :: 1.8",
+    "Framework :: Django :: 1.9",
+    "Framework :: Django :: 1.10",
+    "Framework :: Django :: 1.11",
+    "Framework :: Plone :: 3.2",
+    "Framework :: Plone :: 3.3",
+    "Framework :: Pylons",
+    "Framework :: Zope2",
+    "Framework :: Zope :: 2",
+    "Operating System :: BeOS",
+    "Operating System :: Microsoft :: Windows :: Windows 3.1 or Earlier",
+    "Operating System :: Microsoft :: Windows :: Windows 95/98/2000",
+    "Operating System :: Microsoft :: Windows :: Windows CE",
+    "Operating System :: OS/2",
+    "Operating System :: PalmOS",
+    "Programming Language :: Python :: 2",
+    "Programming Language :: Python :: 2.3",
+    "Programming Language :: Python :: 2.4",
+    "Programming Language :: Python :: 2.5",
+    "Programming Language :: Python :: 2.6",
+    "Programming Language :: Python :: 2.7",
+    "Topic :: Desktop Environment :: PicoGUI",
+    "Topic :: Desktop Environment :: Window Managers :: Afterstep",
+    "Topic :: Desktop Environment :: Window Managers :: Blackbox",
+    "Topic :: Desktop Environment :: Window Managers :: CTWM",
+    "Topic :: Desktop Environment :: Window Managers :: MetaCity",
+    "Topic :: Desktop Environment :: Window Managers :: Oroborus",
+    "Topic :: Desktop Environment :: Window Managers :: Sawfish",
+    "Topic :: Desktop Environment :: Window Managers :: Waimea",
+];
+
+/// Check for classifiers of abandoned ecosystems in a project still being developed
+///
+/// A project is still being developed when it declares a `Development Status` from
+/// "1 - Planning" to "5 - Production/Stable". Nothing is reported for a project declaring no
+/// status, or "6 - Mature" or "7 - Inactive", which may well support ecosystems long gone.
+///
+/// # Examples
+///
+#[cfg_attr(not(feature = "all-categories"), doc = "```ignore")]
+#[cfg_attr(feature = "all-categories", doc = "```")]
+/// use trove_classifiers::lint::{lint_legacy, Declared, Rule};
+///
+/// let mut declared = vec![
+///     Declared::new("Development Status :: 4 - Beta"),
+///     Declared::new("Programming Language :: Python :: 2 :: Only").line(2),
+/// ];
+/// let findings = lint_legacy(&declared);
+/// assert_eq!(findings[0].rule, Rule::Legacy);
+/// assert_eq!(findings[0].line, Some(2));
+///
+/// declared[0] = Declared::new("Development Status :: 7 - Inactive");
+/// assert!(lint_legacy(&declared).is_empty());
+/// ```
+pub fn lint_legacy(declared: &[Declared]) -> Vec<Finding> {
+    let status = declared.iter().find_map(|declaration| {
+        let classifier = declaration.value.parse::<Classifier>().ok()?;
+        Some((classifier, stage(classifier)?))
+    });
+    let Some((status, 1..=5)) = status else {
+        return Vec::new();
+    };
+    let mut findings = Vec::new();
+    for declaration in declared {
+        let value = declaration.value.as_str();
+        let Ok(classifier) = value.parse::<Classifier>() else {
+            continue;
+        };
+        let legacy = LEGACY.iter().any(|legacy| {
+            classifier
+                .as_str()
+                .strip_prefix(legacy)
+                .is_some_and(|rest| rest.is_empty() || rest.starts_with(" :: "))
+        });
+        if legacy {
+            findings.push(Finding {
+                rule: Rule::Legacy,
+                severity: Rule::Legacy.severity(),
+                value: value.to_string(),
+                line: declaration.line,
+                message: format!(
+                    "{value:?} is of an abandoned ecosystem, but the project is {:?}; \
+                     consider removing it",
+                    status.as_str()
+                ),
+            });
+        }
+    }
+    findings
+}
+
+/// Check for frameworks whose declared versions are all past their end of life
+///
+/// Versions are grouped into frameworks by `Classifier::family`, and judged by
+/// `Classifier::is_framework_eol`. A framework with a declared version that is still maintained,
+/// or whose end of life is not published, has no findings.
+///
+/// # Examples
+///
+#[cfg_attr(not(feature = "framework"), doc = "```ignore")]
+#[cfg_attr(feature = "framework", doc = "```")]
+/// use trove_classifiers::lint::{lint_framework_eol, Declared, Rule};
+///
+/// let mut declared = vec![Declared::new("Framework :: Django :: 2.2").line(4)];
+/// let findings = lint_framework_eol(&declared);
+/// assert_eq!(findings[0].rule, Rule::FrameworkEol);
+/// assert_eq!(findings[0].line, Some(4));
+///
+/// declared.push(Declared::new("Framework :: Django :: 5.2"));
+/// assert!(lint_framework_eol(&declared).is_empty());
+/// ```
+pub fn lint_framework_eol(declared: &[Declared]) -> Vec<Finding> {
+    let versions: Vec<(&Declared, Classifier)> = declared
+        .iter()
+        .filter_map(|declaration| {
+            let classifier = declaration.value.parse::<Classifier>().ok()?;
+            eol::end_of_life(classifier)?;
+            Some((declaration, classifier))
+        })
+        .collect();
+    let mut findings = Vec::new();
+    for (declaration, classifier) in &versions {
+        let family = classifier.family();
+        let maintained = versions
+            .iter()
+            .any(|(_, other)| other.family() == family && !other.is_framework_eol());
+        if maintained {
+            continue;
+        }
+        let value = declaration.value.as_str();
+        findings.push(Finding {
+            rule: Rule::FrameworkEol,
+            severity: Rule::FrameworkEol.severity(),
+            value: value.to_string(),
+            line: declaration.line,
+            message: format!(
+                "{value:?} reached its end of life on {}, declare a version of {:?} that is \
+                 still maintained",
+                eol::end_of_life(*classifier).unwrap_or_default(),
+                family.as_str()
+            ),
+        });
+    }
+    findings
+}
+
+/// The number of a `Development Status` stage, such as 4 for "4 - Beta"
+fn stage(classifier: Classifier) -> Option<u8> {
+    match classifier.segments_static() {
+        ["Development Status", stage] => stage.split(' ').next()?.parse().ok(),
+        _ => None,
+    }
+}
+
+/// The rule broken by a string that is not a classifier
+fn check_unknown(value: &str, aliases: Option<&Aliases>) -> (Rule, String) {
+    if value
+        .split("::")
+        .next()
+        .is_some_and(|category| category.trim().eq_ignore_ascii_case("Private"))
+    {
+        return (
+            Rule::Private,
+            format!("{value:?} is private, pypi.org will refuse to upload the project"),
+        );
+    }
+    if let Some(deprecated) = deprecation(value.trim()) {
+        let replacements: Vec<String> = deprecated
+            .replacements()
+            .map(|classifier| format!("{:?}", classifier.as_str()))
+            .collect();
+        let message = if replacements.is_empty() {
+            format!("{value:?} is deprecated, remove it")
+        } else {
+            format!(
+                "{value:?} is deprecated, use {} instead",
+                replacements.join(" and ")
+            )
+        };
+        return (Rule::Deprecated, message);
+    }
+    if let Ok(classifier) = lenient(value) {
+        return (
+            Rule::NonCanonical,
+            format!("{value:?} must be written {:?}", classifier.as_str()),
+        );
+    }
+    if let Some(classifier) = aliases.and_then(|aliases| aliases.get(value)) {
+        return (
+            Rule::NonCanonical,
+            format!(
+                "{value:?} is an alias, and must be written {:?}",
+                classifier.as_str()
+            ),
+        );
+    }
+    (
+        Rule::Unknown,
+        format!("{value:?} is not a known classifier"),
+    )
+}
+
+/// Whether none of the findings would make pypi.org reject an upload
+pub fn passes(findings: &[Finding]) -> bool {
+    findings
+        .iter()
+        .all(|finding| finding.severity < Severity::Error)
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[cfg(feature = "all-categories")]
+    fn rules(values: &[&str]) -> Vec<Rule> {
+        let declared: Vec<Declared> = values.iter().map(|value| Declared::new(value)).collect();
+        lint(&declared).iter().map(|finding| finding.rule).collect()
+    }
+
+    #[test]
+    #[cfg(feature = "typing")]
+    fn valid_declarations_pass() {
+        let findings = lint(&[Declared::new("Typing :: Typed")]);
+        assert!(findings.is_empty());
+        assert!(passes(&findings));
+    }
+
+    #[test]
+    #[cfg(feature = "all-categories")]
+    fn reports_each_rule() {
+        assert_eq!(
+            rules(&[
+                "Typing :: Typed",
+                "Typing :: Sometimes",
+                "Natural Language :: Ukranian",
+                "typing::typed",
+                "private :: Do Not Upload",
+                "Typing :: Typed",
+            ]),
+            [
+                Rule::Unknown,
+                Rule::Deprecated,
+                Rule::NonCanonical,
+                Rule::Private,
+                Rule::Duplicate,
+            ]
+        );
+    }
+
+    #[test]
+    #[cfg(feature = "all-categories")]
+    fn messages_explain_the_fix() {
+        let findings = lint(&[
+            Declared::new("Natural Language :: Ukranian").line(3),
+            Declared::new("Topic :: Communications :: Chat :: AOL Instant Messenger"),
+        ]);
+        assert_eq!(
+            findings[0].message,
+            "\"Natural Language :: Ukranian\" is deprecated, use \
+             \"Natural Language :: Ukrainian\" instead"
+        );
+        assert_eq!(findings[0].line, Some(3));
+        assert!(findings[1].message.ends_with("is deprecated, remove it"));
+        assert!(!passes(&findings));
+    }
+
+    #[test]
+    #[cfg(all(feature = "development-status", feature = "framework"))]
+    fn legacy_classifiers_match_whole_segments() {
+        let declared = [
+            Declared::new("Development Status :: 3 - Alpha"),
+            Declared::new("Framework :: Django :: 1.11"),
+            Declared::new("Framework :: Django :: 2.2"),
+            Declared::new("Framework :: Pylons"),
+        ];
+        let values: Vec<String> = lint_legacy(&declared)
+            .into_iter()
+            .map(|finding| finding.value)
+            .collect();
+        assert_eq!(
+            values,
+            ["Framework :: Django :: 1.11", "Framework :: Pylons"]
+        );
+        assert!(lint_legacy(&declared[1..]).is_empty());
+    }
+
+    #[test]
+    fn rules_are_found_by_code_or_name() {
+        for rule in Rule::ALL {
+            assert_eq!(Rule::find(rule.code()), Some(*rule));
+            assert_eq!(Rule::find(rule.name()), Some(*rule));
+        }
+        assert_eq!(Rule::find("tc001"), Some(Rule::Unknown));
+        assert_eq!(Rule::find("TC999"), None);
+    }
+
+    #[test]
+    #[cfg(feature = "all-categories")]
+    fn explanations_show_findings_and_their_fixes() {
+        // The examples of the version rule are declared at version 3.4.1, and those of the
+        // policy rules follow a policy
+        let required = [Classifier::Typing__Typed];
+        let forbidden = ["License :: Other/Proprietary License".to_string()];
+        let linter = Linter::new();
+        for rule in Rule::ALL {
+            let lint = |declared: &[Declared]| {
+                let mut context = Context::new(declared)
+                    .version("3.4.1")
+                    .strictness(Strictness::Strict);
+                if matches!(rule, Rule::Required | Rule::Forbidden) {
+                    context = context.required(&required).forbidden(&forbidden);
+                }
+                linter.check(&context)
+            };
+            let explanation = rule.explanation();
+            let bad: Vec<Declared> = explanation.bad.lines().map(Declared::new).collect();
+            let found: Vec<Rule> = lint(&bad).iter().map(|finding| finding.rule).collect();
+            assert_eq!(found, [*rule]);
+            let good: Vec<Declared> = explanation
+                .good
+                .lines()
+                .filter(|line| !line.starts_with('('))
+                .map(Declared::new)
+                .collect();
+            assert!(lint(&good).is_empty(), "{rule}");
+        }
+    }
+
+    #[test]
+    #[cfg(feature = "all-categories")]
+    fn linters_check_every_rule_by_line() {
+        struct NoTyping;
+
+        impl Lint for NoTyping {
+            fn code(&self) -> &'static str {
+                "ORG001"
+            }
+
+            fn severity(&self) -> Severity {
+                Severity::Error
+            }
+
+            fn check(&self, context: &Context<'_>) -> Vec<Finding> {
+                context
+                    .classifiers()
+                    .filter(|(_, classifier)| classifier.segments_static()[0] == "Typing")
+                    .map(|(declared, _)| Finding {
+                        rule: Rule::Custom(self.code()),
+                        severity: self.severity(),
+                        value: declared.value.clone(),
+                        line: declared.line,
+                        message: "typing is not allowed".to_string(),
+                    })
+                    .collect()
+            }
+        }
+
+        let linter = Linter::new().register(NoTyping);
+        assert_eq!(linter.codes().last(), Some("ORG001"));
+        let declared = [
+            Declared::new("Typing :: Typed").line(1),
+            Declared::new("Development Status :: 2 - Pre-Alpha").line(2),
+            Declared::new("Typing :: Typed").line(3),
+        ];
+        let context = Context::new(&declared).version("3.4.1");
+        let found: Vec<(Rule, Option<usize>)> = linter
+            .check(&context)
+            .iter()
+            .map(|finding| (finding.rule, finding.line))
+            .collect();
+        assert_eq!(
+            found,
+            [
+                (Rule::Custom("ORG001"), Some(1)),
+                (Rule::VersionStatus, Some(2)),
+                (Rule::Duplicate, Some(3)),
+                (Rule::Custom("ORG001"), Some(3)),
+            ]
+        );
+        assert_eq!(Linter::new().check(&context).len(), 2);
+
+        let linter = linter
+            .level(Rule::Custom("ORG001"), Level::Allow)
+            .level(Rule::Duplicate, Level::Warn)
+            .level(Rule::Duplicate, Level::Deny);
+        assert_eq!(linter.level_of(Rule::Duplicate), Some(Level::Deny));
+        assert_eq!(linter.level_of(Rule::Unknown), None);
+        let found: Vec<(Rule, Severity)> = linter
+            .check(&context)
+            .iter()
+            .map(|finding| (finding.rule, finding.severity))
+            .collect();
+        assert_eq!(
+            found,
+            [
+                (Rule::VersionStatus, Severity::Warning),
+                (Rule::Duplicate, Severity::Error),
+            ]
+        );
+        assert_eq!(Rule::Custom("ORG001").to_string(), "ORG001");
+    }
+
+    #[test]
+    #[cfg(feature = "development-status")]
+    fn statuses_are_checked_against_the_version() {
+        let flagged = |version, status: &str, strictness| {
+            let declared = [Declared::new(&format!("Development Status :: {status}")).line(2)];
+            let findings = lint_version(version, &declared, strictness);
+            assert!(findings.iter().all(|finding| finding.line == Some(2)));
+            !findings.is_empty()
+        };
+        use Strictness::{Lenient, Strict};
+        assert!(flagged("3.4.1", "2 - Pre-Alpha", Lenient));
+        assert!(flagged("0.0.1", "5 - Production/Stable", Lenient));
+        assert!(!flagged("0.4", "5 - Production/Stable", Lenient));
+        assert!(!flagged("2.0b1", "3 - Alpha", Lenient));
+        assert!(flagged("0.4", "5 - Production/Stable", Strict));
+        assert!(flagged("2.0b1", "3 - Alpha", Strict));
+        assert!(!flagged("2.0", "6 - Mature", Strict));
+        assert!(!flagged("0.0.1", "1 - Planning", Strict));
+        assert!(!flagged("0.0.1", "7 - Inactive", Strict));
+        assert!(!flagged("unreleased", "1 - Planning", Strict));
+
+        let findings = lint_version(
+            " 3.4.1 ",
+            &[Declared::new("Development Status :: 2 - Pre-Alpha")],
+            Lenient,
+        );
+        assert_eq!(
+            findings[0].message,
+            "\"Development Status :: 2 - Pre-Alpha\" is declared, but version 3.4.1 suggests \
+             \"Development Status :: 5 - Production/Stable\""
+        );
+    }
+}
@@ -0,0 +1,381 @@
+//! CI matrices driven by a project's declared classifiers.
+//!
+//! A project declaring the Python versions it supports as classifiers can run its tests on the
+//! same versions without listing them again in its workflows. `github_actions` renders the `X.Y`
+//! versions as the JSON array a GitHub Actions matrix reads with `fromJSON`, such as
+//! `python-version: ${{ fromJSON(needs.metadata.outputs.python) }}`. `tox_envlist` and
+//! `nox_parameters` do the same for tox and nox, optionally crossed with the declared Django
+//! versions, and `tox_drift` tells how the `envlist` of an existing `tox.ini` has drifted from
+//! the classifiers.
+//!
+//! # Examples
+//!
+#![cfg_attr(not(feature = "programming-language"), doc = "```ignore")]
+#![cfg_attr(feature = "programming-language", doc = "```")]
+//! use trove_classifiers::{matrix, Classifier};
+//!
+//! let declared = [
+//!     Classifier::ProgrammingLanguage__Python__3,
+//!     Classifier::ProgrammingLanguage__Python__3_10,
+//!     Classifier::ProgrammingLanguage__Python__3_9,
+//! ];
+//! assert_eq!(matrix::python_versions(&declared), ["3.9", "3.10"]);
+//! assert_eq!(matrix::github_actions(&declared), r#"["3.9","3.10"]"#);
+//! ```
+
+use alloc::format;
+use alloc::string::{String, ToString};
+use alloc::vec::Vec;
+
+use crate::json::push_string;
+use crate::pep440::Release;
+use crate::summary::Summary;
+use crate::Classifier;
+
+/// The `X.Y` Python versions of the classifiers, oldest first
+pub fn python_versions(classifiers: &[Classifier]) -> Vec<&'static str> {
+    Summary::new(classifiers).python_versions
+}
+
+/// The `X.Y` Python versions of the classifiers as a JSON array of strings, oldest first
+///
+/// The array is empty when no `X.Y` version is declared.
+pub fn github_actions(classifiers: &[Classifier]) -> String {
+    let mut out = String::from("[");
+    for (index, version) in python_versions(classifiers).into_iter().enumerate() {
+        if index > 0 {
+            out.push(',');
+        }
+        push_string(&mut out, version);
+    }
+    out.push(']');
+    out
+}
+
+/// The `X.Y` Django versions of the classifiers, oldest first
+pub fn django_versions(classifiers: &[Classifier]) -> Vec<&'static str> {
+    let mut versions: Vec<(Release, &'static str)> = classifiers
+        .iter()
+        .filter_map(|classifier| match classifier.segments_static() {
+            ["Framework", "Django", version] => Release::parse(version)
+                .filter(|release| release.parts().len() == 2)
+                .map(|release| (release, *version)),
+            _ => None,
+        })
+        .collect();
+    versions.sort_by(|(a, _), (b, _)| a.parts().cmp(b.parts()));
+    versions.dedup_by_key(|(_, version)| *version);
+    versions.into_iter().map(|(_, version)| version).collect()
+}
+
+/// The tox environments of the `X.Y` Python versions of the classifiers, such as `py39`, oldest
+/// first
+///
+/// With `django`, each Python version is crossed with each declared Django version, as in
+/// `py39-django42`.
+///
+/// # Examples
+///
+#[cfg_attr(not(feature = "all-categories"), doc = "```ignore")]
+#[cfg_attr(feature = "all-categories", doc = "```")]
+/// use trove_classifiers::{matrix, Classifier};
+///
+/// let declared = [
+///     Classifier::ProgrammingLanguage__Python__3_12,
+///     Classifier::Framework__Django__5_1,
+///     Classifier::Framework__Django__4_2,
+/// ];
+/// assert_eq!(matrix::tox_envlist(&declared, false), ["py312"]);
+/// assert_eq!(
+///     matrix::tox_envlist(&declared, true),
+///     ["py312-django42", "py312-django51"]
+/// );
+/// ```
+pub fn tox_envlist(classifiers: &[Classifier], django: bool) -> Vec<String> {
+    let djangos = if django {
+        django_versions(classifiers)
+    } else {
+        Vec::new()
+    };
+    let mut envlist = Vec::new();
+    for python in python_versions(classifiers) {
+        let python = factor("py", python);
+        if djangos.is_empty() {
+            envlist.push(python);
+            continue;
+        }
+        for version in &djangos {
+            envlist.push(format!("{python}-{}", factor("django", version)));
+        }
+    }
+    envlist
+}
+
+/// The decorators of a nox session parametrized by the `X.Y` Python versions of the
+/// classifiers and, with `django`, by the declared Django versions
+///
+/// # Examples
+///
+#[cfg_attr(not(feature = "all-categories"), doc = "```ignore")]
+#[cfg_attr(feature = "all-categories", doc = "```")]
+/// use trove_classifiers::{matrix, Classifier};
+///
+/// let declared = [
+///     Classifier::ProgrammingLanguage__Python__3_12,
+///     Classifier::Framework__Django__5_1,
+/// ];
+/// assert_eq!(
+///     matrix::nox_parameters(&declared, true),
+///     "@nox.session(python=[\"3.12\"])\n@nox.parametrize(\"django\", [\"5.1\"])\n"
+/// );
+/// ```
+pub fn nox_parameters(classifiers: &[Classifier], django: bool) -> String {
+    let list = |versions: Vec<&str>| {
+        let versions: Vec<String> = versions
+            .into_iter()
+            .map(|version| format!("\"{version}\""))
+            .collect();
+        format!("[{}]", versions.join(", "))
+    };
+    let mut out = format!(
+        "@nox.session(python={})\n",
+        list(python_versions(classifiers))
+    );
+    if django {
+        out.push_str(&format!(
+            "@nox.parametrize(\"django\", {})\n",
+            list(django_versions(classifiers))
+        ));
+    }
+    out
+}
+
+/// The environments of the `envlist` (or `env_list`) of a `tox.ini`'s `[tox]` section, with
+/// their braces expanded
+///
+/// `py3{9,10}-django{42,50}` is expanded to four environments, in the order tox runs them.
+pub fn envlist(tox_ini: &str) -> Vec<String> {
+    let mut value = String::new();
+    let mut in_tox = false;
+    let mut in_envlist = false;
+    for line in tox_ini.lines() {
+        let trimmed = line.trim();
+        if trimmed.is_empty() || trimmed.starts_with(['#', ';']) {
+            continue;
+        }
+        let indented = line.starts_with([' ', '\t']);
+        if indented && in_envlist {
+            value.push(',');
+            value.push_str(trimmed);
+            continue;
+        }
+        in_envlist = false;
+        if let Some(section) = trimmed.strip_prefix('[') {
+            in_tox = section.trim_end_matches(']').trim() == "tox";
+            continue;
+        }
+        let Some((key, rest)) = trimmed.split_once('=') else {
+            continue;
+        };
+        if in_tox && !indented && matches!(key.trim(), "envlist" | "env_list") {
+            in_envlist = true;
+            value.push(',');
+            value.push_str(rest.trim());
+        }
+    }
+    let mut environments = Vec::new();
+    for environment in split_outside_braces(&value) {
+        expand(environment.trim(), &mut environments);
+    }
+    environments
+}
+
+/// How a `tox.ini`'s environments differ from the declared classifiers
+#[derive(Clone, Debug, Default, Eq, PartialEq)]
+pub struct Drift {
+    /// The factors of the declared versions that no environment tests, such as `py313`
+    pub missing: Vec<String>,
+    /// The factors of the environments whose versions are not declared, such as `py38`
+    pub extra: Vec<String>,
+}
+
+impl Drift {
+    /// Whether the environments test exactly the declared versions
+    pub fn is_empty(&self) -> bool {
+        self.missing.is_empty() && self.extra.is_empty()
+    }
+}
+
+/// The drift between the `envlist` of a `tox.ini` and the `X.Y` Python versions of the
+/// classifiers, and their Django versions if any environment has a `django` factor
+///
+/// Environments without a version factor, such as `lint`, are ignored.
+///
+/// # Examples
+///
+#[cfg_attr(not(feature = "programming-language"), doc = "```ignore")]
+#[cfg_attr(feature = "programming-language", doc = "```")]
+/// use trove_classifiers::{matrix, Classifier};
+///
+/// let declared = [
+///     Classifier::ProgrammingLanguage__Python__3_12,
+///     Classifier::ProgrammingLanguage__Python__3_13,
+/// ];
+/// let drift = matrix::tox_drift("[tox]\nenvlist = py3{11,12}, lint\n", &declared);
+/// assert_eq!(drift.missing, ["py313"]);
+/// assert_eq!(drift.extra, ["py311"]);
+/// ```
+pub fn tox_drift(tox_ini: &str, classifiers: &[Classifier]) -> Drift {
+    let mut factors: Vec<String> = Vec::new();
+    for environment in envlist(tox_ini) {
+        for part in environment.split('-') {
+            let part = version_factor(part);
+            if !factors.contains(&part) {
+                factors.push(part);
+            }
+        }
+    }
+    let mut declared: Vec<String> = python_versions(classifiers)
+        .into_iter()
+        .map(|version| factor("py", version))
+        .collect();
+    let tests_django = factors.iter().any(|part| is_version_factor("django", part));
+    if tests_django {
+        declared.extend(
+            django_versions(classifiers)
+                .into_iter()
+                .map(|version| factor("django", version)),
+        );
+    }
+    let versioned = |part: &String| {
+        is_version_factor("py", part) || (tests_django && is_version_factor("django", part))
+    };
+    Drift {
+        missing: declared
+            .iter()
+            .filter(|part| !factors.contains(part))
+            .cloned()
+            .collect(),
+        extra: factors
+            .iter()
+            .filter(|part| versioned(part) && !declared.contains(part))
+            .cloned()
+            .collect(),
+    }
+}
+
+/// The tox factor of a version, such as `py310` for `3.10`
+fn factor(name: &str, version: &str) -> String {
+    format!("{name}{}", version.replace('.', ""))
+}
+
+/// A factor with the dots of its version removed, as tox 4 accepts `py3.10` for `py310`
+fn version_factor(part: &str) -> String {
+    let name_len = part
+        .find(|c: char| c.is_ascii_digit())
+        .unwrap_or(part.len());
+    let (name, version) = part.split_at(name_len);
+    if version
+        .bytes()
+        .all(|byte| byte.is_ascii_digit() || byte == b'.')
+    {
+        factor(name, version)
+    } else {
+        part.to_string()
+    }
+}
+
+/// Whether a factor is `name` followed by a version of at least two digits, such as `py39`
+fn is_version_factor(name: &str, part: &str) -> bool {
+    part.strip_prefix(name)
+        .is_some_and(|version| version.len() >= 2 && version.bytes().all(|b| b.is_ascii_digit()))
+}
+
+/// The comma separated items of `value` that are not within braces
+fn split_outside_braces(value: &str) -> Vec<&str> {
+    let mut items = Vec::new();
+    let (mut depth, mut start) = (0usize, 0);
+    for (index, c) in value.char_indices() {
+        match c {
+            '{' => depth += 1,
+            '}' => depth = depth.saturating_sub(1),
+            ',' if depth == 0 => {
+                items.push(&value[start..index]);
+                start = index + 1;
+            }
+            _ => {}
+        }
+    }
+    items.push(&value[start..]);
+    items.retain(|item| !item.trim().is_empty());
+    items
+}
+
+/// Push every environment a tox environment name with braces stands for
+fn expand(name: &str, out: &mut Vec<String>) {
+    let Some(open) = name.find('{') else {
+        out.push(name.to_string());
+        return;
+    };
+    let Some(close) = name[open..].find('}').map(|close| open + close) else {
+        out.push(name.to_string());
+        return;
+    };
+    for alternative in name[open + 1..close].split(',') {
+        let expanded = format!(
+            "{}{}{}",
+            &name[..open],
+            alternative.trim(),
+            &name[close + 1..]
+        );
+        expand(&expanded, out);
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn empty_declarations_give_an_empty_array() {
+        assert_eq!(github_actions(&[]), "[]");
+    }
+
+    #[test]
+    fn envlists_are_expanded() {
+        let tox_ini = "\
+[testenv]
+envlist = ignored
+[tox]
+min_version = 4
+env_list =
+    py3{9,10}-django{42,50}
+    lint, type
+";
+        assert_eq!(
+            envlist(tox_ini),
+            [
+                "py39-django42",
+                "py39-django50",
+                "py310-django42",
+                "py310-django50",
+                "lint",
+                "type"
+            ]
+        );
+    }
+
+    #[test]
+    #[cfg(all(feature = "framework", feature = "programming-language"))]
+    fn django_factors_drift_only_when_tested() {
+        let declared = [
+            Classifier::ProgrammingLanguage__Python__3_10,
+            Classifier::Framework__Django__4_2,
+            Classifier::Framework__Django__5_1,
+        ];
+        assert!(tox_drift("[tox]\nenvlist = py3.10\n", &declared).is_empty());
+        let drift = tox_drift("[tox]\nenvlist = py310-django{42,50}\n", &declared);
+        assert_eq!(drift.missing, ["django51"]);
+        assert_eq!(drift.extra, ["django50"]);
+    }
+}
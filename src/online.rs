@@ -0,0 +1,615 @@
+//! Fetching the classifiers pypi.org accepts right now.
+//!
+//! This crate's dataset is a snapshot of pypa/trove-classifiers, which pypi.org may have moved
+//! past. A `Client` downloads the live list so tools can tell how stale the snapshot is. The
+//! `online` feature provides the blocking `Client`, and the `online-async` feature the
+//! `AsyncClient`, which has the same methods as async functions, for services running on tokio.
+//!
+//! Either client can keep what it fetched in a `Cache` on disk. An answer younger than the
+//! cache's time to live is used without asking the index; an older one is revalidated with its
+//! `ETag` and `Last-Modified` headers, so an unchanged list is not downloaded again; and when the
+//! index cannot be reached or fails with a server error, the cached answer is used however old it
+//! is, so tools keep working offline once the cache is warm.
+//!
+//! # Examples
+//!
+//! ```no_run
+//! # #[cfg(feature = "online")]
+//! # fn stale() -> Result<(), trove_classifiers::online::Error> {
+//! use trove_classifiers::{history, online::Client};
+//!
+//! let live = Client::new().classifiers()?;
+//! let current = history::snapshot(trove_classifiers::PYPA_VERSION).unwrap();
+//! let diff = history::diff(current.classifiers(), live.iter().map(String::as_str));
+//! println!("{} classifiers are missing from this build", diff.added.len());
+//! # Ok(())
+//! # }
+//! ```
+//!
+//! With the `online-async` feature, from within a tokio runtime:
+//!
+//! ```no_run
+//! # #[cfg(feature = "online-async")]
+//! # async fn stale() -> Result<(), trove_classifiers::online::Error> {
+//! use trove_classifiers::online::AsyncClient;
+//!
+//! let live = AsyncClient::new().classifiers().await?;
+//! println!("pypi.org accepts {} classifiers", live.len());
+//! # Ok(())
+//! # }
+//! ```
+
+use std::fmt;
+use std::fs;
+use std::io;
+use std::path::{Path, PathBuf};
+use std::time::{Duration, SystemTime, UNIX_EPOCH};
+
+use crate::integrity::stable_id;
+
+/// The index queried by default
+pub const PYPI_URL: &str = "https://pypi.org";
+
+/// How long a request may take before it is abandoned
+const TIMEOUT: Duration = Duration::from_secs(30);
+
+/// The error returned when the live classifiers cannot be fetched
+#[derive(Debug)]
+#[non_exhaustive]
+pub enum Error {
+    /// The index answered with an HTTP error status
+    Status(u16),
+    /// The index could not be reached or its answer could not be read
+    #[cfg(feature = "online")]
+    Transport(ureq::Error),
+    /// The index could not be reached or its answer could not be read by an `AsyncClient`
+    #[cfg(feature = "online-async")]
+    AsyncTransport(reqwest::Error),
+}
+
+impl fmt::Display for Error {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        match self {
+            Error::Status(status) => write!(f, "the index answered with HTTP status {status}"),
+            #[cfg(feature = "online")]
+            Error::Transport(err) => write!(f, "could not fetch from the index: {err}"),
+            #[cfg(feature = "online-async")]
+            Error::AsyncTransport(err) => write!(f, "could not fetch from the index: {err}"),
+        }
+    }
+}
+
+impl std::error::Error for Error {
+    fn source(&self) -> Option<&(dyn std::error::Error + 'static)> {
+        match self {
+            Error::Status(_) => None,
+            #[cfg(feature = "online")]
+            Error::Transport(err) => Some(err),
+            #[cfg(feature = "online-async")]
+            Error::AsyncTransport(err) => Some(err),
+        }
+    }
+}
+
+#[cfg(feature = "online")]
+impl From<ureq::Error> for Error {
+    fn from(err: ureq::Error) -> Self {
+        match err {
+            ureq::Error::StatusCode(status) => Error::Status(status),
+            err => Error::Transport(err),
+        }
+    }
+}
+
+#[cfg(feature = "online-async")]
+impl From<reqwest::Error> for Error {
+    fn from(err: reqwest::Error) -> Self {
+        match err.status() {
+            Some(status) => Error::Status(status.as_u16()),
+            None => Error::AsyncTransport(err),
+        }
+    }
+}
+
+/// An on-disk cache of the index's answers, shared by every client and process given its
+/// directory
+///
+/// Each URL is stored in its own file, named after a hash of the URL, holding the time it was
+/// fetched, its validators and its body. Failing to write the cache never fails a request.
+#[derive(Clone, Debug, Eq, PartialEq)]
+pub struct Cache {
+    dir: PathBuf,
+    ttl: Duration,
+}
+
+/// A cached answer
+#[derive(Debug, Default, Eq, PartialEq)]
+struct Entry {
+    /// When the answer was fetched or last revalidated, in seconds since the Unix epoch
+    fetched: u64,
+    etag: Option<String>,
+    last_modified: Option<String>,
+    body: String,
+}
+
+impl Cache {
+    /// How long an answer is used without revalidating it, unless `ttl` says otherwise
+    pub const DEFAULT_TTL: Duration = Duration::from_secs(24 * 60 * 60);
+
+    /// A cache in `dir`, which is created when first written
+    pub fn new(dir: impl Into<PathBuf>) -> Self {
+        Cache {
+            dir: dir.into(),
+            ttl: Self::DEFAULT_TTL,
+        }
+    }
+
+    /// A cache in the `trove-classifiers` directory of the user's cache directory:
+    /// `$XDG_CACHE_HOME`, `~/.cache`, or `%LOCALAPPDATA%` on Windows
+    pub fn user() -> Option<Self> {
+        let non_empty = |name| std::env::var_os(name).filter(|value| !value.is_empty());
+        let root = non_empty("XDG_CACHE_HOME")
+            .map(PathBuf::from)
+            .or_else(|| non_empty("HOME").map(|home| Path::new(&home).join(".cache")))
+            .or_else(|| non_empty("LOCALAPPDATA").map(PathBuf::from))?;
+        Some(Cache::new(root.join("trove-classifiers")))
+    }
+
+    /// Use cached answers without revalidating them for `ttl`; zero revalidates every time
+    pub fn ttl(mut self, ttl: Duration) -> Self {
+        self.ttl = ttl;
+        self
+    }
+
+    /// The directory the answers are stored in
+    pub fn dir(&self) -> &Path {
+        &self.dir
+    }
+
+    /// Remove every cached answer
+    pub fn clear(&self) -> io::Result<()> {
+        match fs::remove_dir_all(&self.dir) {
+            Err(err) if err.kind() == io::ErrorKind::NotFound => Ok(()),
+            result => result,
+        }
+    }
+
+    fn path(&self, url: &str) -> PathBuf {
+        // FNV-1a, so that the file of a URL is the same in every process
+        self.dir.join(format!("{:016x}", stable_id(url)))
+    }
+
+    fn read(&self, url: &str) -> Option<Entry> {
+        let contents = fs::read_to_string(self.path(url)).ok()?;
+        let (head, body) = contents.split_once("\n\n")?;
+        let mut lines = head.lines();
+        if lines.next() != Some(url) {
+            return None;
+        }
+        let mut entry = Entry {
+            body: body.to_string(),
+            ..Entry::default()
+        };
+        for line in lines {
+            match line.split_once(": ") {
+                Some(("fetched", fetched)) => entry.fetched = fetched.parse().ok()?,
+                Some(("etag", etag)) => entry.etag = Some(etag.to_string()),
+                Some(("last-modified", date)) => entry.last_modified = Some(date.to_string()),
+                _ => {}
+            }
+        }
+        Some(entry)
+    }
+
+    /// Store an answer, ignoring failures: a cache that cannot be written is only slower
+    fn write(&self, url: &str, entry: &Entry) {
+        let mut contents = format!("{url}\nfetched: {}\n", entry.fetched);
+        for (name, value) in [
+            ("etag", &entry.etag),
+            ("last-modified", &entry.last_modified),
+        ] {
+            if let Some(value) = value {
+                contents.push_str(&format!("{name}: {value}\n"));
+            }
+        }
+        contents.push('\n');
+        contents.push_str(&entry.body);
+        let path = self.path(url);
+        // written aside then renamed, so concurrent readers never see half an answer
+        let partial = path.with_extension(format!("{}.partial", std::process::id()));
+        let _ = fs::create_dir_all(&self.dir)
+            .and_then(|()| fs::write(&partial, contents))
+            .and_then(|()| fs::rename(&partial, &path));
+    }
+
+    fn is_fresh(&self, entry: &Entry) -> bool {
+        now().saturating_sub(entry.fetched) < self.ttl.as_secs()
+    }
+}
+
+/// The seconds since the Unix epoch
+fn now() -> u64 {
+    SystemTime::now()
+        .duration_since(UNIX_EPOCH)
+        .map_or(0, |elapsed| elapsed.as_secs())
+}
+
+/// The answer of a request revalidating a cached one
+enum Revalidated {
+    /// The cached answer is still current
+    NotModified,
+    /// A new answer, with its validators
+    Modified {
+        etag: Option<String>,
+        last_modified: Option<String>,
+        body: String,
+    },
+}
+
+/// The cached answer for `url` if it is fresh, or else the stale entry to revalidate, if any
+fn lookup(cache: Option<&Cache>, url: &str) -> Result<String, Option<Entry>> {
+    let Some(entry) = cache.and_then(|cache| cache.read(url)) else {
+        return Err(None);
+    };
+    match cache.is_some_and(|cache| cache.is_fresh(&entry)) {
+        true => Ok(entry.body),
+        false => Err(Some(entry)),
+    }
+}
+
+/// The body to use given the index's answer to a request for `url`, conditional on `entry`,
+/// updating the cache with it
+fn settle(
+    cache: Option<&Cache>,
+    url: &str,
+    entry: Option<Entry>,
+    answer: Result<Revalidated, Error>,
+) -> Result<String, Error> {
+    let answer = match answer {
+        Ok(Revalidated::Modified {
+            etag,
+            last_modified,
+            body,
+        }) => Entry {
+            fetched: now(),
+            etag,
+            last_modified,
+            body,
+        },
+        Ok(Revalidated::NotModified) => match entry {
+            Some(entry) => Entry {
+                fetched: now(),
+                ..entry
+            },
+            None => return Err(Error::Status(304)),
+        },
+        // offline, or the index is failing: a stale answer beats none
+        Err(err) if !matches!(err, Error::Status(400..=499)) => {
+            return entry.map(|entry| entry.body).ok_or(err)
+        }
+        // the index rejected the request itself, which a cached answer would hide
+        Err(err) => return Err(err),
+    };
+    if let Some(cache) = cache {
+        cache.write(url, &answer);
+    }
+    Ok(answer.body)
+}
+
+const USER_AGENT: &str = concat!("trove-classifiers/", env!("CARGO_PKG_VERSION"));
+
+/// A blocking client of a Python package index
+#[cfg(feature = "online")]
+#[derive(Debug)]
+pub struct Client {
+    agent: ureq::Agent,
+    index: String,
+    cache: Option<Cache>,
+}
+
+#[cfg(feature = "online")]
+impl Default for Client {
+    fn default() -> Self {
+        let agent = ureq::Agent::config_builder()
+            .timeout_global(Some(TIMEOUT))
+            .user_agent(USER_AGENT)
+            .build()
+            .into();
+        Client {
+            agent,
+            index: PYPI_URL.to_string(),
+            cache: None,
+        }
+    }
+}
+
+#[cfg(feature = "online")]
+impl Client {
+    /// A client of pypi.org
+    pub fn new() -> Self {
+        Self::default()
+    }
+
+    /// Query another index implementing the pypi.org API, such as a mirror
+    pub fn index_url(mut self, url: &str) -> Self {
+        self.index = url.trim_end_matches('/').to_string();
+        self
+    }
+
+    /// Keep the index's answers in `cache`, see `Cache`
+    pub fn cache(mut self, cache: Cache) -> Self {
+        self.cache = Some(cache);
+        self
+    }
+
+    /// The URL listing the classifiers the index accepts
+    pub fn classifiers_url(&self) -> String {
+        classifiers_url(&self.index)
+    }
+
+    /// Every classifier string the index accepts, in the order it lists them
+    pub fn classifiers(&self) -> Result<Vec<String>, Error> {
+        let url = self.classifiers_url();
+        let body = match lookup(self.cache.as_ref(), &url) {
+            Ok(body) => body,
+            Err(entry) => {
+                let answer = self.get(&url, entry.as_ref());
+                settle(self.cache.as_ref(), &url, entry, answer)?
+            }
+        };
+        Ok(parse_classifiers(&body))
+    }
+
+    /// Request `url`, conditionally on the validators of `entry`
+    fn get(&self, url: &str, entry: Option<&Entry>) -> Result<Revalidated, Error> {
+        let mut request = self.agent.get(url);
+        for (name, value) in validators(entry) {
+            request = request.header(name, value);
+        }
+        let mut response = request.call()?;
+        if response.status() == 304 {
+            return Ok(Revalidated::NotModified);
+        }
+        let header = |name| {
+            let value = response.headers().get(name)?.to_str().ok()?;
+            Some(value.to_string())
+        };
+        Ok(Revalidated::Modified {
+            etag: header("etag"),
+            last_modified: header("last-modified"),
+            body: response.body_mut().read_to_string()?,
+        })
+    }
+}
+
+/// A client of a Python package index whose requests are futures, for async runtimes
+///
+/// Requests run on the tokio runtime they are awaited in, without blocking its threads. Clones
+/// share their connection pool.
+#[cfg(feature = "online-async")]
+#[derive(Clone, Debug)]
+pub struct AsyncClient {
+    client: reqwest::Client,
+    index: String,
+    cache: Option<Cache>,
+}
+
+#[cfg(feature = "online-async")]
+impl Default for AsyncClient {
+    fn default() -> Self {
+        let client = reqwest::Client::builder()
+            .timeout(TIMEOUT)
+            .user_agent(USER_AGENT)
+            .build()
+            .expect("the TLS backend is compiled in");
+        AsyncClient {
+            client,
+            index: PYPI_URL.to_string(),
+            cache: None,
+        }
+    }
+}
+
+#[cfg(feature = "online-async")]
+impl AsyncClient {
+    /// A client of pypi.org
+    pub fn new() -> Self {
+        Self::default()
+    }
+
+    /// Query another index implementing the pypi.org API, such as a mirror
+    pub fn index_url(mut self, url: &str) -> Self {
+        self.index = url.trim_end_matches('/').to_string();
+        self
+    }
+
+    /// Keep the index's answers in `cache`, see `Cache`
+    pub fn cache(mut self, cache: Cache) -> Self {
+        self.cache = Some(cache);
+        self
+    }
+
+    /// The URL listing the classifiers the index accepts
+    pub fn classifiers_url(&self) -> String {
+        classifiers_url(&self.index)
+    }
+
+    /// Every classifier string the index accepts, in the order it lists them
+    ///
+    /// The cache, if any, is read and written with blocking file operations, which are brief.
+    pub async fn classifiers(&self) -> Result<Vec<String>, Error> {
+        let url = self.classifiers_url();
+        let body = match lookup(self.cache.as_ref(), &url) {
+            Ok(body) => body,
+            Err(entry) => {
+                let answer = self.get(&url, entry.as_ref()).await;
+                settle(self.cache.as_ref(), &url, entry, answer)?
+            }
+        };
+        Ok(parse_classifiers(&body))
+    }
+
+    /// Request `url`, conditionally on the validators of `entry`
+    async fn get(&self, url: &str, entry: Option<&Entry>) -> Result<Revalidated, Error> {
+        let mut request = self.client.get(url);
+        for (name, value) in validators(entry) {
+            request = request.header(name, value);
+        }
+        let response = request.send().await?;
+        if response.status() == reqwest::StatusCode::NOT_MODIFIED {
+            return Ok(Revalidated::NotModified);
+        }
+        let response = response.error_for_status()?;
+        let header = |name| {
+            let value = response.headers().get(name)?.to_str().ok()?;
+            Some(value.to_string())
+        };
+        Ok(Revalidated::Modified {
+            etag: header("etag"),
+            last_modified: header("last-modified"),
+            body: response.text().await?,
+        })
+    }
+}
+
+/// The headers making a request conditional on the validators of a cached answer
+fn validators(entry: Option<&Entry>) -> impl Iterator<Item = (&'static str, &str)> {
+    entry.into_iter().flat_map(|entry| {
+        [
+            ("If-None-Match", entry.etag.as_deref()),
+            ("If-Modified-Since", entry.last_modified.as_deref()),
+        ]
+        .into_iter()
+        .filter_map(|(name, value)| Some((name, value?)))
+    })
+}
+
+fn classifiers_url(index: &str) -> String {
+    format!("{index}/pypi?%3Aaction=list_classifiers")
+}
+
+/// The classifiers of a `list_classifiers` response, one per line
+fn parse_classifiers(body: &str) -> Vec<String> {
+    body.lines()
+        .map(str::trim)
+        .filter(|line| !line.is_empty())
+        .map(str::to_string)
+        .collect()
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    #[cfg(feature = "online")]
+    fn index_url_is_normalized() {
+        let client = Client::new().index_url("https://mirror.example/");
+        assert_eq!(
+            client.classifiers_url(),
+            "https://mirror.example/pypi?%3Aaction=list_classifiers"
+        );
+    }
+
+    #[test]
+    #[cfg(feature = "online-async")]
+    fn async_clients_query_the_same_url() {
+        let client = AsyncClient::new().index_url("https://mirror.example//");
+        assert_eq!(
+            client.classifiers_url(),
+            "https://mirror.example/pypi?%3Aaction=list_classifiers"
+        );
+    }
+
+    fn cache(name: &str) -> Cache {
+        let dir = std::env::temp_dir().join(format!("trove-cache-{}-{name}", std::process::id()));
+        let cache = Cache::new(dir);
+        cache.clear().unwrap();
+        cache
+    }
+
+    fn modified(etag: &str, body: &str) -> Result<Revalidated, Error> {
+        Ok(Revalidated::Modified {
+            etag: Some(etag.to_string()),
+            last_modified: None,
+            body: body.to_string(),
+        })
+    }
+
+    #[test]
+    fn fresh_answers_are_served_from_the_cache() {
+        let cache = cache("fresh");
+        let url = classifiers_url(PYPI_URL);
+        assert_eq!(lookup(Some(&cache), &url), Err(None));
+        let body = settle(
+            Some(&cache),
+            &url,
+            None,
+            modified("\"v1\"", "Typing :: Typed\n"),
+        );
+        assert_eq!(body.unwrap(), "Typing :: Typed\n");
+        assert_eq!(
+            lookup(Some(&cache), &url),
+            Ok("Typing :: Typed\n".to_string())
+        );
+        assert_eq!(lookup(None, &url), Err(None));
+        assert_eq!(
+            lookup(Some(&cache), &classifiers_url("https://mirror.example")),
+            Err(None)
+        );
+        cache.clear().unwrap();
+    }
+
+    #[test]
+    fn stale_answers_are_revalidated() {
+        let cache = cache("stale").ttl(Duration::ZERO);
+        let url = classifiers_url(PYPI_URL);
+        settle(
+            Some(&cache),
+            &url,
+            None,
+            modified("\"v1\"", "Typing :: Typed\n"),
+        )
+        .unwrap();
+        let stale = lookup(Some(&cache), &url).unwrap_err().unwrap();
+        assert!(validators(Some(&stale)).eq([("If-None-Match", "\"v1\"")]));
+
+        let body = settle(
+            Some(&cache),
+            &url,
+            Some(stale),
+            Ok(Revalidated::NotModified),
+        );
+        assert_eq!(body.unwrap(), "Typing :: Typed\n");
+        let stale = lookup(Some(&cache), &url).unwrap_err();
+        let body = settle(Some(&cache), &url, stale, Err(Error::Status(503)));
+        assert_eq!(body.unwrap(), "Typing :: Typed\n");
+        let stale = lookup(Some(&cache), &url).unwrap_err();
+        let body = settle(
+            Some(&cache),
+            &url,
+            stale,
+            modified("\"v2\"", "Typing :: Stubs Only\n"),
+        );
+        assert_eq!(body.unwrap(), "Typing :: Stubs Only\n");
+        let stale = lookup(Some(&cache), &url).unwrap_err();
+        assert!(matches!(
+            settle(Some(&cache), &url, stale, Err(Error::Status(404))),
+            Err(Error::Status(404))
+        ));
+
+        cache.clear().unwrap();
+        assert!(matches!(
+            settle(Some(&cache), &url, None, Err(Error::Status(503))),
+            Err(Error::Status(503))
+        ));
+    }
+
+    #[test]
+    fn parses_one_classifier_per_line() {
+        assert_eq!(
+            parse_classifiers("Typing :: Stubs Only\r\nTyping :: Typed\n\n"),
+            ["Typing :: Stubs Only", "Typing :: Typed"]
+        );
+    }
+}
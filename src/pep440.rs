@@ -0,0 +1,210 @@
+//! Just enough of PEP 440 to tell which release series a version specifier admits, and how far
+//! along a version is.
+
+use core::cmp::Ordering;
+
+/// The numeric release segment of a version, such as `3.12.1`
+#[derive(Clone, Copy, Debug, Eq, PartialEq)]
+pub(crate) struct Release {
+    parts: [u32; 4],
+    len: usize,
+}
+
+impl Release {
+    /// The release segment at the start of `version`, ignoring any pre, post or dev suffix
+    pub(crate) fn parse(version: &str) -> Option<Self> {
+        let version = version.trim().trim_start_matches(['v', 'V']);
+        let mut release = Release {
+            parts: [0; 4],
+            len: 0,
+        };
+        for part in version.split('.') {
+            let digits = part.bytes().take_while(u8::is_ascii_digit).count();
+            if digits == 0 || release.len == release.parts.len() {
+                break;
+            }
+            release.parts[release.len] = part[..digits].parse().ok()?;
+            release.len += 1;
+            if digits < part.len() {
+                break;
+            }
+        }
+        (release.len > 0).then_some(release)
+    }
+
+    pub(crate) fn parts(&self) -> &[u32] {
+        &self.parts[..self.len]
+    }
+
+    /// Compare with `other`, padding the shorter of the two with zeros
+    fn cmp_padded(&self, other: &Release) -> Ordering {
+        self.parts.cmp(&other.parts)
+    }
+
+    /// Compare with the first `self.len` parts of `other`
+    fn cmp_prefix(&self, other: &Release) -> Ordering {
+        self.parts().cmp(&other.parts[..self.len])
+    }
+
+    fn starts_with(&self, prefix: &Release) -> bool {
+        prefix.len <= self.len && self.parts[..prefix.len] == *prefix.parts()
+    }
+}
+
+/// How far along the release it names a version is
+#[derive(Clone, Copy, Debug, Eq, Ord, PartialEq, PartialOrd)]
+pub(crate) enum Phase {
+    /// A development release, such as `1.0.dev1`
+    Dev,
+    /// An alpha release, such as `1.0a1`
+    Alpha,
+    /// A beta release or a release candidate, such as `1.0b2` or `1.0rc1`
+    Beta,
+    /// A final release, possibly with a post release, such as `1.0` or `1.0.post1`
+    Final,
+}
+
+impl Phase {
+    /// The phase of a version, or `None` if it does not start with a release segment
+    ///
+    /// A pre-release of a development release, such as `1.0a1.dev1`, is in the pre-release's
+    /// phase.
+    pub(crate) fn of(version: &str) -> Option<Phase> {
+        let version = version.trim().to_ascii_lowercase();
+        // an epoch, such as `1!2.0`, does not change the phase
+        let version = version
+            .split_once('!')
+            .map_or(version.as_str(), |(_, rest)| rest);
+        let version = version.split('+').next().unwrap_or_default();
+        Release::parse(version)?;
+        let suffix = version
+            .trim_start_matches('v')
+            .trim_start_matches(|c: char| c.is_ascii_digit() || c == '.');
+        let mut dev = false;
+        let words = suffix
+            .split(|c: char| !c.is_ascii_alphabetic())
+            .filter(|word| !word.is_empty());
+        for word in words {
+            match word {
+                "a" | "alpha" => return Some(Phase::Alpha),
+                "b" | "beta" | "c" | "rc" | "pre" | "preview" => return Some(Phase::Beta),
+                "dev" => dev = true,
+                _ => {}
+            }
+        }
+        Some(if dev { Phase::Dev } else { Phase::Final })
+    }
+}
+
+/// Whether some version of the release series `series`, such as every `3.12.*` for `3.12`,
+/// satisfies every clause of the comma separated `specifiers`
+///
+/// Returns `None` if the specifiers cannot be understood.
+pub(crate) fn admits_series(specifiers: &str, series: Release) -> Option<bool> {
+    let mut admitted = true;
+    for clause in specifiers.split(',') {
+        let clause = clause.trim();
+        if clause.is_empty() {
+            continue;
+        }
+        let operator_len = clause
+            .bytes()
+            .take_while(|byte| b"<>=!~".contains(byte))
+            .count();
+        let (operator, version) = clause.split_at(operator_len);
+        let version = version.trim();
+        let (version, wildcard) = match version.strip_suffix(".*") {
+            Some(version) => (version, true),
+            None => (version, false),
+        };
+        let version = Release::parse(version)?;
+        admitted &= match (operator, wildcard) {
+            // any version of the series can be large enough
+            (">=" | ">", false) => series.cmp_prefix(&version) != Ordering::Less,
+            // the first version of the series must be small enough
+            ("<", false) => series.cmp_padded(&version) == Ordering::Less,
+            ("<=", false) => series.cmp_padded(&version) != Ordering::Greater,
+            ("==" | "===", false) => series.cmp_prefix(&version) == Ordering::Equal,
+            ("==", true) => series.starts_with(&version) || version.starts_with(&series),
+            ("!=", true) => !series.starts_with(&version),
+            ("!=", false) => true,
+            ("~=", false) if version.len >= 2 => {
+                let mut prefix = version;
+                prefix.len -= 1;
+                prefix.parts[prefix.len] = 0;
+                series.cmp_prefix(&version) != Ordering::Less
+                    && (series.starts_with(&prefix) || prefix.starts_with(&series))
+            }
+            _ => return None,
+        };
+    }
+    Some(admitted)
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    fn admits(specifiers: &str, series: &str) -> Option<bool> {
+        admits_series(specifiers, Release::parse(series).unwrap())
+    }
+
+    #[test]
+    fn parses_release_segments() {
+        assert_eq!(Release::parse("3.12.1").unwrap().parts(), [3, 12, 1]);
+        assert_eq!(Release::parse("v2.0rc1").unwrap().parts(), [2, 0]);
+        assert_eq!(Release::parse("1.0.post1").unwrap().parts(), [1, 0]);
+        assert_eq!(Release::parse("rc1"), None);
+    }
+
+    #[test]
+    fn phases_follow_the_pre_release() {
+        assert_eq!(Phase::of("1.0"), Some(Phase::Final));
+        assert_eq!(Phase::of("1.0.post2"), Some(Phase::Final));
+        assert_eq!(Phase::of("1.0.dev3"), Some(Phase::Dev));
+        assert_eq!(Phase::of("1.0a1.dev1"), Some(Phase::Alpha));
+        assert_eq!(Phase::of("1.0-Alpha.1"), Some(Phase::Alpha));
+        assert_eq!(Phase::of("v2.0b1"), Some(Phase::Beta));
+        assert_eq!(Phase::of("2.0rc1+local.abc"), Some(Phase::Beta));
+        assert_eq!(Phase::of("1!2.0.dev0"), Some(Phase::Dev));
+        assert_eq!(Phase::of("1.0+a"), Some(Phase::Final));
+        assert_eq!(Phase::of("dev"), None);
+    }
+
+    #[test]
+    fn admits_series_within_bounds() {
+        assert_eq!(admits(">=3.9", "3.8"), Some(false));
+        assert_eq!(admits(">=3.9", "3.9"), Some(true));
+        assert_eq!(admits(">=3.9.1", "3.9"), Some(true));
+        assert_eq!(admits(">3.9", "3.9"), Some(true));
+        assert_eq!(admits(">=3.9, <3.13", "3.12"), Some(true));
+        assert_eq!(admits(">=3.9, <3.13", "3.13"), Some(false));
+        assert_eq!(admits("<3.12.1", "3.12"), Some(true));
+        assert_eq!(admits("<=3.12", "3.12"), Some(true));
+        assert_eq!(admits("<=3.12", "3.13"), Some(false));
+        assert_eq!(admits(">=3", "2.7"), Some(false));
+    }
+
+    #[test]
+    fn admits_series_by_equality() {
+        assert_eq!(admits("==3.10.*", "3.10"), Some(true));
+        assert_eq!(admits("==3.*", "3.10"), Some(true));
+        assert_eq!(admits("==3.*", "2.7"), Some(false));
+        assert_eq!(admits("==3.10.4", "3.10"), Some(true));
+        assert_eq!(admits("==3.10.4", "3.11"), Some(false));
+        assert_eq!(admits("!=3.10.*", "3.10"), Some(false));
+        assert_eq!(admits("!=3.10.1", "3.10"), Some(true));
+        assert_eq!(admits("~=3.9", "3.13"), Some(true));
+        assert_eq!(admits("~=3.9", "4.0"), Some(false));
+        assert_eq!(admits("~=3.9.2", "3.9"), Some(true));
+        assert_eq!(admits("~=3.9.2", "3.10"), Some(false));
+        assert_eq!(admits(">=4.2,<5.1", "5"), Some(true));
+    }
+
+    #[test]
+    fn rejects_unknown_syntax() {
+        assert_eq!(admits("~=3", "3.9"), None);
+        assert_eq!(admits(">=three", "3.9"), None);
+        assert_eq!(admits("", "3.9"), Some(true));
+    }
+}
@@ -0,0 +1,293 @@
+//! Checked-in policies for the classifiers of an organization's projects.
+//!
+//! A `Policy` gathers what a team decides once for all its projects: the `Level` of each lint
+//! rule, the classifiers every project must declare, the categories or prefixes none may declare
+//! and the shorthands `fix` expands. It is read from a `trove.toml`, or from the `[tool.trove]`
+//! table of a `pyproject.toml`:
+//!
+//! ```toml
+//! required = ["Typing :: Typed"]
+//! forbidden = ["Private", "License :: Other/Proprietary License"]
+//!
+//! [levels]
+//! TC005 = "deny"
+//! version-status-mismatch = "allow"
+//!
+//! [aliases]
+//! mit = "License :: OSI Approved :: MIT License"
+//! ```
+//!
+//! # Examples
+//!
+#![cfg_attr(not(feature = "typing"), doc = "```ignore")]
+#![cfg_attr(feature = "typing", doc = "```")]
+//! use trove_classifiers::lint::{Declared, Rule};
+//! use trove_classifiers::policy::Policy;
+//!
+//! let policy = Policy::from_pyproject(
+//!     "[project]\nname = 'example'\n\n[tool.trove]\nrequired = ['Typing :: Typed']\n",
+//! )?
+//! .unwrap();
+//! let declared = [Declared::new("Typing :: Stubs Only")];
+//! let findings = policy.linter().check(&policy.context(&declared));
+//! assert_eq!(findings[0].rule, Rule::Required);
+//! # Ok::<(), trove_classifiers::policy::PolicyError>(())
+//! ```
+
+use std::fmt;
+use std::str::FromStr;
+
+use crate::alias::{AliasError, Aliases};
+use crate::lint::{Context, Declared, Level, Linter, Rule};
+use crate::pyproject::{self, PyprojectError, Value};
+use crate::Classifier;
+
+/// The name a policy file has by convention
+pub const FILE_NAME: &str = "trove.toml";
+
+/// What an organization requires of its projects' classifiers
+#[derive(Clone, Debug, Default, Eq, PartialEq)]
+pub struct Policy {
+    /// The rules whose level is set, in the order they were set
+    pub levels: Vec<(Rule, Level)>,
+    /// The classifiers every project must declare
+    pub required: Vec<Classifier>,
+    /// The categories and prefixes whose classifiers no project may declare
+    pub forbidden: Vec<String>,
+    pub aliases: Aliases,
+}
+
+/// The error returned when a policy cannot be read
+#[derive(Clone, Debug, Eq, PartialEq)]
+pub struct PolicyError {
+    /// The 1-based line of the problem
+    pub line: usize,
+    pub kind: ErrorKind,
+}
+
+#[derive(Clone, Debug, Eq, PartialEq)]
+#[non_exhaustive]
+pub enum ErrorKind {
+    /// The document is not TOML this crate can read
+    Toml(pyproject::ErrorKind),
+    /// The key is not a setting of policies
+    UnknownKey(String),
+    /// The setting is not a string, or not an array of strings, as it must be
+    InvalidValue(String),
+    /// A level is set for a rule with an unknown code or name
+    UnknownRule(String),
+    /// A level is not `allow`, `warn` or `deny`
+    UnknownLevel(String),
+    /// A required classifier is not a known classifier written canonically
+    UnknownClassifier(String),
+    /// An alias cannot be registered
+    Alias(AliasError),
+}
+
+impl fmt::Display for PolicyError {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        write!(f, "line {}: ", self.line)?;
+        match &self.kind {
+            ErrorKind::Toml(pyproject::ErrorKind::Unterminated) => {
+                f.write_str("unterminated string or array")
+            }
+            ErrorKind::Toml(pyproject::ErrorKind::InvalidEscape) => {
+                f.write_str("invalid escape sequence")
+            }
+            ErrorKind::Toml(_) => f.write_str("invalid TOML"),
+            ErrorKind::UnknownKey(key) => write!(f, "{key:?} is not a policy setting"),
+            ErrorKind::InvalidValue(key) => write!(
+                f,
+                "{key:?} must be {}",
+                if key.contains('.') {
+                    "a string"
+                } else {
+                    "an array of strings"
+                }
+            ),
+            ErrorKind::UnknownRule(rule) => write!(f, "{rule:?} is not a rule"),
+            ErrorKind::UnknownLevel(level) => {
+                write!(f, "{level:?} is not a level, expected allow, warn or deny")
+            }
+            ErrorKind::UnknownClassifier(classifier) => {
+                write!(f, "{classifier:?} is not a known classifier")
+            }
+            ErrorKind::Alias(err) => err.fmt(f),
+        }
+    }
+}
+
+impl std::error::Error for PolicyError {}
+
+impl From<PyprojectError> for PolicyError {
+    fn from(err: PyprojectError) -> Self {
+        PolicyError {
+            line: err.line,
+            kind: ErrorKind::Toml(err.kind),
+        }
+    }
+}
+
+impl Policy {
+    /// Read the policy of a `trove.toml`
+    pub fn from_toml(source: &str) -> Result<Policy, PolicyError> {
+        Policy::read(source, "")
+    }
+
+    /// Read the policy of the `[tool.trove]` table of a `pyproject.toml`, if it has one
+    pub fn from_pyproject(source: &str) -> Result<Option<Policy>, PolicyError> {
+        let policy = Policy::read(source, "tool.trove")?;
+        Ok((policy != Policy::default()).then_some(policy))
+    }
+
+    fn read(source: &str, table: &str) -> Result<Policy, PolicyError> {
+        let mut policy = Policy::default();
+        for (key, value, line) in pyproject::values(source, table)? {
+            let error = |kind| PolicyError { line, kind };
+            let invalid = || error(ErrorKind::InvalidValue(key.clone()));
+            let (group, name) = key.split_once('.').unwrap_or((key.as_str(), ""));
+            match (group, value) {
+                ("required", Value::Array(array)) if name.is_empty() => {
+                    for entry in array.entries {
+                        let classifier =
+                            Classifier::from_str(&entry.value).map_err(|_| PolicyError {
+                                line: entry.line,
+                                kind: ErrorKind::UnknownClassifier(entry.value.clone()),
+                            })?;
+                        policy.required.push(classifier);
+                    }
+                }
+                ("forbidden", Value::Array(array)) if name.is_empty() => policy
+                    .forbidden
+                    .extend(array.entries.into_iter().map(|entry| entry.value)),
+                ("levels", Value::String(level)) if !name.is_empty() => {
+                    let rule = Rule::find(name)
+                        .ok_or_else(|| error(ErrorKind::UnknownRule(name.to_string())))?;
+                    let level =
+                        Level::find(&level).ok_or_else(|| error(ErrorKind::UnknownLevel(level)))?;
+                    policy.levels.retain(|(set, _)| *set != rule);
+                    policy.levels.push((rule, level));
+                }
+                ("aliases", Value::String(classifier)) if !name.is_empty() => policy
+                    .aliases
+                    .insert(name, &classifier)
+                    .map_err(|err| error(ErrorKind::Alias(err)))?,
+                ("required" | "forbidden", _) if name.is_empty() => return Err(invalid()),
+                ("levels" | "aliases", _) if !name.is_empty() => return Err(invalid()),
+                _ => return Err(error(ErrorKind::UnknownKey(key.clone()))),
+            }
+        }
+        Ok(policy)
+    }
+
+    /// A linter of the built-in rules at the levels of this policy
+    pub fn linter(&self) -> Linter {
+        self.levels
+            .iter()
+            .fold(Linter::new(), |linter, (rule, level)| {
+                linter.level(*rule, *level)
+            })
+    }
+
+    /// The context checking declared classifiers against this policy
+    pub fn context<'a>(&'a self, declared: &'a [Declared]) -> Context<'a> {
+        Context::new(declared)
+            .required(&self.required)
+            .forbidden(&self.forbidden)
+            .aliases(&self.aliases)
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    #[cfg(feature = "all-categories")]
+    fn reads_every_setting() {
+        let policy = Policy::from_toml(
+            "required = ['Typing :: Typed']\n\
+             forbidden = ['Private']\n\
+             [levels]\n\
+             TC005 = 'warn'\n\
+             duplicate-classifier = 'Deny'\n\
+             [aliases]\n\
+             \"py 3\" = 'Programming Language :: Python :: 3'\n",
+        )
+        .unwrap();
+        assert_eq!(policy.required, [Classifier::Typing__Typed]);
+        assert_eq!(policy.forbidden, ["Private"]);
+        assert_eq!(policy.levels, [(Rule::Duplicate, Level::Deny)]);
+        assert_eq!(
+            policy.aliases.get("PY 3"),
+            Some(Classifier::ProgrammingLanguage__Python__3)
+        );
+
+        let declared = [
+            Declared::new("Private :: Do Not Upload").line(1),
+            Declared::new("Typing :: Typed").line(2),
+            Declared::new("Typing :: Typed").line(3),
+            Declared::new("Py 3").line(4),
+        ];
+        let findings = policy.linter().check(&policy.context(&declared));
+        let found: Vec<(Rule, Option<usize>)> = findings
+            .iter()
+            .map(|finding| (finding.rule, finding.line))
+            .collect();
+        assert_eq!(
+            found,
+            [
+                (Rule::Private, Some(1)),
+                (Rule::Forbidden, Some(1)),
+                (Rule::Duplicate, Some(3)),
+                (Rule::NonCanonical, Some(4)),
+            ]
+        );
+        assert_eq!(
+            findings[3].message,
+            "\"Py 3\" is an alias, and must be written \"Programming Language :: Python :: 3\""
+        );
+        assert!(!crate::lint::passes(&findings));
+    }
+
+    #[test]
+    fn pyprojects_without_a_policy_have_none() {
+        assert_eq!(
+            Policy::from_pyproject("[project]\nname = 'x'\n[tool.other]\nkey = 1\n"),
+            Ok(None)
+        );
+    }
+
+    #[test]
+    fn rejects_invalid_settings() {
+        let error = |source: &str| Policy::from_toml(source).unwrap_err().to_string();
+        assert_eq!(
+            error("requires = []\n"),
+            "line 1: \"requires\" is not a policy setting"
+        );
+        assert_eq!(
+            error("\nrequired = 'Typing :: Typed'\n"),
+            "line 2: \"required\" must be an array of strings"
+        );
+        assert_eq!(
+            error("[levels]\nTC005 = true\n"),
+            "line 2: \"levels.TC005\" must be a string"
+        );
+        assert_eq!(
+            error("[levels]\nTC999 = 'deny'\n"),
+            "line 2: \"TC999\" is not a rule"
+        );
+        assert_eq!(
+            error("[levels]\nTC005 = 'forbid'\n"),
+            "line 2: \"forbid\" is not a level, expected allow, warn or deny"
+        );
+        assert_eq!(
+            error("required = [\n  'typing :: typed',\n]\n"),
+            "line 2: \"typing :: typed\" is not a known classifier"
+        );
+        assert_eq!(
+            error("required = ['Typing :: Typed'"),
+            "line 1: unterminated string or array"
+        );
+    }
+}
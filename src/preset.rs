@@ -0,0 +1,158 @@
+//! Curated starting points for the classifiers of common kinds of project.
+//!
+//! Each preset is the classifiers almost every project of its kind declares, in canonical order.
+//! None of them declares a development status, license or Python version, which differ between
+//! projects of every kind; `builder::ClassifiersBuilder` adds those to a preset. Classifiers of
+//! categories whose features are disabled are left out of the presets.
+//!
+//! # Examples
+//!
+#![cfg_attr(not(feature = "all-categories"), doc = "```ignore")]
+#![cfg_attr(feature = "all-categories", doc = "```")]
+//! use trove_classifiers::builder::ClassifiersBuilder;
+//! use trove_classifiers::{preset, Classifier};
+//!
+//! let classifiers = ClassifiersBuilder::new()
+//!     .preset(preset::TYPED_LIBRARY)
+//!     .license("Apache-2.0")
+//!     .build()?;
+//! assert!(classifiers.contains(&Classifier::Typing__Typed));
+//! assert_eq!(preset::find("typed-library"), Some(preset::TYPED_LIBRARY));
+//! # Ok::<(), trove_classifiers::builder::BuildError>(())
+//! ```
+
+use crate::Classifier;
+
+/// A library of Python modules that ships type annotations
+pub const TYPED_LIBRARY: &[Classifier] = &[
+    #[cfg(feature = "intended-audience")]
+    Classifier::IntendedAudience__Developers,
+    #[cfg(feature = "operating-system")]
+    Classifier::OperatingSystem__OSIndependent,
+    #[cfg(feature = "programming-language")]
+    Classifier::ProgrammingLanguage__Python__3,
+    #[cfg(feature = "programming-language")]
+    Classifier::ProgrammingLanguage__Python__3__Only,
+    #[cfg(feature = "topic")]
+    Classifier::Topic__SoftwareDevelopment__Libraries__PythonModules,
+    #[cfg(feature = "typing")]
+    Classifier::Typing__Typed,
+];
+
+/// A command line application
+pub const CLI_APP: &[Classifier] = &[
+    #[cfg(feature = "environment")]
+    Classifier::Environment__Console,
+    #[cfg(feature = "intended-audience")]
+    Classifier::IntendedAudience__EndUsersDesktop,
+    #[cfg(feature = "operating-system")]
+    Classifier::OperatingSystem__OSIndependent,
+    #[cfg(feature = "programming-language")]
+    Classifier::ProgrammingLanguage__Python__3,
+    #[cfg(feature = "programming-language")]
+    Classifier::ProgrammingLanguage__Python__3__Only,
+    #[cfg(feature = "topic")]
+    Classifier::Topic__Utilities,
+];
+
+/// An extension of the Sphinx documentation generator
+pub const SPHINX_EXTENSION: &[Classifier] = &[
+    #[cfg(feature = "framework")]
+    Classifier::Framework__Sphinx,
+    #[cfg(feature = "framework")]
+    Classifier::Framework__Sphinx__Extension,
+    #[cfg(feature = "intended-audience")]
+    Classifier::IntendedAudience__Developers,
+    #[cfg(feature = "programming-language")]
+    Classifier::ProgrammingLanguage__Python__3,
+    #[cfg(feature = "topic")]
+    Classifier::Topic__Documentation,
+    #[cfg(feature = "topic")]
+    Classifier::Topic__Documentation__Sphinx,
+    #[cfg(feature = "topic")]
+    Classifier::Topic__SoftwareDevelopment__Documentation,
+];
+
+/// A plugin of the pytest test framework
+pub const PYTEST_PLUGIN: &[Classifier] = &[
+    #[cfg(feature = "framework")]
+    Classifier::Framework__Pytest,
+    #[cfg(feature = "intended-audience")]
+    Classifier::IntendedAudience__Developers,
+    #[cfg(feature = "operating-system")]
+    Classifier::OperatingSystem__OSIndependent,
+    #[cfg(feature = "programming-language")]
+    Classifier::ProgrammingLanguage__Python__3,
+    #[cfg(feature = "topic")]
+    Classifier::Topic__SoftwareDevelopment__Testing,
+];
+
+/// A reusable Django application
+pub const DJANGO_APP: &[Classifier] = &[
+    #[cfg(feature = "environment")]
+    Classifier::Environment__WebEnvironment,
+    #[cfg(feature = "framework")]
+    Classifier::Framework__Django,
+    #[cfg(feature = "intended-audience")]
+    Classifier::IntendedAudience__Developers,
+    #[cfg(feature = "operating-system")]
+    Classifier::OperatingSystem__OSIndependent,
+    #[cfg(feature = "programming-language")]
+    Classifier::ProgrammingLanguage__Python__3,
+    #[cfg(feature = "topic")]
+    Classifier::Topic__Internet__WWWHTTP__DynamicContent,
+];
+
+/// A stubs-only package of type annotations for another distribution
+pub const STUBS_PACKAGE: &[Classifier] = &[
+    #[cfg(feature = "intended-audience")]
+    Classifier::IntendedAudience__Developers,
+    #[cfg(feature = "programming-language")]
+    Classifier::ProgrammingLanguage__Python__3,
+    #[cfg(feature = "typing")]
+    Classifier::Typing__StubsOnly,
+];
+
+/// Every preset and its name, such as `typed-library`
+pub const PRESETS: &[(&str, &[Classifier])] = &[
+    ("typed-library", TYPED_LIBRARY),
+    ("cli-app", CLI_APP),
+    ("sphinx-extension", SPHINX_EXTENSION),
+    ("pytest-plugin", PYTEST_PLUGIN),
+    ("django-app", DJANGO_APP),
+    ("stubs-package", STUBS_PACKAGE),
+];
+
+/// The preset with a name, matched ignoring ASCII case
+pub fn find(name: &str) -> Option<&'static [Classifier]> {
+    PRESETS
+        .iter()
+        .find(|(preset, _)| preset.eq_ignore_ascii_case(name.trim()))
+        .map(|(_, classifiers)| *classifiers)
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn presets_are_in_canonical_order() {
+        for (name, classifiers) in PRESETS {
+            assert!(
+                classifiers.windows(2).all(|pair| pair[0] < pair[1]),
+                "{name}"
+            );
+        }
+    }
+
+    #[test]
+    #[cfg(feature = "all-categories")]
+    fn presets_are_complete() {
+        for (name, classifiers) in PRESETS {
+            assert!(classifiers.len() >= 3, "{name}");
+            assert_eq!(find(name), Some(*classifiers));
+        }
+        assert_eq!(find(" CLI-App "), Some(CLI_APP));
+        assert_eq!(find("library"), None);
+    }
+}
@@ -0,0 +1,105 @@
+//! The parts of pypi.org's JSON API describing a project's classifiers.
+//!
+//! Warehouse, the software behind pypi.org, answers `/pypi/<project>/json` and
+//! `/pypi/<project>/<version>/json` with the metadata of a release. `Project` models the fields
+//! of its `info` object this crate has a use for, with the classifiers read as
+//! `KnownAndUnknown`, so those this version of the dataset does not know are kept rather than
+//! failing the document. Every other field is ignored.
+//!
+//! The model only needs serde: a payload fetched by any HTTP client, or saved to disk earlier,
+//! is read with any serde format, and writing it back keeps the unknown classifiers. Warehouse
+//! writes missing strings as either `null` or `""`; both read as `None`.
+//!
+//! # Examples
+//!
+//! ```
+//! use trove_classifiers::pypi_json::Project;
+//! use trove_classifiers::Classifier;
+//!
+//! let payload = r#"{
+//!     "info": {
+//!         "name": "example",
+//!         "version": "1.0",
+//!         "classifiers": ["Typing :: Typed", "Private :: Do Not Upload"],
+//!         "requires_python": ">=3.9",
+//!         "license": "",
+//!         "license_expression": "MIT"
+//!     },
+//!     "releases": {}
+//! }"#;
+//! let project: Project = serde_json::from_str(payload)?;
+//! assert_eq!(project.info.classifiers.known, [Classifier::Typing__Typed]);
+//! assert_eq!(project.info.classifiers.unknown, ["Private :: Do Not Upload"]);
+//! assert_eq!(project.info.requires_python.as_deref(), Some(">=3.9"));
+//! assert_eq!(project.info.license, None);
+//! # Ok::<(), serde_json::Error>(())
+//! ```
+
+use alloc::string::String;
+
+use ::serde::{Deserialize, Deserializer, Serialize};
+
+use crate::serde::KnownAndUnknown;
+
+/// A project, or one of its releases, as the JSON API describes it
+#[derive(Clone, Debug, Default, Deserialize, Eq, PartialEq, Serialize)]
+pub struct Project {
+    pub info: Info,
+}
+
+/// The `info` object of the JSON API: the core metadata of the release
+#[derive(Clone, Debug, Default, Deserialize, Eq, PartialEq, Serialize)]
+pub struct Info {
+    pub name: String,
+    pub version: String,
+    /// The `Classifier` headers of the release
+    #[serde(default)]
+    pub classifiers: KnownAndUnknown,
+    /// The `Requires-Python` specifier, such as `>=3.9`
+    #[serde(default, deserialize_with = "non_empty")]
+    pub requires_python: Option<String>,
+    /// The free-form `License` header, which may be the whole license text
+    #[serde(default, deserialize_with = "non_empty")]
+    pub license: Option<String>,
+    /// The SPDX `License-Expression` header of metadata version 2.4 and later
+    #[serde(default, deserialize_with = "non_empty")]
+    pub license_expression: Option<String>,
+}
+
+/// A string that may be `null` or empty, either of which is `None`
+fn non_empty<'de, D: Deserializer<'de>>(deserializer: D) -> Result<Option<String>, D::Error> {
+    let string = Option::<String>::deserialize(deserializer)?;
+    Ok(string.filter(|string| !string.trim().is_empty()))
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    #[cfg(feature = "typing")]
+    fn round_trips_unknown_classifiers() {
+        let payload = r#"{"info": {"name": "example", "version": "2.0", "requires_python": null,
+            "classifiers": ["Natural Language :: Ukranian", "Typing :: Typed"],
+            "summary": "An example", "license": "BSD"}, "last_serial": 1}"#;
+        let project: Project = serde_json::from_str(payload).unwrap();
+        assert_eq!(project.info.requires_python, None);
+        assert_eq!(project.info.license.as_deref(), Some("BSD"));
+        assert_eq!(
+            project.info.classifiers.unknown,
+            ["Natural Language :: Ukranian"]
+        );
+        let written = serde_json::to_string(&project).unwrap();
+        assert!(
+            written.contains(r#""classifiers":["Typing :: Typed","Natural Language :: Ukranian"]"#)
+        );
+        assert_eq!(serde_json::from_str::<Project>(&written).unwrap(), project);
+    }
+
+    #[test]
+    fn classifiers_may_be_missing() {
+        let project: Project =
+            serde_json::from_str(r#"{"info": {"name": "x", "version": "1"}}"#).unwrap();
+        assert_eq!(project.info.classifiers, KnownAndUnknown::default());
+    }
+}
@@ -0,0 +1,672 @@
+//! Extraction of the classifiers declared in a `pyproject.toml`, and formatting of new ones.
+//!
+//! Only the `classifiers` array of the `[project]` table is read, along with where each of its
+//! strings was found, so tools can point at or rewrite individual entries. The rest of the
+//! document is skipped without being validated, so this is not a TOML parser. `to_toml_array`
+//! writes the array back, correctly quoted, without a TOML writer.
+//!
+//! # Examples
+//!
+//! ```
+//! use trove_classifiers::pyproject;
+//!
+//! let source = r#"
+//! [project]
+//! name = "example"
+//! classifiers = [
+//!     "Typing :: Typed",  # checked by mypy
+//!     'Typing :: Untyped',
+//! ]
+//! "#;
+//!
+//! let array = pyproject::classifiers(source)?.unwrap();
+//! assert_eq!(array.entries[0].value, "Typing :: Typed");
+//! assert_eq!(array.entries[1].line, 6);
+//! assert_eq!(&source[array.entries[1].span.clone()], "'Typing :: Untyped'");
+//! # Ok::<(), pyproject::PyprojectError>(())
+//! ```
+
+use std::fmt;
+use std::fmt::Write;
+use std::ops::Range;
+use std::str::FromStr;
+
+use crate::lint::Declared;
+use crate::{Classifier, ParseError};
+
+/// The `classifiers` array of a `pyproject.toml`
+#[derive(Clone, Debug, Eq, PartialEq)]
+pub struct Array {
+    /// The byte range of the array in the document, from its `[` to its `]`
+    pub span: Range<usize>,
+    /// The strings of the array, in the order they were declared
+    pub entries: Vec<Entry>,
+}
+
+/// A string of the `classifiers` array
+#[derive(Clone, Debug, Eq, PartialEq)]
+pub struct Entry {
+    /// The string's value, with escapes resolved
+    pub value: String,
+    /// The 1-based line the string starts on
+    pub line: usize,
+    /// The byte range of the string in the document, including its quotes
+    pub span: Range<usize>,
+}
+
+impl Entry {
+    /// The classifier this string names
+    pub fn classifier(&self) -> Result<Classifier, ParseError> {
+        Classifier::from_str(&self.value)
+    }
+
+    /// The string as input to `lint::lint`
+    pub fn declared(&self) -> Declared {
+        Declared::new(&self.value).line(self.line)
+    }
+}
+
+/// The error returned when the `classifiers` array cannot be read
+#[derive(Clone, Debug, Eq, PartialEq)]
+pub struct PyprojectError {
+    /// The 1-based line of the problem
+    pub line: usize,
+    pub kind: ErrorKind,
+}
+
+#[derive(Clone, Copy, Debug, Eq, PartialEq)]
+#[non_exhaustive]
+pub enum ErrorKind {
+    /// `classifiers` is not an array
+    NotAnArray,
+    /// An element of the array is not a string
+    NotAString,
+    /// A string or array is not closed before the end of the document
+    Unterminated,
+    /// A basic string contains an unknown escape sequence
+    InvalidEscape,
+}
+
+impl fmt::Display for PyprojectError {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        let problem = match self.kind {
+            ErrorKind::NotAnArray => "project.classifiers is not an array",
+            ErrorKind::NotAString => "project.classifiers holds a value that is not a string",
+            ErrorKind::Unterminated => "unterminated string or array",
+            ErrorKind::InvalidEscape => "invalid escape sequence",
+        };
+        write!(f, "line {}: {problem}", self.line)
+    }
+}
+
+impl std::error::Error for PyprojectError {}
+
+/// How `to_toml_array` lays out an array
+#[derive(Clone, Debug, Eq, PartialEq)]
+#[non_exhaustive]
+pub struct ArrayStyle {
+    /// The indentation of each string of a multi-line array
+    pub indent: String,
+    /// The longest array written on a single line, or 0 to always write one string per line
+    pub width: usize,
+    /// Write literal strings, in single quotes, rather than basic strings where possible
+    pub literal: bool,
+}
+
+impl Default for ArrayStyle {
+    fn default() -> Self {
+        ArrayStyle {
+            indent: "    ".to_string(),
+            width: 0,
+            literal: false,
+        }
+    }
+}
+
+impl ArrayStyle {
+    pub fn indent(mut self, indent: &str) -> Self {
+        self.indent = indent.to_string();
+        self
+    }
+
+    pub fn width(mut self, width: usize) -> Self {
+        self.width = width;
+        self
+    }
+
+    pub fn literal(mut self, enabled: bool) -> Self {
+        self.literal = enabled;
+        self
+    }
+}
+
+/// A TOML array of classifiers, deduplicated and in canonical order, to follow
+/// `classifiers = ` in the `[project]` table
+///
+/// # Examples
+///
+#[cfg_attr(not(feature = "typing"), doc = "```ignore")]
+#[cfg_attr(feature = "typing", doc = "```")]
+/// use trove_classifiers::pyproject::{to_toml_array, ArrayStyle};
+/// use trove_classifiers::Classifier;
+///
+/// let classifiers = [Classifier::Typing__Typed, Classifier::Typing__StubsOnly];
+/// assert_eq!(
+///     to_toml_array(&classifiers, &ArrayStyle::default()),
+///     "[\n    \"Typing :: Stubs Only\",\n    \"Typing :: Typed\",\n]"
+/// );
+/// assert_eq!(
+///     to_toml_array(&classifiers, &ArrayStyle::default().width(80).literal(true)),
+///     "['Typing :: Stubs Only', 'Typing :: Typed']"
+/// );
+/// ```
+pub fn to_toml_array(classifiers: &[Classifier], style: &ArrayStyle) -> String {
+    let mut classifiers = classifiers.to_vec();
+    classifiers.sort();
+    classifiers.dedup();
+    toml_array(classifiers.iter().map(Classifier::as_str), style)
+}
+
+/// A TOML array of strings, in the order given, such as classifiers followed by unknown ones
+/// that should be kept
+pub fn toml_array<I>(values: I, style: &ArrayStyle) -> String
+where
+    I: IntoIterator,
+    I::Item: AsRef<str>,
+{
+    let quoted: Vec<String> = values
+        .into_iter()
+        .map(|value| quote(value.as_ref(), style.literal))
+        .collect();
+    let inline = format!("[{}]", quoted.join(", "));
+    if quoted.is_empty() || inline.len() <= style.width {
+        return inline;
+    }
+    let mut array = String::from("[\n");
+    for string in quoted {
+        writeln!(array, "{}{string},", style.indent).unwrap();
+    }
+    array.push(']');
+    array
+}
+
+/// A TOML literal string if `literal` is set and `value` can be one, or else a basic string
+fn quote(value: &str, literal: bool) -> String {
+    if literal && !value.contains(|c: char| c == '\'' || (c.is_control() && c != '\t')) {
+        return format!("'{value}'");
+    }
+    let mut quoted = String::with_capacity(value.len() + 2);
+    quoted.push('"');
+    for c in value.chars() {
+        match c {
+            '"' => quoted.push_str("\\\""),
+            '\\' => quoted.push_str("\\\\"),
+            c if c.is_control() => write!(quoted, "\\u{:04X}", c as u32).unwrap(),
+            c => quoted.push(c),
+        }
+    }
+    quoted.push('"');
+    quoted
+}
+
+/// The `classifiers` array of the `[project]` table, if the document declares one
+pub fn classifiers(source: &str) -> Result<Option<Array>, PyprojectError> {
+    let mut cursor = Cursor {
+        source,
+        pos: 0,
+        line: 1,
+    };
+    let mut table = String::new();
+    loop {
+        cursor.skip_trivia(true);
+        match cursor.peek() {
+            None => return Ok(None),
+            Some(b'[') => {
+                let end = source[cursor.pos..]
+                    .find(['\n', '#'])
+                    .map_or(source.len(), |end| cursor.pos + end);
+                table = normalize_key(source[cursor.pos..end].trim().trim_matches(['[', ']']));
+                cursor.pos = end;
+            }
+            Some(_) => {
+                let end = source[cursor.pos..]
+                    .find(['=', '\n'])
+                    .map_or(source.len(), |end| cursor.pos + end);
+                let key = normalize_key(&source[cursor.pos..end]);
+                cursor.pos = end;
+                if cursor.peek() != Some(b'=') {
+                    continue;
+                }
+                cursor.pos += 1;
+                cursor.skip_trivia(false);
+                let wanted = match table.as_str() {
+                    "" => key == "project.classifiers",
+                    "project" => key == "classifiers",
+                    _ => false,
+                };
+                if wanted {
+                    return cursor.array().map(Some);
+                }
+                cursor.skip_value()?;
+            }
+        }
+    }
+}
+
+/// A value read by `values`
+#[derive(Clone, Debug, Eq, PartialEq)]
+pub(crate) enum Value {
+    String(String),
+    /// An array of strings
+    Array(Array),
+    /// Any other value, which is skipped
+    Other,
+}
+
+/// Every key in the table `prefix` or in the tables nested in it, with its path relative to
+/// `prefix`, its value and its 1-based line
+///
+/// This is how tools read their `[tool.NAME]` settings. Only strings and arrays of strings are
+/// read; any other value is `Value::Other`.
+pub(crate) fn values(
+    source: &str,
+    prefix: &str,
+) -> Result<Vec<(String, Value, usize)>, PyprojectError> {
+    let mut cursor = Cursor {
+        source,
+        pos: 0,
+        line: 1,
+    };
+    let mut values = Vec::new();
+    let mut table = String::new();
+    loop {
+        cursor.skip_trivia(true);
+        match cursor.peek() {
+            None => return Ok(values),
+            Some(b'[') => {
+                let end = source[cursor.pos..]
+                    .find(['\n', '#'])
+                    .map_or(source.len(), |end| cursor.pos + end);
+                table = normalize_key(source[cursor.pos..end].trim().trim_matches(['[', ']']));
+                cursor.pos = end;
+            }
+            Some(_) => {
+                let end = source[cursor.pos..]
+                    .find(['=', '\n'])
+                    .map_or(source.len(), |end| cursor.pos + end);
+                let key = normalize_key(&source[cursor.pos..end]);
+                cursor.pos = end;
+                if cursor.peek() != Some(b'=') {
+                    continue;
+                }
+                cursor.pos += 1;
+                cursor.skip_trivia(false);
+                let path = if table.is_empty() {
+                    key
+                } else {
+                    format!("{table}.{key}")
+                };
+                let relative = if prefix.is_empty() {
+                    Some(path.as_str())
+                } else {
+                    path.strip_prefix(prefix)
+                        .and_then(|rest| rest.strip_prefix('.'))
+                };
+                let Some(relative) = relative else {
+                    cursor.skip_value()?;
+                    continue;
+                };
+                let line = cursor.line;
+                let value = match cursor.peek() {
+                    Some(b'"' | b'\'') => Value::String(cursor.string()?),
+                    Some(b'[') => {
+                        let mut array = cursor.clone();
+                        match array.array() {
+                            Ok(value) => {
+                                cursor = array;
+                                Value::Array(value)
+                            }
+                            Err(err) if err.kind == ErrorKind::NotAString => {
+                                cursor.skip_value()?;
+                                Value::Other
+                            }
+                            Err(err) => return Err(err),
+                        }
+                    }
+                    _ => {
+                        cursor.skip_value()?;
+                        Value::Other
+                    }
+                };
+                values.push((relative.to_string(), value, line));
+            }
+        }
+    }
+}
+
+/// A dotted key with the whitespace around its dots and the quotes around its parts removed
+fn normalize_key(key: &str) -> String {
+    key.split('.')
+        .map(|part| part.trim().trim_matches(['"', '\'']))
+        .collect::<Vec<_>>()
+        .join(".")
+}
+
+#[derive(Clone)]
+struct Cursor<'a> {
+    source: &'a str,
+    pos: usize,
+    line: usize,
+}
+
+impl<'a> Cursor<'a> {
+    fn peek(&self) -> Option<u8> {
+        self.source.as_bytes().get(self.pos).copied()
+    }
+
+    fn rest(&self) -> &'a str {
+        &self.source[self.pos..]
+    }
+
+    fn error(&self, kind: ErrorKind) -> PyprojectError {
+        PyprojectError {
+            line: self.line,
+            kind,
+        }
+    }
+
+    /// Skip whitespace and comments, and newlines too if `newlines` is set
+    fn skip_trivia(&mut self, newlines: bool) {
+        while let Some(byte) = self.peek() {
+            match byte {
+                b' ' | b'\t' | b'\r' => self.pos += 1,
+                b'\n' if newlines => {
+                    self.pos += 1;
+                    self.line += 1;
+                }
+                b'#' => {
+                    self.pos = self
+                        .rest()
+                        .find('\n')
+                        .map_or(self.source.len(), |end| self.pos + end)
+                }
+                _ => break,
+            }
+        }
+    }
+
+    /// Advance over `len` bytes, counting the newlines among them
+    fn advance(&mut self, len: usize) {
+        self.line += self.rest()[..len].matches('\n').count();
+        self.pos += len;
+    }
+
+    fn array(&mut self) -> Result<Array, PyprojectError> {
+        let start = self.pos;
+        if self.peek() != Some(b'[') {
+            return Err(self.error(ErrorKind::NotAnArray));
+        }
+        self.pos += 1;
+        let mut entries = Vec::new();
+        loop {
+            self.skip_trivia(true);
+            match self.peek() {
+                None => return Err(self.error(ErrorKind::Unterminated)),
+                Some(b']') => {
+                    self.pos += 1;
+                    return Ok(Array {
+                        span: start..self.pos,
+                        entries,
+                    });
+                }
+                Some(b',') => self.pos += 1,
+                Some(b'"' | b'\'') => {
+                    let (line, start) = (self.line, self.pos);
+                    let value = self.string()?;
+                    entries.push(Entry {
+                        value,
+                        line,
+                        span: start..self.pos,
+                    });
+                }
+                Some(_) => return Err(self.error(ErrorKind::NotAString)),
+            }
+        }
+    }
+
+    fn string(&mut self) -> Result<String, PyprojectError> {
+        let quote = &self.rest()[..1];
+        let multiline = self.rest().starts_with(&quote.repeat(3));
+        let delimiter = if multiline {
+            quote.repeat(3)
+        } else {
+            quote.to_string()
+        };
+        let literal = quote == "'";
+        self.pos += delimiter.len();
+        if multiline && self.rest().starts_with('\n') {
+            self.advance(1);
+        } else if multiline && self.rest().starts_with("\r\n") {
+            self.advance(2);
+        }
+
+        let mut value = String::new();
+        loop {
+            let rest = self.rest();
+            let stop = if literal {
+                rest.find(delimiter.as_str())
+            } else {
+                rest.find([quote.as_bytes()[0] as char, '\\'])
+            };
+            let Some(stop) = stop else {
+                return Err(self.error(ErrorKind::Unterminated));
+            };
+            if !multiline && rest[..stop].contains('\n') {
+                return Err(self.error(ErrorKind::Unterminated));
+            }
+            value.push_str(&rest[..stop]);
+            self.advance(stop);
+            if self.rest().starts_with('\\') {
+                self.escape(&mut value, multiline)?;
+            } else if self.rest().starts_with(delimiter.as_str()) {
+                self.pos += delimiter.len();
+                return Ok(value);
+            } else {
+                // a lone quote inside a multi-line basic string
+                value.push_str(quote);
+                self.pos += 1;
+            }
+        }
+    }
+
+    fn escape(&mut self, value: &mut String, multiline: bool) -> Result<(), PyprojectError> {
+        let rest = &self.rest()[1..];
+        let (resolved, len) = match rest.chars().next() {
+            Some('b') => ('\u{8}', 1),
+            Some('t') => ('\t', 1),
+            Some('n') => ('\n', 1),
+            Some('f') => ('\u{c}', 1),
+            Some('r') => ('\r', 1),
+            Some('e') => ('\u{1b}', 1),
+            Some('"') => ('"', 1),
+            Some('\\') => ('\\', 1),
+            Some(unicode @ ('u' | 'U')) => {
+                let digits = if unicode == 'u' { 4 } else { 8 };
+                let resolved = rest
+                    .get(1..=digits)
+                    .and_then(|hex| u32::from_str_radix(hex, 16).ok())
+                    .and_then(char::from_u32)
+                    .ok_or_else(|| self.error(ErrorKind::InvalidEscape))?;
+                (resolved, 1 + digits)
+            }
+            Some(' ' | '\t' | '\r' | '\n') if multiline => {
+                // a line ending backslash trims all whitespace up to the next visible character
+                self.pos += 1;
+                let skipped = self.rest().len() - self.rest().trim_start().len();
+                self.advance(skipped);
+                return Ok(());
+            }
+            _ => return Err(self.error(ErrorKind::InvalidEscape)),
+        };
+        value.push(resolved);
+        self.pos += 1 + len;
+        Ok(())
+    }
+
+    /// Skip over the value of a key this module does not read
+    fn skip_value(&mut self) -> Result<(), PyprojectError> {
+        let mut depth = 0usize;
+        loop {
+            match self.peek() {
+                None if depth == 0 => return Ok(()),
+                None => return Err(self.error(ErrorKind::Unterminated)),
+                Some(b'"' | b'\'') => {
+                    self.string()?;
+                }
+                Some(b'[' | b'{') => {
+                    depth += 1;
+                    self.pos += 1;
+                }
+                Some(b']' | b'}') => {
+                    depth = depth.saturating_sub(1);
+                    self.pos += 1;
+                }
+                Some(b'\n') if depth == 0 => return Ok(()),
+                Some(b'\n' | b'#') => self.skip_trivia(true),
+                Some(_) => self.pos += self.rest().chars().next().map_or(1, char::len_utf8),
+            }
+        }
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn reads_the_values_of_a_table() {
+        let source = "[project]\nname = 'example'\n\n\
+                      [tool.trove]\nrequired = [\n  'Typing :: Typed',\n]\nmax = 3\n\
+                      [tool.trove.levels]\n\"TC005\" = \"deny\"  # strict\n\
+                      [tool.trovex]\nother = 'x'\n";
+        let values = values(source, "tool.trove").unwrap();
+        let keys: Vec<(&str, usize)> = values
+            .iter()
+            .map(|(key, _, line)| (key.as_str(), *line))
+            .collect();
+        assert_eq!(keys, [("required", 5), ("max", 8), ("levels.TC005", 10)]);
+        assert!(matches!(&values[0].1, Value::Array(array) if array.entries.len() == 1));
+        assert_eq!(values[1].1, Value::Other);
+        assert_eq!(values[2].1, Value::String("deny".to_string()));
+    }
+
+    #[test]
+    fn arrays_round_trip() {
+        let values = ["Typing :: Typed", "it's \"quoted\"\\", "tab\tand\u{7}bell"];
+        for style in [
+            ArrayStyle::default(),
+            ArrayStyle::default().literal(true).indent("\t"),
+            ArrayStyle::default().width(200),
+        ] {
+            let source = format!("[project]\nclassifiers = {}\n", toml_array(values, &style));
+            let array = classifiers(&source).unwrap().unwrap();
+            let read: Vec<&str> = array.entries.iter().map(|e| e.value.as_str()).collect();
+            assert_eq!(read, values, "{source}");
+        }
+        assert_eq!(
+            toml_array(values, &ArrayStyle::default().literal(true).width(20)),
+            "[\n    'Typing :: Typed',\n    \"it's \\\"quoted\\\"\\\\\",\n    \
+             \"tab\\u0009and\\u0007bell\",\n]"
+        );
+        assert_eq!(toml_array([""; 0], &ArrayStyle::default()), "[]");
+    }
+
+    #[cfg(feature = "natural-language")]
+    const PYPROJECT: &str = r#"# a comment with classifiers = ["nope"]
+[build-system]
+requires = ["setuptools"]
+classifiers = ["Not :: This One"]
+
+[project]
+name = "example"
+description = """
+[tool.other]
+classifiers = "nor this"
+"""
+dependencies = [
+    "requests[socks]>=2",  # ] in a comment
+]
+classifiers = [ # trailing comment
+    "Development Status :: 4 - Beta",
+    'Topic :: Software Development',
+    """Typing :: Typed""",
+    "Natural Language :: Fr\u0065nch",
+]
+
+[tool.trove]
+classifiers = ["Not :: This One Either"]
+"#;
+
+    #[test]
+    #[cfg(feature = "natural-language")]
+    fn reads_only_project_classifiers() {
+        let array = classifiers(PYPROJECT).unwrap().unwrap();
+        let values: Vec<&str> = array.entries.iter().map(|e| e.value.as_str()).collect();
+        assert_eq!(
+            values,
+            [
+                "Development Status :: 4 - Beta",
+                "Topic :: Software Development",
+                "Typing :: Typed",
+                "Natural Language :: French",
+            ]
+        );
+        let lines: Vec<usize> = array.entries.iter().map(|e| e.line).collect();
+        assert_eq!(lines, [16, 17, 18, 19]);
+        assert!(PYPROJECT[array.span.clone()].starts_with("[ # trailing"));
+        assert!(PYPROJECT[array.span].ends_with("\",\n]"));
+        assert_eq!(
+            array.entries[3].classifier(),
+            Ok(Classifier::NaturalLanguage__French)
+        );
+    }
+
+    #[test]
+    fn reads_dotted_keys() {
+        let array = classifiers("project.classifiers = ['Typing :: Typed']\n")
+            .unwrap()
+            .unwrap();
+        assert_eq!(array.entries[0].value, "Typing :: Typed");
+        assert_eq!(array.entries[0].span, 23..40);
+    }
+
+    #[test]
+    fn missing_array_is_none() {
+        assert_eq!(classifiers("[project]\nname = \"example\"\n"), Ok(None));
+        assert_eq!(classifiers(""), Ok(None));
+    }
+
+    #[test]
+    fn malformed_arrays_are_errors() {
+        let error = |source| classifiers(source).unwrap_err();
+        assert_eq!(
+            error("[project]\nclassifiers = \"Typing :: Typed\"\n").kind,
+            ErrorKind::NotAnArray
+        );
+        assert_eq!(
+            error("[project]\nclassifiers = [\n  1,\n]\n"),
+            PyprojectError {
+                line: 3,
+                kind: ErrorKind::NotAString
+            }
+        );
+        assert_eq!(
+            error("[project]\nclassifiers = [\"Typing\n").kind,
+            ErrorKind::Unterminated
+        );
+        assert_eq!(
+            error("[project]\nclassifiers = [\"\\q\"]\n").kind,
+            ErrorKind::InvalidEscape
+        );
+    }
+}
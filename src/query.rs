@@ -0,0 +1,468 @@
+//! A small filter language over classifiers, for policies kept in configuration files.
+//!
+//! A query is a boolean expression of terms. A `field:value` term tests one property of a
+//! classifier, and a bare term matches like a term of `search`:
+//!
+//! * `category:License` - the first segment is the value
+//! * `prefix:"Framework :: Django"` - the classifier is the value or nested below it
+//! * `leaf:"MIT License"` - the last segment is the value
+//! * `osi:true` - the classifier is an OSI approved license
+//! * `deprecated:false` - pypi.org still accepts the classifier
+//!
+//! Values are compared ignoring ASCII case, and those with spaces are written in double quotes.
+//! Terms combine with `AND`, `OR`, `NOT` and parentheses; `AND` binds tighter than `OR` and is
+//! implied between adjacent terms. Queries can also be built in code from the same terms.
+//!
+//! Queries are evaluated against strings, so they match `Classifier`s, `DynamicClassifier`s and
+//! the strings of deprecated classifiers alike.
+//!
+//! # Examples
+//!
+#![cfg_attr(not(feature = "license"), doc = "```ignore")]
+#![cfg_attr(feature = "license", doc = "```")]
+//! use trove_classifiers::query::Query;
+//! use trove_classifiers::Classifier;
+//!
+//! let query: Query = "category:License AND osi:true AND NOT leaf:\"MIT License\"".parse()?;
+//! assert!(query.matches(Classifier::License__OSIApproved__ApacheSoftwareLicense));
+//! assert!(!query.matches(Classifier::License__OSIApproved__MITLicense));
+//!
+//! let built = Query::category("License")
+//!     .and(Query::osi(true))
+//!     .and(!Query::leaf("MIT License"));
+//! assert_eq!(built, query);
+//! # Ok::<(), trove_classifiers::query::QueryError>(())
+//! ```
+
+use alloc::boxed::Box;
+use alloc::string::{String, ToString};
+use alloc::vec::Vec;
+use core::fmt;
+use core::ops::Not;
+use core::str::FromStr;
+
+use crate::search::term_score;
+use crate::{deprecation, Classifier};
+
+/// A parsed or built query
+#[derive(Clone, Debug, Eq, Hash, PartialEq)]
+#[non_exhaustive]
+pub enum Query {
+    /// The first segment is the value
+    Category(String),
+    /// The classifier is the value or nested below it
+    Prefix(String),
+    /// The last segment is the value
+    Leaf(String),
+    /// Whether the classifier is an OSI approved license
+    Osi(bool),
+    /// Whether pypi.org no longer accepts the classifier
+    Deprecated(bool),
+    /// The value matches like a term of `search`
+    Text(String),
+    And(Box<Query>, Box<Query>),
+    Or(Box<Query>, Box<Query>),
+    Not(Box<Query>),
+}
+
+/// The error returned when a query cannot be parsed
+#[derive(Clone, Debug, Eq, PartialEq)]
+#[non_exhaustive]
+pub enum QueryError {
+    /// A `field:value` term names a field that does not exist
+    UnknownField(String),
+    /// A boolean field has a value other than `true` or `false`
+    NotBoolean { field: String, value: String },
+    /// The query is malformed at a byte offset
+    Syntax {
+        offset: usize,
+        expected: &'static str,
+    },
+}
+
+impl fmt::Display for QueryError {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        match self {
+            QueryError::UnknownField(field) => write!(
+                f,
+                "unknown field {field:?}, expected category, prefix, leaf, osi or deprecated"
+            ),
+            QueryError::NotBoolean { field, value } => {
+                write!(f, "{field} must be true or false, not {value:?}")
+            }
+            QueryError::Syntax { offset, expected } => {
+                write!(f, "expected {expected} at offset {offset}")
+            }
+        }
+    }
+}
+
+#[cfg(feature = "std")]
+impl std::error::Error for QueryError {}
+
+impl Query {
+    pub fn category(name: &str) -> Self {
+        Query::Category(name.to_string())
+    }
+
+    pub fn prefix(prefix: &str) -> Self {
+        Query::Prefix(prefix.to_string())
+    }
+
+    pub fn leaf(leaf: &str) -> Self {
+        Query::Leaf(leaf.to_string())
+    }
+
+    pub fn osi(osi: bool) -> Self {
+        Query::Osi(osi)
+    }
+
+    pub fn deprecated(deprecated: bool) -> Self {
+        Query::Deprecated(deprecated)
+    }
+
+    pub fn text(text: &str) -> Self {
+        Query::Text(text.to_string())
+    }
+
+    /// A query matching what both this and `other` match
+    pub fn and(self, other: Query) -> Self {
+        Query::And(Box::new(self), Box::new(other))
+    }
+
+    /// A query matching what either this or `other` matches
+    pub fn or(self, other: Query) -> Self {
+        Query::Or(Box::new(self), Box::new(other))
+    }
+
+    /// Whether a classifier matches this query
+    pub fn matches(&self, classifier: impl AsRef<str>) -> bool {
+        self.matches_str(classifier.as_ref())
+    }
+
+    /// The classifiers of the dataset matching this query, in canonical order
+    pub fn classifiers(&self) -> impl Iterator<Item = Classifier> + '_ {
+        Classifier::ALL
+            .iter()
+            .copied()
+            .filter(move |classifier| self.matches(classifier))
+    }
+
+    fn matches_str(&self, classifier: &str) -> bool {
+        match self {
+            Query::Category(name) => classifier
+                .split(" :: ")
+                .next()
+                .is_some_and(|category| category.eq_ignore_ascii_case(name)),
+            Query::Prefix(prefix) => {
+                let (string, prefix) = (classifier.as_bytes(), prefix.as_bytes());
+                string
+                    .get(..prefix.len())
+                    .is_some_and(|start| start.eq_ignore_ascii_case(prefix))
+                    && (string.len() == prefix.len() || string[prefix.len()..].starts_with(b" :: "))
+            }
+            Query::Leaf(leaf) => classifier
+                .rsplit(" :: ")
+                .next()
+                .is_some_and(|last| last.eq_ignore_ascii_case(leaf)),
+            Query::Osi(osi) => classifier.starts_with("License :: OSI Approved :: ") == *osi,
+            Query::Deprecated(deprecated) => deprecation(classifier).is_some() == *deprecated,
+            Query::Text(text) => text.is_empty() || term_score(classifier, text).is_some(),
+            Query::And(left, right) => {
+                left.matches_str(classifier) && right.matches_str(classifier)
+            }
+            Query::Or(left, right) => left.matches_str(classifier) || right.matches_str(classifier),
+            Query::Not(query) => !query.matches_str(classifier),
+        }
+    }
+}
+
+/// A query matching what this one does not
+impl Not for Query {
+    type Output = Query;
+
+    fn not(self) -> Self::Output {
+        Query::Not(Box::new(self))
+    }
+}
+
+impl FromStr for Query {
+    type Err = QueryError;
+
+    fn from_str(s: &str) -> Result<Self, Self::Err> {
+        let mut parser = Parser {
+            tokens: tokens(s)?,
+            position: 0,
+            end: s.len(),
+        };
+        let query = parser.or()?;
+        match parser.tokens.get(parser.position) {
+            None => Ok(query),
+            Some((offset, _)) => Err(QueryError::Syntax {
+                offset: *offset,
+                expected: "AND, OR or the end of the query",
+            }),
+        }
+    }
+}
+
+#[derive(Clone, Debug, Eq, PartialEq)]
+enum Token {
+    Open,
+    Close,
+    And,
+    Or,
+    Not,
+    /// A term, with the field it names, if any
+    Term(Option<String>, String),
+}
+
+/// Split a query into tokens, each with its byte offset
+fn tokens(s: &str) -> Result<Vec<(usize, Token)>, QueryError> {
+    let mut tokens = Vec::new();
+    let mut chars = s.char_indices().peekable();
+    while let Some(&(offset, c)) = chars.peek() {
+        match c {
+            c if c.is_whitespace() => {
+                chars.next();
+            }
+            '(' | ')' => {
+                chars.next();
+                tokens.push((offset, if c == '(' { Token::Open } else { Token::Close }));
+            }
+            _ => {
+                let mut field = None;
+                let mut value = String::new();
+                let mut quoted = false;
+                while let Some(&(at, c)) = chars.peek() {
+                    match c {
+                        '"' if value.is_empty() && !quoted => {
+                            chars.next();
+                            value = quoted_string(&mut chars, at)?;
+                            quoted = true;
+                        }
+                        ':' if field.is_none() && !quoted && !value.is_empty() => {
+                            chars.next();
+                            field = Some(core::mem::take(&mut value));
+                        }
+                        c if c.is_whitespace() || c == '(' || c == ')' => break,
+                        _ if quoted => {
+                            return Err(QueryError::Syntax {
+                                offset: at,
+                                expected: "a space after a quoted value",
+                            })
+                        }
+                        _ => {
+                            chars.next();
+                            value.push(c);
+                        }
+                    }
+                }
+                let token = match (&field, value.as_str(), quoted) {
+                    (None, "AND", false) => Token::And,
+                    (None, "OR", false) => Token::Or,
+                    (None, "NOT", false) => Token::Not,
+                    (Some(_), "", false) => {
+                        return Err(QueryError::Syntax {
+                            offset: chars.peek().map_or(s.len(), |&(at, _)| at),
+                            expected: "a value",
+                        })
+                    }
+                    _ => Token::Term(field, value),
+                };
+                tokens.push((offset, token));
+            }
+        }
+    }
+    Ok(tokens)
+}
+
+/// Read the rest of a double quoted string whose opening quote was at `start`
+fn quoted_string(
+    chars: &mut core::iter::Peekable<core::str::CharIndices<'_>>,
+    start: usize,
+) -> Result<String, QueryError> {
+    let mut value = String::new();
+    while let Some((_, c)) = chars.next() {
+        match c {
+            '"' => return Ok(value),
+            '\\' => match chars.next() {
+                Some((_, escaped)) => value.push(escaped),
+                None => break,
+            },
+            _ => value.push(c),
+        }
+    }
+    Err(QueryError::Syntax {
+        offset: start,
+        expected: "a closing quote",
+    })
+}
+
+struct Parser {
+    tokens: Vec<(usize, Token)>,
+    position: usize,
+    /// The length of the query, which is the offset of its end
+    end: usize,
+}
+
+impl Parser {
+    fn peek(&self) -> Option<&Token> {
+        self.tokens.get(self.position).map(|(_, token)| token)
+    }
+
+    fn offset(&self) -> usize {
+        self.tokens
+            .get(self.position)
+            .map_or(self.end, |(offset, _)| *offset)
+    }
+
+    fn or(&mut self) -> Result<Query, QueryError> {
+        let mut query = self.and()?;
+        while self.peek() == Some(&Token::Or) {
+            self.position += 1;
+            query = query.or(self.and()?);
+        }
+        Ok(query)
+    }
+
+    fn and(&mut self) -> Result<Query, QueryError> {
+        let mut query = self.unary()?;
+        loop {
+            match self.peek() {
+                Some(Token::And) => self.position += 1,
+                Some(Token::Not | Token::Open | Token::Term(..)) => {}
+                _ => return Ok(query),
+            }
+            query = query.and(self.unary()?);
+        }
+    }
+
+    fn unary(&mut self) -> Result<Query, QueryError> {
+        let offset = self.offset();
+        let token = self.peek().cloned();
+        self.position += 1;
+        match token {
+            Some(Token::Not) => Ok(!self.unary()?),
+            Some(Token::Open) => {
+                let query = self.or()?;
+                if self.peek() != Some(&Token::Close) {
+                    return Err(QueryError::Syntax {
+                        offset: self.offset(),
+                        expected: "a closing parenthesis",
+                    });
+                }
+                self.position += 1;
+                Ok(query)
+            }
+            Some(Token::Term(field, value)) => term(field, value),
+            _ => Err(QueryError::Syntax {
+                offset,
+                expected: "a term, NOT or an opening parenthesis",
+            }),
+        }
+    }
+}
+
+/// The query of a single term
+fn term(field: Option<String>, value: String) -> Result<Query, QueryError> {
+    let Some(field) = field else {
+        return Ok(Query::Text(value));
+    };
+    let boolean = |field: String, value: String| match value.as_str() {
+        "true" => Ok(true),
+        "false" => Ok(false),
+        _ => Err(QueryError::NotBoolean { field, value }),
+    };
+    match field.as_str() {
+        "category" => Ok(Query::Category(value)),
+        "prefix" => Ok(Query::Prefix(value)),
+        "leaf" => Ok(Query::Leaf(value)),
+        "osi" => boolean(field, value).map(Query::Osi),
+        "deprecated" => boolean(field, value).map(Query::Deprecated),
+        _ => Err(QueryError::UnknownField(field)),
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    fn parse(s: &str) -> Result<Query, QueryError> {
+        s.parse()
+    }
+
+    #[test]
+    fn and_binds_tighter_than_or() {
+        assert_eq!(
+            parse("typed OR category:License osi:true").unwrap(),
+            Query::text("typed").or(Query::category("License").and(Query::osi(true)))
+        );
+        assert_eq!(
+            parse("NOT (a OR b) AND c").unwrap(),
+            (!Query::text("a").or(Query::text("b"))).and(Query::text("c"))
+        );
+    }
+
+    #[test]
+    fn values_may_be_quoted() {
+        assert_eq!(
+            parse(r#"prefix:"Framework :: Django" "C\"" leaf:Typed"#).unwrap(),
+            Query::prefix("Framework :: Django")
+                .and(Query::text("C\""))
+                .and(Query::leaf("Typed"))
+        );
+    }
+
+    #[test]
+    fn malformed_queries_are_located() {
+        let syntax = |s: &str| match parse(s) {
+            Err(QueryError::Syntax { offset, .. }) => offset,
+            other => panic!("{s:?} parsed as {other:?}"),
+        };
+        assert_eq!(syntax(""), 0);
+        assert_eq!(syntax("a AND"), 5);
+        assert_eq!(syntax("(a OR b"), 7);
+        assert_eq!(syntax("a )"), 2);
+        assert_eq!(syntax("leaf:\"Typed"), 5);
+        assert_eq!(syntax("leaf: Typed"), 5);
+        assert_eq!(syntax("\"a\"b"), 3);
+        assert_eq!(
+            parse("kind:library"),
+            Err(QueryError::UnknownField("kind".to_string()))
+        );
+        assert!(matches!(
+            parse("osi:yes"),
+            Err(QueryError::NotBoolean { .. })
+        ));
+    }
+
+    #[test]
+    #[cfg(feature = "all-categories")]
+    fn queries_select_classifiers() {
+        let select = |s: &str| parse(s).unwrap().classifiers().collect::<Vec<_>>();
+        assert_eq!(
+            select("category:typing"),
+            [Classifier::Typing__StubsOnly, Classifier::Typing__Typed]
+        );
+        assert!(select("prefix:\"framework :: django\"")
+            .iter()
+            .all(|c| c.as_str().starts_with("Framework :: Django")));
+        assert!(
+            !select("prefix:\"Framework :: Django\"").contains(&Classifier::Framework__DjangoCMS)
+        );
+        assert_eq!(
+            select("osi:true leaf:\"mit license\""),
+            [Classifier::License__OSIApproved__MITLicense]
+        );
+        assert_eq!(select("deprecated:true"), []);
+        assert_eq!(select("deprecated:false").len(), Classifier::ALL.len());
+    }
+
+    #[test]
+    fn deprecated_strings_match() {
+        let query = parse("deprecated:true category:\"Natural Language\"").unwrap();
+        assert!(query.matches("Natural Language :: Ukranian"));
+        assert!(!query.matches("Natural Language :: Ukrainian"));
+    }
+}
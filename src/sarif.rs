@@ -0,0 +1,134 @@
+//! SARIF logs of lint findings.
+//!
+//! [SARIF](https://docs.oasis-open.org/sarif/sarif/v2.1.0/sarif-v2.1.0.html) is the format
+//! GitHub code scanning and other static analysis dashboards ingest. Each `lint::Rule` is listed
+//! as a reporting descriptor whose id is the rule's stable code, so results can be matched to
+//! rules across runs, and whose description and help come from the rule's `Explanation`.
+//!
+//! # Examples
+//!
+//! ```
+//! use trove_classifiers::lint::{lint, Declared};
+//! use trove_classifiers::sarif::to_sarif;
+//!
+//! let findings = lint(&[Declared::new("Typing :: Sometimes").line(12)]);
+//! let log = to_sarif([("pyproject.toml", findings.as_slice())]);
+//! assert!(log.contains(r#""ruleId":"TC001""#));
+//! assert!(log.contains(r#""startLine":12"#));
+//! ```
+
+use alloc::string::String;
+use core::fmt::Write;
+
+use crate::json::push_string;
+use crate::lint::{Finding, Rule, Severity};
+
+/// The URI of the SARIF 2.1.0 schema
+pub const SCHEMA: &str = "https://json.schemastore.org/sarif-2.1.0.json";
+
+/// A SARIF 2.1.0 log with one run holding the findings of each file
+///
+/// Files are given by the URI of their artifact, usually a path relative to the repository root.
+/// The log is compact JSON.
+pub fn to_sarif<'a, I>(files: I) -> String
+where
+    I: IntoIterator<Item = (&'a str, &'a [Finding])>,
+{
+    let mut out = String::new();
+    out.push_str(r#"{"$schema":"#);
+    push_string(&mut out, SCHEMA);
+    out.push_str(r#","version":"2.1.0","runs":[{"tool":{"driver":{"name":"trove-classifiers","#);
+    out.push_str(r#""version":"#);
+    push_string(&mut out, env!("CARGO_PKG_VERSION"));
+    out.push_str(r#","informationUri":"#);
+    push_string(&mut out, env!("CARGO_PKG_REPOSITORY"));
+    out.push_str(r#","rules":["#);
+    for (index, rule) in Rule::ALL.iter().enumerate() {
+        if index > 0 {
+            out.push(',');
+        }
+        out.push_str(r#"{"id":"#);
+        push_string(&mut out, rule.code());
+        out.push_str(r#","name":"#);
+        push_string(&mut out, rule.name());
+        out.push_str(r#","shortDescription":{"text":"#);
+        push_string(&mut out, rule.summary());
+        out.push_str(r#"},"fullDescription":{"text":"#);
+        push_string(&mut out, rule.explanation().rationale);
+        out.push_str(r#"},"help":{"text":"#);
+        push_string(&mut out, rule.explanation().fix);
+        out.push_str(r#"},"defaultConfiguration":{"level":"#);
+        push_string(&mut out, level(rule.severity()));
+        out.push_str("}}");
+    }
+    out.push_str(r#"]}},"results":["#);
+    let mut first = true;
+    for (uri, findings) in files {
+        for finding in findings {
+            if !first {
+                out.push(',');
+            }
+            first = false;
+            push_result(&mut out, uri, finding);
+        }
+    }
+    out.push_str("]}]}");
+    out
+}
+
+fn push_result(out: &mut String, uri: &str, finding: &Finding) {
+    out.push_str(r#"{"ruleId":"#);
+    push_string(out, finding.rule.code());
+    if let Some(index) = Rule::ALL.iter().position(|rule| *rule == finding.rule) {
+        write!(out, r#","ruleIndex":{index}"#).unwrap();
+    }
+    out.push_str(r#","level":"#);
+    push_string(out, level(finding.severity));
+    out.push_str(r#","message":{"text":"#);
+    push_string(out, &finding.message);
+    out.push_str(r#"},"locations":[{"physicalLocation":{"artifactLocation":{"uri":"#);
+    push_string(out, uri);
+    out.push('}');
+    if let Some(line) = finding.line {
+        write!(out, r#","region":{{"startLine":{line}}}"#).unwrap();
+    }
+    out.push_str("}}]}");
+}
+
+/// The SARIF level of a severity
+fn level(severity: Severity) -> &'static str {
+    match severity {
+        Severity::Warning => "warning",
+        Severity::Error => "error",
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::lint::{lint, Declared};
+
+    #[test]
+    fn empty_logs_list_every_rule() {
+        let log = to_sarif([]);
+        assert!(log.starts_with(r#"{"$schema":"https://json.schemastore.org/sarif-2.1.0.json""#));
+        for rule in Rule::ALL {
+            assert!(log.contains(&alloc::format!(r#"{{"id":"{}""#, rule.code())));
+        }
+        assert!(log.ends_with(r#""results":[]}]}"#));
+    }
+
+    #[test]
+    fn results_locate_findings() {
+        let findings = lint(&[
+            Declared::new("Typing :: \"Sometimes\"").line(3),
+            Declared::new("Private :: Mine"),
+        ]);
+        let log = to_sarif([("a\\pyproject.toml", findings.as_slice())]);
+        let results = &log[log.find(r#""results""#).unwrap()..];
+        assert_eq!(
+            results,
+            r#""results":[{"ruleId":"TC001","ruleIndex":0,"level":"error","message":{"text":"\"Typing :: \\\"Sometimes\\\"\" is not a known classifier"},"locations":[{"physicalLocation":{"artifactLocation":{"uri":"a\\pyproject.toml"},"region":{"startLine":3}}}]},{"ruleId":"TC004","ruleIndex":3,"level":"warning","message":{"text":"\"Private :: Mine\" is private, pypi.org will refuse to upload the project"},"locations":[{"physicalLocation":{"artifactLocation":{"uri":"a\\pyproject.toml"}}}]}]}]}"#
+        );
+    }
+}
@@ -0,0 +1,542 @@
+//! Bulk extraction and validation of classifiers from core metadata files.
+//!
+//! A `Scanner` reads the `Classifier` headers of `METADATA` (wheels, `.dist-info`) and
+//! `PKG-INFO` (sdists, `.egg-info`) files and reports which are known to this crate. It is built
+//! for scanning mirrors and site-packages trees holding thousands of these files:
+//!
+//! * only the header block is read, never the long description that follows it
+//! * the line buffer is reused from one file to the next
+//! * known classifiers are stored as `Classifier` values, only unknown ones are copied
+//! * with the `memmap` feature, files can be memory mapped instead of read
+//!
+//! Build backends generating core metadata can write the headers with
+//! `write_metadata_classifiers`, or replace those of an existing document with
+//! `merge_metadata_classifiers`.
+//!
+//! # Examples
+//!
+#![cfg_attr(not(feature = "typing"), doc = "```ignore")]
+#![cfg_attr(feature = "typing", doc = "```")]
+//! use trove_classifiers::scanner::Scanner;
+//! use trove_classifiers::Classifier;
+//!
+//! let metadata = "Metadata-Version: 2.1\n\
+//!                 Name: example\n\
+//!                 Classifier: Typing :: Typed\n\
+//!                 Classifier: Typing :: Untyped\n\
+//!                 \n\
+//!                 Classifier: this is the description, not a header\n";
+//!
+//! let report = Scanner::new().scan_str(metadata);
+//! assert_eq!(report.classifiers, [Classifier::Typing__Typed]);
+//! assert_eq!(report.unknown[0].line, 4);
+//! assert_eq!(report.unknown[0].value, "Typing :: Untyped");
+//! ```
+
+use std::fs::{self, File};
+use std::io::{self, BufRead, BufReader, Write};
+use std::path::{Path, PathBuf};
+use std::str::FromStr;
+
+use crate::lint::Declared;
+use crate::Classifier;
+
+/// The file names holding core metadata
+pub const METADATA_FILE_NAMES: [&str; 2] = ["METADATA", "PKG-INFO"];
+
+/// The classifiers found in one metadata document
+#[derive(Clone, Debug, Default, Eq, PartialEq)]
+pub struct Report {
+    /// Known classifiers, in the order they were declared
+    pub classifiers: Vec<Classifier>,
+    /// Classifier headers whose value is not a known classifier
+    pub unknown: Vec<UnknownClassifier>,
+}
+
+impl Report {
+    /// Whether every declared classifier is known
+    pub fn is_valid(&self) -> bool {
+        self.unknown.is_empty()
+    }
+}
+
+/// A `Classifier` header with a value that is not a known classifier
+#[derive(Clone, Debug, Eq, PartialEq)]
+pub struct UnknownClassifier {
+    /// The 1-based line the header starts on
+    pub line: usize,
+    /// The header's value
+    pub value: String,
+}
+
+/// The outcome of scanning one file
+#[derive(Debug)]
+pub struct FileReport {
+    pub path: PathBuf,
+    pub report: io::Result<Report>,
+}
+
+/// Extracts and validates `Classifier` headers from core metadata
+#[derive(Debug, Default)]
+pub struct Scanner {
+    line: String,
+    #[cfg(feature = "memmap")]
+    memmap: bool,
+}
+
+impl Scanner {
+    pub fn new() -> Self {
+        Self::default()
+    }
+
+    /// Memory map files instead of reading them through a buffer
+    ///
+    /// Mapping is usually faster for large trees on local disks. A file that is modified while
+    /// it is mapped may produce an inconsistent report.
+    #[cfg(feature = "memmap")]
+    pub fn memmap(mut self, enabled: bool) -> Self {
+        self.memmap = enabled;
+        self
+    }
+
+    /// Scan a metadata document held in memory
+    pub fn scan_str(&mut self, contents: &str) -> Report {
+        let mut headers = Headers::default();
+        for line in contents.lines() {
+            if !headers.push(line) {
+                break;
+            }
+        }
+        headers.finish()
+    }
+
+    /// Scan a metadata document, reading no further than the end of its headers
+    pub fn scan_reader<R: BufRead>(&mut self, mut reader: R) -> io::Result<Report> {
+        let mut headers = Headers::default();
+        loop {
+            self.line.clear();
+            if reader.read_line(&mut self.line)? == 0 {
+                break;
+            }
+            let line = self.line.trim_end_matches(['\n', '\r']);
+            if !headers.push(line) {
+                break;
+            }
+        }
+        Ok(headers.finish())
+    }
+
+    /// Scan a metadata file
+    pub fn scan_file(&mut self, path: &Path) -> io::Result<Report> {
+        let file = File::open(path)?;
+        #[cfg(feature = "memmap")]
+        if self.memmap {
+            // SAFETY: the map is only read while this function runs, and a concurrent
+            // modification can at worst produce an inconsistent report, as documented on
+            // `Scanner::memmap`
+            let map = unsafe { memmap2::Mmap::map(&file)? };
+            let contents = header_block(&map);
+            let contents = std::str::from_utf8(contents)
+                .map_err(|err| io::Error::new(io::ErrorKind::InvalidData, err))?;
+            return Ok(self.scan_str(contents));
+        }
+        self.scan_reader(BufReader::new(file))
+    }
+
+    /// Every `Classifier` header of a metadata document held in memory, known or not, in order
+    ///
+    /// This is the input `lint::lint` expects.
+    pub fn declared_str(&mut self, contents: &str) -> Vec<Declared> {
+        let mut headers = Headers {
+            declared: Some(Vec::new()),
+            ..Headers::default()
+        };
+        for line in contents.lines() {
+            if !headers.push(line) {
+                break;
+            }
+        }
+        headers.flush();
+        headers.declared.unwrap_or_default()
+    }
+
+    /// Scan every metadata document of an iterator, such as files read from an archive
+    pub fn scan_all<'a, I, S>(&'a mut self, documents: I) -> impl Iterator<Item = Report> + 'a
+    where
+        I: IntoIterator<Item = S>,
+        I::IntoIter: 'a,
+        S: AsRef<str>,
+    {
+        documents
+            .into_iter()
+            .map(move |document| self.scan_str(document.as_ref()))
+    }
+
+    /// Scan every `METADATA` and `PKG-INFO` file below a directory
+    ///
+    /// Directories that cannot be read are reported with their error in place of a file, and
+    /// symbolic links are not followed.
+    pub fn scan_dir(&mut self, root: &Path) -> Vec<FileReport> {
+        let mut reports = Vec::new();
+        let mut pending = vec![root.to_path_buf()];
+        while let Some(dir) = pending.pop() {
+            let entries = match fs::read_dir(&dir) {
+                Ok(entries) => entries,
+                Err(err) => {
+                    reports.push(FileReport {
+                        path: dir,
+                        report: Err(err),
+                    });
+                    continue;
+                }
+            };
+            let mut files = Vec::new();
+            for entry in entries {
+                let entry = match entry {
+                    Ok(entry) => entry,
+                    Err(err) => {
+                        reports.push(FileReport {
+                            path: dir.clone(),
+                            report: Err(err),
+                        });
+                        continue;
+                    }
+                };
+                let path = entry.path();
+                match entry.file_type() {
+                    Ok(kind) if kind.is_dir() => pending.push(path),
+                    Ok(kind) if kind.is_file() && is_metadata_file(&path) => files.push(path),
+                    Ok(_) => {}
+                    Err(err) => reports.push(FileReport {
+                        path,
+                        report: Err(err),
+                    }),
+                }
+            }
+            files.sort();
+            for path in files {
+                let report = self.scan_file(&path);
+                reports.push(FileReport { path, report });
+            }
+        }
+        reports
+    }
+}
+
+/// Whether a path names a core metadata file
+pub fn is_metadata_file(path: &Path) -> bool {
+    path.file_name()
+        .and_then(|name| name.to_str())
+        .is_some_and(|name| METADATA_FILE_NAMES.contains(&name))
+}
+
+/// Write a `Classifier` header line per classifier, in canonical order and without duplicates
+pub fn write_metadata_classifiers<W: Write>(
+    mut writer: W,
+    classifiers: &[Classifier],
+) -> io::Result<()> {
+    for classifier in canonical(classifiers) {
+        writeln!(writer, "Classifier: {}", classifier.as_str())?;
+    }
+    Ok(())
+}
+
+/// A metadata document with its `Classifier` headers replaced by those of `classifiers`
+///
+/// The new headers, in canonical order, take the place of the first `Classifier` header, or end
+/// the header block if there was none. Every other header and the body are kept as they are, and
+/// the new lines end like the document's first line.
+pub fn merge_metadata_classifiers(metadata: &str, classifiers: &[Classifier]) -> String {
+    let newline = match metadata.find('\n') {
+        Some(end) if metadata[..end].ends_with('\r') => "\r\n",
+        _ => "\n",
+    };
+    let mut headers = String::new();
+    for classifier in canonical(classifiers) {
+        headers.push_str("Classifier: ");
+        headers.push_str(classifier.as_str());
+        headers.push_str(newline);
+    }
+
+    let mut merged = String::with_capacity(metadata.len() + headers.len());
+    let mut in_classifier = false;
+    let mut inserted = false;
+    let mut lines = metadata.split_inclusive('\n');
+    for line in lines.by_ref() {
+        if line.trim().is_empty() {
+            if !inserted {
+                merged.push_str(&headers);
+                inserted = true;
+            }
+            merged.push_str(line);
+            break;
+        }
+        if line.starts_with([' ', '\t']) {
+            if !in_classifier {
+                merged.push_str(line);
+            }
+            continue;
+        }
+        in_classifier = line
+            .split_once(':')
+            .is_some_and(|(name, _)| name.trim().eq_ignore_ascii_case("classifier"));
+        if !in_classifier {
+            merged.push_str(line);
+        } else if !inserted {
+            merged.push_str(&headers);
+            inserted = true;
+        }
+    }
+    if !inserted {
+        if !merged.is_empty() && !merged.ends_with('\n') {
+            merged.push_str(newline);
+        }
+        merged.push_str(&headers);
+    }
+    merged.extend(lines);
+    merged
+}
+
+/// Classifiers sorted into canonical order, without duplicates
+fn canonical(classifiers: &[Classifier]) -> Vec<Classifier> {
+    let mut sorted = classifiers.to_vec();
+    sorted.sort_unstable();
+    sorted.dedup();
+    sorted
+}
+
+/// The bytes of a document up to the blank line that ends its headers
+#[cfg(feature = "memmap")]
+fn header_block(contents: &[u8]) -> &[u8] {
+    let mut start = 0;
+    while start < contents.len() {
+        let end = contents[start..]
+            .iter()
+            .position(|&byte| byte == b'\n')
+            .map_or(contents.len(), |offset| start + offset + 1);
+        if contents[start..end]
+            .iter()
+            .all(|byte| byte.is_ascii_whitespace())
+        {
+            return &contents[..start];
+        }
+        start = end;
+    }
+    contents
+}
+
+/// The header parsing state of one document
+#[derive(Default)]
+struct Headers {
+    report: Report,
+    line: usize,
+    /// The line of a `Classifier` header that may still be continued on the next line
+    pending: Option<usize>,
+    /// The value of the pending header, reused from one header to the next
+    value: String,
+    /// Every header as declared, when they are collected
+    declared: Option<Vec<Declared>>,
+}
+
+impl Headers {
+    /// Consume one line, returning false once the headers have ended
+    fn push(&mut self, line: &str) -> bool {
+        self.line += 1;
+        if line.trim().is_empty() {
+            return false;
+        }
+        if line.starts_with([' ', '\t']) {
+            // a folded continuation of the previous header
+            if self.pending.is_some() {
+                self.value.push(' ');
+                self.value.push_str(line.trim());
+            }
+            return true;
+        }
+        self.flush();
+        if let Some((name, value)) = line.split_once(':') {
+            if name.trim().eq_ignore_ascii_case("classifier") {
+                self.pending = Some(self.line);
+                self.value.clear();
+                self.value.push_str(value.trim());
+            }
+        }
+        true
+    }
+
+    fn flush(&mut self) {
+        if let Some(line) = self.pending.take() {
+            if let Some(declared) = &mut self.declared {
+                declared.push(Declared::new(&self.value).line(line));
+                return;
+            }
+            match Classifier::from_str(&self.value) {
+                Ok(classifier) => self.report.classifiers.push(classifier),
+                Err(_) => self.report.unknown.push(UnknownClassifier {
+                    line,
+                    value: self.value.clone(),
+                }),
+            }
+        }
+    }
+
+    fn finish(mut self) -> Report {
+        self.flush();
+        self.report
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    const METADATA: &str = "Metadata-Version: 2.1\r\n\
+        Name: example\r\n\
+        classifier: Development Status :: 4 - Beta\r\n\
+        Classifier: Topic :: Software Development ::\r\n\
+        \x20 Libraries\r\n\
+        Classifier:   Typing :: Typed  \r\n\
+        Classifier: Typing :: Sometimes\r\n\
+        Requires-Dist: requests\r\n\
+        \r\n\
+        Classifier: Typing :: Stubs Only\r\n";
+
+    #[cfg(feature = "all-categories")]
+    fn expected() -> Report {
+        Report {
+            classifiers: vec![
+                Classifier::DevelopmentStatus__4Beta,
+                Classifier::Topic__SoftwareDevelopment__Libraries,
+                Classifier::Typing__Typed,
+            ],
+            unknown: vec![UnknownClassifier {
+                line: 7,
+                value: "Typing :: Sometimes".to_string(),
+            }],
+        }
+    }
+
+    #[test]
+    #[cfg(feature = "all-categories")]
+    fn scans_headers_only() {
+        assert_eq!(Scanner::new().scan_str(METADATA), expected());
+    }
+
+    #[test]
+    fn collects_declarations() {
+        let declared = Scanner::new().declared_str(METADATA);
+        assert_eq!(
+            declared,
+            [
+                Declared::new("Development Status :: 4 - Beta").line(3),
+                Declared::new("Topic :: Software Development :: Libraries").line(4),
+                Declared::new("Typing :: Typed").line(6),
+                Declared::new("Typing :: Sometimes").line(7),
+            ]
+        );
+    }
+
+    #[test]
+    #[cfg(feature = "all-categories")]
+    fn reader_matches_str() {
+        let report = Scanner::new().scan_reader(METADATA.as_bytes()).unwrap();
+        assert_eq!(report, expected());
+    }
+
+    #[test]
+    #[cfg(feature = "all-categories")]
+    fn scans_directories() {
+        let root = std::env::temp_dir().join(format!("trove-scanner-{}", std::process::id()));
+        let dist_info = root.join("site-packages").join("example-1.0.dist-info");
+        fs::create_dir_all(&dist_info).unwrap();
+        fs::write(dist_info.join("METADATA"), METADATA).unwrap();
+        fs::write(dist_info.join("RECORD"), "Classifier: Typing :: Typed\n").unwrap();
+        fs::write(root.join("PKG-INFO"), "Name: other\n").unwrap();
+
+        let mut scanner = Scanner::new();
+        let mut reports = scanner.scan_dir(&root);
+        reports.sort_by(|a, b| a.path.cmp(&b.path));
+        fs::remove_dir_all(&root).unwrap();
+
+        assert_eq!(reports.len(), 2);
+        assert_eq!(reports[0].path, root.join("PKG-INFO"));
+        assert_eq!(reports[0].report.as_ref().unwrap(), &Report::default());
+        assert_eq!(reports[1].path, dist_info.join("METADATA"));
+        assert_eq!(reports[1].report.as_ref().unwrap(), &expected());
+    }
+
+    #[test]
+    #[cfg(feature = "all-categories")]
+    fn writes_classifier_headers() {
+        let mut written = Vec::new();
+        write_metadata_classifiers(
+            &mut written,
+            &[
+                Classifier::Typing__Typed,
+                Classifier::DevelopmentStatus__4Beta,
+                Classifier::Typing__Typed,
+            ],
+        )
+        .unwrap();
+        assert_eq!(
+            String::from_utf8(written).unwrap(),
+            "Classifier: Development Status :: 4 - Beta\nClassifier: Typing :: Typed\n"
+        );
+    }
+
+    #[test]
+    #[cfg(feature = "all-categories")]
+    fn merges_classifier_headers() {
+        let classifiers = [
+            Classifier::Typing__Typed,
+            Classifier::DevelopmentStatus__5ProductionStable,
+        ];
+        assert_eq!(
+            merge_metadata_classifiers(METADATA, &classifiers),
+            "Metadata-Version: 2.1\r\n\
+             Name: example\r\n\
+             Classifier: Development Status :: 5 - Production/Stable\r\n\
+             Classifier: Typing :: Typed\r\n\
+             Requires-Dist: requests\r\n\
+             \r\n\
+             Classifier: Typing :: Stubs Only\r\n"
+        );
+        assert_eq!(
+            merge_metadata_classifiers("Name: example", &classifiers[..1]),
+            "Name: example\nClassifier: Typing :: Typed\n"
+        );
+        assert_eq!(
+            merge_metadata_classifiers("Name: example\n\nbody\n", &[]),
+            "Name: example\n\nbody\n"
+        );
+        let merged = merge_metadata_classifiers("Name: example\n\nbody", &classifiers);
+        assert_eq!(
+            Scanner::new().scan_str(&merged).classifiers,
+            [
+                Classifier::DevelopmentStatus__5ProductionStable,
+                Classifier::Typing__Typed
+            ]
+        );
+        assert!(merged.ends_with("\n\nbody"));
+    }
+
+    #[test]
+    #[cfg(all(feature = "memmap", feature = "all-categories"))]
+    fn memmap_matches_reader() {
+        let path = std::env::temp_dir().join(format!("trove-memmap-{}", std::process::id()));
+        fs::write(&path, METADATA).unwrap();
+        let report = Scanner::new().memmap(true).scan_file(&path);
+        fs::remove_file(&path).unwrap();
+        assert_eq!(report.unwrap(), expected());
+    }
+
+    #[test]
+    #[cfg(feature = "memmap")]
+    fn header_block_stops_at_blank_line() {
+        assert_eq!(
+            header_block(b"A: b\r\nC: d\r\n\r\nbody"),
+            b"A: b\r\nC: d\r\n"
+        );
+        assert_eq!(header_block(b"A: b\n"), b"A: b\n");
+    }
+}
@@ -0,0 +1,225 @@
+#[cfg(feature = "alloc")]
+use alloc::vec::Vec;
+
+use crate::Classifier;
+
+/// Every classifier matching each whitespace-separated term of `query`, ignoring ASCII case
+///
+/// A term matches a classifier when it is a substring of its canonical string, or the acronym
+/// of consecutive words within one of its segments, such as `gpl` for "GNU General Public
+/// License". Unlike `complete`, this scans the whole dataset. Matches are yielded in canonical
+/// order, and an empty query matches every classifier; `search_ranked` orders them by relevance.
+///
+/// # Examples
+///
+#[cfg_attr(not(feature = "all-categories"), doc = "```ignore")]
+#[cfg_attr(feature = "all-categories", doc = "```")]
+/// use trove_classifiers::{search, Classifier};
+///
+/// let matches: Vec<Classifier> = search("typing typed").collect();
+/// assert_eq!(matches, [Classifier::Typing__Typed]);
+///
+/// assert!(search("python 3.12").any(|c| c == Classifier::ProgrammingLanguage__Python__3_12));
+/// ```
+pub fn search(query: &str) -> impl Iterator<Item = Classifier> + '_ {
+    Classifier::ALL
+        .iter()
+        .copied()
+        .filter(move |classifier| score(classifier.as_str(), query).is_some())
+}
+
+/// Every classifier whose last segment is `leaf`, ignoring ASCII case and surrounding whitespace
+///
+/// Short names are ambiguous: "Themes" is the last segment of classifiers of several frameworks and
+/// desktop environments.
+/// Matches are yielded in canonical order, so a tool can resolve input with a single match and
+/// offer the others for the user to choose from.
+///
+/// # Examples
+///
+#[cfg_attr(not(feature = "all-categories"), doc = "```ignore")]
+#[cfg_attr(feature = "all-categories", doc = "```")]
+/// use trove_classifiers::{by_leaf, Classifier};
+///
+/// let mit: Vec<Classifier> = by_leaf("mit license").collect();
+/// assert_eq!(mit, [Classifier::License__OSIApproved__MITLicense]);
+///
+/// assert!(by_leaf("Themes").count() > 1);
+/// ```
+pub fn by_leaf(leaf: &str) -> impl Iterator<Item = Classifier> + '_ {
+    let leaf = leaf.trim();
+    Classifier::ALL
+        .iter()
+        .copied()
+        .filter(move |classifier| classifier.leaf().eq_ignore_ascii_case(leaf))
+}
+
+/// A classifier matched by `search_ranked`, and how well it matched
+#[derive(Clone, Copy, Debug, Eq, PartialEq)]
+pub struct SearchMatch {
+    pub classifier: Classifier,
+    /// The sum of each term's score: 4 for a whole segment, 3 for the start of a word, 2 for an
+    /// acronym and 1 for any other substring
+    pub score: u32,
+}
+
+/// The classifiers matched by `search`, best first
+///
+/// Matches are ordered by descending score, then by the length of their canonical string, so
+/// the most specific match for a query comes before its more deeply nested relatives, and
+/// finally in canonical order.
+///
+/// # Examples
+///
+#[cfg_attr(not(feature = "all-categories"), doc = "```ignore")]
+#[cfg_attr(feature = "all-categories", doc = "```")]
+/// use trove_classifiers::{search_ranked, Classifier};
+///
+/// let matches = search_ranked("django");
+/// assert_eq!(matches[0].classifier, Classifier::Framework__Django);
+///
+/// let matches = search_ranked("gpl 3");
+/// assert!(matches.iter().any(|found| {
+///     found.classifier == Classifier::License__OSIApproved__GNUGeneralPublicLicensev3GPLv3
+/// }));
+/// ```
+#[cfg(feature = "alloc")]
+pub fn search_ranked(query: &str) -> Vec<SearchMatch> {
+    let mut matches: Vec<SearchMatch> = Classifier::ALL
+        .iter()
+        .filter_map(|&classifier| {
+            let score = score(classifier.as_str(), query)?;
+            Some(SearchMatch { classifier, score })
+        })
+        .collect();
+    matches.sort_by(|a, b| {
+        b.score
+            .cmp(&a.score)
+            .then(
+                a.classifier
+                    .as_str()
+                    .len()
+                    .cmp(&b.classifier.as_str().len()),
+            )
+            .then(a.classifier.cmp(&b.classifier))
+    });
+    matches
+}
+
+/// The score of `string` for every term of `query`, if they all match
+fn score(string: &str, query: &str) -> Option<u32> {
+    query
+        .split_whitespace()
+        .map(|term| term_score(string, term))
+        .sum()
+}
+
+pub(crate) fn term_score(string: &str, term: &str) -> Option<u32> {
+    let segments = || string.split(" :: ");
+    if segments().any(|segment| segment.eq_ignore_ascii_case(term)) {
+        return Some(4);
+    }
+    let (haystack, needle) = (string.as_bytes(), term.as_bytes());
+    let mut substring = false;
+    for (start, window) in haystack.windows(needle.len()).enumerate() {
+        if window.eq_ignore_ascii_case(needle) {
+            if start == 0 || !haystack[start - 1].is_ascii_alphanumeric() {
+                return Some(3);
+            }
+            substring = true;
+        }
+    }
+    if segments().any(|segment| is_acronym(segment, needle)) {
+        return Some(2);
+    }
+    substring.then_some(1)
+}
+
+/// Whether `acronym` is made of the initials of consecutive words of `segment`
+fn is_acronym(segment: &str, acronym: &[u8]) -> bool {
+    if acronym.len() < 2 {
+        return false;
+    }
+    let initials = || {
+        segment
+            .split(|c: char| !c.is_ascii_alphanumeric())
+            .filter_map(|word| word.bytes().next())
+    };
+    (0..initials().count()).any(|skip| {
+        let mut initials = initials().skip(skip);
+        acronym.iter().all(|letter| {
+            initials
+                .next()
+                .is_some_and(|initial| initial.eq_ignore_ascii_case(letter))
+        })
+    })
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    #[cfg(feature = "framework")]
+    fn leaves_are_shared_across_parents() {
+        let themes: Vec<Classifier> = by_leaf(" themes ").collect();
+        assert!(!themes.contains(&Classifier::Framework__Sphinx__Theme));
+        assert!(themes.len() > 1);
+        assert!(themes
+            .iter()
+            .all(|classifier| classifier.leaf() == "Themes"));
+        assert!(themes.windows(2).all(|pair| pair[0] < pair[1]));
+        assert_eq!(by_leaf("Framework").count(), 0);
+    }
+
+    #[test]
+    fn empty_query_matches_everything() {
+        assert_eq!(search("").count(), Classifier::ALL.len());
+        assert_eq!(search("   ").count(), Classifier::ALL.len());
+    }
+
+    #[test]
+    #[cfg(feature = "framework")]
+    fn every_term_must_match() {
+        let matches: Vec<Classifier> = search("FRAMEWORK django 5").collect();
+        assert!(!matches.is_empty());
+        for classifier in matches {
+            let string = classifier.as_str().to_ascii_lowercase();
+            assert!(string.contains("framework"));
+            assert!(string.contains("django"));
+            assert!(string.contains('5'));
+        }
+        assert_eq!(search("django flask").count(), 0);
+    }
+
+    #[test]
+    fn terms_are_scored_by_kind_of_match() {
+        assert_eq!(term_score("Typing :: Typed", "typed"), Some(4));
+        assert_eq!(term_score("Typing :: Stubs Only", "stub"), Some(3));
+        assert_eq!(term_score("Topic :: Software Development", "sd"), Some(2));
+        assert_eq!(term_score("Typing :: Typed", "yped"), Some(1));
+        assert_eq!(term_score("Typing :: Typed", "untyped"), None);
+    }
+
+    #[test]
+    fn acronyms_are_consecutive_initials() {
+        assert!(is_acronym("GNU General Public License (GPL)", b"gpl"));
+        assert!(is_acronym("GNU General Public License (GPL)", b"GGPL"));
+        assert!(!is_acronym("GNU General Public License (GPL)", b"ggl"));
+        assert!(!is_acronym("Django", b"d"));
+    }
+
+    #[test]
+    #[cfg(all(feature = "alloc", feature = "programming-language"))]
+    fn ranked_matches_are_best_first() {
+        let matches = search_ranked("python 3");
+        assert_eq!(
+            matches[0].classifier,
+            Classifier::ProgrammingLanguage__Python__3
+        );
+        assert!(matches
+            .windows(2)
+            .all(|pair| pair[0].score >= pair[1].score));
+        assert_eq!(matches.len(), search("python 3").count());
+    }
+}
@@ -0,0 +1,386 @@
+//! Serde support: classifiers as their canonical strings, and fields of classifiers that tolerate
+//! unknown strings.
+//!
+//! With the `serde` feature, `Classifier` serializes as its canonical string and deserializes
+//! from one, failing on any other string, so a `Vec<Classifier>` field rejects a document
+//! declaring a single unknown or deprecated classifier. Metadata written against another version
+//! of the dataset often does, so with the `alloc` feature this module offers two other modes:
+//!
+//! * `LenientClassifiers`, or `#[serde(with = "trove_classifiers::serde::skip_unknown")]` on a
+//!   `Vec<Classifier>` field, skips the strings that are not classifiers
+//! * `KnownAndUnknown` keeps them apart from the classifiers, to report or write back
+//!
+//! Compact formats can store classifiers as their stable `Classifier::code` instead, with
+//! `ClassifierCode`, or `#[serde(with = "trove_classifiers::serde::code")]` on a `Classifier`
+//! field and `#[serde(with = "trove_classifiers::serde::codes")]` on a `Vec<Classifier>` field.
+//! A code this build does not know fails to deserialize with an error naming the dataset's
+//! `DATASET_FINGERPRINT`, to tell which build wrote it from which reads it.
+//!
+//! # Examples
+//!
+//! ```
+//! use trove_classifiers::serde::KnownAndUnknown;
+//! use trove_classifiers::Classifier;
+//!
+//! #[derive(serde::Deserialize)]
+//! struct Project {
+//!     #[serde(with = "trove_classifiers::serde::skip_unknown")]
+//!     classifiers: Vec<Classifier>,
+//! }
+//!
+//! let json = r#"{"classifiers": ["Typing :: Typed", "Typing :: Typo"]}"#;
+//! let project: Project = serde_json::from_str(json)?;
+//! assert_eq!(project.classifiers, [Classifier::Typing__Typed]);
+//!
+//! let split: KnownAndUnknown = serde_json::from_str(r#"["Typing :: Typed", "Typing :: Typo"]"#)?;
+//! assert_eq!(split.known, [Classifier::Typing__Typed]);
+//! assert_eq!(split.unknown, ["Typing :: Typo"]);
+//! # Ok::<(), serde_json::Error>(())
+//! ```
+
+#[cfg(feature = "alloc")]
+use alloc::string::{String, ToString};
+#[cfg(feature = "alloc")]
+use alloc::vec::Vec;
+use core::fmt;
+#[cfg(feature = "alloc")]
+use core::str::FromStr;
+
+use ::serde::de::{self, Deserialize, Deserializer, Unexpected, Visitor};
+#[cfg(feature = "alloc")]
+use ::serde::ser::SerializeSeq;
+use ::serde::ser::{Serialize, Serializer};
+
+use crate::{Classifier, ParseError, DATASET_FINGERPRINT};
+
+impl Serialize for Classifier {
+    fn serialize<S: Serializer>(&self, serializer: S) -> Result<S::Ok, S::Error> {
+        serializer.serialize_str(self.as_str())
+    }
+}
+
+impl<'de> Deserialize<'de> for Classifier {
+    fn deserialize<D: Deserializer<'de>>(deserializer: D) -> Result<Self, D::Error> {
+        deserializer.deserialize_str(ClassifierVisitor)
+    }
+}
+
+struct ClassifierVisitor;
+
+impl Visitor<'_> for ClassifierVisitor {
+    type Value = Classifier;
+
+    fn expecting(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        f.write_str("a trove classifier")
+    }
+
+    fn visit_str<E: de::Error>(self, v: &str) -> Result<Classifier, E> {
+        match Classifier::from_str_strict(v) {
+            Ok(classifier) => Ok(classifier),
+            Err(ParseError::Unknown) => Err(E::invalid_value(Unexpected::Str(v), &self)),
+            Err(err) => Err(E::custom(err)),
+        }
+    }
+}
+
+/// Classifiers deserialized from strings, skipping those that are not classifiers
+///
+/// Unknown and deprecated strings are dropped; the classifiers keep their declared order.
+/// Serializes as the classifiers' strings.
+#[cfg(feature = "alloc")]
+#[derive(Clone, Debug, Default, Eq, Hash, PartialEq)]
+pub struct LenientClassifiers(pub Vec<Classifier>);
+
+/// Strings deserialized into the classifiers they name and those that name none
+///
+/// Serializes as the classifiers' strings followed by the unknown strings, so a document is
+/// written back without losing what this version of the dataset does not know.
+#[cfg(feature = "alloc")]
+#[derive(Clone, Debug, Default, Eq, Hash, PartialEq)]
+pub struct KnownAndUnknown {
+    /// The classifiers, in declared order
+    pub known: Vec<Classifier>,
+    /// The unknown and deprecated strings, in declared order
+    pub unknown: Vec<String>,
+}
+
+#[cfg(feature = "alloc")]
+impl From<LenientClassifiers> for Vec<Classifier> {
+    fn from(classifiers: LenientClassifiers) -> Self {
+        classifiers.0
+    }
+}
+
+#[cfg(feature = "alloc")]
+impl Serialize for LenientClassifiers {
+    fn serialize<S: Serializer>(&self, serializer: S) -> Result<S::Ok, S::Error> {
+        self.0.serialize(serializer)
+    }
+}
+
+#[cfg(feature = "alloc")]
+impl<'de> Deserialize<'de> for LenientClassifiers {
+    fn deserialize<D: Deserializer<'de>>(deserializer: D) -> Result<Self, D::Error> {
+        let split = KnownAndUnknown::deserialize(deserializer)?;
+        Ok(LenientClassifiers(split.known))
+    }
+}
+
+#[cfg(feature = "alloc")]
+impl Serialize for KnownAndUnknown {
+    fn serialize<S: Serializer>(&self, serializer: S) -> Result<S::Ok, S::Error> {
+        let mut seq = serializer.serialize_seq(Some(self.known.len() + self.unknown.len()))?;
+        for classifier in &self.known {
+            seq.serialize_element(classifier.as_str())?;
+        }
+        for unknown in &self.unknown {
+            seq.serialize_element(unknown)?;
+        }
+        seq.end()
+    }
+}
+
+#[cfg(feature = "alloc")]
+impl<'de> Deserialize<'de> for KnownAndUnknown {
+    fn deserialize<D: Deserializer<'de>>(deserializer: D) -> Result<Self, D::Error> {
+        let mut split = KnownAndUnknown::default();
+        for string in Vec::<MaybeClassifier>::deserialize(deserializer)? {
+            match string {
+                MaybeClassifier::Known(classifier) => split.known.push(classifier),
+                MaybeClassifier::Unknown(unknown) => split.unknown.push(unknown),
+            }
+        }
+        Ok(split)
+    }
+}
+
+/// A string, which may name a classifier
+#[cfg(feature = "alloc")]
+enum MaybeClassifier {
+    Known(Classifier),
+    Unknown(String),
+}
+
+#[cfg(feature = "alloc")]
+impl<'de> Deserialize<'de> for MaybeClassifier {
+    fn deserialize<D: Deserializer<'de>>(deserializer: D) -> Result<Self, D::Error> {
+        struct StringVisitor;
+
+        impl Visitor<'_> for StringVisitor {
+            type Value = MaybeClassifier;
+
+            fn expecting(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+                f.write_str("a string")
+            }
+
+            fn visit_str<E: de::Error>(self, v: &str) -> Result<MaybeClassifier, E> {
+                Ok(match Classifier::from_str(v) {
+                    Ok(classifier) => MaybeClassifier::Known(classifier),
+                    Err(_) => {
+                        #[cfg(feature = "telemetry")]
+                        crate::telemetry::unknown(v);
+                        MaybeClassifier::Unknown(v.to_string())
+                    }
+                })
+            }
+        }
+
+        deserializer.deserialize_str(StringVisitor)
+    }
+}
+
+/// `#[serde(with = "trove_classifiers::serde::skip_unknown")]` for a `Vec<Classifier>` field,
+/// which deserializes as `LenientClassifiers` does
+#[cfg(feature = "alloc")]
+pub mod skip_unknown {
+    use alloc::vec::Vec;
+
+    use ::serde::{Deserialize, Deserializer, Serialize, Serializer};
+
+    use super::LenientClassifiers;
+    use crate::Classifier;
+
+    pub fn serialize<S: Serializer>(
+        classifiers: &[Classifier],
+        serializer: S,
+    ) -> Result<S::Ok, S::Error> {
+        classifiers.serialize(serializer)
+    }
+
+    pub fn deserialize<'de, D: Deserializer<'de>>(
+        deserializer: D,
+    ) -> Result<Vec<Classifier>, D::Error> {
+        LenientClassifiers::deserialize(deserializer).map(Vec::from)
+    }
+}
+
+/// A classifier that serializes as its stable code
+#[derive(Clone, Copy, Debug, Eq, Hash, Ord, PartialEq, PartialOrd)]
+pub struct ClassifierCode(pub Classifier);
+
+impl From<ClassifierCode> for Classifier {
+    fn from(code: ClassifierCode) -> Self {
+        code.0
+    }
+}
+
+impl Serialize for ClassifierCode {
+    fn serialize<S: Serializer>(&self, serializer: S) -> Result<S::Ok, S::Error> {
+        serializer.serialize_u16(self.0.code())
+    }
+}
+
+impl<'de> Deserialize<'de> for ClassifierCode {
+    fn deserialize<D: Deserializer<'de>>(deserializer: D) -> Result<Self, D::Error> {
+        let code = u16::deserialize(deserializer)?;
+        match Classifier::from_code(code) {
+            Some(classifier) => Ok(ClassifierCode(classifier)),
+            None => Err(de::Error::custom(format_args!(
+                "classifier code {code} is unknown to the dataset with fingerprint \
+                 {DATASET_FINGERPRINT:#018x}"
+            ))),
+        }
+    }
+}
+
+/// `#[serde(with = "trove_classifiers::serde::code")]` for a `Classifier` field, which
+/// serializes as `ClassifierCode` does
+pub mod code {
+    use ::serde::{Deserialize, Deserializer, Serialize, Serializer};
+
+    use super::ClassifierCode;
+    use crate::Classifier;
+
+    pub fn serialize<S: Serializer>(
+        classifier: &Classifier,
+        serializer: S,
+    ) -> Result<S::Ok, S::Error> {
+        ClassifierCode(*classifier).serialize(serializer)
+    }
+
+    pub fn deserialize<'de, D: Deserializer<'de>>(deserializer: D) -> Result<Classifier, D::Error> {
+        ClassifierCode::deserialize(deserializer).map(Classifier::from)
+    }
+}
+
+/// `#[serde(with = "trove_classifiers::serde::codes")]` for a `Vec<Classifier>` field, which
+/// serializes as a sequence of `ClassifierCode`
+#[cfg(feature = "alloc")]
+pub mod codes {
+    use alloc::vec::Vec;
+
+    use ::serde::ser::SerializeSeq;
+    use ::serde::{Deserialize, Deserializer, Serializer};
+
+    use super::ClassifierCode;
+    use crate::Classifier;
+
+    pub fn serialize<S: Serializer>(
+        classifiers: &[Classifier],
+        serializer: S,
+    ) -> Result<S::Ok, S::Error> {
+        let mut seq = serializer.serialize_seq(Some(classifiers.len()))?;
+        for classifier in classifiers {
+            seq.serialize_element(&ClassifierCode(*classifier))?;
+        }
+        seq.end()
+    }
+
+    pub fn deserialize<'de, D: Deserializer<'de>>(
+        deserializer: D,
+    ) -> Result<Vec<Classifier>, D::Error> {
+        let codes = Vec::<ClassifierCode>::deserialize(deserializer)?;
+        Ok(codes.into_iter().map(Classifier::from).collect())
+    }
+}
+
+#[cfg(all(test, feature = "alloc", feature = "typing"))]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn classifiers_are_canonical_strings() {
+        let classifier =
+            |json: &str| serde_json::from_str::<Classifier>(json).map_err(|err| err.to_string());
+        assert_eq!(
+            classifier("\"Typing :: Typed\""),
+            Ok(Classifier::Typing__Typed)
+        );
+        assert_eq!(
+            classifier("\"typing :: typed\""),
+            Err(
+                "invalid value: string \"typing :: typed\", expected a trove classifier \
+                 at line 1 column 17"
+                    .to_string()
+            )
+        );
+        assert_eq!(
+            serde_json::to_string(&Classifier::Typing__Typed).unwrap(),
+            "\"Typing :: Typed\""
+        );
+    }
+
+    #[test]
+    #[cfg(feature = "natural-language")]
+    fn unknown_strings_are_skipped_or_kept() {
+        let declared = r#"["Typing :: Typed", "Typing :: Typo", "Natural Language :: Ukranian",
+                           "Typing :: Stubs Only"]"#;
+        let split: KnownAndUnknown = serde_json::from_str(declared).unwrap();
+        assert_eq!(
+            split.known,
+            [Classifier::Typing__Typed, Classifier::Typing__StubsOnly]
+        );
+        assert_eq!(
+            split.unknown,
+            ["Typing :: Typo", "Natural Language :: Ukranian"]
+        );
+        assert_eq!(
+            serde_json::to_string(&split).unwrap(),
+            r#"["Typing :: Typed","Typing :: Stubs Only","Typing :: Typo","Natural Language :: Ukranian"]"#
+        );
+        let lenient: LenientClassifiers = serde_json::from_str(declared).unwrap();
+        assert_eq!(lenient.0, split.known);
+        let err = serde_json::from_str::<Vec<Classifier>>(declared).unwrap_err();
+        assert!(err
+            .to_string()
+            .starts_with("invalid value: string \"Typing :: Typo\""));
+        let err =
+            serde_json::from_str::<Classifier>("\"Natural Language :: Ukranian\"").unwrap_err();
+        assert!(err
+            .to_string()
+            .starts_with("deprecated classifier \"Natural Language :: Ukranian\", use"));
+        assert!(serde_json::from_str::<KnownAndUnknown>("[1]").is_err());
+    }
+
+    #[test]
+    fn classifiers_serialize_as_codes() {
+        #[derive(Debug, PartialEq, ::serde::Deserialize, ::serde::Serialize)]
+        struct Row {
+            #[serde(with = "crate::serde::code")]
+            primary: Classifier,
+            #[serde(with = "crate::serde::codes")]
+            all: Vec<Classifier>,
+        }
+
+        let row = Row {
+            primary: Classifier::Typing__Typed,
+            all: vec![Classifier::Typing__StubsOnly, Classifier::Typing__Typed],
+        };
+        let json = serde_json::to_string(&row).unwrap();
+        assert_eq!(json, r#"{"primary":865,"all":[864,865]}"#);
+        assert_eq!(serde_json::from_str::<Row>(&json).unwrap(), row);
+        assert_eq!(
+            serde_json::from_str::<ClassifierCode>("865").unwrap(),
+            ClassifierCode(Classifier::Typing__Typed)
+        );
+        let err = serde_json::from_str::<ClassifierCode>("65535").unwrap_err();
+        assert_eq!(
+            err.to_string(),
+            alloc::format!(
+                "classifier code 65535 is unknown to the dataset with fingerprint \
+                 {DATASET_FINGERPRINT:#018x}"
+            )
+        );
+        assert!(serde_json::from_str::<ClassifierCode>("\"Typing :: Typed\"").is_err());
+    }
+}
@@ -0,0 +1,98 @@
+//! Extraction of the classifiers declared in a `setup.cfg`.
+//!
+//! setuptools reads the `classifiers` option of the `[metadata]` section, a list with one
+//! classifier per line, usually indented below the option. Only that option is read, so this is
+//! not an INI parser; a `file:` directive pointing at another file is not followed.
+//!
+//! # Examples
+//!
+//! ```
+//! use trove_classifiers::setup_cfg;
+//!
+//! let source = "\
+//! [metadata]
+//! name = example
+//! classifiers =
+//!     Typing :: Typed
+//!     ; Typing :: Stubs Only
+//!     Typing :: Untyped
+//! ";
+//!
+//! let declared = setup_cfg::declared(source);
+//! assert_eq!(declared.len(), 2);
+//! assert_eq!(declared[1].value, "Typing :: Untyped");
+//! assert_eq!(declared[1].line, Some(6));
+//! ```
+
+use crate::lint::Declared;
+
+/// The classifiers of the `[metadata]` section's `classifiers` option, in order, with their lines
+pub fn declared(source: &str) -> Vec<Declared> {
+    let mut declared = Vec::new();
+    let mut in_metadata = false;
+    let mut in_classifiers = false;
+    for (index, line) in source.lines().enumerate() {
+        let trimmed = line.trim();
+        if trimmed.is_empty() || trimmed.starts_with(['#', ';']) {
+            continue;
+        }
+        let indented = line.starts_with([' ', '\t']);
+        if !indented {
+            in_classifiers = false;
+            if let Some(section) = trimmed.strip_prefix('[') {
+                in_metadata = section.trim_end_matches(']').trim() == "metadata";
+                continue;
+            }
+        }
+        if in_classifiers {
+            declared.push(Declared::new(trimmed).line(index + 1));
+            continue;
+        }
+        if !in_metadata || indented {
+            continue;
+        }
+        let Some((key, value)) = trimmed.split_once(['=', ':']) else {
+            continue;
+        };
+        if key.trim() == "classifiers" {
+            in_classifiers = true;
+            let value = value.trim();
+            if !value.is_empty() && !value.starts_with("file:") {
+                declared.push(Declared::new(value).line(index + 1));
+            }
+        }
+    }
+    declared
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    fn values(source: &str) -> Vec<(String, Option<usize>)> {
+        declared(source)
+            .into_iter()
+            .map(|declared| (declared.value, declared.line))
+            .collect()
+    }
+
+    #[test]
+    fn reads_only_the_metadata_section() {
+        let source = "[options]\nclassifiers =\n    Not :: This\n\
+                      [metadata]\nclassifiers = Typing :: Typed\n\tTopic :: Utilities\n\n\
+                      version = 1.0\n  Not :: A Classifier\n[tool:pytest]\nclassifiers = x\n";
+        assert_eq!(
+            values(source),
+            [
+                ("Typing :: Typed".to_string(), Some(5)),
+                ("Topic :: Utilities".to_string(), Some(6)),
+            ]
+        );
+    }
+
+    #[test]
+    fn file_directives_are_not_followed() {
+        assert!(values("[metadata]\nclassifiers = file: classifiers.txt\n").is_empty());
+        assert!(values("[metadata]\nname = example\n").is_empty());
+    }
+}
@@ -0,0 +1,127 @@
+use core::fmt::{self, Write};
+
+use crate::Classifier;
+
+impl Classifier {
+    /// A lowercase identifier of this classifier that is safe in URL paths and file names
+    ///
+    /// Runs of ASCII letters and digits are kept, lowercased and joined by `-`; every other
+    /// character separates them, except `+` and `#`, which are spelled `plus` and `sharp` so that
+    /// "C++" and "C#" keep slugs of their own. No two classifiers share a slug.
+    ///
+    /// # Examples
+    ///
+    #[cfg_attr(not(feature = "license"), doc = "```ignore")]
+    #[cfg_attr(feature = "license", doc = "```")]
+    /// use trove_classifiers::Classifier;
+    ///
+    /// let mit = Classifier::License__OSIApproved__MITLicense;
+    /// assert_eq!(mit.slug(), "license-osi-approved-mit-license");
+    /// assert_eq!(Classifier::from_slug("license-osi-approved-mit-license"), Some(mit));
+    /// ```
+    #[cfg(feature = "alloc")]
+    pub fn slug(&self) -> alloc::string::String {
+        let mut slug = alloc::string::String::new();
+        write_slug(self.as_str(), &mut slug).unwrap();
+        slug
+    }
+
+    /// The classifier with a slug, as returned by `slug`
+    pub fn from_slug(slug: &str) -> Option<Classifier> {
+        Classifier::ALL.iter().copied().find(|classifier| {
+            let mut rest = Remaining(slug);
+            write_slug(classifier.as_str(), &mut rest).is_ok() && rest.0.is_empty()
+        })
+    }
+}
+
+/// Write the slug of a classifier string
+fn write_slug(classifier: &str, out: &mut impl Write) -> fmt::Result {
+    // Whether anything was written, and whether a `-` is owed before the next letter or digit
+    let (mut started, mut separate) = (false, false);
+    for c in classifier.chars() {
+        let word = match c {
+            '+' => "plus",
+            '#' => "sharp",
+            c if c.is_ascii_alphanumeric() => {
+                if separate && started {
+                    out.write_char('-')?;
+                }
+                out.write_char(c.to_ascii_lowercase())?;
+                (started, separate) = (true, false);
+                continue;
+            }
+            _ => {
+                separate = true;
+                continue;
+            }
+        };
+        if started {
+            out.write_char('-')?;
+        }
+        out.write_str(word)?;
+        (started, separate) = (true, true);
+    }
+    Ok(())
+}
+
+/// A writer that only accepts the text it still expects, consuming it
+struct Remaining<'a>(&'a str);
+
+impl Write for Remaining<'_> {
+    fn write_str(&mut self, s: &str) -> fmt::Result {
+        self.0 = self.0.strip_prefix(s).ok_or(fmt::Error)?;
+        Ok(())
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn slugs_are_unique_and_path_safe() {
+        let mut slugs: Vec<String> = Classifier::ALL.iter().map(Classifier::slug).collect();
+        for slug in &slugs {
+            assert!(slug
+                .bytes()
+                .all(|b| b.is_ascii_lowercase() || b.is_ascii_digit() || b == b'-'));
+            assert!(!slug.starts_with('-') && !slug.ends_with('-') && !slug.contains("--"));
+        }
+        slugs.sort();
+        slugs.dedup();
+        assert_eq!(slugs.len(), Classifier::ALL.len());
+    }
+
+    #[test]
+    fn slugs_round_trip() {
+        for &classifier in Classifier::ALL {
+            assert_eq!(Classifier::from_slug(&classifier.slug()), Some(classifier));
+        }
+        assert_eq!(Classifier::from_slug(""), None);
+        assert_eq!(Classifier::from_slug("typing-typed-"), None);
+        assert_eq!(Classifier::from_slug("typing"), None);
+    }
+
+    #[test]
+    #[cfg(feature = "programming-language")]
+    fn symbols_are_spelled_out() {
+        let slug = |s: &str| {
+            let mut slug = String::new();
+            write_slug(s, &mut slug).unwrap();
+            slug
+        };
+        assert_eq!(
+            slug("Programming Language :: C++"),
+            "programming-language-c-plus-plus"
+        );
+        assert_eq!(
+            slug("Programming Language :: C#"),
+            "programming-language-c-sharp"
+        );
+        assert_eq!(
+            slug("Development Status :: 5 - Production/Stable"),
+            "development-status-5-production-stable"
+        );
+    }
+}
@@ -0,0 +1,1229 @@
+//! Classifier suggestions from a project's other metadata.
+//!
+//! `suggest` looks at what a project already says about itself, such as the Python versions it
+//! requires, its license, its version, its dependencies and its keywords, and proposes the
+//! classifiers that say the same. `dependency_constraints` works the other way around, proposing
+//! the requirements that the declared `Framework` classifiers say the project has, and
+//! `from_environment` describes the environment an artifact was built in.
+//!
+//! # Examples
+//!
+#![cfg_attr(not(feature = "all-categories"), doc = "```ignore")]
+#![cfg_attr(feature = "all-categories", doc = "```")]
+//! use trove_classifiers::suggest::{suggest, Project, Reason};
+//! use trove_classifiers::Classifier;
+//!
+//! let project = Project {
+//!     requires_python: Some(">=3.12".to_string()),
+//!     license: Some("MIT".to_string()),
+//!     dependencies: vec!["django>=5.1,<5.2".to_string()],
+//!     ..Project::default()
+//! };
+//! let suggestions = suggest(&project);
+//! assert!(suggestions.iter().any(|suggestion| {
+//!     suggestion.classifier == Classifier::Framework__Django__5_1
+//!         && suggestion.reason == Reason::Dependency("django".to_string())
+//! }));
+//! assert!(suggestions
+//!     .iter()
+//!     .any(|suggestion| suggestion.classifier == Classifier::License__OSIApproved__MITLicense));
+//! ```
+
+use alloc::format;
+use alloc::string::{String, ToString};
+use alloc::vec::Vec;
+use core::fmt;
+use core::str::FromStr;
+
+use crate::pep440::{admits_series, Phase, Release};
+use crate::{implementation, license, Classifier};
+
+/// What is known about a project
+#[derive(Clone, Debug, Default, Eq, PartialEq)]
+pub struct Project {
+    /// The distribution name, such as `pytest-example`
+    pub name: Option<String>,
+    /// The PEP 440 version, such as `1.2.0rc1`
+    pub version: Option<String>,
+    /// The `Requires-Python` version specifiers, such as `>=3.9`
+    pub requires_python: Option<String>,
+    /// The license as an SPDX expression, such as `MIT OR Apache-2.0`
+    pub license: Option<String>,
+    /// PEP 508 requirements, such as `django>=4.2; python_version >= "3.10"`
+    pub dependencies: Vec<String>,
+    /// The classifiers the project already declares, which are never suggested
+    pub classifiers: Vec<Classifier>,
+    /// Whether the project ships a `py.typed` marker
+    pub typed: bool,
+    /// The text of the project's license file, such as `LICENSE`, which is only read with the
+    /// `license-text` feature
+    pub license_text: Option<String>,
+    /// The text of the project's README, which is only read with the `language-detection`
+    /// feature
+    pub readme: Option<String>,
+    /// The project's keywords, such as `["testing", "mock"]`
+    pub keywords: Vec<String>,
+    /// The one-line summary of the project
+    pub description: Option<String>,
+}
+
+/// A suggested classifier and why it was suggested
+#[derive(Clone, Debug, Eq, PartialEq)]
+pub struct Suggestion {
+    pub classifier: Classifier,
+    pub reason: Reason,
+}
+
+/// Why a classifier was suggested
+#[derive(Clone, Debug, Eq, PartialEq)]
+#[non_exhaustive]
+pub enum Reason {
+    /// The Python version is admitted by `requires_python`
+    RequiresPython,
+    /// The license is part of `license`
+    License,
+    /// The project depends on the named distribution
+    Dependency(String),
+    /// The project's name follows the naming convention of a framework's plugins
+    PluginName,
+    /// The project ships a `py.typed` marker
+    TypedMarker,
+    /// The project's name is that of a stubs-only package
+    StubsName,
+    /// No `Operating System` classifier is declared or suggested
+    NoOperatingSystem,
+    /// No `Development Status` classifier is declared, and this one matches the version
+    Version,
+    /// The license file's text is that of the license, with at least `LICENSE_TEXT_CONFIDENCE`
+    LicenseText,
+    /// Enough of the README is written in the language
+    ReadmeLanguage,
+    /// The keywords and description score at least `TOPIC_SCORE` for the topic
+    Keywords,
+}
+
+impl fmt::Display for Reason {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        match self {
+            Reason::RequiresPython => f.write_str("admitted by requires-python"),
+            Reason::License => f.write_str("matches the license"),
+            Reason::Dependency(name) => write!(f, "depends on {name}"),
+            Reason::PluginName => f.write_str("named like a plugin"),
+            Reason::TypedMarker => f.write_str("ships a py.typed marker"),
+            Reason::StubsName => f.write_str("named like a stubs package"),
+            Reason::NoOperatingSystem => f.write_str("no operating system is declared"),
+            Reason::Version => f.write_str("matches the version"),
+            Reason::LicenseText => f.write_str("matches the license file"),
+            Reason::ReadmeLanguage => f.write_str("the README is written in it"),
+            Reason::Keywords => f.write_str("matches the keywords"),
+        }
+    }
+}
+
+/// Normalized distribution names and the classifier of the framework they provide
+const FRAMEWORKS: &[(&str, &str)] = &[
+    ("aiohttp", "Framework :: aiohttp"),
+    ("anyio", "Framework :: AnyIO"),
+    ("apache-airflow", "Framework :: Apache Airflow"),
+    ("bottle", "Framework :: Bottle"),
+    ("celery", "Framework :: Celery"),
+    ("cherrypy", "Framework :: CherryPy"),
+    ("dash", "Framework :: Dash"),
+    ("datasette", "Framework :: Datasette"),
+    ("django", "Framework :: Django"),
+    ("django-cms", "Framework :: Django CMS"),
+    ("fastapi", "Framework :: FastAPI"),
+    ("flask", "Framework :: Flask"),
+    ("kedro", "Framework :: Kedro"),
+    ("masonite", "Framework :: Masonite"),
+    ("pydantic", "Framework :: Pydantic"),
+    ("pyramid", "Framework :: Pyramid"),
+    ("scrapy", "Framework :: Scrapy"),
+    ("trio", "Framework :: Trio"),
+    ("twisted", "Framework :: Twisted"),
+    ("wagtail", "Framework :: Wagtail"),
+];
+
+/// Distribution name prefixes conventionally used by a framework's plugins
+const PLUGIN_PREFIXES: &[(&str, &str)] = &[
+    ("datasette-", "Framework :: Datasette"),
+    ("flake8-", "Framework :: Flake8"),
+    ("hatch-", "Framework :: Hatch"),
+    ("mkdocs-", "Framework :: MkDocs"),
+    ("napari-", "Framework :: napari"),
+    ("pytest-", "Framework :: Pytest"),
+    ("sphinxcontrib-", "Framework :: Sphinx"),
+    ("tox-", "Framework :: tox"),
+];
+
+/// Keywords and the Topic classifier they are a strong sign of, besides the names of the topics
+/// themselves
+const TOPIC_KEYWORDS: &[(&str, &str)] = &[
+    (
+        "ai",
+        "Topic :: Scientific/Engineering :: Artificial Intelligence",
+    ),
+    ("asgi", "Topic :: Internet :: WWW/HTTP :: HTTP Servers"),
+    ("astronomy", "Topic :: Scientific/Engineering :: Astronomy"),
+    ("backup", "Topic :: System :: Archiving :: Backup"),
+    (
+        "bioinformatics",
+        "Topic :: Scientific/Engineering :: Bio-Informatics",
+    ),
+    ("build", "Topic :: Software Development :: Build Tools"),
+    (
+        "code generation",
+        "Topic :: Software Development :: Code Generators",
+    ),
+    ("compiler", "Topic :: Software Development :: Compilers"),
+    (
+        "computer vision",
+        "Topic :: Scientific/Engineering :: Image Recognition",
+    ),
+    (
+        "crawler",
+        "Topic :: Internet :: WWW/HTTP :: Indexing/Search",
+    ),
+    ("crypto", "Topic :: Security :: Cryptography"),
+    (
+        "data science",
+        "Topic :: Scientific/Engineering :: Information Analysis",
+    ),
+    ("debugger", "Topic :: Software Development :: Debuggers"),
+    ("debugging", "Topic :: Software Development :: Debuggers"),
+    (
+        "deep learning",
+        "Topic :: Scientific/Engineering :: Artificial Intelligence",
+    ),
+    ("devops", "Topic :: System :: Systems Administration"),
+    ("encryption", "Topic :: Security :: Cryptography"),
+    ("finance", "Topic :: Office/Business :: Financial"),
+    ("game", "Topic :: Games/Entertainment"),
+    ("games", "Topic :: Games/Entertainment"),
+    ("gui", "Topic :: Software Development :: User Interfaces"),
+    (
+        "i18n",
+        "Topic :: Software Development :: Internationalization",
+    ),
+    ("l10n", "Topic :: Software Development :: Localization"),
+    ("lint", "Topic :: Software Development :: Quality Assurance"),
+    (
+        "linter",
+        "Topic :: Software Development :: Quality Assurance",
+    ),
+    (
+        "llm",
+        "Topic :: Scientific/Engineering :: Artificial Intelligence",
+    ),
+    (
+        "machine learning",
+        "Topic :: Scientific/Engineering :: Artificial Intelligence",
+    ),
+    ("math", "Topic :: Scientific/Engineering :: Mathematics"),
+    (
+        "mock",
+        "Topic :: Software Development :: Testing :: Mocking",
+    ),
+    ("music", "Topic :: Multimedia :: Sound/Audio"),
+    ("nlp", "Topic :: Text Processing :: Linguistic"),
+    ("orm", "Topic :: Database :: Front-Ends"),
+    (
+        "plotting",
+        "Topic :: Scientific/Engineering :: Visualization",
+    ),
+    ("postgresql", "Topic :: Database"),
+    ("pytest", "Topic :: Software Development :: Testing"),
+    ("rest", "Topic :: Internet :: WWW/HTTP"),
+    (
+        "scraping",
+        "Topic :: Internet :: WWW/HTTP :: Indexing/Search",
+    ),
+    ("sql", "Topic :: Database"),
+    (
+        "static analysis",
+        "Topic :: Software Development :: Quality Assurance",
+    ),
+    (
+        "statistics",
+        "Topic :: Scientific/Engineering :: Mathematics",
+    ),
+    ("sysadmin", "Topic :: System :: Systems Administration"),
+    ("testing", "Topic :: Software Development :: Testing"),
+    (
+        "type checking",
+        "Topic :: Software Development :: Quality Assurance",
+    ),
+    ("web", "Topic :: Internet :: WWW/HTTP"),
+    ("wsgi", "Topic :: Internet :: WWW/HTTP :: WSGI"),
+];
+
+/// The score from which `suggest` suggests a topic, as reached by one keyword naming it alone
+pub const TOPIC_SCORE: f32 = 1.0;
+
+/// Normalized distribution names that only run on one operating system
+const OPERATING_SYSTEMS: &[(&str, &str)] = &[
+    ("pyobjc", "Operating System :: MacOS"),
+    ("pyobjc-core", "Operating System :: MacOS"),
+    ("pywin32", "Operating System :: Microsoft :: Windows"),
+];
+
+/// Classifiers that describe `project` but that it does not declare, in canonical order
+pub fn suggest(project: &Project) -> Vec<Suggestion> {
+    let mut suggestions = Vec::new();
+    let mut add = |classifier: &str, reason: Reason| {
+        if let Ok(classifier) = Classifier::from_str(classifier) {
+            suggestions.push(Suggestion { classifier, reason });
+        }
+    };
+
+    if let Some(specifiers) = &project.requires_python {
+        for classifier in python_versions(specifiers) {
+            add(classifier.as_str(), Reason::RequiresPython);
+        }
+    }
+
+    if let Some(expression) = &project.license {
+        for id in license::spdx_ids(expression) {
+            if let Some(classifier) = license::from_spdx(id) {
+                add(classifier.as_str(), Reason::License);
+            }
+        }
+    }
+
+    #[cfg(feature = "license-text")]
+    if let Some(found) = project
+        .license_text
+        .as_deref()
+        .and_then(suggest_license_classifier_from_text)
+    {
+        if found.confidence >= LICENSE_TEXT_CONFIDENCE {
+            add(found.classifier.as_str(), Reason::LicenseText);
+        }
+    }
+
+    #[cfg(feature = "language-detection")]
+    if let Some(readme) = &project.readme {
+        for language in natural_languages(readme) {
+            add(language.as_str(), Reason::ReadmeLanguage);
+        }
+    }
+
+    for topic in topics(&project.keywords, project.description.as_deref()) {
+        if topic.score >= TOPIC_SCORE {
+            add(topic.classifier.as_str(), Reason::Keywords);
+        }
+    }
+
+    let name = project.name.as_deref().map(normalize);
+    for dependency in &project.dependencies {
+        let (dependency, specifiers) = requirement(dependency);
+        for (framework, classifier) in FRAMEWORKS {
+            if dependency == *framework {
+                add(classifier, Reason::Dependency(dependency.clone()));
+                for version in versions_below(classifier, specifiers) {
+                    add(version.as_str(), Reason::Dependency(dependency.clone()));
+                }
+            }
+        }
+        for (system, classifier) in OPERATING_SYSTEMS {
+            if dependency == *system {
+                add(classifier, Reason::Dependency(dependency.clone()));
+            }
+        }
+    }
+    if let Some(name) = &name {
+        for (prefix, classifier) in PLUGIN_PREFIXES {
+            if name.starts_with(prefix) {
+                add(classifier, Reason::PluginName);
+            }
+        }
+        if name.ends_with("-stubs") || name.starts_with("types-") {
+            add("Typing :: Stubs Only", Reason::StubsName);
+        }
+    }
+    if project.typed {
+        add("Typing :: Typed", Reason::TypedMarker);
+    }
+    let declares_status = project
+        .classifiers
+        .iter()
+        .any(|classifier| classifier.segments_static()[0] == "Development Status");
+    if let Some(status) = project.version.as_deref().and_then(development_status) {
+        if !declares_status {
+            add(status.as_str(), Reason::Version);
+        }
+    }
+
+    suggestions.retain(|suggestion| !project.classifiers.contains(&suggestion.classifier));
+    suggestions.sort_by_key(|suggestion| suggestion.classifier);
+    suggestions.dedup_by_key(|suggestion| suggestion.classifier);
+
+    let operating_system =
+        |classifier: &Classifier| classifier.segments_static()[0] == "Operating System";
+    if !project.classifiers.iter().any(operating_system)
+        && !suggestions
+            .iter()
+            .any(|suggestion| operating_system(&suggestion.classifier))
+    {
+        if let Ok(classifier) = Classifier::from_str("Operating System :: OS Independent") {
+            let index =
+                suggestions.partition_point(|suggestion| suggestion.classifier < classifier);
+            suggestions.insert(
+                index,
+                Suggestion {
+                    classifier,
+                    reason: Reason::NoOperatingSystem,
+                },
+            );
+        }
+    }
+    suggestions
+}
+
+/// The values of PEP 508 environment markers describing where Python runs
+#[derive(Clone, Debug, Default, Eq, PartialEq)]
+pub struct MarkerEnvironment {
+    /// `platform.system()`, such as `Linux`, `Darwin` or `Windows`
+    pub platform_system: Option<String>,
+    /// `sys.implementation.name`, such as `cpython` or `pypy`
+    pub implementation_name: Option<String>,
+    /// The major and minor version of the language, such as `3.12`
+    pub python_version: Option<String>,
+}
+
+/// Values of `platform_system` and the Operating System classifier of each
+const PLATFORM_SYSTEMS: &[(&str, &str)] = &[
+    ("AIX", "Operating System :: POSIX :: AIX"),
+    ("Android", "Operating System :: Android"),
+    ("Darwin", "Operating System :: MacOS"),
+    ("FreeBSD", "Operating System :: POSIX :: BSD :: FreeBSD"),
+    ("GNU", "Operating System :: POSIX :: GNU Hurd"),
+    ("iOS", "Operating System :: iOS"),
+    ("Linux", "Operating System :: POSIX :: Linux"),
+    ("NetBSD", "Operating System :: POSIX :: BSD :: NetBSD"),
+    ("OpenBSD", "Operating System :: POSIX :: BSD :: OpenBSD"),
+    ("SunOS", "Operating System :: POSIX :: SunOS/Solaris"),
+    ("Windows", "Operating System :: Microsoft :: Windows"),
+];
+
+/// The Operating System, implementation and Python version classifiers describing a marker
+/// environment, in canonical order
+///
+/// Values that are missing or unknown, such as a `platform_system` of `Java`, add nothing.
+///
+/// # Examples
+///
+#[cfg_attr(not(feature = "all-categories"), doc = "```ignore")]
+#[cfg_attr(feature = "all-categories", doc = "```")]
+/// use trove_classifiers::suggest::{from_environment, MarkerEnvironment};
+///
+/// let environment = MarkerEnvironment {
+///     platform_system: Some("Linux".to_string()),
+///     implementation_name: Some("cpython".to_string()),
+///     python_version: Some("3.12".to_string()),
+/// };
+/// let classifiers: Vec<&str> = from_environment(&environment)
+///     .iter()
+///     .map(|classifier| classifier.as_str())
+///     .collect();
+/// assert_eq!(
+///     classifiers,
+///     [
+///         "Operating System :: POSIX :: Linux",
+///         "Programming Language :: Python :: 3",
+///         "Programming Language :: Python :: 3.12",
+///         "Programming Language :: Python :: Implementation :: CPython",
+///     ]
+/// );
+/// ```
+pub fn from_environment(environment: &MarkerEnvironment) -> Vec<Classifier> {
+    let mut classifiers = Vec::new();
+    if let Some(system) = &environment.platform_system {
+        let system = system.trim();
+        classifiers.extend(
+            PLATFORM_SYSTEMS
+                .iter()
+                .find(|(name, _)| name.eq_ignore_ascii_case(system))
+                .and_then(|(_, classifier)| Classifier::from_str(classifier).ok()),
+        );
+    }
+    if let Some(name) = &environment.implementation_name {
+        classifiers.extend(implementation::from_sys_name(name));
+    }
+    if let Some([major, minor, ..]) = environment
+        .python_version
+        .as_deref()
+        .and_then(Release::parse)
+        .as_ref()
+        .map(Release::parts)
+    {
+        for version in [format!("{major}"), format!("{major}.{minor}")] {
+            let classifier = format!("Programming Language :: Python :: {version}");
+            classifiers.extend(Classifier::from_str(&classifier).ok());
+        }
+    }
+    classifiers.sort_unstable();
+    classifiers
+}
+
+/// A requirement on a framework's distribution, proposed from the classifiers of the framework
+#[derive(Clone, Debug, Eq, PartialEq)]
+pub struct Constraint {
+    /// The classifier of the framework, such as "Framework :: Django"
+    pub framework: Classifier,
+    /// The normalized distribution name, such as `django`
+    pub name: String,
+    /// The version specifiers admitting every declared version of the framework, such as
+    /// `>=4.2,<5.1`, or nothing if no version is declared
+    pub specifiers: String,
+}
+
+/// Formats the PEP 508 requirement, such as `django>=4.2,<4.3`
+impl fmt::Display for Constraint {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        write!(f, "{}{}", self.name, self.specifiers)
+    }
+}
+
+/// The requirements that declared `Framework` classifiers imply, in canonical order
+///
+/// Each framework whose distribution is known, and that is declared itself or in any version,
+/// gets a constraint. Its specifiers span the declared versions: from the oldest, up to the
+/// series after the newest.
+///
+/// # Examples
+///
+#[cfg_attr(not(feature = "framework"), doc = "```ignore")]
+#[cfg_attr(feature = "framework", doc = "```")]
+/// use trove_classifiers::suggest::dependency_constraints;
+/// use trove_classifiers::Classifier;
+///
+/// let constraints = dependency_constraints(&[
+///     Classifier::Framework__Django,
+///     Classifier::Framework__Django__4_2,
+///     Classifier::Framework__Flask,
+/// ]);
+/// assert_eq!(constraints[0].to_string(), "django>=4.2,<4.3");
+/// assert_eq!(constraints[1].to_string(), "flask");
+/// ```
+pub fn dependency_constraints(classifiers: &[Classifier]) -> Vec<Constraint> {
+    let mut constraints = Vec::new();
+    for (name, framework) in FRAMEWORKS {
+        let Ok(framework) = Classifier::from_str(framework) else {
+            continue;
+        };
+        let depth = framework.segments_static().len();
+        let mut declared = false;
+        let mut versions: Vec<Release> = Vec::new();
+        for classifier in classifiers {
+            if *classifier == framework {
+                declared = true;
+            } else if classifier.family() == framework {
+                declared = true;
+                if let Some(release) = classifier
+                    .segments_static()
+                    .get(depth)
+                    .and_then(|version| Release::parse(version))
+                {
+                    versions.push(release);
+                }
+            }
+        }
+        if !declared {
+            continue;
+        }
+        let next = |release: &Release| {
+            let mut next = release.parts().to_vec();
+            if let Some(last) = next.last_mut() {
+                *last += 1;
+            }
+            next
+        };
+        let oldest = versions.iter().map(Release::parts).min();
+        let end = versions.iter().map(next).max();
+        let specifiers = match (oldest, end) {
+            (Some(oldest), Some(end)) => format!(">={},<{}", join(oldest), join(&end)),
+            _ => String::new(),
+        };
+        constraints.push(Constraint {
+            framework,
+            name: name.to_string(),
+            specifiers,
+        });
+    }
+    constraints.sort_by_key(|constraint| constraint.framework);
+    constraints
+}
+
+/// A release segment written with dots, such as `4.2`
+fn join(parts: &[u32]) -> String {
+    let parts: Vec<String> = parts.iter().map(u32::to_string).collect();
+    parts.join(".")
+}
+
+/// The confidence from which `suggest` trusts a license detected in a license file
+#[cfg(feature = "license-text")]
+pub const LICENSE_TEXT_CONFIDENCE: f32 = 0.9;
+
+/// A license detected in the text of a license file
+#[cfg(feature = "license-text")]
+#[derive(Clone, Copy, Debug, PartialEq)]
+pub struct LicenseMatch {
+    pub classifier: Classifier,
+    /// The SPDX identifier of the license
+    pub spdx: &'static str,
+    /// How closely the text matches the license, from 0 to 1
+    pub confidence: f32,
+}
+
+/// The License classifier of the license whose text is most like that of a license file
+///
+/// Only licenses with a classifier are considered, and texts are compared word pair by word pair,
+/// ignoring case, punctuation and copyright lines, so a confidence close to 1 means the file is
+/// that license with little more than a different copyright holder. Licenses whose texts are the
+/// same, such as `GPL-3.0-only` and `GPL-3.0-or-later`, cannot be told apart; the first of
+/// `license::from_spdx`'s identifiers is returned. Returns `None` for a text without a word pair,
+/// or when the `license` feature is disabled.
+///
+/// # Examples
+///
+/// ```
+/// use trove_classifiers::suggest::suggest_license_classifier_from_text;
+/// use trove_classifiers::Classifier;
+///
+/// let text = "Copyright (c) 2024 Jane Doe\n\nPermission to use, copy, modify, and/or \
+///     distribute this software for any purpose with or without fee is hereby granted, provided \
+///     that the above copyright notice and this permission notice appear in all copies.\n\n\
+///     THE SOFTWARE IS PROVIDED \"AS IS\" AND THE AUTHOR DISCLAIMS ALL WARRANTIES WITH REGARD \
+///     TO THIS SOFTWARE INCLUDING ALL IMPLIED WARRANTIES OF MERCHANTABILITY AND FITNESS. IN NO \
+///     EVENT SHALL THE AUTHOR BE LIABLE FOR ANY SPECIAL, DIRECT, INDIRECT, OR CONSEQUENTIAL \
+///     DAMAGES OR ANY DAMAGES WHATSOEVER RESULTING FROM LOSS OF USE, DATA OR PROFITS, WHETHER \
+///     IN AN ACTION OF CONTRACT, NEGLIGENCE OR OTHER TORTIOUS ACTION, ARISING OUT OF OR IN \
+///     CONNECTION WITH THE USE OR PERFORMANCE OF THIS SOFTWARE.\n";
+/// let found = suggest_license_classifier_from_text(text).unwrap();
+/// assert_eq!(found.classifier, Classifier::License__OSIApproved__ISCLicenseISCL);
+/// assert!(found.confidence > 0.9);
+/// ```
+#[cfg(feature = "license-text")]
+pub fn suggest_license_classifier_from_text(text: &str) -> Option<LicenseMatch> {
+    let (spdx, confidence) = crate::license_text::best_match(text)?;
+    Some(LicenseMatch {
+        classifier: license::from_spdx(spdx)?,
+        spdx,
+        confidence,
+    })
+}
+
+/// A Topic classifier matched by a project's keywords, and how well
+#[derive(Clone, Copy, Debug, PartialEq)]
+pub struct TopicMatch {
+    pub classifier: Classifier,
+    pub score: f32,
+}
+
+/// The Topic classifiers a project's keywords and one-line description point to, best first
+///
+/// Keywords are matched ignoring case and with `-` and `_` read as spaces, against an embedded
+/// index of keywords and against the names of the topics themselves, such as "cryptography" or
+/// the "DNS" of "Name Service (DNS)". A keyword in the index scores 1 for its topic, and a
+/// topic's name scores 1 shared between the topics of that name, so that "testing" scores for both
+/// software and education testing. Words and pairs of words of the description score half as
+/// much as keywords. Each distinct keyword or word counts once, and ties are in canonical order.
+///
+/// # Examples
+///
+#[cfg_attr(not(feature = "topic"), doc = "```ignore")]
+#[cfg_attr(feature = "topic", doc = "```")]
+/// use trove_classifiers::suggest::topics;
+/// use trove_classifiers::Classifier;
+///
+/// let found = topics(&["Machine-Learning", "plotting"], Some("Deep learning made simple"));
+/// assert_eq!(
+///     found[0].classifier,
+///     Classifier::Topic__ScientificEngineering__ArtificialIntelligence
+/// );
+/// assert_eq!(found[0].score, 1.5);
+/// assert_eq!(found[1].classifier, Classifier::Topic__ScientificEngineering__Visualization);
+/// ```
+pub fn topics<S: AsRef<str>>(keywords: &[S], description: Option<&str>) -> Vec<TopicMatch> {
+    let mut terms: Vec<(String, f32)> = Vec::new();
+    let mut add_term = |term: String, weight: f32| {
+        if !term.is_empty() && !terms.iter().any(|(seen, _)| *seen == term) {
+            terms.push((term, weight));
+        }
+    };
+    for keyword in keywords {
+        let words: Vec<String> = keyword
+            .as_ref()
+            .split(|c: char| c.is_whitespace() || c == '-' || c == '_')
+            .filter(|word| !word.is_empty())
+            .map(str::to_lowercase)
+            .collect();
+        add_term(words.join(" "), 1.0);
+    }
+    if let Some(description) = description {
+        let words: Vec<String> = description
+            .split(|c: char| !c.is_alphanumeric())
+            .filter(|word| !word.is_empty())
+            .map(str::to_lowercase)
+            .collect();
+        for (index, word) in words.iter().enumerate() {
+            add_term(word.clone(), 0.5);
+            if let Some(next) = words.get(index + 1) {
+                add_term(format!("{word} {next}"), 0.5);
+            }
+        }
+    }
+
+    let names: Vec<(Classifier, Vec<String>)> = Classifier::ALL
+        .iter()
+        .filter(|classifier| classifier.segments_static()[0] == "Topic")
+        .map(|classifier| (*classifier, topic_names(classifier.leaf())))
+        .collect();
+    let mut found: Vec<TopicMatch> = Vec::new();
+    let mut score = |classifier: Classifier, score: f32| match found
+        .iter_mut()
+        .find(|topic| topic.classifier == classifier)
+    {
+        Some(topic) => topic.score += score,
+        None => found.push(TopicMatch { classifier, score }),
+    };
+    for (term, weight) in &terms {
+        for (keyword, classifier) in TOPIC_KEYWORDS {
+            if keyword == term {
+                if let Ok(classifier) = Classifier::from_str(classifier) {
+                    score(classifier, *weight);
+                }
+            }
+        }
+        let named: Vec<Classifier> = names
+            .iter()
+            .filter(|(_, names)| names.contains(term))
+            .map(|(classifier, _)| *classifier)
+            .collect();
+        for classifier in &named {
+            score(*classifier, weight / named.len() as f32);
+        }
+    }
+    found.sort_by(|a, b| {
+        b.score
+            .total_cmp(&a.score)
+            .then(a.classifier.cmp(&b.classifier))
+    });
+    found
+}
+
+/// The lowercase names a topic is known by: its leaf, the parts of a leaf such as "Sound/Audio",
+/// and the abbreviation and name of a leaf such as "Name Service (DNS)"
+fn topic_names(leaf: &str) -> Vec<String> {
+    let leaf = leaf.to_lowercase();
+    let mut names = Vec::new();
+    if let Some((name, abbreviation)) = leaf.split_once(" (") {
+        names.push(name.to_string());
+        names.push(abbreviation.trim_end_matches(')').to_string());
+    }
+    if leaf.contains('/') {
+        names.extend(leaf.split('/').map(|part| part.trim().to_string()));
+    }
+    names.push(leaf);
+    names
+}
+
+/// The share of a text, in characters, a language must be detected in to be suggested
+#[cfg(feature = "language-detection")]
+const LANGUAGE_SHARE: f32 = 0.2;
+
+/// The Natural Language classifiers of the languages a text such as a README is written in, most
+/// used first
+///
+/// Each paragraph outside of fenced code blocks is detected on its own with
+/// [whatlang](https://docs.rs/whatlang), and paragraphs whose language is uncertain are skipped,
+/// so a README with a translation next to the original yields both languages. A language must
+/// make up a fifth of the text detected to be reported. Mandarin is reported as Chinese
+/// (Simplified), as its script is not told apart.
+///
+/// # Examples
+///
+/// ```
+/// use trove_classifiers::suggest::natural_languages;
+/// use trove_classifiers::Classifier;
+///
+/// let readme = "# ejemplo\n\n\
+///     Una biblioteca para validar los clasificadores de los paquetes de Python antes de \
+///     publicarlos, para que nadie tenga que esperar a que el índice los rechace.\n\n\
+///     A library validating the classifiers of Python packages before they are published, so \
+///     that nobody has to wait for the index to reject them.\n";
+/// assert_eq!(
+///     natural_languages(readme),
+///     [Classifier::NaturalLanguage__Spanish, Classifier::NaturalLanguage__English]
+/// );
+/// ```
+#[cfg(feature = "language-detection")]
+pub fn natural_languages(text: &str) -> Vec<Classifier> {
+    let mut shares: Vec<(Classifier, usize)> = Vec::new();
+    let mut fenced = false;
+    let mut paragraph = String::new();
+    let mut detect = |paragraph: &mut String| {
+        let info = whatlang::detect(paragraph).filter(whatlang::Info::is_reliable);
+        let language = info.and_then(|info| match info.lang() {
+            whatlang::Lang::Cmn => {
+                Classifier::from_str("Natural Language :: Chinese (Simplified)").ok()
+            }
+            whatlang::Lang::Nob => Classifier::from_str("Natural Language :: Norwegian").ok(),
+            whatlang::Lang::Pan => Classifier::from_str("Natural Language :: Panjabi").ok(),
+            whatlang::Lang::Slv => Classifier::from_str("Natural Language :: Slovenian").ok(),
+            lang => Classifier::from_str(&format!("Natural Language :: {}", lang.eng_name())).ok(),
+        });
+        if let Some(language) = language {
+            let length = paragraph.chars().count();
+            match shares.iter_mut().find(|(found, _)| *found == language) {
+                Some((_, share)) => *share += length,
+                None => shares.push((language, length)),
+            }
+        }
+        paragraph.clear();
+    };
+    for line in text.lines() {
+        if line.trim_start().starts_with("```") {
+            fenced = !fenced;
+            detect(&mut paragraph);
+        } else if line.trim().is_empty() {
+            detect(&mut paragraph);
+        } else if !fenced {
+            paragraph.push_str(line);
+            paragraph.push('\n');
+        }
+    }
+    detect(&mut paragraph);
+
+    let total: usize = shares.iter().map(|(_, share)| share).sum();
+    shares.sort_by(|(_, a), (_, b)| b.cmp(a));
+    shares
+        .into_iter()
+        .filter(|(_, share)| *share as f32 >= total as f32 * LANGUAGE_SHARE)
+        .map(|(language, _)| language)
+        .collect()
+}
+
+/// The `Development Status` classifier that a release of a PEP 440 version suggests
+///
+/// Development releases and `0.0.x` releases are pre-alpha, alpha releases alpha, and beta
+/// releases, release candidates and other `0.x` releases beta. Final releases from `1.0` on are
+/// production/stable. "6 - Mature" is never suggested, since only a project's history tells it
+/// apart from a stable one. Returns `None` for a version that does not start with a release
+/// segment, or when the `development-status` feature is disabled.
+///
+/// # Examples
+///
+#[cfg_attr(not(feature = "development-status"), doc = "```ignore")]
+#[cfg_attr(feature = "development-status", doc = "```")]
+/// use trove_classifiers::suggest::development_status;
+/// use trove_classifiers::Classifier;
+///
+/// assert_eq!(development_status("0.3.1"), Some(Classifier::DevelopmentStatus__4Beta));
+/// assert_eq!(
+///     development_status("2.1.0"),
+///     Some(Classifier::DevelopmentStatus__5ProductionStable)
+/// );
+/// assert_eq!(development_status("2.1.0a3"), Some(Classifier::DevelopmentStatus__3Alpha));
+/// ```
+pub fn development_status(version: &str) -> Option<Classifier> {
+    let phase = Phase::of(version)?;
+    let release = Release::parse(version.split_once('!').map_or(version, |(_, rest)| rest))?;
+    let status = match (phase, release.parts()) {
+        (Phase::Dev, _) | (Phase::Final, [0] | [0, 0, ..]) => "Development Status :: 2 - Pre-Alpha",
+        (Phase::Alpha, _) => "Development Status :: 3 - Alpha",
+        (Phase::Beta, _) | (Phase::Final, [0, ..]) => "Development Status :: 4 - Beta",
+        (Phase::Final, _) => "Development Status :: 5 - Production/Stable",
+    };
+    Classifier::from_str(status).ok()
+}
+
+/// The `Programming Language :: Python` classifiers of the versions admitted by `specifiers`
+fn python_versions(specifiers: &str) -> Vec<Classifier> {
+    let mut admitted = versions_below("Programming Language :: Python", specifiers);
+    let major = |major: u32| {
+        admitted.iter().any(|classifier| {
+            let version = classifier.segments_static()[2];
+            Release::parse(version).is_some_and(|release| release.parts()[0] == major)
+        })
+    };
+    let (python2, python3) = (major(2), major(3));
+    let mut implied = Vec::new();
+    if python3 {
+        implied.push("Programming Language :: Python :: 3");
+        if !python2 {
+            implied.push("Programming Language :: Python :: 3 :: Only");
+        }
+    }
+    admitted.extend(
+        implied
+            .into_iter()
+            .filter_map(|classifier| Classifier::from_str(classifier).ok()),
+    );
+    admitted
+}
+
+/// The classifiers directly below `parent` whose last segment is a release series admitted by
+/// `specifiers`, such as "Framework :: Django :: 4.2" for `>=4.2,<5`
+///
+/// Series of a single number, like "Framework :: Django :: 4", are only included when every
+/// more precise series below them is included too. Without specifiers nothing is admitted.
+fn versions_below(parent: &str, specifiers: &str) -> Vec<Classifier> {
+    if specifiers.trim().is_empty() {
+        return Vec::new();
+    }
+    let depth = parent.split(" :: ").count();
+    let children: Vec<(Classifier, Release)> = Classifier::ALL
+        .iter()
+        .filter(|classifier| {
+            let string = classifier.as_str();
+            string.starts_with(parent)
+                && string[parent.len()..].starts_with(" :: ")
+                && classifier.segments_static().len() == depth + 1
+        })
+        .filter_map(|&classifier| {
+            let version = classifier.segments_static()[depth];
+            let release = Release::parse(version)?;
+            // skip segments that merely start with a number
+            (release.parts().len() == version.split('.').count()).then_some((classifier, release))
+        })
+        .collect();
+    let admitted = |release: Release| admits_series(specifiers, release) == Some(true);
+    children
+        .iter()
+        .filter(|(_, release)| admitted(*release))
+        .filter(|(_, release)| {
+            release.parts().len() > 1
+                || children
+                    .iter()
+                    .filter(|(_, other)| {
+                        other.parts().len() > 1 && other.parts()[0] == release.parts()[0]
+                    })
+                    .all(|(_, other)| admitted(*other))
+        })
+        .map(|(classifier, _)| *classifier)
+        .collect()
+}
+
+/// The normalized name and the version specifiers of a PEP 508 requirement
+pub(crate) fn requirement(requirement: &str) -> (String, &str) {
+    let requirement = requirement.split(';').next().unwrap_or_default().trim();
+    let name_len = requirement
+        .find(|c: char| !(c.is_ascii_alphanumeric() || matches!(c, '-' | '_' | '.')))
+        .unwrap_or(requirement.len());
+    let (name, rest) = requirement.split_at(name_len);
+    let rest = rest.trim_start();
+    let rest = match rest.strip_prefix('[') {
+        Some(extras) => extras.split_once(']').map_or("", |(_, rest)| rest),
+        None => rest,
+    };
+    let specifiers = rest.trim().trim_start_matches('(').trim_end_matches(')');
+    // a direct reference such as `name @ https://...` has no specifiers
+    let specifiers = if specifiers.starts_with('@') {
+        ""
+    } else {
+        specifiers
+    };
+    (normalize(name), specifiers)
+}
+
+/// A distribution name normalized as described by PEP 503
+fn normalize(name: &str) -> String {
+    let mut normalized = String::with_capacity(name.len());
+    for c in name.trim().chars() {
+        if matches!(c, '-' | '_' | '.') {
+            if !normalized.ends_with('-') {
+                normalized.push('-');
+            }
+        } else {
+            normalized.push(c.to_ascii_lowercase());
+        }
+    }
+    normalized
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[cfg(feature = "all-categories")]
+    fn strings(suggestions: &[Suggestion]) -> Vec<&'static str> {
+        suggestions
+            .iter()
+            .map(|suggestion| suggestion.classifier.as_str())
+            .collect()
+    }
+
+    #[test]
+    #[cfg(feature = "framework")]
+    fn constraints_span_the_declared_versions() {
+        let constraints = dependency_constraints(&[
+            Classifier::Framework__Django__5_1,
+            Classifier::Framework__Django__4,
+            Classifier::Framework__Django__4_2,
+            Classifier::Framework__DjangoCMS,
+            Classifier::Framework__Odoo__17_0,
+        ]);
+        let requirements: Vec<String> = constraints.iter().map(ToString::to_string).collect();
+        assert_eq!(requirements, ["django>=4,<5.2", "django-cms"]);
+        assert_eq!(constraints[0].framework, Classifier::Framework__Django);
+        assert!(dependency_constraints(&[Classifier::Framework__Odoo]).is_empty());
+    }
+
+    #[test]
+    #[cfg(all(feature = "operating-system", feature = "programming-language"))]
+    fn environments_without_known_values_add_nothing() {
+        let environment = MarkerEnvironment {
+            platform_system: Some("Java".to_string()),
+            implementation_name: Some("graalpy".to_string()),
+            python_version: Some("3".to_string()),
+        };
+        assert!(from_environment(&environment).is_empty());
+        let environment = MarkerEnvironment {
+            platform_system: Some("darwin".to_string()),
+            implementation_name: Some("PyPy".to_string()),
+            ..MarkerEnvironment::default()
+        };
+        let classifiers: Vec<&str> = from_environment(&environment)
+            .iter()
+            .map(Classifier::as_str)
+            .collect();
+        assert_eq!(
+            classifiers,
+            [
+                "Operating System :: MacOS",
+                "Programming Language :: Python :: Implementation :: PyPy"
+            ]
+        );
+    }
+
+    #[test]
+    fn parses_requirements() {
+        assert_eq!(
+            requirement("Django[argon2] (>=4.2, <5) ; python_version >= '3.10'"),
+            ("django".to_string(), ">=4.2, <5")
+        );
+        assert_eq!(
+            requirement("zope.interface"),
+            ("zope-interface".to_string(), "")
+        );
+        assert_eq!(
+            requirement("flask @ https://example.com/flask.whl"),
+            ("flask".to_string(), "")
+        );
+    }
+
+    #[test]
+    #[cfg(feature = "programming-language")]
+    fn suggests_python_versions() {
+        let versions = python_versions(">=3.11, <3.14");
+        let versions: Vec<&str> = versions.iter().map(Classifier::as_str).collect();
+        assert_eq!(
+            versions,
+            [
+                "Programming Language :: Python :: 3.11",
+                "Programming Language :: Python :: 3.12",
+                "Programming Language :: Python :: 3.13",
+                "Programming Language :: Python :: 3",
+                "Programming Language :: Python :: 3 :: Only",
+            ]
+        );
+        assert!(!python_versions(">=2.7")
+            .iter()
+            .any(|classifier| classifier.as_str().ends_with("Only")));
+        assert!(python_versions("not a specifier").is_empty());
+    }
+
+    #[test]
+    #[cfg(feature = "framework")]
+    fn major_series_needs_every_minor_series() {
+        let versions = versions_below("Framework :: Django", ">=4.0,<5.1");
+        let versions: Vec<&str> = versions.iter().map(Classifier::as_str).collect();
+        assert_eq!(
+            versions,
+            [
+                "Framework :: Django :: 4",
+                "Framework :: Django :: 4.0",
+                "Framework :: Django :: 4.1",
+                "Framework :: Django :: 4.2",
+                "Framework :: Django :: 5.0",
+            ]
+        );
+    }
+
+    #[test]
+    #[cfg(feature = "all-categories")]
+    fn suggests_from_every_source() {
+        let project = Project {
+            name: Some("Pytest_Example".to_string()),
+            version: None,
+            requires_python: Some(">=3.13".to_string()),
+            license: Some("(MIT OR Apache-2.0) AND Nonsense".to_string()),
+            dependencies: vec![
+                "pywin32; sys_platform == 'win32'".to_string(),
+                "pydantic>=2".to_string(),
+            ],
+            classifiers: vec![Classifier::ProgrammingLanguage__Python__3],
+            typed: true,
+            license_text: None,
+            readme: None,
+            keywords: vec!["Testing".to_string()],
+            description: None,
+        };
+        let suggestions = suggest(&project);
+        assert_eq!(
+            strings(&suggestions),
+            [
+                "Framework :: Pydantic",
+                "Framework :: Pydantic :: 2",
+                "Framework :: Pytest",
+                "License :: OSI Approved :: Apache Software License",
+                "License :: OSI Approved :: MIT License",
+                "Operating System :: Microsoft :: Windows",
+                "Programming Language :: Python :: 3 :: Only",
+                "Programming Language :: Python :: 3.13",
+                "Programming Language :: Python :: 3.14",
+                "Topic :: Software Development :: Testing",
+                "Typing :: Typed",
+            ]
+        );
+        assert_eq!(suggestions[2].reason, Reason::PluginName);
+        assert_eq!(
+            suggestions[5].reason,
+            Reason::Dependency("pywin32".to_string())
+        );
+    }
+
+    #[test]
+    #[cfg(feature = "all-categories")]
+    fn suggests_os_independent_without_an_operating_system() {
+        let suggestions = suggest(&Project::default());
+        assert_eq!(
+            strings(&suggestions),
+            ["Operating System :: OS Independent"]
+        );
+        assert_eq!(suggestions[0].reason, Reason::NoOperatingSystem);
+
+        let project = Project {
+            classifiers: vec![Classifier::OperatingSystem__POSIX__Linux],
+            ..Project::default()
+        };
+        assert!(suggest(&project).is_empty());
+    }
+
+    #[test]
+    #[cfg(feature = "development-status")]
+    fn statuses_follow_the_version() {
+        let status = |version| development_status(version).map(|status| status.as_str());
+        assert_eq!(status("0.0.3"), Some("Development Status :: 2 - Pre-Alpha"));
+        assert_eq!(
+            status("1.0.dev1"),
+            Some("Development Status :: 2 - Pre-Alpha")
+        );
+        assert_eq!(status("0.9a1"), Some("Development Status :: 3 - Alpha"));
+        assert_eq!(status("0.9"), Some("Development Status :: 4 - Beta"));
+        assert_eq!(status("2.0rc1"), Some("Development Status :: 4 - Beta"));
+        assert_eq!(
+            status("1!1.0.post1"),
+            Some("Development Status :: 5 - Production/Stable")
+        );
+        assert_eq!(
+            status("24.10"),
+            Some("Development Status :: 5 - Production/Stable")
+        );
+        assert_eq!(status("unreleased"), None);
+    }
+
+    #[test]
+    #[cfg(feature = "all-categories")]
+    fn declared_statuses_are_kept() {
+        let mut project = Project {
+            version: Some("1.2.0".to_string()),
+            classifiers: vec![Classifier::OperatingSystem__OSIndependent],
+            ..Project::default()
+        };
+        let suggestions = suggest(&project);
+        assert_eq!(
+            strings(&suggestions),
+            ["Development Status :: 5 - Production/Stable"]
+        );
+        assert_eq!(suggestions[0].reason, Reason::Version);
+
+        project
+            .classifiers
+            .push(Classifier::DevelopmentStatus__6Mature);
+        assert!(suggest(&project).is_empty());
+    }
+
+    #[test]
+    #[cfg(all(feature = "license-text", feature = "all-categories"))]
+    fn license_files_suggest_their_license() {
+        let apache = spdx::license_id("Apache-2.0").unwrap().text();
+        let mut project = Project {
+            license_text: Some(apache.to_string()),
+            classifiers: vec![Classifier::OperatingSystem__OSIndependent],
+            ..Project::default()
+        };
+        let suggestions = suggest(&project);
+        assert_eq!(
+            strings(&suggestions),
+            ["License :: OSI Approved :: Apache Software License"]
+        );
+        assert_eq!(suggestions[0].reason, Reason::LicenseText);
+
+        project.license_text = Some(apache[..apache.len() / 4].to_string());
+        let found = suggest_license_classifier_from_text(&apache[..apache.len() / 4]).unwrap();
+        assert_eq!(found.spdx, "Apache-2.0");
+        assert!(found.confidence < LICENSE_TEXT_CONFIDENCE);
+        assert!(suggest(&project).is_empty());
+    }
+
+    #[test]
+    #[cfg(all(feature = "language-detection", feature = "all-categories"))]
+    fn readmes_suggest_their_languages() {
+        let project = Project {
+            readme: Some(
+                "# Beispiel\n\n\
+                 ```python\nimport beispiel\nprint(beispiel.version)\n```\n\n\
+                 Dieses Paket prüft die Klassifikatoren eines Projekts, bevor es veröffentlicht \
+                 wird, damit niemand auf die Ablehnung durch den Index warten muss.\n\n\
+                 Die Dokumentation beschreibt jede Regel und erklärt, wie man sie anpasst.\n"
+                    .to_string(),
+            ),
+            classifiers: vec![Classifier::OperatingSystem__OSIndependent],
+            ..Project::default()
+        };
+        let suggestions = suggest(&project);
+        assert_eq!(strings(&suggestions), ["Natural Language :: German"]);
+        assert_eq!(suggestions[0].reason, Reason::ReadmeLanguage);
+        assert!(natural_languages("```\nprint('hello')\n```\n").is_empty());
+    }
+
+    #[test]
+    #[cfg(feature = "topic")]
+    fn keywords_score_topics() {
+        for (keyword, classifier) in TOPIC_KEYWORDS {
+            assert!(Classifier::from_str(classifier).is_ok(), "{keyword}");
+        }
+        let scores = |keywords: &[&str], description| {
+            topics(keywords, description)
+                .iter()
+                .map(|topic| (topic.classifier.leaf(), topic.score))
+                .collect::<Vec<_>>()
+        };
+        assert_eq!(
+            scores(&["testing", "DNS"], None),
+            [
+                ("Testing", 1.5),
+                ("Name Service (DNS)", 1.0),
+                ("Testing", 0.5)
+            ]
+        );
+        assert_eq!(
+            scores(&["Sound"], Some("A sound mixer, for mixers")),
+            [("Sound/Audio", 1.0), ("Mixers", 0.5)]
+        );
+        assert!(scores(&["python"], Some("A library")).is_empty());
+    }
+}
@@ -0,0 +1,341 @@
+//! An overview of the classifiers a project declares.
+//!
+//! `Summary` answers the questions a reviewer asks of a project's classifiers at a glance: how
+//! many of each category it declares, which Python versions it claims to support, what kind of
+//! license it is under, what kind of project it is and which of the categories most projects
+//! declare are missing.
+//!
+//! # Examples
+//!
+#![cfg_attr(not(feature = "all-categories"), doc = "```ignore")]
+#![cfg_attr(feature = "all-categories", doc = "```")]
+//! use trove_classifiers::license::LicenseFamily;
+//! use trove_classifiers::summary::Summary;
+//! use trove_classifiers::Classifier;
+//!
+//! let summary = Summary::new(&[
+//!     Classifier::License__OSIApproved__MITLicense,
+//!     Classifier::ProgrammingLanguage__Python__3_12,
+//!     Classifier::ProgrammingLanguage__Python__3_9,
+//!     Classifier::Typing__Typed,
+//! ]);
+//! assert_eq!(summary.total, 4);
+//! assert_eq!(summary.python_versions, ["3.9", "3.12"]);
+//! assert_eq!(summary.license_families, [LicenseFamily::Permissive]);
+//! assert!(summary.missing.contains(&"Development Status"));
+//! ```
+
+use alloc::vec::Vec;
+use core::fmt;
+
+use crate::generated::CATEGORIES;
+use crate::license::{self, LicenseFamily};
+use crate::pep440::Release;
+use crate::Classifier;
+
+/// Classifier prefixes most projects declare at least one classifier under
+pub const RECOMMENDED: &[&str] = &[
+    "Development Status",
+    "Intended Audience",
+    "License",
+    "Operating System",
+    "Programming Language :: Python",
+    "Topic",
+];
+
+/// An overview of a set of classifiers
+#[derive(Clone, Debug, Default, Eq, PartialEq)]
+#[non_exhaustive]
+pub struct Summary {
+    /// How many classifiers there are, counting each once
+    pub total: usize,
+    /// The top-level categories declared and how many classifiers each holds, in canonical order
+    pub categories: Vec<(&'static str, usize)>,
+    /// The `X.Y` Python versions declared, oldest first
+    pub python_versions: Vec<&'static str>,
+    /// The families of the licenses declared, each once
+    pub license_families: Vec<LicenseFamily>,
+    /// The kind of project the classifiers describe
+    pub kind: ProjectKind,
+    /// The prefixes of `RECOMMENDED` no classifier is declared under
+    pub missing: Vec<&'static str>,
+}
+
+impl Summary {
+    pub fn new(classifiers: &[Classifier]) -> Self {
+        let mut unique: Vec<Classifier> = classifiers.to_vec();
+        unique.sort();
+        unique.dedup();
+
+        let categories = CATEGORIES
+            .iter()
+            .map(|category| {
+                let count = unique
+                    .iter()
+                    .filter(|classifier| classifier.segments_static()[0] == category.name)
+                    .count();
+                (category.name, count)
+            })
+            .filter(|(_, count)| *count > 0)
+            .collect();
+
+        let mut python_versions: Vec<(Release, &'static str)> = unique
+            .iter()
+            .filter_map(|classifier| match classifier.segments_static() {
+                ["Programming Language", "Python", version] => Release::parse(version)
+                    .filter(|release| release.parts().len() == 2)
+                    .map(|release| (release, *version)),
+                _ => None,
+            })
+            .collect();
+        python_versions.sort_by(|(a, _), (b, _)| a.parts().cmp(b.parts()));
+
+        let mut license_families: Vec<LicenseFamily> =
+            unique.iter().filter_map(|c| license::family(*c)).collect();
+        license_families.sort();
+        license_families.dedup();
+
+        let missing = RECOMMENDED
+            .iter()
+            .copied()
+            .filter(|prefix| {
+                !unique.iter().any(|classifier| {
+                    classifier
+                        .as_str()
+                        .strip_prefix(prefix)
+                        .is_some_and(|rest| rest.is_empty() || rest.starts_with(" :: "))
+                })
+            })
+            .collect();
+
+        Summary {
+            total: unique.len(),
+            categories,
+            python_versions: python_versions.into_iter().map(|(_, v)| v).collect(),
+            license_families,
+            kind: infer_project_kind(&unique),
+            missing,
+        }
+    }
+}
+
+/// A coarse kind of project, as told by its classifiers
+#[derive(Clone, Copy, Debug, Default, Eq, Hash, Ord, PartialEq, PartialOrd)]
+#[non_exhaustive]
+pub enum ProjectKind {
+    /// A package of modules for other code to import
+    Library,
+    /// A program for end users, run from a console or a desktop
+    Application,
+    /// An extension, plugin or add-on of a framework or tool
+    FrameworkPlugin,
+    /// A theme of a documentation generator, site generator or CMS
+    Theme,
+    /// A stubs-only package of type annotations for another distribution
+    StubsPackage,
+    /// The classifiers give no sign of the kind of project
+    #[default]
+    Unknown,
+}
+
+impl ProjectKind {
+    pub fn as_str(&self) -> &'static str {
+        match self {
+            ProjectKind::Library => "library",
+            ProjectKind::Application => "application",
+            ProjectKind::FrameworkPlugin => "framework plugin",
+            ProjectKind::Theme => "theme",
+            ProjectKind::StubsPackage => "stubs package",
+            ProjectKind::Unknown => "unknown",
+        }
+    }
+}
+
+impl fmt::Display for ProjectKind {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        f.pad(self.as_str())
+    }
+}
+
+/// Frameworks whose packages are nearly all plugins of them
+const PLUGIN_HOSTS: &[&str] = &[
+    "Datasette",
+    "Flake8",
+    "Hatch",
+    "Pytest",
+    "Setuptools Plugin",
+    "napari",
+    "tox",
+];
+
+/// Last segments of Framework classifiers that mark a plugin of the framework
+const PLUGIN_LEAVES: &[&str] = &[
+    "Addon",
+    "Distros",
+    "Domain",
+    "Exporters",
+    "Extension",
+    "Extensions",
+    "Instrumentations",
+    "Mime Renderers",
+    "Plugins",
+    "Prebuilt",
+    "Provider",
+    "Recipe",
+    "Widgets",
+];
+
+/// The kind of project a set of classifiers describes
+///
+/// The most specific sign wins: a stubs-only package, then a theme, then a plugin of a
+/// framework. Otherwise a `Topic :: Software Development :: Libraries` classifier makes a library,
+/// an end-user environment or audience an application, and typing or a developer audience a
+/// library again.
+///
+/// # Examples
+///
+#[cfg_attr(not(feature = "all-categories"), doc = "```ignore")]
+#[cfg_attr(feature = "all-categories", doc = "```")]
+/// use trove_classifiers::summary::{infer_project_kind, ProjectKind};
+/// use trove_classifiers::Classifier;
+///
+/// let kind = infer_project_kind(&[
+///     Classifier::Framework__Sphinx,
+///     Classifier::Framework__Sphinx__Theme,
+/// ]);
+/// assert_eq!(kind, ProjectKind::Theme);
+///
+/// let kind = infer_project_kind(&[Classifier::Environment__Console]);
+/// assert_eq!(kind, ProjectKind::Application);
+/// ```
+pub fn infer_project_kind(classifiers: &[Classifier]) -> ProjectKind {
+    let any = |signal: &dyn Fn(&[&str]) -> bool| {
+        classifiers
+            .iter()
+            .any(|classifier| signal(classifier.segments_static()))
+    };
+    if any(&|segments| segments == ["Typing", "Stubs Only"]) {
+        return ProjectKind::StubsPackage;
+    }
+    if any(&|segments| {
+        segments[0] == "Framework" && segments[segments.len() - 1].starts_with("Theme")
+    }) {
+        return ProjectKind::Theme;
+    }
+    let plugin = |segments: &[&str]| match segments {
+        ["Framework", host] => PLUGIN_HOSTS.contains(host),
+        ["Framework", .., leaf] => PLUGIN_LEAVES.contains(leaf),
+        ["Environment", "Plugins"] => true,
+        _ => false,
+    };
+    if any(&plugin) {
+        return ProjectKind::FrameworkPlugin;
+    }
+    if any(&|segments| segments.starts_with(&["Topic", "Software Development", "Libraries"])) {
+        return ProjectKind::Library;
+    }
+    let application = |segments: &[&str]| match segments {
+        ["Environment", environment, ..] => matches!(
+            *environment,
+            "Console" | "MacOS X" | "Win32 (MS Windows)" | "X11 Applications" | "Handhelds/PDA's"
+        ),
+        ["Intended Audience", audience] => audience.starts_with("End Users"),
+        _ => false,
+    };
+    if any(&application) {
+        return ProjectKind::Application;
+    }
+    if any(&|segments| segments[0] == "Typing" || segments == ["Intended Audience", "Developers"]) {
+        return ProjectKind::Library;
+    }
+    ProjectKind::Unknown
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn empty_summaries_miss_everything() {
+        let summary = Summary::new(&[]);
+        assert_eq!(summary.total, 0);
+        assert!(summary.categories.is_empty());
+        assert_eq!(summary.missing, RECOMMENDED);
+        assert_eq!(summary.kind, ProjectKind::Unknown);
+    }
+
+    #[test]
+    #[cfg(feature = "all-categories")]
+    fn kinds_prefer_the_most_specific_sign() {
+        let kind = |classifiers: &[Classifier]| infer_project_kind(classifiers);
+        assert_eq!(
+            kind(&[
+                Classifier::Typing__StubsOnly,
+                Classifier::Framework__Sphinx__Theme
+            ]),
+            ProjectKind::StubsPackage
+        );
+        assert_eq!(
+            kind(&[
+                Classifier::Framework__Pytest,
+                Classifier::Environment__Console
+            ]),
+            ProjectKind::FrameworkPlugin
+        );
+        assert_eq!(
+            kind(&[Classifier::Framework__Jupyter__JupyterLab__Extensions__Prebuilt]),
+            ProjectKind::FrameworkPlugin
+        );
+        assert_eq!(
+            kind(&[
+                Classifier::Environment__Console,
+                Classifier::Topic__SoftwareDevelopment__Libraries__PythonModules
+            ]),
+            ProjectKind::Library
+        );
+        assert_eq!(
+            kind(&[
+                Classifier::Typing__Typed,
+                Classifier::IntendedAudience__EndUsersDesktop
+            ]),
+            ProjectKind::Application
+        );
+        assert_eq!(kind(&[Classifier::Typing__Typed]), ProjectKind::Library);
+        assert_eq!(kind(&[Classifier::Framework__Django]), ProjectKind::Unknown);
+    }
+
+    #[test]
+    #[cfg(feature = "all-categories")]
+    fn counts_categories_in_canonical_order() {
+        let summary = Summary::new(&[
+            Classifier::Typing__Typed,
+            Classifier::ProgrammingLanguage__Python__3_10,
+            Classifier::ProgrammingLanguage__Python__3,
+            Classifier::ProgrammingLanguage__Python__3__Only,
+            Classifier::ProgrammingLanguage__Python__3_8,
+            Classifier::DevelopmentStatus__5ProductionStable,
+            Classifier::License__OSIApproved__GNUGeneralPublicLicensev3GPLv3,
+            Classifier::License__OSIApproved__MITLicense,
+            Classifier::Typing__Typed,
+        ]);
+        assert_eq!(summary.total, 8);
+        assert_eq!(
+            summary.categories,
+            [
+                ("Development Status", 1),
+                ("License", 2),
+                ("Programming Language", 4),
+                ("Typing", 1),
+            ]
+        );
+        assert_eq!(summary.python_versions, ["3.8", "3.10"]);
+        assert_eq!(
+            summary.license_families,
+            [LicenseFamily::Permissive, LicenseFamily::StrongCopyleft]
+        );
+        assert_eq!(
+            summary.missing,
+            ["Intended Audience", "Operating System", "Topic"]
+        );
+    }
+}
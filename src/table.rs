@@ -0,0 +1,81 @@
+use core::cmp::Ordering;
+
+use crate::Classifier;
+
+/// A top-level category and the classifiers within it
+///
+/// The canonical strings of a category are stored back to back in a single `&'static str`,
+/// which every string conversion of `Classifier` slices into. This keeps one copy of each
+/// string in the compiled artifact and avoids a pointer and length per classifier.
+pub(crate) struct CategoryTable {
+    /// The category's name, which is the first segment of every classifier in it
+    pub(crate) name: &'static str,
+    /// Every classifier in the category, in canonical order
+    pub(crate) classifiers: &'static [Classifier],
+    /// The canonical strings of `classifiers`, concatenated
+    pub(crate) text: &'static str,
+    /// The offset in `text` where each string of `classifiers` ends
+    pub(crate) ends: &'static [u16],
+    /// The index of each of `classifiers` when ordered by their ASCII-lowercased strings
+    pub(crate) sorted: &'static [u16],
+    /// The stable code of each of `classifiers`, from `data/codes.txt`
+    pub(crate) codes: &'static [u16],
+}
+
+impl CategoryTable {
+    /// The canonical string of the classifier at `index` in this category
+    pub(crate) fn string(&self, index: usize) -> &'static str {
+        let start = match index {
+            0 => 0,
+            _ => self.ends[index - 1] as usize,
+        };
+        &self.text[start..self.ends[index] as usize]
+    }
+
+    /// The classifiers of this category whose strings start with `prefix`, ignoring ASCII case
+    ///
+    /// Matches are found by binary search over `sorted`, so the work done before the first
+    /// match is logarithmic in the size of the category.
+    pub(crate) fn complete<'a>(
+        &'static self,
+        prefix: &'a str,
+    ) -> impl Iterator<Item = Classifier> + 'a {
+        let key = |index: &u16| truncated_cmp(self.string(*index as usize), prefix);
+        let start = self
+            .sorted
+            .partition_point(|index| key(index) == Ordering::Less);
+        self.sorted[start..]
+            .iter()
+            .take_while(move |index| key(index) == Ordering::Equal)
+            .map(move |&index| self.classifiers[index as usize])
+    }
+
+    /// The classifier of this category whose string is exactly `s`
+    pub(crate) fn find(&self, s: &str) -> Option<Classifier> {
+        // most candidates are rejected by length alone, without touching the text
+        let text = self.text.as_bytes();
+        let mut start = 0;
+        for (index, &end) in self.ends.iter().enumerate() {
+            let end = end as usize;
+            if end - start == s.len() && &text[start..end] == s.as_bytes() {
+                return Some(self.classifiers[index]);
+            }
+            start = end;
+        }
+        None
+    }
+
+    /// The canonical string of every classifier in this category, in order
+    pub(crate) fn strings(&'static self) -> impl Iterator<Item = &'static str> {
+        (0..self.ends.len()).map(move |index| self.string(index))
+    }
+}
+
+/// Compare `string`, cut to the length of `prefix`, with `prefix`, ignoring ASCII case
+fn truncated_cmp(string: &str, prefix: &str) -> Ordering {
+    let string = &string.as_bytes()[..string.len().min(prefix.len())];
+    string
+        .iter()
+        .map(u8::to_ascii_lowercase)
+        .cmp(prefix.bytes().map(|byte| byte.to_ascii_lowercase()))
+}
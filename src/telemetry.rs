@@ -0,0 +1,82 @@
+//! A hook told of every unknown classifier string the tolerant parsers meet.
+//!
+//! Registries and services reading metadata from the wild want to know which strings their users
+//! attempt that are not classifiers, to spot a popular typo or a classifier worth proposing
+//! upstream. `set_unknown_hook` registers a function that `Classifier::from_str_lenient`,
+//! `Aliases::parse`, `DynamicClassifier::parse`, `Interner::intern`, and with the `serde`
+//! feature `KnownAndUnknown` and `LenientClassifiers`, call with each string they find unknown.
+//! Deprecated classifiers are known, and are not reported. The strict parsers, such as `FromStr`,
+//! report nothing: their callers already have the error.
+//!
+//! There is one hook for the whole process. It is called on the parsing thread, so it should
+//! only record the string, leaving any aggregation or I/O to the application.
+//!
+//! # Examples
+//!
+//! ```
+//! use std::sync::{Arc, Mutex};
+//! use trove_classifiers::{telemetry, DynamicClassifier};
+//!
+//! let seen = Arc::new(Mutex::new(Vec::new()));
+//! let recorder = Arc::clone(&seen);
+//! telemetry::set_unknown_hook(move |unknown| recorder.lock().unwrap().push(unknown.to_string()));
+//!
+//! DynamicClassifier::parse("Typing :: Typed");
+//! DynamicClassifier::parse("Private :: Do Not Upload");
+//! telemetry::clear_unknown_hook();
+//! DynamicClassifier::parse("Private :: Internal");
+//!
+//! assert_eq!(*seen.lock().unwrap(), ["Private :: Do Not Upload"]);
+//! ```
+
+use std::sync::{Arc, PoisonError, RwLock};
+
+use crate::deprecation;
+
+type Hook = Arc<dyn Fn(&str) + Send + Sync>;
+
+static HOOK: RwLock<Option<Hook>> = RwLock::new(None);
+
+/// Call `hook` with every unknown string the tolerant parsers meet, replacing any earlier hook
+pub fn set_unknown_hook(hook: impl Fn(&str) + Send + Sync + 'static) {
+    *HOOK.write().unwrap_or_else(PoisonError::into_inner) = Some(Arc::new(hook));
+}
+
+/// Stop calling the hook registered by `set_unknown_hook`
+pub fn clear_unknown_hook() {
+    *HOOK.write().unwrap_or_else(PoisonError::into_inner) = None;
+}
+
+/// Report a string a tolerant parser did not recognize, unless it is a deprecated classifier
+pub(crate) fn unknown(s: &str) {
+    let hook = HOOK.read().unwrap_or_else(PoisonError::into_inner).clone();
+    // the lock is released before calling, so the hook may replace itself
+    if let Some(hook) = hook {
+        if deprecation(s.trim()).is_none() {
+            hook(s);
+        }
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use std::sync::Mutex;
+
+    use super::*;
+    use crate::Classifier;
+
+    #[test]
+    fn tolerant_parsers_report_unknown_strings() {
+        let seen = Arc::new(Mutex::new(Vec::new()));
+        let recorder = Arc::clone(&seen);
+        set_unknown_hook(move |unknown| recorder.lock().unwrap().push(unknown.to_string()));
+        let _ = Classifier::from_str_lenient("typing :: tpyed");
+        let _ = Classifier::from_str_lenient("Natural Language :: Ukranian");
+        let _ = "Typing :: Untyped".parse::<Classifier>();
+        clear_unknown_hook();
+        let seen = seen.lock().unwrap();
+        assert!(seen.iter().any(|unknown| unknown == "typing :: tpyed"));
+        assert!(!seen.iter().any(|unknown| unknown.contains("Ukranian")));
+        assert!(!seen.iter().any(|unknown| unknown == "Typing :: Untyped"));
+    }
+}
@@ -0,0 +1,123 @@
+//! [minijinja](https://docs.rs/minijinja) filters and functions for templates writing
+//! classifiers.
+//!
+//! Scaffolding and code generation tools template `pyproject.toml` files and documentation.
+//! `register` adds helpers to a minijinja `Environment` so those templates handle classifiers
+//! the way this crate does, each available as a filter and as a function of the same name:
+//!
+//! * `is_classifier(value)`: whether a string is a classifier pypi.org accepts, also available
+//!   as the test `classifier`
+//! * `classifier_leaf(value)`: the last segment of a classifier, such as `3.12`
+//! * `sort_classifiers(values)`: strings sorted in canonical order, unknown ones last, as
+//!   `sort_classifier_strings` sorts them
+//! * `classifiers_markdown(values)`: the Markdown table of `export::to_markdown`
+//!
+//! A string that is not a known classifier given to `classifier_leaf` or `classifiers_markdown`
+//! fails the render with an error naming it, so a typo in a template's data is caught when the
+//! template is rendered rather than when the generated project is uploaded.
+//!
+//! # Examples
+//!
+//! ```
+//! use minijinja::{context, Environment};
+//! use trove_classifiers::template;
+//!
+//! let mut env = Environment::new();
+//! template::register(&mut env);
+//! let source = "{% for c in classifiers|sort_classifiers %}{% if c is classifier %}\
+//!               {{ c|classifier_leaf }};{% endif %}{% endfor %}";
+//! let rendered = env.render_str(
+//!     source,
+//!     context! { classifiers => ["Typing :: Typed", "Private :: Mine", "Framework :: Django"] },
+//! )?;
+//! assert_eq!(rendered, "Django;Typed;");
+//! # Ok::<(), minijinja::Error>(())
+//! ```
+
+use minijinja::{Environment, Error, ErrorKind};
+
+use crate::export::to_markdown;
+use crate::{sort_classifier_strings, Classifier};
+
+/// Add the filters, functions and test of this module to `env`
+pub fn register(env: &mut Environment<'_>) {
+    env.add_filter("is_classifier", is_classifier);
+    env.add_function("is_classifier", is_classifier);
+    env.add_test("classifier", is_classifier);
+    env.add_filter("classifier_leaf", classifier_leaf);
+    env.add_function("classifier_leaf", classifier_leaf);
+    env.add_filter("sort_classifiers", sort_classifiers);
+    env.add_function("sort_classifiers", sort_classifiers);
+    env.add_filter("classifiers_markdown", classifiers_markdown);
+    env.add_function("classifiers_markdown", classifiers_markdown);
+}
+
+fn is_classifier(value: &str) -> bool {
+    value.parse::<Classifier>().is_ok()
+}
+
+fn classifier_leaf(value: &str) -> Result<&'static str, Error> {
+    parse(value).map(|classifier| classifier.leaf())
+}
+
+fn sort_classifiers(mut values: Vec<String>) -> Vec<String> {
+    sort_classifier_strings(&mut values);
+    values
+}
+
+fn classifiers_markdown(values: Vec<String>) -> Result<String, Error> {
+    let classifiers = values
+        .iter()
+        .map(|value| parse(value))
+        .collect::<Result<Vec<Classifier>, Error>>()?;
+    Ok(to_markdown(&classifiers))
+}
+
+/// A classifier, or the template error of a string that is not one
+fn parse(value: &str) -> Result<Classifier, Error> {
+    value.parse().map_err(|_| {
+        Error::new(
+            ErrorKind::InvalidOperation,
+            format!("{value:?} is not a known classifier"),
+        )
+    })
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    fn render(source: &str, values: &[&str]) -> Result<String, Error> {
+        let mut env = Environment::new();
+        register(&mut env);
+        env.render_str(source, minijinja::context! { values => values })
+    }
+
+    #[test]
+    #[cfg(feature = "typing")]
+    fn helpers_are_filters_and_functions() {
+        let values = ["Typing :: Typed", "Typing :: Stubs Only"];
+        assert_eq!(
+            render("{{ values|classifiers_markdown }}", &values).unwrap(),
+            render("{{ classifiers_markdown(values) }}", &values).unwrap(),
+        );
+        assert_eq!(
+            render(
+                "{{ is_classifier(values[0]) }} {{ 'Typing'|is_classifier }}",
+                &values
+            )
+            .unwrap(),
+            "True False"
+        );
+    }
+
+    #[test]
+    fn unknown_classifiers_fail_the_render() {
+        let error = render("{{ values|classifiers_markdown }}", &["Typing :: Typo"]).unwrap_err();
+        assert_eq!(error.kind(), ErrorKind::InvalidOperation);
+        assert!(error
+            .to_string()
+            .contains("\"Typing :: Typo\" is not a known classifier"));
+        assert!(render("{{ classifier_leaf('Typing') }}", &[]).is_err());
+    }
+}
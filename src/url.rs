@@ -0,0 +1,130 @@
+//! Percent-encoding of classifiers in URLs and query strings.
+//!
+//! pypi.org links to classifiers with form encoded queries, as Python's `urllib.parse.quote_plus`
+//! writes them: ASCII letters, digits and `-._~` are kept, spaces become `+`, and every other
+//! byte, including the `::` separators, slashes, plus signs and parentheses, becomes `%XX`.
+//! `encode` and `decode` convert classifiers to and from that form, and `search_url` builds the
+//! link `Classifier::url` returns for any number of classifiers.
+//!
+//! # Examples
+//!
+//! ```
+//! use trove_classifiers::url;
+//!
+//! let encoded = url::encode("Programming Language :: C++");
+//! assert_eq!(encoded, "Programming+Language+%3A%3A+C%2B%2B");
+//! assert_eq!(url::decode(&encoded).as_deref(), Ok("Programming Language :: C++"));
+//! ```
+
+use alloc::string::String;
+use alloc::vec::Vec;
+use core::fmt::{self, Write};
+
+use crate::Classifier;
+
+/// The pypi.org page searching projects by classifier
+pub const SEARCH_URL: &str = "https://pypi.org/search/";
+
+/// The error returned when a string cannot be decoded
+#[derive(Clone, Copy, Debug, Eq, PartialEq)]
+#[non_exhaustive]
+pub enum DecodeError {
+    /// A `%` is not followed by two hexadecimal digits, at this byte offset
+    InvalidEscape(usize),
+    /// The decoded bytes are not UTF-8
+    InvalidUtf8,
+}
+
+impl fmt::Display for DecodeError {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        match self {
+            DecodeError::InvalidEscape(offset) => {
+                write!(f, "invalid percent escape at byte {offset}")
+            }
+            DecodeError::InvalidUtf8 => f.write_str("decoded bytes are not UTF-8"),
+        }
+    }
+}
+
+#[cfg(feature = "std")]
+impl std::error::Error for DecodeError {}
+
+/// A string form encoded for a query string, as pypi.org encodes classifiers
+pub fn encode(s: &str) -> String {
+    let mut encoded = String::with_capacity(s.len());
+    for byte in s.bytes() {
+        match byte {
+            b' ' => encoded.push('+'),
+            b'A'..=b'Z' | b'a'..=b'z' | b'0'..=b'9' | b'-' | b'.' | b'_' | b'~' => {
+                encoded.push(byte as char)
+            }
+            _ => write!(encoded, "%{byte:02X}").unwrap(),
+        }
+    }
+    encoded
+}
+
+/// A form encoded string decoded, with `+` as a space and `%XX` escapes in either case
+pub fn decode(encoded: &str) -> Result<String, DecodeError> {
+    let bytes = encoded.as_bytes();
+    let mut decoded = Vec::with_capacity(bytes.len());
+    let mut index = 0;
+    while index < bytes.len() {
+        match bytes[index] {
+            b'+' => decoded.push(b' '),
+            b'%' => {
+                let escape = bytes
+                    .get(index + 1..index + 3)
+                    .filter(|hex| hex.iter().all(u8::is_ascii_hexdigit))
+                    .and_then(|hex| core::str::from_utf8(hex).ok())
+                    .and_then(|hex| u8::from_str_radix(hex, 16).ok())
+                    .ok_or(DecodeError::InvalidEscape(index))?;
+                decoded.push(escape);
+                index += 2;
+            }
+            byte => decoded.push(byte),
+        }
+        index += 1;
+    }
+    String::from_utf8(decoded).map_err(|_| DecodeError::InvalidUtf8)
+}
+
+/// The pypi.org search URL listing the projects declaring every one of `classifiers`
+pub fn search_url(classifiers: &[Classifier]) -> String {
+    let mut url = String::from(SEARCH_URL);
+    for (index, classifier) in classifiers.iter().enumerate() {
+        url.push_str(if index == 0 { "?c=" } else { "&c=" });
+        url.push_str(&encode(classifier.as_str()));
+    }
+    url
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn decoding_reverses_encoding() {
+        for &classifier in Classifier::ALL {
+            assert_eq!(
+                decode(&encode(classifier.as_str())).unwrap(),
+                classifier.as_str()
+            );
+        }
+        assert_eq!(encode("a/b (c) ü"), "a%2Fb+%28c%29+%C3%BC");
+        assert_eq!(decode("a%2fb%20c+d").unwrap(), "a/b c d");
+        assert_eq!(decode("100%"), Err(DecodeError::InvalidEscape(3)));
+        assert_eq!(decode("%+1"), Err(DecodeError::InvalidEscape(0)));
+        assert_eq!(decode("%FF"), Err(DecodeError::InvalidUtf8));
+    }
+
+    #[test]
+    #[cfg(feature = "typing")]
+    fn search_urls_repeat_the_parameter() {
+        assert_eq!(search_url(&[]), SEARCH_URL);
+        assert_eq!(
+            search_url(&[Classifier::Typing__Typed, Classifier::Typing__StubsOnly]),
+            "https://pypi.org/search/?c=Typing+%3A%3A+Typed&c=Typing+%3A%3A+Stubs+Only"
+        );
+    }
+}
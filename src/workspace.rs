@@ -0,0 +1,443 @@
+//! Discovery and checking of every Python project below a directory.
+//!
+//! Platform teams of monorepos and organizations with many repositories check all their projects
+//! at once. A `Workspace` walks a directory tree, finds each project by the file declaring its
+//! classifiers, lints those against an optional `Policy`, and aggregates the outcome:
+//!
+//! * a directory holding a `pyproject.toml` or a `setup.cfg` is a project; when it holds both,
+//!   the first of them declaring classifiers is read
+//! * a `.dist-info` or `.egg-info` directory is an installed or built distribution, read from
+//!   its `METADATA` or `PKG-INFO`, as is the `PKG-INFO` at the root of an unpacked sdist
+//! * hidden directories, such as `.git` and `.venv`, and the directories of `SKIPPED` are not
+//!   entered, so virtual environments and caches are not mistaken for projects
+//!
+//! Symbolic links are not followed.
+//!
+//! # Examples
+//!
+//! ```no_run
+//! use std::path::Path;
+//! use trove_classifiers::policy::Policy;
+//! use trove_classifiers::workspace::Workspace;
+//!
+//! let policy = Policy::from_toml("required = ['Typing :: Typed']\n")?;
+//! let report = Workspace::new().policy(policy).scan(Path::new("."));
+//! for project in &report.projects {
+//!     println!("{}: {} findings", project.path.display(), project.findings.len());
+//! }
+//! for (classifier, projects) in &report.usage {
+//!     println!("{projects:5} {classifier}");
+//! }
+//! # Ok::<(), trove_classifiers::policy::PolicyError>(())
+//! ```
+
+use std::fmt;
+use std::fs;
+use std::io;
+use std::path::{Path, PathBuf};
+
+use crate::lint::{self, Context, Declared, Finding, Linter};
+use crate::policy::Policy;
+use crate::pyproject::{self, PyprojectError};
+use crate::scanner::{is_metadata_file, Scanner};
+use crate::{setup_cfg, Classifier};
+
+/// The names of directories that are never entered, besides hidden ones
+pub const SKIPPED: &[&str] = &["__pycache__", "node_modules", "site-packages", "venv"];
+
+/// The project files of a directory, in the order they are preferred
+const PROJECT_FILES: [(&str, Source); 2] = [
+    ("pyproject.toml", Source::Pyproject),
+    ("setup.cfg", Source::SetupCfg),
+];
+
+/// The kind of file a project's classifiers were read from
+#[derive(Clone, Copy, Debug, Eq, Hash, PartialEq)]
+#[non_exhaustive]
+pub enum Source {
+    /// The `[project]` table of a `pyproject.toml`
+    Pyproject,
+    /// The `[metadata]` section of a `setup.cfg`
+    SetupCfg,
+    /// The core metadata of a distribution, in a `METADATA` or `PKG-INFO` file
+    Metadata,
+}
+
+/// A project found in the workspace
+#[derive(Clone, Debug, Eq, PartialEq)]
+pub struct Project {
+    /// The file the classifiers were read from
+    pub path: PathBuf,
+    pub source: Source,
+    /// Every classifier declaration, in order
+    pub declared: Vec<Declared>,
+    /// The known classifiers declared, each once, in the order they were first declared
+    pub classifiers: Vec<Classifier>,
+    /// The lint findings, checked against the workspace's policy if it has one
+    pub findings: Vec<Finding>,
+}
+
+impl Project {
+    /// Whether none of the findings would make pypi.org reject an upload
+    pub fn passes(&self) -> bool {
+        lint::passes(&self.findings)
+    }
+}
+
+/// The error of a file or directory that could not be read
+#[derive(Debug)]
+#[non_exhaustive]
+pub enum WorkspaceError {
+    /// The file or directory could not be read
+    Io(PathBuf, io::Error),
+    /// The `pyproject.toml` is not valid TOML, or its classifiers are not an array of strings
+    Pyproject(PathBuf, PyprojectError),
+}
+
+impl WorkspaceError {
+    /// The path of the file or directory that could not be read
+    pub fn path(&self) -> &Path {
+        match self {
+            WorkspaceError::Io(path, _) | WorkspaceError::Pyproject(path, _) => path,
+        }
+    }
+}
+
+impl fmt::Display for WorkspaceError {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        match self {
+            WorkspaceError::Io(path, err) => write!(f, "{}: {err}", path.display()),
+            WorkspaceError::Pyproject(path, err) => write!(f, "{}: {err}", path.display()),
+        }
+    }
+}
+
+impl std::error::Error for WorkspaceError {
+    fn source(&self) -> Option<&(dyn std::error::Error + 'static)> {
+        match self {
+            WorkspaceError::Io(_, err) => Some(err),
+            WorkspaceError::Pyproject(_, err) => Some(err),
+        }
+    }
+}
+
+/// The projects of a workspace, and what they declare together
+#[derive(Debug, Default)]
+pub struct WorkspaceReport {
+    /// Every project found, ordered by path
+    pub projects: Vec<Project>,
+    /// The known classifiers declared and how many projects declare each, in canonical order
+    pub usage: Vec<(Classifier, usize)>,
+    /// The unknown strings declared and how many projects declare each, most declared first
+    pub unknown: Vec<(String, usize)>,
+    /// The files and directories that could not be read
+    pub errors: Vec<WorkspaceError>,
+}
+
+impl WorkspaceReport {
+    /// Every project's findings, with the project
+    pub fn findings(&self) -> impl Iterator<Item = (&Project, &Finding)> {
+        self.projects.iter().flat_map(|project| {
+            project
+                .findings
+                .iter()
+                .map(move |finding| (project, finding))
+        })
+    }
+
+    /// Whether every project passes and every file could be read
+    pub fn passes(&self) -> bool {
+        self.errors.is_empty() && self.projects.iter().all(Project::passes)
+    }
+
+    fn tally(&mut self) {
+        let mut usage: Vec<Classifier> = Vec::new();
+        let mut unknown: Vec<&str> = Vec::new();
+        for project in &self.projects {
+            usage.extend(&project.classifiers);
+            let mut strings: Vec<&str> = project
+                .declared
+                .iter()
+                .map(|declared| declared.value.trim())
+                .filter(|value| value.parse::<Classifier>().is_err())
+                .collect();
+            strings.sort_unstable();
+            strings.dedup();
+            unknown.extend(strings);
+        }
+        usage.sort_unstable();
+        self.usage = counts(usage);
+        unknown.sort_unstable();
+        self.unknown = counts(unknown)
+            .into_iter()
+            .map(|(value, count)| (value.to_string(), count))
+            .collect();
+        self.unknown
+            .sort_by_key(|(_, count)| std::cmp::Reverse(*count));
+    }
+}
+
+/// Each distinct value of a sorted list and how many times it occurs
+fn counts<T: PartialEq>(sorted: Vec<T>) -> Vec<(T, usize)> {
+    let mut counts: Vec<(T, usize)> = Vec::new();
+    for value in sorted {
+        match counts.last_mut() {
+            Some((last, count)) if *last == value => *count += 1,
+            _ => counts.push((value, 1)),
+        }
+    }
+    counts
+}
+
+/// Finds and checks the projects below a directory
+#[derive(Debug, Default)]
+pub struct Workspace {
+    policy: Option<Policy>,
+    skipped: Vec<String>,
+}
+
+impl Workspace {
+    pub fn new() -> Self {
+        Self::default()
+    }
+
+    /// Check every project against `policy` rather than with the default rule levels
+    pub fn policy(mut self, policy: Policy) -> Self {
+        self.policy = Some(policy);
+        self
+    }
+
+    /// Do not enter directories named `name`, besides hidden ones and those of `SKIPPED`
+    pub fn skip(mut self, name: &str) -> Self {
+        self.skipped.push(name.to_string());
+        self
+    }
+
+    /// Find and check every project below `root`
+    pub fn scan(&self, root: &Path) -> WorkspaceReport {
+        let mut report = WorkspaceReport::default();
+        let mut scanner = Scanner::new();
+        let mut pending = vec![root.to_path_buf()];
+        while let Some(dir) = pending.pop() {
+            let entries = match fs::read_dir(&dir) {
+                Ok(entries) => entries,
+                Err(err) => {
+                    report.errors.push(WorkspaceError::Io(dir, err));
+                    continue;
+                }
+            };
+            let mut metadata = Vec::new();
+            let mut project_files = Vec::new();
+            for entry in entries {
+                let entry = match entry {
+                    Ok(entry) => entry,
+                    Err(err) => {
+                        report.errors.push(WorkspaceError::Io(dir.clone(), err));
+                        continue;
+                    }
+                };
+                let path = entry.path();
+                let name = entry.file_name();
+                let name = name.to_string_lossy();
+                match entry.file_type() {
+                    Ok(kind) if kind.is_dir() => {
+                        if !self.is_skipped(&name) {
+                            pending.push(path);
+                        }
+                    }
+                    Ok(kind) if kind.is_file() => {
+                        if let Some(rank) = PROJECT_FILES.iter().position(|(file, _)| *file == name)
+                        {
+                            project_files.push((rank, path));
+                        } else if is_metadata_file(&path) && is_distribution(&dir, &name) {
+                            metadata.push(path);
+                        }
+                    }
+                    Ok(_) => {}
+                    Err(err) => report.errors.push(WorkspaceError::Io(path, err)),
+                }
+            }
+            project_files.sort();
+            let mut candidates = Vec::new();
+            for (rank, path) in project_files {
+                match self.read(&path, PROJECT_FILES[rank].1, &mut scanner) {
+                    Ok(project) => candidates.push(project),
+                    Err(err) => report.errors.push(err),
+                }
+            }
+            let preferred = candidates
+                .iter()
+                .position(|project| !project.declared.is_empty())
+                .unwrap_or_default();
+            report
+                .projects
+                .extend(candidates.into_iter().nth(preferred));
+            // a distribution is one project, whichever metadata files it has
+            metadata.sort();
+            metadata.truncate(1);
+            for path in metadata {
+                match self.read(&path, Source::Metadata, &mut scanner) {
+                    Ok(project) => report.projects.push(project),
+                    Err(err) => report.errors.push(err),
+                }
+            }
+        }
+        report.projects.sort_by(|a, b| a.path.cmp(&b.path));
+        report.tally();
+        report
+    }
+
+    fn is_skipped(&self, name: &str) -> bool {
+        name.starts_with('.')
+            || SKIPPED.contains(&name)
+            || self.skipped.iter().any(|skipped| skipped == name)
+    }
+
+    /// Read and check the project declared by one file
+    fn read(
+        &self,
+        path: &Path,
+        source: Source,
+        scanner: &mut Scanner,
+    ) -> Result<Project, WorkspaceError> {
+        let contents =
+            fs::read_to_string(path).map_err(|err| WorkspaceError::Io(path.to_path_buf(), err))?;
+        let declared = match source {
+            Source::Pyproject => pyproject::classifiers(&contents)
+                .map_err(|err| WorkspaceError::Pyproject(path.to_path_buf(), err))?
+                .map_or_else(Vec::new, |array| {
+                    array.entries.iter().map(|entry| entry.declared()).collect()
+                }),
+            Source::SetupCfg => setup_cfg::declared(&contents),
+            Source::Metadata => scanner.declared_str(&contents),
+        };
+        let mut classifiers: Vec<Classifier> = Vec::new();
+        for classifier in declared
+            .iter()
+            .filter_map(|declared| declared.value.parse().ok())
+        {
+            if !classifiers.contains(&classifier) {
+                classifiers.push(classifier);
+            }
+        }
+        let findings = match &self.policy {
+            Some(policy) => policy.linter().check(&policy.context(&declared)),
+            None => Linter::new().check(&Context::new(&declared)),
+        };
+        Ok(Project {
+            path: path.to_path_buf(),
+            source,
+            declared,
+            classifiers,
+            findings,
+        })
+    }
+}
+
+/// Whether the metadata file `name` in `dir` describes a distribution: that of a `.dist-info` or
+/// `.egg-info` directory, or the `PKG-INFO` at the root of an sdist
+fn is_distribution(dir: &Path, name: &str) -> bool {
+    let dir_name = dir
+        .file_name()
+        .map(|name| name.to_string_lossy())
+        .unwrap_or_default();
+    dir_name.ends_with(".dist-info")
+        || dir_name.ends_with(".egg-info")
+        || (name == "PKG-INFO" && dir.join("setup.py").is_file())
+}
+
+#[cfg(all(test, feature = "typing"))]
+mod tests {
+    use super::*;
+
+    fn workspace(name: &str, files: &[(&str, &str)]) -> PathBuf {
+        let root =
+            std::env::temp_dir().join(format!("trove-workspace-{}-{name}", std::process::id()));
+        let _ = fs::remove_dir_all(&root);
+        for (path, contents) in files {
+            let path = root.join(path);
+            fs::create_dir_all(path.parent().unwrap()).unwrap();
+            fs::write(path, contents).unwrap();
+        }
+        root
+    }
+
+    #[test]
+    fn finds_projects_and_counts_their_classifiers() {
+        let root = workspace(
+            "find",
+            &[
+                (
+                    "a/pyproject.toml",
+                    "[project]\nclassifiers = ['Typing :: Typed', 'Private :: Mine']\n",
+                ),
+                ("a/setup.cfg", "[flake8]\nmax-line-length = 99\n"),
+                (
+                    "b/setup.cfg",
+                    "[metadata]\nclassifiers =\n    Typing :: Typed\n    Private :: Mine\n",
+                ),
+                (
+                    "c/c-1.0.dist-info/METADATA",
+                    "Metadata-Version: 2.1\nClassifier: Typing :: Stubs Only\n",
+                ),
+                ("c/.venv/pyproject.toml", "[project]\n"),
+                ("c/node_modules/x/pyproject.toml", "[project]\n"),
+                (
+                    "d/pyproject.toml",
+                    "[project]\nclassifiers = 'Typing :: Typed'\n",
+                ),
+            ],
+        );
+        let report = Workspace::new().scan(&root);
+        let found: Vec<(PathBuf, Source)> = report
+            .projects
+            .iter()
+            .map(|project| {
+                (
+                    project.path.strip_prefix(&root).unwrap().into(),
+                    project.source,
+                )
+            })
+            .collect();
+        assert_eq!(
+            found,
+            [
+                ("a/pyproject.toml".into(), Source::Pyproject),
+                ("b/setup.cfg".into(), Source::SetupCfg),
+                ("c/c-1.0.dist-info/METADATA".into(), Source::Metadata),
+            ]
+        );
+        assert_eq!(
+            report.usage,
+            [
+                (Classifier::Typing__StubsOnly, 1),
+                (Classifier::Typing__Typed, 2)
+            ]
+        );
+        assert_eq!(report.unknown, [("Private :: Mine".to_string(), 2)]);
+        assert_eq!(report.errors.len(), 1);
+        assert!(report.errors[0].path().ends_with("d/pyproject.toml"));
+        assert!(!report.passes());
+        fs::remove_dir_all(root).unwrap();
+    }
+
+    #[test]
+    fn projects_are_checked_against_the_policy() {
+        let root = workspace(
+            "policy",
+            &[
+                (
+                    "a/pyproject.toml",
+                    "[project]\nclassifiers = ['Typing :: Typed']\n",
+                ),
+                ("b/pyproject.toml", "[project]\nclassifiers = []\n"),
+            ],
+        );
+        let policy = Policy::from_toml("required = ['Typing :: Typed']\n").unwrap();
+        let report = Workspace::new().policy(policy).skip("a").scan(&root);
+        let rules: Vec<lint::Rule> = report.findings().map(|(_, finding)| finding.rule).collect();
+        assert_eq!(rules, [lint::Rule::Required]);
+        assert_eq!(report.projects.len(), 1);
+        fs::remove_dir_all(root).unwrap();
+    }
+}
@@ -0,0 +1,341 @@
+//! The upstream classifier dataset and the names derived from it.
+
+use std::path::Path;
+
+use crate::{read, Result};
+
+/// The upstream dataset as recorded in `data/`.
+pub struct Dataset {
+    pub version: String,
+    pub classifiers: Vec<Entry>,
+    pub categories: Vec<Category>,
+    /// Classifiers pypi.org no longer accepts, ordered by their string.
+    pub deprecated: Vec<Deprecated>,
+    /// Earlier snapshots of the dataset, oldest first.
+    pub history: Vec<Snapshot>,
+    /// The codes of classifiers that have left the dataset, which are never reassigned.
+    pub retired_codes: Vec<u16>,
+}
+
+/// An earlier version of the dataset, recorded as its difference from the current one.
+pub struct Snapshot {
+    pub version: String,
+    /// Classifiers of the snapshot that are no longer in the dataset.
+    pub removed_since: Vec<String>,
+    /// Classifiers of the dataset that were not in the snapshot.
+    pub added_since: Vec<String>,
+}
+
+impl Snapshot {
+    /// Read `data/history/<version>.txt`, made of `+` lines for `removed_since` and `-` lines
+    /// for `added_since`.
+    pub fn parse(version: &str, contents: &str) -> Result<Self> {
+        let mut snapshot = Snapshot {
+            version: version.to_string(),
+            removed_since: Vec::new(),
+            added_since: Vec::new(),
+        };
+        for line in contents.lines().filter(|line| !line.is_empty()) {
+            match line.split_at(1) {
+                ("+", string) => snapshot.removed_since.push(string.to_string()),
+                ("-", string) => snapshot.added_since.push(string.to_string()),
+                _ => return Err(format!("history of {version}: malformed line {line:?}")),
+            }
+        }
+        Ok(snapshot)
+    }
+
+    pub fn render(&self) -> String {
+        let mut out = String::new();
+        for string in &self.removed_since {
+            out.push_str(&format!("+{string}\n"));
+        }
+        for string in &self.added_since {
+            out.push_str(&format!("-{string}\n"));
+        }
+        out
+    }
+
+    /// The classifiers of the snapshot, given those of the dataset it is recorded against.
+    pub fn classifiers(&self, current: &[String]) -> Vec<String> {
+        let mut classifiers: Vec<String> = current
+            .iter()
+            .filter(|string| !self.added_since.contains(string))
+            .chain(&self.removed_since)
+            .cloned()
+            .collect();
+        classifiers.sort();
+        classifiers
+    }
+
+    /// Record `classifiers` of `version` against the dataset `current`.
+    pub fn between(version: &str, classifiers: &[String], current: &[String]) -> Self {
+        Snapshot {
+            version: version.to_string(),
+            removed_since: classifiers
+                .iter()
+                .filter(|string| !current.contains(string))
+                .cloned()
+                .collect(),
+            added_since: current
+                .iter()
+                .filter(|string| !classifiers.contains(string))
+                .cloned()
+                .collect(),
+        }
+    }
+}
+
+/// The stable code of every classifier that has ever been in the dataset, as recorded in
+/// `data/codes.txt`: one `<code>\t<classifier>` line each, in the order the codes were assigned.
+///
+/// Codes start at 1 and are only ever appended, so a code keeps meaning the same classifier
+/// across versions of the dataset, even after that classifier is removed.
+pub struct Codes {
+    pub entries: Vec<(u16, String)>,
+}
+
+impl Codes {
+    pub fn parse(contents: &str) -> Result<Self> {
+        let mut entries: Vec<(u16, String)> = Vec::new();
+        for line in contents.lines().filter(|line| !line.is_empty()) {
+            let (code, string) = line
+                .split_once('\t')
+                .and_then(|(code, string)| Some((code.parse::<u16>().ok()?, string)))
+                .ok_or(format!("codes: malformed line {line:?}"))?;
+            if code == 0 || entries.iter().any(|(existing, _)| *existing >= code) {
+                return Err(format!(
+                    "codes: {code} is not greater than every earlier code"
+                ));
+            }
+            if entries.iter().any(|(_, existing)| existing == string) {
+                return Err(format!("codes: {string:?} has more than one code"));
+            }
+            entries.push((code, string.to_string()));
+        }
+        Ok(Codes { entries })
+    }
+
+    pub fn code(&self, string: &str) -> Option<u16> {
+        self.entries
+            .iter()
+            .find(|(_, existing)| existing == string)
+            .map(|&(code, _)| code)
+    }
+
+    /// Assign the next free codes to the `classifiers` that have none yet.
+    pub fn assign(&mut self, classifiers: &[&str]) -> Result<()> {
+        for string in classifiers {
+            if self.code(string).is_some() {
+                continue;
+            }
+            let last = self.entries.last().map_or(0, |&(code, _)| code);
+            let code = last
+                .checked_add(1)
+                .ok_or("codes: every u16 code is taken")?;
+            self.entries.push((code, string.to_string()));
+        }
+        Ok(())
+    }
+
+    pub fn render(&self) -> String {
+        self.entries
+            .iter()
+            .map(|(code, string)| format!("{code}\t{string}\n"))
+            .collect()
+    }
+}
+
+/// Compare dotted version strings numerically, so that `2024.9.1` sorts before `2024.10.21`.
+pub fn version_key(version: &str) -> Vec<u64> {
+    version
+        .split('.')
+        .map(|part| part.parse().unwrap_or(0))
+        .collect()
+}
+
+/// A classifier that is no longer accepted, and the classifiers replacing it.
+pub struct Deprecated {
+    pub string: String,
+    pub replacements: Vec<String>,
+}
+
+/// A single classifier.
+#[derive(Clone)]
+pub struct Entry {
+    /// The canonical string, e.g. `Typing :: Typed`.
+    pub string: String,
+    /// The enum variant, e.g. `Typing__Typed`.
+    pub variant: String,
+    /// The stable code recorded in `data/codes.txt`.
+    pub code: u16,
+}
+
+/// A top-level category and the classifiers within it.
+pub struct Category {
+    /// The category's name, e.g. `Development Status`.
+    pub name: String,
+    /// The variant of the generated `Category` enum, e.g. `DevelopmentStatus`.
+    pub variant: String,
+    /// The generated module holding the category's data, e.g. `development_status`.
+    pub module: String,
+    /// The cargo feature enabling the category, e.g. `development-status`.
+    pub feature: String,
+    /// The index in `Dataset::classifiers` of the category's first classifier.
+    pub start: usize,
+    pub classifiers: Vec<Entry>,
+}
+
+impl Dataset {
+    pub fn load(data: &Path) -> Result<Self> {
+        let version = read(&data.join("VERSION"))?.trim().to_string();
+        let strings: Vec<String> = read(&data.join("classifiers.txt"))?
+            .lines()
+            .filter(|line| !line.is_empty())
+            .map(str::to_string)
+            .collect();
+
+        let codes = Codes::parse(&read(&data.join("codes.txt"))?)?;
+
+        let mut classifiers: Vec<Entry> = Vec::with_capacity(strings.len());
+        let mut categories: Vec<Category> = Vec::new();
+        for string in strings {
+            let code = codes.code(&string).ok_or(format!(
+                "{string:?} has no code in data/codes.txt, run `cargo xtask fetch`"
+            ))?;
+            let variant = variant_name(&string);
+            if let Some(existing) = classifiers
+                .iter()
+                .find(|entry| entry.string == string || entry.variant == variant)
+            {
+                return Err(format!(
+                    "{string:?} collides with {:?} as {variant}",
+                    existing.string
+                ));
+            }
+
+            let name = category_name(&string);
+            match categories.last_mut() {
+                Some(category) if category.name == name => {}
+                _ => {
+                    if categories.iter().any(|category| category.name == name) {
+                        return Err(format!("category {name:?} is not contiguous"));
+                    }
+                    categories.push(Category {
+                        name: name.to_string(),
+                        variant: variant_name(name),
+                        module: module_name(name),
+                        feature: module_name(name).replace('_', "-"),
+                        start: classifiers.len(),
+                        classifiers: Vec::new(),
+                    });
+                }
+            }
+            let entry = Entry {
+                string,
+                variant,
+                code,
+            };
+            categories
+                .last_mut()
+                .expect("category was just pushed")
+                .classifiers
+                .push(entry.clone());
+            classifiers.push(entry);
+        }
+
+        let mut deprecated = Vec::new();
+        for line in read(&data.join("deprecated.txt"))?.lines() {
+            let mut fields = line.split('\t');
+            let Some(string) = fields.next().filter(|string| !string.is_empty()) else {
+                continue;
+            };
+            if classifiers.iter().any(|entry| entry.string == string) {
+                return Err(format!("{string:?} is both current and deprecated"));
+            }
+            let replacements: Vec<String> = fields.map(str::to_string).collect();
+            if let Some(unknown) = replacements.iter().find(|replacement| {
+                !classifiers
+                    .iter()
+                    .any(|entry| &entry.string == *replacement)
+            }) {
+                return Err(format!("{string:?} is replaced by unknown {unknown:?}"));
+            }
+            deprecated.push(Deprecated {
+                string: string.to_string(),
+                replacements,
+            });
+        }
+        deprecated.sort_by(|a, b| a.string.cmp(&b.string));
+
+        let mut history = Vec::new();
+        if let Ok(entries) = std::fs::read_dir(data.join("history")) {
+            for entry in entries.flatten() {
+                let path = entry.path();
+                let Some(version) = path
+                    .file_name()
+                    .and_then(|name| name.to_str())
+                    .and_then(|name| name.strip_suffix(".txt"))
+                else {
+                    continue;
+                };
+                history.push(Snapshot::parse(version, &read(&path)?)?);
+            }
+        }
+        history.sort_by_key(|snapshot| version_key(&snapshot.version));
+
+        let retired_codes = codes
+            .entries
+            .iter()
+            .filter(|(_, string)| !classifiers.iter().any(|entry| &entry.string == string))
+            .map(|&(code, _)| code)
+            .collect();
+
+        Ok(Dataset {
+            version,
+            classifiers,
+            categories,
+            deprecated,
+            history,
+            retired_codes,
+        })
+    }
+
+    /// 64-bit FNV-1a, as documented for `DATASET_FINGERPRINT`.
+    pub fn fingerprint(&self) -> u64 {
+        let mut hash: u64 = 0xcbf2_9ce4_8422_2325;
+        for entry in &self.classifiers {
+            for byte in entry.string.bytes().chain([b'\n']) {
+                hash ^= u64::from(byte);
+                hash = hash.wrapping_mul(0x0000_0100_0000_01b3);
+            }
+        }
+        hash
+    }
+}
+
+/// The first segment of a classifier.
+fn category_name(classifier: &str) -> &str {
+    classifier.split(" :: ").next().unwrap_or(classifier)
+}
+
+/// The Rust identifier used for a classifier's enum variant.
+fn variant_name(classifier: &str) -> String {
+    classifier
+        .replace("::", "__")
+        .replace('.', "_")
+        .replace(' ', "")
+        .replace(['(', ')', '/', '-', '\'', ','], "")
+        .replace('#', "sharp")
+        .replace('+', "plus")
+}
+
+/// The Rust module name used for a category's data.
+fn module_name(category: &str) -> String {
+    category
+        .split(|c: char| !c.is_ascii_alphanumeric())
+        .filter(|word| !word.is_empty())
+        .map(str::to_ascii_lowercase)
+        .collect::<Vec<_>>()
+        .join("_")
+}
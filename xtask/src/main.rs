@@ -0,0 +1,252 @@
+//! Maintenance tasks for trove-classifiers.
+//!
+//! Run with `cargo xtask <task>` from anywhere in the repository.
+//!
+//! Tasks
+//!
+//! * `fetch` - snapshot the classifiers of the installed python package
+//!   pypa/trove-classifiers into `data/`, keeping the replaced snapshot in `data/history/` and
+//!   assigning stable codes to new classifiers in `data/codes.txt`
+//! * `codegen` - regenerate the `Classifier` enum and its data tables in `src/`, and the JSON
+//!   Schema in `schema/`, from `data/`
+//! * `codegen --check` - fail if `src/` or `schema/` is out of date with `data/`
+
+use std::env;
+use std::fs;
+use std::io::Write;
+use std::path::{Path, PathBuf};
+use std::process::{self, Command, Stdio};
+
+mod dataset;
+mod render;
+
+use dataset::{Codes, Dataset, Snapshot};
+
+type Result<T> = std::result::Result<T, String>;
+
+const FETCH_SCRIPT: &str = r#"
+import importlib.metadata
+import trove_classifiers
+
+print(importlib.metadata.distribution("trove_classifiers").version)
+for classifier in trove_classifiers.sorted_classifiers:
+    print(classifier)
+print()
+for classifier, replacements in sorted(trove_classifiers.deprecated_classifiers.items()):
+    print("\t".join([classifier, *replacements]))
+"#;
+
+fn main() {
+    let args: Vec<String> = env::args().skip(1).collect();
+    let args: Vec<&str> = args.iter().map(String::as_str).collect();
+    let result = match args.as_slice() {
+        ["fetch"] => fetch(),
+        ["codegen"] => codegen(false),
+        ["codegen", "--check"] => codegen(true),
+        _ => Err("usage: cargo xtask (fetch | codegen [--check])".to_string()),
+    };
+    if let Err(err) = result {
+        eprintln!("error: {err}");
+        process::exit(1);
+    }
+}
+
+fn root() -> PathBuf {
+    Path::new(env!("CARGO_MANIFEST_DIR"))
+        .parent()
+        .expect("xtask lives in the workspace root")
+        .to_path_buf()
+}
+
+fn read(path: &Path) -> Result<String> {
+    fs::read_to_string(path).map_err(|err| format!("reading {}: {err}", path.display()))
+}
+
+fn write(path: &Path, contents: &str) -> Result<()> {
+    fs::write(path, contents).map_err(|err| format!("writing {}: {err}", path.display()))
+}
+
+/// Snapshot the installed python package into `data/`.
+///
+/// The interpreter can be overridden with the `PYTHON` environment variable.
+fn fetch() -> Result<()> {
+    let python = env::var("PYTHON").unwrap_or_else(|_| "python3".to_string());
+    let output = Command::new(&python)
+        .args(["-c", FETCH_SCRIPT])
+        .output()
+        .map_err(|err| format!("running {python}: {err}"))?;
+    if !output.status.success() {
+        return Err(format!(
+            "{python} could not read trove-classifiers (is it installed?)\n{}",
+            String::from_utf8_lossy(&output.stderr)
+        ));
+    }
+    let stdout = String::from_utf8(output.stdout).map_err(|err| err.to_string())?;
+    let mut lines = stdout.lines();
+    let version = lines.next().ok_or("no version reported")?;
+    let classifiers: Vec<&str> = lines.by_ref().take_while(|line| !line.is_empty()).collect();
+    let deprecated: Vec<&str> = lines.collect();
+
+    let data = root().join("data");
+    fs::create_dir_all(&data).map_err(|err| format!("creating {}: {err}", data.display()))?;
+    archive(&data, version, &classifiers)?;
+    let codes_txt = data.join("codes.txt");
+    let mut codes = match fs::read_to_string(&codes_txt) {
+        Ok(contents) => Codes::parse(&contents)?,
+        Err(_) => Codes {
+            entries: Vec::new(),
+        },
+    };
+    codes.assign(&classifiers)?;
+    write(&codes_txt, &codes.render())?;
+    write(&data.join("VERSION"), &format!("{version}\n"))?;
+    write(
+        &data.join("classifiers.txt"),
+        &format!("{}\n", classifiers.join("\n")),
+    )?;
+    write(
+        &data.join("deprecated.txt"),
+        &deprecated
+            .iter()
+            .map(|line| format!("{line}\n"))
+            .collect::<String>(),
+    )?;
+    println!(
+        "fetched {} classifiers and {} deprecated classifiers from {version}",
+        classifiers.len(),
+        deprecated.len()
+    );
+    Ok(())
+}
+
+/// Keep the dataset being replaced as a snapshot in `data/history/`, and re-record the existing
+/// snapshots against the classifiers of the new `version`.
+fn archive(data: &Path, version: &str, classifiers: &[&str]) -> Result<()> {
+    let Ok(previous) = Dataset::load(data) else {
+        return Ok(());
+    };
+    if previous.version == version {
+        return Ok(());
+    }
+    let current: Vec<String> = previous
+        .classifiers
+        .iter()
+        .map(|entry| entry.string.clone())
+        .collect();
+    let new: Vec<String> = classifiers
+        .iter()
+        .map(|string| string.to_string())
+        .collect();
+    let history = data.join("history");
+    fs::create_dir_all(&history).map_err(|err| format!("creating {}: {err}", history.display()))?;
+    let mut snapshots = previous.history;
+    snapshots.push(Snapshot::between(&previous.version, &current, &current));
+    for snapshot in snapshots {
+        let rebased = Snapshot::between(&snapshot.version, &snapshot.classifiers(&current), &new);
+        write(
+            &history.join(format!("{}.txt", snapshot.version)),
+            &rebased.render(),
+        )?;
+    }
+    Ok(())
+}
+
+/// Regenerate every generated file, or regenerated region of a file, in `src/` and `schema/`.
+fn codegen(check: bool) -> Result<()> {
+    let root = root();
+    let dataset = Dataset::load(&root.join("data"))?;
+    let src = root.join("src");
+    let generated = src.join("generated");
+
+    let lib_rs = src.join("lib.rs");
+    let manifest = root.join("Cargo.toml");
+    let mut outputs = vec![
+        (
+            manifest.clone(),
+            render::manifest(&read(&manifest)?, &dataset)?,
+        ),
+        (lib_rs.clone(), render::lib(&read(&lib_rs)?, &dataset)),
+        (generated.join("mod.rs"), render::generated_mod(&dataset)),
+        (
+            generated.join("deprecated.rs"),
+            render::deprecated(&dataset),
+        ),
+        (generated.join("history.rs"), render::history(&dataset)),
+        (
+            root.join("schema").join("classifier.schema.json"),
+            render::json_schema(&dataset),
+        ),
+    ];
+    for category in &dataset.categories {
+        outputs.push((
+            generated.join(format!("{}.rs", category.module)),
+            render::category(category)?,
+        ));
+    }
+
+    let mut expected = Vec::new();
+    for (path, source) in outputs {
+        expected.push(path.clone());
+        let formatted = match path.extension() {
+            Some(extension) if extension == "rs" => rustfmt(&source)?,
+            _ => source,
+        };
+        let current = fs::read_to_string(&path).unwrap_or_default();
+        if formatted == current {
+            continue;
+        }
+        if check {
+            return Err(format!(
+                "{} is out of date, run `cargo xtask codegen`",
+                path.display()
+            ));
+        }
+        let dir = path.parent().unwrap_or(&root);
+        fs::create_dir_all(dir).map_err(|err| format!("creating {}: {err}", dir.display()))?;
+        write(&path, &formatted)?;
+        println!("regenerated {}", path.display());
+    }
+
+    // categories can disappear upstream, taking their module with them
+    if let Ok(entries) = fs::read_dir(&generated) {
+        for entry in entries.flatten() {
+            let path = entry.path();
+            if expected.contains(&path) {
+                continue;
+            }
+            if check {
+                return Err(format!("{} is stale", path.display()));
+            }
+            fs::remove_file(&path).map_err(|err| format!("removing {}: {err}", path.display()))?;
+            println!("removed {}", path.display());
+        }
+    }
+    Ok(())
+}
+
+/// Format generated source the same way `cargo fmt` would.
+fn rustfmt(source: &str) -> Result<String> {
+    let mut child = Command::new("rustfmt")
+        .args(["--edition", "2021", "--emit", "stdout"])
+        .stdin(Stdio::piped())
+        .stdout(Stdio::piped())
+        .stderr(Stdio::piped())
+        .spawn()
+        .map_err(|err| format!("running rustfmt: {err}"))?;
+    child
+        .stdin
+        .take()
+        .expect("stdin is piped")
+        .write_all(source.as_bytes())
+        .map_err(|err| format!("writing to rustfmt: {err}"))?;
+    let output = child
+        .wait_with_output()
+        .map_err(|err| format!("running rustfmt: {err}"))?;
+    if !output.status.success() {
+        return Err(format!(
+            "rustfmt failed\n{}",
+            String::from_utf8_lossy(&output.stderr)
+        ));
+    }
+    String::from_utf8(output.stdout).map_err(|err| err.to_string())
+}
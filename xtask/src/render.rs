@@ -0,0 +1,408 @@
+//! Rendering of the generated Rust sources.
+
+use std::fmt::Write;
+
+use crate::dataset::{Category, Dataset};
+use crate::Result;
+
+const HEADER: &str = "// @generated by `cargo xtask codegen` from data/, do not edit by hand.\n\n";
+
+const CLASSIFIER_DOC: &str = r#"/// # Examples
+///
+#[cfg_attr(not(feature = "license"), doc = "```ignore")]
+#[cfg_attr(feature = "license", doc = "```")]
+/// use trove_classifiers::Classifier;
+///
+/// let license_classifier = Classifier::License__OSIApproved__GNUGeneralPublicLicensev3orlaterGPLv3plus;
+/// assert_eq!(license_classifier.as_ref(), "License :: OSI Approved :: GNU General Public License v3 or later (GPLv3+)");
+/// ```
+///
+#[cfg_attr(not(feature = "programming-language"), doc = "```ignore")]
+#[cfg_attr(feature = "programming-language", doc = "```")]
+/// use std::str::FromStr;
+/// use trove_classifiers::Classifier;
+///
+/// let py3 = Classifier::from_str("Programming Language :: Python :: 3 :: Only")?;
+/// assert_eq!(py3, Classifier::ProgrammingLanguage__Python__3__Only);
+/// # Ok::<(), trove_classifiers::ParseError>(())
+/// ```
+"#;
+
+const CATEGORY_DOC: &str = r#"/// A top-level category of classifiers
+///
+/// Every category of the dataset has a variant, including those compiled out by features. The
+/// number of classifiers in each is generated with the dataset, so tables and bitsets can be
+/// sized at compile time.
+///
+/// # Examples
+///
+/// ```
+/// use trove_classifiers::{Category, DATASET_LEN};
+///
+/// // one bit per framework classifier
+/// type Frameworks = [u64; Category::Framework.len().div_ceil(64)];
+/// assert_eq!(core::mem::size_of::<Frameworks>(), 8 * 3);
+///
+/// let total: usize = Category::ALL.iter().map(Category::len).sum();
+/// assert_eq!(total, DATASET_LEN);
+/// ```
+"#;
+
+/// Replace the generated constants in the otherwise hand-written `src/lib.rs`.
+pub fn lib(current: &str, dataset: &Dataset) -> String {
+    let mut out = String::with_capacity(current.len());
+    for line in current.lines() {
+        if line.starts_with("pub const PYPA_VERSION") {
+            writeln!(out, "pub const PYPA_VERSION: &str = {:?};", dataset.version).unwrap();
+        } else if line.starts_with("pub const DATASET_FINGERPRINT") {
+            writeln!(
+                out,
+                "pub const DATASET_FINGERPRINT: u64 = {:#018x};",
+                dataset.fingerprint()
+            )
+            .unwrap();
+        } else {
+            out.push_str(line);
+            out.push('\n');
+        }
+    }
+    out
+}
+
+/// Replace the generated category features in the otherwise hand-written `Cargo.toml`.
+pub fn manifest(current: &str, dataset: &Dataset) -> Result<String> {
+    const BEGIN: &str = "# begin generated category features";
+    const END: &str = "# end generated category features";
+    let (before, rest) = current
+        .split_once(BEGIN)
+        .ok_or(format!("Cargo.toml is missing {BEGIN:?}"))?;
+    let (_, after) = rest
+        .split_once(END)
+        .ok_or(format!("Cargo.toml is missing {END:?}"))?;
+
+    let mut out = String::from(before);
+    out.push_str(BEGIN);
+    out.push_str("\nall-categories = [\n");
+    for category in &dataset.categories {
+        writeln!(out, "    {:?},", category.feature).unwrap();
+    }
+    out.push_str("]\n");
+    for category in &dataset.categories {
+        writeln!(out, "{} = []", category.feature).unwrap();
+    }
+    out.push_str(END);
+    out.push_str(after);
+    Ok(out)
+}
+
+/// `src/generated/mod.rs`: the enum itself and the index of category tables.
+pub fn generated_mod(dataset: &Dataset) -> String {
+    let mut out = String::from(HEADER);
+    out.push_str("//! The classifier dataset, split into one module per top-level category.\n");
+    out.push_str("//!\n");
+    out.push_str("//! Each category is compiled only when its cargo feature is enabled.\n\n");
+
+    let any_category = dataset
+        .categories
+        .iter()
+        .map(|category| format!("feature = {:?}", category.feature))
+        .collect::<Vec<_>>()
+        .join(", ");
+    writeln!(out, "#[cfg(not(any({any_category})))]").unwrap();
+    out.push_str(
+        "compile_error!(\"at least one classifier category feature must be enabled\");\n\n",
+    );
+
+    for category in &dataset.categories {
+        writeln!(out, "#[cfg(feature = {:?})]", category.feature).unwrap();
+        writeln!(out, "mod {};", category.module).unwrap();
+    }
+    out.push_str("pub(crate) mod deprecated;\n");
+    out.push_str("pub(crate) mod history;\n");
+    out.push_str("\nuse crate::table::CategoryTable;\n\n");
+
+    out.push_str(CLASSIFIER_DOC);
+    out.push_str("#[derive(Clone, Copy, Debug, Eq, Hash, Ord, PartialEq, PartialOrd)]\n");
+    out.push_str("#[allow(non_camel_case_types)]\n");
+    out.push_str("#[repr(u16)]\n");
+    out.push_str("pub enum Classifier {\n");
+    for category in &dataset.categories {
+        for (index, entry) in category.classifiers.iter().enumerate() {
+            writeln!(out, "    #[cfg(feature = {:?})]", category.feature).unwrap();
+            writeln!(out, "    {} = {},", entry.variant, category.start + index).unwrap();
+        }
+    }
+    out.push_str("}\n\n");
+
+    out.push_str("impl Classifier {\n");
+    out.push_str("    /// Every classifier, in the canonical order used by pypi.org\n");
+    out.push_str("    pub const ALL: &'static [Classifier] = &[\n");
+    for category in &dataset.categories {
+        for entry in &category.classifiers {
+            writeln!(out, "        #[cfg(feature = {:?})]", category.feature).unwrap();
+            writeln!(out, "        Classifier::{},", entry.variant).unwrap();
+        }
+    }
+    out.push_str("    ];\n}\n\n");
+
+    out.push_str(CATEGORY_DOC);
+    out.push_str("#[derive(Clone, Copy, Debug, Eq, Hash, Ord, PartialEq, PartialOrd)]\n");
+    out.push_str("pub enum Category {\n");
+    for category in &dataset.categories {
+        writeln!(out, "    {},", category.variant).unwrap();
+    }
+    out.push_str("}\n\n");
+
+    out.push_str("impl Category {\n");
+    out.push_str("    /// Every category, in canonical order\n");
+    out.push_str("    pub const ALL: &'static [Category] = &[\n");
+    for category in &dataset.categories {
+        writeln!(out, "        Category::{},", category.variant).unwrap();
+    }
+    out.push_str("    ];\n\n");
+    out.push_str(
+        "    /// The name of this category, which is the first segment of its classifiers\n",
+    );
+    out.push_str("    pub const fn name(&self) -> &'static str {\n");
+    out.push_str("        match self {\n");
+    for category in &dataset.categories {
+        writeln!(
+            out,
+            "            Category::{} => {:?},",
+            category.variant, category.name
+        )
+        .unwrap();
+    }
+    out.push_str("        }\n    }\n\n");
+    out.push_str(
+        "    /// The number of classifiers in this category, including any compiled out by features\n",
+    );
+    out.push_str("    #[allow(clippy::len_without_is_empty)]\n");
+    out.push_str("    pub const fn len(&self) -> usize {\n");
+    out.push_str("        match self {\n");
+    for category in &dataset.categories {
+        writeln!(
+            out,
+            "            Category::{} => {},",
+            category.variant,
+            category.classifiers.len()
+        )
+        .unwrap();
+    }
+    out.push_str("        }\n    }\n}\n\n");
+
+    writeln!(
+        out,
+        "/// The number of classifiers in the dataset, including any compiled out by features\n\
+         pub const DATASET_LEN: usize = {};\n",
+        dataset.classifiers.len()
+    )
+    .unwrap();
+
+    writeln!(
+        out,
+        "/// The stable codes of classifiers no longer in the dataset, which are never reassigned\n\
+         #[cfg(feature = \"alloc\")]\n\
+         pub(crate) static RETIRED_CODES: &[u16] = &{:?};\n",
+        dataset.retired_codes
+    )
+    .unwrap();
+
+    out.push_str("/// Every enabled category, in canonical order\n");
+    out.push_str("pub(crate) static CATEGORIES: &[&CategoryTable] = &[\n");
+    for category in &dataset.categories {
+        writeln!(out, "    #[cfg(feature = {:?})]", category.feature).unwrap();
+        writeln!(out, "    &{}::TABLE,", category.module).unwrap();
+    }
+    out.push_str("];\n\n");
+
+    dispatch(
+        &mut out,
+        dataset,
+        "/// The category table holding a classifier and its index within that table\n\
+         pub(crate) fn locate(classifier: Classifier) -> (&'static CategoryTable, usize)",
+        |category, index| format!("(&{}::TABLE, {index})", category.module),
+    );
+    out.push('\n');
+    dispatch(
+        &mut out,
+        dataset,
+        "/// The segments of a classifier's canonical string\n\
+         pub(crate) fn segments(classifier: Classifier) -> &'static [&'static str]",
+        |category, index| format!("{}::SEGMENTS[{index}]", category.module),
+    );
+    out.push('\n');
+    dispatch(
+        &mut out,
+        dataset,
+        "/// The name of a classifier's variant\n\
+         pub(crate) fn variant_name(classifier: Classifier) -> &'static str",
+        |category, index| format!("{}::VARIANTS[{index}]", category.module),
+    );
+    out.push('\n');
+    dispatch(
+        &mut out,
+        dataset,
+        "/// The category of a classifier\n\
+         pub(crate) fn category(classifier: Classifier) -> Category",
+        |category, _| format!("Category::{}", category.variant),
+    );
+    out
+}
+
+/// A function matching a classifier's discriminant to its category and index within that
+/// category, which are passed to `arm` to build the function's result.
+fn dispatch(
+    out: &mut String,
+    dataset: &Dataset,
+    signature: &str,
+    arm: impl Fn(&Category, &str) -> String,
+) {
+    writeln!(out, "{signature} {{").unwrap();
+    out.push_str("    let discriminant = classifier as usize;\n");
+    out.push_str("    match discriminant {\n");
+    for category in &dataset.categories {
+        let start = category.start;
+        let end = start + category.classifiers.len() - 1;
+        let index = match start {
+            0 => "discriminant".to_string(),
+            _ => format!("discriminant - {start}"),
+        };
+        writeln!(out, "        #[cfg(feature = {:?})]", category.feature).unwrap();
+        writeln!(out, "        {start}..={end} => {},", arm(category, &index)).unwrap();
+    }
+    out.push_str("        _ => unreachable!(\"every discriminant is within a category\"),\n");
+    out.push_str("    }\n}\n");
+}
+
+/// `src/generated/deprecated.rs`: the classifiers pypi.org no longer accepts.
+pub fn deprecated(dataset: &Dataset) -> String {
+    let mut out = String::from(HEADER);
+    out.push_str(
+        "/// Deprecated classifiers, ordered by their string, and the strings of their replacements\n",
+    );
+    out.push_str("pub(crate) static DEPRECATED: &[(&str, &[&str])] = &[\n");
+    for deprecated in &dataset.deprecated {
+        writeln!(
+            out,
+            "    ({:?}, &{:?}),",
+            deprecated.string, deprecated.replacements
+        )
+        .unwrap();
+    }
+    out.push_str("];\n");
+    out
+}
+
+/// `src/generated/history.rs`: earlier snapshots of the dataset.
+pub fn history(dataset: &Dataset) -> String {
+    let mut out = String::from(HEADER);
+    out.push_str(
+        "/// Earlier versions of the dataset, oldest first, each with the classifiers removed and\n\
+         /// added since\n",
+    );
+    out.push_str("pub(crate) static HISTORY: &[(&str, &[&str], &[&str])] = &[\n");
+    for snapshot in &dataset.history {
+        writeln!(
+            out,
+            "    ({:?}, &{:?}, &{:?}),",
+            snapshot.version, snapshot.removed_since, snapshot.added_since
+        )
+        .unwrap();
+    }
+    out.push_str("];\n");
+    out
+}
+
+/// `schema/classifier.schema.json`: the JSON Schema of an accepted classifier, exactly as
+/// `trove_classifiers::export::json_schema(&[])` writes it.
+pub fn json_schema(dataset: &Dataset) -> String {
+    let mut out = String::from("{\n  \"title\": \"Trove classifier\",\n");
+    // Classifiers and versions are printable ASCII without escapes, which Debug and JSON quote
+    // alike
+    writeln!(
+        out,
+        "  \"description\": {:?},",
+        format!(
+            "A classifier pypi.org accepts, as of trove-classifiers {}",
+            dataset.version
+        )
+    )
+    .unwrap();
+    out.push_str("  \"type\": \"string\",\n  \"enum\": [");
+    for (index, entry) in dataset.classifiers.iter().enumerate() {
+        out.push_str(if index == 0 { "\n    " } else { ",\n    " });
+        write!(out, "{:?}", entry.string).unwrap();
+    }
+    out.push_str("\n  ]\n}\n");
+    out
+}
+
+/// `src/generated/<category>.rs`: a single category's table.
+pub fn category(category: &Category) -> Result<String> {
+    let mut out = String::from(HEADER);
+    out.push_str("use crate::table::CategoryTable;\n");
+    out.push_str("use crate::Classifier;\n\n");
+    out.push_str("pub(super) static TABLE: CategoryTable = CategoryTable {\n");
+    writeln!(out, "    name: {:?},", category.name).unwrap();
+    out.push_str("    classifiers: &[\n");
+    for entry in &category.classifiers {
+        writeln!(out, "        Classifier::{},", entry.variant).unwrap();
+    }
+    out.push_str("    ],\n");
+    out.push_str("    text: concat!(\n");
+    for entry in &category.classifiers {
+        writeln!(out, "        {:?},", entry.string).unwrap();
+    }
+    out.push_str("    ),\n");
+    out.push_str("    ends: &[\n");
+    let mut end = 0;
+    for entry in &category.classifiers {
+        end += entry.string.len();
+        let end = u16::try_from(end)
+            .map_err(|_| format!("category {:?} has too much text", category.name))?;
+        writeln!(out, "        {end},").unwrap();
+    }
+    out.push_str("    ],\n");
+    let mut sorted: Vec<usize> = (0..category.classifiers.len()).collect();
+    sorted.sort_by_key(|&index| category.classifiers[index].string.to_ascii_lowercase());
+    out.push_str("    sorted: &[\n");
+    for index in sorted {
+        writeln!(out, "        {index},").unwrap();
+    }
+    out.push_str("    ],\n");
+    out.push_str("    codes: &[\n");
+    for entry in &category.classifiers {
+        writeln!(out, "        {},", entry.code).unwrap();
+    }
+    out.push_str("    ],\n};\n\n");
+
+    // kept apart from TABLE so the linker can drop it from builds that never use segments
+    writeln!(
+        out,
+        "pub(super) static SEGMENTS: [&[&str]; {}] = [",
+        category.classifiers.len()
+    )
+    .unwrap();
+    for entry in &category.classifiers {
+        let segments: Vec<String> = entry
+            .string
+            .split(" :: ")
+            .map(|segment| format!("{segment:?}"))
+            .collect();
+        writeln!(out, "    &[{}],", segments.join(", ")).unwrap();
+    }
+    out.push_str("];\n\n");
+
+    writeln!(
+        out,
+        "pub(super) static VARIANTS: [&str; {}] = [",
+        category.classifiers.len()
+    )
+    .unwrap();
+    for entry in &category.classifiers {
+        writeln!(out, "    {:?},", entry.variant).unwrap();
+    }
+    out.push_str("];\n");
+    Ok(out)
+}